@@ -339,6 +339,14 @@ async fn test_video_poker(ctx: &mut Context) {
     })
     .await;
 
+    let events = ctx
+        .execute(Instruction::CasinoGameMove {
+            session_id,
+            payload: vec![0xFF, 0b0010, 3], // Deuces Wild, full pay, three hands
+        })
+        .await;
+    print_result("Deal", &events);
+
     let events = ctx
         .execute(Instruction::CasinoGameMove {
             session_id,
@@ -779,6 +779,7 @@ async fn run_tournaments(client: Arc<Client>, organizer: Arc<Bot>, duration: Dur
                     tournament_id,
                     start_time_ms: now,
                     end_time_ms: now + 5000, // 5s duration for sim
+                    payout_structure: Default::default(),
                 },
             ),
         )
@@ -283,13 +283,15 @@ fn generate_move_payload(game_type: GameType, rng: &mut StdRng, move_number: u32
             1 => vec![2],
             _ => vec![],
         },
-        GameType::VideoPoker => {
-            if move_number == 0 {
-                vec![rng.gen_range(0u8..=31u8)]
-            } else {
-                vec![]
-            }
-        }
+        GameType::VideoPoker => match move_number {
+            0 => vec![
+                0xFF,
+                rng.gen_range(0u8..12u8),
+                [1u8, 3, 5][rng.gen_range(0..3)],
+            ],
+            1 => vec![rng.gen_range(0u8..=31u8)],
+            _ => vec![],
+        },
         GameType::HiLo => match move_number {
             0 => vec![rng.gen_range(0u8..=1u8)],
             1 => vec![2],
@@ -341,6 +343,49 @@ fn generate_move_payload(game_type: GameType, rng: &mut StdRng, move_number: u32
             3 => vec![4],
             _ => vec![],
         },
+        GameType::PaiGow => match move_number {
+            0 => vec![0],
+            1 => vec![3],
+            _ => vec![],
+        },
+        GameType::LetItRide => match move_number {
+            0 => vec![0],
+            1 | 2 => vec![rng.gen_range(2..=3)],
+            _ => vec![],
+        },
+        GameType::Keno => match move_number {
+            0 => {
+                let spot_count = rng.gen_range(1u8..=10u8);
+                let first = rng.gen_range(1u8..=(81 - spot_count));
+                let stake = rng.gen_range(5u64..=25u64);
+                let mut payload = vec![0, rng.gen_range(0u8..=2u8), rng.gen_range(1u8..=5u8)];
+                payload.extend_from_slice(&stake.to_be_bytes());
+                payload.push(spot_count);
+                payload.extend(first..first + spot_count);
+                payload
+            }
+            _ => vec![],
+        },
+        GameType::BigSix => match move_number {
+            0 => {
+                let amount = rng.gen_range(5u64..=25u64);
+                let mut payload = vec![0, rng.gen_range(0u8..=6u8), 0];
+                payload.extend_from_slice(&amount.to_be_bytes());
+                payload
+            }
+            1 => vec![1],
+            _ => vec![],
+        },
+        GameType::Crash => match move_number {
+            0 => {
+                let amount = rng.gen_range(5u64..=25u64);
+                let mut payload = vec![0, 2, rng.gen_range(2u8..=10u8)];
+                payload.extend_from_slice(&amount.to_be_bytes());
+                payload
+            }
+            1 => vec![1],
+            _ => vec![],
+        },
     }
 }
 
@@ -401,7 +446,13 @@ async fn play_session(client: &Client, actor: &mut ActorState, rng: &mut StdRng,
 
     let session_id = actor.next_session_id();
     let bet = match game_type {
-        GameType::Baccarat | GameType::Craps | GameType::Roulette | GameType::SicBo => 0,
+        GameType::Baccarat
+        | GameType::BigSix
+        | GameType::Craps
+        | GameType::Crash
+        | GameType::Keno
+        | GameType::Roulette
+        | GameType::SicBo => 0,
         _ => rng.gen_range(5u64..=25u64),
     };
 
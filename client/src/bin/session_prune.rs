@@ -0,0 +1,196 @@
+//! Session prune tool - deletes completed casino sessions left in state before session
+//! retention was introduced.
+//!
+//! Sessions that are still active or inside the retention window are skipped on chain, so the
+//! input can be a raw export of session ids (for example from `CasinoGameCompleted` events).
+//!
+//! Usage:
+//!   cargo run --release --bin session-prune -- --identity <IDENTITY_HEX> --sessions-file <PATH>
+//!   cargo run --release --bin session-prune -- --identity <IDENTITY_HEX> --session-id 1 --session-id 2
+
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use commonware_codec::{DecodeExt, ReadExt};
+use commonware_cryptography::{
+    ed25519::{PrivateKey, PublicKey},
+    Signer,
+};
+use commonware_utils::from_hex;
+use nullspace_client::{operation_value, Client};
+use nullspace_types::{
+    casino::MAX_PRUNE_CASINO_SESSIONS,
+    execution::{Instruction, Key, Transaction, Value},
+    Identity,
+};
+use std::env;
+use tracing::{info, warn};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Prune completed casino sessions from state")]
+struct Args {
+    /// Nullspace simulator base URL (http(s)://host:port)
+    #[arg(long, default_value = "http://localhost:8080")]
+    url: String,
+
+    /// Network identity hex (for verifying simulator responses)
+    #[arg(long)]
+    identity: String,
+
+    /// Private key hex of the account submitting the prune transactions
+    #[arg(long)]
+    key: Option<String>,
+
+    /// Path to file with the private key hex
+    #[arg(long)]
+    key_file: Option<String>,
+
+    /// Session id to prune (repeatable)
+    #[arg(long)]
+    session_id: Vec<u64>,
+
+    /// Path to a file with one session id per line
+    #[arg(long)]
+    sessions_file: Option<String>,
+
+    /// Only report which sessions would be pruned
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .init();
+
+    let args = Args::parse();
+    let identity = decode_identity(&args.identity)?;
+    let client = Client::new(&args.url, identity)?;
+
+    let mut session_ids = args.session_id;
+    if let Some(path) = args.sessions_file.as_deref() {
+        session_ids.extend(read_session_ids(path)?);
+    }
+    session_ids.sort_unstable();
+    session_ids.dedup();
+    if session_ids.is_empty() {
+        return Err(anyhow!("Provide --session-id or --sessions-file"));
+    }
+
+    // Only submit sessions that are still in state and complete.
+    let mut completed = Vec::new();
+    for session_id in session_ids {
+        let lookup = client.query_state(&Key::CasinoSession(session_id)).await?;
+        match lookup.and_then(|lookup| operation_value(&lookup.operation).cloned()) {
+            Some(Value::CasinoSession(session)) if session.is_complete => {
+                completed.push(session_id)
+            }
+            Some(Value::CasinoSession(_)) => warn!(session_id, "session still active; skipping"),
+            _ => {}
+        }
+    }
+    info!(sessions = completed.len(), "completed sessions found");
+    if args.dry_run || completed.is_empty() {
+        return Ok(());
+    }
+
+    let key = require_arg_or_env_or_file(
+        args.key,
+        args.key_file,
+        "CASINO_PRUNER_PRIVATE_KEY_HEX",
+        "CASINO_PRUNER_PRIVATE_KEY_FILE",
+    )?;
+    let private = decode_private_key(&key)?;
+    let public = private.public_key();
+    let mut nonce = fetch_nonce(&client, &public).await?;
+
+    let mut txs = Vec::new();
+    for batch in completed.chunks(MAX_PRUNE_CASINO_SESSIONS) {
+        txs.push(Transaction::sign(
+            &private,
+            nonce,
+            Instruction::PruneCasinoSessions {
+                session_ids: batch.to_vec(),
+            },
+        ));
+        nonce += 1;
+    }
+    let batches = txs.len();
+    client
+        .submit_transactions(txs)
+        .await
+        .context("Failed to submit prune transactions")?;
+
+    info!(batches, next_nonce = nonce, "Prune transactions submitted");
+    Ok(())
+}
+
+fn read_session_ids(path: &str) -> Result<Vec<u64>> {
+    let contents = std::fs::read_to_string(path).context("Failed to read sessions file")?;
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.parse::<u64>()
+                .with_context(|| format!("Invalid session id: {line}"))
+        })
+        .collect()
+}
+
+fn decode_identity(hex_str: &str) -> Result<Identity> {
+    let bytes = from_hex(hex_str.trim_start_matches("0x"))
+        .ok_or_else(|| anyhow!("Invalid identity hex"))?;
+    let identity = Identity::decode(&mut bytes.as_slice()).context("Failed to decode identity")?;
+    Ok(identity)
+}
+
+fn read_secret_file(path: &str) -> Result<String> {
+    let contents = std::fs::read_to_string(path).context("Failed to read secret file")?;
+    let trimmed = contents.trim();
+    if trimmed.is_empty() {
+        return Err(anyhow!("Secret file is empty: {path}"));
+    }
+    Ok(trimmed.to_string())
+}
+
+fn require_arg_or_env_or_file(
+    value: Option<String>,
+    file: Option<String>,
+    env_key: &str,
+    env_file: &str,
+) -> Result<String> {
+    if let Some(value) = value {
+        return Ok(value);
+    }
+    if let Some(file_path) = file {
+        return read_secret_file(&file_path);
+    }
+    if let Ok(value) = env::var(env_key) {
+        return Ok(value);
+    }
+    if let Ok(file_path) = env::var(env_file) {
+        return read_secret_file(&file_path);
+    }
+    Err(anyhow!("Missing {env_key} or {env_file} (flag or env var)"))
+}
+
+fn decode_private_key(hex_str: &str) -> Result<PrivateKey> {
+    let bytes = from_hex(hex_str.trim_start_matches("0x"))
+        .ok_or_else(|| anyhow!("Invalid private key hex"))?;
+    let mut buf: &[u8] = bytes.as_slice();
+    let key = PrivateKey::read(&mut buf).context("Failed to decode private key")?;
+    if !buf.is_empty() {
+        return Err(anyhow!("Unexpected trailing bytes in private key"));
+    }
+    Ok(key)
+}
+
+async fn fetch_nonce(client: &Client, public: &PublicKey) -> Result<u64> {
+    let lookup = client.query_state(&Key::Account(public.clone())).await?;
+    let nonce = match lookup.and_then(|lookup| operation_value(&lookup.operation).cloned()) {
+        Some(Value::Account(account)) => account.nonce,
+        _ => 0,
+    };
+    Ok(nonce)
+}
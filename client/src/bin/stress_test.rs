@@ -171,11 +171,16 @@ fn generate_move_payload(game_type: GameType, rng: &mut StdRng, move_number: u32
             }
         }
         GameType::VideoPoker => {
-            if move_number == 0 {
+            match move_number {
+                // Random game, paytable and hand count, then deal
+                0 => vec![
+                    0xFF,
+                    rng.gen_range(0u8..12u8),
+                    [1u8, 3, 5][rng.gen_range(0..3)],
+                ],
                 // Random hold mask (0..31)
-                vec![rng.gen_range(0u8..=31u8)]
-            } else {
-                vec![]
+                1 => vec![rng.gen_range(0u8..=31u8)],
+                _ => vec![],
             }
         }
         GameType::HiLo => {
@@ -243,6 +248,62 @@ fn generate_move_payload(game_type: GameType, rng: &mut StdRng, move_number: u32
                 _ => vec![],
             }
         }
+        GameType::PaiGow => {
+            match move_number {
+                0 => vec![0], // Deal
+                1 => vec![3], // Set hand the house way
+                _ => vec![],
+            }
+        }
+        GameType::LetItRide => {
+            match move_number {
+                0 => vec![0],                        // Deal
+                1 | 2 => vec![rng.gen_range(2..=3)], // Pull back or let it ride
+                _ => vec![],
+            }
+        }
+        GameType::Keno => {
+            match move_number {
+                0 => {
+                    // Play: [0, paytable, races, stake:u64 BE, spot_count, spots...]
+                    let spot_count = rng.gen_range(1u8..=10u8);
+                    let first = rng.gen_range(1u8..=(81 - spot_count));
+                    let stake = rng.gen_range(5u64..=25u64);
+                    let mut payload = vec![0, rng.gen_range(0u8..=2u8), rng.gen_range(1u8..=5u8)];
+                    payload.extend_from_slice(&stake.to_be_bytes());
+                    payload.push(spot_count);
+                    payload.extend(first..first + spot_count);
+                    payload
+                }
+                _ => vec![],
+            }
+        }
+        GameType::BigSix => {
+            match move_number {
+                0 => {
+                    // Place bet: [0, symbol, 0, amount:u64 BE]
+                    let amount = rng.gen_range(5u64..=25u64);
+                    let mut payload = vec![0, rng.gen_range(0u8..=6u8), 0];
+                    payload.extend_from_slice(&amount.to_be_bytes());
+                    payload
+                }
+                1 => vec![1], // Spin
+                _ => vec![],
+            }
+        }
+        GameType::Crash => {
+            match move_number {
+                0 => {
+                    // Place bet with a whole-number auto cash-out: [0, 2, target, amount:u64 BE]
+                    let amount = rng.gen_range(5u64..=25u64);
+                    let mut payload = vec![0, 2, rng.gen_range(2u8..=10u8)];
+                    payload.extend_from_slice(&amount.to_be_bytes());
+                    payload
+                }
+                1 => vec![1], // Draw the crash point
+                _ => vec![],
+            }
+        }
     }
 }
 
@@ -330,7 +391,13 @@ async fn run_bot(
         let session_id = bot.next_session_id();
         // Table-style games place wagers via moves; start with 0 to avoid double-charging.
         let bet = match game_type {
-            GameType::Baccarat | GameType::Craps | GameType::Roulette | GameType::SicBo => 0,
+            GameType::Baccarat
+            | GameType::BigSix
+            | GameType::Craps
+            | GameType::Crash
+            | GameType::Keno
+            | GameType::Roulette
+            | GameType::SicBo => 0,
             _ => rng.gen_range(5u64..=25u64),
         };
 
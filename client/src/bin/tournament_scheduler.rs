@@ -260,8 +260,7 @@ async fn main() -> Result<()> {
 
             if now_ms >= schedule.start_time_ms
                 && now_ms < schedule.end_time_ms
-                && phase != TournamentPhase::Active
-                && phase != TournamentPhase::Complete
+                && phase == TournamentPhase::Registration
                 && last_started_slot != Some(tournament_id)
             {
                 info!(
@@ -279,6 +278,7 @@ async fn main() -> Result<()> {
                         tournament_id,
                         start_time_ms: schedule.start_time_ms,
                         end_time_ms: schedule.end_time_ms,
+                        payout_structure: Default::default(),
                     },
                 )
                 .await
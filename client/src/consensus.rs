@@ -41,6 +41,19 @@ impl Client {
         Ok(Some(seed))
     }
 
+    /// Compute an `expires_at_view` that keeps a transaction valid for `valid_for_views` views
+    /// past the latest seed.
+    ///
+    /// Pass the result to `Transaction::sign_with_extensions` so a transaction that is not
+    /// included in time is rejected instead of landing late.
+    pub async fn expiry_view(&self, valid_for_views: u64) -> Result<u64> {
+        let latest = match self.query_seed(Query::Latest).await? {
+            Some(seed) => seed.view().get(),
+            None => 0,
+        };
+        Ok(latest.saturating_add(valid_for_views))
+    }
+
     /// Wait until the latest seed view is at least `min_view`.
     ///
     /// This is useful for bots that need to wait for the chain to advance. Callers can apply
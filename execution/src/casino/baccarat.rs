@@ -30,7 +30,7 @@ use super::logging::{clamp_i64, format_card_list, push_resolved_entry};
 use super::serialization::{StateReader, StateWriter};
 use super::super_mode::apply_super_multiplier_cards;
 use super::{cards, limits, CasinoGame, GameError, GameResult, GameRng};
-use nullspace_types::casino::GameSession;
+use nullspace_types::casino::{GameSession, GlobalTableBet};
 use std::fmt::Write;
 
 /// Payout multipliers for Baccarat (per Wizard of Odds standard paytables).
@@ -332,6 +332,79 @@ fn banker_draws(banker_total: u8, player_third_card: Option<u8>) -> bool {
     }
 }
 
+/// Deal a full coup from a fresh shoe: two cards each, then third-card draws.
+///
+/// Returns `(player_cards, banker_cards)`. The RNG is consumed identically regardless
+/// of which bets are on the layout, so shared-round tables can replay the same deal.
+fn deal_hands(rng: &mut GameRng) -> Result<(Vec<u8>, Vec<u8>), GameError> {
+    let mut deck = rng.create_shoe(BACCARAT_DECKS);
+
+    // Deal 2 cards each: Player, Banker, Player, Banker
+    let mut player_cards = vec![
+        rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
+        rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
+    ];
+    let mut banker_cards = vec![
+        rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
+        rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
+    ];
+
+    let player_total = hand_total(&player_cards);
+    let banker_total = hand_total(&banker_cards);
+
+    // Natural check (8 or 9 on first two cards)
+    let natural = player_total >= 8 || banker_total >= 8;
+    if natural {
+        return Ok((player_cards, banker_cards));
+    }
+
+    let mut player_third_card: Option<u8> = None;
+    if player_draws(player_total) {
+        let card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
+        player_cards.push(card);
+        player_third_card = Some(card);
+    }
+
+    if banker_draws(banker_total, player_third_card) {
+        let card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
+        banker_cards.push(card);
+    }
+
+    Ok((player_cards, banker_cards))
+}
+
+/// Bets currently on a baccarat layout, for the global table totals.
+///
+/// Baccarat bets have no target, so every entry is reported with `target = 0`.
+pub(crate) fn table_bets(blob: &[u8]) -> Vec<GlobalTableBet> {
+    parse_state(blob)
+        .map(|state| {
+            state
+                .bets
+                .iter()
+                .map(|bet| GlobalTableBet {
+                    bet_type: bet.bet_type as u8,
+                    target: 0,
+                    amount: bet.amount,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Deal the shared coup for a global table round.
+///
+/// Outcome bytes: `[player_len:u8] [player_cards...] [banker_len:u8] [banker_cards...]`.
+pub(crate) fn table_outcome(rng: &mut GameRng) -> Result<Vec<u8>, GameError> {
+    let (player_cards, banker_cards) = deal_hands(rng)?;
+    let mut outcome = Vec::with_capacity(2 + player_cards.len() + banker_cards.len());
+    outcome.push(player_cards.len() as u8);
+    outcome.extend_from_slice(&player_cards);
+    outcome.push(banker_cards.len() as u8);
+    outcome.extend_from_slice(&banker_cards);
+    Ok(outcome)
+}
+
 /// Context for evaluating baccarat bet payouts
 struct BaccaratOutcome {
     player_total: u8,
@@ -704,43 +777,13 @@ impl CasinoGame for Baccarat {
                     return Err(GameError::InvalidMove);
                 }
 
-                // Deal initial cards
-                let mut deck = rng.create_shoe(BACCARAT_DECKS);
-
-                // Deal 2 cards each: Player, Banker, Player, Banker
-                state.player_cards = vec![
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                ];
-                state.banker_cards = vec![
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                ];
-
-                let mut player_total = hand_total(&state.player_cards);
-                let mut banker_total = hand_total(&state.banker_cards);
-
-                // Natural check (8 or 9 on first two cards)
-                let natural = player_total >= 8 || banker_total >= 8;
-
-                let mut player_third_card: Option<u8> = None;
-
-                if !natural {
-                    // Player draws?
-                    if player_draws(player_total) {
-                        let card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
-                        state.player_cards.push(card);
-                        player_third_card = Some(card);
-                        player_total = hand_total(&state.player_cards);
-                    }
+                // Deal both hands (including third-card draws)
+                let (player_cards, banker_cards) = deal_hands(rng)?;
+                state.player_cards = player_cards;
+                state.banker_cards = banker_cards;
 
-                    // Banker draws?
-                    if banker_draws(banker_total, player_third_card) {
-                        let card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
-                        state.banker_cards.push(card);
-                        banker_total = hand_total(&state.banker_cards);
-                    }
-                }
+                let player_total = hand_total(&state.player_cards);
+                let banker_total = hand_total(&state.banker_cards);
 
                 // Build outcome context
                 let outcome = BaccaratOutcome {
@@ -930,37 +973,12 @@ impl CasinoGame for Baccarat {
                 state.bets = bets_to_place;
 
                 // Deal cards (same logic as action 1)
-                let mut deck = rng.create_shoe(BACCARAT_DECKS);
-
-                state.player_cards = vec![
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                ];
-                state.banker_cards = vec![
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                    rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?,
-                ];
-
-                let mut player_total = hand_total(&state.player_cards);
-                let mut banker_total = hand_total(&state.banker_cards);
-
-                let natural = player_total >= 8 || banker_total >= 8;
-                let mut player_third_card: Option<u8> = None;
-
-                if !natural {
-                    if player_draws(player_total) {
-                        let card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
-                        state.player_cards.push(card);
-                        player_third_card = Some(card);
-                        player_total = hand_total(&state.player_cards);
-                    }
+                let (player_cards, banker_cards) = deal_hands(rng)?;
+                state.player_cards = player_cards;
+                state.banker_cards = banker_cards;
 
-                    if banker_draws(banker_total, player_third_card) {
-                        let card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
-                        state.banker_cards.push(card);
-                        banker_total = hand_total(&state.banker_cards);
-                    }
-                }
+                let player_total = hand_total(&state.player_cards);
+                let banker_total = hand_total(&state.banker_cards);
 
                 // Build outcome context
                 let outcome = BaccaratOutcome {
@@ -1055,6 +1073,19 @@ impl CasinoGame for Baccarat {
             _ => Err(GameError::InvalidPayload),
         }
     }
+
+    fn abandon(session: &mut GameSession, _rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        if !state.player_cards.is_empty() {
+            return Err(GameError::GameAlreadyComplete);
+        }
+
+        // Nothing has been dealt, so every pending bet is refunded.
+        let refund: u64 = state.bets.iter().map(|b| b.amount).sum();
+        state.bets.clear();
+        session.state_blob = serialize_state(&state);
+        Ok(GameResult::Push(refund, vec![]))
+    }
 }
 
 #[cfg(test)]
@@ -1370,6 +1401,29 @@ mod tests {
         assert!(state.bets.is_empty());
     }
 
+    #[test]
+    fn test_abandon_refunds_pending_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Baccarat::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let payload = place_bet_payload(BetType::Banker, 100);
+        Baccarat::process_move(&mut session, &payload, &mut rng).expect("Failed to process move");
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let payload = place_bet_payload(BetType::PlayerPair, 20);
+        Baccarat::process_move(&mut session, &payload, &mut rng).expect("Failed to process move");
+
+        let mut rng = GameRng::new(&seed, session.id, 3);
+        let result = Baccarat::abandon(&mut session, &mut rng).expect("Failed to abandon");
+        assert!(matches!(result, GameResult::Push(120, _)));
+
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert!(state.bets.is_empty());
+        assert!(state.player_cards.is_empty());
+    }
+
     fn make_outcome(
         player_total: u8,
         banker_total: u8,
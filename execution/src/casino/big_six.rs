@@ -0,0 +1,830 @@
+//! Big Six (Money Wheel) game implementation with multi-bet support.
+//!
+//! State blob format:
+//! [version:u8=1] [wheel:u8] [bet_count:u8] [bets:BigSixBet×count] [stop:u8]?
+//!
+//! Each BigSixBet (10 bytes):
+//! [bet_type:u8] [number:u8] [amount:u64 BE]
+//!
+//! Payload format:
+//! Action 0: Place bet - [0, bet_type, number, amount_bytes...]
+//! Action 1: Spin wheel and resolve - [1]
+//! Action 2: Clear bets (with refund) - [2]
+//! Action 3: Atomic batch - [3, bet_count, bets...]
+//!           Each bet is 10 bytes: [bet_type:u8, number:u8, amount:u64 BE]
+//!           Ensures all-or-nothing semantics - no partial bet states
+//! Action 4: Set wheel - [4, wheel:u8]
+//!
+//! Bet types (one per wheel symbol, `number` must be 0):
+//! 0 = $1, 1 = $2, 2 = $5, 3 = $10, 4 = $20, 5 = Joker, 6 = Logo
+//!
+//! Wheels (54 segments, weights / "to 1" payouts per symbol):
+//! 0 = Classic: 24/1, 15/2, 7/5, 4/10, 2/20, 1/40, 1/40
+//! 1 = Atlantic City: 23/1, 15/2, 8/5, 4/10, 2/20, 1/45, 1/45
+//!
+//! The wheel stops on segment `next_bounded(total_weight)`; segments are laid out by
+//! symbol in cumulative weight order, so the stop alone identifies the winning symbol.
+//! Super mode boosts winning bets when the wheel stops on a Lightning Segment.
+
+use super::logging::{clamp_i64, push_resolved_entry};
+use super::serialization::{StateReader, StateWriter};
+use super::super_mode::apply_super_multiplier_number;
+use super::{limits, CasinoGame, GameError, GameResult, GameRng};
+use nullspace_types::casino::{GameSession, GlobalTableBet};
+use std::fmt::Write;
+
+const STATE_VERSION: u8 = 1;
+const BET_BYTES: usize = 10;
+const SYMBOL_COUNT: usize = 7;
+
+/// Segment weights and payouts for one wheel layout.
+struct WheelLayout {
+    weights: [u8; SYMBOL_COUNT],
+    /// Winnings per unit staked ("to 1"), indexed by symbol.
+    payouts: [u64; SYMBOL_COUNT],
+}
+
+impl WheelLayout {
+    fn total_weight(&self) -> u8 {
+        self.weights.iter().sum()
+    }
+
+    /// Symbol under a stop position, or `None` when the stop is off the wheel.
+    fn symbol_at(&self, stop: u8) -> Option<BetType> {
+        let mut upper = 0u8;
+        for (idx, weight) in self.weights.iter().enumerate() {
+            upper += weight;
+            if stop < upper {
+                return BetType::try_from(idx as u8).ok();
+            }
+        }
+        None
+    }
+}
+
+const CLASSIC_WHEEL: WheelLayout = WheelLayout {
+    weights: [24, 15, 7, 4, 2, 1, 1],
+    payouts: [1, 2, 5, 10, 20, 40, 40],
+};
+
+const ATLANTIC_CITY_WHEEL: WheelLayout = WheelLayout {
+    weights: [23, 15, 8, 4, 2, 1, 1],
+    payouts: [1, 2, 5, 10, 20, 45, 45],
+};
+
+/// Selectable wheel layouts.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Wheel {
+    #[default]
+    Classic = 0,
+    AtlanticCity = 1,
+}
+
+impl Wheel {
+    fn layout(self) -> &'static WheelLayout {
+        match self {
+            Wheel::Classic => &CLASSIC_WHEEL,
+            Wheel::AtlanticCity => &ATLANTIC_CITY_WHEEL,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Wheel::Classic => "CLASSIC",
+            Wheel::AtlanticCity => "ATLANTIC_CITY",
+        }
+    }
+}
+
+impl TryFrom<u8> for Wheel {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Wheel::Classic),
+            1 => Ok(Wheel::AtlanticCity),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+/// Big Six bet types, one per wheel symbol.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BetType {
+    One = 0,
+    Two = 1,
+    Five = 2,
+    Ten = 3,
+    Twenty = 4,
+    Joker = 5,
+    Logo = 6,
+}
+
+impl BetType {
+    fn label(self) -> &'static str {
+        match self {
+            BetType::One => "$1",
+            BetType::Two => "$2",
+            BetType::Five => "$5",
+            BetType::Ten => "$10",
+            BetType::Twenty => "$20",
+            BetType::Joker => "JOKER",
+            BetType::Logo => "LOGO",
+        }
+    }
+}
+
+impl TryFrom<u8> for BetType {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BetType::One),
+            1 => Ok(BetType::Two),
+            2 => Ok(BetType::Five),
+            3 => Ok(BetType::Ten),
+            4 => Ok(BetType::Twenty),
+            5 => Ok(BetType::Joker),
+            6 => Ok(BetType::Logo),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+/// A single bet in Big Six (10 bytes).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigSixBet {
+    pub bet_type: BetType,
+    pub amount: u64,
+}
+
+impl BigSixBet {
+    pub fn to_bytes(&self) -> [u8; 10] {
+        let mut bytes = [0u8; 10];
+        bytes[0] = self.bet_type as u8;
+        bytes[2..10].copy_from_slice(&self.amount.to_be_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 10 || bytes[1] != 0 {
+            return None;
+        }
+        let bet_type = BetType::try_from(bytes[0]).ok()?;
+        let amount = u64::from_be_bytes(bytes[2..10].try_into().ok()?);
+        if amount == 0 {
+            return None;
+        }
+        Some(Self { bet_type, amount })
+    }
+}
+
+/// Big Six game state.
+struct BigSixState {
+    wheel: Wheel,
+    bets: Vec<BigSixBet>,
+    stop: Option<u8>,
+}
+
+impl BigSixState {
+    fn new() -> Self {
+        Self {
+            wheel: Wheel::default(),
+            bets: Vec::new(),
+            stop: None,
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return Some(Self::new());
+        }
+
+        let mut reader = StateReader::new(bytes);
+        if reader.read_u8()? != STATE_VERSION {
+            return None;
+        }
+        let wheel = Wheel::try_from(reader.read_u8()?).ok()?;
+        let bet_count = reader.read_u8()? as usize;
+
+        // Validate bet count against maximum to prevent DoS via large allocations
+        if bet_count > limits::BIG_SIX_MAX_BETS {
+            return None;
+        }
+
+        let mut bets = Vec::with_capacity(bet_count);
+        for _ in 0..bet_count {
+            bets.push(BigSixBet::from_bytes(reader.read_bytes(BET_BYTES)?)?);
+        }
+
+        let stop = match reader.remaining() {
+            0 => None,
+            1 => {
+                let stop = reader.read_u8()?;
+                if stop >= wheel.layout().total_weight() {
+                    return None;
+                }
+                Some(stop)
+            }
+            _ => return None,
+        };
+
+        Some(Self { wheel, bets, stop })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        // Capacity: 3 (version + wheel + bet count) + bets (10 bytes each) + 1 (optional stop)
+        let capacity = 3 + (self.bets.len() * BET_BYTES) + usize::from(self.stop.is_some());
+        let mut bytes = StateWriter::with_capacity(capacity);
+        bytes.push_u8(STATE_VERSION);
+        bytes.push_u8(self.wheel as u8);
+        bytes.push_u8(self.bets.len() as u8);
+        for bet in &self.bets {
+            bytes.push_bytes(&bet.to_bytes());
+        }
+        if let Some(stop) = self.stop {
+            bytes.push_u8(stop);
+        }
+        bytes.into_inner()
+    }
+}
+
+fn serialize_state(state: &BigSixState) -> Vec<u8> {
+    state.to_bytes()
+}
+
+fn parse_state(bytes: &[u8]) -> Option<BigSixState> {
+    BigSixState::from_bytes(bytes)
+}
+
+/// Spin the wheel, returning the stop position.
+fn spin(rng: &mut GameRng, wheel: Wheel) -> u8 {
+    rng.next_bounded(wheel.layout().total_weight())
+}
+
+/// Bets currently on a big six layout, for the global table totals.
+pub(crate) fn table_bets(blob: &[u8]) -> Vec<GlobalTableBet> {
+    parse_state(blob)
+        .map(|state| {
+            state
+                .bets
+                .iter()
+                .map(|bet| GlobalTableBet {
+                    bet_type: bet.bet_type as u8,
+                    target: 0,
+                    amount: bet.amount,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Spin the shared wheel for a global table round.
+///
+/// Tables always use the default wheel. Consumes the RNG exactly as the spin move does.
+/// Outcome bytes: `[stop, symbol]`.
+pub(crate) fn table_outcome(rng: &mut GameRng) -> Vec<u8> {
+    let wheel = Wheel::default();
+    let stop = spin(rng, wheel);
+    let symbol = wheel
+        .layout()
+        .symbol_at(stop)
+        .map(|symbol| symbol as u8)
+        .unwrap_or_default();
+    vec![stop, symbol]
+}
+
+/// Calculate the total return (stake included) for a single bet.
+fn calculate_bet_payout(bet: &BigSixBet, wheel: Wheel, symbol: BetType) -> u64 {
+    if bet.bet_type != symbol {
+        return 0;
+    }
+    let to_one = wheel.layout().payouts[symbol as usize];
+    bet.amount.saturating_mul(to_one.saturating_add(1))
+}
+
+/// Generate JSON logs for Big Six game completion
+fn generate_big_six_logs(
+    state: &BigSixState,
+    stop: u8,
+    symbol: BetType,
+    total_wagered: u64,
+    total_return: u64,
+) -> Vec<String> {
+    let bet_capacity = state.bets.len().saturating_mul(80);
+    let resolved_capacity = state.bets.len().saturating_mul(40).saturating_add(32);
+    let mut bet_results = String::with_capacity(bet_capacity);
+    let mut resolved_entries = String::with_capacity(resolved_capacity);
+    let mut resolved_sum: i128 = 0;
+    for (idx, bet) in state.bets.iter().enumerate() {
+        if idx > 0 {
+            bet_results.push(',');
+        }
+        let payout = calculate_bet_payout(bet, state.wheel, symbol);
+        let pnl = clamp_i64(i128::from(payout) - i128::from(bet.amount));
+        resolved_sum = resolved_sum.saturating_add(i128::from(pnl));
+        push_resolved_entry(&mut resolved_entries, bet.bet_type.label(), pnl);
+        let _ = write!(
+            bet_results,
+            r#"{{"type":"{}","amount":{},"won":{},"payout":{}}}"#,
+            bet.bet_type.label(),
+            bet.amount,
+            payout > 0,
+            payout
+        );
+    }
+
+    let net_pnl = clamp_i64(i128::from(total_return) - i128::from(total_wagered));
+    let diff = i128::from(net_pnl).saturating_sub(resolved_sum);
+    if diff != 0 {
+        push_resolved_entry(&mut resolved_entries, "ADJUSTMENT", clamp_i64(diff));
+    }
+
+    vec![format!(
+        r#"{{"summary":"Wheel: {} (stop {})","netPnl":{},"resolvedBets":[{}],"wheel":"{}","stop":{},"symbol":"{}","bets":[{}],"totalWagered":{},"totalReturn":{}}}"#,
+        symbol.label(),
+        stop,
+        net_pnl,
+        resolved_entries,
+        state.wheel.name(),
+        stop,
+        symbol.label(),
+        bet_results,
+        total_wagered,
+        total_return
+    )]
+}
+
+/// Spin and settle every bet on the layout, returning the winnings credited back.
+fn resolve_spin(
+    session: &mut GameSession,
+    state: &mut BigSixState,
+    rng: &mut GameRng,
+) -> (u8, BetType, u64) {
+    let stop = spin(rng, state.wheel);
+    let symbol = state.wheel.layout().symbol_at(stop).unwrap_or(BetType::One);
+    state.stop = Some(stop);
+
+    let total_winnings: u64 = state
+        .bets
+        .iter()
+        .map(|bet| calculate_bet_payout(bet, state.wheel, symbol))
+        .sum();
+    let final_winnings = if session.super_mode.is_active && total_winnings > 0 {
+        apply_super_multiplier_number(stop, &session.super_mode.multipliers, total_winnings)
+    } else {
+        total_winnings
+    };
+
+    session.state_blob = serialize_state(state);
+    session.move_count += 1;
+    session.is_complete = true;
+    (stop, symbol, final_winnings)
+}
+
+pub struct BigSix;
+
+impl CasinoGame for BigSix {
+    fn init(session: &mut GameSession, _rng: &mut GameRng) -> GameResult {
+        let state = BigSixState::new();
+        session.state_blob = serialize_state(&state);
+        GameResult::Continue(vec![])
+    }
+
+    fn process_move(
+        session: &mut GameSession,
+        payload: &[u8],
+        rng: &mut GameRng,
+    ) -> Result<GameResult, GameError> {
+        if session.is_complete {
+            return Err(GameError::GameAlreadyComplete);
+        }
+
+        if payload.is_empty() {
+            return Err(GameError::InvalidPayload);
+        }
+
+        let action = payload[0];
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidMove)?;
+
+        match action {
+            // Action 0: Place bet
+            0 => {
+                let (bet_type, number, amount) = super::payload::parse_place_bet_payload(payload)?;
+                let bet_type = BetType::try_from(bet_type)?;
+                if number != 0 {
+                    return Err(GameError::InvalidPayload);
+                }
+                super::payload::ensure_nonzero_amount(amount)?;
+                if state.bets.len() >= limits::BIG_SIX_MAX_BETS {
+                    return Err(GameError::InvalidMove);
+                }
+
+                state.bets.push(BigSixBet { bet_type, amount });
+                session.state_blob = serialize_state(&state);
+                session.move_count += 1;
+                Ok(GameResult::ContinueWithUpdate {
+                    payout: -(amount as i64),
+                    logs: vec![],
+                })
+            }
+
+            // Action 1: Spin the wheel and resolve all bets
+            1 => {
+                if state.bets.is_empty() {
+                    return Err(GameError::InvalidPayload); // Must have at least one bet
+                }
+
+                let total_bet: u64 = state.bets.iter().map(|b| b.amount).sum();
+                let (stop, symbol, winnings) = resolve_spin(session, &mut state, rng);
+
+                // All wagers were deducted via ContinueWithUpdate at bet time, so the completion
+                // result returns the total amount to credit back (if any).
+                let logs = generate_big_six_logs(&state, stop, symbol, total_bet, winnings);
+                if winnings > 0 {
+                    Ok(GameResult::Win(winnings, logs))
+                } else {
+                    Ok(GameResult::LossPreDeducted(total_bet, logs))
+                }
+            }
+
+            // Action 2: Clear all bets (with refund)
+            2 => {
+                let refund: u64 = state.bets.iter().map(|b| b.amount).sum();
+                state.bets.clear();
+                session.state_blob = serialize_state(&state);
+
+                if refund > 0 {
+                    Ok(GameResult::ContinueWithUpdate {
+                        payout: refund as i64,
+                        logs: vec![],
+                    })
+                } else {
+                    Ok(GameResult::Continue(vec![]))
+                }
+            }
+
+            // Action 3: Atomic batch - place all bets + spin in one transaction
+            3 => {
+                // Must have existing bets cleared first (fresh round)
+                if !state.bets.is_empty() || state.stop.is_some() {
+                    return Err(GameError::InvalidMove);
+                }
+
+                if payload.len() < 2 {
+                    return Err(GameError::InvalidPayload);
+                }
+
+                let bet_count = payload[1] as usize;
+                if bet_count == 0 || bet_count > limits::BIG_SIX_MAX_BETS {
+                    return Err(GameError::InvalidPayload);
+                }
+
+                let expected_len = 2 + bet_count * BET_BYTES;
+                if payload.len() < expected_len {
+                    return Err(GameError::InvalidPayload);
+                }
+
+                // Parse and validate all bets first (before any state changes)
+                let mut bets_to_place = Vec::with_capacity(bet_count);
+                let mut total_wager: u64 = 0;
+                for chunk in payload[2..expected_len].chunks_exact(BET_BYTES) {
+                    let bet = BigSixBet::from_bytes(chunk).ok_or(GameError::InvalidPayload)?;
+                    total_wager = total_wager
+                        .checked_add(bet.amount)
+                        .ok_or(GameError::InvalidPayload)?;
+                    bets_to_place.push(bet);
+                }
+
+                session.bet = total_wager;
+                state.bets = bets_to_place;
+
+                let (stop, symbol, winnings) = resolve_spin(session, &mut state, rng);
+                let logs = generate_big_six_logs(&state, stop, symbol, total_wager, winnings);
+                if winnings > 0 {
+                    Ok(GameResult::Win(winnings, logs))
+                } else {
+                    // Total loss - wager is deducted on completion for atomic batch
+                    Ok(GameResult::Loss(logs))
+                }
+            }
+
+            // Action 4: Set wheel
+            4 => {
+                if payload.len() != 2 {
+                    return Err(GameError::InvalidPayload);
+                }
+                if state.stop.is_some() {
+                    return Err(GameError::InvalidMove);
+                }
+                state.wheel = Wheel::try_from(payload[1])?;
+                session.state_blob = serialize_state(&state);
+                Ok(GameResult::Continue(vec![]))
+            }
+
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+
+    fn abandon(session: &mut GameSession, _rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        if state.stop.is_some() {
+            return Err(GameError::GameAlreadyComplete);
+        }
+
+        // The wheel has not been spun, so every pending bet is refunded.
+        let refund: u64 = state.bets.iter().map(|b| b.amount).sum();
+        state.bets.clear();
+        session.state_blob = serialize_state(&state);
+        Ok(GameResult::Push(refund, vec![]))
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_must_use)]
+mod tests {
+    use super::*;
+    use crate::mocks::{create_account_keypair, create_network_keypair, create_seed};
+    use nullspace_types::casino::{GameType, SuperMultiplier, SuperType};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn create_test_seed() -> nullspace_types::Seed {
+        let (network_secret, _) = create_network_keypair();
+        create_seed(&network_secret, 1)
+    }
+
+    fn create_test_session(bet: u64) -> GameSession {
+        let (_, pk) = create_account_keypair(1);
+        GameSession {
+            id: 1,
+            player: pk,
+            game_type: GameType::BigSix,
+            bet,
+            state_blob: vec![],
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: nullspace_types::casino::SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        }
+    }
+
+    /// Helper to create a place bet payload.
+    fn place_bet_payload(bet_type: u8, number: u8, amount: u64) -> Vec<u8> {
+        let mut payload = vec![0, bet_type, number];
+        payload.extend_from_slice(&amount.to_be_bytes());
+        payload
+    }
+
+    fn batch_payload(bets: &[(u8, u64)]) -> Vec<u8> {
+        let mut payload = vec![3, bets.len() as u8];
+        for (bet_type, amount) in bets {
+            payload.extend_from_slice(
+                &BigSixBet {
+                    bet_type: BetType::try_from(*bet_type).expect("valid bet type"),
+                    amount: *amount,
+                }
+                .to_bytes(),
+            );
+        }
+        payload
+    }
+
+    #[test]
+    fn test_wheel_layouts() {
+        for wheel in [Wheel::Classic, Wheel::AtlanticCity] {
+            let layout = wheel.layout();
+            assert_eq!(layout.total_weight(), 54);
+
+            // Every symbol carries a house edge between 10% and 25%.
+            for (weight, payout) in layout.weights.iter().zip(layout.payouts.iter()) {
+                let returned = u64::from(*weight) * (payout + 1);
+                assert!(returned < 54, "{:?} pays over par", wheel);
+                assert!(returned * 4 >= 54 * 3, "{:?} edge too steep", wheel);
+            }
+
+            // Stops walk the symbols in order.
+            assert_eq!(layout.symbol_at(0), Some(BetType::One));
+            assert_eq!(layout.symbol_at(52), Some(BetType::Joker));
+            assert_eq!(layout.symbol_at(53), Some(BetType::Logo));
+            assert_eq!(layout.symbol_at(54), None);
+        }
+
+        assert_eq!(CLASSIC_WHEEL.symbol_at(23), Some(BetType::One));
+        assert_eq!(CLASSIC_WHEEL.symbol_at(24), Some(BetType::Two));
+        assert_eq!(ATLANTIC_CITY_WHEEL.symbol_at(23), Some(BetType::Two));
+    }
+
+    #[test]
+    fn test_bet_payouts() {
+        let bet = BigSixBet {
+            bet_type: BetType::Five,
+            amount: 10,
+        };
+        assert_eq!(
+            calculate_bet_payout(&bet, Wheel::Classic, BetType::Five),
+            60
+        );
+        assert_eq!(calculate_bet_payout(&bet, Wheel::Classic, BetType::One), 0);
+
+        let joker = BigSixBet {
+            bet_type: BetType::Joker,
+            amount: 10,
+        };
+        assert_eq!(
+            calculate_bet_payout(&joker, Wheel::Classic, BetType::Joker),
+            410
+        );
+        assert_eq!(
+            calculate_bet_payout(&joker, Wheel::AtlanticCity, BetType::Joker),
+            460
+        );
+        assert_eq!(
+            calculate_bet_payout(&joker, Wheel::Classic, BetType::Logo),
+            0
+        );
+    }
+
+    #[test]
+    fn test_place_and_spin() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        BigSix::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = BigSix::process_move(&mut session, &place_bet_payload(0, 0, 100), &mut rng);
+        assert!(matches!(
+            result,
+            Ok(GameResult::ContinueWithUpdate { payout: -100, .. })
+        ));
+        let result = BigSix::process_move(&mut session, &place_bet_payload(5, 0, 10), &mut rng);
+        assert!(result.is_ok());
+        assert!(!session.is_complete);
+
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let expected_stop = rng.clone().next_bounded(54);
+        let result = BigSix::process_move(&mut session, &[1], &mut rng).expect("Failed to spin");
+        assert!(session.is_complete);
+
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.stop, Some(expected_stop));
+        match CLASSIC_WHEEL.symbol_at(expected_stop) {
+            Some(BetType::One) => assert!(matches!(result, GameResult::Win(200, _))),
+            Some(BetType::Joker) => assert!(matches!(result, GameResult::Win(410, _))),
+            _ => assert!(matches!(result, GameResult::LossPreDeducted(110, _))),
+        }
+    }
+
+    #[test]
+    fn test_atomic_batch_sets_wager() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        BigSix::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let payload = batch_payload(&[
+            (0, 10),
+            (1, 10),
+            (2, 10),
+            (3, 10),
+            (4, 10),
+            (5, 10),
+            (6, 10),
+        ]);
+        let result = BigSix::process_move(&mut session, &payload, &mut rng)
+            .expect("Failed to process batch");
+
+        // Covering every symbol always wins exactly one bet.
+        assert_eq!(session.bet, 70);
+        assert!(session.is_complete);
+        assert!(matches!(result, GameResult::Win(..)));
+    }
+
+    #[test]
+    fn test_invalid_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        BigSix::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        // Unknown symbol
+        let result = BigSix::process_move(&mut session, &place_bet_payload(7, 0, 10), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Symbol bets take no number
+        let result = BigSix::process_move(&mut session, &place_bet_payload(0, 1, 10), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Zero amount
+        let result = BigSix::process_move(&mut session, &place_bet_payload(0, 0, 0), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Spinning an empty layout
+        let result = BigSix::process_move(&mut session, &[1], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Unknown wheel
+        let result = BigSix::process_move(&mut session, &[4, 2], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+
+        for _ in 0..limits::BIG_SIX_MAX_BETS {
+            BigSix::process_move(&mut session, &place_bet_payload(0, 0, 1), &mut rng)
+                .expect("Failed to place bet");
+        }
+        let result = BigSix::process_move(&mut session, &place_bet_payload(0, 0, 1), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+    }
+
+    #[test]
+    fn test_set_wheel_and_clear() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        BigSix::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        BigSix::process_move(&mut session, &[4, 1], &mut rng).expect("Failed to set wheel");
+        BigSix::process_move(&mut session, &place_bet_payload(2, 0, 25), &mut rng)
+            .expect("Failed to place bet");
+        let result = BigSix::process_move(&mut session, &[2], &mut rng);
+        assert!(matches!(
+            result,
+            Ok(GameResult::ContinueWithUpdate { payout: 25, .. })
+        ));
+
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.wheel, Wheel::AtlanticCity);
+        assert!(state.bets.is_empty());
+    }
+
+    #[test]
+    fn test_super_mode_multiplies_lightning_segment() {
+        let seed = create_test_seed();
+        let stop = GameRng::new(&seed, 1, 1).next_bounded(54);
+        let symbol = CLASSIC_WHEEL.symbol_at(stop).expect("stop on wheel");
+        let base = 10 * (CLASSIC_WHEEL.payouts[symbol as usize] + 1);
+
+        for (segment, expected) in [(stop, base * 5), ((stop + 1) % 54, base)] {
+            let mut session = create_test_session(0);
+            session.super_mode.is_active = true;
+            session.super_mode.multipliers = vec![SuperMultiplier {
+                id: segment,
+                multiplier: 5,
+                super_type: SuperType::Number,
+            }];
+            let mut rng = GameRng::new(&seed, session.id, 0);
+            BigSix::init(&mut session, &mut rng);
+
+            let mut rng = GameRng::new(&seed, session.id, 1);
+            let payload = batch_payload(&[(symbol as u8, 10)]);
+            let result = BigSix::process_move(&mut session, &payload, &mut rng)
+                .expect("Failed to process batch");
+            assert!(matches!(result, GameResult::Win(amount, _) if amount == expected));
+        }
+    }
+
+    #[test]
+    fn test_table_outcome_matches_spin() {
+        let seed = create_test_seed();
+        let mut rng = GameRng::new(&seed, 9, 4);
+        let outcome = table_outcome(&mut rng.clone());
+        let stop = spin(&mut rng, Wheel::Classic);
+        assert_eq!(outcome[0], stop);
+        assert_eq!(
+            Some(outcome[1]),
+            CLASSIC_WHEEL.symbol_at(stop).map(|s| s as u8)
+        );
+    }
+
+    #[test]
+    fn test_abandon_refunds_unspun_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        BigSix::init(&mut session, &mut rng);
+        BigSix::process_move(&mut session, &place_bet_payload(3, 0, 40), &mut rng)
+            .expect("Failed to place bet");
+
+        let result = BigSix::abandon(&mut session, &mut rng);
+        assert!(matches!(result, Ok(GameResult::Push(40, _))));
+
+        BigSix::process_move(&mut session, &place_bet_payload(3, 0, 40), &mut rng)
+            .expect("Failed to place bet");
+        BigSix::process_move(&mut session, &[1], &mut rng).expect("Failed to spin");
+        let result = BigSix::abandon(&mut session, &mut rng);
+        assert!(matches!(result, Err(GameError::GameAlreadyComplete)));
+    }
+
+    #[test]
+    fn test_state_blob_fuzz_does_not_panic() {
+        let mut rng = StdRng::seed_from_u64(0xb16_5e7);
+        for _ in 0..1_000 {
+            let len = rng.gen_range(0..=256);
+            let mut blob = vec![0u8; len];
+            rng.fill(&mut blob[..]);
+            let _ = parse_state(&blob);
+        }
+    }
+}
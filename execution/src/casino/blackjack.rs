@@ -4,26 +4,35 @@
 //! - Standard blackjack main wager (`session.bet`, deducted by `CasinoStartGame`)
 //! - Splits (up to 4 hands) + doubles (deducted via `ContinueWithUpdate`)
 //! - 21+3 side bet (optional, placed before deal)
+//! - Insurance and even money when the dealer peek rule is enabled
 //!
 //! House rules (executor defaults):
 //! - 8-deck shoe, dealer hits soft 17 (H17)
-//! - No surrender, no on-chain insurance
+//! - No surrender, no insurance
 //! - No dealer peek (dealer hole card is drawn at `Reveal` for hidden-info safety)
 //!
-//! State blob format (v4; v2/v3 still accepted):
-//! [version:u8=4]
+//! Dealer peek (opt-in via rules flag 0x40):
+//! - With an ace or ten-value card up, the deal stops at `AwaitingPeek`
+//! - With an ace up the player may take insurance (up to half the bet, pays 2:1) or, holding a
+//!   natural, even money (1:1, settled immediately)
+//! - `Peek` draws the hole card; it is only kept if it completes a dealer blackjack, otherwise
+//!   the state records "no blackjack" and `Reveal` later draws a hole card consistent with it
+//!
+//! State blob format (v5; v2/v3/v4 still accepted):
+//! [version:u8=5]
 //! [stage:u8]
 //! [sideBet21Plus3Amount:u64 BE]
 //! [sideBetLuckyLadiesAmount:u64 BE]
 //! [sideBetPerfectPairsAmount:u64 BE]
 //! [sideBetBustItAmount:u64 BE]
 //! [sideBetRoyalMatchAmount:u64 BE]
+//! [insuranceAmount:u64 BE] [dealerPeeked:u8]   (v5)
 //! [initialPlayerCard1:u8] [initialPlayerCard2:u8]   (0xFF if not dealt yet)
 //! [active_hand_idx:u8]
 //! [hand_count:u8]
 //! ... per hand:
 //!   [bet_mult:u8] (1=base, 2=doubled)
-//!   [status:u8] (0=playing, 1=stand, 2=bust, 3=blackjack, 4=surrendered)
+//!   [status:u8] (0=playing, 1=stand, 2=bust, 3=blackjack, 4=surrendered, 5=even money)
 //!   [was_split:u8] (0/1; split hands cannot be a natural blackjack)
 //!   [card_count:u8]
 //!   [cards...]
@@ -35,6 +44,7 @@
 //! 1 = PlayerTurn
 //! 2 = AwaitingReveal (player done; Reveal resolves)
 //! 3 = Complete
+//! 4 = AwaitingPeek (dealer shows an ace or ten; insurance/even money, then Peek)
 //!
 //! Payload format:
 //! [move:u8] [optional amount:u64 BE]
@@ -47,6 +57,9 @@
 //! 6 = Reveal
 //! 7 = Surrender
 //! 8 = Set rules (flags:u8, decks:u8)
+//! 9 = Insurance (u64)
+//! 10 = Even money
+//! 11 = Peek
 
 use super::logging::{clamp_i64, push_resolved_entry};
 use super::serialization::{StateReader, StateWriter};
@@ -59,11 +72,12 @@ use std::fmt::Write;
 const MAX_HAND_SIZE: usize = 11;
 /// Maximum number of hands allowed (splits).
 const MAX_HANDS: usize = 4;
-const STATE_VERSION: u8 = 4;
+const STATE_VERSION: u8 = 5;
 const CARD_UNKNOWN: u8 = 0xFF;
 const STATE_HEADER_V2_LEN: usize = 14;
 const STATE_HEADER_V3_LEN: usize = 38;
 const STATE_HEADER_V4_LEN: usize = 46;
+const STATE_HEADER_V5_LEN: usize = 55;
 const RULES_LEN: usize = 2;
 const UI_EXTRA_LEN: usize = 3;
 const ROYAL_MATCH_KQ_MULTIPLIER: u64 = 25;
@@ -113,6 +127,7 @@ struct BlackjackRules {
     double_after_split: bool,
     resplit_aces: bool,
     hit_split_aces: bool,
+    dealer_peek: bool,
     decks: BlackjackDecks,
 }
 
@@ -125,6 +140,7 @@ impl Default for BlackjackRules {
             double_after_split: true,
             resplit_aces: true,
             hit_split_aces: true,
+            dealer_peek: false,
             decks: BlackjackDecks::default(),
         }
     }
@@ -139,6 +155,7 @@ impl BlackjackRules {
             double_after_split: flags & 0x08 != 0,
             resplit_aces: flags & 0x10 != 0,
             hit_split_aces: flags & 0x20 != 0,
+            dealer_peek: flags & 0x40 != 0,
             decks: BlackjackDecks::try_from(decks).ok()?,
         })
     }
@@ -163,6 +180,9 @@ impl BlackjackRules {
         if self.hit_split_aces {
             flags |= 0x20;
         }
+        if self.dealer_peek {
+            flags |= 0x40;
+        }
         [flags, self.decks as u8]
     }
 }
@@ -175,6 +195,7 @@ pub enum Stage {
     PlayerTurn = 1,
     AwaitingReveal = 2,
     Complete = 3,
+    AwaitingPeek = 4,
 }
 
 impl TryFrom<u8> for Stage {
@@ -186,6 +207,7 @@ impl TryFrom<u8> for Stage {
             1 => Ok(Stage::PlayerTurn),
             2 => Ok(Stage::AwaitingReveal),
             3 => Ok(Stage::Complete),
+            4 => Ok(Stage::AwaitingPeek),
             _ => Err(GameError::InvalidPayload),
         }
     }
@@ -204,6 +226,9 @@ pub enum Move {
     Reveal = 6,
     Surrender = 7,
     SetRules = 8,
+    Insurance = 9,
+    EvenMoney = 10,
+    Peek = 11,
 }
 
 impl TryFrom<u8> for Move {
@@ -220,6 +245,9 @@ impl TryFrom<u8> for Move {
             6 => Ok(Move::Reveal),
             7 => Ok(Move::Surrender),
             8 => Ok(Move::SetRules),
+            9 => Ok(Move::Insurance),
+            10 => Ok(Move::EvenMoney),
+            11 => Ok(Move::Peek),
             _ => Err(GameError::InvalidPayload),
         }
     }
@@ -232,6 +260,7 @@ pub enum HandStatus {
     Busted = 2,
     Blackjack = 3,
     Surrendered = 4,
+    EvenMoney = 5,
 }
 
 impl TryFrom<u8> for HandStatus {
@@ -243,6 +272,7 @@ impl TryFrom<u8> for HandStatus {
             2 => Ok(HandStatus::Busted),
             3 => Ok(HandStatus::Blackjack),
             4 => Ok(HandStatus::Surrendered),
+            5 => Ok(HandStatus::EvenMoney),
             _ => Err(GameError::InvalidPayload),
         }
     }
@@ -264,6 +294,9 @@ pub struct BlackjackState {
     pub side_bet_perfect_pairs: u64,
     pub side_bet_bust_it: u64,
     pub side_bet_royal_match: u64,
+    pub insurance_bet: u64,
+    /// The dealer peeked and does not have blackjack.
+    pub dealer_peeked: bool,
     pub initial_player_cards: [u8; 2],
     pub active_hand_idx: usize,
     pub hands: Vec<HandState>,
@@ -515,6 +548,14 @@ fn resolve_bust_it_return(state: &BlackjackState) -> u64 {
     bet.saturating_mul(multiplier.saturating_add(1))
 }
 
+fn resolve_insurance_return(state: &BlackjackState) -> u64 {
+    if state.insurance_bet == 0 || !is_blackjack(&state.dealer_cards) {
+        return 0;
+    }
+    // Pays 2:1.
+    state.insurance_bet.saturating_mul(3)
+}
+
 fn resolve_side_bets_return(state: &BlackjackState) -> u64 {
     let dealer_blackjack = is_blackjack(&state.dealer_cards);
     resolve_21plus3_return(state)
@@ -522,6 +563,13 @@ fn resolve_side_bets_return(state: &BlackjackState) -> u64 {
         .saturating_add(resolve_perfect_pairs_return(state))
         .saturating_add(resolve_royal_match_return(state))
         .saturating_add(resolve_bust_it_return(state))
+        .saturating_add(resolve_insurance_return(state))
+}
+
+/// Whether the dealer's up card can make a blackjack, so a peek is needed.
+fn dealer_may_have_blackjack(up: u8) -> bool {
+    let rank = cards::card_rank_one_based(up);
+    rank == 1 || rank >= 10
 }
 
 fn apply_side_bet_update(current: &mut u64, new_bet: u64) -> Result<i64, GameError> {
@@ -600,6 +648,15 @@ fn reveal_dealer_hand(
     play_out: bool,
 ) -> Result<(), GameError> {
     if state.dealer_cards.len() < 2 {
+        if state.dealer_peeked {
+            // The peek showed no blackjack, so the hole card cannot complete one.
+            let up = state
+                .dealer_cards
+                .first()
+                .copied()
+                .ok_or(GameError::InvalidState)?;
+            deck.retain(|&card| !is_blackjack(&[up, card]));
+        }
         let hole = rng.draw_card(deck).ok_or(GameError::DeckExhausted)?;
         state.dealer_cards.push(hole);
     }
@@ -621,7 +678,7 @@ fn reveal_dealer_hand(
 
 /// Serialize state to blob.
 fn serialize_state(state: &BlackjackState) -> Vec<u8> {
-    let mut capacity = STATE_HEADER_V4_LEN + RULES_LEN + UI_EXTRA_LEN;
+    let mut capacity = STATE_HEADER_V5_LEN + RULES_LEN + UI_EXTRA_LEN;
     for hand in &state.hands {
         capacity = capacity.saturating_add(4 + hand.cards.len());
     }
@@ -634,6 +691,8 @@ fn serialize_state(state: &BlackjackState) -> Vec<u8> {
     blob.push_u64_be(state.side_bet_perfect_pairs);
     blob.push_u64_be(state.side_bet_bust_it);
     blob.push_u64_be(state.side_bet_royal_match);
+    blob.push_u64_be(state.insurance_bet);
+    blob.push_u8(state.dealer_peeked as u8);
     blob.push_bytes(&state.initial_player_cards);
     blob.push_u8(state.active_hand_idx as u8);
     blob.push_u8(state.hands.len() as u8);
@@ -666,7 +725,8 @@ fn parse_state(blob: &[u8]) -> Option<BlackjackState> {
     let version = reader.read_u8()?;
     if (version == 2 && blob.len() < STATE_HEADER_V2_LEN)
         || (version == 3 && blob.len() < STATE_HEADER_V3_LEN)
-        || (version == STATE_VERSION && blob.len() < STATE_HEADER_V4_LEN)
+        || (version == 4 && blob.len() < STATE_HEADER_V4_LEN)
+        || (version == STATE_VERSION && blob.len() < STATE_HEADER_V5_LEN)
     {
         return None;
     }
@@ -687,7 +747,7 @@ fn parse_state(blob: &[u8]) -> Option<BlackjackState> {
             reader.read_u64_be()?,
             0,
         )
-    } else if version == STATE_VERSION {
+    } else if version == 4 || version == STATE_VERSION {
         (
             reader.read_u64_be()?,
             reader.read_u64_be()?,
@@ -698,6 +758,11 @@ fn parse_state(blob: &[u8]) -> Option<BlackjackState> {
     } else {
         return None;
     };
+    let (insurance_bet, dealer_peeked) = if version == STATE_VERSION {
+        (reader.read_u64_be()?, reader.read_u8()? != 0)
+    } else {
+        (0, false)
+    };
     let initial_player_cards: [u8; 2] = reader.read_bytes(2)?.try_into().ok()?;
     if !initial_player_cards
         .iter()
@@ -782,6 +847,8 @@ fn parse_state(blob: &[u8]) -> Option<BlackjackState> {
         side_bet_perfect_pairs,
         side_bet_bust_it,
         side_bet_royal_match,
+        insurance_bet,
+        dealer_peeked,
         initial_player_cards,
         active_hand_idx,
         hands,
@@ -802,6 +869,8 @@ impl CasinoGame for Blackjack {
             side_bet_perfect_pairs: 0,
             side_bet_bust_it: 0,
             side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
             initial_player_cards: [CARD_UNKNOWN; 2],
             active_hand_idx: 0,
             hands: Vec::new(),
@@ -884,18 +953,7 @@ impl CasinoGame for Blackjack {
                         was_split: false,
                     }];
                     state.dealer_cards = vec![dealer_up];
-                    state.active_hand_idx = 0;
-                    state.stage = if player_bj {
-                        Stage::AwaitingReveal
-                    } else {
-                        Stage::PlayerTurn
-                    };
-
-                    // If the only hand is already non-playing (natural BJ), we can skip directly to
-                    // reveal stage.
-                    if state.stage == Stage::PlayerTurn && !advance_turn(&mut state) {
-                        state.stage = Stage::AwaitingReveal;
-                    }
+                    enter_first_stage(&mut state);
 
                     session.state_blob = serialize_state(&state);
                     Ok(GameResult::Continue(vec![]))
@@ -964,16 +1022,7 @@ impl CasinoGame for Blackjack {
                             was_split: false,
                         }];
                         state.dealer_cards = vec![dealer_up];
-                        state.active_hand_idx = 0;
-                        state.stage = if player_bj {
-                            Stage::AwaitingReveal
-                        } else {
-                            Stage::PlayerTurn
-                        };
-
-                        if state.stage == Stage::PlayerTurn && !advance_turn(&mut state) {
-                            state.stage = Stage::AwaitingReveal;
-                        }
+                        enter_first_stage(&mut state);
 
                         session.state_blob = serialize_state(&state);
                         Ok(if payout_update == 0 {
@@ -990,23 +1039,7 @@ impl CasinoGame for Blackjack {
                 }
             },
             Stage::PlayerTurn => {
-                // Reconstruct deck (excludes only visible/known cards).
-                let mut used_counts = [0u8; 52];
-                for hand in &state.hands {
-                    for &card in &hand.cards {
-                        if card < 52 {
-                            used_counts[card as usize] =
-                                used_counts[card as usize].saturating_add(1);
-                        }
-                    }
-                }
-                for &card in &state.dealer_cards {
-                    if card < 52 {
-                        used_counts[card as usize] = used_counts[card as usize].saturating_add(1);
-                    }
-                }
-                let mut deck =
-                    rng.create_shoe_excluding_counts(&used_counts, state.rules.decks.count());
+                let mut deck = remaining_shoe(&state, rng);
 
                 match mv {
                     Move::Hit => {
@@ -1233,25 +1266,7 @@ impl CasinoGame for Blackjack {
                         return Err(GameError::InvalidPayload);
                     }
 
-                    // Reconstruct deck excluding all known cards (player hands + dealer up).
-                    let mut used_counts = [0u8; 52];
-                    for hand in &state.hands {
-                        for &card in &hand.cards {
-                            if card < 52 {
-                                used_counts[card as usize] =
-                                    used_counts[card as usize].saturating_add(1);
-                            }
-                        }
-                    }
-                    for &card in &state.dealer_cards {
-                        if card < 52 {
-                            used_counts[card as usize] =
-                                used_counts[card as usize].saturating_add(1);
-                        }
-                    }
-                    let mut deck =
-                        rng.create_shoe_excluding_counts(&used_counts, state.rules.decks.count());
-
+                    let mut deck = remaining_shoe(&state, rng);
                     let any_live = state.hands.iter().any(|h| h.status != HandStatus::Busted);
                     reveal_dealer_hand(&mut state, rng, &mut deck, any_live)?;
 
@@ -1266,11 +1281,165 @@ impl CasinoGame for Blackjack {
                 }
                 _ => Err(GameError::InvalidMove),
             },
+            Stage::AwaitingPeek => {
+                let dealer_up = state
+                    .dealer_cards
+                    .first()
+                    .copied()
+                    .ok_or(GameError::InvalidState)?;
+                let ace_up = cards::card_rank_one_based(dealer_up) == 1;
+                match mv {
+                    Move::Insurance => {
+                        if payload.len() != 9 {
+                            return Err(GameError::InvalidPayload);
+                        }
+                        let amount = super::payload::parse_u64_be(payload, 1)?;
+                        // Insurance is capped at half the main bet.
+                        if !ace_up
+                            || state.insurance_bet != 0
+                            || amount == 0
+                            || amount > session.bet / 2
+                        {
+                            return Err(GameError::InvalidMove);
+                        }
+                        state.insurance_bet = amount;
+                        session.state_blob = serialize_state(&state);
+                        Ok(GameResult::ContinueWithUpdate {
+                            payout: -(amount as i64),
+                            logs: vec![],
+                        })
+                    }
+                    Move::EvenMoney => {
+                        if payload.len() != 1 || !ace_up || state.insurance_bet != 0 {
+                            return Err(GameError::InvalidMove);
+                        }
+                        let hand = state.hands.first_mut().ok_or(GameError::InvalidState)?;
+                        if hand.status != HandStatus::Blackjack {
+                            return Err(GameError::InvalidMove);
+                        }
+                        hand.status = HandStatus::EvenMoney;
+
+                        // The main bet is settled; the dealer only plays on for side bets.
+                        if state.side_bet_lucky_ladies > 0 || state.side_bet_bust_it > 0 {
+                            let mut deck = remaining_shoe(&state, rng);
+                            let play_out = state.side_bet_bust_it > 0;
+                            reveal_dealer_hand(&mut state, rng, &mut deck, play_out)?;
+                        }
+                        let total_return = resolve_main_return(session, &state)
+                            .saturating_add(resolve_side_bets_return(&state));
+
+                        state.stage = Stage::Complete;
+                        session.is_complete = true;
+                        session.state_blob = serialize_state(&state);
+
+                        Ok(finalize_game_result(session, &state, total_return))
+                    }
+                    Move::Peek => {
+                        if payload.len() != 1 {
+                            return Err(GameError::InvalidPayload);
+                        }
+                        let mut deck = remaining_shoe(&state, rng);
+                        let hole = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
+                        if !is_blackjack(&[dealer_up, hole]) {
+                            // Only "no blackjack" is revealed; the hole card is drawn at `Reveal`.
+                            state.dealer_peeked = true;
+                            enter_player_turn(&mut state);
+                            session.state_blob = serialize_state(&state);
+                            return Ok(GameResult::Continue(vec![]));
+                        }
+
+                        state.dealer_cards.push(hole);
+                        let total_return = resolve_main_return(session, &state)
+                            .saturating_add(resolve_side_bets_return(&state));
+
+                        state.stage = Stage::Complete;
+                        session.is_complete = true;
+                        session.state_blob = serialize_state(&state);
+
+                        Ok(finalize_game_result(session, &state, total_return))
+                    }
+                    _ => Err(GameError::InvalidMove),
+                }
+            }
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.stage {
+            Stage::Betting => {
+                // Nothing dealt yet: return the main wager and any side bets.
+                let refund = session
+                    .bet
+                    .saturating_add(state.side_bet_21plus3)
+                    .saturating_add(state.side_bet_lucky_ladies)
+                    .saturating_add(state.side_bet_perfect_pairs)
+                    .saturating_add(state.side_bet_bust_it)
+                    .saturating_add(state.side_bet_royal_match);
+                Ok(GameResult::Push(refund, vec![]))
+            }
+            Stage::PlayerTurn => {
+                // Stand on every hand still in play, then let the dealer resolve.
+                for hand in &mut state.hands {
+                    if hand.status == HandStatus::Playing {
+                        hand.status = HandStatus::Standing;
+                    }
+                }
+                state.stage = Stage::AwaitingReveal;
+                session.state_blob = serialize_state(&state);
+                Self::process_move(session, &[Move::Reveal as u8], rng)
+            }
+            Stage::AwaitingReveal => Self::process_move(session, &[Move::Reveal as u8], rng),
+            Stage::AwaitingPeek => {
+                // Decline insurance; a dealer blackjack settles here, otherwise play on as above.
+                let result = Self::process_move(session, &[Move::Peek as u8], rng)?;
+                if session.is_complete {
+                    Ok(result)
+                } else {
+                    Self::abandon(session, rng)
+                }
+            }
             Stage::Complete => Err(GameError::GameAlreadyComplete),
         }
     }
 }
 
+/// Set the stage after the initial deal: the peek when the rules call for one, otherwise the
+/// player's turn.
+fn enter_first_stage(state: &mut BlackjackState) {
+    let needs_peek = state
+        .dealer_cards
+        .first()
+        .is_some_and(|&up| dealer_may_have_blackjack(up));
+    if state.rules.dealer_peek && needs_peek {
+        state.stage = Stage::AwaitingPeek;
+    } else {
+        enter_player_turn(state);
+    }
+}
+
+/// Start the player's turn; a natural leaves no hand to play, so it goes straight to the reveal.
+fn enter_player_turn(state: &mut BlackjackState) {
+    state.active_hand_idx = 0;
+    state.stage = Stage::PlayerTurn;
+    if !advance_turn(state) {
+        state.stage = Stage::AwaitingReveal;
+    }
+}
+
+/// Rebuild the shoe without the cards already on the table.
+fn remaining_shoe(state: &BlackjackState, rng: &mut GameRng) -> Vec<u8> {
+    let mut used_counts = [0u8; 52];
+    let table = state.hands.iter().flat_map(|hand| &hand.cards);
+    for &card in table.chain(&state.dealer_cards) {
+        if card < 52 {
+            used_counts[card as usize] = used_counts[card as usize].saturating_add(1);
+        }
+    }
+    rng.create_shoe_excluding_counts(&used_counts, state.rules.decks.count())
+}
+
 /// Advance active turn to next playing hand. Returns true if there is a hand to play.
 fn advance_turn(state: &mut BlackjackState) -> bool {
     while state.active_hand_idx < state.hands.len() {
@@ -1295,6 +1464,9 @@ fn resolve_hand_return(
     if hand.status == HandStatus::Surrendered {
         return if dealer_blackjack { 0 } else { bet / 2 };
     }
+    if hand.status == HandStatus::EvenMoney {
+        return bet.saturating_mul(2);
+    }
 
     let (player_value, _) = hand_value(&hand.cards);
     let player_blackjack = is_natural_blackjack(hand);
@@ -1349,6 +1521,7 @@ fn total_wagered(session: &GameSession, state: &BlackjackState) -> u64 {
         .saturating_add(state.side_bet_perfect_pairs)
         .saturating_add(state.side_bet_bust_it)
         .saturating_add(state.side_bet_royal_match)
+        .saturating_add(state.insurance_bet)
 }
 
 fn apply_super_multiplier(session: &GameSession, state: &BlackjackState, total_return: u64) -> u64 {
@@ -1396,6 +1569,7 @@ fn generate_blackjack_logs(session: &GameSession, state: &BlackjackState, total_
             HandStatus::Busted => "BUSTED",
             HandStatus::Blackjack => "BLACKJACK",
             HandStatus::Surrendered => "SURRENDERED",
+            HandStatus::EvenMoney => "EVEN MONEY",
         };
         let pnl = clamp_i64(i128::from(hand_return) - i128::from(bet));
         if state.hands.len() > 1 {
@@ -1469,6 +1643,12 @@ fn generate_blackjack_logs(session: &GameSession, state: &BlackjackState, total_
         push_resolved_entry(&mut resolved_entries, "BUST IT", side_pnl);
         resolved_sum = resolved_sum.saturating_add(i128::from(side_pnl));
     }
+    let insurance_return = resolve_insurance_return(state);
+    if state.insurance_bet > 0 {
+        let side_pnl = clamp_i64(i128::from(insurance_return) - i128::from(state.insurance_bet));
+        push_resolved_entry(&mut resolved_entries, "INSURANCE", side_pnl);
+        resolved_sum = resolved_sum.saturating_add(i128::from(side_pnl));
+    }
     let total_wagered = total_wagered(session, state);
     let net_pnl = clamp_i64(i128::from(total_return) - i128::from(total_wagered));
     let diff = i128::from(net_pnl).saturating_sub(resolved_sum);
@@ -1483,7 +1663,7 @@ fn generate_blackjack_logs(session: &GameSession, state: &BlackjackState, total_
     let summary = format!("P: {}, D: {}", player_label, dealer_value);
 
     vec![format!(
-        r#"{{"summary":"{}","netPnl":{},"resolvedBets":[{}],"hands":[{}],"dealer":{{"cards":[{}],"value":{},"blackjack":{}}},"sideBet21Plus3":{},"sideBet21Plus3Return":{},"sideBetReturn":{},"sideBetLuckyLadies":{},"sideBetLuckyLadiesReturn":{},"sideBetPerfectPairs":{},"sideBetPerfectPairsReturn":{},"sideBetRoyalMatch":{},"sideBetRoyalMatchReturn":{},"sideBetBustIt":{},"sideBetBustItReturn":{},"totalReturn":{}}}"#,
+        r#"{{"summary":"{}","netPnl":{},"resolvedBets":[{}],"hands":[{}],"dealer":{{"cards":[{}],"value":{},"blackjack":{}}},"sideBet21Plus3":{},"sideBet21Plus3Return":{},"sideBetReturn":{},"sideBetLuckyLadies":{},"sideBetLuckyLadiesReturn":{},"sideBetPerfectPairs":{},"sideBetPerfectPairsReturn":{},"sideBetRoyalMatch":{},"sideBetRoyalMatchReturn":{},"sideBetBustIt":{},"sideBetBustItReturn":{},"insurance":{},"insuranceReturn":{},"totalReturn":{}}}"#,
         summary,
         net_pnl,
         resolved_entries,
@@ -1502,6 +1682,8 @@ fn generate_blackjack_logs(session: &GameSession, state: &BlackjackState, total_
         royal_match_return,
         state.side_bet_bust_it,
         bust_it_return,
+        state.insurance_bet,
+        insurance_return,
         total_return
     )]
 }
@@ -1604,6 +1786,8 @@ mod tests {
             side_bet_perfect_pairs: 0,
             side_bet_bust_it: 0,
             side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
             initial_player_cards: [0, 9],
             active_hand_idx: 0,
             hands: vec![HandState {
@@ -1657,6 +1841,151 @@ mod tests {
         assert_eq!(updated.stage, Stage::AwaitingReveal);
     }
 
+    #[test]
+    fn test_dealer_peek_insurance() {
+        let (network_secret, _) = crate::mocks::create_network_keypair();
+        let seed = crate::mocks::create_seed(&network_secret, 1);
+        let (_, public) = crate::mocks::create_account_keypair(1);
+
+        let rules = BlackjackRules {
+            dealer_peek: true,
+            ..Default::default()
+        };
+        let state = BlackjackState {
+            stage: Stage::AwaitingPeek,
+            side_bet_21plus3: 0,
+            side_bet_lucky_ladies: 0,
+            side_bet_perfect_pairs: 0,
+            side_bet_bust_it: 0,
+            side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
+            initial_player_cards: [1, 2],
+            active_hand_idx: 0,
+            hands: vec![HandState {
+                cards: vec![1, 2],
+                bet_mult: 1,
+                status: HandStatus::Playing,
+                was_split: false,
+            }],
+            dealer_cards: vec![13],
+            rules,
+        };
+
+        let (mut dealer_blackjacks, mut played_on) = (0, 0);
+        for id in 0..200 {
+            let mut session = GameSession {
+                id,
+                player: public.clone(),
+                game_type: GameType::Blackjack,
+                bet: 100,
+                state_blob: serialize_state(&state),
+                move_count: 0,
+                created_at: 0,
+                is_complete: false,
+                super_mode: SuperModeState::default(),
+                is_tournament: false,
+                tournament_id: None,
+            };
+            let mut rng = GameRng::new(&seed, id, 1);
+            let mut insurance = vec![Move::Insurance as u8];
+            insurance.extend_from_slice(&60u64.to_be_bytes());
+            assert!(matches!(
+                Blackjack::process_move(&mut session, &insurance, &mut rng),
+                Err(GameError::InvalidMove)
+            ));
+            insurance[1..].copy_from_slice(&50u64.to_be_bytes());
+            assert!(matches!(
+                Blackjack::process_move(&mut session, &insurance, &mut rng),
+                Ok(GameResult::ContinueWithUpdate { payout: -50, .. })
+            ));
+            assert!(matches!(
+                Blackjack::process_move(&mut session, &[Move::EvenMoney as u8], &mut rng),
+                Err(GameError::InvalidMove)
+            ));
+
+            let mut rng = GameRng::new(&seed, id, 2);
+            let result = Blackjack::process_move(&mut session, &[Move::Peek as u8], &mut rng)
+                .expect("peek should succeed");
+            let peeked = parse_state(&session.state_blob).expect("valid blackjack state");
+            if session.is_complete {
+                // Main bet lost, insurance pays 2:1.
+                dealer_blackjacks += 1;
+                assert!(is_blackjack(&peeked.dealer_cards));
+                assert!(matches!(result, GameResult::Win(150, _)));
+                continue;
+            }
+
+            // Only "no blackjack" was revealed, and the later hole card honours it.
+            played_on += 1;
+            assert!(peeked.dealer_peeked);
+            assert_eq!(peeked.stage, Stage::PlayerTurn);
+            assert_eq!(peeked.dealer_cards.len(), 1);
+            let mut rng = GameRng::new(&seed, id, 3);
+            Blackjack::process_move(&mut session, &[Move::Stand as u8], &mut rng)
+                .expect("stand should succeed");
+            let mut rng = GameRng::new(&seed, id, 4);
+            Blackjack::process_move(&mut session, &[Move::Reveal as u8], &mut rng)
+                .expect("reveal should succeed");
+            let revealed = parse_state(&session.state_blob).expect("valid blackjack state");
+            assert!(!is_blackjack(&revealed.dealer_cards[..2]));
+        }
+        assert!(dealer_blackjacks > 0);
+        assert!(played_on > 0);
+    }
+
+    #[test]
+    fn test_even_money_settles_natural() {
+        let (network_secret, _) = crate::mocks::create_network_keypair();
+        let seed = crate::mocks::create_seed(&network_secret, 1);
+        let (_, public) = crate::mocks::create_account_keypair(1);
+
+        let rules = BlackjackRules {
+            dealer_peek: true,
+            ..Default::default()
+        };
+        let state = BlackjackState {
+            stage: Stage::AwaitingPeek,
+            side_bet_21plus3: 0,
+            side_bet_lucky_ladies: 0,
+            side_bet_perfect_pairs: 0,
+            side_bet_bust_it: 0,
+            side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
+            initial_player_cards: [0, 9],
+            active_hand_idx: 0,
+            hands: vec![HandState {
+                cards: vec![0, 9],
+                bet_mult: 1,
+                status: HandStatus::Blackjack,
+                was_split: false,
+            }],
+            dealer_cards: vec![13],
+            rules,
+        };
+        let mut session = GameSession {
+            id: 9,
+            player: public,
+            game_type: GameType::Blackjack,
+            bet: 100,
+            state_blob: serialize_state(&state),
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        };
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = Blackjack::process_move(&mut session, &[Move::EvenMoney as u8], &mut rng)
+            .expect("even money should be offered on a natural");
+        assert!(matches!(result, GameResult::Win(200, _)));
+        assert!(session.is_complete);
+        let settled = parse_state(&session.state_blob).expect("valid blackjack state");
+        assert_eq!(settled.hands[0].status, HandStatus::EvenMoney);
+    }
+
     #[test]
     fn test_resplit_aces_requires_rule() {
         let (network_secret, _) = crate::mocks::create_network_keypair();
@@ -1672,6 +2001,8 @@ mod tests {
             side_bet_perfect_pairs: 0,
             side_bet_bust_it: 0,
             side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
             initial_player_cards: [0, 13],
             active_hand_idx: 0,
             hands: vec![HandState {
@@ -1719,6 +2050,8 @@ mod tests {
             side_bet_perfect_pairs: 0,
             side_bet_bust_it: 0,
             side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
             initial_player_cards: [0, 13],
             active_hand_idx: 0,
             hands: vec![HandState {
@@ -1770,6 +2103,8 @@ mod tests {
             side_bet_perfect_pairs: 0,
             side_bet_bust_it: 0,
             side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
             initial_player_cards: [9, 12],
             active_hand_idx: 0,
             hands: vec![HandState {
@@ -1814,6 +2149,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_abandon_stands_on_open_hands() -> Result<(), GameError> {
+        let (network_secret, _) = crate::mocks::create_network_keypair();
+        let seed = crate::mocks::create_seed(&network_secret, 1);
+        let (_, public) = crate::mocks::create_account_keypair(1);
+
+        let mut session = GameSession {
+            id: 3,
+            player: public,
+            game_type: GameType::Blackjack,
+            bet: 100,
+            state_blob: vec![],
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        };
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Blackjack::init(&mut session, &mut rng);
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        Blackjack::process_move(
+            &mut session,
+            &[Move::Set21Plus3 as u8, 0, 0, 0, 0, 0, 0, 0, 25],
+            &mut rng,
+        )?;
+
+        // Before the deal, every wager is returned.
+        let mut betting = session.clone();
+        let result = Blackjack::abandon(&mut betting, &mut rng)?;
+        assert!(matches!(result, GameResult::Push(125, _)));
+
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        Blackjack::process_move(&mut session, &[Move::Deal as u8], &mut rng)?;
+        let dealt = parse_state(&session.state_blob).expect("state");
+
+        let mut rng = GameRng::new(&seed, session.id, 3);
+        let result = Blackjack::abandon(&mut session, &mut rng)?;
+        assert!(!matches!(
+            result,
+            GameResult::Continue(_) | GameResult::ContinueWithUpdate { .. }
+        ));
+        assert!(session.is_complete);
+
+        // The player's cards are unchanged; only the dealer drew.
+        let resolved = parse_state(&session.state_blob).expect("state");
+        assert_eq!(resolved.stage, Stage::Complete);
+        assert_eq!(resolved.hands[0].cards, dealt.hands[0].cards);
+        assert!(resolved.dealer_cards.len() >= 2);
+        Ok(())
+    }
+
     #[test]
     fn test_hit_all_busted_side_bet_win_returns_win() -> Result<(), GameError> {
         let (network_secret, _) = crate::mocks::create_network_keypair();
@@ -1827,6 +2215,8 @@ mod tests {
             side_bet_perfect_pairs: 0,
             side_bet_bust_it: 0,
             side_bet_royal_match: 0,
+            insurance_bet: 0,
+            dealer_peeked: false,
             initial_player_cards: [1, 2],
             active_hand_idx: 0,
             hands: vec![HandState {
@@ -1939,6 +2329,7 @@ mod tests {
                             }
                         }
                     }
+                    Stage::AwaitingPeek => vec![Move::Peek as u8],
                     Stage::Complete => break,
                 };
 
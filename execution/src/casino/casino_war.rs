@@ -874,6 +874,20 @@ impl CasinoGame for CasinoWar {
             Stage::Complete => Err(GameError::GameAlreadyComplete),
         }
     }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.stage {
+            // No cards dealt yet: return the ante and any tie bet.
+            Stage::Betting => Ok(GameResult::Push(
+                session.bet.saturating_add(state.tie_bet),
+                vec![],
+            )),
+            // Waiting on a tie decision: surrender rather than raise the wager.
+            Stage::War => Self::process_move(session, &[Move::Surrender as u8], rng),
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,8 @@
 //! Each CrapsBetEntry (19 bytes):
 //! [bet_type:u8] [target:u8] [status:u8] [amount:u64 BE] [odds_amount:u64 BE]
 //!
+//! Buy/Lay bets that pay their commission up front keep it in `odds_amount`.
+//!
 //! Phases:
 //! 0 = Come out (initial roll)
 //! 1 = Point phase (rolling for point)
@@ -65,9 +67,24 @@ mod payouts {
     pub const ATS_TALL: u64 = 30;
     pub const ATS_ALL: u64 = 150;
 
+    // One-roll proposition bets ("to 1" winnings, as numerator/denominator).
+    // Horn, C&E and World are split bets; these are the net odds on the whole bet.
+    pub const ANY_SEVEN: (u64, u64) = (4, 1);
+    pub const ANY_CRAPS: (u64, u64) = (7, 1);
+    pub const HORN_2_OR_12: (u64, u64) = (27, 4);  // 30:1 on one unit, other three lose
+    pub const HORN_3_OR_11: (u64, u64) = (3, 1);   // 15:1 on one unit, other three lose
+    pub const CE_CRAPS: (u64, u64) = (3, 1);       // 7:1 on half, the eleven half loses
+    pub const CE_ELEVEN: (u64, u64) = (7, 1);      // 15:1 on half, the craps half loses
+    pub const WORLD_2_OR_12: (u64, u64) = (26, 5); // 30:1 on one unit, other four lose
+    pub const WORLD_3_OR_11: (u64, u64) = (11, 5); // 15:1 on one unit, other four lose
+
+    // Big 6 / Big 8 pay even money
+    pub const BIG_6_OR_8: u64 = 1;
+
     // Commission rates
     pub const YES_NO_COMMISSION_DIVISOR: u64 = 100;  // 1% commission
     pub const NEXT_COMMISSION_DIVISOR: u64 = 100;    // 1% commission
+    pub const BUY_LAY_COMMISSION_PERCENT: u64 = 5;   // 5% vig, rounded up
 }
 
 const STATE_VERSION: u8 = 2;
@@ -158,6 +175,18 @@ pub enum BetType {
     RideLine = 20,  // Ride the Line
     Replay = 21,    // Replay
     HotRoller = 22, // Hot Roller
+    AnySeven = 23,  // One roll: 7 pays 4:1
+    AnyCraps = 24,  // One roll: 2/3/12 pays 7:1
+    Horn = 25,      // One roll: 2/3/11/12 split four ways
+    CAndE = 26,     // One roll: Craps & Eleven split two ways
+    World = 27,     // One roll: Horn + Any Seven split five ways (7 pushes)
+    Big6 = 28,      // 6 before 7, even money
+    Big8 = 29,      // 8 before 7, even money
+    Buy = 30,       // Target (4-10 point) before 7 at true odds, 5% vig on win
+    Lay = 31,       // 7 before target (4-10 point) at true odds, 5% vig on win
+    BuyVigUpFront = 32, // Buy with the vig paid at placement
+    LayVigUpFront = 33, // Lay with the vig paid at placement
+    Put = 34,       // Pass-line bet placed straight on a point (point phase only)
 }
 
 impl TryFrom<u8> for BetType {
@@ -187,6 +216,18 @@ impl TryFrom<u8> for BetType {
             20 => Ok(BetType::RideLine),
             21 => Ok(BetType::Replay),
             22 => Ok(BetType::HotRoller),
+            23 => Ok(BetType::AnySeven),
+            24 => Ok(BetType::AnyCraps),
+            25 => Ok(BetType::Horn),
+            26 => Ok(BetType::CAndE),
+            27 => Ok(BetType::World),
+            28 => Ok(BetType::Big6),
+            29 => Ok(BetType::Big8),
+            30 => Ok(BetType::Buy),
+            31 => Ok(BetType::Lay),
+            32 => Ok(BetType::BuyVigUpFront),
+            33 => Ok(BetType::LayVigUpFront),
+            34 => Ok(BetType::Put),
             _ => Err(()),
         }
     }
@@ -283,7 +324,19 @@ fn is_valid_bet_state(bet: &CrapsBet) -> bool {
         | BetType::DiffDoubles
         | BetType::RideLine
         | BetType::Replay
-        | BetType::HotRoller => bet.status == BetStatus::On && bet.target == 0,
+        | BetType::HotRoller
+        | BetType::AnySeven
+        | BetType::AnyCraps
+        | BetType::Horn
+        | BetType::CAndE
+        | BetType::World
+        | BetType::Big6
+        | BetType::Big8 => bet.status == BetStatus::On && bet.target == 0,
+        BetType::Buy
+        | BetType::Lay
+        | BetType::BuyVigUpFront
+        | BetType::LayVigUpFront
+        | BetType::Put => bet.status == BetStatus::On && is_point_total(bet.target),
     }
 }
 
@@ -321,6 +374,18 @@ fn bet_type_str(bet_type: BetType) -> &'static str {
         BetType::RideLine => "RIDE_LINE",
         BetType::Replay => "REPLAY",
         BetType::HotRoller => "HOT_ROLLER",
+        BetType::AnySeven => "ANY_SEVEN",
+        BetType::AnyCraps => "ANY_CRAPS",
+        BetType::Horn => "HORN",
+        BetType::CAndE => "C_AND_E",
+        BetType::World => "WORLD",
+        BetType::Big6 => "BIG_6",
+        BetType::Big8 => "BIG_8",
+        BetType::Buy => "BUY",
+        BetType::Lay => "LAY",
+        BetType::BuyVigUpFront => "BUY_VIG_UP_FRONT",
+        BetType::LayVigUpFront => "LAY_VIG_UP_FRONT",
+        BetType::Put => "PUT",
     }
 }
 
@@ -675,6 +740,60 @@ fn calculate_next_payout(target: u8, total: u8, amount: u64) -> u64 {
     amount.saturating_add(winnings)
 }
 
+/// Calculate a one-roll proposition bet return (TOTAL RETURN: stake + winnings).
+fn calculate_prop_payout(bet_type: BetType, total: u8, amount: u64) -> u64 {
+    let (numerator, denominator) = match (bet_type, total) {
+        (BetType::AnySeven, 7) => payouts::ANY_SEVEN,
+        (BetType::AnyCraps, 2 | 3 | 12) => payouts::ANY_CRAPS,
+        (BetType::Horn, 2 | 12) => payouts::HORN_2_OR_12,
+        (BetType::Horn, 3 | 11) => payouts::HORN_3_OR_11,
+        (BetType::CAndE, 2 | 3 | 12) => payouts::CE_CRAPS,
+        (BetType::CAndE, 11) => payouts::CE_ELEVEN,
+        (BetType::World, 2 | 12) => payouts::WORLD_2_OR_12,
+        (BetType::World, 3 | 11) => payouts::WORLD_3_OR_11,
+        // The Any Seven unit wins 4:1, which covers the four losing units.
+        (BetType::World, 7) => return amount,
+        _ => return 0,
+    };
+    let winnings = amount.saturating_mul(numerator).saturating_div(denominator);
+    amount.saturating_add(winnings)
+}
+
+fn is_lay_bet(bet_type: BetType) -> bool {
+    matches!(bet_type, BetType::Lay | BetType::LayVigUpFront)
+}
+
+fn is_vig_up_front(bet_type: BetType) -> bool {
+    matches!(bet_type, BetType::BuyVigUpFront | BetType::LayVigUpFront)
+}
+
+/// Commission on a Buy/Lay bet: 5% of the bet for Buy, 5% of the win for Lay (rounded up).
+fn calculate_buy_lay_commission(bet_type: BetType, target: u8, amount: u64) -> u64 {
+    let basis = if is_lay_bet(bet_type) {
+        calculate_odds_payout(target, amount, false)
+    } else {
+        amount
+    };
+    basis
+        .saturating_mul(payouts::BUY_LAY_COMMISSION_PERCENT)
+        .div_ceil(100)
+}
+
+/// Calculate Buy/Lay bet return (TOTAL RETURN: stake + winnings) at true odds.
+/// Vig-on-win bets pay the commission out of the win; up-front bets already paid it.
+fn calculate_buy_lay_payout(bet_type: BetType, target: u8, amount: u64, won: bool) -> u64 {
+    if !won {
+        return 0;
+    }
+    let winnings = calculate_odds_payout(target, amount, !is_lay_bet(bet_type));
+    let commission = if is_vig_up_front(bet_type) {
+        0
+    } else {
+        calculate_buy_lay_commission(bet_type, target, amount)
+    };
+    amount.saturating_add(winnings).saturating_sub(commission)
+}
+
 fn ats_bit_for_total(total: u8) -> u64 {
     match total {
         2 => 1u64 << 0,
@@ -866,7 +985,7 @@ fn process_roll(state: &mut CrapsState, d1: u8, d2: u8) -> Vec<BetResult> {
     let is_double = d1 == d2;
     let mut results = Vec::with_capacity(state.bets.len());
 
-    // 1. Single-roll bets (FIELD, NEXT) - always resolve
+    // 1. Single-roll bets (FIELD, NEXT, propositions) - always resolve
     for (idx, bet) in state.bets.iter().enumerate() {
         if bet.bet_type == BetType::Field {
             results.push(BetResult {
@@ -884,6 +1003,17 @@ fn process_roll(state: &mut CrapsState, d1: u8, d2: u8) -> Vec<BetResult> {
                 resolved: true,
             });
         }
+        if matches!(
+            bet.bet_type,
+            BetType::AnySeven | BetType::AnyCraps | BetType::Horn | BetType::CAndE | BetType::World
+        ) {
+            results.push(BetResult {
+                bet_idx: idx,
+                return_amount: calculate_prop_payout(bet.bet_type, total, bet.amount),
+                wagered: bet.amount,
+                resolved: true,
+            });
+        }
     }
 
     // 2. HARDWAY bets (check for 7 or easy way)
@@ -910,7 +1040,7 @@ fn process_roll(state: &mut CrapsState, d1: u8, d2: u8) -> Vec<BetResult> {
         }
     }
 
-    // 3. YES/NO/BUY bets (working bets only)
+    // 3. YES/NO/BIG 6/BIG 8/BUY/LAY bets (working bets only)
     for (idx, bet) in state.bets.iter().enumerate() {
         if bet.status != BetStatus::On {
             continue;
@@ -951,6 +1081,40 @@ fn process_roll(state: &mut CrapsState, d1: u8, d2: u8) -> Vec<BetResult> {
                     });
                 }
             }
+            BetType::Big6 | BetType::Big8 => {
+                let target = if bet.bet_type == BetType::Big6 { 6 } else { 8 };
+                if total == target || total == 7 {
+                    let return_amount = if total == target {
+                        bet.amount
+                            .saturating_mul(payouts::BIG_6_OR_8.saturating_add(1))
+                    } else {
+                        0
+                    };
+                    results.push(BetResult {
+                        bet_idx: idx,
+                        return_amount,
+                        wagered: bet.amount,
+                        resolved: true,
+                    });
+                }
+            }
+            BetType::Buy | BetType::Lay | BetType::BuyVigUpFront | BetType::LayVigUpFront
+                if total == bet.target || total == 7 =>
+            {
+                let won = (total == 7) == is_lay_bet(bet.bet_type);
+                results.push(BetResult {
+                    bet_idx: idx,
+                    return_amount: calculate_buy_lay_payout(
+                        bet.bet_type,
+                        bet.target,
+                        bet.amount,
+                        won,
+                    ),
+                    // Up-front vig is kept in `odds_amount`.
+                    wagered: bet.amount.saturating_add(bet.odds_amount),
+                    resolved: true,
+                });
+            }
             _ => {}
         }
     }
@@ -1009,7 +1173,7 @@ fn process_roll(state: &mut CrapsState, d1: u8, d2: u8) -> Vec<BetResult> {
                     }
                 }
             }
-            (BetType::Come, BetStatus::On) => {
+            (BetType::Come | BetType::Put, BetStatus::On) => {
                 if total == bet.target {
                     // Win!
                     let odds_payout = calculate_odds_payout(bet.target, bet.odds_amount, true);
@@ -1563,11 +1727,35 @@ impl CasinoGame for Craps {
                     | BetType::DiffDoubles
                     | BetType::RideLine
                     | BetType::Replay
-                    | BetType::HotRoller => {
+                    | BetType::HotRoller
+                    | BetType::AnySeven
+                    | BetType::AnyCraps
+                    | BetType::Horn
+                    | BetType::CAndE
+                    | BetType::World
+                    | BetType::Big6
+                    | BetType::Big8 => {
                         if target != 0 {
                             return Err(GameError::InvalidPayload);
                         }
                     }
+                    BetType::Buy
+                    | BetType::Lay
+                    | BetType::BuyVigUpFront
+                    | BetType::LayVigUpFront => {
+                        if !is_point_total(target) {
+                            return Err(GameError::InvalidPayload);
+                        }
+                    }
+                    BetType::Put => {
+                        if !is_point_total(target) {
+                            return Err(GameError::InvalidPayload);
+                        }
+                        // Put bets go straight onto a number once a point is established.
+                        if state.phase != Phase::Point {
+                            return Err(GameError::InvalidMove);
+                        }
+                    }
                     BetType::Come | BetType::DontCome => {
                         if target != 0 {
                             return Err(GameError::InvalidPayload);
@@ -1634,18 +1822,28 @@ impl CasinoGame for Craps {
                     _ => BetStatus::On,
                 };
 
+                // Up-front vig is charged with the bet and kept in `odds_amount`.
+                let commission = if is_vig_up_front(bet_type) {
+                    calculate_buy_lay_commission(bet_type, target, amount)
+                } else {
+                    0
+                };
+                let deduction = amount
+                    .checked_add(commission)
+                    .ok_or(GameError::InvalidPayload)?;
+
                 state.bets.push(CrapsBet {
                     bet_type,
                     target,
                     status,
                     amount,
-                    odds_amount: 0,
+                    odds_amount: commission,
                 });
 
                 session.state_blob = serialize_state(&state);
 
                 let deduction_i64 =
-                    i64::try_from(amount).map_err(|_| GameError::InvalidPayload)?;
+                    i64::try_from(deduction).map_err(|_| GameError::InvalidPayload)?;
                 Ok(GameResult::ContinueWithUpdate {
                     payout: -deduction_i64,
                     logs: vec![],
@@ -1666,12 +1864,16 @@ impl CasinoGame for Craps {
                     return Err(GameError::InvalidPayload);
                 }
 
-                // Find last contract bet (PASS, DONT_PASS, COME, DONT_COME with status ON)
+                // Find last contract bet (PASS, DONT_PASS, COME, DONT_COME, PUT with status ON)
                 let mut found = false;
                 for bet in state.bets.iter_mut().rev() {
                     if matches!(
                         bet.bet_type,
-                        BetType::Pass | BetType::DontPass | BetType::Come | BetType::DontCome
+                        BetType::Pass
+                            | BetType::DontPass
+                            | BetType::Come
+                            | BetType::DontCome
+                            | BetType::Put
                     ) && bet.status == BetStatus::On
                     {
                         if ![4u8, 5, 6, 8, 9, 10].contains(&bet.target) {
@@ -1874,9 +2076,20 @@ impl CasinoGame for Craps {
                     {
                         return Err(GameError::InvalidPayload);
                     }
+                    // Put bets need an established point, which a fresh round never has.
+                    if bet_type == BetType::Put {
+                        return Err(GameError::InvalidMove);
+                    }
 
-                    // Check for overflow in total wager
-                    let bet_cost = amount;
+                    // Check for overflow in total wager (up-front vig is part of the cost)
+                    let commission = if is_vig_up_front(bet_type) {
+                        calculate_buy_lay_commission(bet_type, target, amount)
+                    } else {
+                        0
+                    };
+                    let bet_cost = amount
+                        .checked_add(commission)
+                        .ok_or(GameError::InvalidPayload)?;
 
                     total_wager = total_wager
                         .checked_add(bet_cost)
@@ -1888,13 +2101,17 @@ impl CasinoGame for Craps {
                         _ => BetStatus::On,
                     };
 
-                    bets_to_place.push(CrapsBet {
+                    let bet = CrapsBet {
                         bet_type,
                         target,
                         status,
                         amount,
-                        odds_amount: 0,
-                    });
+                        odds_amount: commission,
+                    };
+                    if !is_valid_bet_state(&bet) {
+                        return Err(GameError::InvalidPayload);
+                    }
+                    bets_to_place.push(bet);
 
                     offset += 10;
                 }
@@ -2015,6 +2232,38 @@ impl CasinoGame for Craps {
             _ => Err(GameError::InvalidPayload),
         }
     }
+
+    fn abandon(session: &mut GameSession, _rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        let point_set = state.phase == Phase::Point;
+
+        // Unrolled bets are refunded with their odds, as a live table lets them be taken down.
+        // Pass, Come and Put bets that are on a point cannot be removed, so they are lost.
+        let mut refund: u64 = 0;
+        let mut forfeited: u64 = 0;
+        for bet in state.bets.drain(..) {
+            let travelled = match bet.bet_type {
+                BetType::Pass => point_set,
+                BetType::Come => bet.status == BetStatus::On,
+                BetType::Put => true,
+                _ => false,
+            };
+            if travelled {
+                forfeited = forfeited.saturating_add(bet.amount);
+            } else {
+                refund = refund.saturating_add(bet.amount);
+            }
+            refund = refund.saturating_add(bet.odds_amount);
+        }
+        session.state_blob = serialize_state(&state);
+
+        // A partial refund is reported as a push so it never picks up a win modifier.
+        Ok(if refund == 0 && forfeited > 0 {
+            GameResult::LossPreDeducted(forfeited, vec![])
+        } else {
+            GameResult::Push(refund, vec![])
+        })
+    }
 }
 
 #[cfg(test)]
@@ -2111,6 +2360,52 @@ mod tests {
         assert_eq!(deserialized.bets.len(), 2);
     }
 
+    #[test]
+    fn test_abandon_refunds_unrolled_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut state = CrapsState {
+            phase: Phase::Point,
+            main_point: 6,
+            d1: 3,
+            d2: 3,
+            made_points_mask: 0,
+            epoch_point_established: true,
+            field_paytable: FieldPaytable::default(),
+            bets: vec![
+                CrapsBet {
+                    bet_type: BetType::Pass,
+                    target: 0,
+                    status: BetStatus::On,
+                    amount: 100,
+                    odds_amount: 50,
+                },
+                CrapsBet {
+                    bet_type: BetType::Come,
+                    target: 0,
+                    status: BetStatus::Pending,
+                    amount: 25,
+                    odds_amount: 0,
+                },
+            ],
+        };
+        session.state_blob = serialize_state(&state);
+
+        // The pass line is on the point and stays lost; its odds and the pending come bet return.
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = Craps::abandon(&mut session, &mut rng).expect("Failed to abandon");
+        assert!(matches!(result, GameResult::Push(75, _)));
+        let remaining = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert!(remaining.bets.is_empty());
+
+        // With only the travelled pass line left, the session is a loss.
+        state.bets.truncate(1);
+        state.bets[0].odds_amount = 0;
+        session.state_blob = serialize_state(&state);
+        let result = Craps::abandon(&mut session, &mut rng).expect("Failed to abandon");
+        assert!(matches!(result, GameResult::LossPreDeducted(100, _)));
+    }
+
     #[test]
     fn test_state_blob_fuzz_does_not_panic() {
         let mut rng = StdRng::seed_from_u64(0x5eed_5eed);
@@ -2824,4 +3119,214 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].return_amount, 60);
     }
+
+    fn roll_state(phase: Phase, main_point: u8, bets: Vec<CrapsBet>) -> CrapsState {
+        CrapsState {
+            phase,
+            main_point,
+            d1: 0,
+            d2: 0,
+            made_points_mask: 0,
+            epoch_point_established: phase == Phase::Point,
+            field_paytable: FieldPaytable::default(),
+            bets,
+        }
+    }
+
+    fn working_bet(bet_type: BetType, target: u8, amount: u64) -> CrapsBet {
+        CrapsBet {
+            bet_type,
+            target,
+            status: BetStatus::On,
+            amount,
+            odds_amount: 0,
+        }
+    }
+
+    #[test]
+    fn test_prop_payouts() {
+        // Any Seven 4:1, Any Craps 7:1
+        assert_eq!(calculate_prop_payout(BetType::AnySeven, 7, 100), 500);
+        assert_eq!(calculate_prop_payout(BetType::AnySeven, 6, 100), 0);
+        assert_eq!(calculate_prop_payout(BetType::AnyCraps, 3, 100), 800);
+        assert_eq!(calculate_prop_payout(BetType::AnyCraps, 11, 100), 0);
+        // Horn: 2/12 net 27:4, 3/11 net 3:1
+        assert_eq!(calculate_prop_payout(BetType::Horn, 12, 100), 775);
+        assert_eq!(calculate_prop_payout(BetType::Horn, 11, 100), 400);
+        assert_eq!(calculate_prop_payout(BetType::Horn, 7, 100), 0);
+        // C&E: craps net 3:1, eleven net 7:1
+        assert_eq!(calculate_prop_payout(BetType::CAndE, 2, 100), 400);
+        assert_eq!(calculate_prop_payout(BetType::CAndE, 11, 100), 800);
+        assert_eq!(calculate_prop_payout(BetType::CAndE, 7, 100), 0);
+        // World: 2/12 net 26:5, 3/11 net 11:5, 7 pushes
+        assert_eq!(calculate_prop_payout(BetType::World, 2, 100), 620);
+        assert_eq!(calculate_prop_payout(BetType::World, 3, 100), 320);
+        assert_eq!(calculate_prop_payout(BetType::World, 7, 100), 100);
+        assert_eq!(calculate_prop_payout(BetType::World, 8, 100), 0);
+    }
+
+    #[test]
+    fn test_prop_bets_resolve_in_one_roll() {
+        let mut state = roll_state(
+            Phase::ComeOut,
+            0,
+            vec![
+                working_bet(BetType::AnySeven, 0, 10),
+                working_bet(BetType::Horn, 0, 20),
+                working_bet(BetType::World, 0, 25),
+            ],
+        );
+        let results = process_roll(&mut state, 4, 4);
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.resolved && r.return_amount == 0));
+    }
+
+    #[test]
+    fn test_big_six_and_eight() {
+        let mut state = roll_state(
+            Phase::ComeOut,
+            0,
+            vec![
+                working_bet(BetType::Big6, 0, 10),
+                working_bet(BetType::Big8, 0, 10),
+            ],
+        );
+        let results = process_roll(&mut state, 2, 4);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].bet_idx, 0);
+        assert_eq!(results[0].return_amount, 20);
+
+        let results = process_roll(&mut state, 3, 4);
+        let big8 = results
+            .iter()
+            .find(|r| r.bet_idx == 1)
+            .expect("expected Big 8 to resolve on a 7");
+        assert_eq!(big8.return_amount, 0);
+    }
+
+    #[test]
+    fn test_buy_lay_payouts() {
+        // Buy 4: 2:1 with 5% of the bet as vig -> 40 + 80 - 2
+        assert_eq!(calculate_buy_lay_commission(BetType::Buy, 4, 40), 2);
+        assert_eq!(calculate_buy_lay_payout(BetType::Buy, 4, 40, true), 118);
+        assert_eq!(calculate_buy_lay_payout(BetType::Buy, 4, 40, false), 0);
+        // Buy 6: 6:5, vig rounds up (5% of 30 = 1.5 -> 2)
+        assert_eq!(calculate_buy_lay_payout(BetType::Buy, 6, 30, true), 64);
+        // Lay 10: 1:2 with 5% of the win as vig -> 40 + 20 - 1
+        assert_eq!(calculate_buy_lay_commission(BetType::Lay, 10, 40), 1);
+        assert_eq!(calculate_buy_lay_payout(BetType::Lay, 10, 40, true), 59);
+        // Vig up front: the win is paid in full
+        assert_eq!(
+            calculate_buy_lay_payout(BetType::BuyVigUpFront, 4, 40, true),
+            120
+        );
+        assert_eq!(
+            calculate_buy_lay_payout(BetType::LayVigUpFront, 10, 40, true),
+            60
+        );
+    }
+
+    #[test]
+    fn test_buy_and_lay_resolution() {
+        let mut state = roll_state(
+            Phase::ComeOut,
+            0,
+            vec![
+                working_bet(BetType::Buy, 4, 40),
+                working_bet(BetType::Lay, 4, 40),
+            ],
+        );
+        // Point numbers other than the target leave both working.
+        assert!(process_roll(&mut state, 2, 3).is_empty());
+
+        let results = process_roll(&mut state, 1, 3);
+        let buy = results
+            .iter()
+            .find(|r| r.bet_idx == 0)
+            .expect("buy resolves");
+        let lay = results
+            .iter()
+            .find(|r| r.bet_idx == 1)
+            .expect("lay resolves");
+        assert_eq!(buy.return_amount, 118);
+        assert_eq!(lay.return_amount, 0);
+    }
+
+    #[test]
+    fn test_vig_up_front_charged_at_placement() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Craps::init(&mut session, &mut rng);
+
+        let mut payload = vec![0, BetType::BuyVigUpFront as u8, 4];
+        payload.extend_from_slice(&100u64.to_be_bytes());
+        let result = Craps::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(
+            result,
+            Ok(GameResult::ContinueWithUpdate { payout: -105, .. })
+        ));
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.bets[0].odds_amount, 5);
+
+        // Taking the bet down returns the vig with it.
+        let result = Craps::process_move(&mut session, &[3], &mut rng);
+        assert!(matches!(
+            result,
+            Ok(GameResult::ContinueWithUpdate { payout: 105, .. })
+        ));
+
+        // Buy/Lay need a point number.
+        let mut payload = vec![0, BetType::Lay as u8, 7];
+        payload.extend_from_slice(&100u64.to_be_bytes());
+        let result = Craps::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+    }
+
+    #[test]
+    fn test_put_bet_requires_point() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Craps::init(&mut session, &mut rng);
+
+        let mut payload = vec![0, BetType::Put as u8, 6];
+        payload.extend_from_slice(&100u64.to_be_bytes());
+        let result = Craps::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+
+        session.state_blob = serialize_state(&roll_state(Phase::Point, 5, Vec::new()));
+        let result = Craps::process_move(&mut session, &payload, &mut rng);
+        assert!(result.is_ok());
+
+        // Odds can be taken behind a Put bet.
+        let mut odds = vec![1];
+        odds.extend_from_slice(&50u64.to_be_bytes());
+        assert!(Craps::process_move(&mut session, &odds, &mut rng).is_ok());
+
+        let mut state = parse_state(&session.state_blob).expect("Failed to parse state");
+        let results = process_roll(&mut state, 3, 3);
+        assert_eq!(results.len(), 1);
+        // Flat pays even money, odds pay 6:5.
+        assert_eq!(results[0].return_amount, 200 + 50 + 60);
+    }
+
+    #[test]
+    fn test_atomic_batch_rejects_put_and_charges_vig() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Craps::init(&mut session, &mut rng);
+
+        let mut payload = vec![4, 1, BetType::Put as u8, 6];
+        payload.extend_from_slice(&100u64.to_be_bytes());
+        let result = Craps::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+
+        let mut payload = vec![4, 1, BetType::LayVigUpFront as u8, 4];
+        payload.extend_from_slice(&100u64.to_be_bytes());
+        Craps::process_move(&mut session, &payload, &mut rng).expect("batch accepted");
+        // 5% of the 50 win on a lay of 100 against the 4.
+        assert_eq!(session.bet, 103);
+    }
 }
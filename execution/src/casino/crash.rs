@@ -0,0 +1,825 @@
+//! Crash game implementation with auto cash-out targets.
+//!
+//! State blob format:
+//! [version:u8=1] [bet_count:u8] [bets:CrashBet×count] [cash_out:u32 BE] [crash_point:u32 BE]?
+//!
+//! Each CrashBet (10 bytes):
+//! [scale:u8] [target:u8] [amount:u64 BE]
+//!
+//! Payload format:
+//! Action 0: Place bet - [0, scale, target, amount_bytes...]
+//! Action 1: Draw the crash point and resolve - [1]
+//! Action 2: Clear bets (with refund) - [2]
+//! Action 3: Atomic batch - [3, bet_count, bets...]
+//!           Each bet is 10 bytes: [scale:u8, target:u8, amount:u64 BE]
+//!           Ensures all-or-nothing semantics - no partial bet states
+//!
+//! Every bet carries an auto cash-out target (the bet type picks the target's scale):
+//! 0 = hundredths: 1.00x + target/100 (target 1-255, 1.01x-3.55x)
+//! 1 = tenths: 1.0x + target/10 (target 1-255, 1.1x-26.5x)
+//! 2 = whole: target x (target 2-100)
+//!
+//! Multipliers are stored in hundredths (100 = 1.00x) and capped at 100x. The crash point
+//! is `0.97 * 2^32 / (2^32 - r)` for a uniform `r`, so every target returns 97%; draws
+//! below 1.00x bust the round before anyone can cash out (crash point 0).
+//!
+//! On the global table the multiplier climbs in real time during the Rolling phase and a
+//! manual cash-out locks in the current multiplier for all of the player's bets. The crash
+//! point is drawn from the round's roll seed once Rolling ends, and each bet is paid at the
+//! lower of its auto target and the manual cash-out if that multiplier did not crash.
+
+use super::logging::{clamp_i64, push_resolved_entry};
+use super::serialization::{StateReader, StateWriter};
+use super::{limits, CasinoGame, GameError, GameResult, GameRng};
+use nullspace_types::casino::{GameSession, GlobalTableBet};
+use std::fmt::Write;
+
+const STATE_VERSION: u8 = 1;
+const BET_BYTES: usize = 10;
+
+/// 1.00x in hundredths.
+const BASE_MULTIPLIER: u32 = 100;
+/// Highest multiplier (in hundredths) a bet can target or the curve can reach.
+pub const MAX_MULTIPLIER: u32 = 10_000;
+/// Return to player, in hundredths of the stake.
+const RTP_HUNDREDTHS: u64 = 97;
+/// The live multiplier doubles every four seconds.
+const DOUBLING_MS: u64 = 4_000;
+/// Length of the Rolling phase: the time the live curve takes to reach `MAX_MULTIPLIER`.
+pub const ROLL_MS: u64 = 26_250;
+
+/// Scale of a bet's auto cash-out target.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scale {
+    Hundredths = 0,
+    Tenths = 1,
+    Whole = 2,
+}
+
+impl TryFrom<u8> for Scale {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Scale::Hundredths),
+            1 => Ok(Scale::Tenths),
+            2 => Ok(Scale::Whole),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+/// Auto cash-out multiplier (in hundredths) for a scale/target pair, if valid.
+fn target_multiplier(scale: Scale, target: u8) -> Option<u32> {
+    let target = u32::from(target);
+    let multiplier = match scale {
+        Scale::Hundredths if target >= 1 => BASE_MULTIPLIER + target,
+        Scale::Tenths if target >= 1 => BASE_MULTIPLIER + target * 10,
+        Scale::Whole if target >= 2 => target * 100,
+        _ => return None,
+    };
+    (multiplier <= MAX_MULTIPLIER).then_some(multiplier)
+}
+
+/// A single bet in Crash (10 bytes).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrashBet {
+    pub scale: Scale,
+    pub target: u8,
+    pub amount: u64,
+}
+
+impl CrashBet {
+    /// Auto cash-out multiplier in hundredths.
+    fn multiplier(&self) -> u32 {
+        target_multiplier(self.scale, self.target).unwrap_or(MAX_MULTIPLIER)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 10] {
+        let mut bytes = [0u8; 10];
+        bytes[0] = self.scale as u8;
+        bytes[1] = self.target;
+        bytes[2..10].copy_from_slice(&self.amount.to_be_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 10 {
+            return None;
+        }
+        let scale = Scale::try_from(bytes[0]).ok()?;
+        let target = bytes[1];
+        target_multiplier(scale, target)?;
+        let amount = u64::from_be_bytes(bytes[2..10].try_into().ok()?);
+        if amount == 0 {
+            return None;
+        }
+        Some(Self {
+            scale,
+            target,
+            amount,
+        })
+    }
+}
+
+/// Crash game state.
+struct CrashState {
+    bets: Vec<CrashBet>,
+    /// Manual cash-out multiplier in hundredths (0 = none).
+    cash_out: u32,
+    crash_point: Option<u32>,
+}
+
+impl CrashState {
+    fn new() -> Self {
+        Self {
+            bets: Vec::new(),
+            cash_out: 0,
+            crash_point: None,
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return Some(Self::new());
+        }
+
+        let mut reader = StateReader::new(bytes);
+        if reader.read_u8()? != STATE_VERSION {
+            return None;
+        }
+        let bet_count = reader.read_u8()? as usize;
+
+        // Validate bet count against maximum to prevent DoS via large allocations
+        if bet_count > limits::CRASH_MAX_BETS {
+            return None;
+        }
+
+        let mut bets = Vec::with_capacity(bet_count);
+        for _ in 0..bet_count {
+            bets.push(CrashBet::from_bytes(reader.read_bytes(BET_BYTES)?)?);
+        }
+
+        let cash_out = reader.read_u32_be()?;
+        if cash_out != 0 && !(BASE_MULTIPLIER..=MAX_MULTIPLIER).contains(&cash_out) {
+            return None;
+        }
+
+        let crash_point = match reader.remaining() {
+            0 => None,
+            4 => {
+                let crash_point = reader.read_u32_be()?;
+                if crash_point > MAX_MULTIPLIER {
+                    return None;
+                }
+                Some(crash_point)
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            bets,
+            cash_out,
+            crash_point,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        // Capacity: 2 (version + bet count) + bets (10 bytes each) + 4 (cash-out)
+        // + 4 (optional crash point)
+        let capacity =
+            6 + (self.bets.len() * BET_BYTES) + 4 * usize::from(self.crash_point.is_some());
+        let mut bytes = StateWriter::with_capacity(capacity);
+        bytes.push_u8(STATE_VERSION);
+        bytes.push_u8(self.bets.len() as u8);
+        for bet in &self.bets {
+            bytes.push_bytes(&bet.to_bytes());
+        }
+        bytes.push_u32_be(self.cash_out);
+        if let Some(crash_point) = self.crash_point {
+            bytes.push_u32_be(crash_point);
+        }
+        bytes.into_inner()
+    }
+}
+
+fn serialize_state(state: &CrashState) -> Vec<u8> {
+    state.to_bytes()
+}
+
+fn parse_state(bytes: &[u8]) -> Option<CrashState> {
+    CrashState::from_bytes(bytes)
+}
+
+/// Crash point (in hundredths) for a uniform 32-bit draw.
+fn crash_point_from_draw(draw: u32) -> u32 {
+    const SPACE: u64 = 1 << 32;
+    let raw = RTP_HUNDREDTHS * SPACE / (SPACE - u64::from(draw));
+    if raw < u64::from(BASE_MULTIPLIER) {
+        0
+    } else {
+        raw.min(u64::from(MAX_MULTIPLIER)) as u32
+    }
+}
+
+/// Draw the crash point (in hundredths, 0 = bust at 1.00x).
+fn draw_crash_point(rng: &mut GameRng) -> u32 {
+    crash_point_from_draw(rng.next_u32())
+}
+
+/// Live multiplier (in hundredths) after `elapsed_ms` of the Rolling phase.
+///
+/// Doubles every `DOUBLING_MS`, interpolating linearly within each doubling.
+pub fn multiplier_at(elapsed_ms: u64) -> u32 {
+    let doublings = elapsed_ms / DOUBLING_MS;
+    if doublings >= 7 {
+        return MAX_MULTIPLIER;
+    }
+    let base = u64::from(BASE_MULTIPLIER) << doublings;
+    let within = elapsed_ms % DOUBLING_MS;
+    let multiplier = base * (DOUBLING_MS + within) / DOUBLING_MS;
+    multiplier.min(u64::from(MAX_MULTIPLIER)) as u32
+}
+
+fn format_multiplier(multiplier: u32) -> String {
+    format!("{}.{:02}x", multiplier / 100, multiplier % 100)
+}
+
+/// Bets currently in a crash session, for the global table totals.
+pub(crate) fn table_bets(blob: &[u8]) -> Vec<GlobalTableBet> {
+    parse_state(blob)
+        .map(|state| {
+            state
+                .bets
+                .iter()
+                .map(|bet| GlobalTableBet {
+                    bet_type: bet.scale as u8,
+                    target: bet.target,
+                    amount: bet.amount,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Draw the shared crash point for a global table round.
+///
+/// Consumes the RNG exactly as the resolve move does. Outcome bytes: `[crash_point:u32 BE]`.
+pub(crate) fn table_outcome(rng: &mut GameRng) -> Vec<u8> {
+    draw_crash_point(rng).to_be_bytes().to_vec()
+}
+
+/// Record a manual cash-out at `multiplier` (hundredths) on a crash session blob.
+///
+/// Fails if there are no bets, the player already cashed out, or the round is resolved.
+pub(crate) fn cash_out(blob: &[u8], multiplier: u32) -> Result<Vec<u8>, GameError> {
+    let mut state = parse_state(blob).ok_or(GameError::InvalidState)?;
+    if state.crash_point.is_some() {
+        return Err(GameError::GameAlreadyComplete);
+    }
+    if state.bets.is_empty() || state.cash_out != 0 {
+        return Err(GameError::InvalidMove);
+    }
+    state.cash_out = multiplier.clamp(BASE_MULTIPLIER, MAX_MULTIPLIER);
+    Ok(serialize_state(&state))
+}
+
+/// Multiplier (in hundredths) a bet is paid at if it survives: its auto target, capped by
+/// any manual cash-out.
+fn effective_multiplier(bet: &CrashBet, cash_out: u32) -> u32 {
+    if cash_out == 0 {
+        bet.multiplier()
+    } else {
+        bet.multiplier().min(cash_out)
+    }
+}
+
+/// Calculate the total return (stake included) for a single bet.
+fn calculate_bet_payout(bet: &CrashBet, cash_out: u32, crash_point: u32) -> u64 {
+    let multiplier = effective_multiplier(bet, cash_out);
+    if multiplier > crash_point {
+        return 0;
+    }
+    let payout = u128::from(bet.amount) * u128::from(multiplier) / u128::from(BASE_MULTIPLIER);
+    u64::try_from(payout).unwrap_or(u64::MAX)
+}
+
+/// Generate JSON logs for Crash game completion
+fn generate_crash_logs(
+    state: &CrashState,
+    crash_point: u32,
+    total_wagered: u64,
+    total_return: u64,
+) -> Vec<String> {
+    let bet_capacity = state.bets.len().saturating_mul(96);
+    let resolved_capacity = state.bets.len().saturating_mul(40).saturating_add(32);
+    let mut bet_results = String::with_capacity(bet_capacity);
+    let mut resolved_entries = String::with_capacity(resolved_capacity);
+    let mut resolved_sum: i128 = 0;
+    for (idx, bet) in state.bets.iter().enumerate() {
+        if idx > 0 {
+            bet_results.push(',');
+        }
+        let label = format_multiplier(bet.multiplier());
+        let multiplier = effective_multiplier(bet, state.cash_out);
+        let payout = calculate_bet_payout(bet, state.cash_out, crash_point);
+        let pnl = clamp_i64(i128::from(payout) - i128::from(bet.amount));
+        resolved_sum = resolved_sum.saturating_add(i128::from(pnl));
+        push_resolved_entry(&mut resolved_entries, &label, pnl);
+        let _ = write!(
+            bet_results,
+            r#"{{"target":"{}","multiplier":{},"amount":{},"won":{},"payout":{}}}"#,
+            label,
+            multiplier,
+            bet.amount,
+            payout > 0,
+            payout
+        );
+    }
+
+    let net_pnl = clamp_i64(i128::from(total_return) - i128::from(total_wagered));
+    let diff = i128::from(net_pnl).saturating_sub(resolved_sum);
+    if diff != 0 {
+        push_resolved_entry(&mut resolved_entries, "ADJUSTMENT", clamp_i64(diff));
+    }
+
+    vec![format!(
+        r#"{{"summary":"Crashed at {}","netPnl":{},"resolvedBets":[{}],"crashPoint":{},"cashOut":{},"bets":[{}],"totalWagered":{},"totalReturn":{}}}"#,
+        format_multiplier(crash_point),
+        net_pnl,
+        resolved_entries,
+        crash_point,
+        state.cash_out,
+        bet_results,
+        total_wagered,
+        total_return
+    )]
+}
+
+/// Draw the crash point and settle every bet, returning the winnings credited back.
+fn resolve_round(
+    session: &mut GameSession,
+    state: &mut CrashState,
+    rng: &mut GameRng,
+) -> (u32, u64) {
+    let crash_point = draw_crash_point(rng);
+    state.crash_point = Some(crash_point);
+
+    let winnings = state.bets.iter().fold(0u64, |total, bet| {
+        total.saturating_add(calculate_bet_payout(bet, state.cash_out, crash_point))
+    });
+
+    session.state_blob = serialize_state(state);
+    session.move_count += 1;
+    session.is_complete = true;
+    (crash_point, winnings)
+}
+
+pub struct Crash;
+
+impl CasinoGame for Crash {
+    fn init(session: &mut GameSession, _rng: &mut GameRng) -> GameResult {
+        let state = CrashState::new();
+        session.state_blob = serialize_state(&state);
+        GameResult::Continue(vec![])
+    }
+
+    fn process_move(
+        session: &mut GameSession,
+        payload: &[u8],
+        rng: &mut GameRng,
+    ) -> Result<GameResult, GameError> {
+        if session.is_complete {
+            return Err(GameError::GameAlreadyComplete);
+        }
+
+        if payload.is_empty() {
+            return Err(GameError::InvalidPayload);
+        }
+
+        let action = payload[0];
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidMove)?;
+
+        match action {
+            // Action 0: Place bet
+            0 => {
+                let (scale, target, amount) = super::payload::parse_place_bet_payload(payload)?;
+                let scale = Scale::try_from(scale)?;
+                target_multiplier(scale, target).ok_or(GameError::InvalidPayload)?;
+                super::payload::ensure_nonzero_amount(amount)?;
+                if state.cash_out != 0 {
+                    return Err(GameError::InvalidMove);
+                }
+                if state.bets.len() >= limits::CRASH_MAX_BETS {
+                    return Err(GameError::InvalidMove);
+                }
+
+                state.bets.push(CrashBet {
+                    scale,
+                    target,
+                    amount,
+                });
+                session.state_blob = serialize_state(&state);
+                session.move_count += 1;
+                Ok(GameResult::ContinueWithUpdate {
+                    payout: -(amount as i64),
+                    logs: vec![],
+                })
+            }
+
+            // Action 1: Draw the crash point and resolve all bets
+            1 => {
+                if state.bets.is_empty() {
+                    return Err(GameError::InvalidPayload); // Must have at least one bet
+                }
+
+                let total_bet = state
+                    .bets
+                    .iter()
+                    .fold(0u64, |total, bet| total.saturating_add(bet.amount));
+                let (crash_point, winnings) = resolve_round(session, &mut state, rng);
+
+                // All wagers were deducted via ContinueWithUpdate at bet time, so the completion
+                // result returns the total amount to credit back (if any).
+                let logs = generate_crash_logs(&state, crash_point, total_bet, winnings);
+                if winnings > 0 {
+                    Ok(GameResult::Win(winnings, logs))
+                } else {
+                    Ok(GameResult::LossPreDeducted(total_bet, logs))
+                }
+            }
+
+            // Action 2: Clear all bets (with refund)
+            2 => {
+                if state.cash_out != 0 {
+                    return Err(GameError::InvalidMove);
+                }
+                let refund = state
+                    .bets
+                    .iter()
+                    .fold(0u64, |total, bet| total.saturating_add(bet.amount));
+                state.bets.clear();
+                session.state_blob = serialize_state(&state);
+
+                if refund > 0 {
+                    Ok(GameResult::ContinueWithUpdate {
+                        payout: refund as i64,
+                        logs: vec![],
+                    })
+                } else {
+                    Ok(GameResult::Continue(vec![]))
+                }
+            }
+
+            // Action 3: Atomic batch - place all bets + resolve in one transaction
+            3 => {
+                // Must have existing bets cleared first (fresh round)
+                if !state.bets.is_empty() || state.crash_point.is_some() {
+                    return Err(GameError::InvalidMove);
+                }
+
+                if payload.len() < 2 {
+                    return Err(GameError::InvalidPayload);
+                }
+
+                let bet_count = payload[1] as usize;
+                if bet_count == 0 || bet_count > limits::CRASH_MAX_BETS {
+                    return Err(GameError::InvalidPayload);
+                }
+
+                let expected_len = 2 + bet_count * BET_BYTES;
+                if payload.len() < expected_len {
+                    return Err(GameError::InvalidPayload);
+                }
+
+                // Parse and validate all bets first (before any state changes)
+                let mut bets_to_place = Vec::with_capacity(bet_count);
+                let mut total_wager: u64 = 0;
+                for chunk in payload[2..expected_len].chunks_exact(BET_BYTES) {
+                    let bet = CrashBet::from_bytes(chunk).ok_or(GameError::InvalidPayload)?;
+                    total_wager = total_wager
+                        .checked_add(bet.amount)
+                        .ok_or(GameError::InvalidPayload)?;
+                    bets_to_place.push(bet);
+                }
+
+                session.bet = total_wager;
+                state.bets = bets_to_place;
+
+                let (crash_point, winnings) = resolve_round(session, &mut state, rng);
+                let logs = generate_crash_logs(&state, crash_point, total_wager, winnings);
+                if winnings > 0 {
+                    Ok(GameResult::Win(winnings, logs))
+                } else {
+                    // Total loss - wager is deducted on completion for atomic batch
+                    Ok(GameResult::Loss(logs))
+                }
+            }
+
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+
+    fn abandon(session: &mut GameSession, _rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        if state.crash_point.is_some() {
+            return Err(GameError::GameAlreadyComplete);
+        }
+
+        // The crash point has not been drawn, so every pending bet is refunded.
+        let refund = state
+            .bets
+            .iter()
+            .fold(0u64, |total, bet| total.saturating_add(bet.amount));
+        state.bets.clear();
+        state.cash_out = 0;
+        session.state_blob = serialize_state(&state);
+        Ok(GameResult::Push(refund, vec![]))
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_must_use)]
+mod tests {
+    use super::*;
+    use crate::mocks::{create_account_keypair, create_network_keypair, create_seed};
+    use nullspace_types::casino::GameType;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn create_test_seed() -> nullspace_types::Seed {
+        let (network_secret, _) = create_network_keypair();
+        create_seed(&network_secret, 1)
+    }
+
+    fn create_test_session(bet: u64) -> GameSession {
+        let (_, pk) = create_account_keypair(1);
+        GameSession {
+            id: 1,
+            player: pk,
+            game_type: GameType::Crash,
+            bet,
+            state_blob: vec![],
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: nullspace_types::casino::SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        }
+    }
+
+    /// Helper to create a place bet payload.
+    fn place_bet_payload(scale: u8, target: u8, amount: u64) -> Vec<u8> {
+        let mut payload = vec![0, scale, target];
+        payload.extend_from_slice(&amount.to_be_bytes());
+        payload
+    }
+
+    #[test]
+    fn test_target_multipliers() {
+        assert_eq!(target_multiplier(Scale::Hundredths, 1), Some(101));
+        assert_eq!(target_multiplier(Scale::Hundredths, 255), Some(355));
+        assert_eq!(target_multiplier(Scale::Tenths, 5), Some(150));
+        assert_eq!(target_multiplier(Scale::Tenths, 255), Some(2650));
+        assert_eq!(target_multiplier(Scale::Whole, 2), Some(200));
+        assert_eq!(target_multiplier(Scale::Whole, 100), Some(MAX_MULTIPLIER));
+
+        // 1.00x and anything above the cap are not targets.
+        assert_eq!(target_multiplier(Scale::Hundredths, 0), None);
+        assert_eq!(target_multiplier(Scale::Tenths, 0), None);
+        assert_eq!(target_multiplier(Scale::Whole, 1), None);
+        assert_eq!(target_multiplier(Scale::Whole, 101), None);
+    }
+
+    #[test]
+    fn test_multiplier_curve() {
+        assert_eq!(multiplier_at(0), 100);
+        assert_eq!(multiplier_at(2_000), 150);
+        assert_eq!(multiplier_at(4_000), 200);
+        assert_eq!(multiplier_at(12_000), 800);
+        assert!(multiplier_at(ROLL_MS - 1) < MAX_MULTIPLIER);
+        assert_eq!(multiplier_at(ROLL_MS), MAX_MULTIPLIER);
+        assert_eq!(multiplier_at(u64::MAX), MAX_MULTIPLIER);
+
+        let mut last = 0;
+        for elapsed in (0..=ROLL_MS).step_by(50) {
+            let multiplier = multiplier_at(elapsed);
+            assert!(multiplier >= last);
+            last = multiplier;
+        }
+    }
+
+    #[test]
+    fn test_crash_point_draws() {
+        // The lowest 3% of draws bust before 1.00x.
+        assert_eq!(crash_point_from_draw(0), 0);
+        assert_eq!(crash_point_from_draw(u32::MAX / 40), 0);
+        assert_eq!(crash_point_from_draw(u32::MAX / 2), 193);
+        assert_eq!(crash_point_from_draw(u32::MAX), MAX_MULTIPLIER);
+
+        // Each target returns ~97% over a sweep of evenly spaced draws.
+        let steps = 100_000u64;
+        for target in [101, 200, 1_000] {
+            let survived = (0..steps)
+                .map(|i| crash_point_from_draw((i * (1u64 << 32) / steps) as u32))
+                .filter(|point| *point >= target)
+                .count() as u64;
+            let rtp = survived * u64::from(target) * 100 / (steps * 100);
+            assert!((96..=97).contains(&rtp), "target {target} rtp {rtp}");
+        }
+    }
+
+    #[test]
+    fn test_bet_payouts() {
+        let bet = CrashBet {
+            scale: Scale::Tenths,
+            target: 15,
+            amount: 40,
+        };
+        assert_eq!(calculate_bet_payout(&bet, 0, 250), 100);
+        assert_eq!(calculate_bet_payout(&bet, 0, 249), 0);
+        // A manual cash-out below the target pays at the cash-out.
+        assert_eq!(calculate_bet_payout(&bet, 130, 150), 52);
+        assert_eq!(calculate_bet_payout(&bet, 130, 129), 0);
+        // A manual cash-out above the target never raises it.
+        assert_eq!(calculate_bet_payout(&bet, 400, 250), 100);
+        // Busted rounds pay nothing.
+        assert_eq!(calculate_bet_payout(&bet, 100, 0), 0);
+    }
+
+    #[test]
+    fn test_place_and_resolve() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Crash::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = Crash::process_move(&mut session, &place_bet_payload(2, 2, 100), &mut rng);
+        assert!(matches!(
+            result,
+            Ok(GameResult::ContinueWithUpdate { payout: -100, .. })
+        ));
+        Crash::process_move(&mut session, &place_bet_payload(0, 10, 50), &mut rng)
+            .expect("Failed to place bet");
+        assert!(!session.is_complete);
+
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let expected = draw_crash_point(&mut rng.clone());
+        let result = Crash::process_move(&mut session, &[1], &mut rng).expect("Failed to resolve");
+        assert!(session.is_complete);
+
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.crash_point, Some(expected));
+        match expected {
+            point if point >= 200 => assert!(matches!(result, GameResult::Win(255, _))),
+            point if point >= 110 => assert!(matches!(result, GameResult::Win(55, _))),
+            _ => assert!(matches!(result, GameResult::LossPreDeducted(150, _))),
+        }
+    }
+
+    #[test]
+    fn test_cash_out() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Crash::init(&mut session, &mut rng);
+
+        // Nothing to cash out yet.
+        assert!(matches!(
+            cash_out(&session.state_blob, 150),
+            Err(GameError::InvalidMove)
+        ));
+
+        Crash::process_move(&mut session, &place_bet_payload(2, 50, 10), &mut rng)
+            .expect("Failed to place bet");
+        session.state_blob = cash_out(&session.state_blob, 150).expect("Failed to cash out");
+        assert_eq!(
+            parse_state(&session.state_blob).map(|state| state.cash_out),
+            Some(150)
+        );
+
+        // Only one cash-out per round, and the layout is frozen once cashed out.
+        assert!(cash_out(&session.state_blob, 200).is_err());
+        let result = Crash::process_move(&mut session, &place_bet_payload(2, 2, 10), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+        let result = Crash::process_move(&mut session, &[2], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let point = draw_crash_point(&mut rng.clone());
+        let result = Crash::process_move(&mut session, &[1], &mut rng).expect("Failed to resolve");
+        if point >= 150 {
+            assert!(matches!(result, GameResult::Win(15, _)));
+        } else {
+            assert!(matches!(result, GameResult::LossPreDeducted(10, _)));
+        }
+        assert!(matches!(
+            cash_out(&session.state_blob, 150),
+            Err(GameError::GameAlreadyComplete)
+        ));
+    }
+
+    #[test]
+    fn test_atomic_batch_sets_wager() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Crash::init(&mut session, &mut rng);
+
+        let mut payload = vec![3, 2];
+        for (scale, target, amount) in [(Scale::Hundredths, 50, 20), (Scale::Whole, 5, 10)] {
+            payload.extend_from_slice(
+                &CrashBet {
+                    scale,
+                    target,
+                    amount,
+                }
+                .to_bytes(),
+            );
+        }
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let point = draw_crash_point(&mut rng.clone());
+        let result =
+            Crash::process_move(&mut session, &payload, &mut rng).expect("Failed to process batch");
+
+        assert_eq!(session.bet, 30);
+        assert!(session.is_complete);
+        match point {
+            point if point >= 500 => assert!(matches!(result, GameResult::Win(80, _))),
+            point if point >= 150 => assert!(matches!(result, GameResult::Win(30, _))),
+            _ => assert!(matches!(result, GameResult::Loss(_))),
+        }
+    }
+
+    #[test]
+    fn test_invalid_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Crash::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        // Unknown scale
+        let result = Crash::process_move(&mut session, &place_bet_payload(3, 2, 10), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Missing target
+        let result = Crash::process_move(&mut session, &place_bet_payload(0, 0, 10), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Target above the cap
+        let result = Crash::process_move(&mut session, &place_bet_payload(2, 101, 10), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Zero amount
+        let result = Crash::process_move(&mut session, &place_bet_payload(2, 2, 0), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        // Resolving without bets
+        let result = Crash::process_move(&mut session, &[1], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+
+        for _ in 0..limits::CRASH_MAX_BETS {
+            Crash::process_move(&mut session, &place_bet_payload(2, 2, 1), &mut rng)
+                .expect("Failed to place bet");
+        }
+        let result = Crash::process_move(&mut session, &place_bet_payload(2, 2, 1), &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+    }
+
+    #[test]
+    fn test_table_outcome_matches_resolve() {
+        let seed = create_test_seed();
+        let mut rng = GameRng::new(&seed, 9, 4);
+        let outcome = table_outcome(&mut rng.clone());
+        assert_eq!(outcome, draw_crash_point(&mut rng).to_be_bytes().to_vec());
+    }
+
+    #[test]
+    fn test_abandon_refunds_unresolved_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Crash::init(&mut session, &mut rng);
+        Crash::process_move(&mut session, &place_bet_payload(1, 20, 40), &mut rng)
+            .expect("Failed to place bet");
+
+        let result = Crash::abandon(&mut session, &mut rng);
+        assert!(matches!(result, Ok(GameResult::Push(40, _))));
+
+        Crash::process_move(&mut session, &place_bet_payload(1, 20, 40), &mut rng)
+            .expect("Failed to place bet");
+        Crash::process_move(&mut session, &[1], &mut rng).expect("Failed to resolve");
+        let result = Crash::abandon(&mut session, &mut rng);
+        assert!(matches!(result, Err(GameError::GameAlreadyComplete)));
+    }
+
+    #[test]
+    fn test_state_blob_fuzz_does_not_panic() {
+        let mut rng = StdRng::seed_from_u64(0xc2a5_1234);
+        for _ in 0..1_000 {
+            let len = rng.gen_range(0..=256);
+            let mut blob = vec![0u8; len];
+            rng.fill(&mut blob[..]);
+            let _ = parse_state(&blob);
+        }
+    }
+}
@@ -0,0 +1,701 @@
+//! Game adapters for the shared-round global table.
+//!
+//! The global table runs one authoritative round per game
+//! (open -> submit bets -> lock -> reveal -> settle -> finalize). Each player's bets live in
+//! their own `GameSession`, and every session is resolved with the round's `roll_seed`, so the
+//! game module's own payout logic settles the bets and all players see the same outcome.
+//!
+//! This module maps the table-level operations onto each supported game:
+//! - bet placement payloads (`GlobalTableBet` -> game move payload)
+//! - outstanding bets in a session blob (for the totals heatmap and `my_bets`)
+//! - the shared outcome computed at reveal (stored in `GlobalTableRound::outcome`)
+//!
+//! Supported games: Craps, Roulette, Sic Bo, Baccarat, Big Six, Crash.
+//!
+//! Craps bets can stay on the layout across rolls, so craps sessions persist between rounds
+//! and are synced to the table point each round. Roulette, Sic Bo, Baccarat, Big Six and Crash
+//! resolve every bet in a single spin/roll/deal, so their sessions are reset once settled.
+//!
+//! Crash is the only game with a live Rolling phase: its round seed is drawn when Rolling ends
+//! rather than at lock, and players may cash out while the multiplier climbs.
+
+use super::{baccarat, big_six, crash, roulette, sic_bo, GameError, GameRng};
+use commonware_codec::ReadExt;
+use commonware_cryptography::ed25519::PublicKey;
+use nullspace_types::casino::{
+    GameSession, GameType, GlobalTableBet, GlobalTableRound, SuperModeState,
+};
+
+/// Games that can run on the global table, in the order the scheduler advances them.
+pub(crate) const SUPPORTED_GAMES: [GameType; 6] = [
+    GameType::Craps,
+    GameType::Roulette,
+    GameType::SicBo,
+    GameType::Baccarat,
+    GameType::BigSix,
+    GameType::Crash,
+];
+
+/// Owner of the table's scratch session: the compressed ed25519 base point.
+///
+/// The scratch session never holds chips or signs anything, and no game reads its owner,
+/// but `GameSession` needs a valid key (and reveal may run without a signer).
+const TABLE_OWNER: [u8; 32] = [
+    0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+    0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+];
+
+/// Craps bet entry size in the session blob (see `craps.rs`).
+const CRAPS_BET_BYTES: usize = 19;
+/// Craps Field bet type (see `craps::BetType`).
+const CRAPS_FIELD_BET: u8 = 4;
+/// Craps header size: version, phase, main_point, d1, d2, made_points_mask, epoch flag, bet_count.
+const CRAPS_HEADER_LEN: usize = 8;
+
+/// Returns true if the game can run on the global table.
+pub(crate) fn is_supported(game_type: GameType) -> bool {
+    SUPPORTED_GAMES.contains(&game_type)
+}
+
+/// Returns true if unresolved bets carry over into the next round.
+pub(crate) fn carries_bets(game_type: GameType) -> bool {
+    matches!(game_type, GameType::Craps)
+}
+
+/// Returns true if the round runs a live Rolling phase between lock and reveal.
+///
+/// The roll seed for these games is only derived once Rolling ends, so nobody can know the
+/// outcome while players are still allowed to act.
+pub(crate) fn has_rolling_phase(game_type: GameType) -> bool {
+    matches!(game_type, GameType::Crash)
+}
+
+/// Length of the Rolling phase for games that have one.
+pub(crate) fn rolling_ms(game_type: GameType) -> u64 {
+    match game_type {
+        GameType::Crash => crash::ROLL_MS,
+        _ => 0,
+    }
+}
+
+/// Build the place-bet move payload for a table bet.
+pub(crate) fn place_bet_payload(
+    game_type: GameType,
+    bet: &GlobalTableBet,
+) -> Result<Vec<u8>, GameError> {
+    match game_type {
+        GameType::Craps
+        | GameType::Roulette
+        | GameType::SicBo
+        | GameType::BigSix
+        | GameType::Crash => {
+            let mut payload = Vec::with_capacity(11);
+            payload.push(0);
+            payload.push(bet.bet_type);
+            payload.push(bet.target);
+            payload.extend_from_slice(&bet.amount.to_be_bytes());
+            Ok(payload)
+        }
+        GameType::Baccarat => {
+            // Baccarat bets have no target; reject non-zero targets so totals stay keyed
+            // the same way the session reports them.
+            if bet.target != 0 {
+                return Err(GameError::InvalidPayload);
+            }
+            let mut payload = Vec::with_capacity(10);
+            payload.push(0);
+            payload.push(bet.bet_type);
+            payload.extend_from_slice(&bet.amount.to_be_bytes());
+            Ok(payload)
+        }
+        _ => Err(GameError::InvalidPayload),
+    }
+}
+
+/// Move payload that resolves all bets in a session (roll/spin/deal).
+pub(crate) fn resolve_payload(game_type: GameType) -> &'static [u8] {
+    match game_type {
+        GameType::Craps => &[2],
+        _ => &[1],
+    }
+}
+
+/// Bets still on the layout in a session blob.
+pub(crate) fn outstanding_bets(game_type: GameType, blob: &[u8]) -> Vec<GlobalTableBet> {
+    match game_type {
+        GameType::Craps => craps_bets(blob),
+        GameType::Roulette => roulette::table_bets(blob),
+        GameType::SicBo => sic_bo::table_bets(blob),
+        GameType::Baccarat => baccarat::table_bets(blob),
+        GameType::BigSix => big_six::table_bets(blob),
+        GameType::Crash => crash::table_bets(blob),
+        _ => Vec::new(),
+    }
+}
+
+/// Make sure a player session is initialized and aligned with the table for this round.
+pub(crate) fn prepare_session(
+    session: &mut GameSession,
+    round: &GlobalTableRound,
+    roll_seed: [u8; 32],
+) {
+    if session.state_blob.is_empty() {
+        let mut rng = GameRng::from_state(roll_seed);
+        let _ = super::init_game(session, &mut rng);
+    }
+    if session.game_type == GameType::Craps {
+        sync_craps_session_to_table(session, round);
+    }
+}
+
+/// Clear a player session after settlement so the next round starts fresh.
+///
+/// Craps sessions keep their state (bets that did not resolve stay working).
+pub(crate) fn finish_session(session: &mut GameSession) {
+    if !carries_bets(session.game_type) {
+        session.state_blob.clear();
+        session.move_count = 0;
+    }
+}
+
+/// Compute the shared outcome for a round and record it on the round.
+///
+/// Craps rolls a scratch session for the table itself from the current table point, so
+/// point/epoch state advances exactly as it does for players.
+pub(crate) fn reveal_outcome(
+    round: &mut GlobalTableRound,
+    roll_seed: [u8; 32],
+    view: u64,
+) -> Result<(), GameError> {
+    match round.game_type {
+        GameType::Craps => {
+            let mut table_session = GameSession {
+                id: round.round_id,
+                player: table_owner()?,
+                game_type: GameType::Craps,
+                bet: 0,
+                state_blob: vec![],
+                move_count: 0,
+                created_at: view,
+                is_complete: false,
+                super_mode: SuperModeState::default(),
+                is_tournament: false,
+                tournament_id: None,
+            };
+            let mut init_rng = GameRng::from_state(roll_seed);
+            let _ = super::init_game(&mut table_session, &mut init_rng);
+            sync_craps_session_to_table(&mut table_session, round);
+
+            // Craps refuses to roll an empty layout; a one-roll Field marker keeps the point
+            // state untouched while letting the table roll.
+            let marker = place_bet_payload(
+                GameType::Craps,
+                &GlobalTableBet {
+                    bet_type: CRAPS_FIELD_BET,
+                    target: 0,
+                    amount: 1,
+                },
+            )?;
+            let _ = super::process_game_move(&mut table_session, &marker, &mut init_rng)?;
+
+            let mut roll_rng = GameRng::from_state(roll_seed);
+            let _ = super::process_game_move(
+                &mut table_session,
+                resolve_payload(GameType::Craps),
+                &mut roll_rng,
+            )?;
+
+            let state =
+                read_craps_table_state(&table_session.state_blob).ok_or(GameError::InvalidState)?;
+            round.main_point = state.main_point;
+            round.d1 = state.d1;
+            round.d2 = state.d2;
+            round.made_points_mask = state.made_points_mask;
+            round.epoch_point_established = state.epoch_point_established;
+            round.field_paytable = state.field_paytable;
+            round.outcome = vec![state.d1, state.d2];
+        }
+        GameType::Roulette => {
+            let mut rng = GameRng::from_state(roll_seed);
+            round.outcome = roulette::table_outcome(&mut rng);
+        }
+        GameType::SicBo => {
+            let mut rng = GameRng::from_state(roll_seed);
+            round.outcome = sic_bo::table_outcome(&mut rng);
+        }
+        GameType::Baccarat => {
+            let mut rng = GameRng::from_state(roll_seed);
+            round.outcome = baccarat::table_outcome(&mut rng)?;
+        }
+        GameType::BigSix => {
+            let mut rng = GameRng::from_state(roll_seed);
+            round.outcome = big_six::table_outcome(&mut rng);
+        }
+        GameType::Crash => {
+            let mut rng = GameRng::from_state(roll_seed);
+            round.outcome = crash::table_outcome(&mut rng);
+        }
+        _ => return Err(GameError::InvalidMove),
+    }
+    Ok(())
+}
+
+/// Record a manual cash-out on a player's session at the live multiplier.
+///
+/// `elapsed_ms` is the time since Rolling began.
+/// Returns the multiplier locked in (in hundredths).
+pub(crate) fn cash_out(session: &mut GameSession, elapsed_ms: u64) -> Result<u32, GameError> {
+    match session.game_type {
+        GameType::Crash => {
+            let multiplier = crash::multiplier_at(elapsed_ms);
+            session.state_blob = crash::cash_out(&session.state_blob, multiplier)?;
+            Ok(multiplier)
+        }
+        _ => Err(GameError::InvalidMove),
+    }
+}
+
+fn table_owner() -> Result<PublicKey, GameError> {
+    PublicKey::read(&mut TABLE_OWNER.as_slice()).map_err(|_| GameError::InvalidState)
+}
+
+struct CrapsTableState {
+    main_point: u8,
+    d1: u8,
+    d2: u8,
+    made_points_mask: u8,
+    epoch_point_established: bool,
+    field_paytable: u8,
+}
+
+fn sync_craps_session_to_table(session: &mut GameSession, round: &GlobalTableRound) {
+    if session.state_blob.len() < CRAPS_HEADER_LEN {
+        return;
+    }
+    session.state_blob[1] = if round.main_point == 0 { 0 } else { 1 };
+    session.state_blob[2] = round.main_point;
+    session.state_blob[3] = round.d1;
+    session.state_blob[4] = round.d2;
+    session.state_blob[5] = round.made_points_mask;
+    session.state_blob[6] = if round.epoch_point_established { 1 } else { 0 };
+
+    let bet_count = session.state_blob[7] as usize;
+    let rules_offset = CRAPS_HEADER_LEN.saturating_add(bet_count.saturating_mul(CRAPS_BET_BYTES));
+    if session.state_blob.len() > rules_offset {
+        session.state_blob[rules_offset] = round.field_paytable;
+    }
+}
+
+fn read_craps_table_state(blob: &[u8]) -> Option<CrapsTableState> {
+    if blob.len() < CRAPS_HEADER_LEN || blob[0] != 2 {
+        return None;
+    }
+    let bet_count = blob[7] as usize;
+    let rules_offset = CRAPS_HEADER_LEN.saturating_add(bet_count.saturating_mul(CRAPS_BET_BYTES));
+    let field_paytable = if blob.len() > rules_offset {
+        blob[rules_offset]
+    } else {
+        0
+    };
+    Some(CrapsTableState {
+        main_point: blob[2],
+        d1: blob[3],
+        d2: blob[4],
+        made_points_mask: blob[5],
+        epoch_point_established: blob[6] != 0,
+        field_paytable,
+    })
+}
+
+fn craps_bets(blob: &[u8]) -> Vec<GlobalTableBet> {
+    if blob.len() < CRAPS_HEADER_LEN || blob[0] != 2 {
+        return Vec::new();
+    }
+    let bet_count = blob[7] as usize;
+    let mut bets = Vec::with_capacity(bet_count);
+    let mut offset = CRAPS_HEADER_LEN;
+    for _ in 0..bet_count {
+        if offset + CRAPS_BET_BYTES > blob.len() {
+            break;
+        }
+        let bet_type = blob[offset];
+        let target = blob[offset + 1];
+        let amount = u64::from_be_bytes([
+            blob[offset + 3],
+            blob[offset + 4],
+            blob[offset + 5],
+            blob[offset + 6],
+            blob[offset + 7],
+            blob[offset + 8],
+            blob[offset + 9],
+            blob[offset + 10],
+        ]);
+        if amount > 0 {
+            bets.push(GlobalTableBet {
+                bet_type,
+                target,
+                amount,
+            });
+        }
+        offset = offset.saturating_add(CRAPS_BET_BYTES);
+    }
+    bets
+}
+
+#[cfg(test)]
+#[allow(unused_must_use)]
+mod tests {
+    use super::*;
+    use crate::mocks::create_account_keypair;
+    use nullspace_types::casino::{GlobalTablePhase, SuperModeState};
+
+    fn table_round(game_type: GameType) -> GlobalTableRound {
+        GlobalTableRound {
+            game_type,
+            round_id: 1,
+            phase: GlobalTablePhase::Locked,
+            phase_ends_at_ms: 0,
+            main_point: 0,
+            d1: 0,
+            d2: 0,
+            made_points_mask: 0,
+            epoch_point_established: false,
+            field_paytable: 0,
+            rng_commit: Vec::new(),
+            roll_seed: Vec::new(),
+            totals: Vec::new(),
+            outcome: Vec::new(),
+        }
+    }
+
+    fn player_session(game_type: GameType) -> GameSession {
+        let (_, public) = create_account_keypair(7);
+        GameSession {
+            id: 1,
+            player: public,
+            game_type,
+            bet: 0,
+            state_blob: vec![],
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        }
+    }
+
+    #[test]
+    fn test_place_and_extract_bets_roundtrip() {
+        let seed = [3u8; 32];
+        let cases = [
+            (
+                GameType::Craps,
+                GlobalTableBet {
+                    bet_type: 0,
+                    target: 0,
+                    amount: 10,
+                },
+            ),
+            (
+                GameType::Roulette,
+                GlobalTableBet {
+                    bet_type: 0,
+                    target: 17,
+                    amount: 5,
+                },
+            ),
+            (
+                GameType::SicBo,
+                GlobalTableBet {
+                    bet_type: 7,
+                    target: 10,
+                    amount: 8,
+                },
+            ),
+            (
+                GameType::Baccarat,
+                GlobalTableBet {
+                    bet_type: 1,
+                    target: 0,
+                    amount: 25,
+                },
+            ),
+            (
+                GameType::BigSix,
+                GlobalTableBet {
+                    bet_type: 5,
+                    target: 0,
+                    amount: 12,
+                },
+            ),
+            (
+                GameType::Crash,
+                GlobalTableBet {
+                    bet_type: 1,
+                    target: 15,
+                    amount: 30,
+                },
+            ),
+        ];
+        for (game_type, bet) in cases {
+            let round = table_round(game_type);
+            let mut session = player_session(game_type);
+            prepare_session(&mut session, &round, seed);
+            let payload = place_bet_payload(game_type, &bet).expect("payload");
+            let mut rng = GameRng::from_state(seed);
+            super::super::process_game_move(&mut session, &payload, &mut rng)
+                .expect("bet accepted");
+            assert_eq!(outstanding_bets(game_type, &session.state_blob), vec![bet]);
+        }
+    }
+
+    #[test]
+    fn test_craps_prop_buy_and_put_bets_on_table() {
+        let seed = [4u8; 32];
+        let mut round = table_round(GameType::Craps);
+        round.main_point = 6;
+        let mut session = player_session(GameType::Craps);
+        prepare_session(&mut session, &round, seed);
+
+        // Any Seven, Horn, Big 8, Buy 4 (vig up front), Lay 10, Put 8
+        let bets = [
+            (23, 0, 10),
+            (25, 0, 20),
+            (29, 0, 15),
+            (32, 4, 40),
+            (31, 10, 40),
+            (34, 8, 25),
+        ]
+        .map(|(bet_type, target, amount)| GlobalTableBet {
+            bet_type,
+            target,
+            amount,
+        });
+        for bet in &bets {
+            let payload = place_bet_payload(GameType::Craps, bet).expect("payload");
+            let mut rng = GameRng::from_state(seed);
+            super::super::process_game_move(&mut session, &payload, &mut rng)
+                .expect("bet accepted");
+        }
+        // Up-front vig is charged but not counted towards the table totals.
+        assert_eq!(
+            outstanding_bets(GameType::Craps, &session.state_blob),
+            bets.to_vec()
+        );
+
+        // Put bets need a point on the table.
+        let round = table_round(GameType::Craps);
+        let mut session = player_session(GameType::Craps);
+        prepare_session(&mut session, &round, seed);
+        let payload = place_bet_payload(GameType::Craps, &bets[5]).expect("payload");
+        let mut rng = GameRng::from_state(seed);
+        assert!(super::super::process_game_move(&mut session, &payload, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_craps_reveal_without_signer() {
+        assert!(table_owner().is_ok());
+        let mut round = table_round(GameType::Craps);
+        reveal_outcome(&mut round, [5u8; 32], 0).expect("reveal");
+        assert_eq!(round.outcome, vec![round.d1, round.d2]);
+        assert!((1..=6).contains(&round.d1) && (1..=6).contains(&round.d2));
+    }
+
+    #[test]
+    fn test_baccarat_rejects_target() {
+        let bet = GlobalTableBet {
+            bet_type: 0,
+            target: 3,
+            amount: 10,
+        };
+        assert!(place_bet_payload(GameType::Baccarat, &bet).is_err());
+        assert!(place_bet_payload(GameType::Blackjack, &bet).is_err());
+    }
+
+    #[test]
+    fn test_revealed_outcome_matches_player_resolution() {
+        let seed = [9u8; 32];
+
+        // Roulette: the player's spin lands on the revealed number.
+        let mut round = table_round(GameType::Roulette);
+        reveal_outcome(&mut round, seed, 0).expect("reveal");
+        let mut session = player_session(GameType::Roulette);
+        prepare_session(&mut session, &round, seed);
+        let bet = GlobalTableBet {
+            bet_type: 1,
+            target: 0,
+            amount: 10,
+        };
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            &place_bet_payload(GameType::Roulette, &bet).unwrap(),
+            &mut rng,
+        )
+        .unwrap();
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            resolve_payload(GameType::Roulette),
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(session.state_blob.last(), round.outcome.first());
+
+        // Sic Bo: the player's dice match the revealed dice.
+        let mut round = table_round(GameType::SicBo);
+        reveal_outcome(&mut round, seed, 0).expect("reveal");
+        let mut session = player_session(GameType::SicBo);
+        prepare_session(&mut session, &round, seed);
+        let bet = GlobalTableBet {
+            bet_type: 0,
+            target: 0,
+            amount: 10,
+        };
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            &place_bet_payload(GameType::SicBo, &bet).unwrap(),
+            &mut rng,
+        )
+        .unwrap();
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(&mut session, resolve_payload(GameType::SicBo), &mut rng)
+            .unwrap();
+        let len = session.state_blob.len();
+        // [..bets][die1, die2, die3][rules]
+        assert_eq!(
+            &session.state_blob[len - 4..len - 1],
+            round.outcome.as_slice()
+        );
+
+        // Baccarat: the player's hands match the revealed coup.
+        let mut round = table_round(GameType::Baccarat);
+        reveal_outcome(&mut round, seed, 0).expect("reveal");
+        let mut session = player_session(GameType::Baccarat);
+        prepare_session(&mut session, &round, seed);
+        let bet = GlobalTableBet {
+            bet_type: 0,
+            target: 0,
+            amount: 10,
+        };
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            &place_bet_payload(GameType::Baccarat, &bet).unwrap(),
+            &mut rng,
+        )
+        .unwrap();
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            resolve_payload(GameType::Baccarat),
+            &mut rng,
+        )
+        .unwrap();
+        // [bet_count][bet:9][player_len][cards..][banker_len][cards..]
+        assert_eq!(&session.state_blob[10..], round.outcome.as_slice());
+
+        // Big Six: the player's wheel stops on the revealed segment.
+        let mut round = table_round(GameType::BigSix);
+        reveal_outcome(&mut round, seed, 0).expect("reveal");
+        let mut session = player_session(GameType::BigSix);
+        prepare_session(&mut session, &round, seed);
+        let bet = GlobalTableBet {
+            bet_type: 0,
+            target: 0,
+            amount: 10,
+        };
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            &place_bet_payload(GameType::BigSix, &bet).unwrap(),
+            &mut rng,
+        )
+        .unwrap();
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(&mut session, resolve_payload(GameType::BigSix), &mut rng)
+            .unwrap();
+        // [version][wheel][bet_count][bet:10][stop]
+        assert_eq!(session.state_blob.last(), round.outcome.first());
+
+        // Crash: the player's round crashes at the revealed multiplier.
+        let mut round = table_round(GameType::Crash);
+        reveal_outcome(&mut round, seed, 0).expect("reveal");
+        let mut session = player_session(GameType::Crash);
+        prepare_session(&mut session, &round, seed);
+        let bet = GlobalTableBet {
+            bet_type: 2,
+            target: 2,
+            amount: 10,
+        };
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            &place_bet_payload(GameType::Crash, &bet).unwrap(),
+            &mut rng,
+        )
+        .unwrap();
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(&mut session, resolve_payload(GameType::Crash), &mut rng)
+            .unwrap();
+        // [version][bet_count][bet:10][cash_out:4][crash_point:4]
+        let len = session.state_blob.len();
+        assert_eq!(&session.state_blob[len - 4..], round.outcome.as_slice());
+    }
+
+    #[test]
+    fn test_crash_cash_out() {
+        let seed = [6u8; 32];
+        assert!(has_rolling_phase(GameType::Crash));
+        assert!(!has_rolling_phase(GameType::Roulette));
+        assert_eq!(rolling_ms(GameType::Crash), crash::ROLL_MS);
+
+        let round = table_round(GameType::Crash);
+        let mut session = player_session(GameType::Crash);
+        prepare_session(&mut session, &round, seed);
+        assert!(cash_out(&mut session, 1_000).is_err());
+
+        let bet = GlobalTableBet {
+            bet_type: 2,
+            target: 10,
+            amount: 40,
+        };
+        let mut rng = GameRng::from_state(seed);
+        super::super::process_game_move(
+            &mut session,
+            &place_bet_payload(GameType::Crash, &bet).unwrap(),
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(cash_out(&mut session, 4_000), Ok(200));
+        assert!(cash_out(&mut session, 8_000).is_err());
+        // The bets stay on the table totals until settlement.
+        assert_eq!(
+            outstanding_bets(GameType::Crash, &session.state_blob),
+            vec![bet]
+        );
+
+        let mut roulette = player_session(GameType::Roulette);
+        prepare_session(&mut roulette, &table_round(GameType::Roulette), seed);
+        assert_eq!(cash_out(&mut roulette, 0), Err(GameError::InvalidMove));
+    }
+
+    #[test]
+    fn test_finish_session_resets_single_round_games() {
+        let seed = [1u8; 32];
+        let round = table_round(GameType::Roulette);
+        let mut session = player_session(GameType::Roulette);
+        prepare_session(&mut session, &round, seed);
+        assert!(!session.state_blob.is_empty());
+        finish_session(&mut session);
+        assert!(session.state_blob.is_empty());
+
+        let round = table_round(GameType::Craps);
+        let mut session = player_session(GameType::Craps);
+        prepare_session(&mut session, &round, seed);
+        finish_session(&mut session);
+        assert!(!session.state_blob.is_empty());
+    }
+}
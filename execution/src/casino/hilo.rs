@@ -456,6 +456,11 @@ impl CasinoGame for HiLo {
             }
         }
     }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        // Walking away keeps the current pot.
+        Self::process_move(session, &[Move::Cashout as u8], rng)
+    }
 }
 
 #[cfg(test)]
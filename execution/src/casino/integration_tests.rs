@@ -40,10 +40,15 @@ mod tests {
 
         for (i, game_type) in [
             GameType::Baccarat,
+            GameType::BigSix,
             GameType::Blackjack,
             GameType::CasinoWar,
             GameType::Craps,
+            GameType::Crash,
             GameType::HiLo,
+            GameType::Keno,
+            GameType::LetItRide,
+            GameType::PaiGow,
             GameType::Roulette,
             GameType::SicBo,
             GameType::ThreeCard,
@@ -74,10 +79,15 @@ mod tests {
 
         let games = [
             GameType::Baccarat,
+            GameType::BigSix,
             GameType::Blackjack,
             GameType::CasinoWar,
             GameType::Craps,
+            GameType::Crash,
             GameType::HiLo,
+            GameType::Keno,
+            GameType::LetItRide,
+            GameType::PaiGow,
             GameType::Roulette,
             GameType::SicBo,
             GameType::ThreeCard,
@@ -246,10 +256,15 @@ mod tests {
         let mut rng = GameRng::new(&seed, session.id, 0);
         init_game(&mut session, &mut rng);
 
+        // Deal Jacks or Better (full pay)
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = process_game_move(&mut session, &[0xFF, 0], &mut rng);
+        assert!(matches!(result, Ok(GameResult::Continue(_))));
+
         // Verify 5 cards dealt
         assert_eq!(session.state_blob.len(), 7); // stage + 5 cards + rules
 
-        let mut rng = GameRng::new(&seed, session.id, 1);
+        let mut rng = GameRng::new(&seed, session.id, 2);
         // Hold all cards (0b11111)
         let result = process_game_move(&mut session, &[0b11111], &mut rng);
 
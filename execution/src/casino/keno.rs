@@ -0,0 +1,753 @@
+//! Keno implementation.
+//!
+//! The player marks 1-10 spots on a board of 80 numbers and the house draws 20 numbers
+//! without replacement. The ticket pays according to how many spots were hit ("catches"),
+//! using one of several selectable paytables.
+//!
+//! A ticket can run for multiple consecutive races in a single transaction: the same spots
+//! and stake are played on every race, and each race draws from its own RNG stream derived
+//! from the move RNG (`GameRng::derive(race_index)`), so races are independent of each other.
+//!
+//! Like the atomic table-game batches, Keno sessions start with `bet = 0`. The play move sets
+//! `session.bet` to `stake * races`, and the whole ticket is charged when it resolves.
+//!
+//! Paytables (total return per unit staked, by catches; values in the tables below are in
+//! tenths so fractional pays stay integral):
+//! 0 = Classic  (balanced, ~94-95% RTP)
+//! 1 = Jackpot  (top-heavy, ~94% RTP)
+//! 2 = Steady   (frequent small pays, ~95-97% RTP)
+//!
+//! State blob format:
+//! [version:u8=1]
+//! [stage:u8]                  (0=Ticket, 1=Complete)
+//! [paytable:u8]
+//! [races:u8]
+//! [stake:u64 BE]              (per race)
+//! [spotCount:u8] [spots:u8×spotCount]
+//! [drawn:u8×20]×races         (only once complete, in draw order)
+//!
+//! Payload format:
+//! [0, paytable:u8, races:u8, stake:u64 BE, spotCount:u8, spots...] - Play the ticket
+//!     for `races` consecutive races (1-20) and resolve it
+
+use super::logging::{clamp_i64, format_card_list, push_resolved_entry};
+use super::serialization::{StateReader, StateWriter};
+use super::super_mode::apply_super_multiplier_number;
+use super::{limits, CasinoGame, GameError, GameResult, GameRng};
+use nullspace_types::casino::GameSession;
+use std::fmt::Write;
+
+const STATE_VERSION: u8 = 1;
+/// Header length: version(1) + stage(1) + paytable(1) + races(1) + stake(8) + spotCount(1).
+const STATE_HEADER_LEN: usize = 13;
+
+/// Numbers on the board (1-80).
+pub const BOARD_SIZE: u8 = 80;
+/// Numbers drawn per race.
+pub const DRAW_COUNT: usize = 20;
+/// Maximum spots a ticket may mark.
+pub const MAX_SPOTS: usize = 10;
+
+/// Returns are expressed in tenths of the stake.
+const PAY_SCALE: u128 = 10;
+
+/// Classic paytable, indexed by `[spots - 1][catches]` (tenths of the stake).
+const CLASSIC: [&[u32]; MAX_SPOTS] = [
+    &[0, 38],
+    &[0, 0, 155],
+    &[0, 0, 20, 480],
+    &[0, 0, 10, 90, 1_150],
+    &[0, 0, 0, 30, 250, 6_000],
+    &[0, 0, 0, 20, 80, 800, 16_000],
+    &[0, 0, 0, 10, 50, 350, 1_500, 40_000],
+    &[0, 0, 0, 0, 30, 180, 1_000, 5_000, 100_000],
+    &[0, 0, 0, 0, 10, 100, 500, 2_500, 20_000, 100_000],
+    &[20, 0, 0, 0, 0, 50, 250, 1_200, 8_000, 25_000, 100_000],
+];
+
+/// Jackpot paytable: fewer paying catches, bigger top prizes.
+const JACKPOT: [&[u32]; MAX_SPOTS] = [
+    &[0, 38],
+    &[0, 0, 155],
+    &[0, 0, 0, 680],
+    &[0, 0, 0, 20, 2_800],
+    &[0, 0, 0, 0, 350, 8_000],
+    &[0, 0, 0, 0, 20, 1_800, 25_000],
+    &[0, 0, 0, 0, 0, 550, 3_000, 100_000],
+    &[0, 0, 0, 0, 0, 200, 1_600, 10_000, 100_000],
+    &[0, 0, 0, 0, 0, 40, 600, 5_000, 50_000, 100_000],
+    &[0, 0, 0, 0, 0, 20, 240, 2_000, 15_000, 60_000, 100_000],
+];
+
+/// Steady paytable: more paying catches, smaller top prizes.
+const STEADY: [&[u32]; MAX_SPOTS] = [
+    &[0, 38],
+    &[0, 10, 95],
+    &[0, 0, 25, 440],
+    &[0, 0, 15, 65, 1_200],
+    &[0, 0, 5, 40, 250, 3_000],
+    &[0, 0, 5, 20, 100, 600, 5_000],
+    &[0, 0, 5, 10, 50, 250, 1_500, 10_000],
+    &[0, 0, 0, 10, 30, 150, 700, 3_000, 20_000],
+    &[0, 0, 0, 5, 20, 80, 400, 1_500, 8_000, 25_000],
+    &[30, 0, 0, 0, 10, 45, 200, 900, 4_000, 15_000, 50_000],
+];
+
+/// Selectable paytables.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Paytable {
+    Classic = 0,
+    Jackpot = 1,
+    Steady = 2,
+}
+
+impl Paytable {
+    pub const ALL: [Paytable; 3] = [Paytable::Classic, Paytable::Jackpot, Paytable::Steady];
+
+    fn table(self) -> &'static [&'static [u32]; MAX_SPOTS] {
+        match self {
+            Paytable::Classic => &CLASSIC,
+            Paytable::Jackpot => &JACKPOT,
+            Paytable::Steady => &STEADY,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Paytable::Classic => "CLASSIC",
+            Paytable::Jackpot => "JACKPOT",
+            Paytable::Steady => "STEADY",
+        }
+    }
+}
+
+impl TryFrom<u8> for Paytable {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Paytable::Classic),
+            1 => Ok(Paytable::Jackpot),
+            2 => Ok(Paytable::Steady),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+/// Return for `catches` out of `spots`, in tenths of the stake (0 if the combination is invalid).
+pub fn pay_tenths(paytable: Paytable, spots: usize, catches: usize) -> u32 {
+    if spots == 0 || spots > MAX_SPOTS {
+        return 0;
+    }
+    paytable.table()[spots - 1]
+        .get(catches)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Game stages.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Ticket = 0,
+    Complete = 1,
+}
+
+impl TryFrom<u8> for Stage {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Stage::Ticket),
+            1 => Ok(Stage::Complete),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+/// Player actions.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Play = 0,
+}
+
+impl TryFrom<u8> for Action {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Action::Play),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct KenoState {
+    stage: Stage,
+    paytable: Paytable,
+    races: u8,
+    stake: u64,
+    spots: Vec<u8>,
+    draws: Vec<[u8; DRAW_COUNT]>,
+}
+
+impl KenoState {
+    fn new() -> Self {
+        Self {
+            stage: Stage::Ticket,
+            paytable: Paytable::Classic,
+            races: 0,
+            stake: 0,
+            spots: Vec::new(),
+            draws: Vec::new(),
+        }
+    }
+}
+
+fn is_valid_number(number: u8) -> bool {
+    (1..=BOARD_SIZE).contains(&number)
+}
+
+/// Bit set of board numbers (bit `n` set for number `n`).
+fn number_mask(numbers: &[u8]) -> u128 {
+    numbers.iter().fold(0u128, |mask, &n| mask | (1u128 << n))
+}
+
+/// Validate a spot list: 1-10 distinct numbers in 1-80.
+fn validate_spots(spots: &[u8]) -> bool {
+    if spots.is_empty() || spots.len() > MAX_SPOTS {
+        return false;
+    }
+    if !spots.iter().all(|&n| is_valid_number(n)) {
+        return false;
+    }
+    number_mask(spots).count_ones() as usize == spots.len()
+}
+
+fn parse_state(state: &[u8]) -> Option<KenoState> {
+    let mut reader = StateReader::new(state);
+    if reader.read_u8()? != STATE_VERSION {
+        return None;
+    }
+    let stage = Stage::try_from(reader.read_u8()?).ok()?;
+    let paytable = Paytable::try_from(reader.read_u8()?).ok()?;
+    let races = reader.read_u8()?;
+    let stake = reader.read_u64_be()?;
+    let spot_count = reader.read_u8()? as usize;
+    let spots = reader.read_vec(spot_count)?;
+
+    let draw_count = match stage {
+        Stage::Ticket => 0,
+        Stage::Complete => {
+            if !validate_spots(&spots) || races == 0 {
+                return None;
+            }
+            races as usize
+        }
+    };
+    let mut draws = Vec::with_capacity(draw_count);
+    for _ in 0..draw_count {
+        let drawn: [u8; DRAW_COUNT] = reader.read_bytes(DRAW_COUNT)?.try_into().ok()?;
+        if !drawn.iter().all(|&n| is_valid_number(n)) {
+            return None;
+        }
+        draws.push(drawn);
+    }
+    if reader.remaining() != 0 {
+        return None;
+    }
+
+    Some(KenoState {
+        stage,
+        paytable,
+        races,
+        stake,
+        spots,
+        draws,
+    })
+}
+
+fn serialize_state(state: &KenoState) -> Vec<u8> {
+    let mut out = StateWriter::with_capacity(
+        STATE_HEADER_LEN + state.spots.len() + state.draws.len() * DRAW_COUNT,
+    );
+    out.push_u8(STATE_VERSION);
+    out.push_u8(state.stage as u8);
+    out.push_u8(state.paytable as u8);
+    out.push_u8(state.races);
+    out.push_u64_be(state.stake);
+    out.push_u8(state.spots.len() as u8);
+    out.push_bytes(&state.spots);
+    for drawn in &state.draws {
+        out.push_bytes(drawn);
+    }
+    out.into_inner()
+}
+
+/// Draw 20 distinct numbers from 1-80 (partial Fisher-Yates, in draw order).
+fn draw_numbers(rng: &mut GameRng) -> [u8; DRAW_COUNT] {
+    let mut board: [u8; BOARD_SIZE as usize] = std::array::from_fn(|i| i as u8 + 1);
+    let mut drawn = [0u8; DRAW_COUNT];
+    for (i, slot) in drawn.iter_mut().enumerate() {
+        let j = i + rng.next_bounded(BOARD_SIZE - i as u8) as usize;
+        board.swap(i, j);
+        *slot = board[i];
+    }
+    drawn
+}
+
+/// Spots on the ticket that were drawn, in ticket order.
+fn caught_numbers(spots: &[u8], drawn: &[u8; DRAW_COUNT]) -> Vec<u8> {
+    let drawn_mask = number_mask(drawn);
+    spots
+        .iter()
+        .copied()
+        .filter(|&n| drawn_mask & (1u128 << n) != 0)
+        .collect()
+}
+
+fn race_return(paytable: Paytable, spots: usize, catches: usize, stake: u64) -> u64 {
+    let total = u128::from(stake) * u128::from(pay_tenths(paytable, spots, catches)) / PAY_SCALE;
+    u64::try_from(total).unwrap_or(u64::MAX)
+}
+
+/// Resolve every race on the ticket, returning `(catches, return)` per race.
+fn resolve_races(session: &GameSession, state: &KenoState) -> Vec<(Vec<u8>, u64)> {
+    state
+        .draws
+        .iter()
+        .map(|drawn| {
+            let caught = caught_numbers(&state.spots, drawn);
+            let mut payout =
+                race_return(state.paytable, state.spots.len(), caught.len(), state.stake);
+            if session.super_mode.is_active && payout > 0 {
+                // Every caught Lightning Number multiplies the race's return (stacking).
+                for &number in &caught {
+                    payout = apply_super_multiplier_number(
+                        number,
+                        &session.super_mode.multipliers,
+                        payout,
+                    );
+                }
+            }
+            (caught, payout)
+        })
+        .collect()
+}
+
+fn generate_keno_logs(
+    state: &KenoState,
+    races: &[(Vec<u8>, u64)],
+    total_return: u64,
+) -> Vec<String> {
+    let total_wagered = state.stake.saturating_mul(u64::from(state.races));
+    let mut resolved_bets = String::new();
+    let mut race_entries = String::new();
+    let mut resolved_sum: i128 = 0;
+    let mut winning_races = 0usize;
+
+    for (idx, ((caught, payout), drawn)) in races.iter().zip(state.draws.iter()).enumerate() {
+        if *payout > 0 {
+            winning_races += 1;
+        }
+        let pnl = clamp_i64(i128::from(*payout) - i128::from(state.stake));
+        resolved_sum = resolved_sum.saturating_add(i128::from(pnl));
+        push_resolved_entry(&mut resolved_bets, &format!("RACE {}", idx + 1), pnl);
+        if !race_entries.is_empty() {
+            race_entries.push(',');
+        }
+        let _ = write!(
+            race_entries,
+            r#"{{"drawn":[{}],"caught":[{}],"catches":{},"return":{}}}"#,
+            format_card_list(drawn),
+            format_card_list(caught),
+            caught.len(),
+            payout
+        );
+    }
+
+    let summary = if state.races == 1 {
+        let catches = races.first().map(|(caught, _)| caught.len()).unwrap_or(0);
+        format!("Caught {} of {}", catches, state.spots.len())
+    } else {
+        format!(
+            "{} spots x{} races, {} paid",
+            state.spots.len(),
+            state.races,
+            winning_races
+        )
+    };
+    let net_pnl = clamp_i64(i128::from(total_return) - i128::from(total_wagered));
+    let diff = i128::from(net_pnl).saturating_sub(resolved_sum);
+    if diff != 0 {
+        push_resolved_entry(&mut resolved_bets, "ADJUSTMENT", clamp_i64(diff));
+    }
+
+    vec![format!(
+        r#"{{"summary":"{}","netPnl":{},"resolvedBets":[{}],"paytable":"{}","spots":[{}],"stake":{},"races":[{}],"totalWagered":{},"totalReturn":{}}}"#,
+        summary,
+        net_pnl,
+        resolved_bets,
+        state.paytable.name(),
+        format_card_list(&state.spots),
+        state.stake,
+        race_entries,
+        total_wagered,
+        total_return
+    )]
+}
+
+pub struct Keno;
+
+impl CasinoGame for Keno {
+    fn init(session: &mut GameSession, _rng: &mut GameRng) -> GameResult {
+        session.state_blob = serialize_state(&KenoState::new());
+        GameResult::Continue(vec![])
+    }
+
+    fn process_move(
+        session: &mut GameSession,
+        payload: &[u8],
+        rng: &mut GameRng,
+    ) -> Result<GameResult, GameError> {
+        if session.is_complete {
+            return Err(GameError::GameAlreadyComplete);
+        }
+        if payload.is_empty() {
+            return Err(GameError::InvalidPayload);
+        }
+
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidPayload)?;
+
+        match Action::try_from(payload[0])? {
+            Action::Play => {
+                // The ticket is charged atomically on completion, so nothing may be staked yet.
+                if state.stage != Stage::Ticket || session.bet != 0 {
+                    return Err(GameError::InvalidMove);
+                }
+                if payload.len() < 12 {
+                    return Err(GameError::InvalidPayload);
+                }
+
+                let paytable = Paytable::try_from(payload[1])?;
+                let races = payload[2];
+                let stake = super::payload::parse_u64_be(payload, 3)?;
+                let spot_count = payload[11] as usize;
+                if payload.len() != 12 + spot_count {
+                    return Err(GameError::InvalidPayload);
+                }
+                let spots = &payload[12..];
+
+                if races == 0 || races as usize > limits::KENO_MAX_RACES {
+                    return Err(GameError::InvalidPayload);
+                }
+                super::payload::ensure_nonzero_amount(stake)?;
+                if !validate_spots(spots) {
+                    return Err(GameError::InvalidPayload);
+                }
+                let total_wager = stake
+                    .checked_mul(u64::from(races))
+                    .ok_or(GameError::InvalidPayload)?;
+
+                state.paytable = paytable;
+                state.races = races;
+                state.stake = stake;
+                state.spots = spots.to_vec();
+                state.draws = (0..races)
+                    .map(|race| draw_numbers(&mut rng.derive(u32::from(race))))
+                    .collect();
+                state.stage = Stage::Complete;
+
+                let results = resolve_races(session, &state);
+                let total_return = results
+                    .iter()
+                    .fold(0u64, |acc, (_, payout)| acc.saturating_add(*payout));
+
+                session.bet = total_wager;
+                session.state_blob = serialize_state(&state);
+                session.is_complete = true;
+
+                let logs = generate_keno_logs(&state, &results, total_return);
+                if total_return > 0 {
+                    Ok(GameResult::Win(total_return, logs))
+                } else {
+                    // Total loss - the ticket is deducted on completion
+                    Ok(GameResult::Loss(logs))
+                }
+            }
+        }
+    }
+
+    fn abandon(session: &mut GameSession, _rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.stage {
+            // No ticket was played, so only a stake taken at start (if any) is returned.
+            Stage::Ticket => Ok(GameResult::Push(session.bet, vec![])),
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_must_use)]
+mod tests {
+    use super::*;
+    use crate::mocks::{create_account_keypair, create_network_keypair, create_seed};
+    use nullspace_types::casino::{GameType, SuperModeState, SuperMultiplier, SuperType};
+
+    fn create_test_seed() -> nullspace_types::Seed {
+        let (network_secret, _) = create_network_keypair();
+        create_seed(&network_secret, 1)
+    }
+
+    fn create_test_session(bet: u64) -> GameSession {
+        let (_, pk) = create_account_keypair(1);
+        GameSession {
+            id: 1,
+            player: pk,
+            game_type: GameType::Keno,
+            bet,
+            state_blob: vec![],
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        }
+    }
+
+    fn play_payload(paytable: u8, races: u8, stake: u64, spots: &[u8]) -> Vec<u8> {
+        let mut payload = vec![Action::Play as u8, paytable, races];
+        payload.extend_from_slice(&stake.to_be_bytes());
+        payload.push(spots.len() as u8);
+        payload.extend_from_slice(spots);
+        payload
+    }
+
+    fn binomial(n: u128, k: u128) -> u128 {
+        if k > n {
+            return 0;
+        }
+        (0..k).fold(1u128, |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    /// Exact RTP in basis points: sum over catches of C(spots, k) * C(80 - spots, 20 - k).
+    fn exact_rtp_bps(paytable: Paytable, spots: usize) -> u128 {
+        let total = binomial(80, 20);
+        let weighted: u128 = (0..=spots)
+            .map(|k| {
+                binomial(spots as u128, k as u128)
+                    * binomial(80 - spots as u128, (DRAW_COUNT - k) as u128)
+                    * u128::from(pay_tenths(paytable, spots, k))
+            })
+            .sum();
+        weighted * 10_000 / (total * PAY_SCALE)
+    }
+
+    #[test]
+    fn test_paytables_are_well_formed() {
+        for paytable in Paytable::ALL {
+            for spots in 1..=MAX_SPOTS {
+                let row = paytable.table()[spots - 1];
+                assert_eq!(row.len(), spots + 1, "{paytable:?} {spots} spots");
+                // Catching every spot is always the top prize.
+                assert_eq!(row.iter().max(), row.last(), "{paytable:?} {spots} spots");
+            }
+            assert_eq!(pay_tenths(paytable, 0, 0), 0);
+            assert_eq!(pay_tenths(paytable, MAX_SPOTS + 1, 1), 0);
+        }
+    }
+
+    #[test]
+    fn test_paytable_rtp_in_range() {
+        for paytable in Paytable::ALL {
+            for spots in 1..=MAX_SPOTS {
+                let rtp = exact_rtp_bps(paytable, spots);
+                assert!(
+                    (9_300..=9_700).contains(&rtp),
+                    "{paytable:?} {spots} spots: RTP {rtp} bps"
+                );
+            }
+        }
+        assert_eq!(exact_rtp_bps(Paytable::Classic, 1), 9_500);
+    }
+
+    #[test]
+    fn test_draw_numbers_unique_and_in_range() {
+        let seed = create_test_seed();
+        for move_number in 0..200 {
+            let mut rng = GameRng::new(&seed, 1, move_number);
+            let drawn = draw_numbers(&mut rng);
+            assert!(drawn.iter().all(|&n| is_valid_number(n)));
+            assert_eq!(number_mask(&drawn).count_ones() as usize, DRAW_COUNT);
+        }
+    }
+
+    #[test]
+    fn test_single_race_ticket() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Keno::init(&mut session, &mut rng);
+
+        let spots = [3, 17, 22, 41, 58, 77];
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = Keno::process_move(&mut session, &play_payload(0, 1, 100, &spots), &mut rng)
+            .expect("play ticket");
+
+        assert!(session.is_complete);
+        assert_eq!(session.bet, 100);
+        let state = parse_state(&session.state_blob).expect("parse state");
+        assert_eq!(state.stage, Stage::Complete);
+        assert_eq!(state.spots, spots);
+        assert_eq!(state.draws.len(), 1);
+        assert_eq!(parse_state(&serialize_state(&state)), Some(state.clone()));
+
+        let catches = caught_numbers(&spots, &state.draws[0]).len();
+        let expected = race_return(Paytable::Classic, spots.len(), catches, 100);
+        match result {
+            GameResult::Win(amount, _) => assert_eq!(amount, expected),
+            GameResult::Loss(_) => assert_eq!(expected, 0),
+            _ => panic!("unexpected result"),
+        }
+    }
+
+    #[test]
+    fn test_multi_race_ticket_uses_independent_draws() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Keno::init(&mut session, &mut rng);
+
+        let spots = [1, 2, 3, 4, 5];
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = Keno::process_move(&mut session, &play_payload(2, 10, 50, &spots), &mut rng)
+            .expect("play ticket");
+        assert_eq!(session.bet, 500);
+
+        let state = parse_state(&session.state_blob).expect("parse state");
+        assert_eq!(state.draws.len(), 10);
+        let rng = GameRng::new(&seed, session.id, 1);
+        for (race, drawn) in state.draws.iter().enumerate() {
+            assert_eq!(*drawn, draw_numbers(&mut rng.derive(race as u32)));
+        }
+        assert_ne!(state.draws[0], state.draws[1]);
+
+        let expected: u64 = state
+            .draws
+            .iter()
+            .map(|drawn| {
+                let catches = caught_numbers(&spots, drawn).len();
+                race_return(Paytable::Steady, spots.len(), catches, 50)
+            })
+            .sum();
+        match result {
+            GameResult::Win(amount, _) => assert_eq!(amount, expected),
+            GameResult::Loss(_) => assert_eq!(expected, 0),
+            _ => panic!("unexpected result"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_tickets_rejected() {
+        let seed = create_test_seed();
+        let invalid = [
+            play_payload(3, 1, 100, &[1, 2]),  // unknown paytable
+            play_payload(0, 0, 100, &[1, 2]),  // no races
+            play_payload(0, 21, 100, &[1, 2]), // too many races
+            play_payload(0, 1, 0, &[1, 2]),    // zero stake
+            play_payload(0, 1, 100, &[]),      // no spots
+            play_payload(0, 1, 100, &[1, 1]),  // duplicate spot
+            play_payload(0, 1, 100, &[0, 5]),  // off the board
+            play_payload(0, 1, 100, &[81]),    // off the board
+            play_payload(0, 1, 100, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+            play_payload(0, 2, u64::MAX, &[1]), // wager overflow
+            vec![1],                            // unknown action
+        ];
+        for payload in invalid {
+            let mut session = create_test_session(0);
+            let mut rng = GameRng::new(&seed, session.id, 0);
+            Keno::init(&mut session, &mut rng);
+            let mut rng = GameRng::new(&seed, session.id, 1);
+            assert!(Keno::process_move(&mut session, &payload, &mut rng).is_err());
+            assert!(!session.is_complete);
+        }
+
+        // Truncated spot list
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Keno::init(&mut session, &mut rng);
+        let mut payload = play_payload(0, 1, 100, &[1, 2, 3]);
+        payload.pop();
+        assert!(Keno::process_move(&mut session, &payload, &mut rng).is_err());
+
+        // A stake taken at start would be charged twice
+        let mut session = create_test_session(100);
+        Keno::init(&mut session, &mut rng);
+        assert!(matches!(
+            Keno::process_move(&mut session, &play_payload(0, 1, 100, &[1]), &mut rng),
+            Err(GameError::InvalidMove)
+        ));
+    }
+
+    #[test]
+    fn test_lightning_numbers_multiply_caught_spots() {
+        let seed = create_test_seed();
+        let mut rng = GameRng::new(&seed, 1, 1);
+        let drawn = draw_numbers(&mut rng.derive(0));
+        let spots = [drawn[0], drawn[1]];
+
+        let mut session = create_test_session(0);
+        session.super_mode = SuperModeState {
+            is_active: true,
+            multipliers: vec![
+                SuperMultiplier {
+                    id: drawn[0],
+                    multiplier: 3,
+                    super_type: SuperType::Number,
+                },
+                SuperMultiplier {
+                    id: drawn[1],
+                    multiplier: 2,
+                    super_type: SuperType::Number,
+                },
+            ],
+            streak_level: 0,
+            aura_meter: 0,
+        };
+        let mut init_rng = GameRng::new(&seed, session.id, 0);
+        Keno::init(&mut session, &mut init_rng);
+
+        let result = Keno::process_move(&mut session, &play_payload(0, 1, 100, &spots), &mut rng)
+            .expect("play ticket");
+        let base = race_return(Paytable::Classic, 2, 2, 100);
+        assert!(matches!(result, GameResult::Win(amount, _) if amount == base * 6));
+    }
+
+    #[test]
+    fn test_abandon_and_completion() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Keno::init(&mut session, &mut rng);
+        assert!(matches!(
+            Keno::abandon(&mut session, &mut rng),
+            Ok(GameResult::Push(0, _))
+        ));
+
+        Keno::process_move(&mut session, &play_payload(1, 3, 10, &[7, 8, 9]), &mut rng)
+            .expect("play ticket");
+        assert!(matches!(
+            Keno::process_move(&mut session, &play_payload(1, 3, 10, &[7, 8, 9]), &mut rng),
+            Err(GameError::GameAlreadyComplete)
+        ));
+        assert!(matches!(
+            Keno::abandon(&mut session, &mut rng),
+            Err(GameError::GameAlreadyComplete)
+        ));
+    }
+}
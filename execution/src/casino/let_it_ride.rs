@@ -0,0 +1,758 @@
+//! Let It Ride implementation.
+//!
+//! This implementation supports:
+//! - Three equal base bets (each equal to `session.bet`)
+//! - Two "pull back" decisions: Bet 1 after the player's three cards, Bet 2 after the first
+//!   community card; Bet 3 always stays in action
+//! - Optional 3-Card Bonus side bet on the player's three cards
+//! - Progressive reveal of community cards (no hidden cards stored before reveal)
+//!
+//! Each riding bet pays on the final five-card hand: royal flush 1000, straight flush 200,
+//! four of a kind 50, full house 11, flush 8, straight 5, three of a kind 3, two pair 2,
+//! pair of tens or better 1. Anything lower loses every riding bet; there is no dealer hand.
+//!
+//! State blob format (16 bytes):
+//! [version:u8=1]
+//! [stage:u8]
+//! [playerCard1:u8] [playerCard2:u8] [playerCard3:u8]  (0xFF if not dealt yet)
+//! [community1:u8] [community2:u8]                     (0xFF if unrevealed)
+//! [riding:u8]                                         (bit0 = Bet 1, bit1 = Bet 2 still in action)
+//! [bonusBetAmount:u64 BE]
+//!
+//! Stages:
+//! 0 = Betting (optional 3-Card Bonus, then Deal)
+//! 1 = FirstDecision (pull back Bet 1 or let it ride)
+//! 2 = SecondDecision (pull back Bet 2 or let it ride; resolves the hand)
+//! 3 = Complete
+//!
+//! Payload format:
+//! [action:u8] [optional amount:u64 BE]
+//! 0 = Deal (optional u64 = 3-Card Bonus bet)
+//! 1 = Set 3-Card Bonus bet (u64)
+//! 2 = Pull back the current bet
+//! 3 = Let it ride
+
+use super::logging::{clamp_i64, format_card_list, push_resolved_entry};
+use super::serialization::{StateReader, StateWriter};
+use super::super_mode::apply_super_multiplier_cards;
+use super::three_card;
+use super::ultimate_holdem::{evaluate_5_card_fast, HandRank};
+use super::{CasinoGame, GameError, GameResult, GameRng};
+use nullspace_types::casino::GameSession;
+
+const STATE_VERSION: u8 = 1;
+const STATE_LEN: usize = 16;
+const CARD_UNKNOWN: u8 = 0xFF;
+
+const RIDE_BET_1: u8 = 0b01;
+const RIDE_BET_2: u8 = 0b10;
+const RIDE_ALL: u8 = RIDE_BET_1 | RIDE_BET_2;
+
+/// Lowest pair that pays (tens, ace-high ranks).
+const MIN_PAYING_PAIR: u8 = 10;
+
+/// Payout multipliers (expressed as "to 1").
+mod payouts {
+    // Base bets
+    pub const ROYAL_FLUSH: u64 = 1000;
+    pub const STRAIGHT_FLUSH: u64 = 200;
+    pub const FOUR_OF_A_KIND: u64 = 50;
+    pub const FULL_HOUSE: u64 = 11;
+    pub const FLUSH: u64 = 8;
+    pub const STRAIGHT: u64 = 5;
+    pub const THREE_OF_A_KIND: u64 = 3;
+    pub const TWO_PAIR: u64 = 2;
+    pub const TENS_OR_BETTER: u64 = 1;
+
+    // 3-Card Bonus
+    pub const BONUS_STRAIGHT_FLUSH: u64 = 40;
+    pub const BONUS_THREE_OF_A_KIND: u64 = 30;
+    pub const BONUS_STRAIGHT: u64 = 6;
+    pub const BONUS_FLUSH: u64 = 3;
+    pub const BONUS_PAIR: u64 = 1;
+}
+
+/// Game stages.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Betting = 0,
+    FirstDecision = 1,
+    SecondDecision = 2,
+    Complete = 3,
+}
+
+impl TryFrom<u8> for Stage {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Stage::Betting),
+            1 => Ok(Stage::FirstDecision),
+            2 => Ok(Stage::SecondDecision),
+            3 => Ok(Stage::Complete),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+/// Player actions.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Deal = 0,
+    SetBonus = 1,
+    PullBack = 2,
+    LetItRide = 3,
+}
+
+impl TryFrom<u8> for Action {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Action::Deal),
+            1 => Ok(Action::SetBonus),
+            2 => Ok(Action::PullBack),
+            3 => Ok(Action::LetItRide),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct LirState {
+    stage: Stage,
+    player: [u8; 3],
+    community: [u8; 2],
+    riding: u8,
+    bonus_bet: u64,
+}
+
+fn parse_state(state: &[u8]) -> Option<LirState> {
+    if state.len() != STATE_LEN {
+        return None;
+    }
+
+    let mut reader = StateReader::new(state);
+    let version = reader.read_u8()?;
+    if version != STATE_VERSION {
+        return None;
+    }
+    let stage = Stage::try_from(reader.read_u8()?).ok()?;
+    let player: [u8; 3] = reader.read_bytes(3)?.try_into().ok()?;
+    let community: [u8; 2] = reader.read_bytes(2)?.try_into().ok()?;
+    let riding = reader.read_u8()?;
+    if riding & !RIDE_ALL != 0 {
+        return None;
+    }
+    if player
+        .iter()
+        .chain(community.iter())
+        .any(|&card| card >= 52 && card != CARD_UNKNOWN)
+    {
+        return None;
+    }
+    let bonus_bet = reader.read_u64_be()?;
+
+    Some(LirState {
+        stage,
+        player,
+        community,
+        riding,
+        bonus_bet,
+    })
+}
+
+fn serialize_state(state: &LirState) -> Vec<u8> {
+    let mut out = StateWriter::with_capacity(STATE_LEN);
+    out.push_u8(STATE_VERSION);
+    out.push_u8(state.stage as u8);
+    out.push_bytes(&state.player);
+    out.push_bytes(&state.community);
+    out.push_u8(state.riding);
+    out.push_u64_be(state.bonus_bet);
+    out.into_inner()
+}
+
+fn is_known_card(card: u8) -> bool {
+    card < 52
+}
+
+fn known_cards_in_state(state: &LirState) -> Vec<u8> {
+    state
+        .player
+        .iter()
+        .chain(state.community.iter())
+        .copied()
+        .filter(|&card| is_known_card(card))
+        .collect()
+}
+
+fn apply_bonus_update(state: &mut LirState, new_bet: u64) -> Result<i64, GameError> {
+    let old = state.bonus_bet as i128;
+    let new = new_bet as i128;
+    let delta = new - old;
+    if delta > i64::MAX as i128 || delta < i64::MIN as i128 {
+        return Err(GameError::InvalidMove);
+    }
+    state.bonus_bet = new_bet;
+    // Deduct positive increases, refund decreases.
+    Ok(-(delta as i64))
+}
+
+/// Reveal community cards up to and including `last`.
+fn reveal_community(state: &mut LirState, rng: &mut GameRng, last: usize) -> Result<(), GameError> {
+    let used = known_cards_in_state(state);
+    let mut deck = rng.create_deck_excluding(&used);
+    for card in state.community.iter_mut().take(last + 1) {
+        if !is_known_card(*card) {
+            *card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
+        }
+    }
+    Ok(())
+}
+
+/// Base-bet multiplier (to 1) for the final hand, or 0 if it does not pay.
+fn base_multiplier(hand: &(HandRank, [u8; 5])) -> u64 {
+    match hand.0 {
+        HandRank::RoyalFlush => payouts::ROYAL_FLUSH,
+        HandRank::StraightFlush => payouts::STRAIGHT_FLUSH,
+        HandRank::FourOfAKind => payouts::FOUR_OF_A_KIND,
+        HandRank::FullHouse => payouts::FULL_HOUSE,
+        HandRank::Flush => payouts::FLUSH,
+        HandRank::Straight => payouts::STRAIGHT,
+        HandRank::ThreeOfAKind => payouts::THREE_OF_A_KIND,
+        HandRank::TwoPair => payouts::TWO_PAIR,
+        HandRank::Pair if hand.1[0] >= MIN_PAYING_PAIR => payouts::TENS_OR_BETTER,
+        HandRank::Pair | HandRank::HighCard => 0,
+    }
+}
+
+fn bonus_multiplier(rank: three_card::HandRank) -> u64 {
+    match rank {
+        three_card::HandRank::StraightFlush => payouts::BONUS_STRAIGHT_FLUSH,
+        three_card::HandRank::ThreeOfAKind => payouts::BONUS_THREE_OF_A_KIND,
+        three_card::HandRank::Straight => payouts::BONUS_STRAIGHT,
+        three_card::HandRank::Flush => payouts::BONUS_FLUSH,
+        three_card::HandRank::Pair => payouts::BONUS_PAIR,
+        three_card::HandRank::HighCard => 0,
+    }
+}
+
+fn hand_rank_str(rank: HandRank) -> &'static str {
+    match rank {
+        HandRank::HighCard => "HIGH_CARD",
+        HandRank::Pair => "PAIR",
+        HandRank::TwoPair => "TWO_PAIR",
+        HandRank::ThreeOfAKind => "THREE_OF_A_KIND",
+        HandRank::Straight => "STRAIGHT",
+        HandRank::Flush => "FLUSH",
+        HandRank::FullHouse => "FULL_HOUSE",
+        HandRank::FourOfAKind => "FOUR_OF_A_KIND",
+        HandRank::StraightFlush => "STRAIGHT_FLUSH",
+        HandRank::RoyalFlush => "ROYAL_FLUSH",
+    }
+}
+
+fn bonus_rank_str(rank: three_card::HandRank) -> &'static str {
+    match rank {
+        three_card::HandRank::HighCard => "HIGH_CARD",
+        three_card::HandRank::Pair => "PAIR",
+        three_card::HandRank::Flush => "FLUSH",
+        three_card::HandRank::Straight => "STRAIGHT",
+        three_card::HandRank::ThreeOfAKind => "THREE_OF_A_KIND",
+        three_card::HandRank::StraightFlush => "STRAIGHT_FLUSH",
+    }
+}
+
+/// Settle the riding bets and the 3-Card Bonus.
+///
+/// `refund` is a bet pulled back by the move that completes the hand; it is credited with the
+/// result but is not part of the settled wager.
+fn resolve_hand(
+    session: &mut GameSession,
+    state: &mut LirState,
+    refund: u64,
+) -> Result<GameResult, GameError> {
+    if !state
+        .player
+        .iter()
+        .chain(state.community.iter())
+        .all(|&card| is_known_card(card))
+    {
+        return Err(GameError::InvalidState);
+    }
+
+    let bet = session.bet;
+    let bonus_bet = state.bonus_bet;
+    let riding_bets = [
+        ("BET 1", state.riding & RIDE_BET_1 != 0),
+        ("BET 2", state.riding & RIDE_BET_2 != 0),
+        ("BET 3", true),
+    ];
+    let riding_count = riding_bets.iter().filter(|(_, riding)| *riding).count() as u64;
+    let base_wagered = bet.saturating_mul(riding_count);
+    let total_wagered = base_wagered.saturating_add(bonus_bet);
+
+    let cards = [
+        state.player[0],
+        state.player[1],
+        state.player[2],
+        state.community[0],
+        state.community[1],
+    ];
+    let hand = evaluate_5_card_fast(&cards);
+    let mult = base_multiplier(&hand);
+    let mut bet_return = if mult > 0 {
+        bet.saturating_mul(mult.saturating_add(1))
+    } else {
+        0
+    };
+
+    let bonus_rank = three_card::evaluate_hand(&state.player).0;
+    let mut bonus_return: u64 = 0;
+    if bonus_bet > 0 {
+        let mult = bonus_multiplier(bonus_rank);
+        if mult > 0 {
+            bonus_return = bonus_bet.saturating_mul(mult.saturating_add(1));
+        }
+    }
+
+    // Apply super mode multiplier (if any) to winning returns.
+    if session.super_mode.is_active {
+        let multipliers = &session.super_mode.multipliers;
+        if bet_return > 0 {
+            bet_return = apply_super_multiplier_cards(&cards, multipliers, bet_return);
+        }
+        if bonus_return > 0 {
+            bonus_return = apply_super_multiplier_cards(&state.player, multipliers, bonus_return);
+        }
+    }
+
+    let base_return = bet_return.saturating_mul(riding_count);
+    let total_return = base_return.saturating_add(bonus_return);
+
+    state.stage = Stage::Complete;
+    session.is_complete = true;
+
+    // Generate logs for frontend display
+    let mut resolved_entries = String::with_capacity(256);
+    let mut resolved_sum: i128 = 0;
+    let bet_pnl = clamp_i64(i128::from(bet_return) - i128::from(bet));
+    for (label, riding) in &riding_bets {
+        if *riding {
+            push_resolved_entry(&mut resolved_entries, label, bet_pnl);
+            resolved_sum = resolved_sum.saturating_add(i128::from(bet_pnl));
+        }
+    }
+    if bonus_bet > 0 {
+        let pnl = clamp_i64(i128::from(bonus_return) - i128::from(bonus_bet));
+        push_resolved_entry(&mut resolved_entries, "3 CARD BONUS", pnl);
+        resolved_sum = resolved_sum.saturating_add(i128::from(pnl));
+    }
+    let net_pnl = clamp_i64(i128::from(total_return) - i128::from(total_wagered));
+    let diff = i128::from(net_pnl).saturating_sub(resolved_sum);
+    if diff != 0 {
+        push_resolved_entry(&mut resolved_entries, "ADJUSTMENT", clamp_i64(diff));
+    }
+    let summary = format!(
+        "P: {}, {} of 3 bets riding",
+        hand_rank_str(hand.0).replace('_', " "),
+        riding_count
+    );
+
+    let logs = vec![format!(
+        r#"{{"summary":"{}","netPnl":{},"resolvedBets":[{}],"player":{{"cards":[{}],"rank":"{}","bonusRank":"{}"}},"community":[{}],"outcome":"{}","bet":{},"betsRiding":{},"bet1Riding":{},"bet2Riding":{},"bonusBet":{},"betReturn":{},"bonusReturn":{},"pulledBack":{},"totalWagered":{},"totalReturn":{}}}"#,
+        summary,
+        net_pnl,
+        resolved_entries,
+        format_card_list(&state.player),
+        hand_rank_str(hand.0),
+        bonus_rank_str(bonus_rank),
+        format_card_list(&state.community),
+        if mult > 0 { "WIN" } else { "LOSS" },
+        bet,
+        riding_count,
+        riding_bets[0].1,
+        riding_bets[1].1,
+        bonus_bet,
+        base_return,
+        bonus_return,
+        refund,
+        total_wagered,
+        total_return
+    )];
+
+    let credited = total_return.saturating_add(refund);
+    if credited == 0 {
+        Ok(GameResult::LossPreDeducted(total_wagered, logs))
+    } else {
+        Ok(GameResult::Win(credited, logs))
+    }
+}
+
+pub struct LetItRide;
+
+impl CasinoGame for LetItRide {
+    fn init(session: &mut GameSession, _rng: &mut GameRng) -> GameResult {
+        // Start in a betting stage so the 3-Card Bonus can be placed before any cards are dealt.
+        // Bet 3 was deducted by CasinoStartGame; deduct Bets 1 and 2 here.
+        let state = LirState {
+            stage: Stage::Betting,
+            player: [CARD_UNKNOWN; 3],
+            community: [CARD_UNKNOWN; 2],
+            riding: RIDE_ALL,
+            bonus_bet: 0,
+        };
+        session.state_blob = serialize_state(&state);
+        GameResult::ContinueWithUpdate {
+            payout: -(session.bet.saturating_mul(2) as i64),
+            logs: vec![],
+        }
+    }
+
+    fn process_move(
+        session: &mut GameSession,
+        payload: &[u8],
+        rng: &mut GameRng,
+    ) -> Result<GameResult, GameError> {
+        if session.is_complete {
+            return Err(GameError::GameAlreadyComplete);
+        }
+        if payload.is_empty() {
+            return Err(GameError::InvalidPayload);
+        }
+
+        let action = Action::try_from(payload[0])?;
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidPayload)?;
+
+        match state.stage {
+            Stage::Betting => {
+                let payout_update = match action {
+                    Action::SetBonus => {
+                        let new_bet = super::payload::parse_u64_be(payload, 1)?;
+                        apply_bonus_update(&mut state, new_bet)?
+                    }
+                    Action::Deal => {
+                        let payout_update = match payload.len() {
+                            1 => 0,
+                            9 => {
+                                let new_bet = super::payload::parse_u64_be(payload, 1)?;
+                                apply_bonus_update(&mut state, new_bet)?
+                            }
+                            _ => return Err(GameError::InvalidPayload),
+                        };
+
+                        let mut deck = rng.create_deck();
+                        for card in &mut state.player {
+                            *card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
+                        }
+                        state.stage = Stage::FirstDecision;
+                        payout_update
+                    }
+                    _ => return Err(GameError::InvalidMove),
+                };
+                session.state_blob = serialize_state(&state);
+                Ok(if payout_update == 0 {
+                    GameResult::Continue(vec![])
+                } else {
+                    GameResult::ContinueWithUpdate {
+                        payout: payout_update,
+                        logs: vec![],
+                    }
+                })
+            }
+            Stage::FirstDecision => {
+                if payload.len() != 1 {
+                    return Err(GameError::InvalidPayload);
+                }
+                let refund = match action {
+                    Action::PullBack => {
+                        state.riding &= !RIDE_BET_1;
+                        session.bet
+                    }
+                    Action::LetItRide => 0,
+                    _ => return Err(GameError::InvalidMove),
+                };
+                reveal_community(&mut state, rng, 0)?;
+                state.stage = Stage::SecondDecision;
+                session.state_blob = serialize_state(&state);
+                Ok(if refund == 0 {
+                    GameResult::Continue(vec![])
+                } else {
+                    GameResult::ContinueWithUpdate {
+                        payout: refund as i64,
+                        logs: vec![],
+                    }
+                })
+            }
+            Stage::SecondDecision => {
+                if payload.len() != 1 {
+                    return Err(GameError::InvalidPayload);
+                }
+                let refund = match action {
+                    Action::PullBack => {
+                        state.riding &= !RIDE_BET_2;
+                        session.bet
+                    }
+                    Action::LetItRide => 0,
+                    _ => return Err(GameError::InvalidMove),
+                };
+                reveal_community(&mut state, rng, 1)?;
+                let result = resolve_hand(session, &mut state, refund)?;
+                session.state_blob = serialize_state(&state);
+                Ok(result)
+            }
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.stage {
+            Stage::Betting => {
+                // No cards dealt yet: return all three bets and the 3-Card Bonus.
+                let refund = session
+                    .bet
+                    .saturating_mul(3)
+                    .saturating_add(state.bonus_bet);
+                Ok(GameResult::Push(refund, vec![]))
+            }
+            Stage::FirstDecision => {
+                // Pull back both bets and settle in one step so neither refund is lost.
+                state.riding = 0;
+                reveal_community(&mut state, rng, 1)?;
+                let result = resolve_hand(session, &mut state, session.bet.saturating_mul(2))?;
+                session.state_blob = serialize_state(&state);
+                Ok(result)
+            }
+            Stage::SecondDecision => Self::process_move(session, &[Action::PullBack as u8], rng),
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_must_use)]
+mod tests {
+    use super::*;
+    use crate::mocks::{create_account_keypair, create_network_keypair, create_seed};
+    use nullspace_types::casino::GameType;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn create_test_seed() -> nullspace_types::Seed {
+        let (network_secret, _) = create_network_keypair();
+        create_seed(&network_secret, 1)
+    }
+
+    fn create_test_session(bet: u64) -> GameSession {
+        let (_, pk) = create_account_keypair(1);
+        GameSession {
+            id: 1,
+            player: pk,
+            game_type: GameType::LetItRide,
+            bet,
+            state_blob: vec![],
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: nullspace_types::casino::SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        }
+    }
+
+    fn dealt_state(player: [u8; 3], community: [u8; 2], riding: u8, bonus_bet: u64) -> LirState {
+        LirState {
+            stage: Stage::SecondDecision,
+            player,
+            community,
+            riding,
+            bonus_bet,
+        }
+    }
+
+    #[test]
+    fn test_base_pay_table() {
+        // Spades are 0..13, hearts 13..26 (rank = card % 13, Ace = 0).
+        let cases = [
+            ([0, 9, 10, 11, 12], payouts::ROYAL_FLUSH),
+            ([1, 2, 3, 4, 5], payouts::STRAIGHT_FLUSH),
+            ([0, 13, 26, 39, 5], payouts::FOUR_OF_A_KIND),
+            ([9, 22, 35, 10, 23], payouts::FULL_HOUSE),
+            ([0, 2, 4, 6, 8], payouts::FLUSH),
+            ([1, 15, 3, 17, 5], payouts::STRAIGHT),
+            ([4, 17, 30, 6, 21], payouts::THREE_OF_A_KIND),
+            ([1, 14, 5, 18, 20], payouts::TWO_PAIR),
+            ([9, 22, 1, 16, 44], payouts::TENS_OR_BETTER),
+            ([8, 21, 1, 16, 44], 0),
+            ([0, 15, 30, 45, 7], 0),
+        ];
+        for (cards, expected) in cases {
+            let hand = evaluate_5_card_fast(&cards);
+            assert_eq!(base_multiplier(&hand), expected, "cards {cards:?}");
+        }
+    }
+
+    #[test]
+    fn test_bonus_pay_table() {
+        let cases = [
+            ([1, 2, 3], payouts::BONUS_STRAIGHT_FLUSH),
+            ([4, 17, 30], payouts::BONUS_THREE_OF_A_KIND),
+            ([1, 15, 3], payouts::BONUS_STRAIGHT),
+            ([0, 5, 9], payouts::BONUS_FLUSH),
+            ([4, 17, 9], payouts::BONUS_PAIR),
+            ([0, 15, 30], 0),
+        ];
+        for (cards, expected) in cases {
+            let rank = three_card::evaluate_hand(&cards).0;
+            assert_eq!(bonus_multiplier(rank), expected, "cards {cards:?}");
+        }
+    }
+
+    #[test]
+    fn test_init_deducts_two_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+
+        let result = LetItRide::init(&mut session, &mut rng);
+        assert!(matches!(
+            result,
+            GameResult::ContinueWithUpdate { payout: -200, .. }
+        ));
+        let state = parse_state(&session.state_blob).expect("parse state");
+        assert_eq!(state.stage, Stage::Betting);
+        assert_eq!(state.riding, RIDE_ALL);
+    }
+
+    #[test]
+    fn test_winning_hand_pays_each_riding_bet() {
+        let mut session = create_test_session(100);
+        // Two pair (2s and 6s) with Bet 1 pulled back: two bets win 2:1.
+        let mut state = dealt_state([1, 14, 5], [18, 20], RIDE_BET_2, 0);
+
+        let result = resolve_hand(&mut session, &mut state, 0).expect("resolve");
+        assert!(matches!(result, GameResult::Win(600, _)));
+        assert!(session.is_complete);
+        assert_eq!(state.stage, Stage::Complete);
+    }
+
+    #[test]
+    fn test_pull_back_refund_is_credited_on_loss() {
+        let mut session = create_test_session(100);
+        // Pair of nines does not pay; Bet 2 was pulled back by the resolving move.
+        let mut state = dealt_state([8, 21, 1], [16, 44], 0, 0);
+
+        let result = resolve_hand(&mut session, &mut state, 100).expect("resolve");
+        assert!(matches!(result, GameResult::Win(100, _)));
+
+        let mut session = create_test_session(100);
+        let mut state = dealt_state([8, 21, 1], [16, 44], RIDE_ALL, 0);
+        let result = resolve_hand(&mut session, &mut state, 0).expect("resolve");
+        assert!(matches!(result, GameResult::LossPreDeducted(300, _)));
+    }
+
+    #[test]
+    fn test_bonus_pays_without_base_win() {
+        let mut session = create_test_session(100);
+        // Flush draw in the first three cards that misses: bonus pays 3:1, base bets lose.
+        let mut state = dealt_state([0, 5, 8], [15, 30], 0, 10);
+
+        let result = resolve_hand(&mut session, &mut state, 0).expect("resolve");
+        assert!(matches!(result, GameResult::Win(40, _)));
+    }
+
+    #[test]
+    fn test_full_flow_with_bonus() -> Result<(), GameError> {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        LetItRide::init(&mut session, &mut rng);
+
+        let mut payload = vec![Action::Deal as u8];
+        payload.extend_from_slice(&25u64.to_be_bytes());
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = LetItRide::process_move(&mut session, &payload, &mut rng)?;
+        assert!(matches!(
+            result,
+            GameResult::ContinueWithUpdate { payout: -25, .. }
+        ));
+        let state = parse_state(&session.state_blob).expect("parse state");
+        assert_eq!(state.stage, Stage::FirstDecision);
+        assert!(state.player.iter().all(|&c| is_known_card(c)));
+        assert!(state.community.iter().all(|&c| c == CARD_UNKNOWN));
+
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let result = LetItRide::process_move(&mut session, &[Action::PullBack as u8], &mut rng)?;
+        assert!(matches!(
+            result,
+            GameResult::ContinueWithUpdate { payout: 100, .. }
+        ));
+        let state = parse_state(&session.state_blob).expect("parse state");
+        assert_eq!(state.stage, Stage::SecondDecision);
+        assert_eq!(state.riding, RIDE_BET_2);
+        assert!(is_known_card(state.community[0]));
+        assert_eq!(state.community[1], CARD_UNKNOWN);
+
+        let mut rng = GameRng::new(&seed, session.id, 3);
+        LetItRide::process_move(&mut session, &[Action::LetItRide as u8], &mut rng)?;
+        assert!(session.is_complete);
+        let state = parse_state(&session.state_blob).expect("parse state");
+        assert_eq!(state.stage, Stage::Complete);
+        let known = known_cards_in_state(&state);
+        assert_eq!(known.len(), 5);
+        for (i, card) in known.iter().enumerate() {
+            assert!(!known[i + 1..].contains(card));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_decisions_reject_extra_bytes_and_betting_actions() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        LetItRide::init(&mut session, &mut rng);
+        LetItRide::process_move(&mut session, &[Action::Deal as u8], &mut rng).expect("deal");
+
+        let result = LetItRide::process_move(&mut session, &[Action::PullBack as u8, 0], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        let result = LetItRide::process_move(&mut session, &[Action::Deal as u8], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+    }
+
+    #[test]
+    fn test_abandon_pulls_back_both_bets() -> Result<(), GameError> {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        LetItRide::init(&mut session, &mut rng);
+        LetItRide::process_move(&mut session, &[Action::Deal as u8], &mut rng)?;
+
+        let result = LetItRide::abandon(&mut session, &mut rng)?;
+        let credited = match result {
+            GameResult::Win(amount, _) => amount,
+            _ => panic!("expected the pulled-back bets to be credited"),
+        };
+        // Both pulled-back bets come back, plus whatever Bet 3 returns.
+        assert!(credited >= 200);
+        assert!(session.is_complete);
+        let state = parse_state(&session.state_blob).expect("parse state");
+        assert_eq!(state.riding, 0);
+        assert_eq!(state.stage, Stage::Complete);
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_blob_fuzz_does_not_panic() {
+        let mut rng = StdRng::seed_from_u64(0x1e7_1d3);
+        for _ in 0..1_000 {
+            let len = rng.gen_range(0..=64);
+            let mut blob = vec![0u8; len];
+            rng.fill(&mut blob[..]);
+            let _ = parse_state(&blob);
+        }
+    }
+}
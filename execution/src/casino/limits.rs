@@ -4,6 +4,9 @@
 //! If we want runtime configurability, move them into on-chain policy with explicit versioning.
 
 pub const BACCARAT_MAX_BETS: usize = 11;
+pub const BIG_SIX_MAX_BETS: usize = 20;
 pub const CRAPS_MAX_BETS: usize = 20;
+pub const CRASH_MAX_BETS: usize = 20;
+pub const KENO_MAX_RACES: usize = 20;
 pub const ROULETTE_MAX_BETS: usize = 20;
 pub const SIC_BO_MAX_BETS: usize = 20;
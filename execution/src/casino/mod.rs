@@ -11,17 +11,28 @@
 //! - Sic Bo
 //! - Craps
 //! - Casino War
+//! - Pai Gow Poker
+//! - Let It Ride
+//! - Keno
+//! - Big Six
+//! - Crash
 
 pub mod baccarat;
+pub mod big_six;
 pub mod blackjack;
 pub(crate) mod cards;
 pub mod casino_war;
 pub mod craps;
+pub mod crash;
+pub(crate) mod global_table;
 pub mod hilo;
 #[cfg(test)]
 mod integration_tests;
+pub mod keno;
+pub mod let_it_ride;
 pub(crate) mod logging;
 pub mod limits;
+pub mod pai_gow;
 pub(crate) mod payload;
 pub mod roulette;
 pub(crate) mod serialization;
@@ -70,6 +81,20 @@ impl GameRng {
         self.state
     }
 
+    /// Derive an independent child stream, e.g. one per Keno race in a multi-race ticket.
+    ///
+    /// The child is seeded from the current state and the stream index, so it does not
+    /// advance this RNG and distinct indices never share a sequence.
+    pub fn derive(&self, stream: u32) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(&self.state);
+        hasher.update(&stream.to_be_bytes());
+        Self {
+            state: hasher.finalize().0,
+            index: 0,
+        }
+    }
+
     /// Get the next random byte.
     fn next_byte(&mut self) -> u8 {
         if self.index >= 32 {
@@ -380,6 +405,10 @@ pub trait CasinoGame {
         payload: &[u8],
         rng: &mut GameRng,
     ) -> Result<GameResult, GameError>;
+
+    /// Resolve a session the player stopped acting on.
+    /// Applies the game's abandonment policy and returns a completing result.
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError>;
 }
 
 /// Dispatch game initialization to the appropriate game module.
@@ -389,7 +418,12 @@ pub fn init_game(session: &mut GameSession, rng: &mut GameRng) -> GameResult {
         GameType::Blackjack => blackjack::Blackjack::init(session, rng),
         GameType::CasinoWar => casino_war::CasinoWar::init(session, rng),
         GameType::Craps => craps::Craps::init(session, rng),
+        GameType::Crash => crash::Crash::init(session, rng),
         GameType::HiLo => hilo::HiLo::init(session, rng),
+        GameType::PaiGow => pai_gow::PaiGow::init(session, rng),
+        GameType::LetItRide => let_it_ride::LetItRide::init(session, rng),
+        GameType::Keno => keno::Keno::init(session, rng),
+        GameType::BigSix => big_six::BigSix::init(session, rng),
         GameType::Roulette => roulette::Roulette::init(session, rng),
         GameType::SicBo => sic_bo::SicBo::init(session, rng),
         GameType::ThreeCard => three_card::ThreeCardPoker::init(session, rng),
@@ -409,7 +443,12 @@ pub fn process_game_move(
         GameType::Blackjack => blackjack::Blackjack::process_move(session, payload, rng),
         GameType::CasinoWar => casino_war::CasinoWar::process_move(session, payload, rng),
         GameType::Craps => craps::Craps::process_move(session, payload, rng),
+        GameType::Crash => crash::Crash::process_move(session, payload, rng),
         GameType::HiLo => hilo::HiLo::process_move(session, payload, rng),
+        GameType::PaiGow => pai_gow::PaiGow::process_move(session, payload, rng),
+        GameType::LetItRide => let_it_ride::LetItRide::process_move(session, payload, rng),
+        GameType::Keno => keno::Keno::process_move(session, payload, rng),
+        GameType::BigSix => big_six::BigSix::process_move(session, payload, rng),
         GameType::Roulette => roulette::Roulette::process_move(session, payload, rng),
         GameType::SicBo => sic_bo::SicBo::process_move(session, payload, rng),
         GameType::ThreeCard => three_card::ThreeCardPoker::process_move(session, payload, rng),
@@ -420,6 +459,27 @@ pub fn process_game_move(
     }
 }
 
+/// Dispatch abandoned-session resolution to the appropriate game module.
+pub fn abandon_game(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+    match session.game_type {
+        GameType::Baccarat => baccarat::Baccarat::abandon(session, rng),
+        GameType::Blackjack => blackjack::Blackjack::abandon(session, rng),
+        GameType::CasinoWar => casino_war::CasinoWar::abandon(session, rng),
+        GameType::Craps => craps::Craps::abandon(session, rng),
+        GameType::Crash => crash::Crash::abandon(session, rng),
+        GameType::HiLo => hilo::HiLo::abandon(session, rng),
+        GameType::PaiGow => pai_gow::PaiGow::abandon(session, rng),
+        GameType::LetItRide => let_it_ride::LetItRide::abandon(session, rng),
+        GameType::Keno => keno::Keno::abandon(session, rng),
+        GameType::BigSix => big_six::BigSix::abandon(session, rng),
+        GameType::Roulette => roulette::Roulette::abandon(session, rng),
+        GameType::SicBo => sic_bo::SicBo::abandon(session, rng),
+        GameType::ThreeCard => three_card::ThreeCardPoker::abandon(session, rng),
+        GameType::UltimateHoldem => ultimate_holdem::UltimateHoldem::abandon(session, rng),
+        GameType::VideoPoker => video_poker::VideoPoker::abandon(session, rng),
+    }
+}
+
 /// Apply modifiers (shield/double) to a game outcome.
 ///
 /// Returns `(final_payout, was_shielded, was_doubled)`.
@@ -476,7 +536,12 @@ pub fn generate_super_multipliers(
         GameType::ThreeCard => super_mode::generate_three_card_multipliers(rng),
         GameType::UltimateHoldem => super_mode::generate_uth_multipliers(rng),
         GameType::CasinoWar => super_mode::generate_casino_war_multipliers(rng),
+        GameType::PaiGow => super_mode::generate_pai_gow_multipliers(rng),
+        GameType::LetItRide => super_mode::generate_let_it_ride_multipliers(rng),
+        GameType::Keno => super_mode::generate_keno_multipliers(rng),
+        GameType::BigSix => super_mode::generate_big_six_multipliers(rng),
         GameType::HiLo => Vec::new(), // HiLo uses streak-based system
+        GameType::Crash => Vec::new(), // Crash payouts already scale with the multiplier
     }
 }
 
@@ -587,6 +652,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_game_rng_derive() {
+        let seed = create_test_seed();
+        let rng = GameRng::new(&seed, 1, 0);
+
+        // Derived streams are deterministic and independent of each other
+        let mut a1 = rng.derive(0);
+        let mut a2 = rng.derive(0);
+        let mut b = rng.derive(1);
+        let seq_a1: Vec<u8> = (0..32).map(|_| a1.next_u8()).collect();
+        let seq_a2: Vec<u8> = (0..32).map(|_| a2.next_u8()).collect();
+        let seq_b: Vec<u8> = (0..32).map(|_| b.next_u8()).collect();
+        assert_eq!(seq_a1, seq_a2);
+        assert_ne!(seq_a1, seq_b);
+
+        // Deriving does not advance the parent
+        assert_eq!(rng.state(), GameRng::new(&seed, 1, 0).state());
+    }
+
     #[test]
     fn test_game_rng_f32_precision() {
         let seed = create_test_seed();
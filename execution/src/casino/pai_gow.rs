@@ -0,0 +1,1012 @@
+//! Pai Gow Poker implementation.
+//!
+//! This implementation supports:
+//! - A 53-card deck (standard deck plus one joker)
+//! - Player-set split of seven cards into a 5-card high hand and a 2-card low hand
+//! - House-way setting for the dealer (and for players who ask for it)
+//! - 5% commission on winning main bets (rounded up)
+//! - Optional Fortune side bet (pay table below)
+//! - Dealer cards are only drawn once the player's hand is set
+//!
+//! The joker is wild for straights and flushes and otherwise plays as an ace; in the 2-card hand
+//! it is always an ace. Four aces plus the joker make Five Aces, the top hand. Hands are ranked
+//! with the Ultimate Texas Hold'em evaluator, so A-2-3-4-5 is the lowest straight. The high hand
+//! must outrank the low hand, and copies (equal hands) go to the dealer.
+//!
+//! State blob format (28 bytes):
+//! [version:u8=1]
+//! [stage:u8]
+//! [fortuneBet:u64 BE]
+//! [playerCards:u8×7]                  (0xFF if not dealt yet; 52 = joker)
+//! [playerLow1:u8] [playerLow2:u8]     (indices into playerCards of the 2-card hand; 0xFF if unset)
+//! [dealerCards:u8×7]                  (0xFF if unrevealed)
+//! [dealerLow1:u8] [dealerLow2:u8]     (indices into dealerCards; 0xFF if unrevealed)
+//!
+//! Stages:
+//! 0 = Betting (optional Fortune, then Deal)
+//! 1 = Setting (player splits their seven cards)
+//! 2 = Complete
+//!
+//! Payload format:
+//! [action:u8] [optional payload]
+//! 0 = Deal (optional u64 = Fortune bet)
+//! 1 = Set Fortune bet (u64)
+//! 2 = Set hand [low1:u8] [low2:u8] (reveal dealer and resolve)
+//! 3 = Set hand the house way (reveal dealer and resolve)
+//!
+//! Fortune pays (to 1) on the player's seven cards:
+//! 7-card straight flush without joker 8000, 7-card straight flush with joker 1000,
+//! Five Aces 400, royal flush 150, straight flush 50, four of a kind 25, full house 5,
+//! flush 4, three of a kind 3, straight 2.
+
+use super::logging::{clamp_i64, format_card_list, push_resolved_entry};
+use super::serialization::{StateReader, StateWriter};
+use super::super_mode::apply_super_multiplier_cards;
+use super::ultimate_holdem::{evaluate_5_card_fast, HandRank};
+use super::{cards, CasinoGame, GameError, GameResult, GameRng};
+use nullspace_types::casino::GameSession;
+
+const STATE_VERSION: u8 = 1;
+const STATE_LEN: usize = 28;
+const CARD_UNKNOWN: u8 = 0xFF;
+/// The joker follows the 52 standard cards.
+const JOKER: u8 = 52;
+const HAND_SIZE: usize = 7;
+const COMMISSION_PERCENT: u64 = 5;
+
+/// Hand category above Royal Flush for four aces plus the joker.
+const FIVE_ACES: u8 = HandRank::RoyalFlush as u8 + 1;
+
+/// Fortune pays (to 1) indexed by five-card hand category, from High Card up to Five Aces.
+const FORTUNE_PAYS: [u64; 11] = [0, 0, 0, 3, 2, 4, 5, 25, 50, 150, 400];
+const FORTUNE_SEVEN_CARD_STRAIGHT_FLUSH: u64 = 8000;
+const FORTUNE_SEVEN_CARD_STRAIGHT_FLUSH_JOKER: u64 = 1000;
+
+/// Hand category (a `HandRank` value or `FIVE_ACES`) and tiebreak ranks.
+type HandValue = (u8, [u8; 5]);
+
+/// Game stages.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Betting = 0,
+    Setting = 1,
+    Complete = 2,
+}
+
+impl TryFrom<u8> for Stage {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Stage::Betting),
+            1 => Ok(Stage::Setting),
+            2 => Ok(Stage::Complete),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+/// Player actions.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Deal = 0,
+    SetFortune = 1,
+    SetHand = 2,
+    HouseWay = 3,
+}
+
+impl TryFrom<u8> for Action {
+    type Error = GameError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Action::Deal),
+            1 => Ok(Action::SetFortune),
+            2 => Ok(Action::SetHand),
+            3 => Ok(Action::HouseWay),
+            _ => Err(GameError::InvalidPayload),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PaiGowState {
+    stage: Stage,
+    fortune_bet: u64,
+    player: [u8; HAND_SIZE],
+    player_low: [u8; 2],
+    dealer: [u8; HAND_SIZE],
+    dealer_low: [u8; 2],
+}
+
+fn parse_state(state: &[u8]) -> Option<PaiGowState> {
+    if state.len() != STATE_LEN {
+        return None;
+    }
+
+    let mut reader = StateReader::new(state);
+    let version = reader.read_u8()?;
+    if version != STATE_VERSION {
+        return None;
+    }
+    let stage = Stage::try_from(reader.read_u8()?).ok()?;
+    let fortune_bet = reader.read_u64_be()?;
+    let player: [u8; HAND_SIZE] = reader.read_bytes(HAND_SIZE)?.try_into().ok()?;
+    let player_low: [u8; 2] = reader.read_bytes(2)?.try_into().ok()?;
+    let dealer: [u8; HAND_SIZE] = reader.read_bytes(HAND_SIZE)?.try_into().ok()?;
+    let dealer_low: [u8; 2] = reader.read_bytes(2)?.try_into().ok()?;
+    if player
+        .iter()
+        .chain(dealer.iter())
+        .any(|&card| card > JOKER && card != CARD_UNKNOWN)
+    {
+        return None;
+    }
+    if player_low
+        .iter()
+        .chain(dealer_low.iter())
+        .any(|&idx| idx as usize >= HAND_SIZE && idx != CARD_UNKNOWN)
+    {
+        return None;
+    }
+
+    Some(PaiGowState {
+        stage,
+        fortune_bet,
+        player,
+        player_low,
+        dealer,
+        dealer_low,
+    })
+}
+
+fn serialize_state(state: &PaiGowState) -> Vec<u8> {
+    let mut out = StateWriter::with_capacity(STATE_LEN);
+    out.push_u8(STATE_VERSION);
+    out.push_u8(state.stage as u8);
+    out.push_u64_be(state.fortune_bet);
+    out.push_bytes(&state.player);
+    out.push_bytes(&state.player_low);
+    out.push_bytes(&state.dealer);
+    out.push_bytes(&state.dealer_low);
+    out.into_inner()
+}
+
+fn is_known_card(card: u8) -> bool {
+    card <= JOKER
+}
+
+fn is_valid_low(low: [u8; 2]) -> bool {
+    (low[0] as usize) < HAND_SIZE && (low[1] as usize) < HAND_SIZE && low[0] != low[1]
+}
+
+fn apply_fortune_update(state: &mut PaiGowState, new_bet: u64) -> Result<i64, GameError> {
+    let old = state.fortune_bet as i128;
+    let new = new_bet as i128;
+    let delta = new - old;
+    if delta > i64::MAX as i128 || delta < i64::MIN as i128 {
+        return Err(GameError::InvalidMove);
+    }
+    state.fortune_bet = new_bet;
+    // Deduct positive increases, refund decreases.
+    Ok(-(delta as i64))
+}
+
+// === Hand evaluation ===
+
+/// Evaluate a 5-card high hand.
+///
+/// The joker takes whichever card scores best among an ace or a card that completes a
+/// straight or flush.
+fn evaluate_high(hand: &[u8; 5]) -> HandValue {
+    let Some(joker) = hand.iter().position(|&card| card == JOKER) else {
+        let (rank, kickers) = evaluate_5_card_fast(hand);
+        return (rank as u8, kickers);
+    };
+    let aces = hand
+        .iter()
+        .filter(|&&card| card != JOKER && cards::card_rank(card) == 0)
+        .count();
+    if aces == 4 {
+        return (FIVE_ACES, [14; 5]);
+    }
+
+    let mut best = (HandRank::HighCard as u8, [0u8; 5]);
+    for card in 0..cards::CARDS_PER_DECK {
+        if hand.contains(&card) {
+            continue;
+        }
+        let mut candidate = *hand;
+        candidate[joker] = card;
+        let (rank, kickers) = evaluate_5_card_fast(&candidate);
+        let completes = matches!(
+            rank,
+            HandRank::Straight | HandRank::Flush | HandRank::StraightFlush | HandRank::RoyalFlush
+        );
+        if cards::card_rank(card) != 0 && !completes {
+            continue;
+        }
+        best = best.max((rank as u8, kickers));
+    }
+    best
+}
+
+/// Evaluate a 2-card low hand, encoded so it compares directly against high hands.
+fn evaluate_low(hand: &[u8; 2]) -> HandValue {
+    let (a, b) = (way_rank(hand[0]), way_rank(hand[1]));
+    if a == b {
+        (HandRank::Pair as u8, [a, 0, 0, 0, 0])
+    } else {
+        (HandRank::HighCard as u8, [a.max(b), a.min(b), 0, 0, 0])
+    }
+}
+
+/// Best five-card hand from seven cards.
+fn evaluate_best_high(cards: &[u8; HAND_SIZE]) -> HandValue {
+    let mut best = (HandRank::HighCard as u8, [0u8; 5]);
+    for i in 0..HAND_SIZE {
+        for j in (i + 1)..HAND_SIZE {
+            let (high, _) = split_hand(cards, [i as u8, j as u8]);
+            best = best.max(evaluate_high(&high));
+        }
+    }
+    best
+}
+
+/// Split seven cards into the high hand and the low hand at `low` (see `is_valid_low`).
+fn split_hand(cards: &[u8; HAND_SIZE], low: [u8; 2]) -> ([u8; 5], [u8; 2]) {
+    let mut high = [0u8; 5];
+    let mut k = 0;
+    for (idx, &card) in cards.iter().enumerate() {
+        if idx != low[0] as usize && idx != low[1] as usize && k < high.len() {
+            high[k] = card;
+            k += 1;
+        }
+    }
+    (high, [cards[low[0] as usize], cards[low[1] as usize]])
+}
+
+/// True if the high hand outranks the low hand.
+fn is_legal_split(cards: &[u8; HAND_SIZE], low: [u8; 2]) -> bool {
+    let (high, low) = split_hand(cards, low);
+    evaluate_high(&high) > evaluate_low(&low)
+}
+
+/// `Some(true)` for a seven-card straight flush without the joker, `Some(false)` with it.
+fn seven_card_straight_flush(cards: &[u8; HAND_SIZE]) -> Option<bool> {
+    let natural: Vec<u8> = cards
+        .iter()
+        .copied()
+        .filter(|&card| card != JOKER)
+        .collect();
+    let suit = cards::card_suit(*natural.first()?);
+    if natural.iter().any(|&card| cards::card_suit(card) != suit) {
+        return None;
+    }
+    // Every natural card must fall on a distinct rank within one 7-rank window; the joker
+    // fills the gap. Aces play low (1) in the lowest window and high (14) otherwise.
+    let fits = |lowest: u8| {
+        let mut seen = 0u16;
+        natural.iter().all(|&card| {
+            let rank = match cards::card_rank_ace_high(card) {
+                14 if lowest == 1 => 1,
+                rank => rank,
+            };
+            let fresh = seen & (1 << rank) == 0;
+            seen |= 1 << rank;
+            fresh && (lowest..lowest + 7).contains(&rank)
+        })
+    };
+    (1..=8).any(fits).then_some(natural.len() == HAND_SIZE)
+}
+
+fn fortune_multiplier(cards: &[u8; HAND_SIZE]) -> u64 {
+    match seven_card_straight_flush(cards) {
+        Some(true) => FORTUNE_SEVEN_CARD_STRAIGHT_FLUSH,
+        Some(false) => FORTUNE_SEVEN_CARD_STRAIGHT_FLUSH_JOKER,
+        None => {
+            let (rank, _) = evaluate_best_high(cards);
+            FORTUNE_PAYS.get(rank as usize).copied().unwrap_or(0)
+        }
+    }
+}
+
+// === House way ===
+
+/// Ace-high rank used when grouping cards; the joker groups with the aces.
+fn way_rank(card: u8) -> u8 {
+    if card == JOKER {
+        14
+    } else {
+        cards::card_rank_ace_high(card)
+    }
+}
+
+/// The two highest-ranked cards not in `kept`, given indices ordered highest rank first.
+fn highest_outside(order: &[usize; HAND_SIZE], kept: &[usize]) -> [u8; 2] {
+    let mut low = [0u8; 2];
+    let mut rest = order.iter().filter(|idx| !kept.contains(idx));
+    for slot in &mut low {
+        *slot = rest.next().copied().unwrap_or_default() as u8;
+    }
+    low
+}
+
+fn pair_of(members: &[usize]) -> [u8; 2] {
+    [members[0] as u8, members[1] as u8]
+}
+
+/// Set the hand by its pairs, trips and quads:
+/// - No pair: highest card high, next two low.
+/// - One pair: pair high, two highest singles low.
+/// - Two pair: split (lower pair low) unless the high pair is tens or lower and an ace can
+///   play low.
+/// - Three pair: highest pair low.
+/// - Trips: keep together with the two highest singles low; three aces split one ace low.
+/// - Full house or two trips: highest pair low (from the higher trips when there are two).
+/// - Quads: play any other pair low; otherwise keep sixes and lower, keep sevens to tens with
+///   an ace to play low, and split the rest.
+/// - Five Aces: a pair of kings plays low if there is one, otherwise a pair of aces.
+fn house_way_by_groups(cards: &[u8; HAND_SIZE]) -> [u8; 2] {
+    let mut order = [0usize, 1, 2, 3, 4, 5, 6];
+    order.sort_by(|&a, &b| way_rank(cards[b]).cmp(&way_rank(cards[a])));
+
+    // Same-rank groups, highest rank first.
+    let mut groups: Vec<(u8, Vec<usize>)> = Vec::with_capacity(HAND_SIZE);
+    for &idx in &order {
+        let rank = way_rank(cards[idx]);
+        match groups.last_mut() {
+            Some((group_rank, members)) if *group_rank == rank => members.push(idx),
+            _ => groups.push((rank, vec![idx])),
+        }
+    }
+    let of_size = |size: usize| {
+        groups
+            .iter()
+            .filter(move |(_, members)| members.len() == size)
+            .map(|(rank, members)| (*rank, members.as_slice()))
+    };
+    let pairs: Vec<(u8, &[usize])> = of_size(2).collect();
+    let trips: Vec<(u8, &[usize])> = of_size(3).collect();
+    let ace_single = groups
+        .first()
+        .is_some_and(|(rank, members)| *rank == 14 && members.len() == 1);
+
+    if let Some((_, aces)) = of_size(5).next() {
+        return match pairs.iter().find(|(rank, _)| *rank == 13) {
+            Some((_, kings)) => pair_of(kings),
+            None => pair_of(aces),
+        };
+    }
+    if let Some((rank, quads)) = of_size(4).next() {
+        if let Some((_, other)) = trips.first().or(pairs.first()) {
+            return pair_of(other);
+        }
+        return if rank <= 6 || (rank <= 10 && ace_single) {
+            highest_outside(&order, quads)
+        } else {
+            pair_of(quads)
+        };
+    }
+    if let Some(&(rank, three)) = trips.first() {
+        if trips.len() > 1 {
+            return pair_of(three);
+        }
+        if let Some((_, pair)) = pairs.first() {
+            return pair_of(pair);
+        }
+        if rank == 14 {
+            let [next, _] = highest_outside(&order, three);
+            return [three[0] as u8, next];
+        }
+        return highest_outside(&order, three);
+    }
+    match pairs.as_slice() {
+        [] => highest_outside(&order, &order[..1]),
+        [(_, pair)] => highest_outside(&order, pair),
+        [(high, high_pair), (_, low_pair)] => {
+            if *high <= 10 && ace_single {
+                let kept: Vec<usize> = high_pair.iter().chain(low_pair.iter()).copied().collect();
+                highest_outside(&order, &kept)
+            } else {
+                pair_of(low_pair)
+            }
+        }
+        [(_, highest), ..] => pair_of(highest),
+    }
+}
+
+/// The straight or flush setting that leaves the best low hand, if any.
+fn best_straight_or_flush(cards: &[u8; HAND_SIZE]) -> Option<[u8; 2]> {
+    let mut best: Option<(HandValue, HandValue, [u8; 2])> = None;
+    for i in 0..HAND_SIZE {
+        for j in (i + 1)..HAND_SIZE {
+            let low = [i as u8, j as u8];
+            let (high_cards, low_cards) = split_hand(cards, low);
+            let high = evaluate_high(&high_cards);
+            let is_straight_or_flush = [
+                HandRank::Straight,
+                HandRank::Flush,
+                HandRank::StraightFlush,
+                HandRank::RoyalFlush,
+            ]
+            .iter()
+            .any(|&rank| rank as u8 == high.0);
+            if !is_straight_or_flush {
+                continue;
+            }
+            best = best.max(Some((evaluate_low(&low_cards), high, low)));
+        }
+    }
+    best.map(|(_, _, low)| low)
+}
+
+/// Choose the 2-card hand the house way.
+///
+/// Sets by pairs first (see `house_way_by_groups`), then plays a straight or flush high instead
+/// when that keeps a pair low, or when setting by pairs neither keeps a pair low nor makes a
+/// straight or better high.
+fn house_way(cards: &[u8; HAND_SIZE]) -> [u8; 2] {
+    let by_groups = house_way_by_groups(cards);
+    let Some(straight_or_flush) = best_straight_or_flush(cards) else {
+        return by_groups;
+    };
+    let is_pair = |low: [u8; 2]| evaluate_low(&split_hand(cards, low).1).0 == HandRank::Pair as u8;
+    let (group_high, _) = split_hand(cards, by_groups);
+    let weak_by_groups =
+        !is_pair(by_groups) && evaluate_high(&group_high).0 < HandRank::Straight as u8;
+    if is_pair(straight_or_flush) || weak_by_groups {
+        straight_or_flush
+    } else {
+        by_groups
+    }
+}
+
+// === Resolution ===
+
+fn hand_value_str(value: &HandValue) -> &'static str {
+    match value.0 {
+        0 => "HIGH_CARD",
+        1 => "PAIR",
+        2 => "TWO_PAIR",
+        3 => "THREE_OF_A_KIND",
+        4 => "STRAIGHT",
+        5 => "FLUSH",
+        6 => "FULL_HOUSE",
+        7 => "FOUR_OF_A_KIND",
+        8 => "STRAIGHT_FLUSH",
+        9 => "ROYAL_FLUSH",
+        _ => "FIVE_ACES",
+    }
+}
+
+/// Commission on a 1:1 win, rounded up in the house's favour.
+fn commission(bet: u64) -> u64 {
+    let commission = (u128::from(bet) * u128::from(COMMISSION_PERCENT)).div_ceil(100);
+    u64::try_from(commission).unwrap_or(u64::MAX)
+}
+
+/// Draw the dealer's seven cards, set them the house way, and settle both bets.
+fn reveal_and_resolve(
+    session: &mut GameSession,
+    state: &mut PaiGowState,
+    rng: &mut GameRng,
+) -> Result<GameResult, GameError> {
+    let mut deck = rng.create_deck_excluding(&state.player);
+    if !state.player.contains(&JOKER) {
+        deck.push(JOKER);
+    }
+    for card in &mut state.dealer {
+        *card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
+    }
+    state.dealer_low = house_way(&state.dealer);
+    resolve_hands(session, state)
+}
+
+fn resolve_hands(
+    session: &mut GameSession,
+    state: &mut PaiGowState,
+) -> Result<GameResult, GameError> {
+    if !state
+        .player
+        .iter()
+        .chain(state.dealer.iter())
+        .all(|&card| is_known_card(card))
+    {
+        return Err(GameError::InvalidState);
+    }
+    if !is_valid_low(state.player_low) || !is_valid_low(state.dealer_low) {
+        return Err(GameError::InvalidState);
+    }
+
+    let bet = session.bet;
+    let fortune_bet = state.fortune_bet;
+    let total_wagered = bet.saturating_add(fortune_bet);
+
+    let (player_high_cards, player_low_cards) = split_hand(&state.player, state.player_low);
+    let (dealer_high_cards, dealer_low_cards) = split_hand(&state.dealer, state.dealer_low);
+    let player_high = evaluate_high(&player_high_cards);
+    let player_low = evaluate_low(&player_low_cards);
+    let dealer_high = evaluate_high(&dealer_high_cards);
+    let dealer_low = evaluate_low(&dealer_low_cards);
+
+    // Copies go to the dealer.
+    let wins_high = player_high > dealer_high;
+    let wins_low = player_low > dealer_low;
+    let commission_paid = if wins_high && wins_low {
+        commission(bet)
+    } else {
+        0
+    };
+    let mut main_return = match (wins_high, wins_low) {
+        (true, true) => bet.saturating_add(bet.saturating_sub(commission_paid)),
+        (false, false) => 0,
+        _ => bet,
+    };
+
+    let fortune_rank = evaluate_best_high(&state.player);
+    let mut fortune_return: u64 = 0;
+    if fortune_bet > 0 {
+        let mult = fortune_multiplier(&state.player);
+        if mult > 0 {
+            fortune_return = fortune_bet.saturating_mul(mult.saturating_add(1));
+        }
+    }
+
+    // Apply super mode multiplier (if any) to winning returns.
+    if session.super_mode.is_active {
+        let multipliers = &session.super_mode.multipliers;
+        if wins_high && wins_low {
+            main_return = apply_super_multiplier_cards(&state.player, multipliers, main_return);
+        }
+        if fortune_return > 0 {
+            fortune_return =
+                apply_super_multiplier_cards(&state.player, multipliers, fortune_return);
+        }
+    }
+
+    let total_return = main_return.saturating_add(fortune_return);
+
+    state.stage = Stage::Complete;
+    session.is_complete = true;
+
+    // Generate logs for frontend display
+    let format_rank = |value: &HandValue| -> String { hand_value_str(value).replace('_', " ") };
+    let winner = |player_wins: bool| if player_wins { "PLAYER" } else { "DEALER" };
+    let outcome = match (wins_high, wins_low) {
+        (true, true) => "WIN",
+        (false, false) => "LOSS",
+        _ => "PUSH",
+    };
+
+    let mut resolved_entries = String::with_capacity(256);
+    let mut resolved_sum: i128 = 0;
+    let main_pnl = clamp_i64(i128::from(main_return) - i128::from(bet));
+    push_resolved_entry(&mut resolved_entries, "PAI GOW", main_pnl);
+    resolved_sum = resolved_sum.saturating_add(i128::from(main_pnl));
+    if fortune_bet > 0 {
+        let pnl = clamp_i64(i128::from(fortune_return) - i128::from(fortune_bet));
+        push_resolved_entry(&mut resolved_entries, "FORTUNE", pnl);
+        resolved_sum = resolved_sum.saturating_add(i128::from(pnl));
+    }
+    let net_pnl = clamp_i64(i128::from(total_return) - i128::from(total_wagered));
+    let diff = i128::from(net_pnl).saturating_sub(resolved_sum);
+    if diff != 0 {
+        push_resolved_entry(&mut resolved_entries, "ADJUSTMENT", clamp_i64(diff));
+    }
+    let summary = format!(
+        "P: {} / {}, D: {} / {}",
+        format_rank(&player_high),
+        format_rank(&player_low),
+        format_rank(&dealer_high),
+        format_rank(&dealer_low)
+    );
+
+    let logs = vec![format!(
+        r#"{{"summary":"{}","netPnl":{},"resolvedBets":[{}],"player":{{"cards":[{}],"high":[{}],"low":[{}],"highRank":"{}","lowRank":"{}"}},"dealer":{{"cards":[{}],"high":[{}],"low":[{}],"highRank":"{}","lowRank":"{}"}},"highWinner":"{}","lowWinner":"{}","outcome":"{}","bet":{},"commission":{},"fortuneBet":{},"fortuneRank":"{}","mainReturn":{},"fortuneReturn":{},"totalWagered":{},"totalReturn":{}}}"#,
+        summary,
+        net_pnl,
+        resolved_entries,
+        format_card_list(&state.player),
+        format_card_list(&player_high_cards),
+        format_card_list(&player_low_cards),
+        hand_value_str(&player_high),
+        hand_value_str(&player_low),
+        format_card_list(&state.dealer),
+        format_card_list(&dealer_high_cards),
+        format_card_list(&dealer_low_cards),
+        hand_value_str(&dealer_high),
+        hand_value_str(&dealer_low),
+        winner(wins_high),
+        winner(wins_low),
+        outcome,
+        bet,
+        commission_paid,
+        fortune_bet,
+        hand_value_str(&fortune_rank),
+        main_return,
+        fortune_return,
+        total_wagered,
+        total_return
+    )];
+
+    if total_return == 0 {
+        Ok(GameResult::LossPreDeducted(total_wagered, logs))
+    } else {
+        Ok(GameResult::Win(total_return, logs))
+    }
+}
+
+pub struct PaiGow;
+
+impl CasinoGame for PaiGow {
+    fn init(session: &mut GameSession, _rng: &mut GameRng) -> GameResult {
+        // Start in a betting stage so Fortune can be placed before any cards are dealt.
+        let state = PaiGowState {
+            stage: Stage::Betting,
+            fortune_bet: 0,
+            player: [CARD_UNKNOWN; HAND_SIZE],
+            player_low: [CARD_UNKNOWN; 2],
+            dealer: [CARD_UNKNOWN; HAND_SIZE],
+            dealer_low: [CARD_UNKNOWN; 2],
+        };
+        session.state_blob = serialize_state(&state);
+        GameResult::Continue(vec![])
+    }
+
+    fn process_move(
+        session: &mut GameSession,
+        payload: &[u8],
+        rng: &mut GameRng,
+    ) -> Result<GameResult, GameError> {
+        if session.is_complete {
+            return Err(GameError::GameAlreadyComplete);
+        }
+        if payload.is_empty() {
+            return Err(GameError::InvalidPayload);
+        }
+
+        let action = Action::try_from(payload[0])?;
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidPayload)?;
+
+        match state.stage {
+            Stage::Betting => {
+                let payout_update = match action {
+                    Action::SetFortune => {
+                        let new_bet = super::payload::parse_u64_be(payload, 1)?;
+                        apply_fortune_update(&mut state, new_bet)?
+                    }
+                    Action::Deal => {
+                        let payout_update = match payload.len() {
+                            1 => 0,
+                            9 => {
+                                let new_bet = super::payload::parse_u64_be(payload, 1)?;
+                                apply_fortune_update(&mut state, new_bet)?
+                            }
+                            _ => return Err(GameError::InvalidPayload),
+                        };
+
+                        let mut deck = rng.create_deck();
+                        deck.push(JOKER);
+                        for card in &mut state.player {
+                            *card = rng.draw_card(&mut deck).ok_or(GameError::DeckExhausted)?;
+                        }
+                        state.stage = Stage::Setting;
+                        payout_update
+                    }
+                    _ => return Err(GameError::InvalidMove),
+                };
+                session.state_blob = serialize_state(&state);
+                Ok(if payout_update == 0 {
+                    GameResult::Continue(vec![])
+                } else {
+                    GameResult::ContinueWithUpdate {
+                        payout: payout_update,
+                        logs: vec![],
+                    }
+                })
+            }
+            Stage::Setting => {
+                let low = match action {
+                    Action::SetHand => {
+                        if payload.len() != 3 {
+                            return Err(GameError::InvalidPayload);
+                        }
+                        let low = [payload[1], payload[2]];
+                        if !is_valid_low(low) {
+                            return Err(GameError::InvalidPayload);
+                        }
+                        // A fouled hand (low outranking high) is rejected rather than lost.
+                        if !is_legal_split(&state.player, low) {
+                            return Err(GameError::InvalidMove);
+                        }
+                        low
+                    }
+                    Action::HouseWay => {
+                        if payload.len() != 1 {
+                            return Err(GameError::InvalidPayload);
+                        }
+                        house_way(&state.player)
+                    }
+                    _ => return Err(GameError::InvalidMove),
+                };
+                state.player_low = low;
+                let result = reveal_and_resolve(session, &mut state, rng)?;
+                session.state_blob = serialize_state(&state);
+                Ok(result)
+            }
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.stage {
+            // No cards dealt yet: return the main bet and Fortune.
+            Stage::Betting => Ok(GameResult::Push(
+                session.bet.saturating_add(state.fortune_bet),
+                vec![],
+            )),
+            // Set the player's hand the house way.
+            Stage::Setting => Self::process_move(session, &[Action::HouseWay as u8], rng),
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_must_use)]
+mod tests {
+    use super::*;
+    use crate::mocks::{create_account_keypair, create_network_keypair, create_seed};
+    use nullspace_types::casino::GameType;
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    fn create_test_seed() -> nullspace_types::Seed {
+        let (network_secret, _) = create_network_keypair();
+        create_seed(&network_secret, 1)
+    }
+
+    fn create_test_session(bet: u64) -> GameSession {
+        let (_, pk) = create_account_keypair(1);
+        GameSession {
+            id: 1,
+            player: pk,
+            game_type: GameType::PaiGow,
+            bet,
+            state_blob: vec![],
+            move_count: 0,
+            created_at: 0,
+            is_complete: false,
+            super_mode: nullspace_types::casino::SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        }
+    }
+
+    fn settled_state(player: [u8; 7], player_low: [u8; 2], dealer: [u8; 7]) -> PaiGowState {
+        PaiGowState {
+            stage: Stage::Setting,
+            fortune_bet: 0,
+            player,
+            player_low,
+            dealer,
+            dealer_low: house_way(&dealer),
+        }
+    }
+
+    fn sorted(mut low: [u8; 2]) -> [u8; 2] {
+        low.sort_unstable();
+        low
+    }
+
+    #[test]
+    fn test_joker_completes_straights_and_flushes() {
+        // Joker, 2♠, 3♥, 4♦, 5♣ makes a six-high straight.
+        let straight = evaluate_high(&[JOKER, 1, 15, 29, 43]);
+        assert_eq!(straight, (HandRank::Straight as u8, [6, 0, 0, 0, 0]));
+
+        // Joker, 2♠, 4♠, 6♠, 9♠ makes an ace-high flush.
+        let flush = evaluate_high(&[JOKER, 1, 3, 5, 8]);
+        assert_eq!(flush.0, HandRank::Flush as u8);
+        assert_eq!(flush.1[0], 14);
+    }
+
+    #[test]
+    fn test_joker_otherwise_plays_as_ace() {
+        // Joker, K♠, K♥, 3♠, 5♥ is kings with an ace kicker, not trip kings.
+        let hand = evaluate_high(&[JOKER, 12, 25, 2, 17]);
+        assert_eq!(hand, (HandRank::Pair as u8, [13, 14, 5, 3, 0]));
+
+        // Joker and an ace in the low hand are a pair of aces.
+        assert_eq!(
+            evaluate_low(&[JOKER, 13]),
+            (HandRank::Pair as u8, [14, 0, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn test_five_aces_beats_royal_flush() {
+        let five_aces = evaluate_high(&[JOKER, 0, 13, 26, 39]);
+        let royal = evaluate_high(&[9, 10, 11, 12, 0]);
+        assert_eq!(five_aces.0, FIVE_ACES);
+        assert_eq!(royal.0, HandRank::RoyalFlush as u8);
+        assert!(five_aces > royal);
+    }
+
+    #[test]
+    fn test_house_way_two_pair() {
+        // K♠ K♥ Q♠ Q♥ 3♠ 5♥ 8♦: split, queens play low.
+        assert_eq!(sorted(house_way(&[12, 25, 11, 24, 2, 17, 33])), [2, 3]);
+
+        // 6♠ 6♥ 3♠ 3♥ A♠ 8♦ 10♣: keep both pairs, ace and ten play low.
+        assert_eq!(sorted(house_way(&[5, 18, 2, 15, 0, 33, 48])), [4, 6]);
+    }
+
+    #[test]
+    fn test_house_way_never_fouls() {
+        let mut rng = StdRng::seed_from_u64(0x9a1_6011);
+        let mut deck: Vec<u8> = (0..=JOKER).collect();
+        for _ in 0..2_000 {
+            deck.shuffle(&mut rng);
+            let cards: [u8; 7] = deck[..7].try_into().expect("seven cards");
+            let low = house_way(&cards);
+            assert!(is_valid_low(low));
+            assert!(
+                is_legal_split(&cards, low),
+                "fouled {:?} with {:?}",
+                cards,
+                low
+            );
+        }
+    }
+
+    #[test]
+    fn test_seven_card_straight_flush() {
+        // A♠ through 7♠.
+        assert_eq!(
+            seven_card_straight_flush(&[0, 1, 2, 3, 4, 5, 6]),
+            Some(true)
+        );
+        // The joker fills the four.
+        assert_eq!(
+            seven_card_straight_flush(&[0, 1, 2, JOKER, 4, 5, 6]),
+            Some(false)
+        );
+        // Off-suit seven.
+        assert_eq!(seven_card_straight_flush(&[0, 1, 2, 3, 4, 5, 19]), None);
+        assert_eq!(
+            fortune_multiplier(&[0, 1, 2, 3, 4, 5, 6]),
+            FORTUNE_SEVEN_CARD_STRAIGHT_FLUSH
+        );
+    }
+
+    #[test]
+    fn test_win_both_hands_pays_less_commission() {
+        let mut session = create_test_session(100);
+        // Player: kings high, queens low. Dealer: queen high, nothing else.
+        let mut state = settled_state(
+            [12, 25, 11, 24, 2, 17, 33],
+            [2, 3],
+            [1, 16, 32, 48, 4, 21, 37],
+        );
+        let result = resolve_hands(&mut session, &mut state).expect("resolve");
+        assert!(matches!(result, GameResult::Win(195, _)));
+        assert!(session.is_complete);
+    }
+
+    #[test]
+    fn test_copies_go_to_dealer() {
+        let mut session = create_test_session(100);
+        // Both sides hold K-8-7-5-4 high and Q-10 low.
+        let mut state = settled_state(
+            [12, 11, 9, 20, 32, 4, 16],
+            [1, 2],
+            [25, 24, 48, 46, 19, 30, 42],
+        );
+        let result = resolve_hands(&mut session, &mut state).expect("resolve");
+        assert!(matches!(result, GameResult::LossPreDeducted(100, _)));
+    }
+
+    #[test]
+    fn test_set_hand_rejects_foul() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let state = PaiGowState {
+            stage: Stage::Setting,
+            fortune_bet: 0,
+            player: [12, 25, 11, 24, 2, 17, 33],
+            player_low: [CARD_UNKNOWN; 2],
+            dealer: [CARD_UNKNOWN; 7],
+            dealer_low: [CARD_UNKNOWN; 2],
+        };
+        session.state_blob = serialize_state(&state);
+        let mut rng = GameRng::new(&seed, session.id, 1);
+
+        // Kings low over queens high.
+        let result = PaiGow::process_move(&mut session, &[Action::SetHand as u8, 0, 1], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+
+        let result = PaiGow::process_move(&mut session, &[Action::SetHand as u8, 2, 3], &mut rng)
+            .expect("legal split");
+        assert!(matches!(
+            result,
+            GameResult::Win(..) | GameResult::LossPreDeducted(..)
+        ));
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.player_low, [2, 3]);
+        assert!(state.dealer.iter().all(|&card| is_known_card(card)));
+    }
+
+    #[test]
+    fn test_deal_with_fortune_then_house_way() -> Result<(), GameError> {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        PaiGow::init(&mut session, &mut rng);
+
+        let mut payload = vec![Action::Deal as u8];
+        payload.extend_from_slice(&10u64.to_be_bytes());
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = PaiGow::process_move(&mut session, &payload, &mut rng)?;
+        assert!(matches!(
+            result,
+            GameResult::ContinueWithUpdate { payout: -10, .. }
+        ));
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.stage, Stage::Setting);
+        assert_eq!(state.fortune_bet, 10);
+        assert!(state.dealer.iter().all(|&card| card == CARD_UNKNOWN));
+
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        PaiGow::process_move(&mut session, &[Action::HouseWay as u8], &mut rng)?;
+        assert!(session.is_complete);
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.stage, Stage::Complete);
+        let mut seen: Vec<u8> = state
+            .player
+            .iter()
+            .chain(state.dealer.iter())
+            .copied()
+            .collect();
+        seen.sort_unstable();
+        seen.dedup();
+        assert_eq!(seen.len(), 14);
+        Ok(())
+    }
+
+    #[test]
+    fn test_abandon_sets_house_way() -> Result<(), GameError> {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        PaiGow::init(&mut session, &mut rng);
+        assert!(matches!(
+            PaiGow::abandon(&mut session.clone(), &mut rng)?,
+            GameResult::Push(100, _)
+        ));
+
+        PaiGow::process_move(&mut session, &[Action::Deal as u8], &mut rng)?;
+        let player = parse_state(&session.state_blob)
+            .expect("Failed to parse state")
+            .player;
+        PaiGow::abandon(&mut session, &mut rng)?;
+        assert!(session.is_complete);
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.player_low, house_way(&player));
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_blob_fuzz_does_not_panic() {
+        let mut rng = StdRng::seed_from_u64(0x5eed_9a16);
+        for _ in 0..1_000 {
+            let len = rng.gen_range(0..=64);
+            let mut blob = vec![0u8; len];
+            rng.fill(&mut blob[..]);
+            let _ = parse_state(&blob);
+        }
+    }
+}
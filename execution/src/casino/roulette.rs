@@ -36,12 +36,21 @@
 //! 11 = Street (3 numbers in a row, 11:1) - number = row start (1,4,...,34)
 //! 12 = Corner (4-number corner, 8:1) - number = top-left (1-32, not multiple of 3)
 //! 13 = SixLine (6 numbers, 5:1) - number = row start (1,4,...,31)
+//!
+//! Announced (racetrack) bets, single-zero wheel only. The amount is split evenly over the
+//! component chips, so it must be a multiple of the chip count; each chip pays its own odds
+//! and the whole call settles as one bet:
+//! 14 = Voisins du Zero (9 chips) - number = 0
+//! 15 = Tiers du Cylindre (6 chips) - number = 0
+//! 16 = Orphelins (5 chips) - number = 0
+//! 17 = Jeu Zero (4 chips) - number = 0
+//! 18 = Neighbours (2n+1 straight-up chips) - number = [n-1:2 bits][center:6 bits], n = 1-4
 
 use super::logging::{clamp_i64, push_resolved_entry};
 use super::serialization::{StateReader, StateWriter};
 use super::super_mode::apply_super_multiplier_number;
 use super::{limits, CasinoGame, GameError, GameResult, GameRng};
-use nullspace_types::casino::GameSession;
+use nullspace_types::casino::{GameSession, GlobalTableBet};
 use std::fmt::Write;
 
 /// Payout multipliers for Roulette (expressed as "to 1" winnings).
@@ -59,6 +68,8 @@ mod payouts {
 /// State header length: bet_count(1) + zero_rule(1) + phase(1) + totalWagered(8) + pendingReturn(8).
 const STATE_HEADER_V2_LEN: usize = 19;
 const BET_BYTES: usize = 10;
+/// Spins tried when resolving an abandoned En Prison session (double imprisonment can repeat).
+const MAX_ABANDON_SPINS: usize = 16;
 
 /// Red numbers on a roulette wheel.
 const RED_NUMBERS: [u8; 18] = [
@@ -66,6 +77,40 @@ const RED_NUMBERS: [u8; 18] = [
 ];
 const DOUBLE_ZERO: u8 = 37;
 
+/// Single-zero wheel order, clockwise from zero (the racetrack layout).
+const WHEEL_ORDER: [u8; 37] = [
+    0, 32, 15, 19, 4, 21, 2, 25, 17, 34, 6, 27, 13, 36, 11, 30, 8, 23, 10, 5, 24, 16, 33, 1, 20,
+    14, 31, 9, 22, 18, 29, 7, 28, 12, 35, 3, 26,
+];
+
+/// Announced bet layouts: (numbers covered by a chip, chips on that spot).
+const VOISINS_CHIPS: [(&[u8], u64); 7] = [
+    (&[0, 2, 3], 2),
+    (&[4, 7], 1),
+    (&[12, 15], 1),
+    (&[18, 21], 1),
+    (&[19, 22], 1),
+    (&[32, 35], 1),
+    (&[25, 26, 28, 29], 2),
+];
+const TIERS_CHIPS: [(&[u8], u64); 6] = [
+    (&[5, 8], 1),
+    (&[10, 11], 1),
+    (&[13, 16], 1),
+    (&[23, 24], 1),
+    (&[27, 30], 1),
+    (&[33, 36], 1),
+];
+const ORPHELINS_CHIPS: [(&[u8], u64); 5] = [
+    (&[1], 1),
+    (&[6, 9], 1),
+    (&[14, 17], 1),
+    (&[17, 20], 1),
+    (&[31, 34], 1),
+];
+const JEU_ZERO_CHIPS: [(&[u8], u64); 4] =
+    [(&[0, 3], 1), (&[12, 15], 1), (&[26], 1), (&[32, 35], 1)];
+
 fn is_zero_result(zero_rule: ZeroRule, result: u8) -> bool {
     match zero_rule {
         ZeroRule::American => result == 0 || result == DOUBLE_ZERO,
@@ -129,20 +174,25 @@ impl TryFrom<u8> for Phase {
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BetType {
-    Straight = 0, // Single number (35:1)
-    Red = 1,      // Red (1:1)
-    Black = 2,    // Black (1:1)
-    Even = 3,     // Even (1:1)
-    Odd = 4,      // Odd (1:1)
-    Low = 5,      // 1-18 (1:1)
-    High = 6,     // 19-36 (1:1)
-    Dozen = 7,    // 1-12, 13-24, 25-36 (2:1)
-    Column = 8,   // First, second, third column (2:1)
-    SplitH = 9,   // Horizontal split (17:1) - number is left cell in row
-    SplitV = 10,  // Vertical split (17:1) - number is top cell in column
-    Street = 11,  // 3-number row (11:1) - number is row start
-    Corner = 12,  // 4-number corner (8:1) - number is top-left cell
-    SixLine = 13, // 6-number (5:1) - number is row start of top row
+    Straight = 0,    // Single number (35:1)
+    Red = 1,         // Red (1:1)
+    Black = 2,       // Black (1:1)
+    Even = 3,        // Even (1:1)
+    Odd = 4,         // Odd (1:1)
+    Low = 5,         // 1-18 (1:1)
+    High = 6,        // 19-36 (1:1)
+    Dozen = 7,       // 1-12, 13-24, 25-36 (2:1)
+    Column = 8,      // First, second, third column (2:1)
+    SplitH = 9,      // Horizontal split (17:1) - number is left cell in row
+    SplitV = 10,     // Vertical split (17:1) - number is top cell in column
+    Street = 11,     // 3-number row (11:1) - number is row start
+    Corner = 12,     // 4-number corner (8:1) - number is top-left cell
+    SixLine = 13,    // 6-number (5:1) - number is row start of top row
+    Voisins = 14,    // Voisins du Zero (9 chips)
+    Tiers = 15,      // Tiers du Cylindre (6 chips)
+    Orphelins = 16,  // Orphelins (5 chips)
+    JeuZero = 17,    // Jeu Zero (4 chips)
+    Neighbours = 18, // Center number plus n wheel neighbours each side
 }
 
 impl TryFrom<u8> for BetType {
@@ -164,6 +214,11 @@ impl TryFrom<u8> for BetType {
             11 => Ok(BetType::Street),
             12 => Ok(BetType::Corner),
             13 => Ok(BetType::SixLine),
+            14 => Ok(BetType::Voisins),
+            15 => Ok(BetType::Tiers),
+            16 => Ok(BetType::Orphelins),
+            17 => Ok(BetType::JeuZero),
+            18 => Ok(BetType::Neighbours),
             _ => Err(GameError::InvalidPayload),
         }
     }
@@ -174,8 +229,100 @@ fn is_red(number: u8) -> bool {
     RED_NUMBERS.contains(&number)
 }
 
+fn is_announced_bet(bet_type: BetType) -> bool {
+    matches!(
+        bet_type,
+        BetType::Voisins
+            | BetType::Tiers
+            | BetType::Orphelins
+            | BetType::JeuZero
+            | BetType::Neighbours
+    )
+}
+
+/// Split a Neighbours bet number into (center, neighbours each side).
+fn decode_neighbours(number: u8) -> (u8, u8) {
+    (number & 0x3F, (number >> 6) + 1)
+}
+
+/// Component chips of an announced bet: (numbers covered, chips on that spot).
+///
+/// Returns an empty list for layout bets.
+fn announced_chips(bet_type: BetType, number: u8) -> Vec<(&'static [u8], u64)> {
+    match bet_type {
+        BetType::Voisins => VOISINS_CHIPS.to_vec(),
+        BetType::Tiers => TIERS_CHIPS.to_vec(),
+        BetType::Orphelins => ORPHELINS_CHIPS.to_vec(),
+        BetType::JeuZero => JEU_ZERO_CHIPS.to_vec(),
+        BetType::Neighbours => {
+            let (center, neighbours) = decode_neighbours(number);
+            let Some(pos) = WHEEL_ORDER.iter().position(|&n| n == center) else {
+                return Vec::new();
+            };
+            let len = WHEEL_ORDER.len();
+            let span = usize::from(neighbours);
+            (0..=2 * span)
+                .map(|offset| {
+                    let idx = (pos + len + offset - span) % len;
+                    (&WHEEL_ORDER[idx..=idx], 1)
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Number of unit chips an announced bet expands into (0 for layout bets).
+fn announced_chip_count(bet_type: BetType, number: u8) -> u64 {
+    announced_chips(bet_type, number)
+        .iter()
+        .map(|(_, chips)| chips)
+        .sum()
+}
+
+/// Payout for a chip covering `covered` numbers, using the matching layout bet's odds.
+fn chip_payout_multiplier(covered: usize) -> u64 {
+    match covered {
+        1 => payouts::STRAIGHT,
+        2 => payouts::SPLIT,
+        3 => payouts::STREET,
+        _ => payouts::CORNER,
+    }
+}
+
+/// Total return (stake plus winnings) of a bet for a given result, or 0 if it loses.
+fn winning_return(bet: &RouletteBet, result: u8) -> u64 {
+    if is_announced_bet(bet.bet_type) {
+        let chips = announced_chips(bet.bet_type, bet.number);
+        let chip_count: u64 = chips.iter().map(|(_, count)| count).sum();
+        if chip_count == 0 {
+            return 0;
+        }
+        let unit = bet.amount / chip_count;
+        return chips
+            .iter()
+            .filter(|(numbers, _)| numbers.contains(&result))
+            .fold(0u64, |acc, (numbers, count)| {
+                let multiplier = chip_payout_multiplier(numbers.len()).saturating_add(1);
+                acc.saturating_add(unit.saturating_mul(*count).saturating_mul(multiplier))
+            });
+    }
+    if bet_wins(bet.bet_type, bet.number, result) {
+        let multiplier = payout_multiplier(bet.bet_type).saturating_add(1);
+        bet.amount.saturating_mul(multiplier)
+    } else {
+        0
+    }
+}
+
 /// Check if a bet wins for a given result.
 fn bet_wins(bet_type: BetType, bet_number: u8, result: u8) -> bool {
+    if is_announced_bet(bet_type) {
+        return announced_chips(bet_type, bet_number)
+            .iter()
+            .any(|(numbers, _)| numbers.contains(&result));
+    }
+
     // Zero loses all except straight bet on the matching zero (0 or 00).
     if result == 0 || result == DOUBLE_ZERO {
         return bet_type == BetType::Straight && bet_number == result;
@@ -214,6 +361,12 @@ fn bet_wins(bet_type: BetType, bet_number: u8, result: u8) -> bool {
                 || result == bet_number.saturating_add(4)
         }
         BetType::SixLine => result >= bet_number && result <= bet_number.saturating_add(5),
+        // Handled above.
+        BetType::Voisins
+        | BetType::Tiers
+        | BetType::Orphelins
+        | BetType::JeuZero
+        | BetType::Neighbours => false,
     }
 }
 
@@ -233,6 +386,12 @@ fn payout_multiplier(bet_type: BetType) -> u64 {
         BetType::Street => payouts::STREET,
         BetType::Corner => payouts::CORNER,
         BetType::SixLine => payouts::SIX_LINE,
+        // Announced bets pay per chip (see `winning_return`).
+        BetType::Voisins
+        | BetType::Tiers
+        | BetType::Orphelins
+        | BetType::JeuZero
+        | BetType::Neighbours => 0,
     }
 }
 
@@ -263,10 +422,26 @@ fn is_valid_bet_number(bet_type: BetType, number: u8, zero_rule: ZeroRule) -> bo
         BetType::SixLine => {
             (1..=31).contains(&number) && (number - 1) % 3 == 0
         }
+        // Announced bets follow the single-zero wheel.
+        BetType::Voisins | BetType::Tiers | BetType::Orphelins | BetType::JeuZero => {
+            number == 0 && !matches!(zero_rule, ZeroRule::American)
+        }
+        BetType::Neighbours => {
+            decode_neighbours(number).0 <= 36 && !matches!(zero_rule, ZeroRule::American)
+        }
         _ => true,
     }
 }
 
+/// Announced bets must split evenly into their component chips.
+fn is_valid_bet_amount(bet_type: BetType, number: u8, amount: u64) -> bool {
+    if !is_announced_bet(bet_type) {
+        return true;
+    }
+    let chips = announced_chip_count(bet_type, number);
+    chips > 0 && amount.is_multiple_of(chips)
+}
+
 /// Individual bet in roulette.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RouletteBet {
@@ -377,7 +552,9 @@ impl RouletteState {
         let mut bets = Vec::with_capacity(bet_count);
         for _ in 0..bet_count {
             let bet = RouletteBet::from_bytes(reader.read_bytes(BET_BYTES)?)?;
-            if !is_valid_bet_number(bet.bet_type, bet.number, zero_rule) {
+            if !is_valid_bet_number(bet.bet_type, bet.number, zero_rule)
+                || !is_valid_bet_amount(bet.bet_type, bet.number, bet.amount)
+            {
                 return None;
             }
             bets.push(bet);
@@ -421,6 +598,31 @@ fn parse_state(blob: &[u8]) -> Option<RouletteState> {
     RouletteState::from_blob(blob)
 }
 
+/// Bets currently on a roulette layout, for the global table totals.
+pub(crate) fn table_bets(blob: &[u8]) -> Vec<GlobalTableBet> {
+    parse_state(blob)
+        .map(|state| {
+            state
+                .bets
+                .iter()
+                .map(|bet| GlobalTableBet {
+                    bet_type: bet.bet_type as u8,
+                    target: bet.number,
+                    amount: bet.amount,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Spin the shared wheel for a global table round.
+///
+/// Global table sessions always use the standard single-zero wheel, so this consumes
+/// the RNG exactly as the spin move does. Outcome bytes: `[result:u8]`.
+pub(crate) fn table_outcome(rng: &mut GameRng) -> Vec<u8> {
+    vec![spin_result(rng, ZeroRule::Standard)]
+}
+
 /// Generate JSON logs for roulette game completion
 fn generate_roulette_logs(
     state: &RouletteState,
@@ -459,6 +661,11 @@ fn generate_roulette_logs(
             BetType::Street => "STREET",
             BetType::Corner => "CORNER",
             BetType::SixLine => "SIX_LINE",
+            BetType::Voisins => "VOISINS",
+            BetType::Tiers => "TIERS",
+            BetType::Orphelins => "ORPHELINS",
+            BetType::JeuZero => "JEU_ZERO",
+            BetType::Neighbours => "NEIGHBOURS",
         };
         let label = match bet.bet_type {
             BetType::Dozen => format!("DOZEN_{}", bet.number.saturating_add(1)),
@@ -469,6 +676,10 @@ fn generate_roulette_logs(
             | BetType::Street
             | BetType::Corner
             | BetType::SixLine => format!("{} {}", bet_type_str, bet.number),
+            BetType::Neighbours => {
+                let (center, neighbours) = decode_neighbours(bet.number);
+                format!("{} {} N{}", bet_type_str, center, neighbours)
+            }
             _ => bet_type_str.to_string(),
         };
         let bet_return = match state.phase {
@@ -483,8 +694,7 @@ fn generate_roulette_logs(
             }
             Phase::Betting => {
                 if wins {
-                    let multiplier = payout_multiplier(bet.bet_type).saturating_add(1);
-                    bet.amount.saturating_mul(multiplier)
+                    winning_return(bet, result)
                 } else if is_zero_result(state.zero_rule, result)
                     && state.zero_rule == ZeroRule::LaPartage
                     && is_even_money_bet(bet.bet_type)
@@ -573,11 +783,13 @@ impl CasinoGame for Roulette {
                 let bet_type = BetType::try_from(bet_type)?;
                 super::payload::ensure_nonzero_amount(amount)?;
 
-                if !is_valid_bet_number(bet_type, number, state.zero_rule) {
+                if !is_valid_bet_number(bet_type, number, state.zero_rule)
+                    || !is_valid_bet_amount(bet_type, number, amount)
+                {
                     return Err(GameError::InvalidPayload);
                 }
 
-                // Check max bets limit
+                // Check max bets limit (an announced bet counts once)
                 if state.bets.len() >= limits::ROULETTE_MAX_BETS {
                     return Err(GameError::InvalidMove);
                 }
@@ -626,23 +838,15 @@ impl CasinoGame for Roulette {
                             match state.zero_rule {
                                 ZeroRule::Standard | ZeroRule::American => {
                                     for bet in &state.bets {
-                                        if bet_wins(bet.bet_type, bet.number, result) {
-                                            let multiplier =
-                                                payout_multiplier(bet.bet_type).saturating_add(1);
-                                            total_return = total_return.saturating_add(
-                                                bet.amount.saturating_mul(multiplier),
-                                            );
-                                        }
+                                        total_return = total_return
+                                            .saturating_add(winning_return(bet, result));
                                     }
                                 }
                                 ZeroRule::LaPartage => {
                                     for bet in &state.bets {
                                         if bet_wins(bet.bet_type, bet.number, result) {
-                                            let multiplier =
-                                                payout_multiplier(bet.bet_type).saturating_add(1);
-                                            total_return = total_return.saturating_add(
-                                                bet.amount.saturating_mul(multiplier),
-                                            );
+                                            total_return = total_return
+                                                .saturating_add(winning_return(bet, result));
                                         } else if is_even_money_bet(bet.bet_type) {
                                             // Half-back on even-money bets.
                                             total_return =
@@ -658,9 +862,7 @@ impl CasinoGame for Roulette {
                                     for bet in state.bets.drain(..) {
                                         let wins = bet_wins(bet.bet_type, bet.number, result);
                                         if wins {
-                                            let multiplier =
-                                                payout_multiplier(bet.bet_type).saturating_add(1);
-                                            let mut ret = bet.amount.saturating_mul(multiplier);
+                                            let mut ret = winning_return(&bet, result);
                                             if session.super_mode.is_active && ret > 0 {
                                                 ret = apply_super_multiplier_number(
                                                     result,
@@ -694,12 +896,8 @@ impl CasinoGame for Roulette {
                             }
                         } else {
                             for bet in &state.bets {
-                                if bet_wins(bet.bet_type, bet.number, result) {
-                                    let multiplier =
-                                        payout_multiplier(bet.bet_type).saturating_add(1);
-                                    total_return = total_return
-                                        .saturating_add(bet.amount.saturating_mul(multiplier));
-                                }
+                                total_return =
+                                    total_return.saturating_add(winning_return(bet, result));
                             }
                         }
 
@@ -813,7 +1011,17 @@ impl CasinoGame for Roulette {
                 if state.phase != Phase::Betting || state.result.is_some() {
                     return Err(GameError::InvalidMove);
                 }
-                state.zero_rule = ZeroRule::try_from(payload[1])?;
+                let zero_rule = ZeroRule::try_from(payload[1])?;
+                // Pending bets must stay valid on the new wheel (e.g. no announced bets or
+                // 00 straights when switching wheels).
+                if state
+                    .bets
+                    .iter()
+                    .any(|bet| !is_valid_bet_number(bet.bet_type, bet.number, zero_rule))
+                {
+                    return Err(GameError::InvalidMove);
+                }
+                state.zero_rule = zero_rule;
                 session.state_blob = serialize_state(&state);
                 Ok(GameResult::Continue(vec![]))
             }
@@ -862,7 +1070,10 @@ impl CasinoGame for Roulette {
                             .map_err(|_| GameError::InvalidPayload)?,
                     );
 
-                    if amount == 0 {
+                    if amount == 0
+                        || !is_valid_bet_number(bet_type, number, state.zero_rule)
+                        || !is_valid_bet_amount(bet_type, number, amount)
+                    {
                         return Err(GameError::InvalidPayload);
                     }
 
@@ -893,11 +1104,7 @@ impl CasinoGame for Roulette {
                 // Calculate total return (standard rules - no En Prison for atomic batch)
                 let mut total_return: u64 = 0;
                 for bet in &state.bets {
-                    if bet_wins(bet.bet_type, bet.number, result) {
-                        let multiplier = payout_multiplier(bet.bet_type).saturating_add(1);
-                        total_return =
-                            total_return.saturating_add(bet.amount.saturating_mul(multiplier));
-                    }
+                    total_return = total_return.saturating_add(winning_return(bet, result));
                 }
 
                 // Apply super mode multipliers
@@ -925,6 +1132,33 @@ impl CasinoGame for Roulette {
             _ => Err(GameError::InvalidPayload),
         }
     }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.phase {
+            Phase::Betting => {
+                if state.result.is_some() {
+                    return Err(GameError::GameAlreadyComplete);
+                }
+                // The wheel has not been spun, so every pending bet is refunded.
+                let refund = state.total_wagered;
+                state.bets.clear();
+                state.total_wagered = 0;
+                session.state_blob = serialize_state(&state);
+                Ok(GameResult::Push(refund, vec![]))
+            }
+            Phase::Prison => {
+                // Imprisoned bets already ride on the next spin, so spin them out.
+                for _ in 0..MAX_ABANDON_SPINS {
+                    match Self::process_move(session, &[1], rng)? {
+                        GameResult::Continue(_) => continue,
+                        result => return Ok(result),
+                    }
+                }
+                Err(GameError::InvalidState)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1364,6 +1598,42 @@ mod tests {
         panic!("did not find a session that landed on 0");
     }
 
+    #[test]
+    fn test_abandon_refunds_unspun_bets() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Roulette::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let payload = place_bet_payload(BetType::Red, 0, 100);
+        Roulette::process_move(&mut session, &payload, &mut rng).expect("Failed to place bet");
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let payload = place_bet_payload(BetType::Straight, 17, 50);
+        Roulette::process_move(&mut session, &payload, &mut rng).expect("Failed to place bet");
+
+        // Before the spin, every bet is returned.
+        let mut betting = session.clone();
+        let mut rng = GameRng::new(&seed, session.id, 3);
+        let res = Roulette::abandon(&mut betting, &mut rng).expect("Failed to abandon");
+        assert!(matches!(res, GameResult::Push(150, _)));
+        let state = parse_state(&betting.state_blob).expect("Failed to parse state");
+        assert!(state.bets.is_empty());
+
+        // Imprisoned bets are spun out instead.
+        let mut state = parse_state(&session.state_blob).expect("Failed to parse state");
+        state.zero_rule = ZeroRule::EnPrison;
+        state.phase = Phase::Prison;
+        state.result = Some(0);
+        session.state_blob = serialize_state(&state);
+        let res = Roulette::abandon(&mut session, &mut rng).expect("Failed to abandon");
+        assert!(matches!(
+            res,
+            GameResult::Win(_, _) | GameResult::LossPreDeducted(_, _)
+        ));
+        assert!(session.is_complete);
+    }
+
     #[test]
     fn test_en_prison_continues_on_zero_then_resolves() {
         let seed = create_test_seed();
@@ -1549,4 +1819,152 @@ mod tests {
         // Note: It's statistically unlikely to hit 0 in 100 tries (expected ~2-3 times)
         // but not guaranteed. This test just verifies the logic works.
     }
+
+    fn covered_numbers(bet_type: BetType, number: u8) -> Vec<u8> {
+        let mut numbers: Vec<u8> = announced_chips(bet_type, number)
+            .iter()
+            .flat_map(|(covered, _)| covered.iter().copied())
+            .collect();
+        numbers.sort_unstable();
+        numbers.dedup();
+        numbers
+    }
+
+    fn announced_bet(bet_type: BetType, number: u8, amount: u64) -> RouletteBet {
+        RouletteBet {
+            bet_type,
+            number,
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_announced_bet_layouts() {
+        assert_eq!(announced_chip_count(BetType::Voisins, 0), 9);
+        assert_eq!(covered_numbers(BetType::Voisins, 0).len(), 17);
+        assert_eq!(announced_chip_count(BetType::Tiers, 0), 6);
+        assert_eq!(covered_numbers(BetType::Tiers, 0).len(), 12);
+        assert_eq!(announced_chip_count(BetType::Orphelins, 0), 5);
+        assert_eq!(covered_numbers(BetType::Orphelins, 0).len(), 8);
+        assert_eq!(announced_chip_count(BetType::JeuZero, 0), 4);
+        assert_eq!(covered_numbers(BetType::JeuZero, 0).len(), 7);
+
+        // Voisins, Tiers and Orphelins cover the whole wheel exactly once.
+        let mut wheel: Vec<u8> = [BetType::Voisins, BetType::Tiers, BetType::Orphelins]
+            .iter()
+            .flat_map(|&bet_type| covered_numbers(bet_type, 0))
+            .collect();
+        wheel.sort_unstable();
+        assert_eq!(wheel, (0..=36).collect::<Vec<u8>>());
+
+        // 17 and two neighbours each side; 0 and one neighbour wraps around the wheel.
+        assert_eq!(announced_chip_count(BetType::Neighbours, (1 << 6) | 17), 5);
+        assert_eq!(
+            covered_numbers(BetType::Neighbours, (1 << 6) | 17),
+            vec![2, 6, 17, 25, 34]
+        );
+        assert_eq!(covered_numbers(BetType::Neighbours, 0), vec![0, 26, 32]);
+        assert_eq!(announced_chip_count(BetType::Neighbours, (3 << 6) | 5), 9);
+    }
+
+    #[test]
+    fn test_announced_bet_returns() {
+        let voisins = announced_bet(BetType::Voisins, 0, 90);
+        assert_eq!(winning_return(&voisins, 0), 240); // 0/2/3 trio, 2 chips at 11:1
+        assert_eq!(winning_return(&voisins, 26), 180); // corner, 2 chips at 8:1
+        assert_eq!(winning_return(&voisins, 7), 180); // split at 17:1
+        assert_eq!(winning_return(&voisins, 1), 0);
+        assert!(bet_wins(BetType::Voisins, 0, 0));
+
+        let orphelins = announced_bet(BetType::Orphelins, 0, 50);
+        assert_eq!(winning_return(&orphelins, 1), 360);
+        assert_eq!(winning_return(&orphelins, 17), 360); // two splits cover 17
+
+        let jeu_zero = announced_bet(BetType::JeuZero, 0, 40);
+        assert_eq!(winning_return(&jeu_zero, 26), 360);
+        assert_eq!(winning_return(&jeu_zero, 0), 180);
+
+        let neighbours = announced_bet(BetType::Neighbours, (1 << 6) | 17, 50);
+        assert_eq!(winning_return(&neighbours, 6), 360);
+        assert_eq!(winning_return(&neighbours, 0), 0);
+    }
+
+    #[test]
+    fn test_announced_bet_validation() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(0);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        Roulette::init(&mut session, &mut rng);
+
+        // Amount must split evenly over the chips.
+        let payload = place_bet_payload(BetType::Voisins, 0, 100);
+        let result = Roulette::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        let payload = place_bet_payload(BetType::Tiers, 5, 60);
+        let result = Roulette::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        let payload = place_bet_payload(BetType::Neighbours, 37, 50);
+        let result = Roulette::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+
+        // One announced bet is one logical bet.
+        let payload = place_bet_payload(BetType::Voisins, 0, 90);
+        let result = Roulette::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(
+            result,
+            Ok(GameResult::ContinueWithUpdate { payout: -90, .. })
+        ));
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.bets.len(), 1);
+
+        // Announced bets are single-zero only.
+        let result = Roulette::process_move(&mut session, &[3, 4], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidMove)));
+
+        let mut session = create_test_session(0);
+        Roulette::init(&mut session, &mut rng);
+        Roulette::process_move(&mut session, &[3, 4], &mut rng).expect("Failed to set rule");
+        let payload = place_bet_payload(BetType::JeuZero, 0, 40);
+        let result = Roulette::process_move(&mut session, &payload, &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+    }
+
+    #[test]
+    fn test_announced_bet_settles_with_en_prison() {
+        let seed = create_test_seed();
+
+        for session_id in 1..10_000 {
+            let mut test_session = create_test_session(0);
+            test_session.id = session_id;
+            let mut rng = GameRng::new(&seed, session_id, 0);
+            Roulette::init(&mut test_session, &mut rng);
+            Roulette::process_move(&mut test_session, &[3, 2], &mut rng)
+                .expect("Failed to set rule");
+
+            let payload = place_bet_payload(BetType::Red, 0, 100);
+            Roulette::process_move(&mut test_session, &payload, &mut rng)
+                .expect("Failed to place bet");
+            let payload = place_bet_payload(BetType::Voisins, 0, 90);
+            Roulette::process_move(&mut test_session, &payload, &mut rng)
+                .expect("Failed to place bet");
+
+            let mut rng = GameRng::new(&seed, session_id, 1);
+            let res =
+                Roulette::process_move(&mut test_session, &[1], &mut rng).expect("Spin failed");
+            let state = parse_state(&test_session.state_blob).expect("Failed to parse state");
+            if state.result != Some(0) {
+                continue;
+            }
+
+            // Voisins is paid on the zero; only the red bet goes to prison.
+            assert!(matches!(res, GameResult::Continue(_)));
+            assert_eq!(state.phase, Phase::Prison);
+            assert_eq!(state.pending_return, 240);
+            assert_eq!(state.bets.len(), 1);
+            assert_eq!(state.bets[0].bet_type, BetType::Red);
+            return;
+        }
+
+        panic!("did not find a session that landed on 0 with En Prison");
+    }
 }
@@ -34,7 +34,7 @@ use super::logging::{clamp_i64, push_resolved_entry};
 use super::serialization::{StateReader, StateWriter};
 use super::super_mode::apply_super_multiplier_total;
 use super::{limits, CasinoGame, GameError, GameResult, GameRng};
-use nullspace_types::casino::GameSession;
+use nullspace_types::casino::{GameSession, GlobalTableBet};
 use std::fmt::Write;
 
 /// Payout multipliers for Sic Bo (expressed as "to 1" winnings).
@@ -306,6 +306,30 @@ fn parse_state(bytes: &[u8]) -> Option<SicBoState> {
     SicBoState::from_bytes(bytes)
 }
 
+/// Bets currently on a sic bo layout, for the global table totals.
+pub(crate) fn table_bets(blob: &[u8]) -> Vec<GlobalTableBet> {
+    parse_state(blob)
+        .map(|state| {
+            state
+                .bets
+                .iter()
+                .map(|bet| GlobalTableBet {
+                    bet_type: bet.bet_type as u8,
+                    target: bet.number,
+                    amount: bet.amount,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Roll the shared dice for a global table round.
+///
+/// Consumes the RNG exactly as the roll move does. Outcome bytes: `[die1, die2, die3]`.
+pub(crate) fn table_outcome(rng: &mut GameRng) -> Vec<u8> {
+    vec![rng.roll_die(), rng.roll_die(), rng.roll_die()]
+}
+
 /// Payout table for total bets.
 fn total_payout(total: u8, paytable: SicBoPaytable) -> u64 {
     match paytable {
@@ -811,6 +835,19 @@ impl CasinoGame for SicBo {
             _ => Err(GameError::InvalidPayload),
         }
     }
+
+    fn abandon(session: &mut GameSession, _rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        if state.dice.is_some() {
+            return Err(GameError::GameAlreadyComplete);
+        }
+
+        // The dice have not been rolled, so every pending bet is refunded.
+        let refund: u64 = state.bets.iter().map(|b| b.amount).sum();
+        state.bets.clear();
+        session.state_blob = serialize_state(&state);
+        Ok(GameResult::Push(refund, vec![]))
+    }
 }
 
 #[cfg(test)]
@@ -269,12 +269,26 @@ pub fn generate_video_poker_multipliers(rng: &mut GameRng) -> Vec<SuperMultiplie
     mults
 }
 
-/// Apply Video Poker Mega multiplier based on count of Mega Cards in hand
+/// Apply Video Poker Mega multipliers to every hand of a round.
 ///
-/// Returns the boosted payout based on how many Mega Cards are in the final hand.
+/// `hands` holds each hand's final cards, base payout and whether it is a royal
+/// flush. Each hand is boosted by the count of Mega Cards in its own five cards,
+/// so in multi-hand play a held Mega Card boosts every hand it was copied to.
+/// Returns the boosted payout of each hand, in order.
 /// (RTP-adjusted for ~96%: reduced multipliers across all tiers)
-#[allow(dead_code)]
 pub fn apply_video_poker_mega_multiplier(
+    hands: &[([u8; 5], u64, bool)],
+    multipliers: &[SuperMultiplier],
+) -> Vec<u64> {
+    hands
+        .iter()
+        .map(|(hand_cards, base_payout, is_royal_flush)| {
+            apply_mega_multiplier_to_hand(hand_cards, multipliers, *base_payout, *is_royal_flush)
+        })
+        .collect()
+}
+
+fn apply_mega_multiplier_to_hand(
     hand_cards: &[u8],
     multipliers: &[SuperMultiplier],
     base_payout: u64,
@@ -588,6 +602,164 @@ pub fn apply_casino_war_strike_multiplier(
     base_payout.saturating_mul(multiplier)
 }
 
+/// Generate Dragon Pai Gow multipliers (3 Dragon Cards, 2-5x)
+///
+/// Distribution:
+/// - 3 Dragon Cards (specific rank+suit; the joker is never chosen)
+/// - Multipliers: 60% 2x, 30% 3x, 10% 5x
+/// - Applied to winning main bets and Fortune wins via `apply_super_multiplier_cards()`
+///   on the player's seven cards
+/// - Hit Frequency: ~35% of hands hold at least one Dragon Card
+pub fn generate_pai_gow_multipliers(rng: &mut GameRng) -> Vec<SuperMultiplier> {
+    let mut mults = Vec::with_capacity(3);
+    let mut used = 0u64;
+
+    for _ in 0..3 {
+        let card = loop {
+            let c = rng.next_u8() % 52;
+            if (used & (1 << c)) == 0 {
+                used |= 1 << c;
+                break c;
+            }
+        };
+
+        // Distribution: 60% 2x, 30% 3x, 10% 5x
+        let roll = roll_percent(rng);
+        let multiplier = if roll < 6_000 {
+            2
+        } else if roll < 9_000 {
+            3
+        } else {
+            5
+        };
+
+        mults.push(SuperMultiplier {
+            id: card,
+            multiplier,
+            super_type: SuperType::Card,
+        });
+    }
+    mults
+}
+
+/// Generate Let It Ride multipliers (2 Ride Cards, 2-5x)
+///
+/// Distribution:
+/// - 2 Ride Cards (specific rank+suit)
+/// - Multipliers: 60% 2x, 30% 3x, 10% 5x
+/// - Applied to paying base bets (on the final five cards) and 3-Card Bonus wins
+///   (on the player's three cards) via `apply_super_multiplier_cards()`
+/// - Hit Frequency: ~19% of hands hold at least one Ride Card
+pub fn generate_let_it_ride_multipliers(rng: &mut GameRng) -> Vec<SuperMultiplier> {
+    let first = rng.next_u8() % 52;
+    let second = loop {
+        let c = rng.next_u8() % 52;
+        if c != first {
+            break c;
+        }
+    };
+
+    [first, second]
+        .into_iter()
+        .map(|card| {
+            // Distribution: 60% 2x, 30% 3x, 10% 5x
+            let roll = roll_percent(rng);
+            let multiplier = if roll < 6_000 {
+                2
+            } else if roll < 9_000 {
+                3
+            } else {
+                5
+            };
+            SuperMultiplier {
+                id: card,
+                multiplier,
+                super_type: SuperType::Card,
+            }
+        })
+        .collect()
+}
+
+/// Generate Lightning Keno multipliers (3 Lightning Numbers, 2-5x)
+///
+/// Distribution:
+/// - 3 Lightning Numbers (1-80)
+/// - Multipliers: 60% 2x, 30% 3x, 10% 5x
+/// - Applied per race to paying tickets via `apply_super_multiplier_number()` for every
+///   caught spot, so two caught Lightning Numbers stack multiplicatively
+/// - Hit Frequency: each Lightning Number is caught on a race with probability spots/320
+pub fn generate_keno_multipliers(rng: &mut GameRng) -> Vec<SuperMultiplier> {
+    let mut mults = Vec::with_capacity(3);
+    let mut used = 0u128;
+
+    for _ in 0..3 {
+        let number = loop {
+            let n = rng.next_bounded(80) + 1;
+            if (used & (1 << n)) == 0 {
+                used |= 1 << n;
+                break n;
+            }
+        };
+
+        // Distribution: 60% 2x, 30% 3x, 10% 5x
+        let roll = roll_percent(rng);
+        let multiplier = if roll < 6_000 {
+            2
+        } else if roll < 9_000 {
+            3
+        } else {
+            5
+        };
+
+        mults.push(SuperMultiplier {
+            id: number,
+            multiplier,
+            super_type: SuperType::Number,
+        });
+    }
+    mults
+}
+
+/// Generate Big Six multipliers (2 Lightning Segments, 2-10x)
+///
+/// Distribution:
+/// - 2 distinct wheel segments (stop positions 0-53)
+/// - Multipliers: 60% 2x, 30% 5x, 10% 10x
+/// - Applied to winning bets via `apply_super_multiplier_number()` when the wheel
+///   stops on a Lightning Segment
+/// - Hit Frequency: 2/54 spins (~3.7%)
+pub fn generate_big_six_multipliers(rng: &mut GameRng) -> Vec<SuperMultiplier> {
+    let mut mults = Vec::with_capacity(2);
+    let mut used = 0u64;
+
+    for _ in 0..2 {
+        let segment = loop {
+            let s = rng.next_bounded(54);
+            if (used & (1 << s)) == 0 {
+                used |= 1 << s;
+                break s;
+            }
+        };
+
+        // Distribution: 60% 2x, 30% 5x, 10% 10x
+        let roll = roll_percent(rng);
+        let multiplier = if roll < 6_000 {
+            2
+        } else if roll < 9_000 {
+            5
+        } else {
+            10
+        };
+
+        mults.push(SuperMultiplier {
+            id: segment,
+            multiplier,
+            super_type: SuperType::Number,
+        });
+    }
+    mults
+}
+
 /// Generate Super HiLo state (streak-based progressive multipliers)
 ///
 /// Distribution (RTP-adjusted for ~97%):
@@ -960,6 +1132,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_pai_gow_multipliers() {
+        let mut rng = create_test_rng(10);
+        let mults = generate_pai_gow_multipliers(&mut rng);
+
+        assert_eq!(mults.len(), 3);
+        let mut seen = [false; 52];
+        for m in &mults {
+            assert!(m.id < 52);
+            assert!([2, 3, 5].contains(&m.multiplier));
+            assert_eq!(m.super_type, SuperType::Card);
+            assert!(!seen[m.id as usize]);
+            seen[m.id as usize] = true;
+        }
+    }
+
+    #[test]
+    fn test_generate_let_it_ride_multipliers() {
+        let mut rng = create_test_rng(11);
+        let mults = generate_let_it_ride_multipliers(&mut rng);
+
+        assert_eq!(mults.len(), 2);
+        assert_ne!(mults[0].id, mults[1].id);
+        for m in &mults {
+            assert!(m.id < 52);
+            assert!([2, 3, 5].contains(&m.multiplier));
+            assert_eq!(m.super_type, SuperType::Card);
+        }
+    }
+
+    #[test]
+    fn test_generate_keno_multipliers() {
+        let mut rng = create_test_rng(12);
+        let mults = generate_keno_multipliers(&mut rng);
+
+        assert_eq!(mults.len(), 3);
+        let mut seen = [false; 81];
+        for m in &mults {
+            assert!((1..=80).contains(&m.id));
+            assert!([2, 3, 5].contains(&m.multiplier));
+            assert_eq!(m.super_type, SuperType::Number);
+            assert!(!seen[m.id as usize]);
+            seen[m.id as usize] = true;
+        }
+    }
+
+    #[test]
+    fn test_generate_big_six_multipliers() {
+        let mut rng = create_test_rng(13);
+        let mults = generate_big_six_multipliers(&mut rng);
+
+        assert_eq!(mults.len(), 2);
+        assert_ne!(mults[0].id, mults[1].id);
+        for m in &mults {
+            assert!(m.id < 54);
+            assert!([2, 5, 10].contains(&m.multiplier));
+            assert_eq!(m.super_type, SuperType::Number);
+        }
+    }
+
     #[test]
     fn test_generate_hilo_state() {
         let state0 = generate_hilo_state(0);
@@ -1156,18 +1388,30 @@ mod tests {
 
         // 1 Mega Card = 1.2x (RTP-adjusted)
         let payout =
-            apply_video_poker_mega_multiplier(&[0, 10, 20, 30, 40], &multipliers, 100, false);
-        assert_eq!(payout, 120); // 100 * 1.2
+            apply_video_poker_mega_multiplier(&[([0, 10, 20, 30, 40], 100, false)], &multipliers);
+        assert_eq!(payout, vec![120]); // 100 * 1.2
 
         // 2 Mega Cards = 2x (RTP-adjusted)
         let payout =
-            apply_video_poker_mega_multiplier(&[0, 1, 20, 30, 40], &multipliers, 100, false);
-        assert_eq!(payout, 200); // 100 * 2
+            apply_video_poker_mega_multiplier(&[([0, 1, 20, 30, 40], 100, false)], &multipliers);
+        assert_eq!(payout, vec![200]); // 100 * 2
 
         // No Mega Cards = 1x
         let payout =
-            apply_video_poker_mega_multiplier(&[10, 20, 30, 40, 50], &multipliers, 100, false);
-        assert_eq!(payout, 100);
+            apply_video_poker_mega_multiplier(&[([10, 20, 30, 40, 50], 100, false)], &multipliers);
+        assert_eq!(payout, vec![100]);
+
+        // Multi-hand: each hand is boosted by its own Mega Cards.
+        let payout = apply_video_poker_mega_multiplier(
+            &[
+                ([0, 10, 20, 30, 40], 100, false),
+                ([0, 1, 20, 30, 40], 100, false),
+                ([0, 1, 2, 30, 40], 0, false),
+                ([10, 20, 30, 40, 50], 300, false),
+            ],
+            &multipliers,
+        );
+        assert_eq!(payout, vec![120, 200, 0, 300]);
     }
 
     #[test]
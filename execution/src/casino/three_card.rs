@@ -1069,6 +1069,26 @@ impl CasinoGame for ThreeCardPoker {
             Stage::Complete => Err(GameError::GameAlreadyComplete),
         }
     }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.stage {
+            Stage::Betting => {
+                // No cards dealt yet: return the ante and any side bets.
+                let refund = session
+                    .bet
+                    .saturating_add(state.pairplus_bet)
+                    .saturating_add(state.six_card_bonus_bet)
+                    .saturating_add(state.progressive_bet);
+                Ok(GameResult::Push(refund, vec![]))
+            }
+            // Fold an undecided hand; side bets still resolve.
+            Stage::Decision => Self::process_move(session, &[Move::Fold as u8], rng),
+            // The Play bet is already in, so show the hands down.
+            Stage::AwaitingReveal => Self::process_move(session, &[Move::Reveal as u8], rng),
+            Stage::Complete => Err(GameError::GameAlreadyComplete),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1203,6 +1223,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_abandon_folds_undecided_hand() -> Result<(), GameError> {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        ThreeCardPoker::init(&mut session, &mut rng);
+
+        // Before the deal, the ante is returned.
+        let mut betting = session.clone();
+        let res = ThreeCardPoker::abandon(&mut betting, &mut rng)?;
+        assert!(matches!(res, GameResult::Push(100, _)));
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        ThreeCardPoker::process_move(&mut session, &[Move::Deal as u8], &mut rng)?;
+
+        // With no side bets, folding forfeits the ante.
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let res = ThreeCardPoker::abandon(&mut session, &mut rng)?;
+        assert!(matches!(res, GameResult::LossPreDeducted(100, _)));
+        assert!(session.is_complete);
+        Ok(())
+    }
+
     #[test]
     fn test_six_card_bonus_multiplier_examples() {
         // Royal flush in diamonds + junk.
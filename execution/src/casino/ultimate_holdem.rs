@@ -417,7 +417,7 @@ fn evaluate_best_6_card_bonus(cards: &[u8; 6]) -> HandRank {
     best_rank
 }
 
-fn evaluate_5_card_fast(cards: &[u8; 5]) -> (HandRank, [u8; 5]) {
+pub(crate) fn evaluate_5_card_fast(cards: &[u8; 5]) -> (HandRank, [u8; 5]) {
     let mut ranks = [0u8; 5];
     let mut suits = [0u8; 5];
     for i in 0..5 {
@@ -1143,6 +1143,30 @@ impl CasinoGame for UltimateHoldem {
             Stage::Showdown => Err(GameError::GameAlreadyComplete),
         }
     }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        match state.stage {
+            Stage::Betting => {
+                // No cards dealt yet: return the ante and any side bets.
+                let refund = session
+                    .bet
+                    .saturating_add(state.trips_bet)
+                    .saturating_add(state.six_card_bonus_bet)
+                    .saturating_add(state.progressive_bet);
+                Ok(GameResult::Push(refund, vec![]))
+            }
+            Stage::Preflop | Stage::Flop => {
+                // Check down to the river, where folding is allowed.
+                let _ = Self::process_move(session, &[Action::Check as u8], rng)?;
+                Self::abandon(session, rng)
+            }
+            Stage::River => Self::process_move(session, &[Action::Fold as u8], rng),
+            // The Play bet is already in, so show the hands down.
+            Stage::AwaitingReveal => Self::process_move(session, &[Action::Reveal as u8], rng),
+            Stage::Showdown => Err(GameError::GameAlreadyComplete),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1334,6 +1358,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_abandon_checks_down_and_folds() -> Result<(), GameError> {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        UltimateHoldem::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        UltimateHoldem::process_move(&mut session, &[Action::Deal as u8], &mut rng)?;
+
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let res = UltimateHoldem::abandon(&mut session, &mut rng)?;
+        assert!(matches!(res, GameResult::LossPreDeducted(_, _)));
+        assert!(session.is_complete);
+
+        let state = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(state.stage, Stage::Showdown);
+        assert_eq!(state.play_mult, 0);
+        Ok(())
+    }
+
     #[test]
     fn test_trips_refund_on_decrease() -> Result<(), GameError> {
         let seed = create_test_seed();
@@ -1,18 +1,43 @@
-//! Video Poker (Jacks or Better) implementation.
+//! Video Poker implementation.
+//!
+//! Games (selected through the rules byte):
+//! - Jacks or Better
+//! - Deuces Wild (all four deuces are wild, Three of a Kind or better pays)
+//! - Bonus Poker
+//! - Double Bonus Poker
+//! - Double Double Bonus Poker (quads pay more with a low kicker)
+//! - Joker Poker (Kings or Better, 53-card deck with one wild joker)
 //!
 //! State blob format:
 //! [stage:u8] [card1:u8] [card2:u8] [card3:u8] [card4:u8] [card5:u8] [rules:u8]
+//! Multi-hand sessions append:
+//! [hands:u8] [extra hands:(hands-1)×5 cards, Draw stage only]
+//! After the draw card1-card5 hold the final cards of hand 1.
+//!
+//! Stage: 0 = Deal (hold selection), 1 = Draw (complete), 2 = Betting (no cards yet)
+//! Cards are 0xFF until dealt. The joker is encoded as 52.
 //!
-//! Stage: 0 = Deal (initial), 1 = Draw (after hold selection)
+//! Rules byte:
+//! bit 0 = paytable (0 = full pay, 1 = short pay)
+//! bits 1-3 = game (0 = Jacks or Better, 1 = Deuces Wild, 2 = Bonus Poker,
+//!            3 = Double Bonus, 4 = Double Double Bonus, 5 = Joker Poker)
 //!
 //! Payload format:
-//! [holdMask:u8] - bits indicate which cards to hold
+//! [0xFF, rules:u8, hands:u8?] - choose the game, paytable and hand count, then deal
+//!   (Betting stage only; hands is 1, 3, 5, 10 or 50 and defaults to 1)
+//! [holdMask:u8] - bits indicate which cards to hold (Deal stage only)
 //! bit 0 = hold card 1, bit 1 = hold card 2, etc.
-//! [0xFF, rules:u8] - set paytable rules (Deal stage only)
+//!
+//! The game is locked in before any card is shown; picking it after seeing the
+//! hand would let a player choose whichever paytable suits the cards.
+//!
+//! Multi-hand: the held cards are copied to every hand, and each hand draws its
+//! replacements from its own deck of the cards left out of the dealt hand. Each
+//! hand wagers `session.bet`; the extra hands are charged when the cards are dealt.
 
 use super::logging::clamp_i64;
 use super::serialization::{StateReader, StateWriter};
-use super::super_mode::apply_super_multiplier_cards;
+use super::super_mode::apply_video_poker_mega_multiplier;
 use super::{cards, CasinoGame, GameError, GameResult, GameRng};
 use nullspace_types::casino::GameSession;
 
@@ -33,12 +58,24 @@ mod payouts {
 const STATE_LEN_BASE: usize = 6;
 const STATE_LEN_WITH_RULES: usize = 7;
 
+/// Hand counts a session can play.
+const HAND_COUNTS: [u8; 5] = [1, 3, 5, 10, 50];
+
+/// Payload tag for the rules/deal move.
+const SET_RULES_AND_DEAL: u8 = 0xFF;
+
+const CARD_UNKNOWN: u8 = 0xFF;
+
+/// The joker in Joker Poker's 53-card deck.
+pub const JOKER: u8 = 52;
+
 /// Video Poker stages.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Stage {
     Deal = 0,
     Draw = 1,
+    Betting = 2,
 }
 
 impl TryFrom<u8> for Stage {
@@ -48,12 +85,16 @@ impl TryFrom<u8> for Stage {
         match value {
             0 => Ok(Stage::Deal),
             1 => Ok(Stage::Draw),
+            2 => Ok(Stage::Betting),
             _ => Err(GameError::InvalidPayload),
         }
     }
 }
 
 /// Poker hand rankings.
+///
+/// Ids are part of the completion log (`handId`), so new hands are appended.
+/// Which hands can occur depends on the game being played.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Hand {
     HighCard = 0,
@@ -63,9 +104,22 @@ pub enum Hand {
     Straight = 4,
     Flush = 5,
     FullHouse = 6,
+    /// Four of a kind; in the bonus games, Fives through Kings only.
     FourOfAKind = 7,
     StraightFlush = 8,
+    /// Natural royal flush (no wild cards).
     RoyalFlush = 9,
+    FourAces = 10,
+    FourTwosThroughFours = 11,
+    /// Double Double Bonus: four Aces with a 2, 3 or 4 kicker.
+    FourAcesWithKicker = 12,
+    /// Double Double Bonus: four 2s, 3s or 4s with an A, 2, 3 or 4 kicker.
+    FourTwosThroughFoursWithKicker = 13,
+    FiveOfAKind = 14,
+    /// Royal flush completed with a wild card.
+    WildRoyalFlush = 15,
+    FourDeuces = 16,
+    KingsOrBetter = 17,
 }
 
 fn hand_label(hand: Hand) -> &'static str {
@@ -80,15 +134,27 @@ fn hand_label(hand: Hand) -> &'static str {
         Hand::FourOfAKind => "FOUR_OF_A_KIND",
         Hand::StraightFlush => "STRAIGHT_FLUSH",
         Hand::RoyalFlush => "ROYAL_FLUSH",
+        Hand::FourAces => "FOUR_ACES",
+        Hand::FourTwosThroughFours => "FOUR_TWOS_THROUGH_FOURS",
+        Hand::FourAcesWithKicker => "FOUR_ACES_WITH_KICKER",
+        Hand::FourTwosThroughFoursWithKicker => "FOUR_TWOS_THROUGH_FOURS_WITH_KICKER",
+        Hand::FiveOfAKind => "FIVE_OF_A_KIND",
+        Hand::WildRoyalFlush => "WILD_ROYAL_FLUSH",
+        Hand::FourDeuces => "FOUR_DEUCES",
+        Hand::KingsOrBetter => "KINGS_OR_BETTER",
     }
 }
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 enum VideoPokerPaytable {
+    /// Jacks or Better 9/6, Deuces Wild full pay, Bonus 8/5, Double Bonus 10/7,
+    /// Double Double Bonus 9/6, Joker Poker 20/7.
     #[default]
-    NineSix = 0,
-    EightFive = 1,
+    FullPay = 0,
+    /// Jacks or Better 8/5, Deuces Wild "Not So Ugly Ducks", Bonus 7/5,
+    /// Double Bonus 9/6, Double Double Bonus 8/5, Joker Poker 17/7.
+    ShortPay = 1,
 }
 
 impl TryFrom<u8> for VideoPokerPaytable {
@@ -96,8 +162,36 @@ impl TryFrom<u8> for VideoPokerPaytable {
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
-            0 => Ok(VideoPokerPaytable::NineSix),
-            1 => Ok(VideoPokerPaytable::EightFive),
+            0 => Ok(VideoPokerPaytable::FullPay),
+            1 => Ok(VideoPokerPaytable::ShortPay),
+            _ => Err(()),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum VideoPokerGame {
+    #[default]
+    JacksOrBetter = 0,
+    DeucesWild = 1,
+    BonusPoker = 2,
+    DoubleBonus = 3,
+    DoubleDoubleBonus = 4,
+    JokerPoker = 5,
+}
+
+impl TryFrom<u8> for VideoPokerGame {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VideoPokerGame::JacksOrBetter),
+            1 => Ok(VideoPokerGame::DeucesWild),
+            2 => Ok(VideoPokerGame::BonusPoker),
+            3 => Ok(VideoPokerGame::DoubleBonus),
+            4 => Ok(VideoPokerGame::DoubleDoubleBonus),
+            5 => Ok(VideoPokerGame::JokerPoker),
             _ => Err(()),
         }
     }
@@ -105,18 +199,20 @@ impl TryFrom<u8> for VideoPokerPaytable {
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 struct VideoPokerRules {
+    game: VideoPokerGame,
     paytable: VideoPokerPaytable,
 }
 
 impl VideoPokerRules {
     fn from_byte(value: u8) -> Option<Self> {
         Some(Self {
+            game: VideoPokerGame::try_from((value >> 1) & 0x07).ok()?,
             paytable: VideoPokerPaytable::try_from(value & 0x01).ok()?,
         })
     }
 
     fn to_byte(self) -> u8 {
-        self.paytable as u8
+        ((self.game as u8) << 1) | self.paytable as u8
     }
 }
 
@@ -124,9 +220,10 @@ struct VideoPokerState {
     stage: Stage,
     cards: [u8; 5],
     rules: VideoPokerRules,
+    hand_count: u8,
 }
 
-/// Evaluate a 5-card poker hand.
+/// Evaluate a 5-card Jacks or Better hand.
 /// Optimized to avoid heap allocations.
 pub fn evaluate_hand(cards: &[u8; 5]) -> Hand {
     // Extract ranks and suits into fixed arrays
@@ -215,80 +312,461 @@ pub fn evaluate_hand(cards: &[u8; 5]) -> Hand {
     }
 }
 
-/// Payout multiplier for each hand (Jacks or Better paytable, total return).
-fn payout_multiplier(hand: Hand, paytable: VideoPokerPaytable) -> u64 {
-    match paytable {
-        VideoPokerPaytable::NineSix => match hand {
+/// Evaluate a Bonus / Double Bonus hand, splitting quads by rank.
+///
+/// With `kicker_aware` (Double Double Bonus), Aces and 2-4 quads are further
+/// split by the fifth card.
+fn evaluate_bonus_hand(cards: &[u8; 5], kicker_aware: bool) -> Hand {
+    let hand = evaluate_hand(cards);
+    if hand != Hand::FourOfAKind {
+        return hand;
+    }
+
+    let mut counts = [0u8; 14];
+    for &card in cards {
+        counts[cards::card_rank_one_based(card) as usize] += 1;
+    }
+    let quad_rank = counts.iter().position(|&c| c == 4).unwrap_or(0);
+    let kicker = counts.iter().position(|&c| c == 1).unwrap_or(0);
+    let low_kicker = (2..=4).contains(&kicker);
+
+    match quad_rank {
+        1 if kicker_aware && low_kicker => Hand::FourAcesWithKicker,
+        1 => Hand::FourAces,
+        2..=4 if kicker_aware && (kicker == 1 || low_kicker) => {
+            Hand::FourTwosThroughFoursWithKicker
+        }
+        2..=4 => Hand::FourTwosThroughFours,
+        _ => Hand::FourOfAKind,
+    }
+}
+
+/// Best hand category reachable from a set of natural cards plus wild cards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WildRank {
+    HighCard,
+    /// Highest paired rank (Ace high, 2..=14).
+    Pair(u8),
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    WildRoyalFlush,
+    NaturalRoyalFlush,
+    FiveOfAKind,
+}
+
+/// Rank bits 10..=14 (Ten through Ace).
+const ROYAL_RANKS: u16 = 0x1F << 10;
+
+/// Returns true if the ranks in `rank_mask` (bits 2..=14, Ace high) fit inside
+/// a single five-rank window, counting the Ace as low for the wheel.
+fn fits_straight(rank_mask: u16) -> bool {
+    let ace_low = if rank_mask & (1 << 14) != 0 {
+        (rank_mask & !(1 << 14)) | (1 << 1)
+    } else {
+        rank_mask
+    };
+    (1..=10).any(|low| {
+        let window = 0x1Fu16 << low;
+        rank_mask & !window == 0 || ace_low & !window == 0
+    })
+}
+
+/// Evaluate `naturals` (no wild cards, at most 5) completed with `wilds` wild cards.
+fn evaluate_wild(naturals: &[u8], wilds: u8) -> WildRank {
+    let mut counts = [0u8; 15];
+    let mut rank_mask = 0u16;
+    let mut suited = true;
+    for (i, &card) in naturals.iter().enumerate() {
+        let rank = cards::card_rank_ace_high(card);
+        counts[rank as usize] += 1;
+        rank_mask |= 1 << rank;
+        if i > 0 && cards::card_suit(card) != cards::card_suit(naturals[0]) {
+            suited = false;
+        }
+    }
+
+    let most = counts.iter().copied().max().unwrap_or(0) + wilds;
+    let distinct = rank_mask.count_ones() as usize == naturals.len();
+    let straight = distinct && fits_straight(rank_mask);
+    let royal = distinct && rank_mask & !ROYAL_RANKS == 0;
+    let pairs = counts.iter().filter(|&&c| c == 2).count();
+    let trips = counts.contains(&3);
+
+    if most >= 5 {
+        WildRank::FiveOfAKind
+    } else if suited && royal {
+        if wilds == 0 {
+            WildRank::NaturalRoyalFlush
+        } else {
+            WildRank::WildRoyalFlush
+        }
+    } else if suited && straight {
+        WildRank::StraightFlush
+    } else if most == 4 {
+        WildRank::FourOfAKind
+    } else if (trips && pairs == 1) || (wilds == 1 && pairs == 2) {
+        WildRank::FullHouse
+    } else if suited {
+        WildRank::Flush
+    } else if straight {
+        WildRank::Straight
+    } else if most == 3 {
+        WildRank::ThreeOfAKind
+    } else if pairs == 2 {
+        WildRank::TwoPair
+    } else if most == 2 {
+        // A wild card pairs the highest natural card.
+        let need = if wilds == 0 { 2 } else { 1 };
+        let rank = (2..=14u8)
+            .rev()
+            .find(|&r| counts[r as usize] == need)
+            .unwrap_or(0);
+        WildRank::Pair(rank)
+    } else {
+        WildRank::HighCard
+    }
+}
+
+/// Split a hand into natural cards and a count of wild cards.
+fn split_wilds(cards: &[u8; 5], is_wild: impl Fn(u8) -> bool) -> ([u8; 5], usize, u8) {
+    let mut naturals = [0u8; 5];
+    let mut len = 0;
+    let mut wilds = 0u8;
+    for &card in cards {
+        if is_wild(card) {
+            wilds += 1;
+        } else {
+            naturals[len] = card;
+            len += 1;
+        }
+    }
+    (naturals, len, wilds)
+}
+
+/// Evaluate a Deuces Wild hand.
+///
+/// Hands below Three of a Kind do not pay; pairs are reported as `HighCard`.
+pub fn evaluate_deuces_wild(cards: &[u8; 5]) -> Hand {
+    let (naturals, len, wilds) = split_wilds(cards, |card| cards::card_rank_one_based(card) == 2);
+    if wilds == 4 {
+        return Hand::FourDeuces;
+    }
+    match evaluate_wild(&naturals[..len], wilds) {
+        WildRank::NaturalRoyalFlush => Hand::RoyalFlush,
+        WildRank::WildRoyalFlush => Hand::WildRoyalFlush,
+        WildRank::FiveOfAKind => Hand::FiveOfAKind,
+        WildRank::StraightFlush => Hand::StraightFlush,
+        WildRank::FourOfAKind => Hand::FourOfAKind,
+        WildRank::FullHouse => Hand::FullHouse,
+        WildRank::Flush => Hand::Flush,
+        WildRank::Straight => Hand::Straight,
+        WildRank::ThreeOfAKind => Hand::ThreeOfAKind,
+        WildRank::TwoPair => Hand::TwoPair,
+        WildRank::Pair(_) | WildRank::HighCard => Hand::HighCard,
+    }
+}
+
+/// Evaluate a Joker Poker (Kings or Better) hand. The joker is card 52.
+pub fn evaluate_joker_poker(cards: &[u8; 5]) -> Hand {
+    let (naturals, len, wilds) = split_wilds(cards, |card| card == JOKER);
+    match evaluate_wild(&naturals[..len], wilds) {
+        WildRank::NaturalRoyalFlush => Hand::RoyalFlush,
+        WildRank::FiveOfAKind => Hand::FiveOfAKind,
+        WildRank::WildRoyalFlush => Hand::WildRoyalFlush,
+        WildRank::StraightFlush => Hand::StraightFlush,
+        WildRank::FourOfAKind => Hand::FourOfAKind,
+        WildRank::FullHouse => Hand::FullHouse,
+        WildRank::Flush => Hand::Flush,
+        WildRank::Straight => Hand::Straight,
+        WildRank::ThreeOfAKind => Hand::ThreeOfAKind,
+        WildRank::TwoPair => Hand::TwoPair,
+        WildRank::Pair(rank) if rank >= 13 => Hand::KingsOrBetter,
+        WildRank::Pair(_) | WildRank::HighCard => Hand::HighCard,
+    }
+}
+
+fn evaluate_for_game(cards: &[u8; 5], game: VideoPokerGame) -> Hand {
+    match game {
+        VideoPokerGame::JacksOrBetter => evaluate_hand(cards),
+        VideoPokerGame::DeucesWild => evaluate_deuces_wild(cards),
+        VideoPokerGame::BonusPoker | VideoPokerGame::DoubleBonus => {
+            evaluate_bonus_hand(cards, false)
+        }
+        VideoPokerGame::DoubleDoubleBonus => evaluate_bonus_hand(cards, true),
+        VideoPokerGame::JokerPoker => evaluate_joker_poker(cards),
+    }
+}
+
+/// Payout multiplier for each hand (total return per 1 unit wagered).
+///
+/// Hands that cannot occur in the selected game pay nothing.
+fn payout_multiplier(hand: Hand, rules: VideoPokerRules) -> u64 {
+    let full_pay = rules.paytable == VideoPokerPaytable::FullPay;
+    match rules.game {
+        VideoPokerGame::JacksOrBetter => match hand {
             Hand::HighCard => payouts::HIGH_CARD,
             Hand::JacksOrBetter => payouts::JACKS_OR_BETTER,
             Hand::TwoPair => payouts::TWO_PAIR,
             Hand::ThreeOfAKind => payouts::THREE_OF_A_KIND,
             Hand::Straight => payouts::STRAIGHT,
-            Hand::Flush => payouts::FLUSH,
-            Hand::FullHouse => payouts::FULL_HOUSE,
+            Hand::Flush if full_pay => payouts::FLUSH,
+            Hand::Flush => 5,
+            Hand::FullHouse if full_pay => payouts::FULL_HOUSE,
+            Hand::FullHouse => 8,
             Hand::FourOfAKind => payouts::FOUR_OF_A_KIND,
             Hand::StraightFlush => payouts::STRAIGHT_FLUSH,
             Hand::RoyalFlush => payouts::ROYAL_FLUSH,
+            _ => 0,
         },
-        VideoPokerPaytable::EightFive => match hand {
-            Hand::HighCard => payouts::HIGH_CARD,
-            Hand::JacksOrBetter => payouts::JACKS_OR_BETTER,
-            Hand::TwoPair => payouts::TWO_PAIR,
-            Hand::ThreeOfAKind => payouts::THREE_OF_A_KIND,
-            Hand::Straight => payouts::STRAIGHT,
+        VideoPokerGame::DeucesWild => match hand {
+            Hand::ThreeOfAKind => 1,
+            Hand::Straight => 2,
+            Hand::Flush if full_pay => 2,
+            Hand::Flush => 3,
+            Hand::FullHouse if full_pay => 3,
+            Hand::FullHouse => 4,
+            Hand::FourOfAKind if full_pay => 5,
+            Hand::FourOfAKind => 4,
+            Hand::StraightFlush if full_pay => 9,
+            Hand::StraightFlush => 10,
+            Hand::FiveOfAKind if full_pay => 15,
+            Hand::FiveOfAKind => 16,
+            Hand::WildRoyalFlush => 25,
+            Hand::FourDeuces => 200,
+            Hand::RoyalFlush => 800,
+            _ => 0,
+        },
+        VideoPokerGame::BonusPoker => match hand {
+            Hand::JacksOrBetter => 1,
+            Hand::TwoPair => 2,
+            Hand::ThreeOfAKind => 3,
+            Hand::Straight => 4,
             Hand::Flush => 5,
+            Hand::FullHouse if full_pay => 8,
+            Hand::FullHouse => 7,
+            Hand::FourOfAKind => 25,
+            Hand::FourTwosThroughFours => 40,
+            Hand::FourAces => 80,
+            Hand::StraightFlush => 50,
+            Hand::RoyalFlush => 800,
+            _ => 0,
+        },
+        VideoPokerGame::DoubleBonus => match hand {
+            Hand::JacksOrBetter => 1,
+            Hand::TwoPair => 1,
+            Hand::ThreeOfAKind => 3,
+            Hand::Straight => 5,
+            Hand::Flush if full_pay => 7,
+            Hand::Flush => 6,
+            Hand::FullHouse if full_pay => 10,
+            Hand::FullHouse => 9,
+            Hand::FourOfAKind => 50,
+            Hand::FourTwosThroughFours => 80,
+            Hand::FourAces => 160,
+            Hand::StraightFlush => 50,
+            Hand::RoyalFlush => 800,
+            _ => 0,
+        },
+        VideoPokerGame::DoubleDoubleBonus => match hand {
+            Hand::JacksOrBetter => 1,
+            Hand::TwoPair => 1,
+            Hand::ThreeOfAKind => 3,
+            Hand::Straight => 4,
+            Hand::Flush if full_pay => 6,
+            Hand::Flush => 5,
+            Hand::FullHouse if full_pay => 9,
             Hand::FullHouse => 8,
-            Hand::FourOfAKind => payouts::FOUR_OF_A_KIND,
-            Hand::StraightFlush => payouts::STRAIGHT_FLUSH,
-            Hand::RoyalFlush => payouts::ROYAL_FLUSH,
+            Hand::FourOfAKind => 50,
+            Hand::FourTwosThroughFours => 80,
+            Hand::FourAces => 160,
+            Hand::FourTwosThroughFoursWithKicker => 160,
+            Hand::FourAcesWithKicker => 400,
+            Hand::StraightFlush => 50,
+            Hand::RoyalFlush => 800,
+            _ => 0,
         },
+        VideoPokerGame::JokerPoker => match hand {
+            Hand::KingsOrBetter => 1,
+            Hand::TwoPair => 1,
+            Hand::ThreeOfAKind => 2,
+            Hand::Straight => 3,
+            Hand::Flush => 5,
+            Hand::FullHouse => 7,
+            Hand::FourOfAKind if full_pay => 20,
+            Hand::FourOfAKind => 17,
+            Hand::StraightFlush => 50,
+            Hand::WildRoyalFlush => 100,
+            Hand::FiveOfAKind => 200,
+            Hand::RoyalFlush => 800,
+            _ => 0,
+        },
+    }
+}
+
+/// Shuffle the deck for `game`, leaving out `excluded` cards.
+/// Joker Poker adds the joker for a 53-card deck.
+fn create_deck(rng: &mut GameRng, game: VideoPokerGame, excluded: &[u8]) -> Vec<u8> {
+    if game != VideoPokerGame::JokerPoker {
+        return rng.create_deck_excluding(excluded);
     }
+    let mut deck: Vec<u8> = (0..=JOKER)
+        .filter(|card| !excluded.contains(card))
+        .collect();
+    rng.shuffle(&mut deck);
+    deck
 }
 
 fn parse_state(state: &[u8]) -> Option<VideoPokerState> {
     if state.len() < STATE_LEN_BASE {
         return None;
     }
-    if state.len() != STATE_LEN_BASE && state.len() != STATE_LEN_WITH_RULES {
-        return None;
-    }
     let mut reader = StateReader::new(state);
     let stage = Stage::try_from(reader.read_u8()?).ok()?;
     let cards: [u8; 5] = reader.read_bytes(5)?.try_into().ok()?;
-    if cards.iter().any(|&card| card >= 52) {
-        return None;
-    }
     let rules = if reader.remaining() > 0 {
         VideoPokerRules::from_byte(reader.read_u8()?)?
     } else {
         VideoPokerRules::default()
     };
-    Some(VideoPokerState { stage, cards, rules })
+    let hand_count = if reader.remaining() > 0 {
+        // Single-hand sessions keep the 7-byte layout.
+        let hand_count = reader.read_u8()?;
+        if hand_count == 1 || !HAND_COUNTS.contains(&hand_count) || stage == Stage::Betting {
+            return None;
+        }
+        hand_count
+    } else {
+        1
+    };
+    let extra_len = if stage == Stage::Draw && hand_count > 1 {
+        (usize::from(hand_count) - 1) * 5
+    } else {
+        0
+    };
+    if reader.remaining() != extra_len {
+        return None;
+    }
+    // Final cards of hands 2..=hand_count, kept for clients; the game is settled by then.
+    let extra_cards = reader.read_bytes(extra_len)?;
+
+    let has_joker = rules.game == VideoPokerGame::JokerPoker;
+    let is_valid = |card: &u8| cards::is_valid_card(*card) || (has_joker && *card == JOKER);
+    let valid_cards = if stage == Stage::Betting {
+        cards.iter().all(|&card| card == CARD_UNKNOWN)
+    } else {
+        cards.iter().all(is_valid) && extra_cards.iter().all(is_valid)
+    };
+    if !valid_cards {
+        return None;
+    }
+    Some(VideoPokerState {
+        stage,
+        cards,
+        rules,
+        hand_count,
+    })
 }
 
 fn serialize_state(stage: Stage, cards: &[u8; 5], rules: VideoPokerRules) -> Vec<u8> {
-    let mut out = StateWriter::with_capacity(7);
+    serialize_hands(stage, rules, 1, &[*cards])
+}
+
+/// Serialize a session playing `hand_count` hands. `hands[0]` is the dealt (or
+/// hand 1's final) cards; after the draw the rest are the other hands' cards.
+fn serialize_hands(
+    stage: Stage,
+    rules: VideoPokerRules,
+    hand_count: u8,
+    hands: &[[u8; 5]],
+) -> Vec<u8> {
+    let mut out = StateWriter::with_capacity(STATE_LEN_WITH_RULES + hands.len() * 5);
     out.push_u8(stage as u8);
-    out.push_bytes(cards);
+    out.push_bytes(&hands[0]);
     out.push_u8(rules.to_byte());
+    if hand_count > 1 {
+        out.push_u8(hand_count);
+        for hand in &hands[1..] {
+            out.push_bytes(hand);
+        }
+    }
     out.into_inner()
 }
 
+/// Completion log for a multi-hand round: one resolved bet per hand, with the
+/// best hand reported at the top level.
+fn multi_hand_logs(
+    bet: u64,
+    scored: &[(Hand, u64)],
+    returns: &[u64],
+    total_return: u64,
+) -> Vec<String> {
+    let hand_count = scored.len();
+    let total_wagered = bet.saturating_mul(hand_count as u64);
+    let net_pnl = clamp_i64(i128::from(total_return) - i128::from(total_wagered));
+    let mut best = scored[0];
+    for &current in &scored[1..] {
+        if current.1 > best.1 {
+            best = current;
+        }
+    }
+    let best = best.0;
+    let winners = returns.iter().filter(|amount| **amount > 0).count();
+    let multiplier = scored.iter().fold(0u64, |total, (_, multiplier)| {
+        total.saturating_add(*multiplier)
+    });
+
+    let resolved_bets = returns
+        .iter()
+        .enumerate()
+        .map(|(idx, amount)| {
+            let pnl = clamp_i64(i128::from(*amount) - i128::from(bet));
+            format!(r#"{{"label":"HAND {}","pnl":{}}}"#, idx + 1, pnl)
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let hands = scored
+        .iter()
+        .map(|(hand, multiplier)| {
+            format!(
+                r#"{{"hand":"{}","handId":{},"multiplier":{}}}"#,
+                hand_label(*hand),
+                *hand as u8,
+                multiplier
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    vec![format!(
+        r#"{{"summary":"{} hands, {} paid, best: {}","netPnl":{},"resolvedBets":[{}],"hand":"{}","handId":{},"multiplier":{},"totalReturn":{},"hands":[{}]}}"#,
+        hand_count,
+        winners,
+        hand_label(best).replace('_', " "),
+        net_pnl,
+        resolved_bets,
+        hand_label(best),
+        best as u8,
+        multiplier,
+        total_return,
+        hands
+    )]
+}
+
 pub struct VideoPoker;
 
 impl CasinoGame for VideoPoker {
-    fn init(session: &mut GameSession, rng: &mut GameRng) -> GameResult {
-        // Deal 5 cards
-        let mut deck = rng.create_deck();
-        let cards: [u8; 5] = [
-            rng.draw_card(&mut deck).unwrap_or(0),
-            rng.draw_card(&mut deck).unwrap_or(1),
-            rng.draw_card(&mut deck).unwrap_or(2),
-            rng.draw_card(&mut deck).unwrap_or(3),
-            rng.draw_card(&mut deck).unwrap_or(4),
-        ];
-
-        session.state_blob = serialize_state(Stage::Deal, &cards, VideoPokerRules::default());
+    fn init(session: &mut GameSession, _rng: &mut GameRng) -> GameResult {
+        // Cards are dealt once the game and paytable are chosen.
+        session.state_blob = serialize_state(
+            Stage::Betting,
+            &[CARD_UNKNOWN; 5],
+            VideoPokerRules::default(),
+        );
         GameResult::Continue(vec![])
     }
 
@@ -307,15 +785,40 @@ impl CasinoGame for VideoPoker {
 
         let mut state = parse_state(&session.state_blob).ok_or(GameError::InvalidPayload)?;
 
-        if state.stage != Stage::Deal {
-            return Err(GameError::GameAlreadyComplete);
-        }
+        match state.stage {
+            Stage::Betting => {
+                if !(2..=3).contains(&payload.len()) || payload[0] != SET_RULES_AND_DEAL {
+                    return Err(GameError::InvalidPayload);
+                }
+                let rules =
+                    VideoPokerRules::from_byte(payload[1]).ok_or(GameError::InvalidPayload)?;
+                let hand_count = payload.get(2).copied().unwrap_or(1);
+                if !HAND_COUNTS.contains(&hand_count) {
+                    return Err(GameError::InvalidPayload);
+                }
+                // Each extra hand stakes another `session.bet`.
+                let extra_wager = session.bet.saturating_mul(u64::from(hand_count - 1));
+                let extra_wager =
+                    i64::try_from(extra_wager).map_err(|_| GameError::InvalidPayload)?;
+
+                // Deal 5 cards
+                let mut deck = create_deck(rng, rules.game, &[]);
+                for card in state.cards.iter_mut() {
+                    *card = rng.draw_card(&mut deck).ok_or(GameError::InvalidMove)?;
+                }
 
-        if payload.len() == 2 && payload[0] == 0xFF {
-            let rules = VideoPokerRules::from_byte(payload[1]).ok_or(GameError::InvalidPayload)?;
-            state.rules = rules;
-            session.state_blob = serialize_state(state.stage, &state.cards, state.rules);
-            return Ok(GameResult::Continue(vec![]));
+                session.state_blob =
+                    serialize_hands(Stage::Deal, rules, hand_count, &[state.cards]);
+                if hand_count == 1 {
+                    return Ok(GameResult::Continue(vec![]));
+                }
+                return Ok(GameResult::ContinueWithUpdate {
+                    payout: -extra_wager,
+                    logs: vec![],
+                });
+            }
+            Stage::Deal => {}
+            Stage::Draw => return Err(GameError::GameAlreadyComplete),
         }
 
         if payload.len() != 1 {
@@ -325,63 +828,98 @@ impl CasinoGame for VideoPoker {
         let hold_mask = payload[0];
         session.move_count += 1;
 
-        // Build the draw deck from the remaining cards in the pack.
-        // All 5 originally-dealt cards are removed from the deck (even discards cannot be re-drawn).
+        // Every hand starts from the held cards and draws from its own deck.
+        // All 5 originally-dealt cards are removed from each deck (even discards cannot be re-drawn).
         let original_cards = state.cards;
-        let mut deck = rng.create_deck_excluding(&original_cards);
-
-        // Replace non-held cards
-        for (i, card) in state.cards.iter_mut().enumerate() {
-            if hold_mask & (1 << i) == 0 {
-                *card = rng.draw_card(&mut deck).ok_or(GameError::InvalidMove)?;
+        let mut hands = Vec::with_capacity(usize::from(state.hand_count));
+        for _ in 0..state.hand_count {
+            let mut deck = create_deck(rng, state.rules.game, &original_cards);
+            let mut cards = original_cards;
+            for (i, card) in cards.iter_mut().enumerate() {
+                if hold_mask & (1 << i) == 0 {
+                    *card = rng.draw_card(&mut deck).ok_or(GameError::InvalidMove)?;
+                }
             }
+            hands.push(cards);
         }
 
-        session.state_blob = serialize_state(Stage::Draw, &state.cards, state.rules);
+        session.state_blob = serialize_hands(Stage::Draw, state.rules, state.hand_count, &hands);
         session.is_complete = true;
 
-        // Evaluate final hand
-        let hand = evaluate_hand(&state.cards);
-        let multiplier = payout_multiplier(hand, state.rules.paytable);
+        // Evaluate final hands
+        let scored: Vec<(Hand, u64)> = hands
+            .iter()
+            .map(|cards| {
+                let hand = evaluate_for_game(cards, state.rules.game);
+                (hand, payout_multiplier(hand, state.rules))
+            })
+            .collect();
 
         // Pay tables are expressed as total return per 1 unit wagered.
-        let base_return = session.bet.saturating_mul(multiplier);
-        let total_return = if multiplier > 0 {
-            if session.super_mode.is_active {
-                apply_super_multiplier_cards(
-                    &state.cards,
-                    &session.super_mode.multipliers,
-                    base_return,
-                )
-            } else {
-                base_return
-            }
+        let returns: Vec<u64> = if session.super_mode.is_active {
+            let mega_hands: Vec<([u8; 5], u64, bool)> = hands
+                .iter()
+                .zip(&scored)
+                .map(|(cards, &(hand, multiplier))| {
+                    (
+                        *cards,
+                        session.bet.saturating_mul(multiplier),
+                        hand == Hand::RoyalFlush,
+                    )
+                })
+                .collect();
+            apply_video_poker_mega_multiplier(&mega_hands, &session.super_mode.multipliers)
         } else {
-            0
+            scored
+                .iter()
+                .map(|&(_, multiplier)| session.bet.saturating_mul(multiplier))
+                .collect()
+        };
+        let total_return = returns
+            .iter()
+            .fold(0u64, |total, amount| total.saturating_add(*amount));
+
+        let logs = if state.hand_count == 1 {
+            let (hand, multiplier) = scored[0];
+            let hand_name = hand_label(hand);
+            let hand_summary = hand_name.replace('_', " ");
+            let net_pnl = clamp_i64(i128::from(total_return) - i128::from(session.bet));
+            let resolved_bets = format!(r#"{{"label":"HAND","pnl":{}}}"#, net_pnl);
+            // Generate completion logs for frontend display
+            vec![format!(
+                r#"{{"summary":"Hand: {}","netPnl":{},"resolvedBets":[{}],"hand":"{}","handId":{},"multiplier":{},"totalReturn":{}}}"#,
+                hand_summary,
+                net_pnl,
+                resolved_bets,
+                hand_name,
+                hand as u8,
+                multiplier,
+                total_return
+            )]
+        } else {
+            multi_hand_logs(session.bet, &scored, &returns, total_return)
         };
-
-        let hand_name = hand_label(hand);
-        let hand_summary = hand_name.replace('_', " ");
-        let net_pnl = clamp_i64(i128::from(total_return) - i128::from(session.bet));
-        let resolved_bets = format!(r#"{{"label":"HAND","pnl":{}}}"#, net_pnl);
-        // Generate completion logs for frontend display
-        let logs = vec![format!(
-            r#"{{"summary":"Hand: {}","netPnl":{},"resolvedBets":[{}],"hand":"{}","handId":{},"multiplier":{},"totalReturn":{}}}"#,
-            hand_summary,
-            net_pnl,
-            resolved_bets,
-            hand_name,
-            hand as u8,
-            multiplier,
-            total_return
-        )];
 
         if total_return > 0 {
             Ok(GameResult::Win(total_return, logs))
+        } else if state.hand_count > 1 {
+            // The extra hands were charged at the deal.
+            let total_wagered = session.bet.saturating_mul(u64::from(state.hand_count));
+            Ok(GameResult::LossPreDeducted(total_wagered, logs))
         } else {
             Ok(GameResult::Loss(logs))
         }
     }
+
+    fn abandon(session: &mut GameSession, rng: &mut GameRng) -> Result<GameResult, GameError> {
+        let state = parse_state(&session.state_blob).ok_or(GameError::InvalidState)?;
+        if state.stage == Stage::Betting {
+            // No cards dealt yet: return the stake.
+            return Ok(GameResult::Push(session.bet, vec![]));
+        }
+        // Stand pat: every hand is scored as dealt.
+        Self::process_move(session, &[0b11111], rng)
+    }
 }
 
 #[cfg(test)]
@@ -507,28 +1045,444 @@ mod tests {
         assert_eq!(evaluate_hand(&cards), Hand::HighCard);
     }
 
+    fn rules(game: VideoPokerGame, paytable: VideoPokerPaytable) -> VideoPokerRules {
+        VideoPokerRules { game, paytable }
+    }
+
+    /// Start a session and deal under `rules`.
+    fn deal(session: &mut GameSession, seed: &nullspace_types::Seed, rules: VideoPokerRules) {
+        let mut rng = GameRng::new(seed, session.id, 0);
+        VideoPoker::init(session, &mut rng);
+        let mut rng = GameRng::new(seed, session.id, 1);
+        let result = VideoPoker::process_move(session, &[0xFF, rules.to_byte()], &mut rng);
+        assert!(matches!(result, Ok(GameResult::Continue(_))));
+    }
+
     #[test]
     fn test_payout_multipliers() {
+        let nine_six = VideoPokerRules::default();
+        let eight_five = rules(VideoPokerGame::JacksOrBetter, VideoPokerPaytable::ShortPay);
+        assert_eq!(payout_multiplier(Hand::HighCard, nine_six), 0);
+        assert_eq!(payout_multiplier(Hand::JacksOrBetter, nine_six), 1);
+        assert_eq!(payout_multiplier(Hand::TwoPair, nine_six), 2);
+        assert_eq!(payout_multiplier(Hand::RoyalFlush, nine_six), 800);
+        assert_eq!(payout_multiplier(Hand::Flush, eight_five), 5);
+    }
+
+    #[test]
+    fn test_family_payouts() {
+        use VideoPokerGame::*;
+        use VideoPokerPaytable::*;
+
+        assert_eq!(
+            payout_multiplier(Hand::FourDeuces, rules(DeucesWild, FullPay)),
+            200
+        );
         assert_eq!(
-            payout_multiplier(Hand::HighCard, VideoPokerPaytable::NineSix),
+            payout_multiplier(Hand::FiveOfAKind, rules(DeucesWild, FullPay)),
+            15
+        );
+        assert_eq!(
+            payout_multiplier(Hand::FiveOfAKind, rules(DeucesWild, ShortPay)),
+            16
+        );
+        assert_eq!(
+            payout_multiplier(Hand::TwoPair, rules(DeucesWild, FullPay)),
             0
         );
         assert_eq!(
-            payout_multiplier(Hand::JacksOrBetter, VideoPokerPaytable::NineSix),
+            payout_multiplier(Hand::FourAces, rules(BonusPoker, FullPay)),
+            80
+        );
+        assert_eq!(
+            payout_multiplier(Hand::FullHouse, rules(BonusPoker, ShortPay)),
+            7
+        );
+        assert_eq!(
+            payout_multiplier(Hand::FourAces, rules(DoubleBonus, FullPay)),
+            160
+        );
+        assert_eq!(
+            payout_multiplier(Hand::TwoPair, rules(DoubleBonus, FullPay)),
             1
         );
         assert_eq!(
-            payout_multiplier(Hand::TwoPair, VideoPokerPaytable::NineSix),
-            2
+            payout_multiplier(Hand::FourAcesWithKicker, rules(DoubleDoubleBonus, FullPay)),
+            400
+        );
+        assert_eq!(
+            payout_multiplier(
+                Hand::FourTwosThroughFoursWithKicker,
+                rules(DoubleDoubleBonus, ShortPay)
+            ),
+            160
+        );
+        assert_eq!(
+            payout_multiplier(Hand::KingsOrBetter, rules(JokerPoker, FullPay)),
+            1
+        );
+        assert_eq!(
+            payout_multiplier(Hand::JacksOrBetter, rules(JokerPoker, FullPay)),
+            0
+        );
+        assert_eq!(
+            payout_multiplier(Hand::FourOfAKind, rules(JokerPoker, ShortPay)),
+            17
+        );
+    }
+
+    #[test]
+    fn test_rules_byte_roundtrip() {
+        for byte in 0..12u8 {
+            let parsed = VideoPokerRules::from_byte(byte).expect("valid rules byte");
+            assert_eq!(parsed.to_byte(), byte);
+        }
+        assert_eq!(
+            VideoPokerRules::from_byte(0b1001).map(|r| r.game),
+            Some(VideoPokerGame::DoubleDoubleBonus)
+        );
+        assert!(VideoPokerRules::from_byte(12).is_none());
+        assert!(VideoPokerRules::from_byte(14).is_none());
+    }
+
+    #[test]
+    fn test_bonus_quads_by_rank() {
+        let aces = [0, 13, 26, 39, 5]; // A-A-A-A-6
+        let twos = [1, 14, 27, 40, 9]; // 2-2-2-2-10
+        let kings = [12, 25, 38, 51, 1]; // K-K-K-K-2
+        assert_eq!(evaluate_bonus_hand(&aces, false), Hand::FourAces);
+        assert_eq!(
+            evaluate_bonus_hand(&twos, false),
+            Hand::FourTwosThroughFours
+        );
+        assert_eq!(evaluate_bonus_hand(&kings, false), Hand::FourOfAKind);
+        assert_eq!(
+            evaluate_bonus_hand(&[0, 13, 26, 1, 2], false),
+            Hand::ThreeOfAKind
+        );
+    }
+
+    #[test]
+    fn test_double_double_bonus_kickers() {
+        let aces_low_kicker = [0, 13, 26, 39, 3]; // A-A-A-A-4
+        let aces_high_kicker = [0, 13, 26, 39, 4]; // A-A-A-A-5
+        let threes_ace_kicker = [2, 15, 28, 41, 0]; // 3-3-3-3-A
+        let threes_king_kicker = [2, 15, 28, 41, 12]; // 3-3-3-3-K
+        let kings_ace_kicker = [12, 25, 38, 51, 0]; // K-K-K-K-A
+        assert_eq!(
+            evaluate_bonus_hand(&aces_low_kicker, true),
+            Hand::FourAcesWithKicker
+        );
+        assert_eq!(evaluate_bonus_hand(&aces_high_kicker, true), Hand::FourAces);
+        assert_eq!(
+            evaluate_bonus_hand(&threes_ace_kicker, true),
+            Hand::FourTwosThroughFoursWithKicker
         );
         assert_eq!(
-            payout_multiplier(Hand::RoyalFlush, VideoPokerPaytable::NineSix),
-            800
+            evaluate_bonus_hand(&threes_king_kicker, true),
+            Hand::FourTwosThroughFours
         );
         assert_eq!(
-            payout_multiplier(Hand::Flush, VideoPokerPaytable::EightFive),
-            5
+            evaluate_bonus_hand(&kings_ace_kicker, true),
+            Hand::FourOfAKind
+        );
+    }
+
+    #[test]
+    fn test_deuces_wild_evaluation() {
+        // Deuces are cards 1, 14, 27, 40.
+        assert_eq!(evaluate_deuces_wild(&[1, 14, 27, 40, 12]), Hand::FourDeuces);
+        assert_eq!(evaluate_deuces_wild(&[9, 10, 11, 12, 0]), Hand::RoyalFlush);
+        assert_eq!(
+            evaluate_deuces_wild(&[9, 10, 1, 12, 0]),
+            Hand::WildRoyalFlush
+        );
+        assert_eq!(evaluate_deuces_wild(&[6, 19, 32, 1, 14]), Hand::FiveOfAKind);
+        assert_eq!(evaluate_deuces_wild(&[4, 5, 1, 8, 27]), Hand::StraightFlush);
+        assert_eq!(evaluate_deuces_wild(&[6, 19, 1, 14, 30]), Hand::FourOfAKind);
+        assert_eq!(evaluate_deuces_wild(&[6, 19, 8, 21, 1]), Hand::FullHouse);
+        assert_eq!(evaluate_deuces_wild(&[0, 4, 6, 1, 11]), Hand::Flush);
+        assert_eq!(evaluate_deuces_wild(&[0, 1, 15, 29, 43]), Hand::Straight);
+        assert_eq!(
+            evaluate_deuces_wild(&[6, 19, 1, 30, 12]),
+            Hand::ThreeOfAKind
+        );
+        // A deuce with a King is only a pair: not a paying hand.
+        assert_eq!(evaluate_deuces_wild(&[12, 1, 17, 32, 47]), Hand::HighCard);
+    }
+
+    #[test]
+    fn test_joker_poker_evaluation() {
+        assert_eq!(evaluate_joker_poker(&[9, 10, 11, 12, 0]), Hand::RoyalFlush);
+        assert_eq!(
+            evaluate_joker_poker(&[9, 10, JOKER, 12, 0]),
+            Hand::WildRoyalFlush
+        );
+        assert_eq!(
+            evaluate_joker_poker(&[0, 13, 26, 39, JOKER]),
+            Hand::FiveOfAKind
+        );
+        assert_eq!(
+            evaluate_joker_poker(&[4, 5, JOKER, 7, 8]),
+            Hand::StraightFlush
+        );
+        assert_eq!(
+            evaluate_joker_poker(&[11, 24, JOKER, 1, 17]),
+            Hand::ThreeOfAKind
+        );
+        assert_eq!(
+            evaluate_joker_poker(&[11, 24, 1, 14, JOKER]),
+            Hand::FullHouse
+        );
+        // Joker + King makes Kings or Better; a pair of Queens does not pay.
+        assert_eq!(
+            evaluate_joker_poker(&[12, JOKER, 3, 18, 33]),
+            Hand::KingsOrBetter
+        );
+        assert_eq!(evaluate_joker_poker(&[11, 24, 3, 18, 33]), Hand::HighCard);
+        assert_eq!(
+            evaluate_joker_poker(&[0, 13, 3, 18, 33]),
+            Hand::KingsOrBetter
+        );
+    }
+
+    #[test]
+    fn test_joker_poker_uses_53_card_deck() {
+        let seed = create_test_seed();
+        let mut rng = GameRng::new(&seed, 1, 0);
+        let deck = create_deck(&mut rng, VideoPokerGame::JokerPoker, &[]);
+        assert_eq!(deck.len(), 53);
+        assert!(deck.contains(&JOKER));
+
+        let deck = create_deck(&mut rng, VideoPokerGame::JokerPoker, &[JOKER, 0, 1]);
+        assert_eq!(deck.len(), 50);
+        assert!(!deck.contains(&JOKER));
+
+        let deck = create_deck(&mut rng, VideoPokerGame::DeucesWild, &[]);
+        assert_eq!(deck.len(), 52);
+        assert!(!deck.contains(&JOKER));
+    }
+
+    #[test]
+    fn test_joker_only_valid_in_joker_poker() {
+        let cards = [JOKER, 1, 2, 3, 4];
+        let joker_rules = rules(VideoPokerGame::JokerPoker, VideoPokerPaytable::FullPay);
+        assert!(parse_state(&serialize_state(Stage::Deal, &cards, joker_rules)).is_some());
+        assert!(parse_state(&serialize_state(
+            Stage::Deal,
+            &cards,
+            VideoPokerRules::default()
+        ))
+        .is_none());
+    }
+
+    #[test]
+    fn test_rules_locked_after_deal() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let deuces = rules(VideoPokerGame::DeucesWild, VideoPokerPaytable::FullPay);
+        deal(&mut session, &seed, deuces);
+
+        let parsed = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(parsed.stage, Stage::Deal);
+        assert_eq!(parsed.rules, deuces);
+
+        // The game cannot be switched once the cards are visible.
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let result = VideoPoker::process_move(&mut session, &[0xFF, 0], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+    }
+
+    #[test]
+    fn test_hold_mask_rejected_before_deal() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        VideoPoker::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = VideoPoker::process_move(&mut session, &[0b11111], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        let result = VideoPoker::process_move(&mut session, &[0xFF, 12], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+    }
+
+    #[test]
+    fn test_abandon_before_deal_refunds() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        VideoPoker::init(&mut session, &mut rng);
+
+        let result = VideoPoker::abandon(&mut session, &mut rng);
+        assert!(matches!(result, Ok(GameResult::Push(100, _))));
+    }
+
+    #[test]
+    fn test_deuces_wild_payout() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+
+        // Two deuces with a pair of Sevens: four of a kind.
+        let cards = [6, 19, 1, 14, 30];
+        let deuces = rules(VideoPokerGame::DeucesWild, VideoPokerPaytable::FullPay);
+        session.state_blob = serialize_state(Stage::Deal, &cards, deuces);
+
+        let result = VideoPoker::process_move(&mut session, &[0b11111], &mut rng)
+            .expect("Failed to process move");
+        assert!(matches!(result, GameResult::Win(500, _)));
+    }
+
+    #[test]
+    fn test_multi_hand_deal_charges_extra_hands() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        VideoPoker::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = VideoPoker::process_move(&mut session, &[0xFF, 0, 5], &mut rng);
+        assert!(matches!(
+            result,
+            Ok(GameResult::ContinueWithUpdate { payout: -400, .. })
+        ));
+
+        let parsed = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(parsed.stage, Stage::Deal);
+        assert_eq!(parsed.hand_count, 5);
+        // Only hand 1 is dealt; the other hands are drawn from the held cards.
+        assert_eq!(session.state_blob.len(), STATE_LEN_WITH_RULES + 1);
+    }
+
+    #[test]
+    fn test_multi_hand_deal_rejects_unrepresentable_wager() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(u64::MAX / 4);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        VideoPoker::init(&mut session, &mut rng);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let blob = session.state_blob.clone();
+        let result = VideoPoker::process_move(&mut session, &[0xFF, 0, 10], &mut rng);
+        assert!(matches!(result, Err(GameError::InvalidPayload)));
+        assert_eq!(session.state_blob, blob);
+    }
+
+    #[test]
+    fn test_invalid_hand_count_rejected() {
+        let seed = create_test_seed();
+        for hands in [0u8, 2, 4, 51, 255] {
+            let mut session = create_test_session(100);
+            let mut rng = GameRng::new(&seed, session.id, 0);
+            VideoPoker::init(&mut session, &mut rng);
+            let result = VideoPoker::process_move(&mut session, &[0xFF, 0, hands], &mut rng);
+            assert!(matches!(result, Err(GameError::InvalidPayload)));
+        }
+
+        // An explicit single hand is the same as omitting the count.
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        VideoPoker::init(&mut session, &mut rng);
+        let result = VideoPoker::process_move(&mut session, &[0xFF, 0, 1], &mut rng);
+        assert!(matches!(result, Ok(GameResult::Continue(_))));
+        assert_eq!(session.state_blob.len(), STATE_LEN_WITH_RULES);
+    }
+
+    #[test]
+    fn test_multi_hand_draw() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        VideoPoker::init(&mut session, &mut rng);
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        VideoPoker::process_move(&mut session, &[0xFF, 0, 10], &mut rng).expect("Failed to deal");
+        let dealt = parse_state(&session.state_blob)
+            .expect("Failed to parse state")
+            .cards;
+
+        // Hold the first two cards.
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let result = VideoPoker::process_move(&mut session, &[0b00011], &mut rng)
+            .expect("Failed to process move");
+        assert!(session.is_complete);
+        assert_eq!(session.state_blob.len(), STATE_LEN_WITH_RULES + 1 + 9 * 5);
+
+        let parsed = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(parsed.stage, Stage::Draw);
+        assert_eq!(parsed.hand_count, 10);
+
+        let mut hands = vec![parsed.cards];
+        for hand in session.state_blob[STATE_LEN_WITH_RULES + 1..].chunks_exact(5) {
+            hands.push(hand.try_into().expect("5 cards"));
+        }
+        let mut expected_return = 0;
+        for hand in &hands {
+            assert_eq!(hand[..2], dealt[..2]);
+            // Discarded cards never come back in any hand.
+            assert!(hand[2..].iter().all(|card| !dealt.contains(card)));
+            let multiplier = payout_multiplier(evaluate_hand(hand), VideoPokerRules::default());
+            expected_return += 100 * multiplier;
+        }
+        // The hands draw independently.
+        assert!(hands.iter().any(|hand| hand[2..] != hands[0][2..]));
+
+        match result {
+            GameResult::Win(total_return, _) => assert_eq!(total_return, expected_return),
+            GameResult::LossPreDeducted(total_wagered, _) => {
+                assert_eq!(expected_return, 0);
+                assert_eq!(total_wagered, 1_000);
+            }
+            _ => panic!("unexpected multi-hand result"),
+        }
+    }
+
+    #[test]
+    fn test_multi_hand_results_are_aggregated() {
+        let seed = create_test_seed();
+        let default_rules = VideoPokerRules::default();
+
+        // J-J-2-3-4 held on three hands pays 1x each.
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        session.state_blob = serialize_hands(Stage::Deal, default_rules, 3, &[[10, 23, 1, 2, 3]]);
+        let result = VideoPoker::process_move(&mut session, &[0b11111], &mut rng)
+            .expect("Failed to process move");
+        assert!(matches!(result, GameResult::Win(300, _)));
+
+        // A losing hand held on three hands loses every stake, already charged.
+        let mut session = create_test_session(100);
+        let mut rng = GameRng::new(&seed, session.id, 0);
+        session.state_blob = serialize_hands(Stage::Deal, default_rules, 3, &[[0, 15, 30, 45, 8]]);
+        let result = VideoPoker::process_move(&mut session, &[0b11111], &mut rng)
+            .expect("Failed to process move");
+        assert!(matches!(result, GameResult::LossPreDeducted(300, _)));
+    }
+
+    #[test]
+    fn test_super_mode_boosts_every_hand() {
+        use nullspace_types::casino::{SuperMultiplier, SuperType};
+
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        session.super_mode.is_active = true;
+        session.super_mode.multipliers = vec![SuperMultiplier {
+            id: 10,
+            multiplier: 1,
+            super_type: SuperType::Card,
+        }];
+        let mut rng = GameRng::new(&seed, session.id, 0);
+
+        // The held Mega Card (J) is copied to all three hands: 1.2x each.
+        session.state_blob = serialize_hands(
+            Stage::Deal,
+            VideoPokerRules::default(),
+            3,
+            &[[10, 23, 1, 2, 3]],
         );
+        let result = VideoPoker::process_move(&mut session, &[0b11111], &mut rng)
+            .expect("Failed to process move");
+        assert!(matches!(result, GameResult::Win(360, _)));
     }
 
     #[test]
@@ -540,6 +1494,13 @@ mod tests {
         VideoPoker::init(&mut session, &mut rng);
         assert!(!session.is_complete);
 
+        let parsed = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(parsed.stage, Stage::Betting);
+
+        let mut rng = GameRng::new(&seed, session.id, 1);
+        let result = VideoPoker::process_move(&mut session, &[0xFF, 0], &mut rng);
+        assert!(matches!(result, Ok(GameResult::Continue(_))));
+
         let parsed = parse_state(&session.state_blob).expect("Failed to parse state");
         assert_eq!(parsed.stage, Stage::Deal);
         for card in parsed.cards {
@@ -547,7 +1508,7 @@ mod tests {
         }
 
         // Hold all cards
-        let mut rng = GameRng::new(&seed, session.id, 1);
+        let mut rng = GameRng::new(&seed, session.id, 2);
         let result = VideoPoker::process_move(&mut session, &[0b11111], &mut rng);
 
         assert!(result.is_ok());
@@ -555,18 +1516,33 @@ mod tests {
     }
 
     #[test]
-    fn test_discard_all() {
+    fn test_abandon_stands_pat() {
         let seed = create_test_seed();
         let mut session = create_test_session(100);
-        let mut rng = GameRng::new(&seed, session.id, 0);
+        deal(&mut session, &seed, VideoPokerRules::default());
+        let dealt = parse_state(&session.state_blob).expect("Failed to parse state");
 
-        VideoPoker::init(&mut session, &mut rng);
+        let mut rng = GameRng::new(&seed, session.id, 2);
+        let result = VideoPoker::abandon(&mut session, &mut rng);
+        assert!(result.is_ok());
+        assert!(session.is_complete);
+
+        let parsed = parse_state(&session.state_blob).expect("Failed to parse state");
+        assert_eq!(parsed.stage, Stage::Draw);
+        assert_eq!(parsed.cards, dealt.cards);
+    }
+
+    #[test]
+    fn test_discard_all() {
+        let seed = create_test_seed();
+        let mut session = create_test_session(100);
+        deal(&mut session, &seed, VideoPokerRules::default());
         let original_cards = parse_state(&session.state_blob)
             .expect("Failed to parse state")
             .cards;
 
         // Discard all cards (hold none)
-        let mut rng = GameRng::new(&seed, session.id, 1);
+        let mut rng = GameRng::new(&seed, session.id, 2);
         let result = VideoPoker::process_move(&mut session, &[0], &mut rng);
 
         assert!(result.is_ok());
@@ -0,0 +1,137 @@
+use super::super::*;
+use super::casino_error_vec;
+use nullspace_types::casino::{AdminRegistry, AdminRole};
+
+impl<'a, S: State> Layer<'a, S> {
+    pub(in crate::layer) async fn get_admin_registry(
+        &self,
+    ) -> anyhow::Result<Option<AdminRegistry>> {
+        Ok(match self.get(Key::AdminRegistry).await? {
+            Some(Value::AdminRegistry(registry)) => Some(registry),
+            _ => None,
+        })
+    }
+
+    /// Persist the genesis admin registry if the chain does not have one yet.
+    pub(in crate::layer) async fn bootstrap_admin_registry(
+        &mut self,
+        genesis: &GenesisConfig,
+    ) -> anyhow::Result<()> {
+        if genesis.admin_public_keys.is_empty() || self.get_admin_registry().await?.is_some() {
+            return Ok(());
+        }
+        self.insert(
+            Key::AdminRegistry,
+            Value::AdminRegistry(AdminRegistry::with_full_access(
+                genesis.admin_public_keys.iter().cloned(),
+            )),
+        );
+        Ok(())
+    }
+
+    pub(in crate::layer) async fn has_admin_role(
+        &self,
+        public: &PublicKey,
+        role: AdminRole,
+    ) -> anyhow::Result<bool> {
+        // Inside an approved multisig proposal, authority comes from the multisig alone.
+        if self.multisig_roles != 0 {
+            return Ok(self.multisig_roles & role.bit() != 0);
+        }
+        Ok(self
+            .get_admin_registry()
+            .await?
+            .is_some_and(|registry| registry.has_role(public, role)))
+    }
+
+    pub(in crate::layer) async fn admin_registry_for_update(
+        &self,
+        public: &PublicKey,
+    ) -> anyhow::Result<Result<AdminRegistry, Vec<Event>>> {
+        if !self.has_admin_role(public, AdminRole::Registry).await? {
+            return Ok(Err(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Unauthorized admin instruction",
+            )));
+        }
+        Ok(Ok(self.get_admin_registry().await?.unwrap_or_default()))
+    }
+
+    pub(in crate::layer) async fn handle_grant_admin_role(
+        &mut self,
+        public: &PublicKey,
+        grantee: &PublicKey,
+        role: AdminRole,
+    ) -> anyhow::Result<Vec<Event>> {
+        let mut registry = match self.admin_registry_for_update(public).await? {
+            Ok(registry) => registry,
+            Err(events) => return Ok(events),
+        };
+
+        if registry.holds_role(grantee, role) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Key already holds this admin role",
+            ));
+        }
+        if !registry.grant(grantee, role) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Admin registry is full",
+            ));
+        }
+
+        self.insert(Key::AdminRegistry, Value::AdminRegistry(registry));
+
+        Ok(vec![Event::AdminRoleGranted {
+            admin: public.clone(),
+            public: grantee.clone(),
+            role,
+        }])
+    }
+
+    pub(in crate::layer) async fn handle_revoke_admin_role(
+        &mut self,
+        public: &PublicKey,
+        revokee: &PublicKey,
+        role: AdminRole,
+    ) -> anyhow::Result<Vec<Event>> {
+        let mut registry = match self.admin_registry_for_update(public).await? {
+            Ok(registry) => registry,
+            Err(events) => return Ok(events),
+        };
+
+        if !registry.holds_role(revokee, role) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Key does not hold this admin role",
+            ));
+        }
+        // Keep at least one key able to manage roles, otherwise the registry is frozen.
+        if role == AdminRole::Registry && registry.role_count(AdminRole::Registry) <= 1 {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Cannot revoke the last registry admin",
+            ));
+        }
+        registry.revoke(revokee, role);
+
+        self.insert(Key::AdminRegistry, Value::AdminRegistry(registry));
+
+        Ok(vec![Event::AdminRoleRevoked {
+            admin: public.clone(),
+            public: revokee.clone(),
+            role,
+        }])
+    }
+}
@@ -190,7 +190,10 @@ impl<'a, S: State> Layer<'a, S> {
         amount: u64,
         source: &[u8],
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Bridge)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -255,7 +258,10 @@ impl<'a, S: State> Layer<'a, S> {
         withdrawal_id: u64,
         source: &[u8],
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Bridge)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
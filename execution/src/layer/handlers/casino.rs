@@ -99,6 +99,38 @@ fn award_freeroll_credits(
     }
 }
 
+/// Debits a tournament buy-in from the player's balance in the event currency.
+/// Returns false (leaving the player untouched) if the balance is too low.
+fn debit_tournament_currency(
+    player: &mut nullspace_types::casino::Player,
+    currency: nullspace_types::casino::TournamentCurrency,
+    amount: u64,
+) -> bool {
+    let balance = match currency {
+        nullspace_types::casino::TournamentCurrency::Chips => &mut player.balances.chips,
+        nullspace_types::casino::TournamentCurrency::Vusdt => &mut player.balances.vusdt_balance,
+    };
+    match balance.checked_sub(amount) {
+        Some(remaining) => {
+            *balance = remaining;
+            true
+        }
+        None => false,
+    }
+}
+
+fn credit_tournament_currency(
+    player: &mut nullspace_types::casino::Player,
+    currency: nullspace_types::casino::TournamentCurrency,
+    amount: u64,
+) {
+    let balance = match currency {
+        nullspace_types::casino::TournamentCurrency::Chips => &mut player.balances.chips,
+        nullspace_types::casino::TournamentCurrency::Vusdt => &mut player.balances.vusdt_balance,
+    };
+    *balance = balance.saturating_add(amount);
+}
+
 fn record_play_session(
     player: &mut nullspace_types::casino::Player,
     session: &nullspace_types::casino::GameSession,
@@ -404,11 +436,13 @@ impl<'a, S: State> Layer<'a, S> {
         // Determine play mode (cash vs tournament)
         let mut is_tournament = false;
         let mut tournament_id = None;
+        let mut tournament_config = None;
         if let Some(active_tid) = player.tournament.active_tournament {
             if let Some(Value::Tournament(t)) = self.get(Key::Tournament(active_tid)).await? {
                 if matches!(t.phase, nullspace_types::casino::TournamentPhase::Active) {
                     is_tournament = true;
                     tournament_id = Some(active_tid);
+                    tournament_config = Some(t.config);
                 } else {
                     player.tournament.active_tournament = None;
                 }
@@ -417,16 +451,9 @@ impl<'a, S: State> Layer<'a, S> {
             }
         }
 
-        // Some table-style games place all wagers via `CasinoGameMove` deductions (ContinueWithUpdate),
-        // so they can start with `bet = 0` without charging an extra "entry fee".
-        let allows_zero_bet = matches!(
-            game_type,
-            nullspace_types::casino::GameType::Baccarat
-                | nullspace_types::casino::GameType::Craps
-                | nullspace_types::casino::GameType::Roulette
-                | nullspace_types::casino::GameType::SicBo
-        );
-        if bet == 0 && !allows_zero_bet {
+        // Games whose wagers are charged after the start (see `GameType::allows_zero_bet`)
+        // open with `bet = 0` so they don't pay an extra "entry fee".
+        if bet == 0 && !game_type.allows_zero_bet() {
             return Ok(casino_error_vec(
                 public,
                 Some(session_id),
@@ -434,6 +461,35 @@ impl<'a, S: State> Layer<'a, S> {
                 "Bet must be greater than zero",
             ));
         }
+        // Themed tournaments restrict which games their chips play and how much each opens.
+        if let Some(config) = &tournament_config {
+            if !config.allows_game(game_type) {
+                return Ok(casino_error_vec(
+                    public,
+                    Some(session_id),
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    format!("{game_type:?} is not played in this tournament"),
+                ));
+            }
+            if let Some(limit) = config.bet_limit(game_type) {
+                if !limit.allows(bet) {
+                    let message = if limit.max_bet > 0 {
+                        format!(
+                            "Tournament bet must be between {} and {}",
+                            limit.min_bet, limit.max_bet
+                        )
+                    } else {
+                        format!("Tournament bet must be at least {}", limit.min_bet)
+                    };
+                    return Ok(casino_error_vec(
+                        public,
+                        Some(session_id),
+                        nullspace_types::casino::ERROR_INVALID_BET,
+                        message,
+                    ));
+                }
+            }
+        }
         let wants_super = player.modifiers.active_super;
         let super_fee = if wants_super && bet > 0 {
             crate::casino::get_super_mode_fee(bet)
@@ -468,6 +524,10 @@ impl<'a, S: State> Layer<'a, S> {
             ));
         }
 
+        // The previous session is no longer needed once this one starts.
+        self.release_previous_session(&mut player, session_id)
+            .await?;
+
         // Deduct bet (and any upfront super fee) from player
         if is_tournament {
             player.tournament.chips = player.tournament.chips.saturating_sub(required_stack);
@@ -534,6 +594,11 @@ impl<'a, S: State> Layer<'a, S> {
             Key::CasinoSession(session_id),
             Value::CasinoSession(session.clone()),
         );
+        if session.is_complete {
+            self.schedule_session_gc(session_id).await?;
+        } else {
+            self.schedule_session_expiry(&session).await?;
+        }
 
         let mut events = vec![Event::CasinoGameStarted {
             session_id,
@@ -735,7 +800,6 @@ impl<'a, S: State> Layer<'a, S> {
             Ok(session) => session,
             Err(events) => return Ok(events),
         };
-        let now = self.seed_view.saturating_mul(SECS_PER_VIEW);
         let payload_len = payload.len();
         let payload_action = payload.first().copied();
 
@@ -771,6 +835,47 @@ impl<'a, S: State> Layer<'a, S> {
             "casino move processed"
         );
 
+        let atomic_wager = if session.move_count == 1
+            && matches!(
+                session.game_type,
+                nullspace_types::casino::GameType::Baccarat
+                    | nullspace_types::casino::GameType::BigSix
+                    | nullspace_types::casino::GameType::Craps
+                    | nullspace_types::casino::GameType::Crash
+                    | nullspace_types::casino::GameType::Keno
+                    | nullspace_types::casino::GameType::Roulette
+                    | nullspace_types::casino::GameType::SicBo
+            ) {
+            session.bet
+        } else {
+            0
+        };
+        let atomic_super_fee = if atomic_wager > 0 && session.super_mode.is_active {
+            crate::casino::get_super_mode_fee(atomic_wager)
+        } else {
+            0
+        };
+        let atomic_total = atomic_wager.saturating_add(atomic_super_fee);
+
+        self.settle_casino_move(public, session, result, atomic_total)
+            .await
+    }
+
+    /// Apply a game result to the session and player, emitting `CasinoGameMoved` plus any
+    /// completion events.
+    ///
+    /// `atomic_total` is the wager charged on completion when a table game placed and resolved
+    /// its bets in a single move.
+    pub(in crate::layer) async fn settle_casino_move(
+        &mut self,
+        public: &PublicKey,
+        mut session: nullspace_types::casino::GameSession,
+        result: crate::casino::GameResult,
+        atomic_total: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        let session_id = session.id;
+        let now = self.seed_view.saturating_mul(SECS_PER_VIEW);
+
         let result = self
             .apply_progressive_meters_for_completion(&session, result)
             .await?;
@@ -794,28 +899,10 @@ impl<'a, S: State> Layer<'a, S> {
         let mut move_balances: Option<nullspace_types::casino::PlayerBalanceSnapshot> = None;
         let move_logs: Vec<String>;
 
-        let atomic_wager = if session.move_count == 1
-            && matches!(
-                session.game_type,
-                nullspace_types::casino::GameType::Baccarat
-                    | nullspace_types::casino::GameType::Craps
-                    | nullspace_types::casino::GameType::Roulette
-                    | nullspace_types::casino::GameType::SicBo
-            ) {
-            session.bet
-        } else {
-            0
-        };
-        let atomic_super_fee = if atomic_wager > 0 && session.super_mode.is_active {
-            crate::casino::get_super_mode_fee(atomic_wager)
-        } else {
-            0
-        };
-        let atomic_total = atomic_wager.saturating_add(atomic_super_fee);
-
         match result {
             crate::casino::GameResult::Continue(logs) => {
                 move_logs = logs;
+                self.schedule_session_expiry(&session).await?;
                 self.insert(
                     Key::CasinoSession(session_id),
                     Value::CasinoSession(session),
@@ -904,6 +991,7 @@ impl<'a, S: State> Layer<'a, S> {
                         events.push(event);
                     }
                 }
+                self.schedule_session_expiry(&session).await?;
                 self.insert(
                     Key::CasinoSession(session_id),
                     Value::CasinoSession(session),
@@ -916,6 +1004,7 @@ impl<'a, S: State> Layer<'a, S> {
                     Key::CasinoSession(session_id),
                     Value::CasinoSession(session.clone()),
                 );
+                self.schedule_session_gc(session_id).await?;
 
                 // Get player for modifier state
                 if let Some(Value::CasinoPlayer(mut player)) =
@@ -1002,6 +1091,7 @@ impl<'a, S: State> Layer<'a, S> {
                     Key::CasinoSession(session_id),
                     Value::CasinoSession(session.clone()),
                 );
+                self.schedule_session_gc(session_id).await?;
 
                 if let Some(Value::CasinoPlayer(mut player)) =
                     self.get(Key::CasinoPlayer(public.clone())).await?
@@ -1078,6 +1168,7 @@ impl<'a, S: State> Layer<'a, S> {
                     Key::CasinoSession(session_id),
                     Value::CasinoSession(session.clone()),
                 );
+                self.schedule_session_gc(session_id).await?;
 
                 if let Some(Value::CasinoPlayer(mut player)) =
                     self.get(Key::CasinoPlayer(public.clone())).await?
@@ -1159,6 +1250,7 @@ impl<'a, S: State> Layer<'a, S> {
                     Key::CasinoSession(session_id),
                     Value::CasinoSession(session.clone()),
                 );
+                self.schedule_session_gc(session_id).await?;
 
                 if let Some(Value::CasinoPlayer(mut player)) =
                     self.get(Key::CasinoPlayer(public.clone())).await?
@@ -1256,6 +1348,7 @@ impl<'a, S: State> Layer<'a, S> {
                     Key::CasinoSession(session_id),
                     Value::CasinoSession(session.clone()),
                 );
+                self.schedule_session_gc(session_id).await?;
 
                 if let Some(Value::CasinoPlayer(mut player)) =
                     self.get(Key::CasinoPlayer(public.clone())).await?
@@ -1420,68 +1513,170 @@ impl<'a, S: State> Layer<'a, S> {
         }])
     }
 
-    pub(in crate::layer) async fn handle_casino_join_tournament(
+    pub(in crate::layer) async fn handle_casino_create_tournament(
         &mut self,
         public: &PublicKey,
         tournament_id: u64,
+        config: &nullspace_types::casino::TournamentConfig,
     ) -> anyhow::Result<Vec<Event>> {
-        let mut player = match self.casino_player_or_error(public, None).await? {
-            Ok(player) => player,
-            Err(events) => return Ok(events),
-        };
-
-        // Check tournament limit (per-player daily limit).
-        // Approximate time from view (3s per block)
-        let current_time_sec = self.seed_view.saturating_mul(SECS_PER_VIEW);
-        let current_day = current_time_sec / 86400;
-        let last_played_day = player.tournament.last_tournament_ts / 86400;
-
-        if current_day > last_played_day {
-            player.tournament.tournaments_played_today = 0;
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Tournament)
+            .await?
+        {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Unauthorized admin instruction",
+            ));
         }
-
-        if player.tournament.last_tournament_ts > 0 {
-            let since_last =
-                current_time_sec.saturating_sub(player.tournament.last_tournament_ts);
-            if since_last < nullspace_types::casino::TOURNAMENT_JOIN_COOLDOWN_SECS {
+        if config.is_freeroll() && (config.rake_bps > 0 || config.guarantee > 0) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_BET,
+                "Freeroll tournaments cannot take rake or carry a guarantee",
+            ));
+        }
+        if config.rake_bps > nullspace_types::casino::MAX_TOURNAMENT_RAKE_BPS {
+            let message = format!(
+                "Tournament rake exceeds {} bps",
+                nullspace_types::casino::MAX_TOURNAMENT_RAKE_BPS
+            );
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_BET,
+                &message,
+            ));
+        }
+        if config.is_sit_and_go() {
+            if config.max_seats < 2
+                || config.max_seats > nullspace_types::casino::MAX_SIT_AND_GO_SEATS
+            {
+                let message = format!(
+                    "Sit-and-go seats must be between 2 and {}",
+                    nullspace_types::casino::MAX_SIT_AND_GO_SEATS
+                );
                 return Ok(casino_error_vec(
                     public,
                     None,
-                    nullspace_types::casino::ERROR_TOURNAMENT_LIMIT_REACHED,
-                    "Tournament cooldown active, try again later",
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    &message,
+                ));
+            }
+            if config.duration_secs == 0 {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Sit-and-go tournaments need a duration",
+                ));
+            }
+            // Sit-and-go events start the moment they fill, so there is no admin step at
+            // which the treasury could commit an overlay.
+            if config.guarantee > 0 {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_BET,
+                    "Sit-and-go tournaments cannot carry a guarantee",
                 ));
             }
+        } else if config.duration_secs > 0 {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Only sit-and-go tournaments take a duration",
+            ));
         }
-
-        let base_limit = if player.tournament.daily_limit > 0 {
-            player.tournament.daily_limit
-        } else {
-            nullspace_types::casino::FREEROLL_DAILY_LIMIT_FREE
-        };
-        let account_age = if player.profile.created_ts == 0 {
-            0
-        } else {
-            current_time_sec.saturating_sub(player.profile.created_ts)
-        };
-        let daily_limit = if account_age < nullspace_types::casino::ACCOUNT_TIER_NEW_SECS {
-            base_limit.min(nullspace_types::casino::FREEROLL_DAILY_LIMIT_TRIAL)
-        } else {
-            base_limit
-        };
-        if player.tournament.tournaments_played_today >= daily_limit {
-            let message = format!(
-                "Daily tournament limit reached ({}/{})",
-                player.tournament.tournaments_played_today, daily_limit
-            );
+        for (label, rule) in [("Rebuys", &config.rebuy), ("Add-ons", &config.add_on)] {
+            if !rule.is_enabled() {
+                continue;
+            }
+            // Freeroll prize pools are minted, so there is no pool for purchases to join.
+            if config.is_freeroll() {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_BET,
+                    format!("{label} are only offered in paid tournaments"),
+                ));
+            }
+            if rule.cost == 0 || rule.chips == 0 || rule.window_secs == 0 {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_BET,
+                    format!("{label} need a cost, chips and a window"),
+                ));
+            }
+        }
+        if let Err(message) = config.validate_game_rules() {
             return Ok(casino_error_vec(
                 public,
                 None,
-                nullspace_types::casino::ERROR_TOURNAMENT_LIMIT_REACHED,
-                &message,
+                nullspace_types::casino::ERROR_INVALID_BET,
+                message,
+            ));
+        }
+        if let Err(message) = config.payout_structure.validate() {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                message,
+            ));
+        }
+        if self.get(Key::Tournament(tournament_id)).await?.is_some() {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Tournament already exists",
             ));
         }
 
-        // Get or create tournament
+        let tournament = nullspace_types::casino::Tournament {
+            id: tournament_id,
+            phase: nullspace_types::casino::TournamentPhase::Registration,
+            start_block: 0,
+            start_time_ms: 0,
+            end_time_ms: 0,
+            players: Vec::new(),
+            prize_pool: 0,
+            starting_chips: config.starting_stack.chips,
+            starting_shields: config.starting_stack.shields,
+            starting_doubles: config.starting_stack.doubles,
+            leaderboard: nullspace_types::casino::CasinoLeaderboard::default(),
+            config: config.clone(),
+            rake_collected: 0,
+            guarantee_overlay: 0,
+            top_ups: Vec::new(),
+        };
+        self.insert(
+            Key::Tournament(tournament_id),
+            Value::Tournament(tournament),
+        );
+
+        Ok(vec![Event::TournamentCreated {
+            id: tournament_id,
+            config: config.clone(),
+        }])
+    }
+
+    pub(in crate::layer) async fn handle_casino_join_tournament(
+        &mut self,
+        public: &PublicKey,
+        tournament_id: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        let mut player = match self.casino_player_or_error(public, None).await? {
+            Ok(player) => player,
+            Err(events) => return Ok(events),
+        };
+
+        // Get or create tournament (unknown ids are freerolls)
         let mut tournament = match self.get(Key::Tournament(tournament_id)).await? {
             Some(Value::Tournament(t)) => t,
             _ => nullspace_types::casino::Tournament {
@@ -1496,8 +1691,67 @@ impl<'a, S: State> Layer<'a, S> {
                 starting_shields: nullspace_types::casino::STARTING_SHIELDS,
                 starting_doubles: nullspace_types::casino::STARTING_DOUBLES,
                 leaderboard: nullspace_types::casino::CasinoLeaderboard::default(),
+                config: nullspace_types::casino::TournamentConfig::default(),
+                rake_collected: 0,
+                guarantee_overlay: 0,
+                top_ups: Vec::new(),
             },
         };
+        let is_freeroll = tournament.config.is_freeroll();
+
+        // Check tournament limit (per-player daily limit).
+        // Limits only gate freerolls; paid events are limited by the buy-in.
+        // Approximate time from view (3s per block)
+        let current_time_sec = self.seed_view.saturating_mul(SECS_PER_VIEW);
+        if is_freeroll {
+            let current_day = current_time_sec / 86400;
+            let last_played_day = player.tournament.last_tournament_ts / 86400;
+
+            if current_day > last_played_day {
+                player.tournament.tournaments_played_today = 0;
+            }
+
+            if player.tournament.last_tournament_ts > 0 {
+                let since_last =
+                    current_time_sec.saturating_sub(player.tournament.last_tournament_ts);
+                if since_last < nullspace_types::casino::TOURNAMENT_JOIN_COOLDOWN_SECS {
+                    return Ok(casino_error_vec(
+                        public,
+                        None,
+                        nullspace_types::casino::ERROR_TOURNAMENT_LIMIT_REACHED,
+                        "Tournament cooldown active, try again later",
+                    ));
+                }
+            }
+
+            let base_limit = if player.tournament.daily_limit > 0 {
+                player.tournament.daily_limit
+            } else {
+                nullspace_types::casino::FREEROLL_DAILY_LIMIT_FREE
+            };
+            let account_age = if player.profile.created_ts == 0 {
+                0
+            } else {
+                current_time_sec.saturating_sub(player.profile.created_ts)
+            };
+            let daily_limit = if account_age < nullspace_types::casino::ACCOUNT_TIER_NEW_SECS {
+                base_limit.min(nullspace_types::casino::FREEROLL_DAILY_LIMIT_TRIAL)
+            } else {
+                base_limit
+            };
+            if player.tournament.tournaments_played_today >= daily_limit {
+                let message = format!(
+                    "Daily tournament limit reached ({}/{})",
+                    player.tournament.tournaments_played_today, daily_limit
+                );
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_TOURNAMENT_LIMIT_REACHED,
+                    &message,
+                ));
+            }
+        }
 
         // Check if can join
         if !matches!(
@@ -1522,24 +1776,215 @@ impl<'a, S: State> Layer<'a, S> {
             ));
         }
 
-        // Update player tracking
-        player.tournament.tournaments_played_today += 1;
-        player.tournament.last_tournament_ts = current_time_sec;
+        // The player taking the last sit-and-go seat starts the event.
+        let fills_last_seat = tournament.config.is_sit_and_go()
+            && tournament.players.len() >= tournament.config.max_seats as usize;
+        if fills_last_seat && !self.can_schedule_tournament_end().await? {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_TOURNAMENT_LIMIT_REACHED,
+                "Too many sit-and-go events running, try again later",
+            ));
+        }
+
+        let mut events = vec![Event::PlayerJoined {
+            tournament_id,
+            player: public.clone(),
+        }];
+
+        if is_freeroll {
+            // Update player tracking
+            player.tournament.tournaments_played_today += 1;
+            player.tournament.last_tournament_ts = current_time_sec;
+        } else {
+            // Buy-ins fund the prize pool; the rake is held until the event completes.
+            let buy_in = tournament.config.buy_in;
+            if !debit_tournament_currency(&mut player, tournament.config.currency, buy_in) {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
+                    "Insufficient balance for tournament buy-in",
+                ));
+            }
+            let rake = tournament.config.rake_per_entry();
+            tournament.prize_pool = tournament
+                .prize_pool
+                .saturating_add(buy_in.saturating_sub(rake));
+            tournament.rake_collected = tournament.rake_collected.saturating_add(rake);
+            events.push(Event::TournamentBuyIn {
+                tournament_id,
+                player: public.clone(),
+                amount: buy_in,
+                rake,
+                prize_pool: tournament.prize_pool,
+                player_balances: nullspace_types::casino::PlayerBalanceSnapshot::from_player(
+                    &player,
+                ),
+            });
+        }
         player.tournament.active_tournament = Some(tournament_id);
 
         self.insert(
             Key::CasinoPlayer(public.clone()),
             Value::CasinoPlayer(player),
         );
-        self.insert(
-            Key::Tournament(tournament_id),
-            Value::Tournament(tournament),
+        if !fills_last_seat {
+            self.insert(
+                Key::Tournament(tournament_id),
+                Value::Tournament(tournament),
+            );
+            return Ok(events);
+        }
+
+        // Sit-and-go events run on chain time rather than an operator-supplied schedule.
+        let duration_secs = tournament.config.duration_secs;
+        let start_time_ms = current_time_sec.saturating_mul(1000);
+        let end_time_ms = start_time_ms.saturating_add(duration_secs.saturating_mul(1000));
+        events.extend(
+            self.activate_tournament(public, tournament, start_time_ms, end_time_ms)
+                .await?,
         );
+        let end_view = self
+            .seed_view
+            .saturating_add(duration_secs.div_ceil(SECS_PER_VIEW));
+        self.schedule_tournament_end(tournament_id, end_view)
+            .await?;
 
-        Ok(vec![Event::PlayerJoined {
-            tournament_id,
-            player: public.clone(),
-        }])
+        Ok(events)
+    }
+
+    pub(in crate::layer) async fn handle_casino_tournament_top_up(
+        &mut self,
+        public: &PublicKey,
+        tournament_id: u64,
+        kind: nullspace_types::casino::TopUpKind,
+    ) -> anyhow::Result<Vec<Event>> {
+        let mut player = match self.casino_player_or_error(public, None).await? {
+            Ok(player) => player,
+            Err(events) => return Ok(events),
+        };
+        let mut tournament = match self.get(Key::Tournament(tournament_id)).await? {
+            Some(Value::Tournament(t)) => t,
+            _ => {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Tournament not found",
+                ));
+            }
+        };
+        if !matches!(
+            tournament.phase,
+            nullspace_types::casino::TournamentPhase::Active
+        ) || player.tournament.active_tournament != Some(tournament_id)
+        {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_NOT_IN_TOURNAMENT,
+                "Not playing in this tournament",
+            ));
+        }
+
+        let rule = tournament.config.top_up_rule(kind).clone();
+        let label = match kind {
+            nullspace_types::casino::TopUpKind::Rebuy => "Rebuy",
+            nullspace_types::casino::TopUpKind::AddOn => "Add-on",
+        };
+        if !rule.is_enabled() {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                format!("{label} not offered in this tournament"),
+            ));
+        }
+        let elapsed_secs = self
+            .seed_view
+            .saturating_sub(tournament.start_block)
+            .saturating_mul(SECS_PER_VIEW);
+        if elapsed_secs >= rule.window_secs {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                format!("{label} window has closed"),
+            ));
+        }
+        let bought = tournament.top_ups_of(public).count(kind);
+        if bought >= rule.max_per_player {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_TOURNAMENT_LIMIT_REACHED,
+                format!("{label} limit reached ({bought}/{})", rule.max_per_player),
+            ));
+        }
+        // Rebuys bring busted players back; add-ons are for players who are still in. Chips
+        // riding on an open session can still come back, so that player isn't busted yet.
+        let busted = player.tournament.chips == 0 && player.session.active_session.is_none();
+        match kind {
+            nullspace_types::casino::TopUpKind::Rebuy if !busted => {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Rebuys are only available once busted",
+                ));
+            }
+            nullspace_types::casino::TopUpKind::AddOn if busted => {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Busted players must rebuy before taking an add-on",
+                ));
+            }
+            _ => {}
+        }
+        if !debit_tournament_currency(&mut player, tournament.config.currency, rule.cost) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
+                format!("Insufficient balance for {}", label.to_lowercase()),
+            ));
+        }
+
+        player.tournament.chips = player.tournament.chips.saturating_add(rule.chips);
+        player.tournament.shields = player.tournament.shields.saturating_add(rule.shields);
+        player.tournament.doubles = player.tournament.doubles.saturating_add(rule.doubles);
+        tournament.prize_pool = tournament.prize_pool.saturating_add(rule.cost);
+        let totals = tournament.record_top_up(public, kind);
+        tournament.leaderboard.update(
+            public.clone(),
+            player.profile.name.clone(),
+            player.tournament.chips,
+        );
+
+        let event = Event::TournamentTopUp {
+            tournament_id,
+            player: public.clone(),
+            kind,
+            cost: rule.cost,
+            prize_pool: tournament.prize_pool,
+            rebuys: totals.rebuys,
+            add_ons: totals.add_ons,
+            player_balances: nullspace_types::casino::PlayerBalanceSnapshot::from_player(&player),
+        };
+        self.insert(
+            Key::CasinoPlayer(public.clone()),
+            Value::CasinoPlayer(player),
+        );
+        self.insert(
+            Key::Tournament(tournament_id),
+            Value::Tournament(tournament),
+        );
+
+        Ok(vec![event])
     }
 
     pub(in crate::layer) async fn handle_casino_set_tournament_limit(
@@ -1548,7 +1993,10 @@ impl<'a, S: State> Layer<'a, S> {
         player_key: &PublicKey,
         daily_limit: u8,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Tournament)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1592,8 +2040,12 @@ impl<'a, S: State> Layer<'a, S> {
         tournament_id: u64,
         start_time_ms: u64,
         end_time_ms: u64,
+        payout_structure: &nullspace_types::casino::PayoutStructure,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Tournament)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1601,6 +2053,14 @@ impl<'a, S: State> Layer<'a, S> {
                 "Unauthorized admin instruction",
             ));
         }
+        if let Err(message) = payout_structure.validate() {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                message,
+            ));
+        }
         let mut tournament = match self.get(Key::Tournament(tournament_id)).await? {
             Some(Value::Tournament(t)) => {
                 // Prevent double-starts which would double-mint the prize pool.
@@ -1620,6 +2080,22 @@ impl<'a, S: State> Layer<'a, S> {
                         "Tournament already complete",
                     ));
                 }
+                if matches!(t.phase, nullspace_types::casino::TournamentPhase::Cancelled) {
+                    return Ok(casino_error_vec(
+                        public,
+                        None,
+                        nullspace_types::casino::ERROR_INVALID_MOVE,
+                        "Tournament was cancelled",
+                    ));
+                }
+                if t.config.is_sit_and_go() {
+                    return Ok(casino_error_vec(
+                        public,
+                        None,
+                        nullspace_types::casino::ERROR_INVALID_MOVE,
+                        "Sit-and-go tournaments start when full",
+                    ));
+                }
                 t
             }
             None => {
@@ -1636,6 +2112,10 @@ impl<'a, S: State> Layer<'a, S> {
                     starting_shields: nullspace_types::casino::STARTING_SHIELDS,
                     starting_doubles: nullspace_types::casino::STARTING_DOUBLES,
                     leaderboard: nullspace_types::casino::CasinoLeaderboard::default(),
+                    config: nullspace_types::casino::TournamentConfig::default(),
+                    rake_collected: 0,
+                    guarantee_overlay: 0,
+                    top_ups: Vec::new(),
                 };
                 t.add_player(public.clone());
                 t
@@ -1647,11 +2127,24 @@ impl<'a, S: State> Layer<'a, S> {
             }
         };
 
+        let is_freeroll = tournament.config.is_freeroll();
+
         // Enforce fixed tournament duration (5 minutes) for freeroll tournaments.
         // Ignore client-provided end time if inconsistent.
         let expected_duration_ms =
             nullspace_types::casino::TOURNAMENT_DURATION_SECS.saturating_mul(1000);
-        let end_time_ms = if end_time_ms >= start_time_ms
+        let end_time_ms = if !is_freeroll {
+            // Paid events run on the schedule the operator advertised.
+            if end_time_ms <= start_time_ms {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Tournament end time must be after start time",
+                ));
+            }
+            end_time_ms
+        } else if end_time_ms >= start_time_ms
             && end_time_ms.saturating_sub(start_time_ms) == expected_duration_ms
         {
             end_time_ms
@@ -1659,36 +2152,54 @@ impl<'a, S: State> Layer<'a, S> {
             start_time_ms.saturating_add(expected_duration_ms)
         };
 
-        // Calculate Prize Pool (Inflationary)
-        let total_supply = nullspace_types::casino::TOTAL_SUPPLY as u128;
-        let annual_bps = nullspace_types::casino::ANNUAL_EMISSION_RATE_BPS as u128;
-        let tournaments_per_day = nullspace_types::casino::TOURNAMENTS_PER_DAY as u128;
-        let reward_pool_cap =
-            total_supply * nullspace_types::casino::REWARD_POOL_BPS as u128 / 10000;
-
-        let annual_emission = total_supply * annual_bps / 10000;
-        let daily_emission = annual_emission / 365;
-        let per_game_emission = daily_emission / tournaments_per_day;
-
-        // Cap emissions to the remaining reward pool (25% of supply over ~5 years)
-        let mut house = self.get_or_init_house().await?;
-        let remaining_pool = reward_pool_cap.saturating_sub(house.total_issuance as u128);
-        let capped_emission = per_game_emission.min(remaining_pool);
-        let prize_pool = capped_emission as u64;
+        if !payout_structure.is_default() {
+            tournament.config.payout_structure = payout_structure.clone();
+        }
+        self.activate_tournament(public, tournament, start_time_ms, end_time_ms)
+            .await
+    }
 
-        // Track Issuance in House
-        house.total_issuance = house
-            .total_issuance
-            .saturating_add(prize_pool)
-            .min(reward_pool_cap as u64);
-        self.insert(Key::House, Value::House(house));
+    /// Fund the prize pool, reset every entrant's tournament stack and move `tournament`
+    /// to the Active phase. A guarantee the treasury cannot fund is reported to `public`.
+    async fn activate_tournament(
+        &mut self,
+        public: &PublicKey,
+        mut tournament: nullspace_types::casino::Tournament,
+        start_time_ms: u64,
+        end_time_ms: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        let tournament_id = tournament.id;
+        if tournament.config.is_freeroll() {
+            tournament.prize_pool = self.mint_freeroll_prize_pool().await?;
+        } else {
+            // Paid events keep the buy-ins collected at registration; the treasury
+            // tops the pool up to the guarantee when entries fall short.
+            let overlay = tournament
+                .config
+                .guarantee
+                .saturating_sub(tournament.prize_pool);
+            if overlay > 0 {
+                if !self
+                    .fund_tournament_guarantee(tournament.config.currency, overlay)
+                    .await?
+                {
+                    return Ok(casino_error_vec(
+                        public,
+                        None,
+                        nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
+                        "Treasury cannot fund the tournament guarantee",
+                    ));
+                }
+                tournament.guarantee_overlay = overlay;
+                tournament.prize_pool = tournament.prize_pool.saturating_add(overlay);
+            }
+        }
 
         // Update state
         tournament.phase = nullspace_types::casino::TournamentPhase::Active;
         tournament.start_block = self.seed_view;
         tournament.start_time_ms = start_time_ms;
         tournament.end_time_ms = end_time_ms;
-        tournament.prize_pool = prize_pool;
 
         // Reset tournament-only stacks for all players and rebuild the tournament leaderboard
         let mut leaderboard = nullspace_types::casino::CasinoLeaderboard::default();
@@ -1739,12 +2250,120 @@ impl<'a, S: State> Layer<'a, S> {
         }])
     }
 
+    /// Mints the inflationary prize pool for a freeroll from the reward pool emissions.
+    async fn mint_freeroll_prize_pool(&mut self) -> anyhow::Result<u64> {
+        // Calculate Prize Pool (Inflationary)
+        let total_supply = nullspace_types::casino::TOTAL_SUPPLY as u128;
+        let annual_bps = nullspace_types::casino::ANNUAL_EMISSION_RATE_BPS as u128;
+        let tournaments_per_day = nullspace_types::casino::TOURNAMENTS_PER_DAY as u128;
+        let reward_pool_cap =
+            total_supply * nullspace_types::casino::REWARD_POOL_BPS as u128 / 10000;
+
+        let annual_emission = total_supply * annual_bps / 10000;
+        let daily_emission = annual_emission / 365;
+        let per_game_emission = daily_emission / tournaments_per_day;
+
+        // Cap emissions to the remaining reward pool (25% of supply over ~5 years)
+        let mut house = self.get_or_init_house().await?;
+        let remaining_pool = reward_pool_cap.saturating_sub(house.total_issuance as u128);
+        let capped_emission = per_game_emission.min(remaining_pool);
+        let prize_pool = capped_emission as u64;
+
+        // Track Issuance in House
+        house.total_issuance = house
+            .total_issuance
+            .saturating_add(prize_pool)
+            .min(reward_pool_cap as u64);
+        self.insert(Key::House, Value::House(house));
+
+        Ok(prize_pool)
+    }
+
+    /// Funds a paid tournament's guarantee shortfall from the treasury.
+    ///
+    /// Chip overlays are minted against the remaining reward pool; vUSDT overlays
+    /// are drawn from the recovery pool. Returns false if the source cannot cover it.
+    async fn fund_tournament_guarantee(
+        &mut self,
+        currency: nullspace_types::casino::TournamentCurrency,
+        amount: u64,
+    ) -> anyhow::Result<bool> {
+        let mut house = self.get_or_init_house().await?;
+        match currency {
+            nullspace_types::casino::TournamentCurrency::Chips => {
+                let reward_pool_cap = (nullspace_types::casino::TOTAL_SUPPLY as u128
+                    * nullspace_types::casino::REWARD_POOL_BPS as u128
+                    / 10000) as u64;
+                let remaining_pool = reward_pool_cap.saturating_sub(house.total_issuance);
+                if remaining_pool < amount {
+                    return Ok(false);
+                }
+                house.total_issuance = house.total_issuance.saturating_add(amount);
+            }
+            nullspace_types::casino::TournamentCurrency::Vusdt => {
+                if house.recovery_pool_vusdt < amount {
+                    return Ok(false);
+                }
+                house.recovery_pool_vusdt -= amount;
+            }
+        }
+        self.insert(Key::House, Value::House(house));
+        Ok(true)
+    }
+
+    /// Returns an unused guarantee overlay to the source it was drawn from.
+    async fn return_tournament_guarantee(
+        &mut self,
+        currency: nullspace_types::casino::TournamentCurrency,
+        amount: u64,
+    ) -> anyhow::Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let mut house = self.get_or_init_house().await?;
+        match currency {
+            nullspace_types::casino::TournamentCurrency::Chips => {
+                house.total_issuance = house.total_issuance.saturating_sub(amount);
+            }
+            nullspace_types::casino::TournamentCurrency::Vusdt => {
+                house.recovery_pool_vusdt = house.recovery_pool_vusdt.saturating_add(amount);
+            }
+        }
+        self.insert(Key::House, Value::House(house));
+        Ok(())
+    }
+
+    /// Books the rake of a completed paid tournament as house revenue.
+    async fn book_tournament_rake(
+        &mut self,
+        currency: nullspace_types::casino::TournamentCurrency,
+        amount: u64,
+    ) -> anyhow::Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        match currency {
+            nullspace_types::casino::TournamentCurrency::Chips => {
+                self.update_house_pnl(amount as i128).await?;
+            }
+            nullspace_types::casino::TournamentCurrency::Vusdt => {
+                let mut house = self.get_or_init_house().await?;
+                house.recovery_pool_vusdt = house.recovery_pool_vusdt.saturating_add(amount);
+                self.insert(Key::House, Value::House(house));
+            }
+        }
+        Ok(())
+    }
+
     pub(in crate::layer) async fn handle_casino_end_tournament(
         &mut self,
         public: &PublicKey,
         tournament_id: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Tournament)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1752,6 +2371,14 @@ impl<'a, S: State> Layer<'a, S> {
                 "Unauthorized admin instruction",
             ));
         }
+        self.complete_tournament(tournament_id).await
+    }
+
+    /// Pay out an active tournament and close it; anything else is left untouched.
+    pub(in crate::layer) async fn complete_tournament(
+        &mut self,
+        tournament_id: u64,
+    ) -> anyhow::Result<Vec<Event>> {
         let mut tournament =
             if let Some(Value::Tournament(t)) = self.get(Key::Tournament(tournament_id)).await? {
                 t
@@ -1768,6 +2395,8 @@ impl<'a, S: State> Layer<'a, S> {
 
         let now = self.seed_view.saturating_mul(SECS_PER_VIEW);
         let policy = self.get_or_init_policy().await?;
+        let is_freeroll = tournament.config.is_freeroll();
+        let currency = tournament.config.currency;
 
         // Gather player tournament chips
         let mut rankings: Vec<(PublicKey, u64, u128)> = Vec::new();
@@ -1780,46 +2409,91 @@ impl<'a, S: State> Layer<'a, S> {
             }
         }
 
-        // Sort descending
-        rankings.sort_by(|a, b| b.1.cmp(&a.1));
-
-        // Determine winners (Top 15% for MTT style)
+        // Sort descending; equal stacks keep public key order so tie splits are deterministic
+        rankings.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
         let num_players = rankings.len();
-        let num_winners = (num_players.saturating_mul(15).saturating_add(99)) / 100;
-        let num_winners = num_winners.max(1).min(num_players);
-
-        // Calculate payout weights (1/rank harmonic distribution)
-        let mut weights = Vec::with_capacity(num_winners);
-        let mut total_weight: u128 = 0;
-        for (i, (_, _, proof_weight)) in rankings.iter().take(num_winners).enumerate() {
-            let base_weight = PROOF_WEIGHT_SCALE / (i as u128 + 1);
-            let w = base_weight
-                .saturating_mul(*proof_weight)
-                .checked_div(PROOF_WEIGHT_SCALE)
-                .unwrap_or(0);
-            weights.push(w);
-            total_weight = total_weight.saturating_add(w);
-        }
-
-        // Distribute Prize Pool
-        if total_weight > 0 && tournament.prize_pool > 0 {
-            for (i, (pk, _, _)) in rankings.iter().take(num_winners).enumerate() {
-                let weight = weights[i];
-                let payout = (tournament.prize_pool as u128)
-                    .saturating_mul(weight)
-                    .checked_div(total_weight)
-                    .unwrap_or(0) as u64;
-
-                if payout > 0 {
-                    if let Some(Value::CasinoPlayer(mut p)) =
-                        self.get(Key::CasinoPlayer(pk.clone())).await?
-                    {
-                        // Tournament prizes are credited as non-transferable freeroll credits.
-                        award_freeroll_credits(&mut p, payout, now, &policy);
-                        self.insert(Key::CasinoPlayer(pk.clone()), Value::CasinoPlayer(p));
-                    }
+
+        let payout_structure = &tournament.config.payout_structure;
+        let mut payouts: Vec<(PublicKey, u64)> = if payout_structure.is_default() {
+            // Determine winners (Top 15% for MTT style)
+            let num_winners = (num_players.saturating_mul(15).saturating_add(99)) / 100;
+            let num_winners = num_winners.max(1).min(num_players);
+
+            // Calculate payout weights (1/rank harmonic distribution)
+            let mut weights = Vec::with_capacity(num_winners);
+            let mut total_weight: u128 = 0;
+            for (i, (_, _, proof_weight)) in rankings.iter().take(num_winners).enumerate() {
+                let base_weight = PROOF_WEIGHT_SCALE / (i as u128 + 1);
+                // Paid prizes follow finishing position only; proof-of-play weighting
+                // exists to deter freeroll farming.
+                let w = if is_freeroll {
+                    base_weight
+                        .saturating_mul(*proof_weight)
+                        .checked_div(PROOF_WEIGHT_SCALE)
+                        .unwrap_or(0)
+                } else {
+                    base_weight
+                };
+                weights.push(w);
+                total_weight = total_weight.saturating_add(w);
+            }
+
+            // Distribute Prize Pool
+            let mut payouts = Vec::with_capacity(num_winners);
+            if total_weight > 0 && tournament.prize_pool > 0 {
+                for (i, (pk, _, _)) in rankings.iter().take(num_winners).enumerate() {
+                    let weight = weights[i];
+                    let payout = (tournament.prize_pool as u128)
+                        .saturating_mul(weight)
+                        .checked_div(total_weight)
+                        .unwrap_or(0) as u64;
+                    payouts.push((pk.clone(), payout));
+                }
+            }
+            if !is_freeroll {
+                // Rounding dust goes to the winner so the whole paid pool is returned to players.
+                let distributed: u64 = payouts.iter().map(|(_, payout)| *payout).sum();
+                if let Some((_, first)) = payouts.first_mut() {
+                    *first =
+                        first.saturating_add(tournament.prize_pool.saturating_sub(distributed));
+                }
+            }
+            payouts
+        } else {
+            // Custom structures pay by finishing position, splitting ties evenly.
+            let chips: Vec<u64> = rankings.iter().map(|(_, chips, _)| *chips).collect();
+            payout_structure
+                .split(tournament.prize_pool, &chips)
+                .into_iter()
+                .zip(rankings.iter())
+                .map(|(payout, (pk, _, _))| (pk.clone(), payout))
+                .collect()
+        };
+        payouts.retain(|(_, payout)| *payout > 0);
+        let num_winners = payouts.len();
+
+        for (pk, payout) in &payouts {
+            if let Some(Value::CasinoPlayer(mut p)) =
+                self.get(Key::CasinoPlayer(pk.clone())).await?
+            {
+                if is_freeroll {
+                    // Tournament prizes are credited as non-transferable freeroll credits.
+                    award_freeroll_credits(&mut p, *payout, now, &policy);
+                } else {
+                    // Paid events pay out in the buy-in currency.
+                    credit_tournament_currency(&mut p, currency, *payout);
                 }
+                self.insert(Key::CasinoPlayer(pk.clone()), Value::CasinoPlayer(p));
+            }
+        }
+
+        if !is_freeroll {
+            if payouts.is_empty() {
+                self.return_tournament_guarantee(currency, tournament.guarantee_overlay)
+                    .await?;
             }
+            self.book_tournament_rake(currency, tournament.rake_collected)
+                .await?;
         }
 
         // Clear tournament flags and stacks now that the event is over
@@ -1861,25 +2535,161 @@ impl<'a, S: State> Layer<'a, S> {
             "tournament ended"
         );
 
-        Ok(vec![Event::TournamentEnded {
+        let mut events = vec![Event::TournamentEnded {
             id: tournament_id,
             rankings: rankings_summary,
-        }])
+        }];
+        if !is_freeroll {
+            events.push(Event::TournamentPrizesPaid {
+                id: tournament_id,
+                currency,
+                payouts,
+            });
+        }
+        Ok(events)
     }
 
-    pub(in crate::layer) async fn handle_global_table_init(
+    pub(in crate::layer) async fn handle_casino_cancel_tournament(
         &mut self,
         public: &PublicKey,
-        config: &nullspace_types::casino::GlobalTableConfig,
+        tournament_id: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
-            return Ok(casino_error_vec(
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Tournament)
+            .await?
+        {
+            return Ok(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_UNAUTHORIZED,
                 "Unauthorized admin instruction",
             ));
         }
+        let mut tournament = match self.get(Key::Tournament(tournament_id)).await? {
+            Some(Value::Tournament(t)) => t,
+            _ => {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Tournament not found",
+                ));
+            }
+        };
+        match tournament.phase {
+            nullspace_types::casino::TournamentPhase::Complete => {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Tournament already complete",
+                ));
+            }
+            nullspace_types::casino::TournamentPhase::Cancelled => {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Tournament already cancelled",
+                ));
+            }
+            _ => {}
+        }
+
+        let is_freeroll = tournament.config.is_freeroll();
+        let currency = tournament.config.currency;
+        let buy_in = tournament.config.buy_in;
+
+        // Refund buy-ins in full (rake included) along with any rebuys and add-ons, and clear
+        // tournament stacks
+        let mut refunds: Vec<(PublicKey, u64)> = Vec::new();
+        for player_pk in &tournament.players {
+            if let Some(Value::CasinoPlayer(mut player)) =
+                self.get(Key::CasinoPlayer(player_pk.clone())).await?
+            {
+                if !is_freeroll {
+                    let refund = buy_in.saturating_add(tournament.top_up_spend(player_pk));
+                    credit_tournament_currency(&mut player, currency, refund);
+                    refunds.push((player_pk.clone(), refund));
+                }
+                if player.tournament.active_tournament == Some(tournament_id) {
+                    player.tournament.active_tournament = None;
+                    player.tournament.chips = 0;
+                    player.tournament.shields = 0;
+                    player.tournament.doubles = 0;
+                    player.clear_active_modifiers();
+                    player.session.active_session = None;
+                }
+                self.insert(
+                    Key::CasinoPlayer(player_pk.clone()),
+                    Value::CasinoPlayer(player),
+                );
+            }
+        }
+
+        if is_freeroll {
+            // Return emissions minted at start to the reward pool.
+            if matches!(
+                tournament.phase,
+                nullspace_types::casino::TournamentPhase::Active
+            ) && tournament.prize_pool > 0
+            {
+                let mut house = self.get_or_init_house().await?;
+                house.total_issuance = house.total_issuance.saturating_sub(tournament.prize_pool);
+                self.insert(Key::House, Value::House(house));
+            }
+        } else {
+            self.return_tournament_guarantee(currency, tournament.guarantee_overlay)
+                .await?;
+        }
+
+        tournament.phase = nullspace_types::casino::TournamentPhase::Cancelled;
+        tournament.prize_pool = 0;
+        tournament.rake_collected = 0;
+        tournament.guarantee_overlay = 0;
+        let players = tournament.players.len();
+        self.insert(
+            Key::Tournament(tournament_id),
+            Value::Tournament(tournament),
+        );
+
+        tracing::info!(
+            tournament_id = tournament_id,
+            players,
+            refunds = refunds.len(),
+            "tournament cancelled"
+        );
+
+        Ok(vec![Event::TournamentCancelled {
+            id: tournament_id,
+            refunds,
+        }])
+    }
+
+    pub(in crate::layer) async fn handle_global_table_init(
+        &mut self,
+        public: &PublicKey,
+        config: &nullspace_types::casino::GlobalTableConfig,
+    ) -> anyhow::Result<Vec<Event>> {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::GlobalTable)
+            .await?
+        {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Unauthorized admin instruction",
+            ));
+        }
+        if !crate::casino::global_table::is_supported(config.game_type) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Global table does not support this game",
+            ));
+        }
         if config.min_bet == 0 {
             return Ok(casino_error_vec(
                 public,
@@ -1935,7 +2745,10 @@ impl<'a, S: State> Layer<'a, S> {
         public: &PublicKey,
         game_type: nullspace_types::casino::GameType,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::GlobalTable)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1943,258 +2756,342 @@ impl<'a, S: State> Layer<'a, S> {
                 "Unauthorized admin instruction",
             ));
         }
-        if game_type != nullspace_types::casino::GameType::Craps {
+        if !crate::casino::global_table::is_supported(game_type) {
             return Ok(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Global table supports craps only",
+                "Global table does not support this game",
             ));
         }
 
-        let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
-            Some(Value::GlobalTableConfig(config)) => config,
-            _ => {
-                return Ok(casino_error_vec(
+        Ok(match self.open_global_table_round(game_type).await? {
+            Ok(events) => events,
+            Err(message) => casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                message,
+            ),
+        })
+    }
+
+    pub(in crate::layer) async fn handle_global_table_submit_bets(
+        &mut self,
+        public: &PublicKey,
+        game_type: nullspace_types::casino::GameType,
+        round_id: u64,
+        bets: &[nullspace_types::casino::GlobalTableBet],
+    ) -> anyhow::Result<Vec<Event>> {
+        if !crate::casino::global_table::is_supported(game_type) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Global table does not support this game",
+            ));
+        }
+
+        if bets.is_empty() {
+            return Ok(global_table_bet_rejected(
+                public,
+                round_id,
+                nullspace_types::casino::ERROR_INVALID_BET,
+                "No bets provided",
+            ));
+        }
+
+        let (config, mut round, mut player, mut player_session) = match self
+            .global_table_betting_context(public, game_type, round_id)
+            .await?
+        {
+            Ok(context) => context,
+            Err(events) => return Ok(events),
+        };
+
+        let round_bet_count = player_session.round_bets.len().saturating_add(bets.len());
+        if round_bet_count > config.max_bets_per_round as usize
+            || round_bet_count > *nullspace_types::casino::global_table_bets_cfg().end()
+        {
+            return Ok(global_table_bet_rejected(
+                public,
+                round_id,
+                nullspace_types::casino::ERROR_INVALID_BET,
+                "Too many bets submitted",
+            ));
+        }
+
+        let mut players = self.global_table_players(game_type).await?;
+        let newly_seated = !players.contains(public);
+        if newly_seated && players.len() >= nullspace_types::casino::MAX_GLOBAL_TABLE_PLAYERS {
+            return Ok(global_table_bet_rejected(
+                public,
+                round_id,
+                nullspace_types::casino::ERROR_INVALID_BET,
+                "Global table full",
+            ));
+        }
+
+        let roll_seed = round_roll_seed_or_default(&round, &self.seed);
+        crate::casino::global_table::prepare_session(
+            &mut player_session.session,
+            &round,
+            roll_seed,
+        );
+        player_session.session.is_complete = false;
+        if player_session.round_bets.is_empty() {
+            player_session.round_start_blob = player_session.session.state_blob.clone();
+        }
+
+        let mut working_session = player_session.session.clone();
+        let delta = match place_global_table_bets(
+            &mut working_session,
+            &config,
+            roll_seed,
+            bets,
+        ) {
+            Ok(delta) => delta,
+            Err(message) => {
+                return Ok(global_table_bet_rejected(
                     public,
-                    None,
-                    nullspace_types::casino::ERROR_INVALID_MOVE,
-                    "Global table config missing",
+                    round_id,
+                    nullspace_types::casino::ERROR_INVALID_BET,
+                    message,
                 ))
             }
         };
 
-        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
-        let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
-            Some(Value::GlobalTableRound(round)) => round,
-            _ => default_global_table_round(game_type),
-        };
-
-        let can_open = round.round_id == 0
-            || (matches!(
-                round.phase,
-                nullspace_types::casino::GlobalTablePhase::Cooldown
-            ) && now_ms >= round.phase_ends_at_ms);
-        if !can_open {
-            return Ok(casino_error_vec(
+        if !self
+            .apply_global_table_chip_delta(&mut player, delta)
+            .await?
+        {
+            return Ok(global_table_bet_rejected(
                 public,
-                None,
-                nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Round already active",
+                round_id,
+                nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
+                "Insufficient chips",
             ));
         }
 
-        round.round_id = round.round_id.saturating_add(1);
-        round.phase = nullspace_types::casino::GlobalTablePhase::Betting;
-        round.phase_ends_at_ms = now_ms.saturating_add(config.betting_ms);
-        round.rng_commit.clear();
-        round.roll_seed.clear();
+        player_session.session = working_session;
+        player_session.round_bets.extend_from_slice(bets);
+        for bet in bets {
+            add_table_total(&mut round.totals, bet.bet_type, bet.target, bet.amount);
+        }
 
+        self.insert(
+            Key::CasinoPlayer(public.clone()),
+            Value::CasinoPlayer(player.clone()),
+        );
+        self.insert(
+            Key::GlobalTablePlayerSession(game_type, public.clone()),
+            Value::GlobalTablePlayerSession(player_session.clone()),
+        );
         self.insert(
             Key::GlobalTableRound(game_type),
-            Value::GlobalTableRound(round.clone()),
+            Value::GlobalTableRound(round),
         );
+        if newly_seated {
+            players.push(public.clone());
+            self.insert(
+                Key::GlobalTablePlayers(game_type),
+                Value::GlobalTablePlayers(players),
+            );
+        }
 
-        Ok(vec![Event::GlobalTableRoundOpened { round }])
+        let mut events = vec![Event::GlobalTableBetAccepted {
+            player: public.clone(),
+            round_id,
+            bets: bets.to_vec(),
+            player_balances: nullspace_types::casino::PlayerBalanceSnapshot::from_player(&player),
+        }];
+        if let Some(event) = self
+            .update_casino_leaderboard(public, &player)
+            .await?
+        {
+            events.push(event);
+        }
+        Ok(events)
     }
 
-    pub(in crate::layer) async fn handle_global_table_submit_bets(
+    pub(in crate::layer) async fn handle_global_table_cancel_bets(
         &mut self,
         public: &PublicKey,
         game_type: nullspace_types::casino::GameType,
         round_id: u64,
-        bets: &[nullspace_types::casino::GlobalTableBet],
     ) -> anyhow::Result<Vec<Event>> {
-        if game_type != nullspace_types::casino::GameType::Craps {
+        if !crate::casino::global_table::is_supported(game_type) {
             return Ok(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Global table supports craps only",
+                "Global table does not support this game",
             ));
         }
 
-        if bets.is_empty() {
-            return Ok(vec![Event::GlobalTableBetRejected {
-                player: public.clone(),
+        let (_, mut round, mut player, mut player_session) = match self
+            .global_table_betting_context(public, game_type, round_id)
+            .await?
+        {
+            Ok(context) => context,
+            Err(events) => return Ok(events),
+        };
+
+        if player_session.round_bets.is_empty() {
+            return Ok(global_table_bet_rejected(
+                public,
                 round_id,
-                error_code: nullspace_types::casino::ERROR_INVALID_BET,
-                message: "No bets provided".to_string(),
-            }]);
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "No bets to cancel",
+            ));
         }
 
-        let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
-            Some(Value::GlobalTableConfig(config)) => config,
-            _ => {
-                return Ok(vec![Event::GlobalTableBetRejected {
-                    player: public.clone(),
-                    round_id,
-                    error_code: nullspace_types::casino::ERROR_INVALID_MOVE,
-                    message: "Global table config missing".to_string(),
-                }])
-            }
-        };
-
-        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
-        let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
-            Some(Value::GlobalTableRound(round)) => round,
-            _ => {
-                return Ok(vec![Event::GlobalTableBetRejected {
-                    player: public.clone(),
-                    round_id,
-                    error_code: nullspace_types::casino::ERROR_INVALID_MOVE,
-                    message: "Round not initialized".to_string(),
-                }])
-            }
-        };
+        // Every placement deducted its stake, so cancelling refunds the full amount.
+        let cancelled = std::mem::take(&mut player_session.round_bets);
+        let refund = cancelled
+            .iter()
+            .fold(0u64, |total, bet| total.saturating_add(bet.amount));
+        let refund = i64::try_from(refund).unwrap_or(i64::MAX);
+        self.apply_global_table_chip_delta(&mut player, refund)
+            .await?;
 
-        if round.round_id != round_id {
-            return Ok(vec![Event::GlobalTableBetRejected {
-                player: public.clone(),
-                round_id,
-                error_code: nullspace_types::casino::ERROR_INVALID_MOVE,
-                message: "Round ID mismatch".to_string(),
-            }]);
+        player_session.session.state_blob = std::mem::take(&mut player_session.round_start_blob);
+        for bet in &cancelled {
+            subtract_table_total(&mut round.totals, bet.bet_type, bet.target, bet.amount);
         }
 
-        if !matches!(
-            round.phase,
-            nullspace_types::casino::GlobalTablePhase::Betting
-        ) || now_ms >= round.phase_ends_at_ms
+        self.insert(
+            Key::CasinoPlayer(public.clone()),
+            Value::CasinoPlayer(player.clone()),
+        );
+        self.insert(
+            Key::GlobalTablePlayerSession(game_type, public.clone()),
+            Value::GlobalTablePlayerSession(player_session),
+        );
+        self.insert(
+            Key::GlobalTableRound(game_type),
+            Value::GlobalTableRound(round),
+        );
+
+        let mut events = vec![Event::GlobalTableBetsCancelled {
+            player: public.clone(),
+            round_id,
+            bets: cancelled,
+            player_balances: nullspace_types::casino::PlayerBalanceSnapshot::from_player(&player),
+        }];
+        if let Some(event) = self
+            .update_casino_leaderboard(public, &player)
+            .await?
         {
-            return Ok(vec![Event::GlobalTableBetRejected {
-                player: public.clone(),
-                round_id,
-                error_code: nullspace_types::casino::ERROR_INVALID_MOVE,
-                message: "Betting window closed".to_string(),
-            }]);
+            events.push(event);
         }
+        Ok(events)
+    }
 
-        let mut player = match self.casino_player_or_error(public, None).await? {
-            Ok(player) => player,
-            Err(_) => {
-                return Ok(vec![Event::GlobalTableBetRejected {
-                    player: public.clone(),
-                    round_id,
-                    error_code: nullspace_types::casino::ERROR_PLAYER_NOT_FOUND,
-                    message: "Player not found".to_string(),
-                }])
-            }
-        };
+    pub(in crate::layer) async fn handle_global_table_replace_bets(
+        &mut self,
+        public: &PublicKey,
+        game_type: nullspace_types::casino::GameType,
+        round_id: u64,
+        bets: &[nullspace_types::casino::GlobalTableBet],
+    ) -> anyhow::Result<Vec<Event>> {
+        if !crate::casino::global_table::is_supported(game_type) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Global table does not support this game",
+            ));
+        }
 
-        let mut player_session = match self
-            .get(Key::GlobalTablePlayerSession(game_type, public.clone()))
+        if bets.is_empty() {
+            return Ok(global_table_bet_rejected(
+                public,
+                round_id,
+                nullspace_types::casino::ERROR_INVALID_BET,
+                "No bets provided",
+            ));
+        }
+
+        let (config, mut round, mut player, mut player_session) = match self
+            .global_table_betting_context(public, game_type, round_id)
             .await?
         {
-            Some(Value::GlobalTablePlayerSession(session)) => session,
-            _ => {
-                let session = nullspace_types::casino::GameSession {
-                    id: round.round_id,
-                    player: public.clone(),
-                    game_type,
-                    bet: 0,
-                    state_blob: vec![],
-                    move_count: 0,
-                    created_at: self.seed_view,
-                    is_complete: false,
-                    super_mode: nullspace_types::casino::SuperModeState::default(),
-                    is_tournament: false,
-                    tournament_id: None,
-                };
-                nullspace_types::casino::GlobalTablePlayerSession {
-                    game_type,
-                    session,
-                    last_settled_round: round.round_id.saturating_sub(1),
-                }
-            }
+            Ok(context) => context,
+            Err(events) => return Ok(events),
         };
 
-        if player_session.last_settled_round.saturating_add(1) != round.round_id {
-            return Ok(vec![Event::GlobalTableBetRejected {
-                player: public.clone(),
+        if bets.len() > config.max_bets_per_round as usize {
+            return Ok(global_table_bet_rejected(
+                public,
                 round_id,
-                error_code: nullspace_types::casino::ERROR_INVALID_MOVE,
-                message: "Previous round not settled".to_string(),
-            }]);
+                nullspace_types::casino::ERROR_INVALID_BET,
+                "Too many bets submitted",
+            ));
         }
 
-        if bets.len() > config.max_bets_per_round as usize {
-            return Ok(vec![Event::GlobalTableBetRejected {
-                player: public.clone(),
+        let mut players = self.global_table_players(game_type).await?;
+        let newly_seated = !players.contains(public);
+        if newly_seated && players.len() >= nullspace_types::casino::MAX_GLOBAL_TABLE_PLAYERS {
+            return Ok(global_table_bet_rejected(
+                public,
                 round_id,
-                error_code: nullspace_types::casino::ERROR_INVALID_BET,
-                message: "Too many bets submitted".to_string(),
-            }]);
+                nullspace_types::casino::ERROR_INVALID_BET,
+                "Global table full",
+            ));
         }
 
-        ensure_craps_session_state(&mut player_session.session, &round, &self.seed);
+        let roll_seed = round_roll_seed_or_default(&round, &self.seed);
+        crate::casino::global_table::prepare_session(
+            &mut player_session.session,
+            &round,
+            roll_seed,
+        );
         player_session.session.is_complete = false;
-
-        let mut working_session = player_session.session.clone();
-        let mut delta: i64 = 0;
-        for bet in bets {
-            if bet.amount < config.min_bet || bet.amount > config.max_bet {
-                return Ok(vec![Event::GlobalTableBetRejected {
-                    player: public.clone(),
-                    round_id,
-                    error_code: nullspace_types::casino::ERROR_INVALID_BET,
-                    message: "Bet amount out of range".to_string(),
-                }]);
-            }
-            let mut payload = Vec::with_capacity(11);
-            payload.push(0);
-            payload.push(bet.bet_type);
-            payload.push(bet.target);
-            payload.extend_from_slice(&bet.amount.to_be_bytes());
-            let mut rng = crate::casino::GameRng::from_state(round_roll_seed_or_default(
-                &round,
-                &self.seed,
-            ));
-            let result =
-                crate::casino::process_game_move(&mut working_session, &payload, &mut rng);
-            let result = match result {
-                Ok(result) => result,
-                Err(_) => {
-                    return Ok(vec![Event::GlobalTableBetRejected {
-                        player: public.clone(),
-                        round_id,
-                        error_code: nullspace_types::casino::ERROR_INVALID_BET,
-                        message: "Invalid bet".to_string(),
-                    }])
-                }
-            };
-            delta = delta.saturating_add(game_result_delta(&result));
+        if player_session.round_bets.is_empty() {
+            player_session.round_start_blob = player_session.session.state_blob.clone();
         }
 
-        if delta < 0 {
-            let deduction = delta
-                .checked_neg()
-                .and_then(|v| u64::try_from(v).ok())
-                .unwrap_or(0);
-            if player.balances.chips < deduction {
-                return Ok(vec![Event::GlobalTableBetRejected {
-                    player: public.clone(),
+        // Rebuild from the pre-round state so the old bets are gone before the new ones land.
+        let mut working_session = player_session.session.clone();
+        working_session.state_blob = player_session.round_start_blob.clone();
+        let placed = match place_global_table_bets(
+            &mut working_session,
+            &config,
+            roll_seed,
+            bets,
+        ) {
+            Ok(delta) => delta,
+            Err(message) => {
+                return Ok(global_table_bet_rejected(
+                    public,
                     round_id,
-                    error_code: nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
-                    message: "Insufficient chips".to_string(),
-                }]);
-            }
-            player.balances.chips = player.balances.chips.saturating_sub(deduction);
-            if deduction > 0 {
-                self.update_house_pnl(deduction as i128).await?;
-            }
-        } else if delta > 0 {
-            let addition = u64::try_from(delta).unwrap_or(0);
-            player.balances.chips = player.balances.chips.saturating_add(addition);
-            if addition > 0 {
-                self.update_house_pnl(-(addition as i128)).await?;
+                    nullspace_types::casino::ERROR_INVALID_BET,
+                    message,
+                ))
             }
+        };
+        let refund = player_session
+            .round_bets
+            .iter()
+            .fold(0u64, |total, bet| total.saturating_add(bet.amount));
+        let delta = placed.saturating_add(i64::try_from(refund).unwrap_or(i64::MAX));
+
+        if !self
+            .apply_global_table_chip_delta(&mut player, delta)
+            .await?
+        {
+            return Ok(global_table_bet_rejected(
+                public,
+                round_id,
+                nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
+                "Insufficient chips",
+            ));
         }
 
+        let cancelled = std::mem::replace(&mut player_session.round_bets, bets.to_vec());
         player_session.session = working_session;
-        for bet in bets {
-            add_table_total(&mut round.totals, bet.bet_type, bet.target, bet.amount);
-        }
+        apply_bet_totals_delta(&mut round.totals, &cancelled, bets);
 
         self.insert(
             Key::CasinoPlayer(public.clone()),
@@ -2202,16 +3099,24 @@ impl<'a, S: State> Layer<'a, S> {
         );
         self.insert(
             Key::GlobalTablePlayerSession(game_type, public.clone()),
-            Value::GlobalTablePlayerSession(player_session.clone()),
+            Value::GlobalTablePlayerSession(player_session),
         );
         self.insert(
             Key::GlobalTableRound(game_type),
             Value::GlobalTableRound(round),
         );
+        if newly_seated {
+            players.push(public.clone());
+            self.insert(
+                Key::GlobalTablePlayers(game_type),
+                Value::GlobalTablePlayers(players),
+            );
+        }
 
-        let mut events = vec![Event::GlobalTableBetAccepted {
+        let mut events = vec![Event::GlobalTableBetsReplaced {
             player: public.clone(),
             round_id,
+            cancelled,
             bets: bets.to_vec(),
             player_balances: nullspace_types::casino::PlayerBalanceSnapshot::from_player(&player),
         }];
@@ -2224,47 +3129,27 @@ impl<'a, S: State> Layer<'a, S> {
         Ok(events)
     }
 
-    pub(in crate::layer) async fn handle_global_table_lock(
+    pub(in crate::layer) async fn handle_global_table_cash_out(
         &mut self,
         public: &PublicKey,
         game_type: nullspace_types::casino::GameType,
         round_id: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
-            return Ok(casino_error_vec(
-                public,
-                None,
-                nullspace_types::casino::ERROR_UNAUTHORIZED,
-                "Unauthorized admin instruction",
-            ));
-        }
-        if game_type != nullspace_types::casino::GameType::Craps {
+        if !crate::casino::global_table::has_rolling_phase(game_type) {
             return Ok(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Global table supports craps only",
+                "Global table game has no cash-out",
             ));
         }
 
-        let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
-            Some(Value::GlobalTableConfig(config)) => config,
-            _ => {
-                return Ok(casino_error_vec(
-                    public,
-                    None,
-                    nullspace_types::casino::ERROR_INVALID_MOVE,
-                    "Global table config missing",
-                ))
-            }
-        };
-        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
-        let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
+        let round = match self.get(Key::GlobalTableRound(game_type)).await? {
             Some(Value::GlobalTableRound(round)) => round,
             _ => {
-                return Ok(casino_error_vec(
+                return Ok(global_table_bet_rejected(
                     public,
-                    None,
+                    round_id,
                     nullspace_types::casino::ERROR_INVALID_MOVE,
                     "Round not initialized",
                 ))
@@ -2272,189 +3157,315 @@ impl<'a, S: State> Layer<'a, S> {
         };
 
         if round.round_id != round_id {
-            return Ok(casino_error_vec(
+            return Ok(global_table_bet_rejected(
                 public,
-                None,
+                round_id,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
                 "Round ID mismatch",
             ));
         }
 
+        // Cash-outs are ordered by block view: the multiplier is read off the curve at the
+        // view the instruction executes in.
+        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
         if !matches!(
             round.phase,
-            nullspace_types::casino::GlobalTablePhase::Betting
-        ) || now_ms < round.phase_ends_at_ms
+            nullspace_types::casino::GlobalTablePhase::Rolling
+        ) || now_ms >= round.phase_ends_at_ms
         {
-            return Ok(casino_error_vec(
+            return Ok(global_table_bet_rejected(
                 public,
-                None,
+                round_id,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Betting still open",
+                "Round not rolling",
             ));
         }
 
-        round.phase = nullspace_types::casino::GlobalTablePhase::Locked;
-        round.phase_ends_at_ms = now_ms.saturating_add(config.lock_ms);
-        let roll_seed = derive_global_table_roll_seed(&self.seed, round.round_id);
-        round.roll_seed = roll_seed.to_vec();
-        round.rng_commit = hash_roll_seed(&round.roll_seed);
+        let mut player_session = match self
+            .get(Key::GlobalTablePlayerSession(game_type, public.clone()))
+            .await?
+        {
+            Some(Value::GlobalTablePlayerSession(session))
+                if session.last_settled_round.saturating_add(1) == round.round_id =>
+            {
+                session
+            }
+            _ => {
+                return Ok(global_table_bet_rejected(
+                    public,
+                    round_id,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "No bets to cash out",
+                ))
+            }
+        };
+
+        let rolling_started_at = round
+            .phase_ends_at_ms
+            .saturating_sub(crate::casino::global_table::rolling_ms(game_type));
+        let multiplier = match crate::casino::global_table::cash_out(
+            &mut player_session.session,
+            now_ms.saturating_sub(rolling_started_at),
+        ) {
+            Ok(multiplier) => multiplier,
+            Err(_) => {
+                return Ok(global_table_bet_rejected(
+                    public,
+                    round_id,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Nothing to cash out",
+                ))
+            }
+        };
+
         self.insert(
-            Key::GlobalTableRound(game_type),
-            Value::GlobalTableRound(round.clone()),
+            Key::GlobalTablePlayerSession(game_type, public.clone()),
+            Value::GlobalTablePlayerSession(player_session),
         );
 
-        Ok(vec![Event::GlobalTableLocked {
-            game_type,
+        Ok(vec![Event::GlobalTableCashedOut {
+            player: public.clone(),
             round_id,
-            phase_ends_at_ms: round.phase_ends_at_ms,
+            multiplier,
         }])
     }
 
-    pub(in crate::layer) async fn handle_global_table_reveal(
+    /// Load everything a betting-phase instruction needs, rejecting if the round is not
+    /// taking bets from this player.
+    async fn global_table_betting_context(
         &mut self,
         public: &PublicKey,
         game_type: nullspace_types::casino::GameType,
         round_id: u64,
-    ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
-            return Ok(casino_error_vec(
-                public,
-                None,
-                nullspace_types::casino::ERROR_UNAUTHORIZED,
-                "Unauthorized admin instruction",
-            ));
-        }
-        if game_type != nullspace_types::casino::GameType::Craps {
-            return Ok(casino_error_vec(
-                public,
-                None,
-                nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Global table supports craps only",
-            ));
-        }
-
+    ) -> anyhow::Result<
+        Result<
+            (
+                nullspace_types::casino::GlobalTableConfig,
+                nullspace_types::casino::GlobalTableRound,
+                nullspace_types::casino::Player,
+                nullspace_types::casino::GlobalTablePlayerSession,
+            ),
+            Vec<Event>,
+        >,
+    > {
         let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
             Some(Value::GlobalTableConfig(config)) => config,
             _ => {
-                return Ok(casino_error_vec(
+                return Ok(Err(global_table_bet_rejected(
                     public,
-                    None,
+                    round_id,
                     nullspace_types::casino::ERROR_INVALID_MOVE,
                     "Global table config missing",
-                ))
+                )))
             }
         };
 
         let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
-        let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
+        let round = match self.get(Key::GlobalTableRound(game_type)).await? {
             Some(Value::GlobalTableRound(round)) => round,
             _ => {
-                return Ok(casino_error_vec(
+                return Ok(Err(global_table_bet_rejected(
                     public,
-                    None,
+                    round_id,
                     nullspace_types::casino::ERROR_INVALID_MOVE,
                     "Round not initialized",
-                ))
+                )))
             }
         };
 
         if round.round_id != round_id {
-            return Ok(casino_error_vec(
+            return Ok(Err(global_table_bet_rejected(
                 public,
-                None,
+                round_id,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
                 "Round ID mismatch",
-            ));
+            )));
         }
 
         if !matches!(
             round.phase,
-            nullspace_types::casino::GlobalTablePhase::Locked
-        ) || now_ms < round.phase_ends_at_ms
+            nullspace_types::casino::GlobalTablePhase::Betting
+        ) || now_ms >= round.phase_ends_at_ms
         {
-            return Ok(casino_error_vec(
+            return Ok(Err(global_table_bet_rejected(
                 public,
-                None,
+                round_id,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Round not locked",
-            ));
+                "Betting window closed",
+            )));
         }
 
-        let roll_seed = match roll_seed_from_round(&round) {
-            Some(seed) => seed,
-            None if round.rng_commit.is_empty() => {
-                let seed = derive_global_table_roll_seed(&self.seed, round.round_id);
-                round.roll_seed = seed.to_vec();
-                round.rng_commit = hash_roll_seed(&round.roll_seed);
-                seed
-            }
-            None => {
-                return Ok(casino_error_vec(
+        let player = match self.casino_player_or_error(public, None).await? {
+            Ok(player) => player,
+            Err(_) => {
+                return Ok(Err(global_table_bet_rejected(
                     public,
-                    None,
-                    nullspace_types::casino::ERROR_INVALID_MOVE,
-                    "Round RNG commit missing",
-                ));
+                    round_id,
+                    nullspace_types::casino::ERROR_PLAYER_NOT_FOUND,
+                    "Player not found",
+                )))
             }
         };
-        let expected_commit = hash_roll_seed(&roll_seed);
-        if !round.rng_commit.is_empty() && round.rng_commit != expected_commit {
+
+        let mut player_session = match self
+            .get(Key::GlobalTablePlayerSession(game_type, public.clone()))
+            .await?
+        {
+            Some(Value::GlobalTablePlayerSession(session)) => session,
+            _ => {
+                let session = nullspace_types::casino::GameSession {
+                    id: round.round_id,
+                    player: public.clone(),
+                    game_type,
+                    bet: 0,
+                    state_blob: vec![],
+                    move_count: 0,
+                    created_at: self.seed_view,
+                    is_complete: false,
+                    super_mode: nullspace_types::casino::SuperModeState::default(),
+                    is_tournament: false,
+                    tournament_id: None,
+                };
+                nullspace_types::casino::GlobalTablePlayerSession {
+                    game_type,
+                    session,
+                    last_settled_round: round.round_id.saturating_sub(1),
+                    round_bets: Vec::new(),
+                    round_start_blob: Vec::new(),
+                }
+            }
+        };
+
+        // Rounds a player sat out with nothing on the table have nothing to settle.
+        if player_session.last_settled_round.saturating_add(1) < round.round_id
+            && player_session.round_bets.is_empty()
+            && crate::casino::global_table::outstanding_bets(
+                game_type,
+                &player_session.session.state_blob,
+            )
+            .is_empty()
+        {
+            player_session.last_settled_round = round.round_id.saturating_sub(1);
+        }
+
+        if player_session.last_settled_round.saturating_add(1) != round.round_id {
+            return Ok(Err(global_table_bet_rejected(
+                public,
+                round_id,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Previous round not settled",
+            )));
+        }
+
+        Ok(Ok((config, round, player, player_session)))
+    }
+
+    /// Players with bets at a global table, in the order they first bet.
+    async fn global_table_players(
+        &self,
+        game_type: nullspace_types::casino::GameType,
+    ) -> anyhow::Result<Vec<PublicKey>> {
+        Ok(match self.get(Key::GlobalTablePlayers(game_type)).await? {
+            Some(Value::GlobalTablePlayers(players)) => players,
+            _ => Vec::new(),
+        })
+    }
+
+    /// Credit or debit a global table chip delta, keeping house PnL in step.
+    ///
+    /// Returns false (and changes nothing) if the player cannot cover a debit.
+    async fn apply_global_table_chip_delta(
+        &mut self,
+        player: &mut nullspace_types::casino::Player,
+        delta: i64,
+    ) -> anyhow::Result<bool> {
+        if delta < 0 {
+            let deduction = delta.unsigned_abs();
+            if player.balances.chips < deduction {
+                return Ok(false);
+            }
+            player.balances.chips = player.balances.chips.saturating_sub(deduction);
+            self.update_house_pnl(deduction as i128).await?;
+        } else if delta > 0 {
+            let addition = delta.unsigned_abs();
+            player.balances.chips = player.balances.chips.saturating_add(addition);
+            self.update_house_pnl(-(addition as i128)).await?;
+        }
+        Ok(true)
+    }
+
+    pub(in crate::layer) async fn handle_global_table_lock(
+        &mut self,
+        public: &PublicKey,
+        game_type: nullspace_types::casino::GameType,
+        round_id: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::GlobalTable)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
-                nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Round RNG commit mismatch",
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Unauthorized admin instruction",
             ));
         }
-        if round.rng_commit.is_empty() {
-            round.rng_commit = expected_commit;
+        if !crate::casino::global_table::is_supported(game_type) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Global table does not support this game",
+            ));
         }
 
-        let mut table_session = nullspace_types::casino::GameSession {
-            id: round.round_id,
-            player: public.clone(),
-            game_type,
-            bet: 0,
-            state_blob: vec![],
-            move_count: 0,
-            created_at: self.seed_view,
-            is_complete: false,
-            super_mode: nullspace_types::casino::SuperModeState::default(),
-            is_tournament: false,
-            tournament_id: None,
-        };
-        let mut init_rng = crate::casino::GameRng::from_state(roll_seed);
-        let _ = crate::casino::init_game(&mut table_session, &mut init_rng);
-        sync_craps_session_to_table(&mut table_session, &round);
+        Ok(match self.lock_global_table_round(game_type, round_id).await? {
+            Ok(events) => events,
+            Err(message) => casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                message,
+            ),
+        })
+    }
 
-        let mut roll_rng = crate::casino::GameRng::from_state(roll_seed);
-        if crate::casino::process_game_move(&mut table_session, &[2], &mut roll_rng).is_err() {
+    pub(in crate::layer) async fn handle_global_table_reveal(
+        &mut self,
+        public: &PublicKey,
+        game_type: nullspace_types::casino::GameType,
+        round_id: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::GlobalTable)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
-                nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Round roll failed",
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Unauthorized admin instruction",
             ));
         }
-
-        if let Some(state) = read_craps_table_state(&table_session.state_blob) {
-            round.main_point = state.main_point;
-            round.d1 = state.d1;
-            round.d2 = state.d2;
-            round.made_points_mask = state.made_points_mask;
-            round.epoch_point_established = state.epoch_point_established;
-            round.field_paytable = state.field_paytable;
+        if !crate::casino::global_table::is_supported(game_type) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Global table does not support this game",
+            ));
         }
 
-        round.phase = nullspace_types::casino::GlobalTablePhase::Payout;
-        round.phase_ends_at_ms = now_ms.saturating_add(config.payout_ms);
-
-        self.insert(
-            Key::GlobalTableRound(game_type),
-            Value::GlobalTableRound(round.clone()),
-        );
-
-        Ok(vec![Event::GlobalTableOutcome { round }])
+        Ok(match self.reveal_global_table_round(game_type, round_id).await? {
+            Ok(events) => events,
+            Err(message) => casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                message,
+            ),
+        })
     }
 
     pub(in crate::layer) async fn handle_global_table_settle(
@@ -2463,12 +3474,12 @@ impl<'a, S: State> Layer<'a, S> {
         game_type: nullspace_types::casino::GameType,
         round_id: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if game_type != nullspace_types::casino::GameType::Craps {
+        if !crate::casino::global_table::is_supported(game_type) {
             return Ok(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Global table supports craps only",
+                "Global table does not support this game",
             ));
         }
 
@@ -2506,18 +3517,41 @@ impl<'a, S: State> Layer<'a, S> {
             ));
         }
 
+        match self
+            .settle_global_table_player(public, game_type, &mut round)
+            .await?
+        {
+            Ok(events) => {
+                self.insert(
+                    Key::GlobalTableRound(game_type),
+                    Value::GlobalTableRound(round),
+                );
+                Ok(events)
+            }
+            Err(events) => Ok(events),
+        }
+    }
+
+    /// Settle one player's bets against a revealed round, crediting chips and updating
+    /// the round totals in place (the caller stores the round).
+    async fn settle_global_table_player(
+        &mut self,
+        public: &PublicKey,
+        game_type: nullspace_types::casino::GameType,
+        round: &mut nullspace_types::casino::GlobalTableRound,
+    ) -> anyhow::Result<Result<Vec<Event>, Vec<Event>>> {
         if round.roll_seed.len() != 32 {
-            return Ok(casino_error_vec(
+            return Ok(Err(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
                 "Round outcome not revealed",
-            ));
+            )));
         }
 
         let mut player = match self.casino_player_or_error(public, None).await? {
             Ok(player) => player,
-            Err(events) => return Ok(events),
+            Err(events) => return Ok(Err(events)),
         };
 
         let mut player_session = match self
@@ -2526,39 +3560,40 @@ impl<'a, S: State> Layer<'a, S> {
         {
             Some(Value::GlobalTablePlayerSession(session)) => session,
             _ => {
-                return Ok(casino_error_vec(
+                return Ok(Err(casino_error_vec(
                     public,
                     None,
                     nullspace_types::casino::ERROR_INVALID_MOVE,
                     "Player not registered for global table",
-                ))
+                )))
             }
         };
 
         if player_session.last_settled_round.saturating_add(1) != round.round_id {
-            return Ok(casino_error_vec(
+            return Ok(Err(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
                 "Round already settled or out of order",
-            ));
+            )));
         }
 
-        ensure_craps_session_state(&mut player_session.session, &round, &self.seed);
+        let roll_seed = roll_seed_from_round(round).unwrap_or([0u8; 32]);
+        crate::casino::global_table::prepare_session(&mut player_session.session, round, roll_seed);
         player_session.session.is_complete = false;
 
-        let bet_count = bet_count_from_blob(&player_session.session.state_blob);
-        let before_bets = extract_craps_bets(&player_session.session.state_blob);
+        let before_bets = crate::casino::global_table::outstanding_bets(
+            game_type,
+            &player_session.session.state_blob,
+        );
 
-        let payout_delta = if bet_count == 0 {
-            sync_craps_session_to_table(&mut player_session.session, &round);
+        let payout_delta = if before_bets.is_empty() {
             0
         } else {
-            let roll_seed = roll_seed_from_round(&round).unwrap_or([0u8; 32]);
             let mut roll_rng = crate::casino::GameRng::from_state(roll_seed);
             let result = crate::casino::process_game_move(
                 &mut player_session.session,
-                &[2],
+                crate::casino::global_table::resolve_payload(game_type),
                 &mut roll_rng,
             )
             .map_err(|_| anyhow::anyhow!("settle failed"))?;
@@ -2578,12 +3613,12 @@ impl<'a, S: State> Layer<'a, S> {
                             .and_then(|v| u64::try_from(v).ok())
                             .unwrap_or(0);
                         if player.balances.chips < deduction {
-                            return Ok(casino_error_vec(
+                            return Ok(Err(casino_error_vec(
                                 public,
                                 None,
                                 nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
                                 "Insufficient chips for settlement",
-                            ));
+                            )));
                         }
                         player.balances.chips = player.balances.chips.saturating_sub(deduction);
                         if deduction > 0 {
@@ -2631,12 +3666,12 @@ impl<'a, S: State> Layer<'a, S> {
                 crate::casino::GameResult::LossWithExtraDeduction(extra, _) => {
                     if extra > 0 {
                         if player.balances.chips < extra {
-                            return Ok(casino_error_vec(
+                            return Ok(Err(casino_error_vec(
                                 public,
                                 None,
                                 nullspace_types::casino::ERROR_INSUFFICIENT_FUNDS,
                                 "Insufficient chips for settlement",
-                            ));
+                            )));
                         }
                         player.balances.chips = player.balances.chips.saturating_sub(extra);
                         self.update_house_pnl(extra as i128).await?;
@@ -2659,8 +3694,14 @@ impl<'a, S: State> Layer<'a, S> {
         };
 
         player_session.last_settled_round = round.round_id;
+        player_session.round_bets.clear();
+        player_session.round_start_blob.clear();
+        crate::casino::global_table::finish_session(&mut player_session.session);
 
-        let after_bets = extract_craps_bets(&player_session.session.state_blob);
+        let after_bets = crate::casino::global_table::outstanding_bets(
+            game_type,
+            &player_session.session.state_blob,
+        );
         apply_bet_totals_delta(&mut round.totals, &before_bets, &after_bets);
 
         self.insert(
@@ -2671,14 +3712,22 @@ impl<'a, S: State> Layer<'a, S> {
             Key::GlobalTablePlayerSession(game_type, public.clone()),
             Value::GlobalTablePlayerSession(player_session.clone()),
         );
-        self.insert(
-            Key::GlobalTableRound(game_type),
-            Value::GlobalTableRound(round),
-        );
+        if after_bets.is_empty() {
+            let mut players = self.global_table_players(game_type).await?;
+            players.retain(|player| player != public);
+            if players.is_empty() {
+                self.remove(Key::GlobalTablePlayers(game_type));
+            } else {
+                self.insert(
+                    Key::GlobalTablePlayers(game_type),
+                    Value::GlobalTablePlayers(players),
+                );
+            }
+        }
 
         let mut events = vec![Event::GlobalTablePlayerSettled {
             player: public.clone(),
-            round_id,
+            round_id: round.round_id,
             payout: payout_delta,
             player_balances: nullspace_types::casino::PlayerBalanceSnapshot::from_player(&player),
             my_bets: after_bets,
@@ -2689,7 +3738,7 @@ impl<'a, S: State> Layer<'a, S> {
         {
             events.push(event);
         }
-        Ok(events)
+        Ok(Ok(events))
     }
 
     pub(in crate::layer) async fn handle_global_table_finalize(
@@ -2698,7 +3747,10 @@ impl<'a, S: State> Layer<'a, S> {
         game_type: nullspace_types::casino::GameType,
         round_id: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::GlobalTable)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -2706,47 +3758,305 @@ impl<'a, S: State> Layer<'a, S> {
                 "Unauthorized admin instruction",
             ));
         }
-        if game_type != nullspace_types::casino::GameType::Craps {
+        if !crate::casino::global_table::is_supported(game_type) {
             return Ok(casino_error_vec(
                 public,
                 None,
                 nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Global table supports craps only",
+                "Global table does not support this game",
             ));
         }
 
+        Ok(match self.finalize_global_table_round(game_type, round_id).await? {
+            Ok(events) => events,
+            Err(message) => casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                message,
+            ),
+        })
+    }
+
+    /// Advance every configured global table whose current phase has elapsed.
+    ///
+    /// Runs at the start of each block so rounds progress on chain time alone
+    /// (open -> lock -> reveal -> finalize, rolling before reveal for crash). The admin phase
+    /// instructions still work, but a keeper is no longer required; players settle their own
+    /// bets once a round is revealed, and opening the next round settles any they left.
+    pub(in crate::layer) async fn advance_global_tables(&mut self) -> anyhow::Result<Vec<Event>> {
+        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
+        let mut events = Vec::new();
+        for game_type in crate::casino::global_table::SUPPORTED_GAMES {
+            if !matches!(
+                self.get(Key::GlobalTableConfig(game_type)).await?,
+                Some(Value::GlobalTableConfig(_))
+            ) {
+                continue;
+            }
+            let round = match self.get(Key::GlobalTableRound(game_type)).await? {
+                Some(Value::GlobalTableRound(round)) => round,
+                _ => default_global_table_round(game_type),
+            };
+            if round.round_id != 0 && now_ms < round.phase_ends_at_ms {
+                continue;
+            }
+
+            let result = match round.phase {
+                nullspace_types::casino::GlobalTablePhase::Cooldown => {
+                    self.open_global_table_round(game_type).await?
+                }
+                nullspace_types::casino::GlobalTablePhase::Betting => {
+                    self.lock_global_table_round(game_type, round.round_id)
+                        .await?
+                }
+                nullspace_types::casino::GlobalTablePhase::Locked
+                | nullspace_types::casino::GlobalTablePhase::Rolling => {
+                    self.reveal_global_table_round(game_type, round.round_id)
+                        .await?
+                }
+                nullspace_types::casino::GlobalTablePhase::Payout => {
+                    self.finalize_global_table_round(game_type, round.round_id)
+                        .await?
+                }
+            };
+            match result {
+                Ok(phase_events) => events.extend(phase_events),
+                Err(message) => {
+                    tracing::warn!(
+                        game_type = ?game_type,
+                        round_id = round.round_id,
+                        message,
+                        "global table phase advance failed"
+                    );
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    async fn open_global_table_round(
+        &mut self,
+        game_type: nullspace_types::casino::GameType,
+    ) -> anyhow::Result<Result<Vec<Event>, &'static str>> {
         let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
             Some(Value::GlobalTableConfig(config)) => config,
-            _ => {
-                return Ok(casino_error_vec(
-                    public,
-                    None,
-                    nullspace_types::casino::ERROR_INVALID_MOVE,
-                    "Global table config missing",
-                ))
+            _ => return Ok(Err("Global table config missing")),
+        };
+
+        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
+        let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
+            Some(Value::GlobalTableRound(round)) => round,
+            _ => default_global_table_round(game_type),
+        };
+
+        let can_open = round.round_id == 0
+            || (matches!(
+                round.phase,
+                nullspace_types::casino::GlobalTablePhase::Cooldown
+            ) && now_ms >= round.phase_ends_at_ms);
+        if !can_open {
+            return Ok(Err("Round already active"));
+        }
+
+        // Settle whoever left bets on the closing round so nobody is locked out of the next.
+        let mut events = Vec::new();
+        for player in self.global_table_players(game_type).await? {
+            let due = match self
+                .get(Key::GlobalTablePlayerSession(game_type, player.clone()))
+                .await?
+            {
+                Some(Value::GlobalTablePlayerSession(session)) => {
+                    session.last_settled_round.saturating_add(1) == round.round_id
+                }
+                _ => false,
+            };
+            if !due {
+                continue;
+            }
+            match self
+                .settle_global_table_player(&player, game_type, &mut round)
+                .await?
+            {
+                Ok(settle_events) => events.extend(settle_events),
+                Err(_) => {
+                    tracing::warn!(
+                        game_type = ?game_type,
+                        round_id = round.round_id,
+                        player = ?player,
+                        "global table auto-settle failed"
+                    );
+                }
             }
+        }
+
+        round.round_id = round.round_id.saturating_add(1);
+        round.phase = nullspace_types::casino::GlobalTablePhase::Betting;
+        round.phase_ends_at_ms = now_ms.saturating_add(config.betting_ms);
+        round.rng_commit.clear();
+        round.roll_seed.clear();
+        round.outcome.clear();
+
+        self.insert(
+            Key::GlobalTableRound(game_type),
+            Value::GlobalTableRound(round.clone()),
+        );
+
+        events.push(Event::GlobalTableRoundOpened { round });
+        Ok(Ok(events))
+    }
+
+    async fn lock_global_table_round(
+        &mut self,
+        game_type: nullspace_types::casino::GameType,
+        round_id: u64,
+    ) -> anyhow::Result<Result<Vec<Event>, &'static str>> {
+        let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
+            Some(Value::GlobalTableConfig(config)) => config,
+            _ => return Ok(Err("Global table config missing")),
+        };
+        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
+        let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
+            Some(Value::GlobalTableRound(round)) => round,
+            _ => return Ok(Err("Round not initialized")),
+        };
+
+        if round.round_id != round_id {
+            return Ok(Err("Round ID mismatch"));
+        }
+
+        if !matches!(
+            round.phase,
+            nullspace_types::casino::GlobalTablePhase::Betting
+        ) || now_ms < round.phase_ends_at_ms
+        {
+            return Ok(Err("Betting still open"));
+        }
+
+        round.phase = nullspace_types::casino::GlobalTablePhase::Locked;
+        round.phase_ends_at_ms = now_ms.saturating_add(config.lock_ms);
+        // Games with a live Rolling phase draw their seed when Rolling ends, so the outcome
+        // stays unknown while players can still cash out.
+        if !crate::casino::global_table::has_rolling_phase(game_type) {
+            let roll_seed = derive_global_table_roll_seed(&self.seed, round.round_id);
+            round.roll_seed = roll_seed.to_vec();
+            round.rng_commit = hash_roll_seed(&round.roll_seed);
+        }
+        self.insert(
+            Key::GlobalTableRound(game_type),
+            Value::GlobalTableRound(round.clone()),
+        );
+
+        Ok(Ok(vec![Event::GlobalTableLocked {
+            game_type,
+            round_id,
+            phase_ends_at_ms: round.phase_ends_at_ms,
+        }]))
+    }
+
+    async fn reveal_global_table_round(
+        &mut self,
+        game_type: nullspace_types::casino::GameType,
+        round_id: u64,
+    ) -> anyhow::Result<Result<Vec<Event>, &'static str>> {
+        let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
+            Some(Value::GlobalTableConfig(config)) => config,
+            _ => return Ok(Err("Global table config missing")),
         };
 
         let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
         let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
             Some(Value::GlobalTableRound(round)) => round,
-            _ => {
-                return Ok(casino_error_vec(
-                    public,
-                    None,
-                    nullspace_types::casino::ERROR_INVALID_MOVE,
-                    "Round not initialized",
-                ))
+            _ => return Ok(Err("Round not initialized")),
+        };
+
+        if round.round_id != round_id {
+            return Ok(Err("Round ID mismatch"));
+        }
+
+        let rolling = crate::casino::global_table::has_rolling_phase(game_type);
+        let ready = match round.phase {
+            nullspace_types::casino::GlobalTablePhase::Locked => true,
+            nullspace_types::casino::GlobalTablePhase::Rolling => rolling,
+            _ => false,
+        };
+        if !ready || now_ms < round.phase_ends_at_ms {
+            return Ok(Err("Round not locked"));
+        }
+
+        if rolling
+            && matches!(
+                round.phase,
+                nullspace_types::casino::GlobalTablePhase::Locked
+            )
+        {
+            round.phase = nullspace_types::casino::GlobalTablePhase::Rolling;
+            round.phase_ends_at_ms =
+                now_ms.saturating_add(crate::casino::global_table::rolling_ms(game_type));
+            self.insert(
+                Key::GlobalTableRound(game_type),
+                Value::GlobalTableRound(round.clone()),
+            );
+            return Ok(Ok(vec![Event::GlobalTableRolling {
+                game_type,
+                round_id,
+                phase_ends_at_ms: round.phase_ends_at_ms,
+            }]));
+        }
+
+        let roll_seed = match roll_seed_from_round(&round) {
+            Some(seed) => seed,
+            None if round.rng_commit.is_empty() => {
+                let seed = derive_global_table_roll_seed(&self.seed, round.round_id);
+                round.roll_seed = seed.to_vec();
+                round.rng_commit = hash_roll_seed(&round.roll_seed);
+                seed
             }
+            None => return Ok(Err("Round RNG commit missing")),
+        };
+        let expected_commit = hash_roll_seed(&roll_seed);
+        if !round.rng_commit.is_empty() && round.rng_commit != expected_commit {
+            return Ok(Err("Round RNG commit mismatch"));
+        }
+        if round.rng_commit.is_empty() {
+            round.rng_commit = expected_commit;
+        }
+
+        if crate::casino::global_table::reveal_outcome(&mut round, roll_seed, self.seed_view)
+            .is_err()
+        {
+            return Ok(Err("Round roll failed"));
+        }
+
+        round.phase = nullspace_types::casino::GlobalTablePhase::Payout;
+        round.phase_ends_at_ms = now_ms.saturating_add(config.payout_ms);
+
+        self.insert(
+            Key::GlobalTableRound(game_type),
+            Value::GlobalTableRound(round.clone()),
+        );
+
+        Ok(Ok(vec![Event::GlobalTableOutcome { round }]))
+    }
+
+    async fn finalize_global_table_round(
+        &mut self,
+        game_type: nullspace_types::casino::GameType,
+        round_id: u64,
+    ) -> anyhow::Result<Result<Vec<Event>, &'static str>> {
+        let config = match self.get(Key::GlobalTableConfig(game_type)).await? {
+            Some(Value::GlobalTableConfig(config)) => config,
+            _ => return Ok(Err("Global table config missing")),
+        };
+
+        let now_ms = self.seed_view.saturating_mul(MS_PER_VIEW);
+        let mut round = match self.get(Key::GlobalTableRound(game_type)).await? {
+            Some(Value::GlobalTableRound(round)) => round,
+            _ => return Ok(Err("Round not initialized")),
         };
 
         if round.round_id != round_id {
-            return Ok(casino_error_vec(
-                public,
-                None,
-                nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Round ID mismatch",
-            ));
+            return Ok(Err("Round ID mismatch"));
         }
 
         if !matches!(
@@ -2754,12 +4064,7 @@ impl<'a, S: State> Layer<'a, S> {
             nullspace_types::casino::GlobalTablePhase::Payout
         ) || now_ms < round.phase_ends_at_ms
         {
-            return Ok(casino_error_vec(
-                public,
-                None,
-                nullspace_types::casino::ERROR_INVALID_MOVE,
-                "Round not ready for finalize",
-            ));
+            return Ok(Err("Round not ready for finalize"));
         }
 
         round.phase = nullspace_types::casino::GlobalTablePhase::Cooldown;
@@ -2769,7 +4074,7 @@ impl<'a, S: State> Layer<'a, S> {
             Value::GlobalTableRound(round.clone()),
         );
 
-        Ok(vec![Event::GlobalTableFinalized { game_type, round_id }])
+        Ok(Ok(vec![Event::GlobalTableFinalized { game_type, round_id }]))
     }
 
     async fn update_casino_leaderboard(
@@ -2951,15 +4256,6 @@ impl<'a, S: State> Layer<'a, S> {
     }
 }
 
-struct CrapsTableState {
-    main_point: u8,
-    d1: u8,
-    d2: u8,
-    made_points_mask: u8,
-    epoch_point_established: bool,
-    field_paytable: u8,
-}
-
 fn default_global_table_round(
     game_type: nullspace_types::casino::GameType,
 ) -> nullspace_types::casino::GlobalTableRound {
@@ -2977,6 +4273,7 @@ fn default_global_table_round(
         rng_commit: Vec::new(),
         roll_seed: Vec::new(),
         totals: Vec::new(),
+        outcome: Vec::new(),
     }
 }
 
@@ -3002,102 +4299,40 @@ fn hash_roll_seed(roll_seed: &[u8]) -> Vec<u8> {
     hasher.finalize().0.to_vec()
 }
 
-fn ensure_craps_session_state(
-    session: &mut nullspace_types::casino::GameSession,
-    round: &nullspace_types::casino::GlobalTableRound,
-    seed: &nullspace_types::Seed,
-) {
-    if session.state_blob.is_empty() {
-        let mut rng = crate::casino::GameRng::from_state(round_roll_seed_or_default(round, seed));
-        let _ = crate::casino::init_game(session, &mut rng);
-    }
-    sync_craps_session_to_table(session, round);
+fn global_table_bet_rejected(
+    public: &PublicKey,
+    round_id: u64,
+    error_code: u8,
+    message: &str,
+) -> Vec<Event> {
+    vec![Event::GlobalTableBetRejected {
+        player: public.clone(),
+        round_id,
+        error_code,
+        message: message.to_string(),
+    }]
 }
 
-fn sync_craps_session_to_table(
+/// Place table bets on a player's session, returning the chip delta (negative stakes).
+fn place_global_table_bets(
     session: &mut nullspace_types::casino::GameSession,
-    round: &nullspace_types::casino::GlobalTableRound,
-) {
-    if session.state_blob.len() < 8 {
-        return;
-    }
-    session.state_blob[1] = if round.main_point == 0 { 0 } else { 1 };
-    session.state_blob[2] = round.main_point;
-    session.state_blob[3] = round.d1;
-    session.state_blob[4] = round.d2;
-    session.state_blob[5] = round.made_points_mask;
-    session.state_blob[6] = if round.epoch_point_established { 1 } else { 0 };
-
-    let bet_count = session.state_blob[7] as usize;
-    let rules_offset = 8usize.saturating_add(bet_count.saturating_mul(19));
-    if session.state_blob.len() > rules_offset {
-        session.state_blob[rules_offset] = round.field_paytable;
-    }
-}
-
-fn bet_count_from_blob(blob: &[u8]) -> usize {
-    if blob.len() < 8 {
-        return 0;
-    }
-    blob[7] as usize
-}
-
-fn read_craps_table_state(blob: &[u8]) -> Option<CrapsTableState> {
-    if blob.len() < 8 || blob[0] != 2 {
-        return None;
-    }
-    let bet_count = blob[7] as usize;
-    let rules_offset = 8usize.saturating_add(bet_count.saturating_mul(19));
-    let field_paytable = if blob.len() > rules_offset {
-        blob[rules_offset]
-    } else {
-        0
-    };
-    Some(CrapsTableState {
-        main_point: blob[2],
-        d1: blob[3],
-        d2: blob[4],
-        made_points_mask: blob[5],
-        epoch_point_established: blob[6] != 0,
-        field_paytable,
-    })
-}
-
-fn extract_craps_bets(
-    blob: &[u8],
-) -> Vec<nullspace_types::casino::GlobalTableBet> {
-    if blob.len() < 8 || blob[0] != 2 {
-        return Vec::new();
-    }
-    let bet_count = blob[7] as usize;
-    let mut bets = Vec::with_capacity(bet_count);
-    let mut offset = 8usize;
-    for _ in 0..bet_count {
-        if offset + 19 > blob.len() {
-            break;
-        }
-        let bet_type = blob[offset];
-        let target = blob[offset + 1];
-        let amount = u64::from_be_bytes([
-            blob[offset + 3],
-            blob[offset + 4],
-            blob[offset + 5],
-            blob[offset + 6],
-            blob[offset + 7],
-            blob[offset + 8],
-            blob[offset + 9],
-            blob[offset + 10],
-        ]);
-        if amount > 0 {
-            bets.push(nullspace_types::casino::GlobalTableBet {
-                bet_type,
-                target,
-                amount,
-            });
+    config: &nullspace_types::casino::GlobalTableConfig,
+    roll_seed: [u8; 32],
+    bets: &[nullspace_types::casino::GlobalTableBet],
+) -> Result<i64, &'static str> {
+    let mut delta: i64 = 0;
+    for bet in bets {
+        if bet.amount < config.min_bet || bet.amount > config.max_bet {
+            return Err("Bet amount out of range");
         }
-        offset = offset.saturating_add(19);
+        let payload = crate::casino::global_table::place_bet_payload(session.game_type, bet)
+            .map_err(|_| "Invalid bet")?;
+        let mut rng = crate::casino::GameRng::from_state(roll_seed);
+        let result = crate::casino::process_game_move(session, &payload, &mut rng)
+            .map_err(|_| "Invalid bet")?;
+        delta = delta.saturating_add(game_result_delta(&result));
     }
-    bets
+    Ok(delta)
 }
 
 fn add_table_total(
@@ -3116,7 +4351,7 @@ fn add_table_total(
         existing.amount = existing.amount.saturating_add(amount);
         return;
     }
-    if totals.len() >= 64 {
+    if totals.len() >= nullspace_types::casino::MAX_GLOBAL_TABLE_TOTALS {
         return;
     }
     totals.push(nullspace_types::casino::GlobalTableTotal {
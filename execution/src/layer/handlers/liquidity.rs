@@ -363,6 +363,12 @@ fn validate_policy(policy: &nullspace_types::casino::PolicyState) -> Result<(),
     if policy.oracle_max_deviation_bps > MAX_BASIS_POINTS {
         return Err("invalid oracle deviation");
     }
+    if policy.transaction_fee_burn_bps > MAX_BASIS_POINTS {
+        return Err("invalid transaction fee burn");
+    }
+    if policy.casino_session_retention_views == 0 {
+        return Err("invalid session retention");
+    }
     if policy.bridge_max_withdraw > 0 && policy.bridge_min_withdraw > policy.bridge_max_withdraw {
         return Err("invalid bridge min/max");
     }
@@ -1250,7 +1256,10 @@ impl<'a, S: State> Layer<'a, S> {
         bootstrap_price_vusdt_numerator: u64,
         bootstrap_price_rng_denominator: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Treasury)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1337,7 +1346,10 @@ impl<'a, S: State> Layer<'a, S> {
         &mut self,
         public: &PublicKey,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Treasury)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1609,7 +1621,10 @@ impl<'a, S: State> Layer<'a, S> {
         public: &PublicKey,
         policy: &nullspace_types::casino::PolicyState,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Policy)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1641,7 +1656,10 @@ impl<'a, S: State> Layer<'a, S> {
         updated_ts: u64,
         source: &[u8],
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Oracle)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1688,7 +1706,10 @@ impl<'a, S: State> Layer<'a, S> {
         public: &PublicKey,
         treasury: &nullspace_types::casino::TreasuryState,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Treasury)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1717,7 +1738,10 @@ impl<'a, S: State> Layer<'a, S> {
         public: &PublicKey,
         vesting: &nullspace_types::casino::TreasuryVestingState,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Treasury)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1751,7 +1775,10 @@ impl<'a, S: State> Layer<'a, S> {
         bucket: &nullspace_types::casino::TreasuryBucket,
         amount: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Treasury)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1822,7 +1849,10 @@ impl<'a, S: State> Layer<'a, S> {
         public: &PublicKey,
         amount: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Policy)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1852,7 +1882,10 @@ impl<'a, S: State> Layer<'a, S> {
         target: &PublicKey,
         amount: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Policy)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -1918,7 +1951,10 @@ impl<'a, S: State> Layer<'a, S> {
         public: &PublicKey,
         amount: u64,
     ) -> anyhow::Result<Vec<Event>> {
-        if !super::is_admin_public_key(public) {
+        if !self
+            .has_admin_role(public, nullspace_types::casino::AdminRole::Policy)
+            .await?
+        {
             return Ok(casino_error_vec(
                 public,
                 None,
@@ -0,0 +1,374 @@
+use super::super::*;
+use super::casino_error_vec;
+use nullspace_types::casino::{
+    AdminRole, MultisigAccount, MultisigProposal, MAX_MULTISIG_TIMELOCK_SECS,
+};
+
+fn current_time_sec(view: u64) -> u64 {
+    view.saturating_mul(3)
+}
+
+impl<'a, S: State> Layer<'a, S> {
+    async fn get_multisig(&self, multisig_id: u64) -> anyhow::Result<Option<MultisigAccount>> {
+        Ok(match self.get(Key::Multisig(multisig_id)).await? {
+            Some(Value::Multisig(multisig)) => Some(multisig),
+            _ => None,
+        })
+    }
+
+    async fn get_multisig_proposal(
+        &self,
+        multisig_id: u64,
+        proposal_id: u64,
+    ) -> anyhow::Result<Option<MultisigProposal>> {
+        Ok(
+            match self
+                .get(Key::MultisigProposal(multisig_id, proposal_id))
+                .await?
+            {
+                Some(Value::MultisigProposal(proposal)) => Some(proposal),
+                _ => None,
+            },
+        )
+    }
+
+    /// Load a multisig and check that `public` is one of its signers.
+    async fn multisig_for_signer(
+        &self,
+        public: &PublicKey,
+        multisig_id: u64,
+    ) -> anyhow::Result<Result<MultisigAccount, Vec<Event>>> {
+        let Some(multisig) = self.get_multisig(multisig_id).await? else {
+            return Ok(Err(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Multisig not found",
+            )));
+        };
+        if !multisig.is_signer(public) {
+            return Ok(Err(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Not a multisig signer",
+            )));
+        }
+        Ok(Ok(multisig))
+    }
+
+    /// Load a proposal that is still waiting to execute.
+    async fn pending_multisig_proposal(
+        &self,
+        public: &PublicKey,
+        multisig_id: u64,
+        proposal_id: u64,
+    ) -> anyhow::Result<Result<MultisigProposal, Vec<Event>>> {
+        let Some(proposal) = self.get_multisig_proposal(multisig_id, proposal_id).await? else {
+            return Ok(Err(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Multisig proposal not found",
+            )));
+        };
+        if proposal.executed {
+            return Ok(Err(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Multisig proposal already executed",
+            )));
+        }
+        Ok(Ok(proposal))
+    }
+
+    pub(in crate::layer) async fn handle_create_multisig(
+        &mut self,
+        public: &PublicKey,
+        signers: &[PublicKey],
+        threshold: u8,
+        timelock_secs: u64,
+        roles: u8,
+    ) -> anyhow::Result<Vec<Event>> {
+        let mut registry = match self.admin_registry_for_update(public).await? {
+            Ok(registry) => registry,
+            Err(events) => return Ok(events),
+        };
+
+        let mut signers = signers.to_vec();
+        signers.sort_unstable();
+        signers.dedup();
+        if threshold == 0 {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Invalid multisig threshold",
+            ));
+        }
+        if threshold as usize > signers.len() {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Multisig threshold exceeds signer count",
+            ));
+        }
+        if timelock_secs > MAX_MULTISIG_TIMELOCK_SECS {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Multisig time-lock too long",
+            ));
+        }
+        if roles == 0 || roles & !AdminRole::all_mask() != 0 {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Invalid multisig roles",
+            ));
+        }
+
+        let multisig = MultisigAccount {
+            id: registry.next_multisig_id,
+            signers,
+            threshold,
+            timelock_secs,
+            roles,
+            next_proposal_id: 0,
+        };
+        registry.next_multisig_id = registry.next_multisig_id.saturating_add(1);
+        // From here on these roles can only be exercised through the multisig.
+        registry.guarded_roles |= roles;
+
+        self.insert(Key::AdminRegistry, Value::AdminRegistry(registry));
+        self.insert(
+            Key::Multisig(multisig.id),
+            Value::Multisig(multisig.clone()),
+        );
+
+        Ok(vec![Event::MultisigCreated {
+            admin: public.clone(),
+            multisig,
+        }])
+    }
+
+    pub(in crate::layer) async fn handle_propose_multisig(
+        &mut self,
+        public: &PublicKey,
+        multisig_id: u64,
+        instruction: &Instruction,
+    ) -> anyhow::Result<Vec<Event>> {
+        let mut multisig = match self.multisig_for_signer(public, multisig_id).await? {
+            Ok(multisig) => multisig,
+            Err(events) => return Ok(events),
+        };
+        if instruction.is_multisig_proposal_step() {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Multisig proposals cannot be nested",
+            ));
+        }
+        if matches!(
+            instruction,
+            Instruction::DissolveMultisig { multisig_id: target } if *target != multisig_id
+        ) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "A multisig can only dissolve itself",
+            ));
+        }
+
+        let mut proposal = MultisigProposal {
+            multisig_id,
+            id: multisig.next_proposal_id,
+            proposer: public.clone(),
+            instruction: instruction.clone(),
+            approvals: vec![public.clone()],
+            executable_at: 0,
+            executed: false,
+        };
+        if proposal.is_approved(&multisig) {
+            proposal.executable_at =
+                current_time_sec(self.seed_view).saturating_add(multisig.timelock_secs);
+        }
+        multisig.next_proposal_id = multisig.next_proposal_id.saturating_add(1);
+
+        self.insert(
+            Key::MultisigProposal(multisig_id, proposal.id),
+            Value::MultisigProposal(proposal.clone()),
+        );
+        self.insert(Key::Multisig(multisig_id), Value::Multisig(multisig));
+
+        Ok(vec![Event::MultisigProposed {
+            proposer: public.clone(),
+            multisig_id,
+            proposal_id: proposal.id,
+        }])
+    }
+
+    pub(in crate::layer) async fn handle_approve_multisig(
+        &mut self,
+        public: &PublicKey,
+        multisig_id: u64,
+        proposal_id: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        let multisig = match self.multisig_for_signer(public, multisig_id).await? {
+            Ok(multisig) => multisig,
+            Err(events) => return Ok(events),
+        };
+        let mut proposal = match self
+            .pending_multisig_proposal(public, multisig_id, proposal_id)
+            .await?
+        {
+            Ok(proposal) => proposal,
+            Err(events) => return Ok(events),
+        };
+
+        let was_approved = proposal.is_approved(&multisig);
+        if !proposal.approve(public) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Signer already approved this proposal",
+            ));
+        }
+        // The time-lock starts once the threshold is reached, not when the proposal was made.
+        if !was_approved && proposal.is_approved(&multisig) {
+            proposal.executable_at =
+                current_time_sec(self.seed_view).saturating_add(multisig.timelock_secs);
+        }
+
+        let approvals = proposal.approvals.len() as u8;
+        let executable_at = proposal.executable_at;
+        self.insert(
+            Key::MultisigProposal(multisig_id, proposal_id),
+            Value::MultisigProposal(proposal),
+        );
+
+        Ok(vec![Event::MultisigApproved {
+            signer: public.clone(),
+            multisig_id,
+            proposal_id,
+            approvals,
+            executable_at,
+        }])
+    }
+
+    pub(in crate::layer) async fn handle_execute_multisig(
+        &mut self,
+        public: &PublicKey,
+        multisig_id: u64,
+        proposal_id: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        let multisig = match self.multisig_for_signer(public, multisig_id).await? {
+            Ok(multisig) => multisig,
+            Err(events) => return Ok(events),
+        };
+        let mut proposal = match self
+            .pending_multisig_proposal(public, multisig_id, proposal_id)
+            .await?
+        {
+            Ok(proposal) => proposal,
+            Err(events) => return Ok(events),
+        };
+        if !proposal.is_approved(&multisig) {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Multisig proposal has not reached its threshold",
+            ));
+        }
+        if current_time_sec(self.seed_view) < proposal.executable_at {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_RATE_LIMITED,
+                "Multisig proposal is still time-locked",
+            ));
+        }
+
+        self.multisig_roles = multisig.roles;
+        let inner = self.apply_instruction(public, &proposal.instruction).await;
+        self.multisig_roles = 0;
+        let inner = match inner? {
+            Ok(events) => events,
+            // A rejected inner instruction leaves the proposal pending so it can be retried.
+            Err(events) if events.is_empty() => {
+                return Ok(casino_error_vec(
+                    public,
+                    None,
+                    nullspace_types::casino::ERROR_INVALID_MOVE,
+                    "Multisig proposal had no effect",
+                ))
+            }
+            Err(events) => return Ok(events),
+        };
+
+        proposal.executed = true;
+        self.insert(
+            Key::MultisigProposal(multisig_id, proposal_id),
+            Value::MultisigProposal(proposal),
+        );
+
+        let mut events = vec![Event::MultisigExecuted {
+            executor: public.clone(),
+            multisig_id,
+            proposal_id,
+        }];
+        events.extend(inner);
+        Ok(events)
+    }
+
+    /// Delete a multisig and recompute which roles are still guarded.
+    ///
+    /// Only reachable through the multisig's own approved proposal (proposals are checked to
+    /// target the multisig they were made on).
+    pub(in crate::layer) async fn handle_dissolve_multisig(
+        &mut self,
+        public: &PublicKey,
+        multisig_id: u64,
+    ) -> anyhow::Result<Vec<Event>> {
+        if self.multisig_roles == 0 {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_UNAUTHORIZED,
+                "Multisig dissolution must be approved by the multisig",
+            ));
+        }
+        if self.get_multisig(multisig_id).await?.is_none() {
+            return Ok(casino_error_vec(
+                public,
+                None,
+                nullspace_types::casino::ERROR_INVALID_MOVE,
+                "Multisig not found",
+            ));
+        }
+
+        self.remove(Key::Multisig(multisig_id));
+        let mut registry = self.get_admin_registry().await?.unwrap_or_default();
+        let mut guarded_roles = 0;
+        for id in 0..registry.next_multisig_id {
+            if let Some(multisig) = self.get_multisig(id).await? {
+                guarded_roles |= multisig.roles;
+            }
+        }
+        registry.guarded_roles = guarded_roles;
+        self.insert(Key::AdminRegistry, Value::AdminRegistry(registry));
+
+        Ok(vec![Event::MultisigDissolved {
+            multisig_id,
+            guarded_roles,
+        }])
+    }
+}
@@ -0,0 +1,105 @@
+use super::super::*;
+use nullspace_types::casino::{
+    GameSession, SessionDeadline, MAX_SESSION_GC_VIEWS_PER_BLOCK, SESSION_EXPIRY,
+};
+use nullspace_types::execution::MAX_BLOCK_TRANSACTIONS;
+
+impl<'a, S: State> Layer<'a, S> {
+    /// Queue `session` to be checked for inactivity `SESSION_EXPIRY` views from now.
+    ///
+    /// Called when the session starts and after every move that leaves it open, so the latest
+    /// deadline always sits `SESSION_EXPIRY` views after the last move.
+    pub(in crate::layer) async fn schedule_session_expiry(
+        &mut self,
+        session: &GameSession,
+    ) -> anyhow::Result<()> {
+        let deadline = SessionDeadline {
+            session_id: session.id,
+            move_count: session.move_count,
+        };
+        // A full view spills into the next one so every active session is eventually checked.
+        let mut view = self.seed_view.saturating_add(SESSION_EXPIRY);
+        loop {
+            let key = Key::CasinoSessionDeadlines(view);
+            let mut deadlines = match self.get(key.clone()).await? {
+                Some(Value::CasinoSessionDeadlines(deadlines)) => deadlines,
+                _ => Vec::new(),
+            };
+            if deadlines.len() < MAX_BLOCK_TRANSACTIONS {
+                deadlines.push(deadline);
+                self.insert(key, Value::CasinoSessionDeadlines(deadlines));
+                return Ok(());
+            }
+            view = view.saturating_add(1);
+        }
+    }
+
+    /// Resolve sessions that went `SESSION_EXPIRY` views without a move, sweeping at most
+    /// `MAX_SESSION_GC_VIEWS_PER_BLOCK` deadline views per block.
+    pub(in crate::layer) async fn expire_casino_sessions(&mut self) -> anyhow::Result<Vec<Event>> {
+        let mut gc = self.session_gc_state().await?;
+        if gc.next_deadline_view > self.seed_view {
+            return Ok(Vec::new());
+        }
+
+        let end = self.seed_view.min(
+            gc.next_deadline_view
+                .saturating_add(MAX_SESSION_GC_VIEWS_PER_BLOCK - 1),
+        );
+        let mut events = Vec::new();
+        for view in gc.next_deadline_view..=end {
+            let key = Key::CasinoSessionDeadlines(view);
+            let Some(Value::CasinoSessionDeadlines(deadlines)) = self.get(key.clone()).await?
+            else {
+                continue;
+            };
+            self.remove(key);
+            for deadline in deadlines {
+                events.extend(self.expire_casino_session(deadline).await?);
+            }
+        }
+        gc.next_deadline_view = end.saturating_add(1);
+        self.insert(Key::CasinoSessionGc, Value::CasinoSessionGc(gc));
+        Ok(events)
+    }
+
+    /// Abandon the session behind `deadline` if the player has not moved since it was set.
+    async fn expire_casino_session(
+        &mut self,
+        deadline: SessionDeadline,
+    ) -> anyhow::Result<Vec<Event>> {
+        let mut session = match self.get(Key::CasinoSession(deadline.session_id)).await? {
+            Some(Value::CasinoSession(session)) if !session.is_complete => session,
+            _ => return Ok(Vec::new()),
+        };
+        // The player moved since; that move queued its own deadline.
+        if session.move_count != deadline.move_count {
+            return Ok(Vec::new());
+        }
+
+        session.move_count = session.move_count.saturating_add(1);
+        let mut rng = crate::casino::GameRng::new(&self.seed, session.id, session.move_count);
+        let result = match crate::casino::abandon_game(&mut session, &mut rng) {
+            Ok(result) => result,
+            Err(err) => {
+                // A game that cannot resolve itself returns the stake rather than keep it.
+                tracing::warn!(
+                    session_id = session.id,
+                    game_type = ?session.game_type,
+                    ?err,
+                    "abandon failed; refunding session"
+                );
+                crate::casino::GameResult::Push(session.bet, Vec::new())
+            }
+        };
+        tracing::info!(
+            player = ?session.player,
+            session_id = session.id,
+            game_type = ?session.game_type,
+            "casino session abandoned"
+        );
+
+        let player = session.player.clone();
+        self.settle_casino_move(&player, session, result, 0).await
+    }
+}
@@ -0,0 +1,143 @@
+use super::super::*;
+use nullspace_types::casino::{SessionGcState, MAX_SESSION_GC_VIEWS_PER_BLOCK};
+
+impl<'a, S: State> Layer<'a, S> {
+    /// Delete `session_id` if it is complete and was created no later than `created_by`.
+    ///
+    /// The creation bound keeps a reused session id from being removed before its own
+    /// retention window ends.
+    async fn delete_completed_session(
+        &mut self,
+        session_id: u64,
+        created_by: u64,
+    ) -> anyhow::Result<bool> {
+        match self.get(Key::CasinoSession(session_id)).await? {
+            Some(Value::CasinoSession(session))
+                if session.is_complete && session.created_at <= created_by =>
+            {
+                self.remove(Key::CasinoSession(session_id));
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Queue a just-completed session for deletion once its retention window has passed.
+    pub(in crate::layer) async fn schedule_session_gc(
+        &mut self,
+        session_id: u64,
+    ) -> anyhow::Result<()> {
+        // A view's list is capped at the block limit; once it is full the session spills into
+        // the next view's list, which the sweep reaches one view later.
+        let mut view = self.seed_view;
+        loop {
+            let key = Key::CompletedCasinoSessions(view);
+            let mut session_ids = match self.get(key.clone()).await? {
+                Some(Value::CompletedCasinoSessions(session_ids)) => session_ids,
+                _ => Vec::new(),
+            };
+            if session_ids.len() < nullspace_types::execution::MAX_BLOCK_TRANSACTIONS {
+                session_ids.push(session_id);
+                self.insert(key, Value::CompletedCasinoSessions(session_ids));
+                return Ok(());
+            }
+            view = view.saturating_add(1);
+        }
+    }
+
+    /// Record `session_id` as the player's latest session, deleting the previous one if complete.
+    pub(in crate::layer) async fn release_previous_session(
+        &mut self,
+        player: &mut nullspace_types::casino::Player,
+        session_id: u64,
+    ) -> anyhow::Result<()> {
+        if let Some(previous) = player.session.last_session.replace(session_id) {
+            self.delete_completed_session(previous, self.seed_view)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Views a completed session is kept in state, as set by the policy.
+    async fn session_retention_views(&mut self) -> anyhow::Result<u64> {
+        Ok(self
+            .get_or_init_policy()
+            .await?
+            .casino_session_retention_views)
+    }
+
+    /// Load the sweep cursors.
+    ///
+    /// Nothing was queued before a sweep first ran, so each one starts at the view it would
+    /// currently be processing.
+    pub(in crate::layer) async fn session_gc_state(&mut self) -> anyhow::Result<SessionGcState> {
+        if let Some(Value::CasinoSessionGc(gc)) = self.get(Key::CasinoSessionGc).await? {
+            return Ok(gc);
+        }
+        let retention = self.session_retention_views().await?;
+        Ok(SessionGcState {
+            next_view: self.seed_view.saturating_sub(retention),
+            next_deadline_view: self.seed_view,
+        })
+    }
+
+    /// Delete sessions whose retention window ended, sweeping at most
+    /// `MAX_SESSION_GC_VIEWS_PER_BLOCK` completion views per block.
+    pub(in crate::layer) async fn collect_casino_sessions(&mut self) -> anyhow::Result<()> {
+        let retention = self.session_retention_views().await?;
+        let Some(due) = self.seed_view.checked_sub(retention) else {
+            return Ok(());
+        };
+        let mut gc = self.session_gc_state().await?;
+        if gc.next_view > due {
+            return Ok(());
+        }
+
+        let end = due.min(
+            gc.next_view
+                .saturating_add(MAX_SESSION_GC_VIEWS_PER_BLOCK - 1),
+        );
+        for view in gc.next_view..=end {
+            let key = Key::CompletedCasinoSessions(view);
+            let Some(Value::CompletedCasinoSessions(session_ids)) = self.get(key.clone()).await?
+            else {
+                continue;
+            };
+            for session_id in session_ids {
+                self.delete_completed_session(session_id, view).await?;
+            }
+            self.remove(key);
+        }
+        gc.next_view = end.saturating_add(1);
+        self.insert(Key::CasinoSessionGc, Value::CasinoSessionGc(gc));
+        Ok(())
+    }
+
+    pub(in crate::layer) async fn handle_prune_casino_sessions(
+        &mut self,
+        public: &PublicKey,
+        session_ids: &[u64],
+    ) -> anyhow::Result<Vec<Event>> {
+        // Sessions created inside the retention window may still be in use.
+        let retention = self.session_retention_views().await?;
+        let Some(created_by) = self.seed_view.checked_sub(retention) else {
+            return Ok(Vec::new());
+        };
+        let mut pruned = Vec::new();
+        for &session_id in session_ids {
+            if self
+                .delete_completed_session(session_id, created_by)
+                .await?
+            {
+                pruned.push(session_id);
+            }
+        }
+        if pruned.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(vec![Event::CasinoSessionsPruned {
+            pruner: public.clone(),
+            session_ids: pruned,
+        }])
+    }
+}
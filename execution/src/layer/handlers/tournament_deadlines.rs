@@ -0,0 +1,73 @@
+use super::super::*;
+use nullspace_types::casino::{
+    TournamentDeadline, MAX_SCHEDULED_TOURNAMENT_ENDS, MAX_TOURNAMENT_ENDS_PER_BLOCK,
+};
+
+impl<'a, S: State> Layer<'a, S> {
+    /// Pending sit-and-go ends, ordered by `end_view`.
+    pub(in crate::layer) async fn tournament_deadlines(
+        &self,
+    ) -> anyhow::Result<Vec<TournamentDeadline>> {
+        Ok(match self.get(Key::TournamentDeadlines).await? {
+            Some(Value::TournamentDeadlines(deadlines)) => deadlines,
+            _ => Vec::new(),
+        })
+    }
+
+    /// Whether another sit-and-go end can be queued without overflowing the deadline list.
+    pub(in crate::layer) async fn can_schedule_tournament_end(&self) -> anyhow::Result<bool> {
+        Ok(self.tournament_deadlines().await?.len() < MAX_SCHEDULED_TOURNAMENT_ENDS)
+    }
+
+    /// Queue `tournament_id` to be paid out once `end_view` is reached.
+    pub(in crate::layer) async fn schedule_tournament_end(
+        &mut self,
+        tournament_id: u64,
+        end_view: u64,
+    ) -> anyhow::Result<()> {
+        let mut deadlines = self.tournament_deadlines().await?;
+        let index = deadlines.partition_point(|deadline| deadline.end_view <= end_view);
+        deadlines.insert(
+            index,
+            TournamentDeadline {
+                end_view,
+                tournament_id,
+            },
+        );
+        self.insert(
+            Key::TournamentDeadlines,
+            Value::TournamentDeadlines(deadlines),
+        );
+        Ok(())
+    }
+
+    /// Complete sit-and-go events whose end view has passed, at most
+    /// `MAX_TOURNAMENT_ENDS_PER_BLOCK` per block; the rest carry over to the next block.
+    pub(in crate::layer) async fn end_due_tournaments(&mut self) -> anyhow::Result<Vec<Event>> {
+        let mut deadlines = self.tournament_deadlines().await?;
+        let due = deadlines
+            .iter()
+            .take(MAX_TOURNAMENT_ENDS_PER_BLOCK)
+            .take_while(|deadline| deadline.end_view <= self.seed_view)
+            .count();
+        if due == 0 {
+            return Ok(Vec::new());
+        }
+
+        let ended: Vec<_> = deadlines.drain(..due).collect();
+        if deadlines.is_empty() {
+            self.remove(Key::TournamentDeadlines);
+        } else {
+            self.insert(
+                Key::TournamentDeadlines,
+                Value::TournamentDeadlines(deadlines),
+            );
+        }
+
+        let mut events = Vec::new();
+        for deadline in ended {
+            events.extend(self.complete_tournament(deadline.tournament_id).await?);
+        }
+        Ok(events)
+    }
+}
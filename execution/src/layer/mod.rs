@@ -8,7 +8,7 @@ use commonware_cryptography::{
 #[cfg(feature = "parallel")]
 use commonware_runtime::ThreadPool;
 use nullspace_types::{
-    execution::{Event, Instruction, Key, Output, Transaction, Value},
+    execution::{Event, GenesisConfig, Instruction, Key, Output, Transaction, Value},
     Seed,
 };
 use std::collections::BTreeMap;
@@ -18,6 +18,8 @@ use crate::casino::cards as card_utils;
 use crate::state::{load_account, validate_and_increment_nonce, PrepareError, State, Status};
 
 mod handlers;
+#[cfg(feature = "parallel")]
+mod parallel;
 
 // Keep a small amount of LP tokens permanently locked so the pool can never be fully drained.
 // This mirrors the MINIMUM_LIQUIDITY pattern used by Raydium/Uniswap to avoid zero-price states.
@@ -154,6 +156,15 @@ pub struct Layer<'a, S: State> {
 
     seed: Seed,
     seed_view: u64,
+
+    /// Roles of the multisig whose approved proposal is executing (0 otherwise).
+    multisig_roles: u8,
+    /// State writes made so far; an instruction that writes nothing had no effect.
+    write_count: u64,
+
+    /// Transaction fees charged in this block, credited to the house once the block is done.
+    fees_burned: u64,
+    fees_to_house: u64,
 }
 
 impl<'a, S: State> Layer<'a, S> {
@@ -183,27 +194,122 @@ impl<'a, S: State> Layer<'a, S> {
 
             seed,
             seed_view,
+
+            multisig_roles: 0,
+            write_count: 0,
+
+            fees_burned: 0,
+            fees_to_house: 0,
         }
     }
 
     fn insert(&mut self, key: Key, value: Value) {
+        self.write_count += 1;
         self.pending.insert(key, Status::Update(value));
     }
 
+    fn remove(&mut self, key: Key) {
+        self.write_count += 1;
+        self.pending.insert(key, Status::Delete);
+    }
+
     pub fn view(&self) -> View {
         View::new(self.seed_view)
     }
 
-    async fn prepare(&mut self, transaction: &Transaction) -> Result<(), PrepareError> {
+    async fn prepare(&mut self, transaction: &Transaction) -> Result<Option<Event>, PrepareError> {
+        if let Some(expires_at_view) = transaction.expires_at_view {
+            if transaction.is_expired(self.seed_view) {
+                return Err(PrepareError::Expired {
+                    expires_at_view,
+                    view: self.seed_view,
+                });
+            }
+        }
         let mut account = load_account(self, &transaction.public)
             .await
             .map_err(PrepareError::State)?;
         validate_and_increment_nonce(&mut account, transaction.nonce)?;
+        let fee_event = self
+            .charge_transaction_fee(&transaction.public, transaction.fee)
+            .await?;
         self.insert(
             Key::Account(transaction.public.clone()),
             Value::Account(account),
         );
 
+        Ok(fee_event)
+    }
+
+    /// Debit a transaction fee from the signer's chips (the player's RNG balance), splitting it
+    /// between burn and house by the policy's `transaction_fee_burn_bps`.
+    ///
+    /// A fee the signer cannot cover rejects the transaction without consuming its nonce.
+    /// The house share is only accumulated here and credited by [`Self::credit_transaction_fees`],
+    /// so paying a fee never makes two transactions contend on `Key::House`.
+    async fn charge_transaction_fee(
+        &mut self,
+        public: &PublicKey,
+        fee: u64,
+    ) -> Result<Option<Event>, PrepareError> {
+        if fee == 0 {
+            return Ok(None);
+        }
+        let mut player = match self
+            .get(Key::CasinoPlayer(public.clone()))
+            .await
+            .map_err(PrepareError::State)?
+        {
+            Some(Value::CasinoPlayer(player)) => player,
+            _ => {
+                return Err(PrepareError::InsufficientFee {
+                    required: fee,
+                    available: 0,
+                })
+            }
+        };
+        if player.balances.chips < fee {
+            return Err(PrepareError::InsufficientFee {
+                required: fee,
+                available: player.balances.chips,
+            });
+        }
+        player.balances.chips -= fee;
+
+        let burn_bps = self
+            .get_or_init_policy()
+            .await
+            .map_err(PrepareError::State)?
+            .transaction_fee_burn_bps;
+        let burned = (fee as u128 * burn_bps as u128 / 10_000) as u64;
+        self.fees_burned = self.fees_burned.saturating_add(burned);
+        self.fees_to_house = self.fees_to_house.saturating_add(fee - burned);
+
+        let player_balances = nullspace_types::casino::PlayerBalanceSnapshot::from_player(&player);
+        self.insert(
+            Key::CasinoPlayer(public.clone()),
+            Value::CasinoPlayer(player),
+        );
+
+        Ok(Some(Event::TransactionFeePaid {
+            player: public.clone(),
+            fee,
+            burned,
+            player_balances,
+        }))
+    }
+
+    /// Credit the fees charged so far in this block to the house.
+    async fn credit_transaction_fees(&mut self) -> Result<()> {
+        if self.fees_burned == 0 && self.fees_to_house == 0 {
+            return Ok(());
+        }
+        let mut house = self.get_or_init_house().await?;
+        house.total_burned = house.total_burned.saturating_add(self.fees_burned);
+        house.accumulated_fees = house.accumulated_fees.saturating_add(self.fees_to_house);
+        self.insert(Key::House, Value::House(house));
+        self.fees_burned = 0;
+        self.fees_to_house = 0;
         Ok(())
     }
 
@@ -250,12 +356,14 @@ impl<'a, S: State> Layer<'a, S> {
                 tournament_id,
                 start_time_ms,
                 end_time_ms,
+                payout_structure,
             } => {
                 self.handle_casino_start_tournament(
                     public,
                     *tournament_id,
                     *start_time_ms,
                     *end_time_ms,
+                    payout_structure,
                 )
                 .await
             }
@@ -301,10 +409,50 @@ impl<'a, S: State> Layer<'a, S> {
                 self.handle_global_table_finalize(public, *game_type, *round_id)
                     .await
             }
+            Instruction::GlobalTableCancelBets {
+                game_type,
+                round_id,
+            } => {
+                self.handle_global_table_cancel_bets(public, *game_type, *round_id)
+                    .await
+            }
+            Instruction::GlobalTableReplaceBets {
+                game_type,
+                round_id,
+                bets,
+            } => {
+                self.handle_global_table_replace_bets(public, *game_type, *round_id, bets)
+                    .await
+            }
+            Instruction::GlobalTableCashOut {
+                game_type,
+                round_id,
+            } => {
+                self.handle_global_table_cash_out(public, *game_type, *round_id)
+                    .await
+            }
             Instruction::CasinoEndTournament { tournament_id } => {
                 self.handle_casino_end_tournament(public, *tournament_id)
                     .await
             }
+            Instruction::CasinoCreateTournament {
+                tournament_id,
+                config,
+            } => {
+                self.handle_casino_create_tournament(public, *tournament_id, config)
+                    .await
+            }
+            Instruction::CasinoCancelTournament { tournament_id } => {
+                self.handle_casino_cancel_tournament(public, *tournament_id)
+                    .await
+            }
+            Instruction::CasinoTournamentTopUp {
+                tournament_id,
+                kind,
+            } => {
+                self.handle_casino_tournament_top_up(public, *tournament_id, *kind)
+                    .await
+            }
             _ => anyhow::bail!("internal error: apply_casino called with non-casino instruction"),
         }
     }
@@ -462,10 +610,96 @@ impl<'a, S: State> Layer<'a, S> {
         }
     }
 
+    async fn apply_admin(
+        &mut self,
+        public: &PublicKey,
+        instruction: &Instruction,
+    ) -> Result<Vec<Event>> {
+        match instruction {
+            Instruction::GrantAdminRole {
+                public: grantee,
+                role,
+            } => self.handle_grant_admin_role(public, grantee, *role).await,
+            Instruction::RevokeAdminRole {
+                public: revokee,
+                role,
+            } => self.handle_revoke_admin_role(public, revokee, *role).await,
+            Instruction::CreateMultisig {
+                signers,
+                threshold,
+                timelock_secs,
+                roles,
+            } => {
+                self.handle_create_multisig(public, signers, *threshold, *timelock_secs, *roles)
+                    .await
+            }
+            Instruction::ProposeMultisig {
+                multisig_id,
+                instruction,
+            } => {
+                self.handle_propose_multisig(public, *multisig_id, instruction)
+                    .await
+            }
+            Instruction::ApproveMultisig {
+                multisig_id,
+                proposal_id,
+            } => {
+                self.handle_approve_multisig(public, *multisig_id, *proposal_id)
+                    .await
+            }
+            Instruction::DissolveMultisig { multisig_id } => {
+                self.handle_dissolve_multisig(public, *multisig_id).await
+            }
+            _ => anyhow::bail!("internal error: apply_admin called with non-admin instruction"),
+        }
+    }
+
     async fn apply(&mut self, transaction: &Transaction) -> Result<Vec<Event>> {
         let instruction = &transaction.instruction;
         let public = &transaction.public;
 
+        match instruction {
+            // Executing a proposal re-enters `apply_instruction` with the multisig's roles, so it
+            // is dispatched here rather than alongside the other admin instructions.
+            Instruction::ExecuteMultisig {
+                multisig_id,
+                proposal_id,
+            } => {
+                self.handle_execute_multisig(public, *multisig_id, *proposal_id)
+                    .await
+            }
+            _ => Ok(match self.apply_instruction(public, instruction).await? {
+                Ok(events) | Err(events) => events,
+            }),
+        }
+    }
+
+    /// Run an instruction, returning `Err` with its events if the instruction was refused.
+    ///
+    /// Handlers refuse either with a rejection event or by returning before they write any
+    /// state, so an instruction only counts as applied if it wrote state without raising one.
+    async fn apply_instruction(
+        &mut self,
+        public: &PublicKey,
+        instruction: &Instruction,
+    ) -> Result<Result<Vec<Event>, Vec<Event>>> {
+        let write_count = self.write_count;
+        let events = self.dispatch_instruction(public, instruction).await?;
+        let rejected = self.write_count == write_count
+            || events.iter().any(|event| {
+                matches!(
+                    event,
+                    Event::CasinoError { .. } | Event::GlobalTableBetRejected { .. }
+                )
+            });
+        Ok(if rejected { Err(events) } else { Ok(events) })
+    }
+
+    async fn dispatch_instruction(
+        &mut self,
+        public: &PublicKey,
+        instruction: &Instruction,
+    ) -> Result<Vec<Event>> {
         match instruction {
             Instruction::CasinoRegister { .. }
             | Instruction::CasinoDeposit { .. }
@@ -476,13 +710,19 @@ impl<'a, S: State> Layer<'a, S> {
             | Instruction::CasinoSetTournamentLimit { .. }
             | Instruction::CasinoStartTournament { .. }
             | Instruction::CasinoEndTournament { .. }
+            | Instruction::CasinoCreateTournament { .. }
+            | Instruction::CasinoCancelTournament { .. }
+            | Instruction::CasinoTournamentTopUp { .. }
             | Instruction::GlobalTableInit { .. }
             | Instruction::GlobalTableOpenRound { .. }
             | Instruction::GlobalTableSubmitBets { .. }
             | Instruction::GlobalTableLock { .. }
             | Instruction::GlobalTableReveal { .. }
             | Instruction::GlobalTableSettle { .. }
-            | Instruction::GlobalTableFinalize { .. } => {
+            | Instruction::GlobalTableFinalize { .. }
+            | Instruction::GlobalTableCancelBets { .. }
+            | Instruction::GlobalTableReplaceBets { .. }
+            | Instruction::GlobalTableCashOut { .. } => {
                 self.apply_casino(public, instruction).await
             }
 
@@ -520,6 +760,21 @@ impl<'a, S: State> Layer<'a, S> {
             | Instruction::FinalizeBridgeWithdrawal { .. } => {
                 self.apply_bridge(public, instruction).await
             }
+
+            Instruction::GrantAdminRole { .. }
+            | Instruction::RevokeAdminRole { .. }
+            | Instruction::CreateMultisig { .. }
+            | Instruction::ProposeMultisig { .. }
+            | Instruction::ApproveMultisig { .. }
+            | Instruction::DissolveMultisig { .. } => self.apply_admin(public, instruction).await,
+
+            Instruction::PruneCasinoSessions { session_ids } => {
+                self.handle_prune_casino_sessions(public, session_ids).await
+            }
+
+            Instruction::ExecuteMultisig { .. } => {
+                anyhow::bail!("internal error: nested multisig execution")
+            }
         }
     }
 
@@ -620,34 +875,116 @@ impl<'a, S: State> Layer<'a, S> {
         })
     }
 
+    /// Prepare and apply a single transaction, returning its events.
+    ///
+    /// Returns `None` when the transaction is dropped before it runs (bad nonce, unpaid fee or
+    /// expired); its nonce is then left untouched.
+    async fn execute_transaction(&mut self, tx: &Transaction) -> Result<Option<Vec<Event>>> {
+        let fee_event = match self.prepare(tx).await {
+            Ok(fee_event) => fee_event,
+            Err(PrepareError::NonceMismatch { expected, got }) => {
+                debug!(
+                    public = ?tx.public,
+                    expected,
+                    got,
+                    "nonce mismatch; dropping transaction"
+                );
+                return Ok(None);
+            }
+            Err(PrepareError::InsufficientFee {
+                required,
+                available,
+            }) => {
+                debug!(
+                    public = ?tx.public,
+                    required,
+                    available,
+                    "insufficient balance for fee; dropping transaction"
+                );
+                return Ok(None);
+            }
+            Err(PrepareError::Expired {
+                expires_at_view,
+                view,
+            }) => {
+                debug!(
+                    public = ?tx.public,
+                    expires_at_view,
+                    view,
+                    "transaction expired; dropping transaction"
+                );
+                return Ok(None);
+            }
+            Err(PrepareError::State(err)) => {
+                return Err(err).context("state error during prepare");
+            }
+        };
+        let mut events: Vec<Event> = fee_event.into_iter().collect();
+        events.extend(self.apply(tx).await?);
+        Ok(Some(events))
+    }
+
     pub async fn execute(
         &mut self,
-        #[cfg(feature = "parallel")] _pool: ThreadPool,
+        #[cfg(feature = "parallel")] pool: ThreadPool,
+        genesis: &GenesisConfig,
         transactions: Vec<Transaction>,
     ) -> Result<(Vec<Output>, BTreeMap<PublicKey, u64>)> {
         let mut processed_nonces = BTreeMap::new();
         let mut outputs = Vec::new();
 
-        for tx in transactions {
-            match self.prepare(&tx).await {
-                Ok(()) => {}
-                Err(PrepareError::NonceMismatch { expected, got }) => {
-                    debug!(
-                        public = ?tx.public,
-                        expected,
-                        got,
-                        "nonce mismatch; dropping transaction"
-                    );
-                    continue;
-                }
-                Err(PrepareError::State(err)) => {
-                    return Err(err).context("state error during prepare");
-                }
+        // The genesis admin keys are persisted once; after that, admin roles only change on chain.
+        self.bootstrap_admin_registry(genesis).await?;
+
+        // Global table phases advance on chain time before any transaction runs, so bets
+        // and settlements in this block see the round as of this view.
+        outputs.extend(
+            self.advance_global_tables()
+                .await?
+                .into_iter()
+                .map(Output::Event),
+        );
+
+        // Completed sessions past their retention window are dropped; their outcome is in the
+        // events log.
+        self.collect_casino_sessions().await?;
+
+        // Sessions the player stopped acting on are resolved by their game's abandonment policy.
+        outputs.extend(
+            self.expire_casino_sessions()
+                .await?
+                .into_iter()
+                .map(Output::Event),
+        );
+
+        // Sit-and-go events end on chain time once their duration lapses.
+        outputs.extend(
+            self.end_due_tournaments()
+                .await?
+                .into_iter()
+                .map(Output::Event),
+        );
+
+        #[cfg(feature = "parallel")]
+        let results = self.execute_parallel(&pool, &transactions).await?;
+        #[cfg(not(feature = "parallel"))]
+        let results = {
+            let mut results = Vec::with_capacity(transactions.len());
+            for tx in &transactions {
+                results.push(self.execute_transaction(tx).await?);
             }
+            results
+        };
+
+        for (tx, events) in transactions.into_iter().zip(results) {
+            let Some(events) = events else {
+                continue;
+            };
             processed_nonces.insert(tx.public.clone(), tx.nonce.saturating_add(1));
-            outputs.extend(self.apply(&tx).await?.into_iter().map(Output::Event));
+            outputs.extend(events.into_iter().map(Output::Event));
             outputs.push(Output::Transaction(tx));
         }
+        self.credit_transaction_fees().await?;
 
         Ok((outputs, processed_nonces))
     }
@@ -667,12 +1004,12 @@ impl<'a, S: State> State for Layer<'a, S> {
     }
 
     async fn insert(&mut self, key: Key, value: Value) -> Result<()> {
-        self.pending.insert(key, Status::Update(value));
+        self.insert(key, value);
         Ok(())
     }
 
     async fn delete(&mut self, key: Key) -> Result<()> {
-        self.pending.insert(key, Status::Delete);
+        self.remove(key);
         Ok(())
     }
 }
@@ -682,8 +1019,7 @@ mod tests {
     use crate::mocks::{create_account_keypair, create_network_keypair, create_seed};
     use commonware_runtime::deterministic::Runner;
     use commonware_runtime::Runner as _;
-    use commonware_utils::hex;
-    use nullspace_types::casino::{GameType, TournamentPhase};
+    use nullspace_types::casino::{AdminRegistry, GameType, TournamentPhase};
 
     const TEST_NAMESPACE: &[u8] = b"test-namespace";
 
@@ -715,6 +1051,53 @@ mod tests {
         }
     }
 
+    fn apply_changes(state: &mut MockState, changes: Vec<(Key, Status)>) {
+        for (key, status) in changes {
+            match status {
+                Status::Update(value) => {
+                    state.data.insert(key, value);
+                }
+                Status::Delete => {
+                    state.data.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Genesis config granting `admin` every admin role.
+    fn admin_genesis(admin: &PublicKey) -> GenesisConfig {
+        GenesisConfig {
+            admin_public_keys: vec![admin.clone()],
+        }
+    }
+
+    /// Persist the registry that `admin_genesis(admin)` bootstraps.
+    fn grant_genesis_admin(state: &mut MockState, admin: &PublicKey) {
+        state.data.insert(
+            Key::AdminRegistry,
+            Value::AdminRegistry(AdminRegistry::with_full_access([admin.clone()])),
+        );
+    }
+
+    async fn run_view(
+        state: &mut MockState,
+        network_secret: &commonware_cryptography::bls12381::primitives::group::Private,
+        master_public: <MinSig as Variant>::Public,
+        view: u64,
+        txs: Vec<Transaction>,
+    ) -> Vec<Event> {
+        let seed = create_seed(network_secret, view);
+        let mut layer = Layer::new(&*state, master_public, TEST_NAMESPACE, seed);
+        let mut events = Vec::new();
+        for tx in txs {
+            assert!(layer.prepare(&tx).await.is_ok());
+            events.extend(layer.apply(&tx).await.unwrap());
+        }
+        let changes = layer.commit();
+        apply_changes(state, changes);
+        events
+    }
+
     #[test]
     fn test_nonce_validation() {
         let executor = Runner::default();
@@ -751,7 +1134,7 @@ mod tests {
     }
 
     #[test]
-    fn test_casino_register() {
+    fn test_transaction_fee() {
         let executor = Runner::default();
         executor.start(|_| async move {
             let state = MockState::new();
@@ -760,48 +1143,246 @@ mod tests {
             let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
 
             let (signer, public) = create_account_keypair(1);
+            let register = Instruction::CasinoRegister {
+                name: "Alice".to_string(),
+            };
 
-            // Register player
+            // An unregistered account has nothing to pay the fee with
+            let tx = Transaction::sign_with_fee(&signer, 0, register.clone(), 10);
+            assert!(matches!(
+                layer.prepare(&tx).await,
+                Err(PrepareError::InsufficientFee {
+                    required: 10,
+                    available: 0
+                })
+            ));
+
+            // The rejected fee did not consume the nonce
+            let tx = Transaction::sign(&signer, 0, register.clone());
+            assert!(matches!(layer.prepare(&tx).await, Ok(None)));
+            layer.apply(&tx).await.unwrap();
+
+            let tx = Transaction::sign_with_fee(&signer, 1, register.clone(), 10);
+            let Ok(Some(Event::TransactionFeePaid {
+                fee,
+                burned,
+                player_balances,
+                ..
+            })) = layer.prepare(&tx).await
+            else {
+                panic!("Expected TransactionFeePaid event");
+            };
+            assert_eq!(fee, 10);
+            assert_eq!(burned, 5);
+            assert_eq!(player_balances.chips, 990);
+
+            // The house share is credited once the block is done.
+            assert_eq!(layer.get(Key::House).await.unwrap(), None);
+            layer.credit_transaction_fees().await.unwrap();
+            let house = layer.get_or_init_house().await.unwrap();
+            assert_eq!(house.total_burned, 5);
+            assert_eq!(house.accumulated_fees, 5);
+
+            // A fee larger than the balance is rejected
+            let tx = Transaction::sign_with_fee(&signer, 2, register.clone(), 5_000);
+            assert!(matches!(
+                layer.prepare(&tx).await,
+                Err(PrepareError::InsufficientFee {
+                    required: 5_000,
+                    available: 990
+                })
+            ));
+            if let Some(Value::CasinoPlayer(player)) =
+                layer.get(Key::CasinoPlayer(public)).await.unwrap()
+            {
+                assert_eq!(player.balances.chips, 990);
+            } else {
+                panic!("Player not found");
+            }
+
+            // Policy can send the whole fee to the house
+            let policy = nullspace_types::casino::PolicyState {
+                transaction_fee_burn_bps: 0,
+                ..Default::default()
+            };
+            layer.insert(Key::Policy, Value::Policy(policy));
+            let tx = Transaction::sign_with_fee(&signer, 2, register, 10);
+            let Ok(Some(Event::TransactionFeePaid { burned, .. })) = layer.prepare(&tx).await
+            else {
+                panic!("Expected TransactionFeePaid event");
+            };
+            assert_eq!(burned, 0);
+            layer.credit_transaction_fees().await.unwrap();
+            let house = layer.get_or_init_house().await.unwrap();
+            assert_eq!(house.total_burned, 5);
+            assert_eq!(house.accumulated_fees, 15);
+
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_transaction_expiry() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 10);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+
+            let (signer, _) = create_account_keypair(1);
+            let register = Instruction::CasinoRegister {
+                name: "Alice".to_string(),
+            };
+
+            // Expired before this view: rejected without consuming the nonce
+            let tx = Transaction::sign_with_extensions(&signer, 0, register.clone(), 0, Some(9));
+            assert!(matches!(
+                layer.prepare(&tx).await,
+                Err(PrepareError::Expired {
+                    expires_at_view: 9,
+                    view: 10
+                })
+            ));
+
+            // Still valid at its last view
+            let tx = Transaction::sign_with_extensions(&signer, 0, register, 0, Some(10));
+            assert!(layer.prepare(&tx).await.is_ok());
+
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_completed_session_retention() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (signer, public) = create_account_keypair(1);
+            let session = |id: u64, is_complete: bool| nullspace_types::casino::GameSession {
+                id,
+                player: public.clone(),
+                game_type: GameType::Blackjack,
+                bet: 10,
+                state_blob: vec![],
+                move_count: 1,
+                created_at: 1,
+                is_complete,
+                super_mode: Default::default(),
+                is_tournament: false,
+                tournament_id: None,
+            };
+
+            // Session 1 completes at view 1; session 2 completed before retention existed.
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            layer.insert(
+                Key::CasinoSession(1),
+                Value::CasinoSession(session(1, true)),
+            );
+            layer.schedule_session_gc(1).await.unwrap();
+            layer.insert(
+                Key::CasinoSession(2),
+                Value::CasinoSession(session(2, true)),
+            );
+            layer.insert(
+                Key::CasinoSession(3),
+                Value::CasinoSession(session(3, false)),
+            );
+            let changes = layer.commit();
+            apply_changes(&mut state, changes);
+
+            // Still inside the retention window.
+            let retention = nullspace_types::casino::DEFAULT_CASINO_SESSION_RETENTION_VIEWS;
+            let seed = create_seed(&network_secret, retention);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            layer.collect_casino_sessions().await.unwrap();
+            assert!(layer.get(Key::CasinoSession(1)).await.unwrap().is_some());
+
+            // One view later the sweep deletes it along with the view's list.
+            let seed = create_seed(&network_secret, retention + 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            layer.collect_casino_sessions().await.unwrap();
+            assert!(layer.get(Key::CasinoSession(1)).await.unwrap().is_none());
+            assert!(layer
+                .get(Key::CompletedCasinoSessions(1))
+                .await
+                .unwrap()
+                .is_none());
+            assert!(matches!(
+                layer.get(Key::CasinoSessionGc).await.unwrap(),
+                Some(Value::CasinoSessionGc(gc)) if gc.next_view == 2
+            ));
+
+            // Sessions that were never queued are pruned explicitly; active ones are kept.
             let tx = Transaction::sign(
                 &signer,
                 0,
-                Instruction::CasinoRegister {
-                    name: "Alice".to_string(),
+                Instruction::PruneCasinoSessions {
+                    session_ids: vec![2, 3],
                 },
             );
             assert!(layer.prepare(&tx).await.is_ok());
             let events = layer.apply(&tx).await.unwrap();
+            assert!(matches!(
+                events.as_slice(),
+                [Event::CasinoSessionsPruned { session_ids, .. }] if session_ids == &vec![2]
+            ));
+            assert!(layer.get(Key::CasinoSession(2)).await.unwrap().is_none());
+            assert!(layer.get(Key::CasinoSession(3)).await.unwrap().is_some());
+        });
+    }
 
-            assert_eq!(events.len(), 2);
-            if let Event::CasinoPlayerRegistered { player, name } = &events[0] {
-                assert_eq!(player, &public);
-                assert_eq!(name, "Alice");
-            } else {
-                panic!("Expected CasinoPlayerRegistered event");
-            }
-            if let Event::CasinoLeaderboardUpdated { leaderboard } = &events[1] {
-                assert_eq!(leaderboard.entries.len(), 1);
-                assert_eq!(leaderboard.entries[0].name, "Alice");
-            } else {
-                panic!("Expected CasinoLeaderboardUpdated event");
-            }
+    #[test]
+    fn test_completed_session_retention_follows_policy() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (_, public) = create_account_keypair(1);
 
-            // Verify player was created
-            if let Some(Value::CasinoPlayer(player)) =
-                layer.get(Key::CasinoPlayer(public)).await.unwrap()
-            {
-                assert_eq!(player.profile.name, "Alice");
-                assert_eq!(player.balances.chips, 1000); // Initial chips
-            } else {
-                panic!("Player not found");
-            }
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            let policy = nullspace_types::casino::PolicyState {
+                casino_session_retention_views: 5,
+                ..Default::default()
+            };
+            layer.insert(Key::Policy, Value::Policy(policy));
+            layer.insert(
+                Key::CasinoSession(1),
+                Value::CasinoSession(nullspace_types::casino::GameSession {
+                    id: 1,
+                    player: public,
+                    game_type: GameType::Blackjack,
+                    bet: 10,
+                    state_blob: vec![],
+                    move_count: 1,
+                    created_at: 1,
+                    is_complete: true,
+                    super_mode: Default::default(),
+                    is_tournament: false,
+                    tournament_id: None,
+                }),
+            );
+            layer.schedule_session_gc(1).await.unwrap();
+            let changes = layer.commit();
+            apply_changes(&mut state, changes);
 
-            let _ = layer.commit();
+            let seed = create_seed(&network_secret, 5);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            layer.collect_casino_sessions().await.unwrap();
+            assert!(layer.get(Key::CasinoSession(1)).await.unwrap().is_some());
+
+            let seed = create_seed(&network_secret, 6);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            layer.collect_casino_sessions().await.unwrap();
+            assert!(layer.get(Key::CasinoSession(1)).await.unwrap().is_none());
         });
     }
 
     #[test]
-    fn test_tournament_join_start_end_flow() {
+    fn test_completed_session_list_spills_into_next_view() {
         let executor = Runner::default();
         executor.start(|_| async move {
             let state = MockState::new();
@@ -809,9 +1390,39 @@ mod tests {
             let seed = create_seed(&network_secret, 1);
             let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
 
+            let full = nullspace_types::execution::MAX_BLOCK_TRANSACTIONS as u64;
+            for session_id in 0..=full {
+                layer.schedule_session_gc(session_id).await.unwrap();
+            }
+            assert!(matches!(
+                layer.get(Key::CompletedCasinoSessions(1)).await.unwrap(),
+                Some(Value::CompletedCasinoSessions(ids)) if ids.len() as u64 == full
+            ));
+            assert!(matches!(
+                layer.get(Key::CompletedCasinoSessions(2)).await.unwrap(),
+                Some(Value::CompletedCasinoSessions(ids)) if ids == vec![full]
+            ));
+        });
+    }
+
+    #[test]
+    fn test_start_game_releases_previous_session() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
             let (signer, public) = create_account_keypair(1);
-            let (admin_signer, admin_public) = create_account_keypair(999);
-            std::env::set_var("CASINO_ADMIN_PUBLIC_KEY_HEX", hex(admin_public.as_ref()));
+            let start = |nonce: u64, session_id: u64| {
+                Transaction::sign(
+                    &signer,
+                    nonce,
+                    Instruction::CasinoStartGame {
+                        game_type: GameType::Blackjack,
+                        bet: 10,
+                        session_id,
+                    },
+                )
+            };
 
             let register = Transaction::sign(
                 &signer,
@@ -820,16 +1431,295 @@ mod tests {
                     name: "Alice".to_string(),
                 },
             );
-            assert!(layer.prepare(&register).await.is_ok());
-            let _ = layer.apply(&register).await.unwrap();
-
-            let tournament_id = 42;
-            let join = Transaction::sign(
-                &signer,
+            let _ = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
                 1,
-                Instruction::CasinoJoinTournament { tournament_id },
-            );
-            assert!(layer.prepare(&join).await.is_ok());
+                vec![register, start(1, 1)],
+            )
+            .await;
+
+            // Finish session 1, then start another.
+            let Some(Value::CasinoSession(mut session)) =
+                state.data.get(&Key::CasinoSession(1)).cloned()
+            else {
+                panic!("session not found");
+            };
+            session.is_complete = true;
+            state
+                .data
+                .insert(Key::CasinoSession(1), Value::CasinoSession(session));
+            let _ = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![start(2, 2)],
+            )
+            .await;
+
+            assert!(!state.data.contains_key(&Key::CasinoSession(1)));
+            assert!(state.data.contains_key(&Key::CasinoSession(2)));
+            let Some(Value::CasinoPlayer(player)) = state.data.get(&Key::CasinoPlayer(public))
+            else {
+                panic!("player not found");
+            };
+            assert_eq!(player.session.last_session, Some(2));
+        });
+    }
+
+    #[test]
+    fn test_inactive_session_is_abandoned() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let expired = 1 + nullspace_types::casino::SESSION_EXPIRY;
+            let last_move_expired = 50 + nullspace_types::casino::SESSION_EXPIRY;
+
+            // Mirrors `Layer::execute`: the expiry sweep runs before the block's transactions.
+            async fn run_block(
+                state: &mut MockState,
+                network_secret: &commonware_cryptography::bls12381::primitives::group::Private,
+                master_public: <MinSig as Variant>::Public,
+                view: u64,
+                txs: Vec<Transaction>,
+            ) -> Vec<Event> {
+                let seed = create_seed(network_secret, view);
+                let mut layer = Layer::new(&*state, master_public, TEST_NAMESPACE, seed);
+                let mut events = layer.expire_casino_sessions().await.unwrap();
+                for tx in txs {
+                    assert!(layer.prepare(&tx).await.is_ok());
+                    events.extend(layer.apply(&tx).await.unwrap());
+                }
+                let changes = layer.commit();
+                apply_changes(state, changes);
+                events
+            }
+            let completed = |events: &[Event], id: u64| {
+                events.iter().any(|event| {
+                    matches!(
+                        event,
+                        Event::CasinoGameCompleted { session_id, .. } if *session_id == id
+                    )
+                })
+            };
+
+            // Alice never acts on her hand; Bob deals his at view 50.
+            let mut txs = Vec::new();
+            for (seed, session_id) in [(1, 1), (2, 2)] {
+                let (signer, _) = create_account_keypair(seed);
+                txs.push(Transaction::sign(
+                    &signer,
+                    0,
+                    Instruction::CasinoRegister {
+                        name: format!("Player{seed}"),
+                    },
+                ));
+                txs.push(Transaction::sign(
+                    &signer,
+                    1,
+                    Instruction::CasinoStartGame {
+                        game_type: GameType::ThreeCard,
+                        bet: 10,
+                        session_id,
+                    },
+                ));
+            }
+            let _ = run_block(&mut state, &network_secret, master_public, 1, txs).await;
+            let (bob, _) = create_account_keypair(2);
+            let deal = Transaction::sign(
+                &bob,
+                2,
+                Instruction::CasinoGameMove {
+                    session_id: 2,
+                    payload: vec![2],
+                },
+            );
+            let _ = run_block(&mut state, &network_secret, master_public, 50, vec![deal]).await;
+
+            // Alice's undealt hand is refunded; Bob's window runs from his last move.
+            let events =
+                run_block(&mut state, &network_secret, master_public, expired, vec![]).await;
+            assert!(completed(&events, 1));
+            assert!(!completed(&events, 2));
+            let (_, alice) = create_account_keypair(1);
+            let Some(Value::CasinoPlayer(player)) = state.data.get(&Key::CasinoPlayer(alice))
+            else {
+                panic!("player not found");
+            };
+            assert_eq!(player.balances.chips, 1000);
+            assert!(matches!(
+                state.data.get(&Key::CasinoSession(2)),
+                Some(Value::CasinoSession(session)) if !session.is_complete
+            ));
+
+            // Bob's hand is folded exactly `SESSION_EXPIRY` views after his move, not a view sooner.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                last_move_expired - 1,
+                vec![],
+            )
+            .await;
+            assert!(!completed(&events, 2));
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                last_move_expired,
+                vec![],
+            )
+            .await;
+            assert!(completed(&events, 2));
+            assert!(matches!(
+                state.data.get(&Key::CasinoSession(2)),
+                Some(Value::CasinoSession(session)) if session.is_complete
+            ));
+        });
+    }
+
+    #[test]
+    fn test_unresolvable_session_is_refunded_on_expiry() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (signer, public) = create_account_keypair(1);
+
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            for (nonce, instruction) in [
+                Instruction::CasinoRegister {
+                    name: "Alice".to_string(),
+                },
+                Instruction::CasinoStartGame {
+                    game_type: GameType::ThreeCard,
+                    bet: 10,
+                    session_id: 1,
+                },
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let tx = Transaction::sign(&signer, nonce as u64, instruction);
+                assert!(layer.prepare(&tx).await.is_ok());
+                layer.apply(&tx).await.unwrap();
+            }
+            let changes = layer.commit();
+            apply_changes(&mut state, changes);
+
+            // A state blob the game can no longer parse cannot be abandoned normally.
+            let Some(Value::CasinoSession(mut session)) =
+                state.data.get(&Key::CasinoSession(1)).cloned()
+            else {
+                panic!("session not found");
+            };
+            session.state_blob = vec![];
+            state
+                .data
+                .insert(Key::CasinoSession(1), Value::CasinoSession(session));
+
+            let seed = create_seed(&network_secret, 1 + nullspace_types::casino::SESSION_EXPIRY);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            let events = layer.expire_casino_sessions().await.unwrap();
+            assert!(events
+                .iter()
+                .any(|event| matches!(event, Event::CasinoGameCompleted { session_id: 1, .. })));
+            let Some(Value::CasinoPlayer(player)) =
+                layer.get(Key::CasinoPlayer(public)).await.unwrap()
+            else {
+                panic!("player not found");
+            };
+            assert_eq!(player.balances.chips, 1000);
+        });
+    }
+
+    #[test]
+    fn test_casino_register() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+
+            let (signer, public) = create_account_keypair(1);
+
+            // Register player
+            let tx = Transaction::sign(
+                &signer,
+                0,
+                Instruction::CasinoRegister {
+                    name: "Alice".to_string(),
+                },
+            );
+            assert!(layer.prepare(&tx).await.is_ok());
+            let events = layer.apply(&tx).await.unwrap();
+
+            assert_eq!(events.len(), 2);
+            if let Event::CasinoPlayerRegistered { player, name } = &events[0] {
+                assert_eq!(player, &public);
+                assert_eq!(name, "Alice");
+            } else {
+                panic!("Expected CasinoPlayerRegistered event");
+            }
+            if let Event::CasinoLeaderboardUpdated { leaderboard } = &events[1] {
+                assert_eq!(leaderboard.entries.len(), 1);
+                assert_eq!(leaderboard.entries[0].name, "Alice");
+            } else {
+                panic!("Expected CasinoLeaderboardUpdated event");
+            }
+
+            // Verify player was created
+            if let Some(Value::CasinoPlayer(player)) =
+                layer.get(Key::CasinoPlayer(public)).await.unwrap()
+            {
+                assert_eq!(player.profile.name, "Alice");
+                assert_eq!(player.balances.chips, 1000); // Initial chips
+            } else {
+                panic!("Player not found");
+            }
+
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_tournament_join_start_end_flow() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+
+            let (signer, public) = create_account_keypair(1);
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            layer
+                .bootstrap_admin_registry(&admin_genesis(&admin_public))
+                .await
+                .unwrap();
+
+            let register = Transaction::sign(
+                &signer,
+                0,
+                Instruction::CasinoRegister {
+                    name: "Alice".to_string(),
+                },
+            );
+            assert!(layer.prepare(&register).await.is_ok());
+            let _ = layer.apply(&register).await.unwrap();
+
+            let tournament_id = 42;
+            let join = Transaction::sign(
+                &signer,
+                1,
+                Instruction::CasinoJoinTournament { tournament_id },
+            );
+            assert!(layer.prepare(&join).await.is_ok());
             let events = layer.apply(&join).await.unwrap();
             assert!(matches!(
                 events.get(0),
@@ -846,6 +1736,7 @@ mod tests {
                     tournament_id,
                     start_time_ms,
                     end_time_ms: start_time_ms + expected_duration_ms,
+                    payout_structure: Default::default(),
                 },
             );
             assert!(layer.prepare(&start).await.is_ok());
@@ -865,6 +1756,7 @@ mod tests {
                     tournament_id,
                     start_time_ms,
                     end_time_ms: start_time_ms + expected_duration_ms,
+                    payout_structure: Default::default(),
                 },
             );
             assert!(layer.prepare(&start).await.is_ok());
@@ -929,7 +1821,7 @@ mod tests {
     }
 
     #[test]
-    fn test_game_start_persists_session() {
+    fn test_paid_tournament_guarantee_rake_and_cancel() {
         let executor = Runner::default();
         executor.start(|_| async move {
             let state = MockState::new();
@@ -937,41 +1829,202 @@ mod tests {
             let seed = create_seed(&network_secret, 1);
             let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
 
-            let (signer, public) = create_account_keypair(1);
-            let register = Transaction::sign(
-                &signer,
+            let (alice_signer, alice) = create_account_keypair(1);
+            let (bob_signer, bob) = create_account_keypair(2);
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            layer
+                .bootstrap_admin_registry(&admin_genesis(&admin_public))
+                .await
+                .unwrap();
+
+            for (signer, name) in [(&alice_signer, "Alice"), (&bob_signer, "Bob")] {
+                let register = Transaction::sign(
+                    signer,
+                    0,
+                    Instruction::CasinoRegister {
+                        name: name.to_string(),
+                    },
+                );
+                assert!(layer.prepare(&register).await.is_ok());
+                let _ = layer.apply(&register).await.unwrap();
+            }
+            let starting_balance = match layer.get(Key::CasinoPlayer(alice.clone())).await.unwrap()
+            {
+                Some(Value::CasinoPlayer(player)) => player.balances.chips,
+                _ => panic!("Player not found"),
+            };
+            let house_before = layer.get_or_init_house().await.unwrap();
+
+            let tournament_id = 7;
+            let config = nullspace_types::casino::TournamentConfig {
+                currency: nullspace_types::casino::TournamentCurrency::Chips,
+                buy_in: 100,
+                rake_bps: 1_000,
+                guarantee: 500,
+                ..Default::default()
+            };
+            let create = Transaction::sign(
+                &alice_signer,
+                1,
+                Instruction::CasinoCreateTournament {
+                    tournament_id,
+                    config: config.clone(),
+                },
+            );
+            assert!(layer.prepare(&create).await.is_ok());
+            let events = layer.apply(&create).await.unwrap();
+            assert!(
+                !events
+                    .iter()
+                    .any(|event| matches!(event, Event::TournamentCreated { .. })),
+                "non-admin should not create tournaments"
+            );
+
+            let create = Transaction::sign(
+                &admin_signer,
                 0,
-                Instruction::CasinoRegister {
-                    name: "Alice".to_string(),
+                Instruction::CasinoCreateTournament {
+                    tournament_id,
+                    config: config.clone(),
                 },
             );
-            assert!(layer.prepare(&register).await.is_ok());
-            let _ = layer.apply(&register).await.unwrap();
+            assert!(layer.prepare(&create).await.is_ok());
+            let events = layer.apply(&create).await.unwrap();
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::TournamentCreated { id, .. } if *id == tournament_id
+            )));
 
-            let session_id = 42;
+            for (signer, nonce) in [(&alice_signer, 2), (&bob_signer, 1)] {
+                let join = Transaction::sign(
+                    signer,
+                    nonce,
+                    Instruction::CasinoJoinTournament { tournament_id },
+                );
+                assert!(layer.prepare(&join).await.is_ok());
+                let events = layer.apply(&join).await.unwrap();
+                assert!(events.iter().any(|event| matches!(
+                    event,
+                    Event::TournamentBuyIn {
+                        amount: 100,
+                        rake: 10,
+                        ..
+                    }
+                )));
+            }
+
+            let start_time_ms = 1_700_000_000_000;
             let start = Transaction::sign(
-                &signer,
+                &admin_signer,
                 1,
-                Instruction::CasinoStartGame {
-                    game_type: GameType::Blackjack,
-                    bet: 10,
-                    session_id,
+                Instruction::CasinoStartTournament {
+                    tournament_id,
+                    start_time_ms,
+                    end_time_ms: start_time_ms + 3_600_000,
+                    payout_structure: Default::default(),
                 },
             );
             assert!(layer.prepare(&start).await.is_ok());
-            let events = layer.apply(&start).await.unwrap();
+            let _ = layer.apply(&start).await.unwrap();
+
+            match layer.get(Key::Tournament(tournament_id)).await.unwrap() {
+                Some(Value::Tournament(tournament)) => {
+                    assert!(matches!(tournament.phase, TournamentPhase::Active));
+                    // 2 x (100 - 10 rake) collected, topped up to the 500 guarantee.
+                    assert_eq!(tournament.prize_pool, 500);
+                    assert_eq!(tournament.rake_collected, 20);
+                    assert_eq!(tournament.guarantee_overlay, 320);
+                    assert_eq!(tournament.end_time_ms, start_time_ms + 3_600_000);
+                }
+                _ => panic!("Tournament not found"),
+            }
+
+            // Alice finishes with the bigger stack and takes the whole pool.
+            if let Some(Value::CasinoPlayer(mut player)) =
+                layer.get(Key::CasinoPlayer(alice.clone())).await.unwrap()
+            {
+                player.tournament.chips *= 2;
+                layer.insert(
+                    Key::CasinoPlayer(alice.clone()),
+                    Value::CasinoPlayer(player),
+                );
+            }
+
+            let end = Transaction::sign(
+                &admin_signer,
+                2,
+                Instruction::CasinoEndTournament { tournament_id },
+            );
+            assert!(layer.prepare(&end).await.is_ok());
+            let events = layer.apply(&end).await.unwrap();
             assert!(events.iter().any(|event| matches!(
                 event,
-                Event::CasinoGameStarted { session_id: id, .. } if *id == session_id
+                Event::TournamentPrizesPaid { id, payouts, .. }
+                    if *id == tournament_id && payouts == &vec![(alice.clone(), 500)]
             )));
 
-            if let Some(Value::CasinoSession(session)) =
-                layer.get(Key::CasinoSession(session_id)).await.unwrap()
-            {
-                assert_eq!(session.id, session_id);
-                assert_eq!(session.player, public);
-            } else {
-                panic!("Session not found");
+            match layer.get(Key::CasinoPlayer(alice.clone())).await.unwrap() {
+                Some(Value::CasinoPlayer(player)) => {
+                    assert_eq!(player.balances.chips, starting_balance - 100 + 500);
+                    assert_eq!(player.tournament.active_tournament, None);
+                }
+                _ => panic!("Player not found"),
+            }
+            let house = layer.get_or_init_house().await.unwrap();
+            assert_eq!(house.net_pnl, house_before.net_pnl + 20);
+            assert_eq!(house.total_issuance, house_before.total_issuance + 320);
+
+            // A cancelled event refunds every buy-in in full.
+            let cancelled_id = 8;
+            let create = Transaction::sign(
+                &admin_signer,
+                3,
+                Instruction::CasinoCreateTournament {
+                    tournament_id: cancelled_id,
+                    config,
+                },
+            );
+            assert!(layer.prepare(&create).await.is_ok());
+            let _ = layer.apply(&create).await.unwrap();
+            let join = Transaction::sign(
+                &bob_signer,
+                2,
+                Instruction::CasinoJoinTournament {
+                    tournament_id: cancelled_id,
+                },
+            );
+            assert!(layer.prepare(&join).await.is_ok());
+            let _ = layer.apply(&join).await.unwrap();
+
+            let cancel = Transaction::sign(
+                &admin_signer,
+                4,
+                Instruction::CasinoCancelTournament {
+                    tournament_id: cancelled_id,
+                },
+            );
+            assert!(layer.prepare(&cancel).await.is_ok());
+            let events = layer.apply(&cancel).await.unwrap();
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::TournamentCancelled { id, refunds }
+                    if *id == cancelled_id && refunds == &vec![(bob.clone(), 100)]
+            )));
+
+            match layer.get(Key::CasinoPlayer(bob)).await.unwrap() {
+                Some(Value::CasinoPlayer(player)) => {
+                    // Only the completed event's buy-in is gone.
+                    assert_eq!(player.balances.chips, starting_balance - 100);
+                    assert_eq!(player.tournament.active_tournament, None);
+                }
+                _ => panic!("Player not found"),
+            }
+            match layer.get(Key::Tournament(cancelled_id)).await.unwrap() {
+                Some(Value::Tournament(tournament)) => {
+                    assert!(matches!(tournament.phase, TournamentPhase::Cancelled));
+                    assert_eq!(tournament.prize_pool, 0);
+                }
+                _ => panic!("Tournament not found"),
             }
 
             let _ = layer.commit();
@@ -979,80 +2032,2021 @@ mod tests {
     }
 
     #[test]
-    fn test_layer_execute_is_deterministic_for_identical_inputs() {
+    fn test_tournament_payout_structure_splits_ties() {
         let executor = Runner::default();
         executor.start(|_| async move {
-            let state1 = MockState::new();
-            let state2 = MockState::new();
-
+            let state = MockState::new();
             let (network_secret, master_public) = create_network_keypair();
             let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
 
-            let (signer, _public) = create_account_keypair(1);
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            layer
+                .bootstrap_admin_registry(&admin_genesis(&admin_public))
+                .await
+                .unwrap();
 
-            let txs = vec![
-                Transaction::sign(
+            let tournament_id = 11;
+            let create = Transaction::sign(
+                &admin_signer,
+                0,
+                Instruction::CasinoCreateTournament {
+                    tournament_id,
+                    config: nullspace_types::casino::TournamentConfig {
+                        buy_in: 100,
+                        ..Default::default()
+                    },
+                },
+            );
+            assert!(layer.prepare(&create).await.is_ok());
+            let _ = layer.apply(&create).await.unwrap();
+
+            let mut players = Vec::new();
+            for (seed, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol")] {
+                let (signer, public) = create_account_keypair(seed);
+                let register = Transaction::sign(
                     &signer,
                     0,
                     Instruction::CasinoRegister {
-                        name: "Alice".to_string(),
+                        name: name.to_string(),
                     },
-                ),
-                Transaction::sign(
+                );
+                assert!(layer.prepare(&register).await.is_ok());
+                let _ = layer.apply(&register).await.unwrap();
+                let join = Transaction::sign(
                     &signer,
                     1,
-                    Instruction::CasinoStartGame {
-                        game_type: GameType::Roulette,
-                        bet: 100,
-                        session_id: 1,
+                    Instruction::CasinoJoinTournament { tournament_id },
+                );
+                assert!(layer.prepare(&join).await.is_ok());
+                let _ = layer.apply(&join).await.unwrap();
+                players.push(public);
+            }
+
+            let start_time_ms = 1_700_000_000_000;
+            let start = Transaction::sign(
+                &admin_signer,
+                1,
+                Instruction::CasinoStartTournament {
+                    tournament_id,
+                    start_time_ms,
+                    end_time_ms: start_time_ms + 600_000,
+                    payout_structure: nullspace_types::casino::PayoutStructure {
+                        shares_bps: vec![5_000, 3_000, 1_000],
+                    },
+                },
+            );
+            assert!(layer.prepare(&start).await.is_ok());
+            let events = layer.apply(&start).await.unwrap();
+            assert!(
+                !events
+                    .iter()
+                    .any(|event| matches!(event, Event::TournamentStarted { .. })),
+                "payout shares must sum to 100%"
+            );
+
+            let start = Transaction::sign(
+                &admin_signer,
+                2,
+                Instruction::CasinoStartTournament {
+                    tournament_id,
+                    start_time_ms,
+                    end_time_ms: start_time_ms + 600_000,
+                    payout_structure: nullspace_types::casino::PayoutStructure::top_three(),
+                },
+            );
+            assert!(layer.prepare(&start).await.is_ok());
+            let _ = layer.apply(&start).await.unwrap();
+
+            // Carol wins outright; Alice and Bob tie for second and split 2nd + 3rd.
+            let winner = players[2].clone();
+            if let Some(Value::CasinoPlayer(mut player)) =
+                layer.get(Key::CasinoPlayer(winner.clone())).await.unwrap()
+            {
+                player.tournament.chips *= 2;
+                layer.insert(Key::CasinoPlayer(winner), Value::CasinoPlayer(player));
+            }
+
+            let end = Transaction::sign(
+                &admin_signer,
+                3,
+                Instruction::CasinoEndTournament { tournament_id },
+            );
+            assert!(layer.prepare(&end).await.is_ok());
+            let events = layer.apply(&end).await.unwrap();
+            let payouts = events
+                .iter()
+                .find_map(|event| match event {
+                    Event::TournamentPrizesPaid { payouts, .. } => Some(payouts.clone()),
+                    _ => None,
+                })
+                .expect("prizes paid");
+            let mut tied = [players[0].clone(), players[1].clone()];
+            tied.sort();
+            assert_eq!(
+                payouts,
+                vec![
+                    (players[2].clone(), 150),
+                    (tied[0].clone(), 75),
+                    (tied[1].clone(), 75),
+                ]
+            );
+
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_sit_and_go_starts_when_full_and_ends_on_chain_time() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            layer
+                .bootstrap_admin_registry(&admin_genesis(&admin_public))
+                .await
+                .unwrap();
+
+            let tournament_id = 12;
+            let duration_secs = 30;
+            let payout_structure = nullspace_types::casino::PayoutStructure::winner_take_all();
+            let create = Transaction::sign(
+                &admin_signer,
+                0,
+                Instruction::CasinoCreateTournament {
+                    tournament_id,
+                    config: nullspace_types::casino::TournamentConfig {
+                        buy_in: 100,
+                        max_seats: 2,
+                        duration_secs,
+                        payout_structure: payout_structure.clone(),
+                        ..Default::default()
+                    },
+                },
+            );
+            assert!(layer.prepare(&create).await.is_ok());
+            let _ = layer.apply(&create).await.unwrap();
+
+            let mut players = Vec::new();
+            for (seed, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol")] {
+                let (signer, public) = create_account_keypair(seed);
+                let register = Transaction::sign(
+                    &signer,
+                    0,
+                    Instruction::CasinoRegister {
+                        name: name.to_string(),
+                    },
+                );
+                assert!(layer.prepare(&register).await.is_ok());
+                let _ = layer.apply(&register).await.unwrap();
+                let join = Transaction::sign(
+                    &signer,
+                    1,
+                    Instruction::CasinoJoinTournament { tournament_id },
+                );
+                assert!(layer.prepare(&join).await.is_ok());
+                let events = layer.apply(&join).await.unwrap();
+                let started = events
+                    .iter()
+                    .any(|event| matches!(event, Event::TournamentStarted { .. }));
+                // Only the player taking the last seat starts the event; the table is then
+                // closed to latecomers.
+                assert_eq!(started, players.len() == 1, "join {name}");
+                players.push(public);
+            }
+            let Some(Value::Tournament(tournament)) =
+                layer.get(Key::Tournament(tournament_id)).await.unwrap()
+            else {
+                panic!("tournament not found");
+            };
+            assert!(matches!(tournament.phase, TournamentPhase::Active));
+            // Entrants are kept sorted by key, not in join order.
+            let mut seated = players[..2].to_vec();
+            seated.sort();
+            assert_eq!(tournament.players, seated);
+            assert_eq!(tournament.prize_pool, 200);
+            assert_eq!(tournament.config.payout_structure, payout_structure);
+            assert_eq!(
+                tournament.end_time_ms - tournament.start_time_ms,
+                duration_secs * 1000
+            );
+
+            // Admins cannot start a sit-and-go by hand.
+            let start = Transaction::sign(
+                &admin_signer,
+                1,
+                Instruction::CasinoStartTournament {
+                    tournament_id,
+                    start_time_ms: 0,
+                    end_time_ms: 0,
+                    payout_structure: Default::default(),
+                },
+            );
+            assert!(layer.prepare(&start).await.is_ok());
+            let events = layer.apply(&start).await.unwrap();
+            assert!(matches!(events.as_slice(), [Event::CasinoError { .. }]));
+
+            // Views are three seconds apart.
+            let end_view = 1 + duration_secs / 3;
+            assert_eq!(
+                layer.get(Key::TournamentDeadlines).await.unwrap(),
+                Some(Value::TournamentDeadlines(vec![
+                    nullspace_types::casino::TournamentDeadline {
+                        end_view,
+                        tournament_id,
+                    }
+                ]))
+            );
+            let changes = layer.commit();
+            apply_changes(&mut state, changes);
+
+            // Nothing ends before the duration lapses.
+            let seed = create_seed(&network_secret, end_view - 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            assert!(layer.end_due_tournaments().await.unwrap().is_empty());
+
+            let seed = create_seed(&network_secret, end_view);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            let events = layer.end_due_tournaments().await.unwrap();
+            assert!(events
+                .iter()
+                .any(|event| matches!(event, Event::TournamentPrizesPaid { .. })));
+            let Some(Value::Tournament(tournament)) =
+                layer.get(Key::Tournament(tournament_id)).await.unwrap()
+            else {
+                panic!("tournament not found");
+            };
+            assert!(matches!(tournament.phase, TournamentPhase::Complete));
+            assert!(layer.get(Key::TournamentDeadlines).await.unwrap().is_none());
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_tournament_rebuys_and_add_ons() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            layer
+                .bootstrap_admin_registry(&admin_genesis(&admin_public))
+                .await
+                .unwrap();
+
+            let tournament_id = 13;
+            let rule = nullspace_types::casino::TopUpRule {
+                max_per_player: 1,
+                window_secs: 60,
+                cost: 100,
+                chips: 1_000,
+                shields: 1,
+                doubles: 0,
+            };
+            let create = Transaction::sign(
+                &admin_signer,
+                0,
+                Instruction::CasinoCreateTournament {
+                    tournament_id,
+                    config: nullspace_types::casino::TournamentConfig {
+                        buy_in: 100,
+                        rebuy: rule.clone(),
+                        add_on: nullspace_types::casino::TopUpRule {
+                            cost: 50,
+                            chips: 500,
+                            shields: 0,
+                            ..rule
+                        },
+                        ..Default::default()
+                    },
+                },
+            );
+            assert!(layer.prepare(&create).await.is_ok());
+            let _ = layer.apply(&create).await.unwrap();
+
+            let mut players = Vec::new();
+            for (seed, name) in [(1, "Alice"), (2, "Bob")] {
+                let (signer, public) = create_account_keypair(seed);
+                let register = Transaction::sign(
+                    &signer,
+                    0,
+                    Instruction::CasinoRegister {
+                        name: name.to_string(),
+                    },
+                );
+                assert!(layer.prepare(&register).await.is_ok());
+                let _ = layer.apply(&register).await.unwrap();
+                let join = Transaction::sign(
+                    &signer,
+                    1,
+                    Instruction::CasinoJoinTournament { tournament_id },
+                );
+                assert!(layer.prepare(&join).await.is_ok());
+                let _ = layer.apply(&join).await.unwrap();
+                players.push((signer, public));
+            }
+            let start_time_ms = 1_700_000_000_000;
+            let start = Transaction::sign(
+                &admin_signer,
+                1,
+                Instruction::CasinoStartTournament {
+                    tournament_id,
+                    start_time_ms,
+                    end_time_ms: start_time_ms + 600_000,
+                    payout_structure: Default::default(),
+                },
+            );
+            assert!(layer.prepare(&start).await.is_ok());
+            let _ = layer.apply(&start).await.unwrap();
+
+            let (alice_signer, alice) = &players[0];
+            let (bob_signer, _) = &players[1];
+            let bust = |mut player: nullspace_types::casino::Player| {
+                player.tournament.chips = 0;
+                Value::CasinoPlayer(player)
+            };
+
+            // Only busted players may rebuy.
+            let rebuy = |nonce| {
+                Transaction::sign(
+                    alice_signer,
+                    nonce,
+                    Instruction::CasinoTournamentTopUp {
+                        tournament_id,
+                        kind: nullspace_types::casino::TopUpKind::Rebuy,
+                    },
+                )
+            };
+            let tx = rebuy(2);
+            assert!(layer.prepare(&tx).await.is_ok());
+            let events = layer.apply(&tx).await.unwrap();
+            assert!(matches!(events.as_slice(), [Event::CasinoError { .. }]));
+
+            let Some(Value::CasinoPlayer(player)) =
+                layer.get(Key::CasinoPlayer(alice.clone())).await.unwrap()
+            else {
+                panic!("player not found");
+            };
+            let shields = player.tournament.shields;
+
+            // Chips still riding on an open session don't count as busted.
+            let mut mid_hand = player.clone();
+            mid_hand.session.active_session = Some(1);
+            layer.insert(Key::CasinoPlayer(alice.clone()), bust(mid_hand));
+            let tx = rebuy(3);
+            assert!(layer.prepare(&tx).await.is_ok());
+            let events = layer.apply(&tx).await.unwrap();
+            assert!(matches!(events.as_slice(), [Event::CasinoError { .. }]));
+
+            layer.insert(Key::CasinoPlayer(alice.clone()), bust(player));
+            let tx = rebuy(4);
+            assert!(layer.prepare(&tx).await.is_ok());
+            let events = layer.apply(&tx).await.unwrap();
+            assert!(matches!(
+                events.as_slice(),
+                [Event::TournamentTopUp {
+                    cost: 100,
+                    prize_pool: 300,
+                    rebuys: 1,
+                    add_ons: 0,
+                    player_balances,
+                    ..
+                }] if player_balances.tournament_chips == 1_000
+                    && player_balances.tournament_shields == shields + 1
+            ));
+
+            // The per-player limit holds even after busting again.
+            let Some(Value::CasinoPlayer(player)) =
+                layer.get(Key::CasinoPlayer(alice.clone())).await.unwrap()
+            else {
+                panic!("player not found");
+            };
+            layer.insert(Key::CasinoPlayer(alice.clone()), bust(player));
+            let tx = rebuy(5);
+            assert!(layer.prepare(&tx).await.is_ok());
+            let events = layer.apply(&tx).await.unwrap();
+            assert!(matches!(
+                events.as_slice(),
+                [Event::CasinoError { error_code, .. }]
+                    if *error_code == nullspace_types::casino::ERROR_TOURNAMENT_LIMIT_REACHED
+            ));
+
+            let add_on = Transaction::sign(
+                bob_signer,
+                2,
+                Instruction::CasinoTournamentTopUp {
+                    tournament_id,
+                    kind: nullspace_types::casino::TopUpKind::AddOn,
+                },
+            );
+            assert!(layer.prepare(&add_on).await.is_ok());
+            let events = layer.apply(&add_on).await.unwrap();
+            assert!(matches!(
+                events.as_slice(),
+                [Event::TournamentTopUp {
+                    cost: 50,
+                    prize_pool: 350,
+                    rebuys: 0,
+                    add_ons: 1,
+                    ..
+                }]
+            ));
+            let changes = layer.commit();
+            apply_changes(&mut state, changes);
+
+            // Purchases stop once the window closes (views are three seconds apart).
+            let seed = create_seed(&network_secret, 1 + 60 / 3);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            let add_on = Transaction::sign(
+                alice_signer,
+                6,
+                Instruction::CasinoTournamentTopUp {
+                    tournament_id,
+                    kind: nullspace_types::casino::TopUpKind::AddOn,
+                },
+            );
+            assert!(layer.prepare(&add_on).await.is_ok());
+            let events = layer.apply(&add_on).await.unwrap();
+            assert!(matches!(
+                events.as_slice(),
+                [Event::CasinoError { message, .. }] if message.contains("window")
+            ));
+
+            // Cancelling refunds the buy-in plus every purchase.
+            let cancel = Transaction::sign(
+                &admin_signer,
+                2,
+                Instruction::CasinoCancelTournament { tournament_id },
+            );
+            assert!(layer.prepare(&cancel).await.is_ok());
+            let events = layer.apply(&cancel).await.unwrap();
+            let Some(Event::TournamentCancelled { refunds, .. }) = events.last() else {
+                panic!("tournament not cancelled");
+            };
+            assert!(refunds.contains(&(alice.clone(), 200)));
+            assert!(refunds.contains(&(players[1].1.clone(), 150)));
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_tournament_game_rules_and_starting_stack() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            layer
+                .bootstrap_admin_registry(&admin_genesis(&admin_public))
+                .await
+                .unwrap();
+
+            // A "Blackjack only" freeroll with a deeper stack and no modifiers.
+            let tournament_id = 14;
+            let create = Transaction::sign(
+                &admin_signer,
+                0,
+                Instruction::CasinoCreateTournament {
+                    tournament_id,
+                    config: nullspace_types::casino::TournamentConfig {
+                        allowed_games: vec![GameType::Blackjack],
+                        bet_limits: vec![nullspace_types::casino::TournamentBetLimit {
+                            game_type: GameType::Blackjack,
+                            min_bet: 10,
+                            max_bet: 50,
+                        }],
+                        starting_stack: nullspace_types::casino::TournamentStack {
+                            chips: 5_000,
+                            shields: 0,
+                            doubles: 0,
+                        },
+                        ..Default::default()
+                    },
+                },
+            );
+            assert!(layer.prepare(&create).await.is_ok());
+            let _ = layer.apply(&create).await.unwrap();
+
+            let (signer, public) = create_account_keypair(1);
+            let register = Transaction::sign(
+                &signer,
+                0,
+                Instruction::CasinoRegister {
+                    name: "Alice".to_string(),
+                },
+            );
+            assert!(layer.prepare(&register).await.is_ok());
+            let _ = layer.apply(&register).await.unwrap();
+            let join = Transaction::sign(
+                &signer,
+                1,
+                Instruction::CasinoJoinTournament { tournament_id },
+            );
+            assert!(layer.prepare(&join).await.is_ok());
+            let _ = layer.apply(&join).await.unwrap();
+
+            let start_time_ms = 1_700_000_000_000;
+            let start = Transaction::sign(
+                &admin_signer,
+                1,
+                Instruction::CasinoStartTournament {
+                    tournament_id,
+                    start_time_ms,
+                    end_time_ms: start_time_ms + 300_000,
+                    payout_structure: Default::default(),
+                },
+            );
+            assert!(layer.prepare(&start).await.is_ok());
+            let _ = layer.apply(&start).await.unwrap();
+
+            let Some(Value::CasinoPlayer(player)) =
+                layer.get(Key::CasinoPlayer(public.clone())).await.unwrap()
+            else {
+                panic!("player not found");
+            };
+            assert_eq!(player.tournament.chips, 5_000);
+            assert_eq!(player.tournament.shields, 0);
+            assert_eq!(player.tournament.doubles, 0);
+
+            let cases = [
+                (GameType::Roulette, 20, false),
+                (GameType::Blackjack, 5, false),
+                (GameType::Blackjack, 100, false),
+                (GameType::Blackjack, 20, true),
+            ];
+            for (i, (game_type, bet, accepted)) in cases.into_iter().enumerate() {
+                let session_id = 100 + i as u64;
+                let start_game = Transaction::sign(
+                    &signer,
+                    2 + i as u64,
+                    Instruction::CasinoStartGame {
+                        game_type,
+                        bet,
+                        session_id,
+                    },
+                );
+                assert!(layer.prepare(&start_game).await.is_ok());
+                let events = layer.apply(&start_game).await.unwrap();
+                assert_eq!(
+                    events
+                        .iter()
+                        .any(|event| matches!(event, Event::CasinoGameStarted { .. })),
+                    accepted,
+                    "{game_type:?} bet {bet}"
+                );
+            }
+
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_game_start_persists_session() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+
+            let (signer, public) = create_account_keypair(1);
+            let register = Transaction::sign(
+                &signer,
+                0,
+                Instruction::CasinoRegister {
+                    name: "Alice".to_string(),
+                },
+            );
+            assert!(layer.prepare(&register).await.is_ok());
+            let _ = layer.apply(&register).await.unwrap();
+
+            let session_id = 42;
+            let start = Transaction::sign(
+                &signer,
+                1,
+                Instruction::CasinoStartGame {
+                    game_type: GameType::Blackjack,
+                    bet: 10,
+                    session_id,
+                },
+            );
+            assert!(layer.prepare(&start).await.is_ok());
+            let events = layer.apply(&start).await.unwrap();
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::CasinoGameStarted { session_id: id, .. } if *id == session_id
+            )));
+
+            if let Some(Value::CasinoSession(session)) =
+                layer.get(Key::CasinoSession(session_id)).await.unwrap()
+            {
+                assert_eq!(session.id, session_id);
+                assert_eq!(session.player, public);
+            } else {
+                panic!("Session not found");
+            }
+
+            let _ = layer.commit();
+        });
+    }
+
+    #[test]
+    fn test_layer_execute_is_deterministic_for_identical_inputs() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let state1 = MockState::new();
+            let state2 = MockState::new();
+
+            let (network_secret, master_public) = create_network_keypair();
+            let seed = create_seed(&network_secret, 1);
+
+            let (signer, _public) = create_account_keypair(1);
+
+            let txs = vec![
+                Transaction::sign(
+                    &signer,
+                    0,
+                    Instruction::CasinoRegister {
+                        name: "Alice".to_string(),
+                    },
+                ),
+                Transaction::sign(
+                    &signer,
+                    1,
+                    Instruction::CasinoStartGame {
+                        game_type: GameType::Roulette,
+                        bet: 100,
+                        session_id: 1,
+                    },
+                ),
+                Transaction::sign(
+                    &signer,
+                    2,
+                    Instruction::CasinoGameMove {
+                        session_id: 1,
+                        payload: {
+                            let mut payload = vec![0u8, 1u8, 0u8]; // place RED bet
+                            payload.extend_from_slice(&100u64.to_be_bytes());
+                            payload
+                        },
                     },
                 ),
                 Transaction::sign(
+                    &signer,
+                    3,
+                    Instruction::CasinoGameMove {
+                        session_id: 1,
+                        payload: vec![1u8], // spin
+                    },
+                ),
+            ];
+
+            let mut layer1 = Layer::new(&state1, master_public, TEST_NAMESPACE, seed.clone());
+            let mut layer2 = Layer::new(&state2, master_public, TEST_NAMESPACE, seed);
+
+            #[cfg(feature = "parallel")]
+            let pool = ThreadPool::new(
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(1)
+                    .build()
+                    .expect("failed to create execution pool"),
+            );
+
+            let genesis = GenesisConfig::default();
+            #[cfg(feature = "parallel")]
+            let (outputs1, nonces1) = layer1
+                .execute(pool.clone(), &genesis, txs.clone())
+                .await
+                .unwrap();
+            #[cfg(not(feature = "parallel"))]
+            let (outputs1, nonces1) = layer1.execute(&genesis, txs.clone()).await.unwrap();
+
+            #[cfg(feature = "parallel")]
+            let (outputs2, nonces2) = layer2.execute(pool, &genesis, txs).await.unwrap();
+            #[cfg(not(feature = "parallel"))]
+            let (outputs2, nonces2) = layer2.execute(&genesis, txs).await.unwrap();
+
+            assert_eq!(outputs1, outputs2);
+            assert_eq!(nonces1, nonces2);
+            assert!(layer1.commit() == layer2.commit());
+        });
+    }
+
+    #[test]
+    fn test_global_table_roulette_round_settles() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            grant_genesis_admin(&mut state, &admin_public);
+            let (signer, public) = create_account_keypair(1);
+
+            let config = nullspace_types::casino::GlobalTableConfig {
+                game_type: GameType::Roulette,
+                betting_ms: 3_000,
+                lock_ms: 3_000,
+                payout_ms: 3_000,
+                cooldown_ms: 3_000,
+                min_bet: 1,
+                max_bet: 1_000,
+                max_bets_per_round: 10,
+            };
+            let bets = vec![
+                nullspace_types::casino::GlobalTableBet {
+                    bet_type: 1, // Red
+                    target: 0,
+                    amount: 100,
+                },
+                nullspace_types::casino::GlobalTableBet {
+                    bet_type: 0, // Straight 17
+                    target: 17,
+                    amount: 10,
+                },
+            ];
+
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                1,
+                vec![
+                    Transaction::sign(&admin_signer, 0, Instruction::GlobalTableInit { config }),
+                    Transaction::sign(
+                        &admin_signer,
+                        1,
+                        Instruction::GlobalTableOpenRound {
+                            game_type: GameType::Roulette,
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer,
+                        0,
+                        Instruction::CasinoRegister {
+                            name: "Alice".to_string(),
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer,
+                        1,
+                        Instruction::GlobalTableSubmitBets {
+                            game_type: GameType::Roulette,
+                            round_id: 1,
+                            bets: bets.clone(),
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert!(events
+                .iter()
+                .any(|event| matches!(event, Event::GlobalTableBetAccepted { .. })));
+
+            let _ = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![Transaction::sign(
+                    &admin_signer,
+                    2,
+                    Instruction::GlobalTableLock {
+                        game_type: GameType::Roulette,
+                        round_id: 1,
+                    },
+                )],
+            )
+            .await;
+
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                3,
+                vec![
+                    Transaction::sign(
+                        &admin_signer,
+                        3,
+                        Instruction::GlobalTableReveal {
+                            game_type: GameType::Roulette,
+                            round_id: 1,
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer,
+                        2,
+                        Instruction::GlobalTableSettle {
+                            game_type: GameType::Roulette,
+                            round_id: 1,
+                        },
+                    ),
+                ],
+            )
+            .await;
+
+            let result = events
+                .iter()
+                .find_map(|event| match event {
+                    Event::GlobalTableOutcome { round } => round.outcome.first().copied(),
+                    _ => None,
+                })
+                .expect("outcome revealed");
+            let (payout, my_bets) = events
+                .iter()
+                .find_map(|event| match event {
+                    Event::GlobalTablePlayerSettled {
+                        player,
+                        payout,
+                        my_bets,
+                        ..
+                    } if player == &public => Some((*payout, my_bets.clone())),
+                    _ => None,
+                })
+                .expect("player settled");
+            assert!(my_bets.is_empty());
+
+            const RED: [u8; 18] = [
+                1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36,
+            ];
+            let mut expected = 0i64;
+            if RED.contains(&result) {
+                expected += 200;
+            }
+            if result == 17 {
+                expected += 360;
+            }
+            assert_eq!(payout, expected);
+
+            let Some(Value::CasinoPlayer(player)) = state
+                .get(Key::CasinoPlayer(public.clone()))
+                .await
+                .unwrap()
+            else {
+                panic!("Player not found");
+            };
+            assert_eq!(player.balances.chips as i64, 1_000 - 110 + expected);
+
+            let Some(Value::GlobalTableRound(round)) = state
+                .get(Key::GlobalTableRound(GameType::Roulette))
+                .await
+                .unwrap()
+            else {
+                panic!("Round not found");
+            };
+            assert!(round.totals.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_global_table_phases_advance_without_admin() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            grant_genesis_admin(&mut state, &admin_public);
+            let (signer, public) = create_account_keypair(1);
+
+            let config = nullspace_types::casino::GlobalTableConfig {
+                game_type: GameType::SicBo,
+                betting_ms: 3_000,
+                lock_ms: 3_000,
+                payout_ms: 3_000,
+                cooldown_ms: 3_000,
+                min_bet: 1,
+                max_bet: 1_000,
+                max_bets_per_round: 10,
+            };
+
+            // Mirrors `Layer::execute`: the scheduler runs before the block's transactions.
+            async fn run_block(
+                state: &mut MockState,
+                network_secret: &commonware_cryptography::bls12381::primitives::group::Private,
+                master_public: <MinSig as Variant>::Public,
+                view: u64,
+                txs: Vec<Transaction>,
+            ) -> Vec<Event> {
+                let seed = create_seed(network_secret, view);
+                let mut layer = Layer::new(&*state, master_public, TEST_NAMESPACE, seed);
+                let mut events = layer.advance_global_tables().await.unwrap();
+                for tx in txs {
+                    assert!(layer.prepare(&tx).await.is_ok());
+                    events.extend(layer.apply(&tx).await.unwrap());
+                }
+                let changes = layer.commit();
+                apply_changes(state, changes);
+                events
+            }
+
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                1,
+                vec![
+                    Transaction::sign(&admin_signer, 0, Instruction::GlobalTableInit { config }),
+                    Transaction::sign(
+                        &signer,
+                        0,
+                        Instruction::CasinoRegister {
+                            name: "Alice".to_string(),
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert!(!events
+                .iter()
+                .any(|event| matches!(event, Event::GlobalTableRoundOpened { .. })));
+
+            // View 2: the round opens on its own and accepts bets in the same block.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![Transaction::sign(
+                    &signer,
+                    1,
+                    Instruction::GlobalTableSubmitBets {
+                        game_type: GameType::SicBo,
+                        round_id: 1,
+                        bets: vec![nullspace_types::casino::GlobalTableBet {
+                            bet_type: 0, // Small
+                            target: 0,
+                            amount: 50,
+                        }],
+                    },
+                )],
+            )
+            .await;
+            assert!(matches!(
+                events.first(),
+                Some(Event::GlobalTableRoundOpened { round }) if round.round_id == 1
+            ));
+            assert!(events
+                .iter()
+                .any(|event| matches!(event, Event::GlobalTableBetAccepted { .. })));
+
+            // View 3: betting closes.
+            let events = run_block(&mut state, &network_secret, master_public, 3, vec![]).await;
+            assert!(matches!(
+                events.as_slice(),
+                [Event::GlobalTableLocked { round_id: 1, .. }]
+            ));
+
+            // View 4: the outcome is revealed and the player settles.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                4,
+                vec![Transaction::sign(
                     &signer,
                     2,
-                    Instruction::CasinoGameMove {
-                        session_id: 1,
-                        payload: {
-                            let mut payload = vec![0u8, 1u8, 0u8]; // place RED bet
-                            payload.extend_from_slice(&100u64.to_be_bytes());
-                            payload
-                        },
+                    Instruction::GlobalTableSettle {
+                        game_type: GameType::SicBo,
+                        round_id: 1,
                     },
-                ),
+                )],
+            )
+            .await;
+            assert!(matches!(
+                events.first(),
+                Some(Event::GlobalTableOutcome { round }) if round.outcome.len() == 3
+            ));
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::GlobalTablePlayerSettled { player, .. } if player == &public
+            )));
+
+            // View 5: finalize; view 6: the next round opens.
+            let events = run_block(&mut state, &network_secret, master_public, 5, vec![]).await;
+            assert!(matches!(
+                events.as_slice(),
+                [Event::GlobalTableFinalized { round_id: 1, .. }]
+            ));
+            let events = run_block(&mut state, &network_secret, master_public, 6, vec![]).await;
+            assert!(matches!(
+                events.as_slice(),
+                [Event::GlobalTableRoundOpened { round }] if round.round_id == 2
+            ));
+
+            // Nothing is due mid-phase.
+            let events = run_block(&mut state, &network_secret, master_public, 6, vec![]).await;
+            assert!(events.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_global_table_unsettled_bets_settle_when_next_round_opens() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            grant_genesis_admin(&mut state, &admin_public);
+            let (signer, public) = create_account_keypair(1);
+
+            let config = nullspace_types::casino::GlobalTableConfig {
+                game_type: GameType::SicBo,
+                betting_ms: 3_000,
+                lock_ms: 3_000,
+                payout_ms: 3_000,
+                cooldown_ms: 3_000,
+                min_bet: 1,
+                max_bet: 1_000,
+                max_bets_per_round: 10,
+            };
+            let submit = |nonce: u64, round_id: u64| {
                 Transaction::sign(
+                    &signer,
+                    nonce,
+                    Instruction::GlobalTableSubmitBets {
+                        game_type: GameType::SicBo,
+                        round_id,
+                        bets: vec![nullspace_types::casino::GlobalTableBet {
+                            bet_type: 0, // Small
+                            target: 0,
+                            amount: 50,
+                        }],
+                    },
+                )
+            };
+
+            // Mirrors `Layer::execute`: the scheduler runs before the block's transactions.
+            async fn run_block(
+                state: &mut MockState,
+                network_secret: &commonware_cryptography::bls12381::primitives::group::Private,
+                master_public: <MinSig as Variant>::Public,
+                view: u64,
+                txs: Vec<Transaction>,
+            ) -> Vec<Event> {
+                let seed = create_seed(network_secret, view);
+                let mut layer = Layer::new(&*state, master_public, TEST_NAMESPACE, seed);
+                let mut events = layer.advance_global_tables().await.unwrap();
+                for tx in txs {
+                    assert!(layer.prepare(&tx).await.is_ok());
+                    events.extend(layer.apply(&tx).await.unwrap());
+                }
+                let changes = layer.commit();
+                apply_changes(state, changes);
+                events
+            }
+            async fn chips(state: &MockState, public: &PublicKey) -> u64 {
+                match state.get(Key::CasinoPlayer(public.clone())).await.unwrap() {
+                    Some(Value::CasinoPlayer(player)) => player.balances.chips,
+                    _ => panic!("Player not found"),
+                }
+            }
+
+            let _ = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                1,
+                vec![
+                    Transaction::sign(&admin_signer, 0, Instruction::GlobalTableInit { config }),
+                    Transaction::sign(
+                        &signer,
+                        0,
+                        Instruction::CasinoRegister {
+                            name: "Alice".to_string(),
+                        },
+                    ),
+                ],
+            )
+            .await;
+            let starting_chips = chips(&state, &public).await;
+
+            // Round 1 runs to cooldown without the player ever sending a settle.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![submit(1, 1)],
+            )
+            .await;
+            assert!(events
+                .iter()
+                .any(|event| matches!(event, Event::GlobalTableBetAccepted { .. })));
+            for view in 3..=5 {
+                let _ = run_block(&mut state, &network_secret, master_public, view, vec![]).await;
+            }
+            assert_eq!(
+                state
+                    .get(Key::GlobalTablePlayers(GameType::SicBo))
+                    .await
+                    .unwrap(),
+                Some(Value::GlobalTablePlayers(vec![public.clone()]))
+            );
+
+            // View 6: opening round 2 settles round 1, and the player can bet again.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                6,
+                vec![submit(2, 2)],
+            )
+            .await;
+            let payout = events
+                .iter()
+                .find_map(|event| match event {
+                    Event::GlobalTablePlayerSettled {
+                        player,
+                        round_id: 1,
+                        payout,
+                        ..
+                    } if player == &public => Some(*payout),
+                    _ => None,
+                })
+                .expect("round 1 settled on open");
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::GlobalTableRoundOpened { round } if round.round_id == 2
+            )));
+            assert!(events
+                .iter()
+                .any(|event| matches!(event, Event::GlobalTableBetAccepted { round_id: 2, .. })));
+            assert!(!events
+                .iter()
+                .any(|event| matches!(event, Event::GlobalTableBetRejected { .. })));
+
+            let expected = (starting_chips as i64 - 100 + payout) as u64;
+            assert_eq!(chips(&state, &public).await, expected);
+        });
+    }
+
+    #[test]
+    fn test_global_table_cancel_and_replace_bets() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            grant_genesis_admin(&mut state, &admin_public);
+            let (signer, public) = create_account_keypair(1);
+
+            let config = nullspace_types::casino::GlobalTableConfig {
+                game_type: GameType::Roulette,
+                betting_ms: 30_000,
+                lock_ms: 3_000,
+                payout_ms: 3_000,
+                cooldown_ms: 3_000,
+                min_bet: 1,
+                max_bet: 1_000,
+                max_bets_per_round: 3,
+            };
+            let bet = |bet_type: u8, target: u8, amount: u64| {
+                nullspace_types::casino::GlobalTableBet {
+                    bet_type,
+                    target,
+                    amount,
+                }
+            };
+            async fn chips(state: &MockState, public: &PublicKey) -> u64 {
+                match state.get(Key::CasinoPlayer(public.clone())).await.unwrap() {
+                    Some(Value::CasinoPlayer(player)) => player.balances.chips,
+                    _ => panic!("Player not found"),
+                }
+            }
+            async fn totals(state: &MockState) -> Vec<nullspace_types::casino::GlobalTableTotal> {
+                match state
+                    .get(Key::GlobalTableRound(GameType::Roulette))
+                    .await
+                    .unwrap()
+                {
+                    Some(Value::GlobalTableRound(round)) => round.totals,
+                    _ => panic!("Round not found"),
+                }
+            }
+
+            let _ = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                1,
+                vec![
+                    Transaction::sign(&admin_signer, 0, Instruction::GlobalTableInit { config }),
+                    Transaction::sign(
+                        &admin_signer,
+                        1,
+                        Instruction::GlobalTableOpenRound {
+                            game_type: GameType::Roulette,
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer,
+                        0,
+                        Instruction::CasinoRegister {
+                            name: "Alice".to_string(),
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer,
+                        1,
+                        Instruction::GlobalTableSubmitBets {
+                            game_type: GameType::Roulette,
+                            round_id: 1,
+                            bets: vec![bet(1, 0, 100), bet(0, 17, 10)],
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert_eq!(chips(&state, &public).await, 1_000 - 110);
+
+            // The round cap counts bets already on the table.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![Transaction::sign(
+                    &signer,
+                    2,
+                    Instruction::GlobalTableSubmitBets {
+                        game_type: GameType::Roulette,
+                        round_id: 1,
+                        bets: vec![bet(3, 0, 5), bet(4, 0, 5)],
+                    },
+                )],
+            )
+            .await;
+            assert!(matches!(
+                events.as_slice(),
+                [Event::GlobalTableBetRejected { message, .. }] if message == "Too many bets submitted"
+            ));
+
+            // Replace refunds the old stake and charges the new one.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![Transaction::sign(
                     &signer,
                     3,
-                    Instruction::CasinoGameMove {
-                        session_id: 1,
-                        payload: vec![1u8], // spin
+                    Instruction::GlobalTableReplaceBets {
+                        game_type: GameType::Roulette,
+                        round_id: 1,
+                        bets: vec![bet(2, 0, 50)],
                     },
-                ),
-            ];
+                )],
+            )
+            .await;
+            assert!(matches!(
+                events.first(),
+                Some(Event::GlobalTableBetsReplaced { cancelled, bets, .. })
+                    if cancelled.len() == 2 && bets == &vec![bet(2, 0, 50)]
+            ));
+            assert_eq!(chips(&state, &public).await, 1_000 - 50);
+            assert_eq!(
+                totals(&state).await,
+                vec![nullspace_types::casino::GlobalTableTotal {
+                    bet_type: 2,
+                    target: 0,
+                    amount: 50,
+                }]
+            );
 
-            let mut layer1 = Layer::new(&state1, master_public, TEST_NAMESPACE, seed.clone());
-            let mut layer2 = Layer::new(&state2, master_public, TEST_NAMESPACE, seed);
+            // Cancel returns everything and clears the heatmap.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                3,
+                vec![
+                    Transaction::sign(
+                        &signer,
+                        4,
+                        Instruction::GlobalTableCancelBets {
+                            game_type: GameType::Roulette,
+                            round_id: 1,
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer,
+                        5,
+                        Instruction::GlobalTableCancelBets {
+                            game_type: GameType::Roulette,
+                            round_id: 1,
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert!(matches!(
+                events.first(),
+                Some(Event::GlobalTableBetsCancelled { bets, .. }) if bets == &vec![bet(2, 0, 50)]
+            ));
+            assert!(matches!(
+                events.last(),
+                Some(Event::GlobalTableBetRejected { message, .. }) if message == "No bets to cancel"
+            ));
+            assert_eq!(chips(&state, &public).await, 1_000);
+            assert!(totals(&state).await.is_empty());
 
-            #[cfg(feature = "parallel")]
-            let pool = ThreadPool::new(
-                rayon::ThreadPoolBuilder::new()
-                    .num_threads(1)
-                    .build()
-                    .expect("failed to create execution pool"),
+            let Some(Value::GlobalTablePlayerSession(session)) = state
+                .get(Key::GlobalTablePlayerSession(GameType::Roulette, public.clone()))
+                .await
+                .unwrap()
+            else {
+                panic!("Player session not found");
+            };
+            assert!(session.round_bets.is_empty());
+            assert!(crate::casino::global_table::outstanding_bets(
+                GameType::Roulette,
+                &session.session.state_blob
+            )
+            .is_empty());
+        });
+    }
+
+    #[test]
+    fn test_global_table_crash_round_with_cash_out() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            grant_genesis_admin(&mut state, &admin_public);
+            let (alice_signer, alice) = create_account_keypair(1);
+            let (bob_signer, bob) = create_account_keypair(2);
+
+            let config = nullspace_types::casino::GlobalTableConfig {
+                game_type: GameType::Crash,
+                betting_ms: 3_000,
+                lock_ms: 3_000,
+                payout_ms: 3_000,
+                cooldown_ms: 3_000,
+                min_bet: 1,
+                max_bet: 1_000,
+                max_bets_per_round: 5,
+            };
+            // Whole-number 100x auto cash-out.
+            let bet = nullspace_types::casino::GlobalTableBet {
+                bet_type: 2,
+                target: 100,
+                amount: 100,
+            };
+            let cash_out = |signer, nonce: u64| {
+                Transaction::sign(
+                    signer,
+                    nonce,
+                    Instruction::GlobalTableCashOut {
+                        game_type: GameType::Crash,
+                        round_id: 1,
+                    },
+                )
+            };
+
+            // Mirrors `Layer::execute`: the scheduler runs before the block's transactions.
+            async fn run_block(
+                state: &mut MockState,
+                network_secret: &commonware_cryptography::bls12381::primitives::group::Private,
+                master_public: <MinSig as Variant>::Public,
+                view: u64,
+                txs: Vec<Transaction>,
+            ) -> Vec<Event> {
+                let seed = create_seed(network_secret, view);
+                let mut layer = Layer::new(&*state, master_public, TEST_NAMESPACE, seed);
+                let mut events = layer.advance_global_tables().await.unwrap();
+                for tx in txs {
+                    assert!(layer.prepare(&tx).await.is_ok());
+                    events.extend(layer.apply(&tx).await.unwrap());
+                }
+                let changes = layer.commit();
+                apply_changes(state, changes);
+                events
+            }
+            async fn chips(state: &MockState, public: &PublicKey) -> u64 {
+                match state.get(Key::CasinoPlayer(public.clone())).await.unwrap() {
+                    Some(Value::CasinoPlayer(player)) => player.balances.chips,
+                    _ => panic!("Player not found"),
+                }
+            }
+            async fn round(state: &MockState) -> nullspace_types::casino::GlobalTableRound {
+                match state
+                    .get(Key::GlobalTableRound(GameType::Crash))
+                    .await
+                    .unwrap()
+                {
+                    Some(Value::GlobalTableRound(round)) => round,
+                    _ => panic!("Round not found"),
+                }
+            }
+
+            let _ = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                1,
+                vec![
+                    Transaction::sign(&admin_signer, 0, Instruction::GlobalTableInit { config }),
+                    Transaction::sign(
+                        &alice_signer,
+                        0,
+                        Instruction::CasinoRegister {
+                            name: "Alice".to_string(),
+                        },
+                    ),
+                    Transaction::sign(
+                        &bob_signer,
+                        0,
+                        Instruction::CasinoRegister {
+                            name: "Bob".to_string(),
+                        },
+                    ),
+                ],
+            )
+            .await;
+
+            // View 2: both players bet with a 100x auto cash-out.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                [(&alice_signer, 1), (&bob_signer, 1)]
+                    .into_iter()
+                    .map(|(signer, nonce)| {
+                        Transaction::sign(
+                            signer,
+                            nonce,
+                            Instruction::GlobalTableSubmitBets {
+                                game_type: GameType::Crash,
+                                round_id: 1,
+                                bets: vec![bet.clone()],
+                            },
+                        )
+                    })
+                    .collect(),
+            )
+            .await;
+            assert_eq!(
+                events
+                    .iter()
+                    .filter(|event| matches!(event, Event::GlobalTableBetAccepted { .. }))
+                    .count(),
+                2
             );
 
-            #[cfg(feature = "parallel")]
-            let (outputs1, nonces1) = layer1.execute(pool.clone(), txs.clone()).await.unwrap();
-            #[cfg(not(feature = "parallel"))]
-            let (outputs1, nonces1) = layer1.execute(txs.clone()).await.unwrap();
+            // View 3: betting closes without drawing the seed; cash-outs wait for Rolling.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                3,
+                vec![cash_out(&alice_signer, 2)],
+            )
+            .await;
+            assert!(matches!(
+                events.as_slice(),
+                [
+                    Event::GlobalTableLocked { round_id: 1, .. },
+                    Event::GlobalTableBetRejected { message, .. },
+                ] if message == "Round not rolling"
+            ));
+            assert!(round(&state).await.roll_seed.is_empty());
+            assert!(round(&state).await.rng_commit.is_empty());
 
-            #[cfg(feature = "parallel")]
-            let (outputs2, nonces2) = layer2.execute(pool, txs).await.unwrap();
-            #[cfg(not(feature = "parallel"))]
-            let (outputs2, nonces2) = layer2.execute(txs).await.unwrap();
+            // View 4: the multiplier starts climbing.
+            let events = run_block(&mut state, &network_secret, master_public, 4, vec![]).await;
+            let rolling_ends_at = 12_000 + crate::casino::crash::ROLL_MS;
+            assert!(matches!(
+                events.as_slice(),
+                [Event::GlobalTableRolling { round_id: 1, phase_ends_at_ms, .. }]
+                    if *phase_ends_at_ms == rolling_ends_at
+            ));
 
-            assert_eq!(outputs1, outputs2);
-            assert_eq!(nonces1, nonces2);
-            assert!(layer1.commit() == layer2.commit());
+            // View 5: Alice cashes out 3s into the roll (1.75x); a second cash-out is refused.
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                5,
+                vec![cash_out(&alice_signer, 3), cash_out(&alice_signer, 4)],
+            )
+            .await;
+            assert!(matches!(
+                events.as_slice(),
+                [
+                    Event::GlobalTableCashedOut { player, multiplier: 175, .. },
+                    Event::GlobalTableBetRejected { message, .. },
+                ] if player == &alice && message == "Nothing to cash out"
+            ));
+
+            // The roll runs its full course before the crash point is drawn.
+            let mut view = 6;
+            while view * 3_000 < rolling_ends_at {
+                let events =
+                    run_block(&mut state, &network_secret, master_public, view, vec![]).await;
+                assert!(events.is_empty());
+                view += 1;
+            }
+
+            let events = run_block(
+                &mut state,
+                &network_secret,
+                master_public,
+                view,
+                vec![
+                    Transaction::sign(
+                        &alice_signer,
+                        5,
+                        Instruction::GlobalTableSettle {
+                            game_type: GameType::Crash,
+                            round_id: 1,
+                        },
+                    ),
+                    Transaction::sign(
+                        &bob_signer,
+                        2,
+                        Instruction::GlobalTableSettle {
+                            game_type: GameType::Crash,
+                            round_id: 1,
+                        },
+                    ),
+                ],
+            )
+            .await;
+            let Some(Event::GlobalTableOutcome { round }) = events.first() else {
+                panic!("Outcome not revealed");
+            };
+            assert_eq!(round.roll_seed.len(), 32);
+            let crash_point = u32::from_be_bytes(round.outcome[..].try_into().unwrap());
+
+            // Alice is paid at her cash-out, Bob only if the curve reached his target.
+            let alice_return = if crash_point >= 175 { 175 } else { 0 };
+            let bob_return = if crash_point >= 10_000 { 10_000 } else { 0 };
+            assert_eq!(chips(&state, &alice).await, 1_000 - 100 + alice_return);
+            assert_eq!(chips(&state, &bob).await, 1_000 - 100 + bob_return);
+        });
+    }
+
+    #[test]
+    fn test_admin_registry_roles() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            use nullspace_types::casino::{AdminRole, ERROR_INVALID_MOVE, ERROR_UNAUTHORIZED};
+
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            let (oracle_signer, oracle_public) = create_account_keypair(5);
+
+            fn error_code(events: &[Event]) -> Option<u8> {
+                match events {
+                    [Event::CasinoError { error_code, .. }] => Some(*error_code),
+                    _ => None,
+                }
+            }
+            let update_oracle = Instruction::UpdateOracle {
+                price_vusdt_numerator: 2,
+                price_rng_denominator: 1,
+                updated_ts: 0,
+                source: Vec::new(),
+            };
+
+            // Genesis bootstrap persists the configured key with every role.
+            let seed = create_seed(&network_secret, 1);
+            let mut layer = Layer::new(&state, master_public, TEST_NAMESPACE, seed);
+            layer
+                .bootstrap_admin_registry(&admin_genesis(&admin_public))
+                .await
+                .unwrap();
+            let changes = layer.commit();
+            apply_changes(&mut state, changes);
+            let Some(Value::AdminRegistry(registry)) = state.data.get(&Key::AdminRegistry)
+            else {
+                panic!("admin registry not bootstrapped");
+            };
+            assert!(AdminRole::ALL
+                .iter()
+                .all(|role| registry.has_role(&admin_public, *role)));
+
+            // Keys without a role are rejected, including for registry changes.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![
+                    Transaction::sign(&oracle_signer, 0, update_oracle.clone()),
+                    Transaction::sign(
+                        &oracle_signer,
+                        1,
+                        Instruction::GrantAdminRole {
+                            public: oracle_public.clone(),
+                            role: AdminRole::Oracle,
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert_eq!(events.len(), 2);
+            assert!(events
+                .iter()
+                .all(|event| error_code(std::slice::from_ref(event)) == Some(ERROR_UNAUTHORIZED)));
+
+            // A granted role unlocks only its own instructions.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                3,
+                vec![Transaction::sign(
+                    &admin_signer,
+                    0,
+                    Instruction::GrantAdminRole {
+                        public: oracle_public.clone(),
+                        role: AdminRole::Oracle,
+                    },
+                )],
+            )
+            .await;
+            assert!(matches!(
+                events.as_slice(),
+                [Event::AdminRoleGranted { role: AdminRole::Oracle, .. }]
+            ));
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                4,
+                vec![
+                    Transaction::sign(&oracle_signer, 2, update_oracle.clone()),
+                    Transaction::sign(
+                        &oracle_signer,
+                        3,
+                        Instruction::SetPolicy {
+                            policy: nullspace_types::casino::PolicyState::default(),
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert!(matches!(events[0], Event::OracleUpdated { .. }));
+            assert_eq!(error_code(&events[1..]), Some(ERROR_UNAUTHORIZED));
+
+            // Revoking takes effect even though the environment still lists the admin key.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                5,
+                vec![
+                    Transaction::sign(
+                        &admin_signer,
+                        1,
+                        Instruction::RevokeAdminRole {
+                            public: admin_public.clone(),
+                            role: AdminRole::Tournament,
+                        },
+                    ),
+                    Transaction::sign(
+                        &admin_signer,
+                        2,
+                        Instruction::CasinoSetTournamentLimit {
+                            player: oracle_public.clone(),
+                            daily_limit: 3,
+                        },
+                    ),
+                    Transaction::sign(
+                        &admin_signer,
+                        3,
+                        Instruction::RevokeAdminRole {
+                            public: oracle_public.clone(),
+                            role: AdminRole::Oracle,
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert!(matches!(events[0], Event::AdminRoleRevoked { .. }));
+            assert_eq!(error_code(&events[1..2]), Some(ERROR_UNAUTHORIZED));
+            assert!(matches!(events[2], Event::AdminRoleRevoked { .. }));
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                6,
+                vec![Transaction::sign(&oracle_signer, 4, update_oracle)],
+            )
+            .await;
+            assert_eq!(error_code(&events), Some(ERROR_UNAUTHORIZED));
+
+            // The last registry admin cannot remove themselves.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                7,
+                vec![Transaction::sign(
+                    &admin_signer,
+                    4,
+                    Instruction::RevokeAdminRole {
+                        public: admin_public.clone(),
+                        role: AdminRole::Registry,
+                    },
+                )],
+            )
+            .await;
+            assert_eq!(error_code(&events), Some(ERROR_INVALID_MOVE));
+        });
+    }
+
+    #[test]
+    fn test_multisig_proposal_flow() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            use nullspace_types::casino::{
+                AdminRole, PolicyState, ERROR_INVALID_MOVE, ERROR_RATE_LIMITED,
+                ERROR_UNAUTHORIZED,
+            };
+
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            grant_genesis_admin(&mut state, &admin_public);
+            let (signer_a, public_a) = create_account_keypair(11);
+            let (signer_b, public_b) = create_account_keypair(12);
+            let (signer_c, public_c) = create_account_keypair(13);
+
+            fn error_code(events: &[Event]) -> Option<u8> {
+                match events {
+                    [Event::CasinoError { error_code, .. }] => Some(*error_code),
+                    _ => None,
+                }
+            }
+            let set_policy = Instruction::SetPolicy {
+                policy: PolicyState::default(),
+            };
+
+            // A 2-of-3 multisig with a 30s time-lock takes over the policy role.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                1,
+                vec![
+                    Transaction::sign(
+                        &admin_signer,
+                        0,
+                        Instruction::CreateMultisig {
+                            signers: vec![public_a.clone(), public_b.clone(), public_c.clone()],
+                            threshold: 2,
+                            timelock_secs: 30,
+                            roles: AdminRole::Policy.bit(),
+                        },
+                    ),
+                    Transaction::sign(&admin_signer, 1, set_policy.clone()),
+                ],
+            )
+            .await;
+            assert!(matches!(
+                events[0],
+                Event::MultisigCreated { ref multisig, .. } if multisig.id == 0
+            ));
+            assert_eq!(error_code(&events[1..]), Some(ERROR_UNAUTHORIZED));
+
+            // One signature is not enough to execute.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![
+                    Transaction::sign(
+                        &signer_a,
+                        0,
+                        Instruction::ProposeMultisig {
+                            multisig_id: 0,
+                            instruction: Box::new(set_policy.clone()),
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer_a,
+                        1,
+                        Instruction::ExecuteMultisig {
+                            multisig_id: 0,
+                            proposal_id: 0,
+                        },
+                    ),
+                    Transaction::sign(
+                        &admin_signer,
+                        2,
+                        Instruction::ApproveMultisig {
+                            multisig_id: 0,
+                            proposal_id: 0,
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert!(matches!(
+                events[0],
+                Event::MultisigProposed { proposal_id: 0, .. }
+            ));
+            assert_eq!(error_code(&events[1..2]), Some(ERROR_UNAUTHORIZED));
+            assert_eq!(error_code(&events[2..]), Some(ERROR_UNAUTHORIZED));
+
+            // The second approval starts the time-lock.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                4,
+                vec![
+                    Transaction::sign(
+                        &signer_b,
+                        0,
+                        Instruction::ApproveMultisig {
+                            multisig_id: 0,
+                            proposal_id: 0,
+                        },
+                    ),
+                    Transaction::sign(
+                        &signer_b,
+                        1,
+                        Instruction::ExecuteMultisig {
+                            multisig_id: 0,
+                            proposal_id: 0,
+                        },
+                    ),
+                ],
+            )
+            .await;
+            assert!(matches!(
+                events[0],
+                Event::MultisigApproved {
+                    approvals: 2,
+                    executable_at: 42,
+                    ..
+                }
+            ));
+            assert_eq!(error_code(&events[1..]), Some(ERROR_RATE_LIMITED));
+
+            // Once the time-lock elapsed any signer can execute, exactly once.
+            let execute = |nonce| {
+                Transaction::sign(
+                    &signer_c,
+                    nonce,
+                    Instruction::ExecuteMultisig {
+                        multisig_id: 0,
+                        proposal_id: 0,
+                    },
+                )
+            };
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                14,
+                vec![execute(0), execute(1)],
+            )
+            .await;
+            assert!(matches!(events[0], Event::MultisigExecuted { .. }));
+            assert!(matches!(events[1], Event::PolicyUpdated { .. }));
+            assert_eq!(error_code(&events[2..]), Some(ERROR_INVALID_MOVE));
+            assert!(matches!(
+                state.data.get(&Key::Policy),
+                Some(Value::Policy(_))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_multisig_dissolve_releases_guarded_roles() {
+        let executor = Runner::default();
+        executor.start(|_| async move {
+            use nullspace_types::casino::{
+                AdminRole, PolicyState, ERROR_INVALID_MOVE, ERROR_UNAUTHORIZED,
+            };
+
+            let mut state = MockState::new();
+            let (network_secret, master_public) = create_network_keypair();
+            let (admin_signer, admin_public) = create_account_keypair(999);
+            grant_genesis_admin(&mut state, &admin_public);
+            let (signer_a, public_a) = create_account_keypair(11);
+
+            fn error_code(events: &[Event]) -> Option<u8> {
+                match events {
+                    [Event::CasinoError { error_code, .. }] => Some(*error_code),
+                    _ => None,
+                }
+            }
+            let set_policy = Instruction::SetPolicy {
+                policy: PolicyState::default(),
+            };
+            let create = Instruction::CreateMultisig {
+                signers: vec![public_a.clone()],
+                threshold: 1,
+                timelock_secs: 0,
+                roles: AdminRole::Policy.bit(),
+            };
+            let propose = |nonce, multisig_id, target| {
+                Transaction::sign(
+                    &signer_a,
+                    nonce,
+                    Instruction::ProposeMultisig {
+                        multisig_id,
+                        instruction: Box::new(Instruction::DissolveMultisig {
+                            multisig_id: target,
+                        }),
+                    },
+                )
+            };
+
+            // Two multisigs guard the policy role; a single key cannot dissolve either.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                1,
+                vec![
+                    Transaction::sign(&admin_signer, 0, create.clone()),
+                    Transaction::sign(&admin_signer, 1, create),
+                    Transaction::sign(
+                        &admin_signer,
+                        2,
+                        Instruction::DissolveMultisig { multisig_id: 0 },
+                    ),
+                    propose(0, 0, 1),
+                ],
+            )
+            .await;
+            assert_eq!(error_code(&events[2..3]), Some(ERROR_UNAUTHORIZED));
+            assert_eq!(error_code(&events[3..]), Some(ERROR_INVALID_MOVE));
+
+            // Dissolving one multisig leaves the role guarded by the other.
+            let execute = |nonce, multisig_id| {
+                Transaction::sign(
+                    &signer_a,
+                    nonce,
+                    Instruction::ExecuteMultisig {
+                        multisig_id,
+                        proposal_id: 0,
+                    },
+                )
+            };
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                2,
+                vec![
+                    propose(1, 0, 0),
+                    execute(2, 0),
+                    Transaction::sign(&admin_signer, 3, set_policy.clone()),
+                ],
+            )
+            .await;
+            assert!(matches!(events[1], Event::MultisigExecuted { .. }));
+            assert!(matches!(
+                events[2],
+                Event::MultisigDissolved {
+                    multisig_id: 0,
+                    guarded_roles,
+                } if guarded_roles == AdminRole::Policy.bit()
+            ));
+            assert_eq!(error_code(&events[3..]), Some(ERROR_UNAUTHORIZED));
+            assert!(!state.data.contains_key(&Key::Multisig(0)));
+
+            // Once the last one is gone the admin key can set the policy again.
+            let events = run_view(
+                &mut state,
+                &network_secret,
+                master_public,
+                3,
+                vec![
+                    propose(3, 1, 1),
+                    execute(4, 1),
+                    Transaction::sign(&admin_signer, 4, set_policy),
+                ],
+            )
+            .await;
+            assert!(matches!(
+                events[2],
+                Event::MultisigDissolved {
+                    multisig_id: 1,
+                    guarded_roles: 0,
+                }
+            ));
+            assert!(matches!(events[3], Event::PolicyUpdated { .. }));
         });
     }
 }
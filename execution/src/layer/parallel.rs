@@ -0,0 +1,545 @@
+//! Parallel transaction execution.
+//!
+//! Transactions are split into groups that share none of the keys they are expected to
+//! contend on (see [`conflict_hints`]). The keys they need are read from the state up front, on
+//! the async runtime, so that every group can then run on the thread pool against that snapshot
+//! without touching storage. A transaction that reads a key missing from the snapshot is re-run
+//! once the key has been fetched. The results are then committed in block order: a transaction is
+//! kept only if every key it read was last written by its own group, otherwise it (and the rest
+//! of its group) is re-run against the committed state. The outputs are therefore identical to
+//! running the block sequentially.
+
+use super::*;
+use commonware_runtime::ThreadPool;
+use futures::FutureExt;
+use rayon::prelude::*;
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+/// Rounds of fetching keys missed by speculation before the affected groups are left to be
+/// re-run in block order.
+const MAX_PREFETCH_ROUNDS: usize = 4;
+
+/// Snapshot of the block state plus the earlier writes of one group, recording every read.
+///
+/// Reading a key that is not in the snapshot records it as missing and fails, so speculation
+/// never waits on storage.
+struct Speculative<'b> {
+    snapshot: &'b BTreeMap<Key, Option<Value>>,
+    writes: &'b BTreeMap<Key, Status>,
+    reads: Mutex<BTreeSet<Key>>,
+    missing: Mutex<BTreeSet<Key>>,
+}
+
+impl State for Speculative<'_> {
+    async fn get(&self, key: Key) -> Result<Option<Value>> {
+        self.reads
+            .lock()
+            .expect("reads lock poisoned")
+            .insert(key.clone());
+        match self.writes.get(&key) {
+            Some(Status::Update(value)) => Ok(Some(value.clone())),
+            Some(Status::Delete) => Ok(None),
+            None => match self.snapshot.get(&key) {
+                Some(value) => Ok(value.clone()),
+                None => {
+                    self.missing
+                        .lock()
+                        .expect("missing lock poisoned")
+                        .insert(key.clone());
+                    anyhow::bail!("key not in speculative snapshot")
+                }
+            },
+        }
+    }
+
+    async fn insert(&mut self, _key: Key, _value: Value) -> Result<()> {
+        anyhow::bail!("speculative state is read-only")
+    }
+
+    async fn delete(&mut self, _key: Key) -> Result<()> {
+        anyhow::bail!("speculative state is read-only")
+    }
+}
+
+/// Outcome of running one transaction against a [`Speculative`] view.
+struct Speculation {
+    result: Result<Option<Vec<Event>>>,
+    reads: BTreeSet<Key>,
+    /// Keys read but absent from the snapshot; the speculation must be re-run without them.
+    missing: BTreeSet<Key>,
+    writes: BTreeMap<Key, Status>,
+    fees_burned: u64,
+    fees_to_house: u64,
+}
+
+/// Keys a transaction is likely to contend on, used to keep such transactions in one group.
+///
+/// The hint does not need to be complete: any other conflict is caught when results are
+/// committed and only costs a re-run.
+fn conflict_hints(tx: &Transaction) -> Vec<Key> {
+    let mut keys = vec![Key::Account(tx.public.clone())];
+    match &tx.instruction {
+        Instruction::CasinoRegister { .. } => keys.push(Key::PlayerRegistry),
+        Instruction::CasinoStartGame { session_id, .. }
+        | Instruction::CasinoGameMove { session_id, .. } => {
+            keys.push(Key::CasinoSession(*session_id))
+        }
+        Instruction::CasinoJoinTournament { tournament_id }
+        | Instruction::CasinoStartTournament { tournament_id, .. }
+        | Instruction::CasinoEndTournament { tournament_id }
+        | Instruction::CasinoCreateTournament { tournament_id, .. }
+        | Instruction::CasinoCancelTournament { tournament_id }
+        | Instruction::CasinoTournamentTopUp { tournament_id, .. } => {
+            keys.push(Key::Tournament(*tournament_id))
+        }
+        Instruction::GlobalTableInit { config } => {
+            keys.push(Key::GlobalTableRound(config.game_type))
+        }
+        Instruction::GlobalTableOpenRound { game_type }
+        | Instruction::GlobalTableSubmitBets { game_type, .. }
+        | Instruction::GlobalTableLock { game_type, .. }
+        | Instruction::GlobalTableReveal { game_type, .. }
+        | Instruction::GlobalTableSettle { game_type, .. }
+        | Instruction::GlobalTableFinalize { game_type, .. }
+        | Instruction::GlobalTableCancelBets { game_type, .. }
+        | Instruction::GlobalTableReplaceBets { game_type, .. }
+        | Instruction::GlobalTableCashOut { game_type, .. } => {
+            keys.push(Key::GlobalTableRound(*game_type))
+        }
+        Instruction::Stake { .. }
+        | Instruction::Unstake
+        | Instruction::ClaimRewards
+        | Instruction::ProcessEpoch => keys.push(Key::House),
+        Instruction::Swap { .. }
+        | Instruction::AddLiquidity { .. }
+        | Instruction::RemoveLiquidity { .. }
+        | Instruction::SeedAmm { .. }
+        | Instruction::FinalizeAmmBootstrap => keys.push(Key::AmmPool),
+        Instruction::CreateVault
+        | Instruction::DepositCollateral { .. }
+        | Instruction::BorrowUSDT { .. }
+        | Instruction::RepayUSDT { .. }
+        | Instruction::LiquidateVault { .. }
+        | Instruction::RetireVaultDebt { .. }
+        | Instruction::RetireWorstVaultDebt { .. } => keys.push(Key::VaultRegistry),
+        Instruction::DepositSavings { .. }
+        | Instruction::WithdrawSavings { .. }
+        | Instruction::ClaimSavingsRewards => keys.push(Key::SavingsPool),
+        Instruction::BridgeWithdraw { .. }
+        | Instruction::BridgeDeposit { .. }
+        | Instruction::FinalizeBridgeWithdrawal { .. } => keys.push(Key::BridgeState),
+        Instruction::GrantAdminRole { .. }
+        | Instruction::RevokeAdminRole { .. }
+        | Instruction::CreateMultisig { .. }
+        | Instruction::ProposeMultisig { .. }
+        | Instruction::ApproveMultisig { .. }
+        | Instruction::ExecuteMultisig { .. }
+        | Instruction::DissolveMultisig { .. } => keys.push(Key::AdminRegistry),
+        _ => {}
+    }
+    keys
+}
+
+/// Keys fetched for a transaction before speculation: its hints plus what every transaction
+/// touches when it pays a fee.
+fn prefetch_keys(tx: &Transaction) -> Vec<Key> {
+    let mut keys = conflict_hints(tx);
+    keys.push(Key::CasinoPlayer(tx.public.clone()));
+    keys.push(Key::Policy);
+    keys
+}
+
+/// Partition transactions into groups sharing no hinted key.
+///
+/// Returns the groups (transaction indices in block order) and the group of each transaction.
+fn group_transactions(transactions: &[Transaction]) -> (Vec<Vec<usize>>, Vec<usize>) {
+    fn find(parent: &mut [usize], mut index: usize) -> usize {
+        while parent[index] != index {
+            parent[index] = parent[parent[index]];
+            index = parent[index];
+        }
+        index
+    }
+
+    let mut parent: Vec<usize> = (0..transactions.len()).collect();
+    let mut owners: BTreeMap<Key, usize> = BTreeMap::new();
+    for (index, tx) in transactions.iter().enumerate() {
+        for key in conflict_hints(tx) {
+            let Some(&owner) = owners.get(&key) else {
+                owners.insert(key, index);
+                continue;
+            };
+            let a = find(&mut parent, index);
+            let b = find(&mut parent, owner);
+            if a != b {
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of = vec![0; transactions.len()];
+    let mut roots: BTreeMap<usize, usize> = BTreeMap::new();
+    for (index, group_slot) in group_of.iter_mut().enumerate() {
+        let root = find(&mut parent, index);
+        let group = *roots.entry(root).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(index);
+        *group_slot = group;
+    }
+    (groups, group_of)
+}
+
+impl<'a, S: State> Layer<'a, S> {
+    /// Run `tx` on a child layer reading through `writes` into `snapshot`.
+    fn speculate(
+        &self,
+        snapshot: &BTreeMap<Key, Option<Value>>,
+        writes: &BTreeMap<Key, Status>,
+        tx: &Transaction,
+    ) -> Speculation {
+        let view = Speculative {
+            snapshot,
+            writes,
+            reads: Mutex::default(),
+            missing: Mutex::default(),
+        };
+        let mut child = Layer {
+            state: &view,
+            pending: BTreeMap::new(),
+
+            seed: self.seed.clone(),
+            seed_view: self.seed_view,
+
+            multisig_roles: 0,
+            write_count: 0,
+
+            fees_burned: 0,
+            fees_to_house: 0,
+        };
+        let result = child
+            .execute_transaction(tx)
+            .now_or_never()
+            .expect("speculative state never waits");
+        let Layer {
+            pending,
+            fees_burned,
+            fees_to_house,
+            ..
+        } = child;
+        Speculation {
+            result,
+            reads: view.reads.into_inner().expect("reads lock poisoned"),
+            missing: view.missing.into_inner().expect("missing lock poisoned"),
+            writes: pending,
+            fees_burned,
+            fees_to_house,
+        }
+    }
+
+    /// Run the transactions of one group in order against `snapshot`.
+    fn speculate_group(
+        &self,
+        snapshot: &BTreeMap<Key, Option<Value>>,
+        group: &[usize],
+        transactions: &[Transaction],
+    ) -> Vec<Speculation> {
+        let mut writes = BTreeMap::new();
+        let mut speculations = Vec::with_capacity(group.len());
+        for &index in group {
+            let speculation = self.speculate(snapshot, &writes, &transactions[index]);
+            writes.extend(speculation.writes.clone());
+            speculations.push(speculation);
+        }
+        speculations
+    }
+
+    /// Run `tx` on a child layer over the committed state of this layer.
+    async fn rerun(&self, tx: &Transaction) -> Speculation {
+        let mut child = Layer {
+            state: self,
+            pending: BTreeMap::new(),
+
+            seed: self.seed.clone(),
+            seed_view: self.seed_view,
+
+            multisig_roles: 0,
+            write_count: 0,
+
+            fees_burned: 0,
+            fees_to_house: 0,
+        };
+        let result = child.execute_transaction(tx).await;
+        let Layer {
+            pending,
+            fees_burned,
+            fees_to_house,
+            ..
+        } = child;
+        Speculation {
+            result,
+            reads: BTreeSet::new(),
+            missing: BTreeSet::new(),
+            writes: pending,
+            fees_burned,
+            fees_to_house,
+        }
+    }
+
+    /// Execute `transactions` on `pool`, producing the same results as running them in order.
+    pub(super) async fn execute_parallel(
+        &mut self,
+        pool: &ThreadPool,
+        transactions: &[Transaction],
+    ) -> Result<Vec<Option<Vec<Event>>>> {
+        let (groups, group_of) = group_transactions(transactions);
+        let mut results = Vec::with_capacity(transactions.len());
+        if groups.len() <= 1 {
+            for tx in transactions {
+                results.push(self.execute_transaction(tx).await?);
+            }
+            return Ok(results);
+        }
+
+        // Speculate every group against the state as of the start of the block, fetching the
+        // keys it needs here (storage may rely on the runtime) and re-running groups that missed.
+        let mut snapshot: BTreeMap<Key, Option<Value>> = BTreeMap::new();
+        let mut wanted: BTreeSet<Key> = transactions.iter().flat_map(prefetch_keys).collect();
+        let mut speculations: Vec<Vec<Speculation>> = groups.iter().map(|_| Vec::new()).collect();
+        let mut incomplete: Vec<usize> = (0..groups.len()).collect();
+        for _ in 0..MAX_PREFETCH_ROUNDS {
+            for key in std::mem::take(&mut wanted) {
+                let value = self.get(key.clone()).await?;
+                snapshot.insert(key, value);
+            }
+
+            let layer = &*self;
+            let snapshot = &snapshot;
+            let rerun: Vec<Vec<Speculation>> = pool.install(|| {
+                incomplete
+                    .par_iter()
+                    .map(|&group| layer.speculate_group(snapshot, &groups[group], transactions))
+                    .collect()
+            });
+
+            let mut still_incomplete = Vec::new();
+            for (group, group_speculations) in incomplete.into_iter().zip(rerun) {
+                let missing: BTreeSet<Key> = group_speculations
+                    .iter()
+                    .flat_map(|speculation| speculation.missing.iter().cloned())
+                    .collect();
+                if !missing.is_empty() {
+                    wanted.extend(missing);
+                    still_incomplete.push(group);
+                }
+                speculations[group] = group_speculations;
+            }
+            incomplete = still_incomplete;
+            if incomplete.is_empty() {
+                break;
+            }
+        }
+        let mut speculations: Vec<_> = speculations.into_iter().map(Vec::into_iter).collect();
+
+        // Commit in block order. `None` marks keys last written by a re-run transaction.
+        let mut last_writer: BTreeMap<Key, Option<usize>> = BTreeMap::new();
+        let mut stale = vec![false; groups.len()];
+        for (tx, &group) in transactions.iter().zip(&group_of) {
+            let speculation = speculations[group]
+                .next()
+                .expect("one speculation per transaction");
+            let valid = !stale[group]
+                && speculation.missing.is_empty()
+                && speculation
+                    .reads
+                    .iter()
+                    .all(|key| match last_writer.get(key) {
+                        Some(writer) => *writer == Some(group),
+                        None => true,
+                    });
+            let (speculation, writer) = if valid {
+                (speculation, Some(group))
+            } else {
+                // Later transactions of this group built on the discarded result.
+                stale[group] = true;
+                (self.rerun(tx).await, None)
+            };
+
+            results.push(speculation.result?);
+            for key in speculation.writes.keys() {
+                last_writer.insert(key.clone(), writer);
+            }
+            self.pending.extend(speculation.writes);
+            self.fees_burned = self.fees_burned.saturating_add(speculation.fees_burned);
+            self.fees_to_house = self.fees_to_house.saturating_add(speculation.fees_to_house);
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocks::{
+        create_account_keypair, create_adbs_with_pool_result, create_network_keypair, create_seed,
+    };
+    use crate::state::Memory;
+    use commonware_runtime::{deterministic, tokio, Metrics as _, Runner as _};
+    use commonware_utils::NZUsize;
+    use nullspace_types::casino::GameType;
+
+    const TEST_NAMESPACE: &[u8] = b"test-namespace";
+
+    #[test]
+    fn test_group_transactions() {
+        let (alice, _) = create_account_keypair(1);
+        let (bob, _) = create_account_keypair(2);
+        let (carol, _) = create_account_keypair(3);
+        let transactions = vec![
+            Transaction::sign(&alice, 0, Instruction::CasinoDeposit { amount: 10 }),
+            Transaction::sign(&bob, 0, Instruction::CasinoDeposit { amount: 10 }),
+            Transaction::sign(&alice, 1, Instruction::CasinoDeposit { amount: 10 }),
+            Transaction::sign(
+                &carol,
+                0,
+                Instruction::AddLiquidity {
+                    rng_amount: 1,
+                    usdt_amount: 1,
+                },
+            ),
+            Transaction::sign(
+                &bob,
+                1,
+                Instruction::Swap {
+                    amount_in: 1,
+                    min_amount_out: 0,
+                    is_buying_rng: true,
+                },
+            ),
+        ];
+
+        let (groups, group_of) = group_transactions(&transactions);
+        assert_eq!(groups, vec![vec![0, 2], vec![1, 3, 4]]);
+        assert_eq!(group_of, vec![0, 1, 0, 1, 1]);
+    }
+
+    /// Register the players used by [`assert_parallel_matches_sequential`].
+    async fn register_players<S: State>(state: &mut S) {
+        let (network_secret, master_public) = create_network_keypair();
+        let signers: Vec<_> = (1..=6).map(create_account_keypair).collect();
+
+        let mut layer = Layer::new(
+            &*state,
+            master_public,
+            TEST_NAMESPACE,
+            create_seed(&network_secret, 1),
+        );
+        for (index, (signer, _)) in signers.iter().enumerate() {
+            let tx = Transaction::sign(
+                signer,
+                0,
+                Instruction::CasinoRegister {
+                    name: format!("player-{index}"),
+                },
+            );
+            assert!(layer.execute_transaction(&tx).await.unwrap().is_some());
+        }
+        let changes = layer.commit();
+        state.apply(changes).await.unwrap();
+    }
+
+    /// Execute a block of games and shared keys both ways on `state` and check the outcomes
+    /// are identical.
+    async fn assert_parallel_matches_sequential<S: State>(state: &S) {
+        let (network_secret, master_public) = create_network_keypair();
+        let signers: Vec<_> = (1..=6).map(create_account_keypair).collect();
+
+        let mut transactions = Vec::new();
+        for (index, (signer, _)) in signers.iter().enumerate() {
+            let session_id = index as u64 + 1;
+            transactions.push(Transaction::sign_with_fee(
+                signer,
+                1,
+                Instruction::CasinoStartGame {
+                    game_type: GameType::Blackjack,
+                    bet: 10,
+                    session_id,
+                },
+                2,
+            ));
+            transactions.push(Transaction::sign(
+                signer,
+                2,
+                Instruction::CasinoGameMove {
+                    session_id,
+                    payload: vec![1],
+                },
+            ));
+            // Stale nonce: dropped in both modes.
+            transactions.push(Transaction::sign(
+                signer,
+                1,
+                Instruction::CasinoDeposit { amount: 5 },
+            ));
+        }
+
+        let seed = create_seed(&network_secret, 2);
+        let mut sequential = Layer::new(state, master_public, TEST_NAMESPACE, seed.clone());
+        let mut expected = Vec::new();
+        for tx in &transactions {
+            expected.push(sequential.execute_transaction(tx).await.unwrap());
+        }
+
+        let pool = ThreadPool::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(4)
+                .build()
+                .expect("failed to create execution pool"),
+        );
+        let mut parallel = Layer::new(state, master_public, TEST_NAMESPACE, seed);
+        let results = parallel
+            .execute_parallel(&pool, &transactions)
+            .await
+            .unwrap();
+
+        assert_eq!(results, expected);
+        assert_eq!(parallel.fees_burned, sequential.fees_burned);
+        assert_eq!(parallel.fees_to_house, sequential.fees_to_house);
+        assert!(parallel.commit() == sequential.commit());
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let executor = deterministic::Runner::default();
+        executor.start(|_| async move {
+            let mut state = Memory::default();
+            register_players(&mut state).await;
+            assert_parallel_matches_sequential(&state).await;
+        });
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential_on_adb() {
+        // Reads from disk go through the runtime's blocking pool, which is not reachable from
+        // the execution thread pool.
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let (mut state, _) = create_adbs_with_pool_result(&context, NZUsize!(1))
+                .await
+                .unwrap();
+            register_players(&mut state).await;
+            state.commit(None).await.unwrap();
+            state.close().await.unwrap();
+
+            // Reopen with a single cached page so speculation needs data that is only on disk.
+            let (state, _) =
+                create_adbs_with_pool_result(&context.with_label("reopened"), NZUsize!(1))
+                    .await
+                    .unwrap();
+            assert_parallel_matches_sequential(&state).await;
+        });
+    }
+}
@@ -18,8 +18,8 @@
 //! ```rust,ignore
 //! # #[cfg(feature = "mocks")]
 //! # {
-//! use nullspace_execution::state_transition::execute_state_transition;
-//! use nullspace_types::{Identity, NAMESPACE};
+//! use nullspace_execution::state_transition::{execute_state_transition, ChainContext};
+//! use nullspace_types::{execution::GenesisConfig, Identity, NAMESPACE};
 //! use nullspace_execution::mocks::{create_network_keypair, create_seed};
 //!
 //! # async fn example(
@@ -35,7 +35,10 @@
 //! let _result = execute_state_transition(
 //!     state,
 //!     events,
-//!     identity,
+//!     ChainContext {
+//!         identity,
+//!         genesis: &GenesisConfig::default(),
+//!     },
 //!     /* height */ 1,
 //!     /* seed */ seed,
 //!     /* transactions */ vec![],
@@ -35,10 +35,11 @@ use commonware_utils::{union, NZUsize, NZU64};
 use commonware_math::algebra::Random;
 use nullspace_types::{
     api::Summary,
-    execution::{Output, Progress, Seed, Transaction, Value},
+    execution::{GenesisConfig, Output, Progress, Seed, Transaction, Value},
     Identity, NAMESPACE,
 };
 use rand::{rngs::StdRng, SeedableRng};
+use std::num::NonZeroUsize;
 
 type EventsDb<E> = keyless::Keyless<E, Output, Sha256, Clean<Digest>>;
 
@@ -82,10 +83,16 @@ pub fn create_seed(network_secret: &Private, view: u64) -> Seed {
 pub async fn create_adbs_result<E: Spawner + Metrics + Storage + Clock>(
     context: &E,
 ) -> anyhow::Result<(Adb<E, EightCap>, EventsDb<E>)> {
-    let buffer_pool = PoolRef::new(
-        NZUsize!(TEST_BUFFER_POOL_PAGES),
-        NZUsize!(TEST_BUFFER_POOL_PAGE_SIZE),
-    );
+    create_adbs_with_pool_result(context, NZUsize!(TEST_BUFFER_POOL_PAGES)).await
+}
+
+/// Creates state and events databases for testing whose buffer pool caches at most
+/// `pool_pages` pages, so that reads beyond it go to storage.
+pub async fn create_adbs_with_pool_result<E: Spawner + Metrics + Storage + Clock>(
+    context: &E,
+    pool_pages: NonZeroUsize,
+) -> anyhow::Result<(Adb<E, EightCap>, EventsDb<E>)> {
+    let buffer_pool = PoolRef::new(pool_pages, NZUsize!(TEST_BUFFER_POOL_PAGE_SIZE));
 
     let state = Adb::init(
         context.with_label("state"),
@@ -172,7 +179,10 @@ pub async fn execute_block_result<E: Spawner + Metrics + Storage + Clock>(
     let result = state_transition::execute_state_transition(
         state,
         events,
-        network_identity,
+        state_transition::ChainContext {
+            identity: network_identity,
+            genesis: &GenesisConfig::default(),
+        },
         height,
         seed.clone(),
         txs,
@@ -606,6 +616,7 @@ mod tests {
                 .execute(
                     #[cfg(feature = "parallel")]
                     pool.clone(),
+                    &GenesisConfig::default(),
                     vec![tx.clone()],
                 )
                 .await
@@ -628,7 +639,10 @@ mod tests {
             let result = state_transition::execute_state_transition(
                 &mut state,
                 &mut events,
-                network_identity,
+                state_transition::ChainContext {
+                    identity: network_identity,
+                    genesis: &GenesisConfig::default(),
+                },
                 height,
                 seed,
                 vec![tx],
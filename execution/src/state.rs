@@ -48,6 +48,8 @@ where
 #[derive(Debug)]
 pub enum PrepareError {
     NonceMismatch { expected: u64, got: u64 },
+    InsufficientFee { required: u64, available: u64 },
+    Expired { expires_at_view: u64, view: u64 },
     State(anyhow::Error),
 }
 
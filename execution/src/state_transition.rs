@@ -15,7 +15,7 @@ use commonware_storage::qmdb::keyless;
 use commonware_storage::qmdb::store::CleanStore as _;
 use commonware_storage::translator::Translator;
 use nullspace_types::{
-    execution::{Key, Output, Seed, Transaction, Value},
+    execution::{GenesisConfig, Key, Output, Seed, Transaction, Value},
     Identity, NAMESPACE,
 };
 use std::collections::BTreeMap;
@@ -64,6 +64,15 @@ pub struct StateTransitionResult {
     pub processed_nonces: BTreeMap<PublicKey, u64>,
 }
 
+/// Chain-wide inputs to [`execute_state_transition`] that do not change between blocks.
+#[derive(Clone, Copy)]
+pub struct ChainContext<'a> {
+    /// Network identity the block seeds are verified against.
+    pub identity: Identity,
+    /// Genesis configuration (seeds the admin registry on first use).
+    pub genesis: &'a GenesisConfig,
+}
+
 /// Execute state transition for a block
 ///
 /// This function processes all transactions in a block, updating both state and events
@@ -75,7 +84,7 @@ pub struct StateTransitionResult {
 pub async fn execute_state_transition<S, T>(
     state: &mut Adb<S, T>,
     events: &mut EventsDb<S>,
-    identity: Identity,
+    chain: ChainContext<'_>,
     height: u64,
     seed: Seed,
     transactions: Vec<Transaction>,
@@ -144,11 +153,12 @@ where
 
             let (outputs, nonces, changes) = {
                 let state_view = StateView::new(state);
-                let mut layer = Layer::new(&state_view, identity, NAMESPACE, seed);
+                let mut layer = Layer::new(&state_view, chain.identity, NAMESPACE, seed);
                 let (outputs, nonces) = layer
                     .execute(
                         #[cfg(feature = "parallel")]
                         pool,
+                        chain.genesis,
                         transactions,
                     )
                     .await
@@ -215,11 +225,12 @@ where
 
             let (outputs, nonces, changes) = {
                 let state_view = StateView::new(state);
-                let mut layer = Layer::new(&state_view, identity, NAMESPACE, seed);
+                let mut layer = Layer::new(&state_view, chain.identity, NAMESPACE, seed);
                 let (outputs, nonces) = layer
                     .execute(
                         #[cfg(feature = "parallel")]
                         pool,
+                        chain.genesis,
                         transactions,
                     )
                     .await
@@ -14,6 +14,12 @@ import {
 // CRITICAL: Must match TRANSACTION_NAMESPACE in Rust (b"_NULLSPACE_TX")
 export const TRANSACTION_NAMESPACE = new TextEncoder().encode('_NULLSPACE_TX');
 
+// Transaction extension block (matching TRANSACTION_EXTENSIONS_FLAG / TRANSACTION_EXT_* in Rust)
+// When the flag is set on the encoded nonce, [extensions:u8] and the flagged fields follow it.
+export const TRANSACTION_EXTENSIONS_FLAG = 1n << 63n;
+export const TRANSACTION_EXT_FEE = 0x01;
+export const TRANSACTION_EXT_EXPIRY = 0x02;
+
 // Instruction tags (matching types/src/execution.rs)
 export const InstructionTag = {
   CasinoRegister: 10,
@@ -31,6 +37,9 @@ export const InstructionTag = {
   GlobalTableReveal: 64,
   GlobalTableSettle: 65,
   GlobalTableFinalize: 66,
+  GlobalTableCancelBets: 67,
+  GlobalTableReplaceBets: 68,
+  GlobalTableCashOut: 69,
 } as const;
 
 // Submission tags (matching types/src/api.rs)
@@ -94,6 +103,11 @@ export const RouletteBetType = {
   Street: ROULETTE_BET_TYPES.STREET,      // 3-number row (11:1) - number is row start (1,4,...,34)
   Corner: ROULETTE_BET_TYPES.CORNER,      // 4-number corner (8:1) - number is top-left (1-32)
   SixLine: ROULETTE_BET_TYPES.SIX_LINE,   // 6 numbers (5:1) - number is row start (1,4,...,31)
+  Voisins: ROULETTE_BET_TYPES.VOISINS,    // Voisins du Zero (9 chips) - number = 0
+  Tiers: ROULETTE_BET_TYPES.TIERS,        // Tiers du Cylindre (6 chips) - number = 0
+  Orphelins: ROULETTE_BET_TYPES.ORPHELINS, // Orphelins (5 chips) - number = 0
+  JeuZero: ROULETTE_BET_TYPES.JEU_ZERO,   // Jeu Zero (4 chips) - number = 0
+  Neighbours: ROULETTE_BET_TYPES.NEIGHBOURS, // 2n+1 straights - number = [n-1:2][center:6]
 } as const;
 
 export type RouletteBetTypeValue = typeof RouletteBetType[keyof typeof RouletteBetType];
@@ -140,6 +154,18 @@ export const CrapsBet = {
   RideLine: CRAPS_BET_TYPES.RIDE_LINE,
   Replay: CRAPS_BET_TYPES.REPLAY,
   HotRoller: CRAPS_BET_TYPES.HOT_ROLLER,
+  AnySeven: CRAPS_BET_TYPES.ANY_SEVEN,
+  AnyCraps: CRAPS_BET_TYPES.ANY_CRAPS,
+  Horn: CRAPS_BET_TYPES.HORN,
+  CAndE: CRAPS_BET_TYPES.C_AND_E,
+  World: CRAPS_BET_TYPES.WORLD,
+  Big6: CRAPS_BET_TYPES.BIG_6,
+  Big8: CRAPS_BET_TYPES.BIG_8,
+  Buy: CRAPS_BET_TYPES.BUY,
+  Lay: CRAPS_BET_TYPES.LAY,
+  BuyVigUpFront: CRAPS_BET_TYPES.BUY_VIG_UP_FRONT,
+  LayVigUpFront: CRAPS_BET_TYPES.LAY_VIG_UP_FRONT,
+  Put: CRAPS_BET_TYPES.PUT,
 } as const;
 
 export type CrapsBet = typeof CrapsBet[keyof typeof CrapsBet];
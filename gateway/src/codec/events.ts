@@ -8,6 +8,11 @@
  * the gateway Update/FilteredEvents parsing is unique to this module.
  */
 import { logDebug, logError, logWarn } from '../logger.js';
+import {
+  TRANSACTION_EXT_EXPIRY,
+  TRANSACTION_EXT_FEE,
+  TRANSACTION_EXTENSIONS_FLAG,
+} from './constants.js';
 
 /**
  * Event tags matching Rust nullspace_types::execution::tags::event
@@ -25,6 +30,12 @@ export const EVENT_TAGS = {
   CASINO_ERROR: 29,
   CASINO_DEPOSITED: 41,
   PLAYER_MODIFIER_TOGGLED: 42,
+  TOURNAMENT_CREATED: 78,
+  TOURNAMENT_BUY_IN: 79,
+  TOURNAMENT_CANCELLED: 80,
+  TOURNAMENT_PRIZES_PAID: 81,
+  TOURNAMENT_TOP_UP: 82,
+  CASINO_SESSIONS_PRUNED: 84,
 } as const;
 
 export const GLOBAL_TABLE_EVENT_TAGS = {
@@ -35,10 +46,16 @@ export const GLOBAL_TABLE_EVENT_TAGS = {
   GLOBAL_TABLE_OUTCOME: 64,
   GLOBAL_TABLE_PLAYER_SETTLED: 65,
   GLOBAL_TABLE_FINALIZED: 66,
+  GLOBAL_TABLE_BETS_CANCELLED: 67,
+  GLOBAL_TABLE_BETS_REPLACED: 68,
+  GLOBAL_TABLE_ROLLING: 76,
+  GLOBAL_TABLE_CASHED_OUT: 77,
 } as const;
 
-const GLOBAL_TABLE_GAME_TYPE_CRAPS = 3;
-const GLOBAL_TABLE_MAX_TOTALS = 64;
+// Baccarat, Craps, Roulette, Sic Bo, Big Six, Crash
+const GLOBAL_TABLE_GAME_TYPES = new Set([0, 3, 6, 7, 13, 14]);
+const GLOBAL_TABLE_MAX_OUTCOME = 8;
+const GLOBAL_TABLE_MAX_TOTALS = 128;
 const GLOBAL_TABLE_MAX_BETS = 64;
 const GLOBAL_TABLE_VALUE_TAG = 30;
 const STATE_OP_UPDATE_CONTEXT = 0xD2;
@@ -99,6 +116,7 @@ export interface GlobalTableRound {
   rngCommit: Uint8Array;
   rollSeed: Uint8Array;
   totals: GlobalTableTotal[];
+  outcome: Uint8Array;
 }
 
 export type GlobalTableEvent =
@@ -126,6 +144,18 @@ export type GlobalTableEvent =
       roundId: bigint;
       phaseEndsAtMs: bigint;
     }
+  | {
+      type: 'rolling';
+      gameType: number;
+      roundId: bigint;
+      phaseEndsAtMs: bigint;
+    }
+  | {
+      type: 'cashed_out';
+      player: Uint8Array;
+      roundId: bigint;
+      multiplier: number;
+    }
   | {
       type: 'outcome';
       round: GlobalTableRound;
@@ -142,6 +172,21 @@ export type GlobalTableEvent =
       type: 'finalized';
       gameType: number;
       roundId: bigint;
+    }
+  | {
+      type: 'bets_cancelled';
+      player: Uint8Array;
+      roundId: bigint;
+      bets: GlobalTableBet[];
+      balanceSnapshot?: { chips: bigint; vusdt: bigint; rng: bigint };
+    }
+  | {
+      type: 'bets_replaced';
+      player: Uint8Array;
+      roundId: bigint;
+      cancelled: GlobalTableBet[];
+      bets: GlobalTableBet[];
+      balanceSnapshot?: { chips: bigint; vusdt: bigint; rng: bigint };
     };
 
 /**
@@ -510,6 +555,8 @@ function skipPolicyState(reader: BinaryReader): void {
   reader.readBool(); // oracle_enabled
   reader.readU16BE(); // oracle_max_deviation_bps
   reader.readU64BE(); // oracle_stale_secs
+  reader.readU16BE(); // transaction_fee_burn_bps
+  reader.readU64BE(); // casino_session_retention_views
 }
 
 function skipTreasuryState(reader: BinaryReader): void {
@@ -565,11 +612,16 @@ function skipInstruction(reader: BinaryReader): void {
     case 16: // CasinoJoinTournament
       reader.readU64BE();
       return;
-    case 17: // CasinoStartTournament
+    case 17: { // CasinoStartTournament
       reader.readU64BE();
       reader.readU64BE();
       reader.readU64BE();
+      const placesLen = reader.readVarint(); // payout structure
+      for (let i = 0; i < placesLen; i += 1) {
+        reader.readU16BE();
+      }
       return;
+    }
     case 18: // Stake
       reader.readU64BE();
       reader.readU64BE();
@@ -656,13 +708,52 @@ function skipInstruction(reader: BinaryReader): void {
       reader.readU64BE();
       reader.readVec();
       return;
+    case 47: // GrantAdminRole
+    case 48: // RevokeAdminRole
+      reader.readPublicKey();
+      reader.readU8(); // role
+      return;
+    case 49: { // CreateMultisig
+      const signersLen = reader.readVarint();
+      for (let i = 0; i < signersLen; i += 1) {
+        reader.readPublicKey();
+      }
+      reader.readU8(); // threshold
+      reader.readU64BE(); // timelock_secs
+      reader.readU8(); // roles
+      return;
+    }
+    case 50: // ProposeMultisig
+      reader.readU64BE();
+      skipInstruction(reader);
+      return;
+    case 51: // ApproveMultisig
+    case 52: // ExecuteMultisig
+      reader.readU64BE();
+      reader.readU64BE();
+      return;
+    case 54: // CasinoCreateTournament
+      reader.readU64BE();
+      skipTournamentConfig(reader);
+      return;
+    case 55: // CasinoCancelTournament
+      reader.readU64BE();
+      return;
+    case 56: // CasinoTournamentTopUp
+      reader.readU64BE();
+      reader.readU8(); // kind
+      return;
+    case 57: // DissolveMultisig
+      reader.readU64BE();
+      return;
     case 60: // GlobalTableInit
       skipGlobalTableConfig(reader);
       return;
     case 61: // GlobalTableOpenRound
       reader.readU8();
       return;
-    case 62: { // GlobalTableSubmitBets
+    case 62: // GlobalTableSubmitBets
+    case 68: { // GlobalTableReplaceBets
       reader.readU8();
       reader.readU64BE();
       const betsLen = reader.readVarint();
@@ -677,6 +768,8 @@ function skipInstruction(reader: BinaryReader): void {
     case 64: // GlobalTableReveal
     case 65: // GlobalTableSettle
     case 66: // GlobalTableFinalize
+    case 67: // GlobalTableCancelBets
+    case 69: // GlobalTableCashOut
       reader.readU8();
       reader.readU64BE();
       return;
@@ -686,7 +779,16 @@ function skipInstruction(reader: BinaryReader): void {
 }
 
 function skipTransaction(reader: BinaryReader): void {
-  reader.readU64BE(); // nonce
+  const nonce = reader.readU64BE();
+  if (nonce & TRANSACTION_EXTENSIONS_FLAG) {
+    const extensions = reader.readU8();
+    if (extensions & TRANSACTION_EXT_FEE) {
+      reader.readU64BE(); // fee
+    }
+    if (extensions & TRANSACTION_EXT_EXPIRY) {
+      reader.readU64BE(); // expires_at_view
+    }
+  }
   skipInstruction(reader);
   reader.readPublicKey(); // public key
   reader.readBytes(ED25519_SIGNATURE_SIZE); // signature
@@ -702,6 +804,54 @@ function skipCasinoLeaderboard(reader: BinaryReader): void {
   }
 }
 
+function skipTournamentConfig(reader: BinaryReader): void {
+  reader.readU8(); // currency
+  reader.readU64BE(); // buy_in
+  reader.readU16BE(); // rake_bps
+  reader.readU64BE(); // guarantee
+  reader.readU32BE(); // max_seats
+  reader.readU64BE(); // duration_secs
+  skipTopUpRule(reader); // rebuy
+  skipTopUpRule(reader); // add_on
+  const allowedGamesLen = reader.readVarint();
+  for (let i = 0; i < allowedGamesLen; i += 1) {
+    reader.readU8(); // game_type
+  }
+  const betLimitsLen = reader.readVarint();
+  for (let i = 0; i < betLimitsLen; i += 1) {
+    reader.readU8(); // game_type
+    reader.readU64BE(); // min_bet
+    reader.readU64BE(); // max_bet
+  }
+  reader.readU64BE(); // starting chips
+  reader.readU32BE(); // starting shields
+  reader.readU32BE(); // starting doubles
+  const payoutPlaces = reader.readVarint();
+  for (let i = 0; i < payoutPlaces; i += 1) {
+    reader.readU16BE(); // payout share bps
+  }
+}
+
+function skipTopUpRule(reader: BinaryReader): void {
+  reader.readU8(); // max_per_player
+  reader.readU64BE(); // window_secs
+  reader.readU64BE(); // cost
+  reader.readU64BE(); // chips
+  reader.readU32BE(); // shields
+  reader.readU32BE(); // doubles
+}
+
+function skipPlayerBalanceSnapshot(reader: BinaryReader): void {
+  reader.readU64BE(); // chips
+  reader.readU64BE(); // vusdt_balance
+  reader.readU32BE(); // shields
+  reader.readU32BE(); // doubles
+  reader.readU64BE(); // tournament_chips
+  reader.readU32BE(); // tournament_shields
+  reader.readU32BE(); // tournament_doubles
+  reader.readOptionU64BE(); // active_tournament
+}
+
 function skipTournamentRankings(reader: BinaryReader): void {
   const count = reader.readVarint();
   for (let i = 0; i < count; i += 1) {
@@ -747,6 +897,45 @@ function skipEventByTag(reader: BinaryReader, tag: number): void {
       reader.readU64BE();
       skipTournamentRankings(reader);
       return;
+    case EVENT_TAGS.TOURNAMENT_CREATED:
+      reader.readU64BE();
+      skipTournamentConfig(reader);
+      return;
+    case EVENT_TAGS.TOURNAMENT_BUY_IN:
+      reader.readU64BE();
+      reader.readPublicKey();
+      reader.readU64BE(); // amount
+      reader.readU64BE(); // rake
+      reader.readU64BE(); // prize_pool
+      skipPlayerBalanceSnapshot(reader);
+      return;
+    case EVENT_TAGS.TOURNAMENT_CANCELLED:
+      reader.readU64BE();
+      skipTournamentRankings(reader); // refunds
+      return;
+    case EVENT_TAGS.TOURNAMENT_PRIZES_PAID:
+      reader.readU64BE();
+      reader.readU8(); // currency
+      skipTournamentRankings(reader); // payouts
+      return;
+    case EVENT_TAGS.TOURNAMENT_TOP_UP:
+      reader.readU64BE();
+      reader.readPublicKey();
+      reader.readU8(); // kind
+      reader.readU64BE(); // cost
+      reader.readU64BE(); // prize_pool
+      reader.readU8(); // rebuys
+      reader.readU8(); // add_ons
+      skipPlayerBalanceSnapshot(reader);
+      return;
+    case EVENT_TAGS.CASINO_SESSIONS_PRUNED: {
+      reader.readPublicKey();
+      const count = reader.readVarint();
+      for (let i = 0; i < count; i += 1) {
+        reader.readU64BE(); // session_id
+      }
+      return;
+    }
     default:
       throw new Error(`Unknown event tag ${tag}`);
   }
@@ -813,6 +1002,20 @@ function parseGlobalTableEventWithTag(
       };
     case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_OUTCOME:
       return { type: 'outcome', round: parseGlobalTableRound(reader) };
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_ROLLING:
+      return {
+        type: 'rolling',
+        gameType: reader.readU8(),
+        roundId: reader.readU64BE(),
+        phaseEndsAtMs: reader.readU64BE(),
+      };
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_CASHED_OUT:
+      return {
+        type: 'cashed_out',
+        player: reader.readPublicKey(),
+        roundId: reader.readU64BE(),
+        multiplier: reader.readU32BE(),
+      };
     case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_PLAYER_SETTLED: {
       const player = reader.readPublicKey();
       const roundId = reader.readU64BE();
@@ -838,6 +1041,34 @@ function parseGlobalTableEventWithTag(
         gameType: reader.readU8(),
         roundId: reader.readU64BE(),
       };
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_BETS_CANCELLED: {
+      const player = reader.readPublicKey();
+      const roundId = reader.readU64BE();
+      const bets = readGlobalTableBets(reader);
+      const balanceSnapshot = reader.readPlayerBalanceSnapshot();
+      return {
+        type: 'bets_cancelled',
+        player,
+        roundId,
+        bets,
+        balanceSnapshot,
+      };
+    }
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_BETS_REPLACED: {
+      const player = reader.readPublicKey();
+      const roundId = reader.readU64BE();
+      const cancelled = readGlobalTableBets(reader);
+      const bets = readGlobalTableBets(reader);
+      const balanceSnapshot = reader.readPlayerBalanceSnapshot();
+      return {
+        type: 'bets_replaced',
+        player,
+        roundId,
+        cancelled,
+        bets,
+        balanceSnapshot,
+      };
+    }
     default:
       return null;
   }
@@ -1020,6 +1251,15 @@ function decodeEvents(
   return { casino, global };
 }
 
+function readGlobalTableBets(reader: BinaryReader): GlobalTableBet[] {
+  const len = reader.readVarint();
+  const bets: GlobalTableBet[] = [];
+  for (let i = 0; i < len; i += 1) {
+    bets.push(readGlobalTableBet(reader));
+  }
+  return bets;
+}
+
 function readGlobalTableBet(reader: BinaryReader): GlobalTableBet {
   return {
     betType: reader.readU8(),
@@ -1054,6 +1294,7 @@ function parseGlobalTableRound(reader: BinaryReader): GlobalTableRound {
   for (let i = 0; i < totalsLen; i += 1) {
     totals.push(readGlobalTableTotal(reader));
   }
+  const outcome = reader.readVec();
 
   return {
     gameType,
@@ -1069,6 +1310,7 @@ function parseGlobalTableRound(reader: BinaryReader): GlobalTableRound {
     rngCommit,
     rollSeed,
     totals,
+    outcome,
   };
 }
 
@@ -1117,6 +1359,20 @@ function parseGlobalTableEvent(reader: BinaryReader): GlobalTableEvent | null {
       };
     case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_OUTCOME:
       return { type: 'outcome', round: parseGlobalTableRound(reader) };
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_ROLLING:
+      return {
+        type: 'rolling',
+        gameType: reader.readU8(),
+        roundId: reader.readU64BE(),
+        phaseEndsAtMs: reader.readU64BE(),
+      };
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_CASHED_OUT:
+      return {
+        type: 'cashed_out',
+        player: reader.readPublicKey(),
+        roundId: reader.readU64BE(),
+        multiplier: reader.readU32BE(),
+      };
     case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_PLAYER_SETTLED: {
       const player = reader.readPublicKey();
       const roundId = reader.readU64BE();
@@ -1142,6 +1398,34 @@ function parseGlobalTableEvent(reader: BinaryReader): GlobalTableEvent | null {
         gameType: reader.readU8(),
         roundId: reader.readU64BE(),
       };
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_BETS_CANCELLED: {
+      const player = reader.readPublicKey();
+      const roundId = reader.readU64BE();
+      const bets = readGlobalTableBets(reader);
+      const balanceSnapshot = reader.readPlayerBalanceSnapshot();
+      return {
+        type: 'bets_cancelled',
+        player,
+        roundId,
+        bets,
+        balanceSnapshot,
+      };
+    }
+    case GLOBAL_TABLE_EVENT_TAGS.GLOBAL_TABLE_BETS_REPLACED: {
+      const player = reader.readPublicKey();
+      const roundId = reader.readU64BE();
+      const cancelled = readGlobalTableBets(reader);
+      const bets = readGlobalTableBets(reader);
+      const balanceSnapshot = reader.readPlayerBalanceSnapshot();
+      return {
+        type: 'bets_replaced',
+        player,
+        roundId,
+        cancelled,
+        bets,
+        balanceSnapshot,
+      };
+    }
     default:
       return null;
   }
@@ -1389,14 +1673,24 @@ function validateGlobalTableEvent(event: GlobalTableEvent): boolean {
     case 'outcome':
       return validateGlobalTableRound(event.round);
     case 'locked':
+    case 'rolling':
     case 'finalized':
-      return event.roundId !== 0n && event.gameType === GLOBAL_TABLE_GAME_TYPE_CRAPS;
+      return event.roundId !== 0n && GLOBAL_TABLE_GAME_TYPES.has(event.gameType);
     case 'bet_accepted':
       return event.roundId !== 0n && event.bets.length <= GLOBAL_TABLE_MAX_BETS;
     case 'bet_rejected':
+    case 'cashed_out':
       return event.roundId !== 0n;
     case 'player_settled':
       return event.roundId !== 0n && event.myBets.length <= GLOBAL_TABLE_MAX_BETS;
+    case 'bets_cancelled':
+      return event.roundId !== 0n && event.bets.length <= GLOBAL_TABLE_MAX_BETS;
+    case 'bets_replaced':
+      return (
+        event.roundId !== 0n
+        && event.cancelled.length <= GLOBAL_TABLE_MAX_BETS
+        && event.bets.length <= GLOBAL_TABLE_MAX_BETS
+      );
     default:
       return false;
   }
@@ -1404,11 +1698,12 @@ function validateGlobalTableEvent(event: GlobalTableEvent): boolean {
 
 function validateGlobalTableRound(round: GlobalTableRound): boolean {
   if (round.roundId === 0n) return false;
-  if (round.gameType !== GLOBAL_TABLE_GAME_TYPE_CRAPS) return false;
+  if (!GLOBAL_TABLE_GAME_TYPES.has(round.gameType)) return false;
   if (round.phase < 0 || round.phase > 4) return false;
   if (round.totals.length > GLOBAL_TABLE_MAX_TOTALS) return false;
   if (round.rngCommit.length !== 0 && round.rngCommit.length !== 32) return false;
   if (round.rollSeed.length !== 0 && round.rollSeed.length !== 32) return false;
+  if (round.outcome.length > GLOBAL_TABLE_MAX_OUTCOME) return false;
   return true;
 }
 
@@ -1441,6 +1736,14 @@ export function parseGameLog(log: string): Record<string, unknown> | null {
     7: 'FOUR_OF_A_KIND',
     8: 'STRAIGHT_FLUSH',
     9: 'ROYAL_FLUSH',
+    10: 'FOUR_ACES',
+    11: 'FOUR_TWOS_THROUGH_FOURS',
+    12: 'FOUR_ACES_WITH_KICKER',
+    13: 'FOUR_TWOS_THROUGH_FOURS_WITH_KICKER',
+    14: 'FIVE_OF_A_KIND',
+    15: 'WILD_ROYAL_FLUSH',
+    16: 'FOUR_DEUCES',
+    17: 'KINGS_OR_BETTER',
   };
 
   const normalizeVideoPokerLog = (data: Record<string, unknown>): Record<string, unknown> => {
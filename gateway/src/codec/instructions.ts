@@ -173,13 +173,39 @@ export function encodeGlobalTableSubmitBets(
   gameType: GameType,
   roundId: bigint,
   bets: GlobalTableBetInput[]
+): Uint8Array {
+  return encodeGlobalTableBets(InstructionTag.GlobalTableSubmitBets, gameType, roundId, bets);
+}
+
+export function encodeGlobalTableReplaceBets(
+  gameType: GameType,
+  roundId: bigint,
+  bets: GlobalTableBetInput[]
+): Uint8Array {
+  return encodeGlobalTableBets(InstructionTag.GlobalTableReplaceBets, gameType, roundId, bets);
+}
+
+export function encodeGlobalTableCancelBets(gameType: GameType, roundId: bigint): Uint8Array {
+  const result = new Uint8Array(10);
+  const view = new DataView(result.buffer);
+  result[0] = InstructionTag.GlobalTableCancelBets;
+  result[1] = gameType;
+  view.setBigUint64(2, roundId, false);
+  return result;
+}
+
+function encodeGlobalTableBets(
+  tag: number,
+  gameType: GameType,
+  roundId: bigint,
+  bets: GlobalTableBetInput[]
 ): Uint8Array {
   const lenVarint = encodeVarint(bets.length);
   const result = new Uint8Array(1 + 1 + 8 + lenVarint.length + bets.length * 10);
   const view = new DataView(result.buffer);
 
   let offset = 0;
-  result[offset] = InstructionTag.GlobalTableSubmitBets;
+  result[offset] = tag;
   offset += 1;
   result[offset] = gameType;
   offset += 1;
@@ -233,3 +259,12 @@ export function encodeGlobalTableFinalize(gameType: GameType, roundId: bigint):
   view.setBigUint64(2, roundId, false);
   return result;
 }
+
+export function encodeGlobalTableCashOut(gameType: GameType, roundId: bigint): Uint8Array {
+  const result = new Uint8Array(10);
+  const view = new DataView(result.buffer);
+  result[0] = InstructionTag.GlobalTableCashOut;
+  result[1] = gameType;
+  view.setBigUint64(2, roundId, false);
+  return result;
+}
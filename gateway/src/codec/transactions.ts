@@ -9,7 +9,13 @@
  */
 import { ed25519 } from '@noble/curves/ed25519';
 import { sha256 } from '@noble/hashes/sha256';
-import { TRANSACTION_NAMESPACE, SubmissionTag } from './constants.js';
+import {
+  TRANSACTION_EXT_EXPIRY,
+  TRANSACTION_EXT_FEE,
+  TRANSACTION_EXTENSIONS_FLAG,
+  TRANSACTION_NAMESPACE,
+  SubmissionTag,
+} from './constants.js';
 
 /**
  * Encode a number as a varint (protobuf-style variable-length integer)
@@ -67,20 +73,48 @@ function unionUnique(namespace: Uint8Array, message: Uint8Array): Uint8Array {
  * Transaction format:
  * [nonce:u64 BE] [instruction bytes] [pubkey:32] [signature:64]
  *
+ * With a non-zero fee or an expiry, the nonce carries TRANSACTION_EXTENSIONS_FLAG and an extension
+ * block follows it:
+ * [nonce|flag:u64 BE] [extensions:u8] [fee:u64 BE]? [expiresAtView:u64 BE]? [instruction bytes] ...
+ *
  * Signature covers (using union_unique format):
- * [varint(namespace.len)] [TRANSACTION_NAMESPACE] [nonce + instruction]
+ * [varint(namespace.len)] [TRANSACTION_NAMESPACE] [nonce + extensions + instruction]
  */
 export function buildTransaction(
   nonce: bigint,
   instruction: Uint8Array,
-  privateKey: Uint8Array
+  privateKey: Uint8Array,
+  fee: bigint = 0n,
+  expiresAtView?: bigint
 ): Uint8Array {
   const publicKey = ed25519.getPublicKey(privateKey);
 
-  // Build payload for signing: nonce (8 bytes BE) + instruction
-  const payload = new Uint8Array(8 + instruction.length);
-  new DataView(payload.buffer).setBigUint64(0, nonce, false);  // BE
-  payload.set(instruction, 8);
+  let extensions = 0;
+  if (fee > 0n) extensions |= TRANSACTION_EXT_FEE;
+  if (expiresAtView !== undefined) extensions |= TRANSACTION_EXT_EXPIRY;
+
+  // Build payload for signing: nonce (8 bytes BE) + optional extension block + instruction
+  let headerLen = 8;
+  if (extensions !== 0) headerLen += 1;
+  if (extensions & TRANSACTION_EXT_FEE) headerLen += 8;
+  if (extensions & TRANSACTION_EXT_EXPIRY) headerLen += 8;
+  const payload = new Uint8Array(headerLen + instruction.length);
+  const view = new DataView(payload.buffer);
+  if (extensions === 0) {
+    view.setBigUint64(0, nonce, false);  // BE
+  } else {
+    view.setBigUint64(0, nonce | TRANSACTION_EXTENSIONS_FLAG, false);  // BE
+    payload[8] = extensions;
+    let offset = 9;
+    if (extensions & TRANSACTION_EXT_FEE) {
+      view.setBigUint64(offset, fee, false);  // BE
+      offset += 8;
+    }
+    if (expiresAtView !== undefined) {
+      view.setBigUint64(offset, expiresAtView, false);  // BE
+    }
+  }
+  payload.set(instruction, headerLen);
 
   // Sign with union_unique format: [varint(namespace.len)] [namespace] [payload]
   // This matches how commonware-cryptography handles namespaced signing
@@ -1,7 +1,10 @@
 /**
  * Video Poker game handler
  *
- * Payload uses a hold mask; there is no move opcode for deal/draw.
+ * Multi-stage flow matching execution/src/casino/video_poker.rs:
+ * 1. CasinoStartGame → Betting stage
+ * 2. Deal move ([0xFF, rules]) → Deal stage (Jacks or Better, full pay)
+ * 3. Hold mask → Draw (complete)
  */
 import { GameHandler, type HandlerContext, type HandleResult } from './base.js';
 import { GameType } from '../codec/index.js';
@@ -51,7 +54,28 @@ export class VideoPokerHandler extends GameHandler {
       ctx.session.gameSessionCounter++
     );
 
-    return this.startGame(ctx, BigInt(amount), gameSessionId);
+    const startResult = await this.startGame(ctx, BigInt(amount), gameSessionId);
+    if (!startResult.success) {
+      return startResult;
+    }
+
+    const dealPayload = encodeGameActionPayload({ game: 'videopoker', action: 'deal' });
+    const dealResult = await this.makeMove(ctx, dealPayload);
+    if (!dealResult.success) {
+      return dealResult;
+    }
+
+    // Spread dealResult.response FIRST, then override type to ensure it's 'game_started'
+    return {
+      success: true,
+      response: {
+        ...(dealResult.response || {}),
+        type: 'game_started',
+        gameType: GameType.VideoPoker,
+        sessionId: ctx.session.activeGameId?.toString(),
+        bet: amount.toString(),
+      },
+    };
   }
 
   private async handleHold(
@@ -190,6 +190,30 @@ const betTypeToView = (
       return { type: 'REPLAY' };
     case 22:
       return { type: 'HOT_ROLLER' };
+    case 23:
+      return { type: 'ANY_SEVEN' };
+    case 24:
+      return { type: 'ANY_CRAPS' };
+    case 25:
+      return { type: 'HORN' };
+    case 26:
+      return { type: 'C_AND_E' };
+    case 27:
+      return { type: 'WORLD' };
+    case 28:
+      return { type: 'BIG_6' };
+    case 29:
+      return { type: 'BIG_8' };
+    case 30:
+      return { type: 'BUY' };
+    case 31:
+      return { type: 'LAY' };
+    case 32:
+      return { type: 'BUY_VIG_UP_FRONT' };
+    case 33:
+      return { type: 'LAY_VIG_UP_FRONT' };
+    case 34:
+      return { type: 'PUT' };
     default:
       return { type: `BET_${betType}` };
   }
@@ -245,6 +269,17 @@ const BOT_BET_TYPES: CrapsBetName[] = [
   'RIDE_LINE',
   'REPLAY',
   'HOT_ROLLER',
+  'ANY_SEVEN',
+  'ANY_CRAPS',
+  'HORN',
+  'C_AND_E',
+  'WORLD',
+  'BIG_6',
+  'BIG_8',
+  'BUY',
+  'LAY',
+  'BUY_VIG_UP_FRONT',
+  'LAY_VIG_UP_FRONT',
 ];
 
 const betKey = (betType: number, target: number): string => `${betType}:${target}`;
@@ -768,6 +803,25 @@ export class OnchainCrapsTable {
         );
         break;
       }
+      case 'bets_cancelled':
+      case 'bets_replaced': {
+        const playerHex = Buffer.from(event.player).toString('hex');
+        const removed = event.type === 'bets_cancelled' ? event.bets : event.cancelled;
+        const added = event.type === 'bets_replaced' ? event.bets : [];
+        this.removeBetsFromMap(this.playerBets, playerHex, removed);
+        this.addBetsToMap(this.playerBets, playerHex, added);
+        for (const bet of removed) {
+          this.adjustTotals(bet.betType, bet.target, -bet.amount);
+        }
+        this.addBetsToTotals(added);
+        if (event.balanceSnapshot?.chips !== undefined) {
+          this.updateSessionsBalance(playerHex, event.balanceSnapshot.chips);
+          const bot = this.botsByKey.get(playerHex);
+          if (bot) bot.balance = event.balanceSnapshot.chips;
+        }
+        this.requestBroadcast(true);
+        break;
+      }
       case 'player_settled': {
         const playerHex = Buffer.from(event.player).toString('hex');
         const before = this.playerBets.get(playerHex) ?? new Map<string, StoredBet>();
@@ -878,6 +932,26 @@ export class OnchainCrapsTable {
     map.set(playerHex, existing);
   }
 
+  private removeBetsFromMap(
+    map: Map<string, Map<string, StoredBet>>,
+    playerHex: string,
+    bets: GlobalTableBet[],
+  ): void {
+    const existing = map.get(playerHex);
+    if (!existing) return;
+    for (const bet of bets) {
+      const key = betKey(bet.betType, bet.target);
+      const current = existing.get(key);
+      if (!current) continue;
+      const nextAmount = current.amount - bet.amount;
+      if (nextAmount <= 0n) {
+        existing.delete(key);
+      } else {
+        existing.set(key, { ...current, amount: nextAmount });
+      }
+    }
+  }
+
   private buildBetMap(bets: GlobalTableBet[]): Map<string, StoredBet> {
     const result = new Map<string, StoredBet>();
     for (const bet of bets) {
@@ -1229,7 +1303,14 @@ export class OnchainCrapsTable {
     for (let i = 0; i < betCount; i += 1) {
       const betName = BOT_BET_TYPES[Math.floor(Math.random() * BOT_BET_TYPES.length)] ?? 'PASS';
       let target: number | undefined;
-      if (betName === 'YES' || betName === 'NO') {
+      if (
+        betName === 'YES'
+        || betName === 'NO'
+        || betName === 'BUY'
+        || betName === 'LAY'
+        || betName === 'BUY_VIG_UP_FRONT'
+        || betName === 'LAY_VIG_UP_FRONT'
+      ) {
         target = YES_NO_TARGETS[Math.floor(Math.random() * YES_NO_TARGETS.length)];
       } else if (betName === 'NEXT') {
         target = NEXT_TARGETS[Math.floor(Math.random() * NEXT_TARGETS.length)];
@@ -36,7 +36,6 @@ use nullspace_types::{
         MAX_EVENTS_PROOF_NODES, MAX_EVENTS_PROOF_OPS, MAX_STATE_PROOF_NODES, MAX_STATE_PROOF_OPS,
     },
     execution::{Output, Progress, Value},
-    genesis_digest,
 };
 use prometheus_client::metrics::{counter::Counter, gauge::Gauge, histogram::Histogram};
 use rand::RngCore;
@@ -194,7 +193,7 @@ impl<
     pub fn new(context: R, config: Config<I>) -> (Self, Mailbox) {
         // Create mailbox
         let (sender, mailbox) = mpsc::channel(config.mailbox_size);
-        let inbound = Mailbox::new(sender, context.stopped());
+        let inbound = Mailbox::new(sender, context.stopped(), config.genesis);
 
         // Create metrics
         let certificates_processed = Gauge::default();
@@ -386,7 +385,7 @@ impl<
         }
 
         // Compute genesis digest
-        let genesis_digest = genesis_digest();
+        let genesis_digest = self.config.genesis;
 
         // Track uploads
         let mut uploads_outstanding = 0;
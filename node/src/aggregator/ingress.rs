@@ -18,10 +18,7 @@ use futures::{
     SinkExt,
 };
 use nullspace_execution::state_transition::StateTransitionResult;
-use nullspace_types::{
-    execution::{Output, Value},
-    genesis_digest,
-};
+use nullspace_types::execution::{Output, Value};
 use tracing::warn;
 
 type AggregationScheme =
@@ -81,11 +78,16 @@ pub enum Message {
 pub struct Mailbox {
     sender: mpsc::Sender<Message>,
     stopped: Signal,
+    genesis: Digest,
 }
 
 impl Mailbox {
-    pub(super) fn new(sender: mpsc::Sender<Message>, stopped: Signal) -> Self {
-        Self { sender, stopped }
+    pub(super) fn new(sender: mpsc::Sender<Message>, stopped: Signal, genesis: Digest) -> Self {
+        Self {
+            sender,
+            stopped,
+            genesis,
+        }
     }
 
     pub(super) async fn uploaded(&mut self, index: Index) {
@@ -143,17 +145,17 @@ impl Automaton for Mailbox {
             result = sender.send(Message::Genesis { response }) => {
                 if result.is_err() {
                     warn!("aggregator mailbox closed; returning genesis digest");
-                    return genesis_digest();
+                    return self.genesis;
                 }
             },
             _ = &mut stopped => {
                 warn!("aggregator shutting down; returning genesis digest");
-                return genesis_digest();
+                return self.genesis;
             }
         }
         receiver.await.unwrap_or_else(|_| {
             warn!("aggregator actor dropped genesis response; returning genesis digest");
-            genesis_digest()
+            self.genesis
         })
     }
 
@@ -166,14 +168,14 @@ impl Automaton for Mailbox {
                 if result.is_err() {
                     warn!(index = context, "aggregator mailbox closed; propose returns genesis digest");
                     let (fallback_tx, fallback_rx) = oneshot::channel();
-                    let _ = fallback_tx.send(genesis_digest());
+                    let _ = fallback_tx.send(self.genesis);
                     return fallback_rx;
                 }
             },
             _ = &mut stopped => {
                 warn!(index = context, "aggregator shutting down; propose returns genesis digest");
                 let (fallback_tx, fallback_rx) = oneshot::channel();
-                let _ = fallback_tx.send(genesis_digest());
+                let _ = fallback_tx.send(self.genesis);
                 return fallback_rx;
             }
         }
@@ -3,7 +3,7 @@ mod ingress;
 
 use crate::{indexer::Indexer, supervisor::ViewSupervisor};
 pub use actor::Actor;
-use commonware_cryptography::ed25519::PublicKey;
+use commonware_cryptography::{ed25519::PublicKey, sha256::Digest};
 use commonware_runtime::buffer::PoolRef;
 use commonware_runtime::Quota;
 pub use ingress::{Mailbox, Message};
@@ -15,6 +15,7 @@ pub struct Config<I: Indexer> {
     pub supervisor: ViewSupervisor,
     pub public_key: PublicKey,
     pub identity: Identity,
+    pub genesis: Digest,
     pub backfill_quota: Quota,
     pub mailbox_size: usize,
     pub partition: String,
@@ -37,7 +37,7 @@ use futures::{future, future::Either};
 use nullspace_execution::{state_transition, Adb, PrepareError, State};
 use nullspace_types::{
     execution::{Key, Output, Value, MAX_BLOCK_TRANSACTIONS},
-    genesis_block, genesis_digest, Block, Identity,
+    genesis_block, Block, GenesisConfig, Identity,
 };
 use prometheus_client::metrics::{counter::Counter, histogram::Histogram};
 use rand::{CryptoRng, Rng};
@@ -325,6 +325,7 @@ pub struct Actor<
     inbound: Mailbox<R>,
     mailbox: mpsc::Receiver<Message<R>>,
     identity: Identity,
+    genesis: GenesisConfig,
     partition_prefix: String,
     mmr_items_per_blob: NonZero<u64>,
     mmr_write_buffer: NonZero<usize>,
@@ -356,7 +357,11 @@ impl<
     ) -> (Self, ViewSupervisor, EpochSupervisor, AggregationSupervisor, Mailbox<R>) {
         // Create actor
         let (sender, mailbox) = mpsc::channel(config.mailbox_size);
-        let inbound = Mailbox::new(sender, context.stopped());
+        let inbound = Mailbox::new(
+            sender,
+            context.stopped(),
+            genesis_block(&config.genesis).digest(),
+        );
 
         // Create supervisors
         let identity = *config.sharing.public();
@@ -371,6 +376,7 @@ impl<
                 mailbox,
                 inbound: inbound.clone(),
                 identity,
+                genesis: config.genesis,
                 partition_prefix: config.partition_prefix,
                 mmr_items_per_blob: config.mmr_items_per_blob,
                 mmr_write_buffer: config.mmr_write_buffer,
@@ -596,7 +602,8 @@ impl<
         let execution_pool = ThreadPool::new(execution_pool);
 
         // Compute genesis digest
-        let genesis_digest = genesis_digest();
+        let genesis = genesis_block(&self.genesis);
+        let genesis_digest = genesis.digest();
 
         let mut committed_height = {
             let state_guard = state.lock().await;
@@ -797,7 +804,7 @@ impl<
                                     drop(ancestry_timer);
                                     if let Err(err) = self
                                         .inbound
-                                        .ancestry(round, Arc::from(vec![genesis_block()]), propose_timer, response)
+                                        .ancestry(round, Arc::from(vec![genesis.clone()]), propose_timer, response)
                                         .await
                                     {
                                         warn!(view = view.get(), ?err, "failed to send ancestry response");
@@ -874,6 +881,10 @@ impl<
 
                                     // Apply transaction nonces to state
                                     for tx in &block.transactions {
+                                        // Expired transactions are dropped by execution without consuming a nonce
+                                        if tx.is_expired(block.view.get()) {
+                                            continue;
+                                        }
                                         // We don't care if the nonces are valid or not, we just need to ensure we'll process tip the same way as state will be processed during finalization
                                         let _ = apply_transaction_nonce(
                                             state_for_nonce.clone(),
@@ -896,6 +907,11 @@ impl<
                                         break;
                                     }
 
+                                    // Skip transactions that would be rejected as expired at this view
+                                    if tx.is_expired(view.get()) {
+                                        continue;
+                                    }
+
                                     // Attempt to apply
                                     if apply_transaction_nonce(
                                         state_for_nonce.clone(),
@@ -994,7 +1010,7 @@ impl<
 
                                 // Get the parent and current block
                                 let parent_request = if parent.1 == genesis_digest {
-                                    Either::Left(future::ready(Ok(genesis_block())))
+                                    Either::Left(future::ready(Ok(genesis.clone())))
                                 } else {
                                     let parent_round = Round::new(round.epoch(), parent.0);
                                     Either::Right(marshal.subscribe(Some(parent_round), parent.1).await)
@@ -1113,7 +1129,10 @@ impl<
                                     let result = state_transition::execute_state_transition(
                                         &mut *state_guard,
                                         &mut *events_guard,
-                                        self.identity,
+                                        state_transition::ChainContext {
+                                            identity: self.identity,
+                                            genesis: &self.genesis,
+                                        },
                                         height,
                                         seed,
                                         block.transactions,
@@ -1154,6 +1173,9 @@ impl<
                                     next_nonce_cache.insert(now, public.clone(), *next_nonce);
                                 }
 
+                                // Later blocks have a higher view, so anything expiring by this one is dead
+                                mempool.evict_expired(block.view.get().saturating_add(1));
+
                                 // Queue proof generation for changes
                                 let state_op_count = result.state_end_op - result.state_start_op;
                                 let events_op_count = result.events_end_op - result.events_start_op;
@@ -11,7 +11,7 @@ use futures::{
     channel::{mpsc, oneshot},
     SinkExt,
 };
-use nullspace_types::{genesis_digest, Block, Seed};
+use nullspace_types::{Block, Seed};
 use std::sync::Arc;
 use thiserror::Error;
 use tracing::warn;
@@ -58,6 +58,7 @@ pub enum Message<E: Clock> {
 pub struct Mailbox<E: Clock> {
     sender: mpsc::Sender<Message<E>>,
     stopped: Signal,
+    genesis: Digest,
 }
 
 #[derive(Debug, Error)]
@@ -71,8 +72,12 @@ pub enum MailboxError {
 }
 
 impl<E: Clock> Mailbox<E> {
-    pub(super) fn new(sender: mpsc::Sender<Message<E>>, stopped: Signal) -> Self {
-        Self { sender, stopped }
+    pub(super) fn new(sender: mpsc::Sender<Message<E>>, stopped: Signal, genesis: Digest) -> Self {
+        Self {
+            sender,
+            stopped,
+            genesis,
+        }
     }
 
     async fn send(&self, message: Message<E>) -> Result<(), MailboxError> {
@@ -144,13 +149,13 @@ impl<E: Clock> Automaton for Mailbox<E> {
                 ?err,
                 "application mailbox unavailable; returning genesis digest"
             );
-            return genesis_digest();
+            return self.genesis;
         }
         match self.receive(receiver).await {
             Ok(digest) => digest,
             Err(err) => {
                 warn!(?err, "application request failed; returning genesis digest");
-                genesis_digest()
+                self.genesis
             }
         }
     }
@@ -3,7 +3,10 @@ use commonware_runtime::Metrics;
 use nullspace_types::execution::Transaction;
 use prometheus_client::metrics::{counter::Counter, gauge::Gauge};
 use std::sync::atomic::AtomicU64;
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+};
 
 /// The maximum number of transactions a single account can have in the mempool.
 // Increased for higher transaction throughput per account
@@ -26,17 +29,28 @@ pub struct Mempool {
     /// already been processed) and should just try return the transaction with the lowest nonce we
     /// are currently tracking.
     ///
-    /// This is implemented as a round-robin ring over accounts with pending transactions, backed
-    /// by a `Vec` + cursor. A `HashMap` index allows removing accounts in `O(1)` when their
-    /// backlog becomes empty, avoiding stale entries and periodic compaction scans.
-    queue: Vec<PublicKey>,
-    queue_positions: HashMap<PublicKey, usize>,
-    queue_cursor: usize,
+    /// Accounts are ordered by the fee of their lowest-nonce transaction (highest first), then
+    /// by a sequence number that is refreshed whenever an account is served, so accounts
+    /// offering the same fee take turns. `queue_positions` holds each account's current key,
+    /// allowing it to be moved or removed in `O(log n)`.
+    queue: BTreeMap<(Reverse<u64>, u64), PublicKey>,
+    queue_positions: HashMap<PublicKey, (Reverse<u64>, u64)>,
+    /// Accounts ordered by the fee of their highest-nonce transaction (lowest first), used to
+    /// pick an eviction candidate when the mempool is full.
+    tails: BTreeMap<(u64, u64), PublicKey>,
+    tail_positions: HashMap<PublicKey, (u64, u64)>,
+    next_sequence: u64,
+    /// Transactions with an expiry, keyed by their last valid view. Entries are not removed
+    /// when a transaction leaves the mempool another way; eviction checks they still match.
+    expiries: BTreeMap<u64, Vec<(PublicKey, u64)>>,
+    /// Lowest view a future block can have (transactions expiring before it are dead).
+    min_view: u64,
 
     unique: Gauge,
     accounts: Gauge,
     rejected_total: Counter<u64, AtomicU64>,
     trimmed_total: Counter<u64, AtomicU64>,
+    expired_total: Counter<u64, AtomicU64>,
 }
 
 impl Mempool {
@@ -56,6 +70,7 @@ impl Mempool {
         let accounts = Gauge::default();
         let rejected_total: Counter<u64, AtomicU64> = Counter::default();
         let trimmed_total: Counter<u64, AtomicU64> = Counter::default();
+        let expired_total: Counter<u64, AtomicU64> = Counter::default();
         context.register(
             "transactions",
             "Number of transactions in the mempool",
@@ -76,6 +91,11 @@ impl Mempool {
             "Number of transactions trimmed due to per-account backlog limits",
             trimmed_total.clone(),
         );
+        context.register(
+            "expired_total",
+            "Number of transactions evicted after their expiry view",
+            expired_total.clone(),
+        );
 
         // Initialize mempool
         Self {
@@ -83,64 +103,146 @@ impl Mempool {
             max_transactions,
             total_transactions: 0,
             tracked: HashMap::new(),
-            queue: Vec::new(),
+            queue: BTreeMap::new(),
             queue_positions: HashMap::new(),
-            queue_cursor: 0,
+            tails: BTreeMap::new(),
+            tail_positions: HashMap::new(),
+            next_sequence: 0,
+            expiries: BTreeMap::new(),
+            min_view: 0,
 
             unique,
             accounts,
             rejected_total,
             trimmed_total,
+            expired_total,
         }
     }
 
     fn remove_from_queue(&mut self, public: &PublicKey) {
-        let Some(idx) = self.queue_positions.remove(public) else {
+        if let Some(key) = self.queue_positions.remove(public) {
+            self.queue.remove(&key);
+        }
+        if let Some(key) = self.tail_positions.remove(public) {
+            self.tails.remove(&key);
+        }
+    }
+
+    /// Re-key an account in the fee indexes after its backlog changed.
+    ///
+    /// Keys whose fee is unchanged keep their sequence number, so the account keeps its turn.
+    fn reindex(&mut self, public: &PublicKey) {
+        let Some(tracked) = self.tracked.get(public) else {
+            self.remove_from_queue(public);
             return;
         };
+        let (Some((_, head)), Some((_, tail))) =
+            (tracked.first_key_value(), tracked.last_key_value())
+        else {
+            self.remove_from_queue(public);
+            return;
+        };
+        let (head_fee, tail_fee) = (head.fee, tail.fee);
 
-        let last_index = self.queue.len().saturating_sub(1);
-        let removed = self.queue.swap_remove(idx);
-        debug_assert_eq!(&removed, public);
-
-        // Update position for the element that was moved into `idx`.
-        if idx < self.queue.len() {
-            let moved = self.queue[idx].clone();
-            self.queue_positions.insert(moved, idx);
+        let current = self.queue_positions.get(public);
+        if current.is_none_or(|(Reverse(fee), _)| *fee != head_fee) {
+            if let Some(key) = self.queue_positions.remove(public) {
+                self.queue.remove(&key);
+            }
+            let key = (Reverse(head_fee), self.next_sequence());
+            self.queue.insert(key, public.clone());
+            self.queue_positions.insert(public.clone(), key);
         }
 
-        if self.queue.is_empty() {
-            self.queue_cursor = 0;
-            return;
+        let current = self.tail_positions.get(public);
+        if current.is_none_or(|(fee, _)| *fee != tail_fee) {
+            if let Some(key) = self.tail_positions.remove(public) {
+                self.tails.remove(&key);
+            }
+            let key = (tail_fee, self.next_sequence());
+            self.tails.insert(key, public.clone());
+            self.tail_positions.insert(public.clone(), key);
         }
+    }
 
-        // If the cursor pointed at the last element, it either moved to `idx` or was removed.
-        if self.queue_cursor == last_index {
-            if idx < self.queue.len() {
-                self.queue_cursor = idx;
-            } else {
-                self.queue_cursor = 0;
-            }
+    fn next_sequence(&mut self) -> u64 {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        sequence
+    }
+
+    /// Evict the cheapest tail transaction if it pays less than `fee`.
+    ///
+    /// Only the highest nonce of an account is a candidate, so eviction never leaves a gap
+    /// in front of transactions we keep.
+    fn evict_cheaper_than(&mut self, fee: u64) -> bool {
+        let Some((&(tail_fee, _), public)) = self.tails.first_key_value() else {
+            return false;
+        };
+        if tail_fee >= fee {
+            return false;
         }
 
-        if self.queue_cursor >= self.queue.len() {
-            self.queue_cursor = 0;
+        let public = public.clone();
+        let Some(tracked) = self.tracked.get_mut(&public) else {
+            self.remove_from_queue(&public);
+            return false;
+        };
+        tracked.pop_last();
+        self.total_transactions = self.total_transactions.saturating_sub(1);
+        if tracked.is_empty() {
+            self.tracked.remove(&public);
         }
+        self.reindex(&public);
+        true
     }
 
     /// Add a transaction to the mempool.
+    ///
+    /// A transaction for an already-tracked nonce replaces it only if it pays a higher fee.
     pub fn add(&mut self, tx: Transaction) {
-        // If there are too many transactions, ignore
-        if self.total_transactions >= self.max_transactions {
+        // Drop transactions that can no longer be included
+        if tx.is_expired(self.min_view) {
+            self.expired_total.inc();
+            return;
+        }
+
+        // If there already exists a transaction at this nonce, keep it unless outbid.
+        if let Some(existing) = self
+            .tracked
+            .get_mut(&tx.public)
+            .and_then(|tracked| tracked.get_mut(&tx.nonce))
+        {
+            if tx.fee > existing.fee {
+                if let Some(expires_at_view) = tx.expires_at_view {
+                    self.expiries
+                        .entry(expires_at_view)
+                        .or_default()
+                        .push((tx.public.clone(), tx.nonce));
+                }
+                let public = tx.public.clone();
+                *existing = tx;
+                self.reindex(&public);
+            }
+            return;
+        }
+
+        // If there are too many transactions, make room only for a better-paying one
+        if self.total_transactions >= self.max_transactions && !self.evict_cheaper_than(tx.fee) {
             self.rejected_total.inc();
             return;
         }
 
         // Track the transaction.
         let public = tx.public.clone();
-        let (was_empty, entry_len) = {
+        if let Some(expires_at_view) = tx.expires_at_view {
+            self.expiries
+                .entry(expires_at_view)
+                .or_default()
+                .push((public.clone(), tx.nonce));
+        }
+        let entry_len = {
             let entry = self.tracked.entry(public.clone()).or_default();
-            let was_empty = entry.is_empty();
 
             // If there already exists a transaction at some nonce, return.
             if entry.contains_key(&tx.nonce) {
@@ -162,24 +264,14 @@ impl Mempool {
                 self.trimmed_total.inc();
             }
 
-            (was_empty, entry.len())
+            entry.len()
         };
 
         // Avoid tracking empty per-account entries (can happen if `max_backlog == 0`).
         if entry_len == 0 {
             self.tracked.remove(&public);
-            self.remove_from_queue(&public);
-        } else if was_empty {
-            // Add to queue if this is the first entry for this account.
-            if self.queue_positions.contains_key(&public) {
-                // Defensive: `tracked` should not contain empty entries, but avoid duplicating
-                // queue entries if invariants were violated by earlier versions.
-            } else {
-                self.queue_positions
-                    .insert(public.clone(), self.queue.len());
-                self.queue.push(public);
-            }
         }
+        self.reindex(&public);
 
         // Update metrics
         self.unique.set(self.total_transactions as i64);
@@ -199,14 +291,62 @@ impl Mempool {
             if nonce >= &min {
                 break false;
             }
-            tracked.pop_first();
+            let Some((nonce, tx)) = tracked.pop_first() else {
+                break true;
+            };
             self.total_transactions = self.total_transactions.saturating_sub(1);
+
+            // Processed transactions no longer need their expiry tracked.
+            if let Some(expires_at_view) = tx.expires_at_view {
+                if let Some(entries) = self.expiries.get_mut(&expires_at_view) {
+                    entries.retain(|(tracked_public, tracked_nonce)| {
+                        tracked_public != public || *tracked_nonce != nonce
+                    });
+                    if entries.is_empty() {
+                        self.expiries.remove(&expires_at_view);
+                    }
+                }
+            }
         };
 
         // If the account has no remaining transactions, remove it from the mempool.
         if removed_account {
             self.tracked.remove(public);
-            self.remove_from_queue(public);
+        }
+        self.reindex(public);
+
+        // Update metrics
+        self.unique.set(self.total_transactions as i64);
+        self.accounts.set(self.tracked.len() as i64);
+    }
+
+    /// Evict transactions that expire before `min_view`, the lowest view a future block can have.
+    pub fn evict_expired(&mut self, min_view: u64) {
+        if min_view <= self.min_view {
+            return;
+        }
+        self.min_view = min_view;
+
+        let live = self.expiries.split_off(&min_view);
+        let expired = std::mem::replace(&mut self.expiries, live);
+        for (public, nonce) in expired.into_values().flatten() {
+            let Some(tracked) = self.tracked.get_mut(&public) else {
+                continue;
+            };
+            // The entry may be stale (processed, trimmed, or replaced by a later expiry).
+            let still_expired = tracked
+                .get(&nonce)
+                .is_some_and(|tx| tx.is_expired(min_view));
+            if !still_expired {
+                continue;
+            }
+            tracked.remove(&nonce);
+            self.total_transactions = self.total_transactions.saturating_sub(1);
+            self.expired_total.inc();
+            if tracked.is_empty() {
+                self.tracked.remove(&public);
+            }
+            self.reindex(&public);
         }
 
         // Update metrics
@@ -220,19 +360,14 @@ impl Mempool {
     #[allow(dead_code)]
     pub fn next(&mut self) -> Option<Transaction> {
         loop {
-            // Fast-path for empty mempool.
-            if self.queue.is_empty() {
+            // Pick the account offering the highest fee, breaking ties in round-robin order.
+            let Some((_, public)) = self.queue.pop_first() else {
                 self.unique.set(self.total_transactions as i64);
                 self.accounts.set(self.tracked.len() as i64);
                 return None;
-            }
-
-            if self.queue_cursor >= self.queue.len() {
-                self.queue_cursor = 0;
-            }
-
-            // Pick the next account in round-robin order.
-            let public = self.queue[self.queue_cursor].clone();
+            };
+            // Served accounts go to the back of their fee class.
+            self.queue_positions.remove(&public);
 
             let Some(tracked) = self.tracked.get_mut(&public) else {
                 // Stale queue entry (shouldn't happen, but keep defensive hygiene).
@@ -240,12 +375,7 @@ impl Mempool {
                 continue;
             };
 
-            let (tx, became_empty) = match tracked.pop_first() {
-                Some((_, tx)) => (Some(tx), tracked.is_empty()),
-                None => (None, true),
-            };
-
-            let Some(tx) = tx else {
+            let Some((_, tx)) = tracked.pop_first() else {
                 // Account has no transactions; drop it.
                 self.tracked.remove(&public);
                 self.remove_from_queue(&public);
@@ -253,13 +383,10 @@ impl Mempool {
             };
 
             self.total_transactions = self.total_transactions.saturating_sub(1);
-            if became_empty {
+            if tracked.is_empty() {
                 self.tracked.remove(&public);
-                self.remove_from_queue(&public);
-            } else {
-                // Move to the next account.
-                self.queue_cursor = (self.queue_cursor + 1) % self.queue.len();
             }
+            self.reindex(&public);
 
             // Update metrics
             self.unique.set(self.total_transactions as i64);
@@ -271,35 +398,15 @@ impl Mempool {
 
     /// Peek at the lowest-nonce transaction for each account (non-destructive).
     /// Returns up to `max_count` transactions without removing them from the mempool.
-    /// Transactions are returned in round-robin order across accounts.
+    /// Transactions are returned by descending fee, in round-robin order across accounts
+    /// paying the same fee.
     pub fn peek_batch(&self, max_count: usize) -> Vec<Transaction> {
-        let mut result = Vec::with_capacity(max_count);
-        if self.queue.is_empty() || max_count == 0 {
-            return result;
-        }
-
-        let mut cursor = self.queue_cursor;
-        let mut accounts_visited = 0;
-        let total_accounts = self.queue.len();
-
-        // Visit each account at most once
-        while result.len() < max_count && accounts_visited < total_accounts {
-            if cursor >= self.queue.len() {
-                cursor = 0;
-            }
-
-            let public = &self.queue[cursor];
-            if let Some(tracked) = self.tracked.get(public) {
-                if let Some((_, tx)) = tracked.first_key_value() {
-                    result.push(tx.clone());
-                }
-            }
-
-            cursor = (cursor + 1) % self.queue.len();
-            accounts_visited += 1;
-        }
-
-        result
+        self.queue
+            .values()
+            .filter_map(|public| self.tracked.get(public)?.first_key_value())
+            .take(max_count)
+            .map(|(_, tx)| tx.clone())
+            .collect()
     }
 }
 
@@ -593,6 +700,191 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_next_prefers_higher_fee() {
+        let runner = deterministic::Runner::default();
+        runner.start(|ctx| async move {
+            let mut mempool = Mempool::new(ctx);
+
+            for (seed, fee) in [(1u64, 0u64), (2, 5), (3, 0), (4, 20)] {
+                let private = PrivateKey::from_seed(seed);
+                mempool.add(Transaction::sign_with_fee(
+                    &private,
+                    0,
+                    Instruction::CasinoDeposit { amount: 100 },
+                    fee,
+                ));
+            }
+
+            let fees: Vec<u64> = std::iter::from_fn(|| mempool.next())
+                .map(|tx| tx.fee)
+                .collect();
+            assert_eq!(fees, vec![20, 5, 0, 0]);
+        });
+    }
+
+    #[test]
+    fn test_peek_batch_orders_by_fee() {
+        let runner = deterministic::Runner::default();
+        runner.start(|ctx| async move {
+            let mut mempool = Mempool::new(ctx);
+
+            let bot = PrivateKey::from_seed(1);
+            for nonce in 0..3 {
+                mempool.add(Transaction::sign(
+                    &bot,
+                    nonce,
+                    Instruction::CasinoDeposit { amount: 1 },
+                ));
+            }
+            for seed in 2..5 {
+                let private = PrivateKey::from_seed(seed);
+                mempool.add(Transaction::sign_with_fee(
+                    &private,
+                    0,
+                    Instruction::CasinoDeposit { amount: 1 },
+                    seed,
+                ));
+            }
+
+            let batch = mempool.peek_batch(2);
+            let fees: Vec<u64> = batch.iter().map(|tx| tx.fee).collect();
+            assert_eq!(fees, vec![4, 3]);
+
+            // Only one transaction per account, even if the batch has room
+            let batch = mempool.peek_batch(10);
+            assert_eq!(batch.len(), 4);
+            assert_eq!(batch[3].public, bot.public_key());
+        });
+    }
+
+    #[test]
+    fn test_add_replaces_same_nonce_with_higher_fee() {
+        let runner = deterministic::Runner::default();
+        runner.start(|ctx| async move {
+            let mut mempool = Mempool::new(ctx);
+
+            let private = PrivateKey::from_seed(1);
+            let public = private.public_key();
+            let deposit = Instruction::CasinoDeposit { amount: 100 };
+            mempool.add(Transaction::sign_with_fee(&private, 0, deposit.clone(), 5));
+            mempool.add(Transaction::sign_with_fee(&private, 0, deposit.clone(), 3));
+            let fee = |mempool: &Mempool| mempool.tracked[&public][&0].fee;
+            assert_eq!(fee(&mempool), 5);
+
+            mempool.add(Transaction::sign_with_fee(&private, 0, deposit, 8));
+            assert_eq!(fee(&mempool), 8);
+            assert_eq!(mempool.total_transactions, 1);
+        });
+    }
+
+    #[test]
+    fn test_replacement_reorders_accounts() {
+        let runner = deterministic::Runner::default();
+        runner.start(|ctx| async move {
+            let mut mempool = Mempool::new(ctx);
+
+            let deposit = Instruction::CasinoDeposit { amount: 1 };
+            let first = PrivateKey::from_seed(1);
+            let second = PrivateKey::from_seed(2);
+            mempool.add(Transaction::sign_with_fee(&first, 0, deposit.clone(), 5));
+            mempool.add(Transaction::sign_with_fee(&second, 0, deposit.clone(), 3));
+
+            // Outbidding its own head moves the second account to the front
+            mempool.add(Transaction::sign_with_fee(&second, 0, deposit, 9));
+            assert_eq!(mempool.queue.len(), 2);
+            assert_eq!(mempool.tails.len(), 2);
+            assert_eq!(mempool.next().unwrap().public, second.public_key());
+            assert_eq!(mempool.next().unwrap().public, first.public_key());
+            assert!(mempool.queue.is_empty());
+            assert!(mempool.tails.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_full_mempool_evicts_cheaper_transaction() {
+        let runner = deterministic::Runner::default();
+        runner.start(|ctx| async move {
+            let mut mempool = Mempool::new_with_limits(ctx, DEFAULT_MAX_BACKLOG, 2);
+
+            let bot = PrivateKey::from_seed(1);
+            for nonce in 0..2 {
+                mempool.add(Transaction::sign(
+                    &bot,
+                    nonce,
+                    Instruction::CasinoDeposit { amount: 1 },
+                ));
+            }
+
+            // A zero-fee transaction is still rejected
+            let other = PrivateKey::from_seed(2);
+            mempool.add(Transaction::sign(
+                &other,
+                0,
+                Instruction::CasinoDeposit { amount: 1 },
+            ));
+            assert!(!mempool.tracked.contains_key(&other.public_key()));
+
+            // A paying transaction displaces the bot's furthest-future transaction
+            mempool.add(Transaction::sign_with_fee(
+                &other,
+                0,
+                Instruction::CasinoDeposit { amount: 1 },
+                1,
+            ));
+            assert_eq!(mempool.total_transactions, 2);
+            assert!(mempool.tracked.contains_key(&other.public_key()));
+            let bot_txs = mempool.tracked.get(&bot.public_key()).unwrap();
+            assert_eq!(bot_txs.len(), 1);
+            assert!(bot_txs.contains_key(&0));
+        });
+    }
+
+    #[test]
+    fn test_evict_expired_transactions() {
+        let runner = deterministic::Runner::default();
+        runner.start(|ctx| async move {
+            let mut mempool = Mempool::new(ctx);
+
+            let private = PrivateKey::from_seed(1);
+            let public = private.public_key();
+            for (nonce, expires_at_view) in [(0, Some(5)), (1, None), (2, Some(10))] {
+                mempool.add(Transaction::sign_with_extensions(
+                    &private,
+                    nonce,
+                    Instruction::CasinoDeposit { amount: 100 },
+                    0,
+                    expires_at_view,
+                ));
+            }
+            assert_eq!(mempool.total_transactions, 3);
+
+            // A transaction is still valid at its expiry view
+            mempool.evict_expired(5);
+            assert_eq!(mempool.total_transactions, 3);
+
+            mempool.evict_expired(6);
+            assert_eq!(mempool.total_transactions, 2);
+            assert!(!mempool.tracked[&public].contains_key(&0));
+            assert_eq!(mempool.expired_total.get(), 1);
+
+            // Late arrivals that already expired are dropped on add
+            mempool.add(Transaction::sign_with_extensions(
+                &private,
+                3,
+                Instruction::CasinoDeposit { amount: 100 },
+                0,
+                Some(5),
+            ));
+            assert_eq!(mempool.total_transactions, 2);
+
+            mempool.evict_expired(11);
+            assert_eq!(mempool.total_transactions, 1);
+            assert!(mempool.tracked[&public].contains_key(&1));
+            assert!(mempool.expiries.is_empty());
+        });
+    }
+
     #[test]
     fn test_next_empty_mempool() {
         let runner = deterministic::Runner::default();
@@ -11,6 +11,7 @@ pub use actor::Actor;
 mod ingress;
 use commonware_runtime::buffer::PoolRef;
 pub use ingress::Mailbox;
+use nullspace_types::GenesisConfig;
 mod mempool;
 
 /// Configuration for the application.
@@ -24,6 +25,9 @@ pub struct Config<I: Indexer> {
     /// The share of the secret.
     pub share: group::Share,
 
+    /// Chain parameters committed to by the genesis block.
+    pub genesis: GenesisConfig,
+
     /// Number of messages from consensus to hold in our backlog
     /// before blocking.
     pub mailbox_size: usize,
@@ -1,6 +1,6 @@
 //! Key generation for local consensus network
 //!
-//! Usage: cargo run --bin generate-keys -- --nodes 3 --output configs/local
+//! Usage: cargo run --bin generate-keys -- --nodes 3 --output configs/local [--genesis-admin <hex>]
 //!
 //! This generates:
 //! - nodeN.yaml files with Ed25519 keys and BLS threshold shares
@@ -21,6 +21,7 @@ use nullspace_node::defaults::{
     DEFAULT_MAILBOX_SIZE, DEFAULT_MAX_PENDING_SEED_LISTENERS, DEFAULT_MEMPOOL_MAX_BACKLOG,
     DEFAULT_MEMPOOL_MAX_TRANSACTIONS, DEFAULT_MESSAGE_BACKLOG, DEFAULT_WORKER_THREADS,
 };
+use nullspace_node::parse_peer_public_key;
 use rand::{rngs::StdRng, SeedableRng};
 use std::fs;
 use std::path::PathBuf;
@@ -52,6 +53,10 @@ struct Args {
     /// Indexer URL (simulator endpoint)
     #[arg(long, default_value = "http://localhost:8080")]
     indexer: String,
+
+    /// Casino admin public key (hex) granted every admin role at genesis (repeatable)
+    #[arg(long = "genesis-admin")]
+    genesis_admins: Vec<String>,
 }
 
 fn main() {
@@ -92,6 +97,18 @@ fn run() -> Result<()> {
         )
     })?;
 
+    // Every validator must commit to the same genesis admins
+    let genesis_admins = args
+        .genesis_admins
+        .iter()
+        .map(|key| {
+            parse_peer_public_key(key)
+                .map(|key| format!("\"{}\"", hex(&key.encode())))
+                .with_context(|| format!("Invalid genesis admin public key: {key}"))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join(", ");
+
     // Generate Ed25519 keys first
     let node_data: Vec<_> = (0..n)
         .map(|i| {
@@ -190,6 +207,9 @@ execution_concurrency: {execution_concurrency}
 mempool_max_backlog: {mempool_max_backlog}
 mempool_max_transactions: {mempool_max_transactions}
 max_pending_seed_listeners: {max_pending_seed_listeners}
+
+# Genesis (must be identical on every validator)
+genesis_admin_public_keys: [{genesis_admins}]
 "#,
             i = i,
             seed = args.seed,
@@ -209,6 +229,7 @@ max_pending_seed_listeners: {max_pending_seed_listeners}
             mempool_max_backlog = DEFAULT_MEMPOOL_MAX_BACKLOG,
             mempool_max_transactions = DEFAULT_MEMPOOL_MAX_TRANSACTIONS,
             max_pending_seed_listeners = DEFAULT_MAX_PENDING_SEED_LISTENERS,
+            genesis_admins = genesis_admins,
         );
 
         let config_path = args.output.join(format!("node{}.yaml", i));
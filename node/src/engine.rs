@@ -28,7 +28,7 @@ use commonware_runtime::{
 use commonware_storage::archive::{immutable, prunable};
 use commonware_storage::translator::Translator;
 use commonware_utils::{NZDuration, NZU64};
-use nullspace_types::{Activity, Block, Finalization, NAMESPACE};
+use nullspace_types::{genesis_digest, Activity, Block, Finalization, GenesisConfig, NAMESPACE};
 use rand::{CryptoRng, Rng};
 use std::{
     collections::hash_map::RandomState,
@@ -199,6 +199,7 @@ pub struct ConsensusConfig {
 
 pub struct ApplicationConfig<I: Indexer> {
     pub indexer: I,
+    pub genesis: GenesisConfig,
     pub execution_concurrency: usize,
     pub max_uploads_outstanding: usize,
     pub mempool_max_backlog: usize,
@@ -302,6 +303,7 @@ impl<
                     locations_items_per_blob: cfg.storage.locations_items_per_blob,
                     buffer_pool: buffer_pool.clone(),
                     indexer: cfg.application.indexer.clone(),
+                    genesis: cfg.application.genesis.clone(),
                     execution_concurrency: cfg.application.execution_concurrency,
                     mempool_max_backlog: cfg.application.mempool_max_backlog,
                     mempool_max_transactions: cfg.application.mempool_max_transactions,
@@ -339,6 +341,7 @@ impl<
             context.with_label("aggregator"),
             aggregator::Config {
                 identity,
+                genesis: genesis_digest(&cfg.application.genesis),
                 supervisor: view_supervisor.clone(),
                 namespace: NAMESPACE.to_vec(),
                 public_key: public_key.clone(),
@@ -19,7 +19,7 @@ use thiserror::Error;
 use tracing::Level;
 use url::Url;
 
-use nullspace_types::Identity;
+use nullspace_types::{GenesisConfig, Identity};
 
 pub mod aggregator;
 pub mod application;
@@ -102,6 +102,10 @@ pub struct Config {
     pub indexer: String,
     pub execution_concurrency: usize,
 
+    /// Keys holding every admin role at genesis. Must be identical on every validator.
+    #[serde(default)]
+    pub genesis_admin_public_keys: Vec<HexBytes>,
+
     // Tunables (defaults preserve current constants in `node/src/main.rs`).
     #[serde(default = "default_max_uploads_outstanding")]
     pub max_uploads_outstanding: usize,
@@ -234,6 +238,7 @@ pub struct ValidatedConfig {
 
     pub indexer: String,
     pub execution_concurrency: usize,
+    pub genesis: GenesisConfig,
 
     pub max_uploads_outstanding: usize,
     pub max_message_size: usize,
@@ -309,6 +314,13 @@ impl fmt::Debug for RedactedConfig<'_> {
             )
             .field("indexer", &cfg.indexer)
             .field("execution_concurrency", &cfg.execution_concurrency)
+            .field(
+                "genesis_admin_public_keys",
+                &cfg.genesis_admin_public_keys
+                    .iter()
+                    .map(|key| hex(key.as_ref()))
+                    .collect::<Vec<_>>(),
+            )
             .field("max_uploads_outstanding", &cfg.max_uploads_outstanding)
             .field("max_message_size", &cfg.max_message_size)
             .field("leader_timeout_ms", &cfg.leader_timeout_ms)
@@ -774,6 +786,12 @@ impl Config {
             })?;
         let identity = *sharing.public();
 
+        let admin_public_keys = self
+            .genesis_admin_public_keys
+            .iter()
+            .map(|key| decode_bytes("genesis_admin_public_keys", key))
+            .collect::<Result<Vec<PublicKey>, _>>()?;
+
         let log_level =
             Level::from_str(&self.log_level).map_err(|_| ConfigError::InvalidLogLevel {
                 value: self.log_level.clone(),
@@ -803,6 +821,7 @@ impl Config {
             max_pending_seed_listeners: self.max_pending_seed_listeners,
             indexer: self.indexer,
             execution_concurrency: self.execution_concurrency,
+            genesis: GenesisConfig { admin_public_keys },
             max_uploads_outstanding: self.max_uploads_outstanding,
             max_message_size: self.max_message_size,
             leader_timeout: Duration::from_millis(self.leader_timeout_ms),
@@ -217,6 +217,10 @@ fn print_dry_run_report(config: &nullspace_node::ValidatedConfig, peer_count: us
         config.max_uploads_outstanding
     );
     println!("  execution: concurrency={}", config.execution_concurrency);
+    println!(
+        "  genesis: admin_public_keys={}",
+        config.genesis.admin_public_keys.len()
+    );
 }
 
 fn parse_bootstrappers(
@@ -558,6 +562,7 @@ fn main_result() -> Result<()> {
                 },
                 application: engine::ApplicationConfig {
                     indexer,
+                    genesis: config.genesis,
                     execution_concurrency: config.execution_concurrency,
                     max_uploads_outstanding: config.max_uploads_outstanding,
                     mempool_max_backlog: config.mempool_max_backlog,
@@ -84,6 +84,7 @@ fn config_redacted_debug_does_not_leak_secrets() {
         max_pending_seed_listeners: 10_000,
         indexer: "http://127.0.0.1:8080".to_string(),
         execution_concurrency: 4,
+        genesis_admin_public_keys: Vec::new(),
         max_uploads_outstanding: 4,
         max_message_size: 10 * 1024 * 1024,
         leader_timeout_ms: 1_000,
@@ -312,6 +313,7 @@ fn all_online(n: u32, seed: u64, link: Link, required: u64) -> String {
                 },
                 application: engine::ApplicationConfig {
                     indexer: indexer.clone(),
+                    genesis: GenesisConfig::default(),
                     execution_concurrency: 2,
                     max_uploads_outstanding: 4,
                     mempool_max_backlog: 64,
@@ -590,6 +592,7 @@ fn test_backfill() {
                 },
                 application: engine::ApplicationConfig {
                     indexer: indexer.clone(),
+                    genesis: GenesisConfig::default(),
                     execution_concurrency: 2,
                     max_uploads_outstanding: 4,
                     mempool_max_backlog: 64,
@@ -744,6 +747,7 @@ fn test_backfill() {
             },
             application: engine::ApplicationConfig {
                 indexer: indexer.clone(),
+                genesis: GenesisConfig::default(),
                 execution_concurrency: 2,
                 max_uploads_outstanding: 4,
                 mempool_max_backlog: 64,
@@ -944,6 +948,7 @@ fn test_unclean_shutdown() {
                     },
                     application: engine::ApplicationConfig {
                         indexer: indexer.clone(),
+                        genesis: GenesisConfig::default(),
                         execution_concurrency: 2,
                         max_uploads_outstanding: 4,
                         mempool_max_backlog: 64,
@@ -1161,6 +1166,7 @@ fn test_execution(seed: u64, link: Link) -> String {
                 },
                 application: engine::ApplicationConfig {
                     indexer: indexer.clone(),
+                    genesis: GenesisConfig::default(),
                     execution_concurrency: 2,
                     max_uploads_outstanding: 4,
                     mempool_max_backlog: 64,
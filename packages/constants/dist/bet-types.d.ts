@@ -35,6 +35,18 @@ export declare const CRAPS_BET_TYPES: {
     readonly RIDE_LINE: 20;
     readonly REPLAY: 21;
     readonly HOT_ROLLER: 22;
+    readonly ANY_SEVEN: 23;
+    readonly ANY_CRAPS: 24;
+    readonly HORN: 25;
+    readonly C_AND_E: 26;
+    readonly WORLD: 27;
+    readonly BIG_6: 28;
+    readonly BIG_8: 29;
+    readonly BUY: 30;
+    readonly LAY: 31;
+    readonly BUY_VIG_UP_FRONT: 32;
+    readonly LAY_VIG_UP_FRONT: 33;
+    readonly PUT: 34;
 };
 export type CrapsBetName = keyof typeof CRAPS_BET_TYPES;
 export declare function encodeCrapsBet(type: CrapsBetName, target?: number): {
@@ -57,13 +69,26 @@ export declare const ROULETTE_BET_TYPES: {
     readonly STREET: 11;
     readonly CORNER: 12;
     readonly SIX_LINE: 13;
+    readonly VOISINS: 14;
+    readonly TIERS: 15;
+    readonly ORPHELINS: 16;
+    readonly JEU_ZERO: 17;
+    readonly NEIGHBOURS: 18;
 };
-export declare const ROULETTE_BET_NAMES: readonly ["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE"];
+export declare const ROULETTE_BET_NAMES: readonly ["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE", "VOISINS", "TIERS", "ORPHELINS", "JEU_ZERO", "NEIGHBOURS"];
 export type RouletteBetName = typeof ROULETTE_BET_NAMES[number];
 export declare function encodeRouletteBet(type: RouletteBetName, target?: number): {
     type: number;
     value: number;
 };
+export declare const ROULETTE_ANNOUNCED_CHIPS: {
+    readonly VOISINS: 9;
+    readonly TIERS: 6;
+    readonly ORPHELINS: 5;
+    readonly JEU_ZERO: 4;
+};
+export declare const ROULETTE_MAX_NEIGHBOURS = 4;
+export declare function encodeRouletteNeighbours(center: number, neighbours: number): number;
 export declare function rouletteRequiresTarget(type: RouletteBetName): boolean;
 export declare const SICBO_BET_TYPES: {
     readonly SMALL: 0;
@@ -86,4 +111,27 @@ export declare function encodeSicBoBet(type: SicBoBetName, target?: number): {
     target: number;
 };
 export declare function sicboRequiresTarget(type: SicBoBetName): boolean;
+export declare const BIG_SIX_BET_TYPES: {
+    readonly ONE: 0;
+    readonly TWO: 1;
+    readonly FIVE: 2;
+    readonly TEN: 3;
+    readonly TWENTY: 4;
+    readonly JOKER: 5;
+    readonly LOGO: 6;
+};
+export type BigSixBetName = keyof typeof BIG_SIX_BET_TYPES;
+export declare function encodeBigSixBet(type: BigSixBetName): {
+    betType: number;
+    target: number;
+};
+export declare const CRASH_TARGET_SCALES: {
+    readonly HUNDREDTHS: 0;
+    readonly TENTHS: 1;
+    readonly WHOLE: 2;
+};
+export declare function encodeCrashTarget(multiplier: number): {
+    betType: number;
+    target: number;
+};
 //# sourceMappingURL=bet-types.d.ts.map
\ No newline at end of file
@@ -38,6 +38,18 @@ export const CRAPS_BET_TYPES = {
     RIDE_LINE: 20,
     REPLAY: 21,
     HOT_ROLLER: 22,
+    ANY_SEVEN: 23,
+    ANY_CRAPS: 24,
+    HORN: 25,
+    C_AND_E: 26,
+    WORLD: 27,
+    BIG_6: 28,
+    BIG_8: 29,
+    BUY: 30,
+    LAY: 31,
+    BUY_VIG_UP_FRONT: 32,
+    LAY_VIG_UP_FRONT: 33,
+    PUT: 34,
 };
 const CRAPS_HARDWAY_MAP = {
     4: 8,
@@ -61,13 +73,28 @@ export function encodeCrapsBet(type, target) {
         || type === 'RIDE_LINE'
         || type === 'REPLAY'
         || type === 'HOT_ROLLER'
-        || type === 'FIRE') {
+        || type === 'FIRE'
+        || type === 'ANY_SEVEN'
+        || type === 'ANY_CRAPS'
+        || type === 'HORN'
+        || type === 'C_AND_E'
+        || type === 'WORLD'
+        || type === 'BIG_6'
+        || type === 'BIG_8') {
         encodedTarget = 0;
     }
     return { betType, target: encodedTarget };
 }
 export function crapsRequiresTarget(type) {
-    return type === 'YES' || type === 'NO' || type === 'NEXT' || type === 'HARDWAY';
+    return (type === 'YES'
+        || type === 'NO'
+        || type === 'NEXT'
+        || type === 'HARDWAY'
+        || type === 'BUY'
+        || type === 'LAY'
+        || type === 'BUY_VIG_UP_FRONT'
+        || type === 'LAY_VIG_UP_FRONT'
+        || type === 'PUT');
 }
 // Roulette bet types (execution/src/casino/roulette.rs)
 export const ROULETTE_BET_TYPES = {
@@ -85,6 +112,11 @@ export const ROULETTE_BET_TYPES = {
     STREET: 11,
     CORNER: 12,
     SIX_LINE: 13,
+    VOISINS: 14,
+    TIERS: 15,
+    ORPHELINS: 16,
+    JEU_ZERO: 17,
+    NEIGHBOURS: 18,
 };
 export const ROULETTE_BET_NAMES = [
     'STRAIGHT',
@@ -106,6 +138,11 @@ export const ROULETTE_BET_NAMES = [
     'STREET',
     'CORNER',
     'SIX_LINE',
+    'VOISINS',
+    'TIERS',
+    'ORPHELINS',
+    'JEU_ZERO',
+    'NEIGHBOURS',
 ];
 export function encodeRouletteBet(type, target) {
     switch (type) {
@@ -147,15 +184,39 @@ export function encodeRouletteBet(type, target) {
             return { type: ROULETTE_BET_TYPES.CORNER, value: target ?? 0 };
         case 'SIX_LINE':
             return { type: ROULETTE_BET_TYPES.SIX_LINE, value: target ?? 0 };
+        case 'VOISINS':
+            return { type: ROULETTE_BET_TYPES.VOISINS, value: 0 };
+        case 'TIERS':
+            return { type: ROULETTE_BET_TYPES.TIERS, value: 0 };
+        case 'ORPHELINS':
+            return { type: ROULETTE_BET_TYPES.ORPHELINS, value: 0 };
+        case 'JEU_ZERO':
+            return { type: ROULETTE_BET_TYPES.JEU_ZERO, value: 0 };
+        case 'NEIGHBOURS':
+            return { type: ROULETTE_BET_TYPES.NEIGHBOURS, value: target ?? 0 };
     }
 }
+// Announced bets split the amount evenly over their chips, so amounts must be a multiple
+// of the chip count. Neighbours uses 2n+1 chips.
+export const ROULETTE_ANNOUNCED_CHIPS = {
+    VOISINS: 9,
+    TIERS: 6,
+    ORPHELINS: 5,
+    JEU_ZERO: 4,
+};
+export const ROULETTE_MAX_NEIGHBOURS = 4;
+// Neighbours target byte: [n-1:2 bits][center:6 bits], single-zero wheel only.
+export function encodeRouletteNeighbours(center, neighbours) {
+    return ((neighbours - 1) << 6) | center;
+}
 export function rouletteRequiresTarget(type) {
     return type === 'STRAIGHT'
         || type === 'SPLIT_H'
         || type === 'SPLIT_V'
         || type === 'STREET'
         || type === 'CORNER'
-        || type === 'SIX_LINE';
+        || type === 'SIX_LINE'
+        || type === 'NEIGHBOURS';
 }
 // Sic Bo bet types (execution/src/casino/sic_bo.rs)
 export const SICBO_BET_TYPES = {
@@ -189,4 +250,42 @@ export function sicboRequiresTarget(type) {
         || type === 'HOP3_HARD'
         || type === 'HOP4_EASY';
 }
+// Big Six bet types (execution/src/casino/big_six.rs), one per wheel symbol
+export const BIG_SIX_BET_TYPES = {
+    ONE: 0,
+    TWO: 1,
+    FIVE: 2,
+    TEN: 3,
+    TWENTY: 4,
+    JOKER: 5,
+    LOGO: 6,
+};
+export function encodeBigSixBet(type) {
+    return {
+        betType: BIG_SIX_BET_TYPES[type],
+        target: 0,
+    };
+}
+// Crash auto cash-out target scales (execution/src/casino/crash.rs), sent as the bet type
+export const CRASH_TARGET_SCALES = {
+    HUNDREDTHS: 0,
+    TENTHS: 1,
+    WHOLE: 2,
+};
+// Encodes an auto cash-out multiplier in hundredths (250 = 2.50x) using the finest exact scale.
+export function encodeCrashTarget(multiplier) {
+    if (!Number.isInteger(multiplier) || multiplier <= 100 || multiplier > 10000) {
+        throw new Error(`Invalid crash target ${multiplier}`);
+    }
+    if (multiplier <= 355) {
+        return { betType: CRASH_TARGET_SCALES.HUNDREDTHS, target: multiplier - 100 };
+    }
+    if (multiplier % 10 === 0 && multiplier <= 2650) {
+        return { betType: CRASH_TARGET_SCALES.TENTHS, target: (multiplier - 100) / 10 };
+    }
+    if (multiplier % 100 === 0) {
+        return { betType: CRASH_TARGET_SCALES.WHOLE, target: multiplier / 100 };
+    }
+    throw new Error(`Crash target ${multiplier} is not representable`);
+}
 //# sourceMappingURL=bet-types.js.map
\ No newline at end of file
@@ -16,6 +16,11 @@ export declare const GAME_TYPE_TO_ID: {
     readonly 7: "sic_bo";
     readonly 8: "three_card_poker";
     readonly 9: "ultimate_texas_holdem";
+    readonly 10: "pai_gow_poker";
+    readonly 11: "let_it_ride";
+    readonly 12: "keno";
+    readonly 13: "big_six";
+    readonly 14: "crash";
 };
 export declare const GAME_DISPLAY_NAMES: {
     readonly baccarat: "Baccarat";
@@ -28,6 +33,11 @@ export declare const GAME_DISPLAY_NAMES: {
     readonly sic_bo: "Sic Bo";
     readonly three_card_poker: "Three Card Poker";
     readonly ultimate_texas_holdem: "Ultimate Texas Hold'em";
+    readonly pai_gow_poker: "Pai Gow Poker";
+    readonly let_it_ride: "Let It Ride";
+    readonly keno: "Keno";
+    readonly big_six: "Big Six";
+    readonly crash: "Crash";
 };
 export declare const GAME_EMOJIS: {
     readonly baccarat: "👑";
@@ -40,5 +50,10 @@ export declare const GAME_EMOJIS: {
     readonly sic_bo: "🀄";
     readonly three_card_poker: "🎴";
     readonly ultimate_texas_holdem: "🤠";
+    readonly pai_gow_poker: "🐉";
+    readonly let_it_ride: "🏇";
+    readonly keno: "🎱";
+    readonly big_six: "🎡";
+    readonly crash: "🚀";
 };
 //# sourceMappingURL=games.d.ts.map
\ No newline at end of file
@@ -17,6 +17,11 @@ export const GAME_TYPE_TO_ID = {
     [GameType.SicBo]: "sic_bo",
     [GameType.ThreeCard]: "three_card_poker",
     [GameType.UltimateHoldem]: "ultimate_texas_holdem",
+    [GameType.PaiGow]: "pai_gow_poker",
+    [GameType.LetItRide]: "let_it_ride",
+    [GameType.Keno]: "keno",
+    [GameType.BigSix]: "big_six",
+    [GameType.Crash]: "crash",
 };
 export const GAME_DISPLAY_NAMES = {
     baccarat: "Baccarat",
@@ -29,6 +34,11 @@ export const GAME_DISPLAY_NAMES = {
     sic_bo: "Sic Bo",
     three_card_poker: "Three Card Poker",
     ultimate_texas_holdem: "Ultimate Texas Hold'em",
+    pai_gow_poker: "Pai Gow Poker",
+    let_it_ride: "Let It Ride",
+    keno: "Keno",
+    big_six: "Big Six",
+    crash: "Crash",
 };
 export const GAME_EMOJIS = {
     baccarat: "\u{1F451}",
@@ -41,5 +51,10 @@ export const GAME_EMOJIS = {
     sic_bo: "\u{1F004}",
     three_card_poker: "\u{1F3B4}",
     ultimate_texas_holdem: "\u{1F920}",
+    pai_gow_poker: "\u{1F409}",
+    let_it_ride: "\u{1F3C7}",
+    keno: "\u{1F3B1}",
+    big_six: "\u{1F3A1}",
+    crash: "\u{1F680}",
 };
 //# sourceMappingURL=games.js.map
\ No newline at end of file
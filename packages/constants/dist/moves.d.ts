@@ -51,6 +51,18 @@ export declare const CrapsBetType: {
     readonly RideLine: 20;
     readonly Replay: 21;
     readonly HotRoller: 22;
+    readonly AnySeven: 23;
+    readonly AnyCraps: 24;
+    readonly Horn: 25;
+    readonly CAndE: 26;
+    readonly World: 27;
+    readonly Big6: 28;
+    readonly Big8: 29;
+    readonly Buy: 30;
+    readonly Lay: 31;
+    readonly BuyVigUpFront: 32;
+    readonly LayVigUpFront: 33;
+    readonly Put: 34;
 };
 export declare const BaccaratMove: {
     readonly PlaceBet: 0;
@@ -69,6 +81,14 @@ export declare const CasinoWarMove: {
 export declare const VideoPokerMove: {
     readonly SetRules: 255;
 };
+export declare const VideoPokerGame: {
+    readonly JacksOrBetter: 0;
+    readonly DeucesWild: 1;
+    readonly BonusPoker: 2;
+    readonly DoubleBonus: 3;
+    readonly DoubleDoubleBonus: 4;
+    readonly JokerPoker: 5;
+};
 export declare const HiLoMove: {
     readonly Higher: 0;
     readonly Lower: 1;
@@ -82,6 +102,19 @@ export declare const SicBoMove: {
     readonly AtomicBatch: 3;
     readonly SetRules: 4;
 };
+export declare const BigSixMove: {
+    readonly PlaceBet: 0;
+    readonly Spin: 1;
+    readonly ClearBets: 2;
+    readonly AtomicBatch: 3;
+    readonly SetWheel: 4;
+};
+export declare const CrashMove: {
+    readonly PlaceBet: 0;
+    readonly Resolve: 1;
+    readonly ClearBets: 2;
+    readonly AtomicBatch: 3;
+};
 export declare const ThreeCardMove: {
     readonly Play: 0;
     readonly Fold: 1;
@@ -116,6 +149,8 @@ export type CasinoWarMoveType = (typeof CasinoWarMove)[keyof typeof CasinoWarMov
 export type VideoPokerMoveType = (typeof VideoPokerMove)[keyof typeof VideoPokerMove];
 export type HiLoMoveType = (typeof HiLoMove)[keyof typeof HiLoMove];
 export type SicBoMoveType = (typeof SicBoMove)[keyof typeof SicBoMove];
+export type BigSixMoveType = (typeof BigSixMove)[keyof typeof BigSixMove];
+export type CrashMoveType = (typeof CrashMove)[keyof typeof CrashMove];
 export type ThreeCardMoveType = (typeof ThreeCardMove)[keyof typeof ThreeCardMove];
 export type UltimateHoldemMoveType = (typeof UltimateHoldemMove)[keyof typeof UltimateHoldemMove];
 //# sourceMappingURL=moves.d.ts.map
\ No newline at end of file
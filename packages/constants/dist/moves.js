@@ -52,7 +52,7 @@ export const CrapsBetType = {
     Hardway8: 10,
     Hardway10: 11,
     Fire: 12,
-    // 13 (Buy) removed in Rust
+    // 13 (old Buy) removed in Rust; Buy is now 30
     // 14 unused
     AtsSmall: 15,
     AtsTall: 16,
@@ -62,6 +62,18 @@ export const CrapsBetType = {
     RideLine: 20,
     Replay: 21,
     HotRoller: 22,
+    AnySeven: 23,
+    AnyCraps: 24,
+    Horn: 25,
+    CAndE: 26,
+    World: 27,
+    Big6: 28,
+    Big8: 29,
+    Buy: 30,
+    Lay: 31,
+    BuyVigUpFront: 32,
+    LayVigUpFront: 33,
+    Put: 34,
 };
 // execution/src/casino/baccarat.rs - Payload action codes
 export const BaccaratMove = {
@@ -81,10 +93,20 @@ export const CasinoWarMove = {
     SetRules: 5,
 };
 // execution/src/casino/video_poker.rs - payload tags (no move enum)
-// [holdMask:u8] to hold cards, [0xFF, rules:u8] to set rules
+// [0xFF, rules:u8, hands:u8?] to pick the game/paytable/hand count and deal,
+// then [holdMask:u8] to hold cards
 export const VideoPokerMove = {
     SetRules: 0xff,
 };
+// execution/src/casino/video_poker.rs - rules byte (bit 0 = short pay, bits 1-3 = game)
+export const VideoPokerGame = {
+    JacksOrBetter: 0,
+    DeucesWild: 1,
+    BonusPoker: 2,
+    DoubleBonus: 3,
+    DoubleDoubleBonus: 4,
+    JokerPoker: 5,
+};
 // execution/src/casino/hilo.rs - Move enum
 export const HiLoMove = {
     Higher: 0,
@@ -100,6 +122,21 @@ export const SicBoMove = {
     AtomicBatch: 3,
     SetRules: 4,
 };
+// execution/src/casino/big_six.rs - Move enum
+export const BigSixMove = {
+    PlaceBet: 0,
+    Spin: 1,
+    ClearBets: 2,
+    AtomicBatch: 3,
+    SetWheel: 4,
+};
+// execution/src/casino/crash.rs - Move enum
+export const CrashMove = {
+    PlaceBet: 0,
+    Resolve: 1,
+    ClearBets: 2,
+    AtomicBatch: 3,
+};
 // execution/src/casino/three_card.rs - Move enum
 export const ThreeCardMove = {
     Play: 0,
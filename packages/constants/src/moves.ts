@@ -56,7 +56,7 @@ export const CrapsBetType = {
   Hardway8: 10,
   Hardway10: 11,
   Fire: 12,
-  // 13 (Buy) removed in Rust
+  // 13 (old Buy) removed in Rust; Buy is now 30
   // 14 unused
   AtsSmall: 15,
   AtsTall: 16,
@@ -66,6 +66,18 @@ export const CrapsBetType = {
   RideLine: 20,
   Replay: 21,
   HotRoller: 22,
+  AnySeven: 23,
+  AnyCraps: 24,
+  Horn: 25,
+  CAndE: 26,
+  World: 27,
+  Big6: 28,
+  Big8: 29,
+  Buy: 30,
+  Lay: 31,
+  BuyVigUpFront: 32,
+  LayVigUpFront: 33,
+  Put: 34,
 } as const;
 
 // execution/src/casino/baccarat.rs - Payload action codes
@@ -88,11 +100,22 @@ export const CasinoWarMove = {
 } as const;
 
 // execution/src/casino/video_poker.rs - payload tags (no move enum)
-// [holdMask:u8] to hold cards, [0xFF, rules:u8] to set rules
+// [0xFF, rules:u8, hands:u8?] to pick the game/paytable/hand count and deal,
+// then [holdMask:u8] to hold cards
 export const VideoPokerMove = {
   SetRules: 0xff,
 } as const;
 
+// execution/src/casino/video_poker.rs - rules byte (bit 0 = short pay, bits 1-3 = game)
+export const VideoPokerGame = {
+  JacksOrBetter: 0,
+  DeucesWild: 1,
+  BonusPoker: 2,
+  DoubleBonus: 3,
+  DoubleDoubleBonus: 4,
+  JokerPoker: 5,
+} as const;
+
 // execution/src/casino/hilo.rs - Move enum
 export const HiLoMove = {
   Higher: 0,
@@ -110,6 +133,23 @@ export const SicBoMove = {
   SetRules: 4,
 } as const;
 
+// execution/src/casino/big_six.rs - Move enum
+export const BigSixMove = {
+  PlaceBet: 0,
+  Spin: 1,
+  ClearBets: 2,
+  AtomicBatch: 3,
+  SetWheel: 4,
+} as const;
+
+// execution/src/casino/crash.rs - Move enum
+export const CrashMove = {
+  PlaceBet: 0,
+  Resolve: 1,
+  ClearBets: 2,
+  AtomicBatch: 3,
+} as const;
+
 // execution/src/casino/three_card.rs - Move enum
 export const ThreeCardMove = {
   Play: 0,
@@ -149,5 +189,7 @@ export type CasinoWarMoveType = (typeof CasinoWarMove)[keyof typeof CasinoWarMov
 export type VideoPokerMoveType = (typeof VideoPokerMove)[keyof typeof VideoPokerMove];
 export type HiLoMoveType = (typeof HiLoMove)[keyof typeof HiLoMove];
 export type SicBoMoveType = (typeof SicBoMove)[keyof typeof SicBoMove];
+export type BigSixMoveType = (typeof BigSixMove)[keyof typeof BigSixMove];
+export type CrashMoveType = (typeof CrashMove)[keyof typeof CrashMove];
 export type ThreeCardMoveType = (typeof ThreeCardMove)[keyof typeof ThreeCardMove];
 export type UltimateHoldemMoveType = (typeof UltimateHoldemMove)[keyof typeof UltimateHoldemMove];
@@ -1,10 +1,15 @@
 import { describe, it, expect } from 'vitest';
 import {
   BACCARAT_BET_TYPES,
+  BIG_SIX_BET_TYPES,
   CRAPS_BET_TYPES,
+  CRASH_TARGET_SCALES,
   encodeBaccaratBet,
+  encodeBigSixBet,
   encodeCrapsBet,
+  encodeCrashTarget,
   encodeRouletteBet,
+  encodeRouletteNeighbours,
   encodeSicBoBet,
   crapsRequiresTarget,
   rouletteRequiresTarget,
@@ -37,6 +42,12 @@ describe('bet type helpers', () => {
     expect(encodeRouletteBet('RED')).toEqual({ type: 1, value: 0 });
     expect(encodeRouletteBet('DOZEN_2')).toEqual({ type: 7, value: 1 });
     expect(encodeRouletteBet('SIX_LINE', 5)).toEqual({ type: 13, value: 5 });
+    expect(encodeRouletteBet('VOISINS')).toEqual({ type: 14, value: 0 });
+    expect(rouletteRequiresTarget('NEIGHBOURS')).toBe(true);
+    expect(encodeRouletteBet('NEIGHBOURS', encodeRouletteNeighbours(17, 2))).toEqual({
+      type: 18,
+      value: 81,
+    });
   });
 
   it('encodes sic bo bets with target rules', () => {
@@ -46,4 +57,17 @@ describe('bet type helpers', () => {
     expect(encodeSicBoBet('SMALL')).toEqual({ betType: 0, target: 0 });
     expect(encodeSicBoBet('TRIPLE_SPECIFIC', 3)).toEqual({ betType: 4, target: 3 });
   });
+
+  it('encodes big six bets without targets', () => {
+    expect(encodeBigSixBet('ONE')).toEqual({ betType: 0, target: 0 });
+    expect(encodeBigSixBet('JOKER')).toEqual({ betType: BIG_SIX_BET_TYPES.JOKER, target: 0 });
+  });
+
+  it('encodes crash targets at the finest exact scale', () => {
+    expect(encodeCrashTarget(150)).toEqual({ betType: CRASH_TARGET_SCALES.HUNDREDTHS, target: 50 });
+    expect(encodeCrashTarget(1250)).toEqual({ betType: CRASH_TARGET_SCALES.TENTHS, target: 115 });
+    expect(encodeCrashTarget(5000)).toEqual({ betType: CRASH_TARGET_SCALES.WHOLE, target: 50 });
+    expect(() => encodeCrashTarget(100)).toThrow();
+    expect(() => encodeCrashTarget(1234)).toThrow();
+  });
 });
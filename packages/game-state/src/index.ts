@@ -107,6 +107,8 @@ export type BlackjackParsedState = {
   sideBetPerfectPairs: number;
   sideBetBustIt: number;
   sideBetRoyalMatch: number;
+  insuranceBet: number;
+  dealerPeeked: boolean;
   initPlayerCards: [number, number];
   activeHandIndex: number;
   hands: BlackjackHand[];
@@ -123,7 +125,7 @@ export const parseBlackjackState = (stateBlob: Uint8Array): BlackjackParsedState
   const reader = new SafeReader(stateBlob);
   try {
     const version = reader.readU8('version');
-    if (version !== 2 && version !== 3 && version !== 4) {
+    if (version !== 2 && version !== 3 && version !== 4 && version !== 5) {
       return null;
     }
     const stage = reader.readU8('stage');
@@ -140,6 +142,12 @@ export const parseBlackjackState = (stateBlob: Uint8Array): BlackjackParsedState
     if (version >= 4) {
       sideBetRoyalMatch = Number(reader.readU64BE('side bet royal match'));
     }
+    let insuranceBet = 0;
+    let dealerPeeked = false;
+    if (version >= 5) {
+      insuranceBet = Number(reader.readU64BE('insurance'));
+      dealerPeeked = reader.readU8('dealer peeked') !== 0;
+    }
     const initP1 = reader.readU8('init player card 1');
     const initP2 = reader.readU8('init player card 2');
     const activeHandIndex = reader.readU8('active hand index');
@@ -175,6 +183,8 @@ export const parseBlackjackState = (stateBlob: Uint8Array): BlackjackParsedState
       sideBetPerfectPairs,
       sideBetBustIt,
       sideBetRoyalMatch,
+      insuranceBet,
+      dealerPeeked,
       initPlayerCards: [initP1, initP2],
       activeHandIndex,
       hands,
@@ -416,6 +426,10 @@ export const parseHiLoState = (stateBlob: Uint8Array): HiLoParsedState | null =>
 export type VideoPokerParsedState = {
   stage: number;
   cards: number[];
+  rules: number;
+  hands: number;
+  /** Final cards of hands 2..hands (multi-hand, after the draw). */
+  extraHands: number[][];
 };
 
 export const parseVideoPokerState = (stateBlob: Uint8Array): VideoPokerParsedState | null => {
@@ -428,7 +442,14 @@ export const parseVideoPokerState = (stateBlob: Uint8Array): VideoPokerParsedSta
     for (let i = 1; i <= 5 && i < stateBlob.length; i += 1) {
       cards.push(stateBlob[i]);
     }
-    return { stage, cards };
+    // bit 0 = short pay, bits 1-3 = game (0 = Jacks or Better ... 5 = Joker Poker)
+    const rules = stateBlob.length > 6 ? stateBlob[6] : 0;
+    const hands = stateBlob.length > 7 ? stateBlob[7] : 1;
+    const extraHands: number[][] = [];
+    for (let offset = 8; offset + 5 <= stateBlob.length; offset += 5) {
+      extraHands.push(Array.from(stateBlob.subarray(offset, offset + 5)));
+    }
+    return { stage, cards, rules, hands, extraHands };
   } catch {
     return null;
   }
@@ -572,3 +593,188 @@ export const parseUltimateHoldemState = (
     return null;
   }
 };
+
+export type PaiGowParsedState = {
+  version: number;
+  stage: number;
+  fortuneBet: number;
+  playerCards: number[];
+  playerLow: number[];
+  dealerCards: number[];
+  dealerLow: number[];
+};
+
+export const parsePaiGowState = (stateBlob: Uint8Array): PaiGowParsedState | null => {
+  if (stateBlob.length < 28) {
+    return null;
+  }
+  try {
+    const version = stateBlob[0];
+    if (version !== 1) {
+      return null;
+    }
+    const stage = stateBlob[1];
+    const fortuneBet = Number(readU64BEAt(stateBlob, 2, 'fortune bet'));
+    const playerCards = Array.from(stateBlob.slice(10, 17));
+    const playerLow = [stateBlob[17], stateBlob[18]];
+    const dealerCards = Array.from(stateBlob.slice(19, 26));
+    const dealerLow = [stateBlob[26], stateBlob[27]];
+    return {
+      version,
+      stage,
+      fortuneBet,
+      playerCards,
+      playerLow,
+      dealerCards,
+      dealerLow,
+    };
+  } catch {
+    return null;
+  }
+};
+
+export type LetItRideParsedState = {
+  version: number;
+  stage: number;
+  playerCards: number[];
+  communityCards: number[];
+  bet1Riding: boolean;
+  bet2Riding: boolean;
+  bonusBet: number;
+};
+
+export const parseLetItRideState = (stateBlob: Uint8Array): LetItRideParsedState | null => {
+  if (stateBlob.length < 16) {
+    return null;
+  }
+  try {
+    const version = stateBlob[0];
+    if (version !== 1) {
+      return null;
+    }
+    const stage = stateBlob[1];
+    const playerCards = [stateBlob[2], stateBlob[3], stateBlob[4]];
+    const communityCards = [stateBlob[5], stateBlob[6]];
+    const riding = stateBlob[7];
+    const bonusBet = Number(readU64BEAt(stateBlob, 8, 'bonus bet'));
+    return {
+      version,
+      stage,
+      playerCards,
+      communityCards,
+      bet1Riding: (riding & 0b01) !== 0,
+      bet2Riding: (riding & 0b10) !== 0,
+      bonusBet,
+    };
+  } catch {
+    return null;
+  }
+};
+
+export type KenoParsedState = {
+  version: number;
+  stage: number;
+  paytable: number;
+  races: number;
+  stake: number;
+  spots: number[];
+  draws: number[][];
+};
+
+export const parseKenoState = (stateBlob: Uint8Array): KenoParsedState | null => {
+  const reader = new SafeReader(stateBlob);
+  try {
+    const version = reader.readU8('version');
+    if (version !== 1) {
+      return null;
+    }
+    const stage = reader.readU8('stage');
+    const paytable = reader.readU8('paytable');
+    const races = reader.readU8('races');
+    const stake = Number(reader.readU64BE('stake'));
+    const spotCount = reader.readU8('spot count');
+    const spots = Array.from(reader.readBytes(spotCount, 'spots'));
+    const draws: number[][] = [];
+    if (stage === 1) {
+      for (let race = 0; race < races; race += 1) {
+        draws.push(Array.from(reader.readBytes(20, 'drawn numbers')));
+      }
+    }
+    return { version, stage, paytable, races, stake, spots, draws };
+  } catch {
+    return null;
+  }
+};
+
+export type BigSixParsedBet = {
+  betType: number;
+  amount: number;
+};
+
+export type BigSixParsedState = {
+  version: number;
+  wheel: number;
+  bets: BigSixParsedBet[];
+  stop: number | null;
+};
+
+export const parseBigSixState = (stateBlob: Uint8Array): BigSixParsedState | null => {
+  const reader = new SafeReader(stateBlob);
+  try {
+    const version = reader.readU8('version');
+    if (version !== 1) {
+      return null;
+    }
+    const wheel = reader.readU8('wheel');
+    const betCount = reader.readU8('bet count');
+    const bets: BigSixParsedBet[] = [];
+    for (let i = 0; i < betCount; i += 1) {
+      const betType = reader.readU8('bet type');
+      reader.readU8('bet number');
+      const amount = Number(reader.readU64BE('bet amount'));
+      bets.push({ betType, amount });
+    }
+    const stop = reader.remaining() > 0 ? reader.readU8('stop') : null;
+    return { version, wheel, bets, stop };
+  } catch {
+    return null;
+  }
+};
+
+export type CrashParsedBet = {
+  scale: number;
+  target: number;
+  amount: number;
+};
+
+export type CrashParsedState = {
+  version: number;
+  bets: CrashParsedBet[];
+  cashOut: number;
+  crashPoint: number | null;
+};
+
+export const parseCrashState = (stateBlob: Uint8Array): CrashParsedState | null => {
+  const reader = new SafeReader(stateBlob);
+  try {
+    const version = reader.readU8('version');
+    if (version !== 1) {
+      return null;
+    }
+    const betCount = reader.readU8('bet count');
+    const bets: CrashParsedBet[] = [];
+    for (let i = 0; i < betCount; i += 1) {
+      const scale = reader.readU8('bet scale');
+      const target = reader.readU8('bet target');
+      const amount = Number(reader.readU64BE('bet amount'));
+      bets.push({ scale, target, amount });
+    }
+    const cashOut = readU32BEAt(reader.readBytes(4, 'cash out'), 0, 'cash out');
+    const crashPoint = reader.remaining() > 0
+      ? readU32BEAt(reader.readBytes(4, 'crash point'), 0, 'crash point')
+      : null;
+    return { version, bets, cashOut, crashPoint };
+  } catch {
+    return null;
+  }
+};
@@ -1,10 +1,15 @@
 import { describe, it, expect } from 'vitest';
 import {
   parseBaccaratState,
+  parseBigSixState,
   parseBlackjackState,
   parseCasinoWarState,
   parseCrapsState,
+  parseCrashState,
   parseHiLoState,
+  parseKenoState,
+  parseLetItRideState,
+  parsePaiGowState,
   parseRouletteState,
   parseSicBoState,
   parseThreeCardState,
@@ -55,6 +60,32 @@ describe('game-state parsers', () => {
     expect(parsed?.dealerCards.length).toBe(0);
   });
 
+  it('parses blackjack insurance state', () => {
+    const blob = new Uint8Array(62);
+    blob[0] = 5; // version
+    blob[1] = 4; // awaiting peek
+    writeU64BE(blob, 34, 0n); // royal match
+    writeU64BE(blob, 42, 50n); // insurance
+    blob[50] = 0; // dealer peeked
+    blob[51] = 1; // init cards
+    blob[52] = 2;
+    blob[53] = 0; // active hand
+    blob[54] = 1; // hand count
+    blob[55] = 1; // bet multiplier
+    blob[56] = 0; // playing
+    blob[57] = 0; // split flag
+    blob[58] = 2; // card count
+    blob[59] = 1;
+    blob[60] = 2;
+    blob[61] = 0; // dealer count
+
+    const parsed = parseBlackjackState(blob);
+    expect(parsed?.stage).toBe(4);
+    expect(parsed?.insuranceBet).toBe(50);
+    expect(parsed?.dealerPeeked).toBe(false);
+    expect(parsed?.hands[0].cards).toEqual([1, 2]);
+  });
+
   it('parses baccarat state', () => {
     const blob = new Uint8Array([0, 0, 0]);
     const parsed = parseBaccaratState(blob);
@@ -130,6 +161,25 @@ describe('game-state parsers', () => {
     const parsed = parseVideoPokerState(blob);
     expect(parsed?.stage).toBe(0);
     expect(parsed?.cards).toEqual([1, 2, 3, 4, 5]);
+    expect(parsed?.rules).toBe(0);
+  });
+
+  it('parses video poker rules byte', () => {
+    const blob = new Uint8Array([0, 52, 2, 3, 4, 5, 0b1011]);
+    const parsed = parseVideoPokerState(blob);
+    expect(parsed?.cards).toEqual([52, 2, 3, 4, 5]);
+    expect(parsed?.rules).toBe(0b1011);
+  });
+
+  it('parses multi-hand video poker state', () => {
+    const blob = new Uint8Array([1, 1, 2, 3, 4, 5, 0, 3, 1, 2, 6, 7, 8, 1, 2, 9, 10, 11]);
+    const parsed = parseVideoPokerState(blob);
+    expect(parsed?.cards).toEqual([1, 2, 3, 4, 5]);
+    expect(parsed?.hands).toBe(3);
+    expect(parsed?.extraHands).toEqual([
+      [1, 2, 6, 7, 8],
+      [1, 2, 9, 10, 11],
+    ]);
   });
 
   it('parses casino war state', () => {
@@ -192,6 +242,111 @@ describe('game-state parsers', () => {
     expect(parsed?.progressiveBet).toBe(5);
   });
 
+  it('parses pai gow state', () => {
+    const blob = new Uint8Array(28);
+    blob[0] = 1; // version
+    blob[1] = 1; // stage
+    writeU64BE(blob, 2, 20n); // fortune
+    for (let i = 0; i < 7; i += 1) {
+      blob[10 + i] = i;
+      blob[19 + i] = 20 + i;
+    }
+    blob[17] = 0;
+    blob[18] = 6;
+    blob[26] = 21;
+    blob[27] = 22;
+    const parsed = parsePaiGowState(blob);
+    expect(parsed?.stage).toBe(1);
+    expect(parsed?.fortuneBet).toBe(20);
+    expect(parsed?.playerCards).toEqual([0, 1, 2, 3, 4, 5, 6]);
+    expect(parsed?.playerLow).toEqual([0, 6]);
+    expect(parsed?.dealerLow).toEqual([21, 22]);
+  });
+
+  it('parses let it ride state', () => {
+    const blob = new Uint8Array(16);
+    blob[0] = 1; // version
+    blob[1] = 2; // stage
+    blob[2] = 1;
+    blob[3] = 2;
+    blob[4] = 3;
+    blob[5] = 4;
+    blob[6] = 0xff;
+    blob[7] = 0b10; // bet 1 pulled back
+    writeU64BE(blob, 8, 30n); // bonus
+    const parsed = parseLetItRideState(blob);
+    expect(parsed?.stage).toBe(2);
+    expect(parsed?.communityCards).toEqual([4, 0xff]);
+    expect(parsed?.bet1Riding).toBe(false);
+    expect(parsed?.bet2Riding).toBe(true);
+    expect(parsed?.bonusBet).toBe(30);
+  });
+
+  it('parses keno state', () => {
+    const blob = new Uint8Array(13 + 3 + 2 * 20);
+    blob[0] = 1; // version
+    blob[1] = 1; // complete
+    blob[2] = 2; // steady paytable
+    blob[3] = 2; // races
+    writeU64BE(blob, 4, 25n); // stake per race
+    blob[12] = 3;
+    blob.set([7, 8, 80], 13);
+    for (let i = 0; i < 40; i += 1) {
+      blob[16 + i] = i + 1;
+    }
+    const parsed = parseKenoState(blob);
+    expect(parsed?.paytable).toBe(2);
+    expect(parsed?.stake).toBe(25);
+    expect(parsed?.spots).toEqual([7, 8, 80]);
+    expect(parsed?.draws).toHaveLength(2);
+    expect(parsed?.draws[1][0]).toBe(21);
+    expect(parseKenoState(blob.slice(0, 40))).toBeNull();
+  });
+
+  it('parses big six state', () => {
+    const blob = new Uint8Array(3 + 2 * 10 + 1);
+    blob[0] = 1; // version
+    blob[1] = 1; // atlantic city wheel
+    blob[2] = 2;
+    blob[3] = 0; // $1
+    writeU64BE(blob, 5, 10n);
+    blob[13] = 5; // joker
+    writeU64BE(blob, 15, 2n);
+    blob[23] = 52;
+    const parsed = parseBigSixState(blob);
+    expect(parsed?.wheel).toBe(1);
+    expect(parsed?.bets).toEqual([
+      { betType: 0, amount: 10 },
+      { betType: 5, amount: 2 },
+    ]);
+    expect(parsed?.stop).toBe(52);
+    expect(parseBigSixState(blob.slice(0, 23))?.stop).toBeNull();
+    expect(parseBigSixState(blob.slice(0, 10))).toBeNull();
+  });
+
+  it('parses crash state', () => {
+    const blob = new Uint8Array(2 + 2 * 10 + 8);
+    blob[0] = 1; // version
+    blob[1] = 2;
+    blob[2] = 0; // hundredths
+    blob[3] = 50; // 1.50x
+    writeU64BE(blob, 4, 10n);
+    blob[12] = 2; // whole
+    blob[13] = 5; // 5x
+    writeU64BE(blob, 14, 3n);
+    writeU32BE(blob, 22, 175);
+    writeU32BE(blob, 26, 412);
+    const parsed = parseCrashState(blob);
+    expect(parsed?.bets).toEqual([
+      { scale: 0, target: 50, amount: 10 },
+      { scale: 2, target: 5, amount: 3 },
+    ]);
+    expect(parsed?.cashOut).toBe(175);
+    expect(parsed?.crashPoint).toBe(412);
+    expect(parseCrashState(blob.slice(0, 26))?.crashPoint).toBeNull();
+    expect(parseCrashState(blob.slice(0, 24))).toBeNull();
+  });
+
   it('returns null for malformed blobs', () => {
     expect(parseBlackjackState(new Uint8Array())).toBeNull();
     expect(parseBaccaratState(new Uint8Array())).toBeNull();
@@ -203,5 +358,10 @@ describe('game-state parsers', () => {
     expect(parseCasinoWarState(new Uint8Array())).toBeNull();
     expect(parseThreeCardState(new Uint8Array())).toBeNull();
     expect(parseUltimateHoldemState(new Uint8Array())).toBeNull();
+    expect(parsePaiGowState(new Uint8Array())).toBeNull();
+    expect(parseLetItRideState(new Uint8Array())).toBeNull();
+    expect(parseKenoState(new Uint8Array())).toBeNull();
+    expect(parseBigSixState(new Uint8Array())).toBeNull();
+    expect(parseCrashState(new Uint8Array())).toBeNull();
   });
 });
@@ -1283,20 +1283,20 @@ export declare const BlackjackSplitRequestSchema: z.ZodObject<{
     type: "blackjack_split";
 }>;
 export declare const RouletteBetSchema: z.ZodObject<{
-    type: z.ZodUnion<[z.ZodEnum<["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE"]>, z.ZodNumber]>;
+    type: z.ZodUnion<[z.ZodEnum<["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE", "VOISINS", "TIERS", "ORPHELINS", "JEU_ZERO", "NEIGHBOURS"]>, z.ZodNumber]>;
     amount: z.ZodNumber;
     target: z.ZodOptional<z.ZodNumber>;
     number: z.ZodOptional<z.ZodNumber>;
     value: z.ZodOptional<z.ZodNumber>;
 }, "strip", z.ZodTypeAny, {
     amount: number;
-    type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+    type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
     number?: number | undefined;
     value?: number | undefined;
     target?: number | undefined;
 }, {
     amount: number;
-    type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+    type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
     number?: number | undefined;
     value?: number | undefined;
     target?: number | undefined;
@@ -1304,20 +1304,20 @@ export declare const RouletteBetSchema: z.ZodObject<{
 export declare const RouletteSpinRequestSchema: z.ZodObject<{
     type: z.ZodLiteral<"roulette_spin">;
     bets: z.ZodArray<z.ZodObject<{
-        type: z.ZodUnion<[z.ZodEnum<["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE"]>, z.ZodNumber]>;
+        type: z.ZodUnion<[z.ZodEnum<["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE", "VOISINS", "TIERS", "ORPHELINS", "JEU_ZERO", "NEIGHBOURS"]>, z.ZodNumber]>;
         amount: z.ZodNumber;
         target: z.ZodOptional<z.ZodNumber>;
         number: z.ZodOptional<z.ZodNumber>;
         value: z.ZodOptional<z.ZodNumber>;
     }, "strip", z.ZodTypeAny, {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
     }, {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
@@ -1326,7 +1326,7 @@ export declare const RouletteSpinRequestSchema: z.ZodObject<{
     type: "roulette_spin";
     bets: {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
@@ -1335,7 +1335,7 @@ export declare const RouletteSpinRequestSchema: z.ZodObject<{
     type: "roulette_spin";
     bets: {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
@@ -1963,20 +1963,20 @@ export declare const OutboundMessageSchema: z.ZodDiscriminatedUnion<"type", [z.Z
 }>, z.ZodObject<{
     type: z.ZodLiteral<"roulette_spin">;
     bets: z.ZodArray<z.ZodObject<{
-        type: z.ZodUnion<[z.ZodEnum<["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE"]>, z.ZodNumber]>;
+        type: z.ZodUnion<[z.ZodEnum<["STRAIGHT", "RED", "BLACK", "EVEN", "ODD", "LOW", "HIGH", "DOZEN_1", "DOZEN_2", "DOZEN_3", "COL_1", "COL_2", "COL_3", "ZERO", "SPLIT_H", "SPLIT_V", "STREET", "CORNER", "SIX_LINE", "VOISINS", "TIERS", "ORPHELINS", "JEU_ZERO", "NEIGHBOURS"]>, z.ZodNumber]>;
         amount: z.ZodNumber;
         target: z.ZodOptional<z.ZodNumber>;
         number: z.ZodOptional<z.ZodNumber>;
         value: z.ZodOptional<z.ZodNumber>;
     }, "strip", z.ZodTypeAny, {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
     }, {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
@@ -1985,7 +1985,7 @@ export declare const OutboundMessageSchema: z.ZodDiscriminatedUnion<"type", [z.Z
     type: "roulette_spin";
     bets: {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
@@ -1994,7 +1994,7 @@ export declare const OutboundMessageSchema: z.ZodDiscriminatedUnion<"type", [z.Z
     type: "roulette_spin";
     bets: {
         amount: number;
-        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+        type: number | "STRAIGHT" | "RED" | "BLACK" | "EVEN" | "ODD" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
         number?: number | undefined;
         value?: number | undefined;
         target?: number | undefined;
@@ -3,6 +3,7 @@ import {
   HiLoMove,
   ThreeCardMove,
   UltimateHoldemMove,
+  VideoPokerMove,
 } from '@nullspace/constants';
 
 export type HiLoAction = 'higher' | 'lower' | 'same' | 'cashout';
@@ -18,6 +19,15 @@ export function encodeHiLoAction(action: HiLoAction): Uint8Array {
   return new Uint8Array([HILO_OPCODES[action]]);
 }
 
+// Rules byte: bit 0 = short pay, bits 1-3 = `VideoPokerGame` (0 = Jacks or Better, full pay).
+// Hands: 1, 3, 5, 10 or 50; each hand stakes the session bet.
+export function encodeVideoPokerDeal(rules = 0, hands = 1): Uint8Array {
+  if (hands === 1) {
+    return new Uint8Array([VideoPokerMove.SetRules, rules & 0xff]);
+  }
+  return new Uint8Array([VideoPokerMove.SetRules, rules & 0xff, hands & 0xff]);
+}
+
 export function encodeVideoPokerHold(holds: boolean[]): Uint8Array {
   let holdBits = 0;
   for (let i = 0; i < 5 && i < holds.length; i += 1) {
@@ -134,6 +144,7 @@ export function encodeUltimateHoldemDeal(options: UltimateHoldemDealOptions = {}
 
 export type GameActionPayload =
   | { game: 'hilo'; action: HiLoAction }
+  | { game: 'videopoker'; action: 'deal'; rules?: number; hands?: number }
   | { game: 'videopoker'; action: 'hold'; holds: boolean[] }
   | { game: 'casinowar'; action: CasinoWarAction }
   | { game: 'casinowar'; action: 'set_tie_bet'; amount: bigint }
@@ -148,6 +159,9 @@ export function encodeGameActionPayload(payload: GameActionPayload): Uint8Array
     case 'hilo':
       return encodeHiLoAction(payload.action);
     case 'videopoker':
+      if (payload.action === 'deal') {
+        return encodeVideoPokerDeal(payload.rules, payload.hands);
+      }
       return encodeVideoPokerHold(payload.holds);
     case 'casinowar':
       if (payload.action === 'set_tie_bet') {
@@ -12,9 +12,14 @@ export declare enum GameType {
     Roulette = 6,
     SicBo = 7,
     ThreeCard = 8,
-    UltimateHoldem = 9
+    UltimateHoldem = 9,
+    PaiGow = 10,
+    LetItRide = 11,
+    Keno = 12,
+    BigSix = 13,
+    Crash = 14
 }
-export type GameId = 'baccarat' | 'blackjack' | 'casino_war' | 'craps' | 'video_poker' | 'hi_lo' | 'roulette' | 'sic_bo' | 'three_card_poker' | 'ultimate_texas_holdem';
+export type GameId = 'baccarat' | 'blackjack' | 'casino_war' | 'craps' | 'video_poker' | 'hi_lo' | 'roulette' | 'sic_bo' | 'three_card_poker' | 'ultimate_texas_holdem' | 'pai_gow_poker' | 'let_it_ride' | 'keno' | 'big_six' | 'crash';
 export interface GameSession {
     id: bigint;
     gameType: GameType;
@@ -14,5 +14,10 @@ export var GameType;
     GameType[GameType["SicBo"] = 7] = "SicBo";
     GameType[GameType["ThreeCard"] = 8] = "ThreeCard";
     GameType[GameType["UltimateHoldem"] = 9] = "UltimateHoldem";
+    GameType[GameType["PaiGow"] = 10] = "PaiGow";
+    GameType[GameType["LetItRide"] = 11] = "LetItRide";
+    GameType[GameType["Keno"] = 12] = "Keno";
+    GameType[GameType["BigSix"] = 13] = "BigSix";
+    GameType[GameType["Crash"] = 14] = "Crash";
 })(GameType || (GameType = {}));
 //# sourceMappingURL=game.js.map
\ No newline at end of file
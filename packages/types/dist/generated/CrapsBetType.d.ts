@@ -1,2 +1,2 @@
-export type CrapsBetType = "PASS" | "DONT_PASS" | "COME" | "DONT_COME" | "FIELD" | "YES" | "NO" | "NEXT" | "HARDWAY" | "FIRE" | "ATS_SMALL" | "ATS_TALL" | "ATS_ALL" | "MUGGSY" | "DIFF_DOUBLES" | "RIDE_LINE" | "REPLAY" | "HOT_ROLLER";
+export type CrapsBetType = "PASS" | "DONT_PASS" | "COME" | "DONT_COME" | "FIELD" | "YES" | "NO" | "NEXT" | "HARDWAY" | "FIRE" | "ATS_SMALL" | "ATS_TALL" | "ATS_ALL" | "MUGGSY" | "DIFF_DOUBLES" | "RIDE_LINE" | "REPLAY" | "HOT_ROLLER" | "ANY_SEVEN" | "ANY_CRAPS" | "HORN" | "C_AND_E" | "WORLD" | "BIG_6" | "BIG_8" | "BUY" | "LAY" | "BUY_VIG_UP_FRONT" | "LAY_VIG_UP_FRONT" | "PUT";
 //# sourceMappingURL=CrapsBetType.d.ts.map
\ No newline at end of file
@@ -1,2 +1,2 @@
-export type GameType = "NONE" | "BACCARAT" | "BLACKJACK" | "CASINO_WAR" | "CRAPS" | "ROULETTE" | "SIC_BO" | "THREE_CARD" | "ULTIMATE_HOLDEM" | "VIDEO_POKER" | "HILO";
+export type GameType = "NONE" | "BACCARAT" | "BLACKJACK" | "CASINO_WAR" | "CRAPS" | "ROULETTE" | "SIC_BO" | "THREE_CARD" | "ULTIMATE_HOLDEM" | "VIDEO_POKER" | "HILO" | "PAI_GOW" | "LET_IT_RIDE" | "KENO" | "BIG_SIX" | "CRASH";
 //# sourceMappingURL=GameType.d.ts.map
\ No newline at end of file
@@ -14,6 +14,11 @@ export enum GameType {
   SicBo = 7,
   ThreeCard = 8,
   UltimateHoldem = 9,
+  PaiGow = 10,
+  LetItRide = 11,
+  Keno = 12,
+  BigSix = 13,
+  Crash = 14,
 }
 
 export type GameId =
@@ -26,7 +31,12 @@ export type GameId =
   | 'roulette'
   | 'sic_bo'
   | 'three_card_poker'
-  | 'ultimate_texas_holdem';
+  | 'ultimate_texas_holdem'
+  | 'pai_gow_poker'
+  | 'let_it_ride'
+  | 'keno'
+  | 'big_six'
+  | 'crash';
 
 export interface GameSession {
   id: bigint;
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type CrapsBetType = "PASS" | "DONT_PASS" | "COME" | "DONT_COME" | "FIELD" | "YES" | "NO" | "NEXT" | "HARDWAY" | "FIRE" | "ATS_SMALL" | "ATS_TALL" | "ATS_ALL" | "MUGGSY" | "DIFF_DOUBLES" | "RIDE_LINE" | "REPLAY" | "HOT_ROLLER";
+export type CrapsBetType = "PASS" | "DONT_PASS" | "COME" | "DONT_COME" | "FIELD" | "YES" | "NO" | "NEXT" | "HARDWAY" | "FIRE" | "ATS_SMALL" | "ATS_TALL" | "ATS_ALL" | "MUGGSY" | "DIFF_DOUBLES" | "RIDE_LINE" | "REPLAY" | "HOT_ROLLER" | "ANY_SEVEN" | "ANY_CRAPS" | "HORN" | "C_AND_E" | "WORLD" | "BIG_6" | "BIG_8" | "BUY" | "LAY" | "BUY_VIG_UP_FRONT" | "LAY_VIG_UP_FRONT" | "PUT";
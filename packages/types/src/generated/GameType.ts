@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type GameType = "NONE" | "BACCARAT" | "BLACKJACK" | "CASINO_WAR" | "CRAPS" | "ROULETTE" | "SIC_BO" | "THREE_CARD" | "ULTIMATE_HOLDEM" | "VIDEO_POKER" | "HILO";
+export type GameType = "NONE" | "BACCARAT" | "BLACKJACK" | "CASINO_WAR" | "CRAPS" | "ROULETTE" | "SIC_BO" | "THREE_CARD" | "ULTIMATE_HOLDEM" | "VIDEO_POKER" | "HILO" | "PAI_GOW" | "LET_IT_RIDE" | "KENO" | "BIG_SIX" | "CRASH";
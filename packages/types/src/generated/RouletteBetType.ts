@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type RouletteBetType = "STRAIGHT" | "RED" | "BLACK" | "ODD" | "EVEN" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE";
+export type RouletteBetType = "STRAIGHT" | "RED" | "BLACK" | "ODD" | "EVEN" | "LOW" | "HIGH" | "DOZEN_1" | "DOZEN_2" | "DOZEN_3" | "COL_1" | "COL_2" | "COL_3" | "ZERO" | "SPLIT_H" | "SPLIT_V" | "STREET" | "CORNER" | "SIX_LINE" | "VOISINS" | "TIERS" | "ORPHELINS" | "JEU_ZERO" | "NEIGHBOURS";
@@ -81,6 +81,21 @@ if [ ! -f "$CONFIG_DIR/peers.yaml" ]; then
     exit 1
 fi
 
+# Seed genesis admins from CASINO_ADMIN_PUBLIC_KEY_HEX when the configs have none.
+# The genesis block commits to these keys, so this only applies to a fresh chain.
+if [ -n "${CASINO_ADMIN_PUBLIC_KEY_HEX:-}" ]; then
+    GENESIS_ADMINS=$(echo "$CASINO_ADMIN_PUBLIC_KEY_HEX" | tr ', ' '\n\n' | grep -v '^$' | sed 's/.*/"&"/' | paste -sd, - | sed 's/,/, /g')
+    for i in $(seq 0 $((NODES - 1))); do
+        NODE_CONFIG="$CONFIG_DIR/node$i.yaml"
+        if ! grep -q "^genesis_admin_public_keys:" "$NODE_CONFIG"; then
+            printf '\ngenesis_admin_public_keys: [%s]\n' "$GENESIS_ADMINS" >> "$NODE_CONFIG"
+        elif grep -q "^genesis_admin_public_keys: \[\]" "$NODE_CONFIG"; then
+            sed -i.bak "s/^genesis_admin_public_keys: \[\]/genesis_admin_public_keys: [$GENESIS_ADMINS]/" "$NODE_CONFIG"
+            rm -f "$NODE_CONFIG.bak"
+        fi
+    done
+fi
+
 # Extract identity for simulator
 IDENTITY=""
 if [ -f "$CONFIG_DIR/.env.local" ]; then
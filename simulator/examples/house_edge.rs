@@ -345,6 +345,163 @@ fn mask_for_ranks(cards: &[u8; 5], ranks: &[u8]) -> u8 {
     mask
 }
 
+// --- Wild-card video poker hold strategies ---
+fn wild_mask(cards: &[u8; 5], is_wild: impl Fn(u8) -> bool) -> u8 {
+    let mut mask = 0u8;
+    for (i, &card) in cards.iter().enumerate() {
+        if is_wild(card) {
+            mask |= 1u8 << i;
+        }
+    }
+    mask
+}
+
+/// Wild cards plus the largest (then highest) paired group of natural cards.
+fn wild_group_mask(cards: &[u8; 5], is_wild: impl Fn(u8) -> bool) -> u8 {
+    let mut counts = [0u8; 15];
+    for &card in cards {
+        if !is_wild(card) {
+            counts[card_rank_ace_high(card) as usize] += 1;
+        }
+    }
+    let best = (2..=14u8).max_by_key(|&r| counts[r as usize]).unwrap_or(2);
+    let mut mask = wild_mask(cards, &is_wild);
+    if counts[best as usize] >= 2 {
+        for (i, &card) in cards.iter().enumerate() {
+            if !is_wild(card) && card_rank_ace_high(card) == best {
+                mask |= 1u8 << i;
+            }
+        }
+    }
+    mask
+}
+
+/// Wild cards plus naturals of one suit, when together they make four to a flush.
+fn wild_flush_draw_mask(cards: &[u8; 5], is_wild: impl Fn(u8) -> bool) -> Option<u8> {
+    let wilds = wild_mask(cards, &is_wild);
+    (0..4u8).find_map(|suit| {
+        let mut mask = wilds;
+        for (i, &card) in cards.iter().enumerate() {
+            if !is_wild(card) && card_suit(card) == suit {
+                mask |= 1u8 << i;
+            }
+        }
+        (mask.count_ones() >= 4).then_some(mask)
+    })
+}
+
+fn deuces_wild_hold_mask(cards: &[u8; 5]) -> u8 {
+    use casino::video_poker::Hand;
+    let is_deuce = |card: u8| card_rank_one_based(card) == 2;
+    let deuces = wild_mask(cards, is_deuce);
+    match casino::video_poker::evaluate_deuces_wild(cards) {
+        Hand::FourDeuces => deuces,
+        Hand::ThreeOfAKind | Hand::FourOfAKind => wild_group_mask(cards, is_deuce),
+        Hand::HighCard | Hand::TwoPair => {
+            let group = wild_group_mask(cards, is_deuce);
+            if group != deuces {
+                return group;
+            }
+            wild_flush_draw_mask(cards, is_deuce).unwrap_or(deuces)
+        }
+        _ => 0b1_1111,
+    }
+}
+
+fn joker_poker_hold_mask(cards: &[u8; 5]) -> u8 {
+    use casino::video_poker::{Hand, JOKER};
+    let is_joker = |card: u8| card == JOKER;
+    let joker = wild_mask(cards, is_joker);
+    let mut high_cards = joker;
+    for (i, &card) in cards.iter().enumerate() {
+        if card != JOKER && card_rank_ace_high(card) >= 13 {
+            high_cards |= 1u8 << i;
+        }
+    }
+    match casino::video_poker::evaluate_joker_poker(cards) {
+        Hand::ThreeOfAKind | Hand::FourOfAKind => wild_group_mask(cards, is_joker),
+        Hand::TwoPair => {
+            let ranks = cards.map(card_rank_ace_high);
+            let paired: Vec<u8> = ranks
+                .iter()
+                .copied()
+                .filter(|r| ranks.iter().filter(|&&x| x == *r).count() == 2)
+                .collect();
+            mask_for_ranks(cards, &paired)
+        }
+        Hand::KingsOrBetter if joker != 0 => high_cards,
+        Hand::KingsOrBetter => wild_group_mask(cards, is_joker),
+        Hand::HighCard => {
+            let group = wild_group_mask(cards, is_joker);
+            if group != joker {
+                return group;
+            }
+            wild_flush_draw_mask(cards, is_joker).unwrap_or(high_cards)
+        }
+        _ => 0b1_1111,
+    }
+}
+
+// --- Let It Ride pull-back strategy ---
+fn lir_sorted_ranks(cards: &[u8]) -> Vec<u8> {
+    let mut ranks: Vec<u8> = cards.iter().map(|&c| card_rank_ace_high(c)).collect();
+    ranks.sort_unstable();
+    ranks
+}
+
+fn lir_has_paying_pair(ranks: &[u8]) -> bool {
+    ranks.windows(2).any(|w| w[0] == w[1] && w[0] >= 10)
+}
+
+fn lir_is_suited(cards: &[u8]) -> bool {
+    cards.iter().all(|&c| card_suit(c) == card_suit(cards[0]))
+}
+
+// Let it ride on three cards: paying pair or trips, three to a royal, three suited in a row
+// (except 2-3-4 and A-2-3), suited spread 4 with a high card, suited spread 5 with two.
+fn lir_ride_first(cards: &[u8; 3]) -> bool {
+    let ranks = lir_sorted_ranks(cards);
+    if lir_has_paying_pair(&ranks) || ranks[0] == ranks[2] {
+        return true;
+    }
+    if !lir_is_suited(cards) || ranks[0] == ranks[1] || ranks[1] == ranks[2] {
+        return false;
+    }
+    // Treat an ace as low when that gives the tighter spread.
+    let (low, high) = if ranks[2] == 14 && ranks[1] <= 5 {
+        (1, ranks[1])
+    } else {
+        (ranks[0], ranks[2])
+    };
+    let spread = high - low + 1;
+    let high_cards = ranks.iter().filter(|&&r| r >= 10).count();
+    match spread {
+        3 => high_cards == 3 || low >= 3,
+        4 => high_cards >= 1,
+        5 => high_cards >= 2,
+        _ => false,
+    }
+}
+
+// Let it ride on four cards: paying pair or better, four to a flush, four to an outside
+// straight, or four high cards to an inside straight.
+fn lir_ride_second(cards: &[u8; 4]) -> bool {
+    let ranks = lir_sorted_ranks(cards);
+    let has_pair = ranks.windows(2).any(|w| w[0] == w[1]);
+    let pairs = ranks.windows(2).filter(|w| w[0] == w[1]).count();
+    if lir_has_paying_pair(&ranks) || pairs >= 2 {
+        return true;
+    }
+    if lir_is_suited(cards) {
+        return true;
+    }
+    if has_pair {
+        return false;
+    }
+    let outside = ranks[3] - ranks[0] == 3 && ranks[0] >= 2 && ranks[3] <= 13;
+    outside || ranks[0] >= 10
+}
+
 // --- Simple cursor for parsing state blobs ---
 struct Cursor<'a> {
     buf: &'a [u8],
@@ -426,11 +583,16 @@ fn parse_blackjack_state(blob: &[u8]) -> Option<BjState> {
         2 => 1,
         3 => 4,
         4 => 5,
+        // v5 follows the side bets with the insurance amount and the dealer-peeked flag.
+        5 => 6,
         _ => return None,
     };
     for _ in 0..side_bet_fields {
         let _ = cur.read_u64_be()?;
     }
+    if version >= 5 {
+        let _dealer_peeked = cur.read_u8()?;
+    }
     let _initial = cur.read_bytes(2)?;
     let active_hand_idx = cur.read_u8()? as usize;
     let hand_count = cur.read_u8()? as usize;
@@ -630,6 +792,30 @@ fn parse_uth_state(blob: &[u8]) -> Option<UthStateParsed> {
     Some(UthStateParsed { player, community })
 }
 
+// --- Let It Ride parsing ---
+#[derive(Clone)]
+struct LirStateParsed {
+    stage: u8,
+    player: [u8; 3],
+    community: [u8; 2],
+}
+
+fn parse_let_it_ride_state(blob: &[u8]) -> Option<LirStateParsed> {
+    const STATE_VERSION: u8 = 1;
+    let mut cur = Cursor::new(blob);
+    let version = cur.read_u8()?;
+    if version != STATE_VERSION {
+        return None;
+    }
+    let stage = cur.read_u8()?;
+    let player = cur.read_bytes(3)?.try_into().ok()?;
+    let community = cur.read_bytes(2)?.try_into().ok()?;
+    // skip riding mask + bonus bet
+    let _riding = cur.read_u8()?;
+    let _bonus = cur.read_u64_be()?;
+    Some(LirStateParsed { stage, player, community })
+}
+
 // --- Video Poker parsing ---
 #[derive(Clone)]
 struct VpState {
@@ -953,16 +1139,31 @@ fn sim_casino_war_tie(trials: usize, bet: u64, side_bet: u64, seed: &Seed, playe
     })
 }
 
-fn sim_video_poker(trials: usize, bet: u64, seed: &Seed, player: &ed25519::PublicKey) -> Stats {
+fn sim_video_poker(
+    trials: usize,
+    bet: u64,
+    rules: u8,
+    hands: u8,
+    hold: fn(&[u8; 5]) -> u8,
+    seed: &Seed,
+    player: &ed25519::PublicKey,
+) -> Stats {
     run_trials(trials, |id| {
         let mut session = new_session(id, player, GameType::VideoPoker, bet);
         let mut net = 0i64;
         let mut wagered = 0u64;
         apply_payout(&mut net, &mut wagered, -(bet as i64));
         init_game(&mut session, seed, &mut net, &mut wagered);
+        apply_move(
+            &mut session,
+            seed,
+            &[0xFF, rules, hands],
+            &mut net,
+            &mut wagered,
+        );
 
         let state = parse_video_poker_state(&session.state_blob).expect("vp parse");
-        let mask = video_poker_hold_mask(&state.cards);
+        let mask = hold(&state.cards);
         let payload = [mask];
         apply_move(&mut session, seed, &payload, &mut net, &mut wagered);
         (net, wagered)
@@ -1004,6 +1205,8 @@ fn sim_video_poker_diag(
         let mut wagered = 0u64;
         apply_payout(&mut net, &mut wagered, -(bet as i64));
         init_game(&mut session, seed, &mut net, &mut wagered);
+        // Jacks or Better, full pay
+        apply_move(&mut session, seed, &[0xFF, 0], &mut net, &mut wagered);
 
         let state = parse_video_poker_state(&session.state_blob).expect("vp parse");
         let initial_hand = casino::video_poker::evaluate_hand(&state.cards);
@@ -1052,6 +1255,16 @@ fn video_poker_hand_label(hand: casino::video_poker::Hand) -> &'static str {
         casino::video_poker::Hand::FourOfAKind => "FOUR_OF_A_KIND",
         casino::video_poker::Hand::StraightFlush => "STRAIGHT_FLUSH",
         casino::video_poker::Hand::RoyalFlush => "ROYAL_FLUSH",
+        casino::video_poker::Hand::FourAces => "FOUR_ACES",
+        casino::video_poker::Hand::FourTwosThroughFours => "FOUR_TWOS_THROUGH_FOURS",
+        casino::video_poker::Hand::FourAcesWithKicker => "FOUR_ACES_WITH_KICKER",
+        casino::video_poker::Hand::FourTwosThroughFoursWithKicker => {
+            "FOUR_TWOS_THROUGH_FOURS_WITH_KICKER"
+        }
+        casino::video_poker::Hand::FiveOfAKind => "FIVE_OF_A_KIND",
+        casino::video_poker::Hand::WildRoyalFlush => "WILD_ROYAL_FLUSH",
+        casino::video_poker::Hand::FourDeuces => "FOUR_DEUCES",
+        casino::video_poker::Hand::KingsOrBetter => "KINGS_OR_BETTER",
     }
 }
 
@@ -1289,6 +1502,107 @@ fn sim_uth_with_side(trials: usize, bet: u64, trips: u64, six: u64, prog: u64, s
     })
 }
 
+fn sim_let_it_ride(trials: usize, bet: u64, bonus: u64, seed: &Seed, player: &ed25519::PublicKey) -> Stats {
+    run_trials(trials, |id| {
+        let mut session = new_session(id, player, GameType::LetItRide, bet);
+        let mut net = 0i64;
+        let mut wagered = 0u64;
+        apply_payout(&mut net, &mut wagered, -(bet as i64)); // Bet 3
+        init_game(&mut session, seed, &mut net, &mut wagered); // Bets 1 and 2
+
+        // Deal (with optional 3-Card Bonus)
+        let deal = if bonus > 0 { bet_payload_u64(0u8, bonus) } else { vec![0u8] };
+        apply_move(&mut session, seed, &deal, &mut net, &mut wagered);
+        let state = parse_let_it_ride_state(&session.state_blob).expect("lir parse");
+        if state.stage != 1 {
+            return (net, wagered);
+        }
+
+        // First decision on the player's three cards
+        let first = if lir_ride_first(&state.player) { 3u8 } else { 2u8 };
+        apply_move(&mut session, seed, &[first], &mut net, &mut wagered);
+        let state = parse_let_it_ride_state(&session.state_blob).expect("lir parse");
+
+        // Second decision with the first community card
+        let four = [state.player[0], state.player[1], state.player[2], state.community[0]];
+        let second = if lir_ride_second(&four) { 3u8 } else { 2u8 };
+        apply_move(&mut session, seed, &[second], &mut net, &mut wagered);
+        (net, wagered)
+    })
+}
+
+fn keno_play_payload(paytable: u8, races: u8, stake: u64, spots: &[u8]) -> Vec<u8> {
+    let mut payload = vec![0u8, paytable, races];
+    payload.extend_from_slice(&stake.to_be_bytes());
+    payload.push(spots.len() as u8);
+    payload.extend_from_slice(spots);
+    payload
+}
+
+fn binomial(n: u64, k: u64) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Exact Keno RTP for one paytable row: sum over catches k of
+/// C(spots, k) * C(80 - spots, 20 - k) / C(80, 20) * pay(k).
+fn keno_exact_rtp(paytable: casino::keno::Paytable, spots: usize) -> f64 {
+    let board = casino::keno::BOARD_SIZE as u64;
+    let drawn = casino::keno::DRAW_COUNT as u64;
+    let total = binomial(board, drawn);
+    (0..=spots)
+        .map(|k| {
+            let ways = binomial(spots as u64, k as u64) * binomial(board - spots as u64, drawn - k as u64);
+            let pay = casino::keno::pay_tenths(paytable, spots, k) as f64 / 10.0;
+            ways / total * pay
+        })
+        .sum()
+}
+
+fn sim_keno(trials: usize, paytable: u8, races: u8, stake: u64, spots: &[u8], seed: &Seed, player: &ed25519::PublicKey) -> Stats {
+    run_trials(trials, |id| {
+        let mut session = new_session(id, player, GameType::Keno, 0);
+        let mut net = 0i64;
+        let mut wagered = 0u64;
+        init_game(&mut session, seed, &mut net, &mut wagered);
+        let payload = keno_play_payload(paytable, races, stake, spots);
+        let result = process_move(&mut session, seed, &payload);
+        // The whole ticket is charged when it resolves.
+        apply_payout(&mut net, &mut wagered, -(session.bet as i64));
+        apply_result(&mut net, &mut wagered, result);
+        (net, wagered)
+    })
+}
+
+fn sim_big_six(wheel: u8, bet_type: u8, amount: u64, trials: usize, seed: &Seed, player: &ed25519::PublicKey) -> Stats {
+    run_trials(trials, |id| {
+        let mut session = new_session(id, player, GameType::BigSix, 0);
+        let mut net = 0i64;
+        let mut wagered = 0u64;
+        init_game(&mut session, seed, &mut net, &mut wagered);
+        apply_move(&mut session, seed, &[4u8, wheel], &mut net, &mut wagered);
+        let payload = bet_payload_table(0, bet_type, 0, amount);
+        apply_move(&mut session, seed, &payload, &mut net, &mut wagered);
+        apply_move(&mut session, seed, &[1u8], &mut net, &mut wagered);
+        (net, wagered)
+    })
+}
+
+fn sim_crash(scale: u8, target: u8, amount: u64, trials: usize, seed: &Seed, player: &ed25519::PublicKey) -> Stats {
+    run_trials(trials, |id| {
+        let mut session = new_session(id, player, GameType::Crash, 0);
+        let mut net = 0i64;
+        let mut wagered = 0u64;
+        init_game(&mut session, seed, &mut net, &mut wagered);
+        let payload = bet_payload_table(0, scale, target, amount);
+        apply_move(&mut session, seed, &payload, &mut net, &mut wagered);
+        apply_move(&mut session, seed, &[1u8], &mut net, &mut wagered);
+        (net, wagered)
+    })
+}
+
 fn main() {
     let seed = make_seed();
     let player = make_player();
@@ -1345,6 +1659,14 @@ fn main() {
         ("EN_PRISON_DOUBLE", 3u8),
         ("AMERICAN", 4u8),
     ];
+    // Announced bets (single-zero wheel only): (label, bet type, number, chips)
+    let announced_bets = vec![
+        ("VOISINS", 14u8, 0u8, 9u64),
+        ("TIERS", 15u8, 0u8, 6u64),
+        ("ORPHELINS", 16u8, 0u8, 5u64),
+        ("JEU_ZERO", 17u8, 0u8, 4u64),
+        ("NEIGHBOURS_17_2", 18u8, (1u8 << 6) | 17, 5u64),
+    ];
     for (rule_label, rule) in zero_rules {
         for (bet_label, bet_type, number) in &roulette_bets {
             let label = format!("{} ({})", bet_label, rule_label);
@@ -1359,6 +1681,23 @@ fn main() {
                 stderr: stats.stderr(),
             });
         }
+        if rule == 4 {
+            continue;
+        }
+        for (bet_label, bet_type, number, chips) in &announced_bets {
+            let label = format!("{} ({})", bet_label, rule_label);
+            let amount = BASE_BET * chips;
+            let stats = sim_roulette(*bet_type, *number, amount, rule, TRIALS, &seed, &player);
+            results.push(ResultRow {
+                game: "Roulette".to_string(),
+                bet: label,
+                trials: stats.trials,
+                avg_wagered: stats.mean_wagered(),
+                avg_net: stats.mean_net(),
+                edge: stats.house_edge(),
+                stderr: stats.stderr(),
+            });
+        }
     }
 
     // Sic Bo
@@ -1549,6 +1888,52 @@ fn main() {
         });
     }
 
+    // Proposition bets and Big 6/8
+    let prop_bets = vec![
+        ("ANY_SEVEN", 23u8),
+        ("ANY_CRAPS", 24u8),
+        ("HORN", 25u8),
+        ("C_AND_E", 26u8),
+        ("WORLD", 27u8),
+        ("BIG_6", 28u8),
+        ("BIG_8", 29u8),
+    ];
+    for (label, bet_type) in prop_bets {
+        let stats = sim_craps_simple(bet_type, 0u8, BASE_BET, TRIALS, &seed, &player);
+        results.push(ResultRow {
+            game: "Craps".to_string(),
+            bet: label.to_string(),
+            trials: stats.trials,
+            avg_wagered: stats.mean_wagered(),
+            avg_net: stats.mean_net(),
+            edge: stats.house_edge(),
+            stderr: stats.stderr(),
+        });
+    }
+
+    // Buy/Lay, vig on win and vig up front
+    let buy_lay_bets = vec![
+        ("BUY", 30u8),
+        ("LAY", 31u8),
+        ("BUY_VIG_UP_FRONT", 32u8),
+        ("LAY_VIG_UP_FRONT", 33u8),
+    ];
+    for (name, bet_type) in buy_lay_bets {
+        for target in [4u8, 5, 6, 8, 9, 10] {
+            let label = format!("{}_{}", name, target);
+            let stats = sim_craps_simple(bet_type, target, BASE_BET, TRIALS, &seed, &player);
+            results.push(ResultRow {
+                game: "Craps".to_string(),
+                bet: label,
+                trials: stats.trials,
+                avg_wagered: stats.mean_wagered(),
+                avg_net: stats.mean_net(),
+                edge: stats.house_edge(),
+                stderr: stats.stderr(),
+            });
+        }
+    }
+
     // Blackjack main + side bet
     let bj_main = sim_blackjack_main(TRIALS, BASE_BET, &seed, &player);
     results.push(ResultRow {
@@ -1599,16 +1984,55 @@ fn main() {
         stderr: cw_with_tie.stderr(),
     });
 
-    // Video Poker
-    let vp = sim_video_poker(TRIALS, BASE_BET, &seed, &player);
+    // Video Poker: every game on both paytables (rules byte = game << 1 | short pay).
+    let vp_games: [(&str, u8, fn(&[u8; 5]) -> u8); 6] = [
+        ("JACKS_OR_BETTER", 0, video_poker_hold_mask),
+        ("DEUCES_WILD", 1, deuces_wild_hold_mask),
+        ("BONUS_POKER", 2, video_poker_hold_mask),
+        ("DOUBLE_BONUS", 3, video_poker_hold_mask),
+        ("DOUBLE_DOUBLE_BONUS", 4, video_poker_hold_mask),
+        ("JOKER_POKER", 5, joker_poker_hold_mask),
+    ];
+    for (name, game, hold) in vp_games {
+        for (paytable, short_pay) in [("FULL_PAY", 0u8), ("SHORT_PAY", 1u8)] {
+            let rules = (game << 1) | short_pay;
+            let vp = sim_video_poker(TRIALS, BASE_BET, rules, 1, hold, &seed, &player);
+            // Jacks or Better full pay keeps the original MAIN row.
+            let bet = if rules == 0 {
+                "MAIN".to_string()
+            } else {
+                format!("{name}_{paytable}")
+            };
+            results.push(ResultRow {
+                game: "VideoPoker".to_string(),
+                bet,
+                trials: vp.trials,
+                avg_wagered: vp.mean_wagered(),
+                avg_net: vp.mean_net(),
+                edge: vp.house_edge(),
+                stderr: vp.stderr(),
+            });
+        }
+    }
+
+    // Multi-hand play: the same held cards redrawn on five hands.
+    let vp_multi = sim_video_poker(
+        TRIALS,
+        BASE_BET,
+        0,
+        5,
+        video_poker_hold_mask,
+        &seed,
+        &player,
+    );
     results.push(ResultRow {
         game: "VideoPoker".to_string(),
-        bet: "MAIN".to_string(),
-        trials: vp.trials,
-        avg_wagered: vp.mean_wagered(),
-        avg_net: vp.mean_net(),
-        edge: vp.house_edge(),
-        stderr: vp.stderr(),
+        bet: "FIVE_PLAY".to_string(),
+        trials: vp_multi.trials,
+        avg_wagered: vp_multi.mean_wagered(),
+        avg_net: vp_multi.mean_net(),
+        edge: vp_multi.house_edge(),
+        stderr: vp_multi.stderr(),
     });
 
     // Hi-Lo
@@ -1719,6 +2143,101 @@ fn main() {
         stderr: uth_prog.stderr(),
     });
 
+    // Let It Ride
+    let lir_main = sim_let_it_ride(TRIALS, BASE_BET, 0, &seed, &player);
+    results.push(ResultRow {
+        game: "LetItRide".to_string(),
+        bet: "MAIN".to_string(),
+        trials: lir_main.trials,
+        avg_wagered: lir_main.mean_wagered(),
+        avg_net: lir_main.mean_net(),
+        edge: lir_main.house_edge(),
+        stderr: lir_main.stderr(),
+    });
+
+    let lir_bonus = sim_let_it_ride(TRIALS, BASE_BET, SIDE_BET, &seed, &player);
+    let lir_bonus_ev = lir_bonus.mean_net() - lir_main.mean_net();
+    results.push(ResultRow {
+        game: "LetItRide".to_string(),
+        bet: "THREE_CARD_BONUS".to_string(),
+        trials: lir_bonus.trials,
+        avg_wagered: SIDE_BET as f64,
+        avg_net: lir_bonus_ev,
+        edge: -lir_bonus_ev / (SIDE_BET as f64),
+        stderr: lir_bonus.stderr(),
+    });
+
+    // Keno: exact RTP for every paytable row, plus a multi-race ticket simulated through the engine
+    let keno_paytables = [
+        ("CLASSIC", casino::keno::Paytable::Classic),
+        ("JACKPOT", casino::keno::Paytable::Jackpot),
+        ("STEADY", casino::keno::Paytable::Steady),
+    ];
+    for (table_label, paytable) in keno_paytables {
+        for spots in 1..=casino::keno::MAX_SPOTS {
+            let rtp = keno_exact_rtp(paytable, spots);
+            results.push(ResultRow {
+                game: "Keno".to_string(),
+                bet: format!("{}_{}_SPOT (exact)", table_label, spots),
+                trials: 0,
+                avg_wagered: 1.0,
+                avg_net: rtp - 1.0,
+                edge: 1.0 - rtp,
+                stderr: 0.0,
+            });
+        }
+
+        let keno_sim = sim_keno(TRIALS, paytable as u8, 5, BASE_BET, &[3, 14, 27, 45, 62], &seed, &player);
+        results.push(ResultRow {
+            game: "Keno".to_string(),
+            bet: format!("{}_5_SPOT_X5_RACES", table_label),
+            trials: keno_sim.trials,
+            avg_wagered: keno_sim.mean_wagered(),
+            avg_net: keno_sim.mean_net(),
+            edge: keno_sim.house_edge(),
+            stderr: keno_sim.stderr(),
+        });
+    }
+
+    // Big Six: every symbol on both wheel layouts
+    let big_six_symbols = ["$1", "$2", "$5", "$10", "$20", "JOKER", "LOGO"];
+    for (wheel_label, wheel) in [("CLASSIC", 0u8), ("ATLANTIC_CITY", 1u8)] {
+        for (bet_type, symbol) in big_six_symbols.iter().enumerate() {
+            let stats = sim_big_six(wheel, bet_type as u8, BASE_BET, TRIALS, &seed, &player);
+            results.push(ResultRow {
+                game: "BigSix".to_string(),
+                bet: format!("{}_{}", wheel_label, symbol),
+                trials: stats.trials,
+                avg_wagered: stats.mean_wagered(),
+                avg_net: stats.mean_net(),
+                edge: stats.house_edge(),
+                stderr: stats.stderr(),
+            });
+        }
+    }
+
+    // Crash: a spread of auto cash-out targets
+    let crash_targets = [
+        ("1.01X", 0u8, 1u8),
+        ("1.50X", 0, 50),
+        ("2X", 2, 2),
+        ("5X", 2, 5),
+        ("10X", 2, 10),
+        ("100X", 2, 100),
+    ];
+    for (label, scale, target) in crash_targets {
+        let stats = sim_crash(scale, target, BASE_BET, TRIALS, &seed, &player);
+        results.push(ResultRow {
+            game: "Crash".to_string(),
+            bet: label.to_string(),
+            trials: stats.trials,
+            avg_wagered: stats.mean_wagered(),
+            avg_net: stats.mean_net(),
+            edge: stats.house_edge(),
+            stderr: stats.stderr(),
+        });
+    }
+
     if std::env::var("VIDEO_POKER_DIAG").is_ok() {
         let diag_trials = std::env::var("VIDEO_POKER_DIAG_TRIALS")
             .ok()
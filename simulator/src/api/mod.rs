@@ -180,7 +180,8 @@ impl Api {
                 "/explorer/games/:pubkey",
                 get(crate::explorer::get_game_history),
             )
-            .route("/explorer/search", get(crate::explorer::search_explorer));
+            .route("/explorer/search", get(crate::explorer::search_explorer))
+            .route("/fees/estimate", get(crate::explorer::estimate_fees));
 
         #[cfg(feature = "passkeys")]
         let router = router
@@ -13,8 +13,8 @@ use commonware_cryptography::{
 use commonware_storage::qmdb::keyless;
 use commonware_utils::{from_hex, hex};
 use nullspace_types::{
-    casino::GameType,
-    execution::{Event, Instruction, Output, Progress},
+    casino::{GameType, TopUpKind},
+    execution::{Event, Instruction, Output, Progress, MAX_BLOCK_TRANSACTIONS},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -28,6 +28,9 @@ use std::{
 use crate::{ExplorerMetrics, Simulator};
 
 const EXPLORER_CACHE_CONTROL: &str = "public, max-age=2, stale-while-revalidate=10";
+/// Number of recent blocks sampled by the fee estimator when none is requested.
+const FEE_ESTIMATE_DEFAULT_BLOCKS: usize = 20;
+const FEE_ESTIMATE_MAX_BLOCKS: usize = 200;
 
 #[derive(Clone, Serialize)]
 pub struct ExplorerBlock {
@@ -48,6 +51,8 @@ pub struct ExplorerTransaction {
     position: u32,
     public_key: String,
     nonce: u64,
+    fee: u64,
+    expires_at_view: Option<u64>,
     description: String,
     instruction: String,
 }
@@ -262,6 +267,12 @@ fn record_event(
         Event::PlayerJoined { .. } => "PlayerJoined",
         Event::TournamentPhaseChanged { .. } => "TournamentPhaseChanged",
         Event::TournamentEnded { .. } => "TournamentEnded",
+        Event::TournamentCreated { .. } => "TournamentCreated",
+        Event::TournamentBuyIn { .. } => "TournamentBuyIn",
+        Event::TournamentCancelled { .. } => "TournamentCancelled",
+        Event::TournamentPrizesPaid { .. } => "TournamentPrizesPaid",
+        Event::TournamentTopUp { .. } => "TournamentTopUp",
+        Event::CasinoSessionsPruned { .. } => "CasinoSessionsPruned",
         Event::VaultCreated { .. } => "VaultCreated",
         Event::CollateralDeposited { .. } => "CollateralDeposited",
         Event::VusdtBorrowed { .. } => "VusdtBorrowed",
@@ -296,6 +307,18 @@ fn record_event(
         Event::GlobalTableOutcome { .. } => "GlobalTableOutcome",
         Event::GlobalTablePlayerSettled { .. } => "GlobalTablePlayerSettled",
         Event::GlobalTableFinalized { .. } => "GlobalTableFinalized",
+        Event::GlobalTableBetsCancelled { .. } => "GlobalTableBetsCancelled",
+        Event::GlobalTableBetsReplaced { .. } => "GlobalTableBetsReplaced",
+        Event::GlobalTableRolling { .. } => "GlobalTableRolling",
+        Event::GlobalTableCashedOut { .. } => "GlobalTableCashedOut",
+        Event::AdminRoleGranted { .. } => "AdminRoleGranted",
+        Event::AdminRoleRevoked { .. } => "AdminRoleRevoked",
+        Event::MultisigCreated { .. } => "MultisigCreated",
+        Event::MultisigProposed { .. } => "MultisigProposed",
+        Event::MultisigApproved { .. } => "MultisigApproved",
+        Event::MultisigExecuted { .. } => "MultisigExecuted",
+        Event::MultisigDissolved { .. } => "MultisigDissolved",
+        Event::TransactionFeePaid { .. } => "TransactionFeePaid",
     };
 
     let max_account_entries = explorer.max_account_entries;
@@ -376,6 +399,21 @@ fn record_event(
                 touch_account(pk);
             }
         }
+        Event::TournamentCreated { .. } => {}
+        Event::TournamentBuyIn { player, .. } | Event::TournamentTopUp { player, .. } => {
+            touch_account(player)
+        }
+        Event::CasinoSessionsPruned { pruner, .. } => touch_account(pruner),
+        Event::TournamentCancelled { refunds, .. } => {
+            for (pk, _) in refunds {
+                touch_account(pk);
+            }
+        }
+        Event::TournamentPrizesPaid { payouts, .. } => {
+            for (pk, _) in payouts {
+                touch_account(pk);
+            }
+        }
         Event::VaultCreated { player, .. } => touch_account(player),
         Event::CollateralDeposited { player, .. } => touch_account(player),
         Event::VusdtBorrowed { player, .. } => touch_account(player),
@@ -412,9 +450,21 @@ fn record_event(
         Event::GlobalTableLocked { .. } => {}
         Event::GlobalTableOutcome { .. } => {}
         Event::GlobalTableFinalized { .. } => {}
+        Event::GlobalTableRolling { .. } => {}
         Event::GlobalTableBetAccepted { player, .. } => touch_account(player),
         Event::GlobalTableBetRejected { player, .. } => touch_account(player),
         Event::GlobalTablePlayerSettled { player, .. } => touch_account(player),
+        Event::GlobalTableBetsCancelled { player, .. } => touch_account(player),
+        Event::GlobalTableBetsReplaced { player, .. } => touch_account(player),
+        Event::GlobalTableCashedOut { player, .. } => touch_account(player),
+        Event::AdminRoleGranted { .. } => {}
+        Event::AdminRoleRevoked { .. } => {}
+        Event::MultisigCreated { .. } => {}
+        Event::MultisigProposed { .. } => {}
+        Event::MultisigApproved { .. } => {}
+        Event::MultisigExecuted { .. } => {}
+        Event::MultisigDissolved { .. } => {}
+        Event::TransactionFeePaid { player, .. } => touch_account(player),
     }
 }
 
@@ -430,6 +480,11 @@ fn describe_game_type(game_type: &GameType) -> &'static str {
         GameType::SicBo => "Sic Bo",
         GameType::ThreeCard => "Three Card",
         GameType::UltimateHoldem => "Ultimate Hold'em",
+        GameType::PaiGow => "Pai Gow Poker",
+        GameType::LetItRide => "Let It Ride",
+        GameType::Keno => "Keno",
+        GameType::BigSix => "Big Six",
+        GameType::Crash => "Crash",
     }
 }
 
@@ -475,9 +530,17 @@ fn describe_instruction(instruction: &Instruction) -> String {
             tournament_id,
             start_time_ms,
             end_time_ms,
-        } => format!(
-            "Start tournament {tournament_id} (start {start_time_ms}, end {end_time_ms})"
-        ),
+            payout_structure,
+        } => {
+            let payouts = if payout_structure.is_default() {
+                "default payouts".to_string()
+            } else {
+                format!("{} paid places", payout_structure.shares_bps.len())
+            };
+            format!(
+                "Start tournament {tournament_id} (start {start_time_ms}, end {end_time_ms}, {payouts})"
+            )
+        }
         Instruction::GlobalTableInit { config } => format!(
             "Init global table ({})",
             describe_game_type(&config.game_type)
@@ -523,9 +586,56 @@ fn describe_instruction(instruction: &Instruction) -> String {
             "Finalize global table round ({}, round {round_id})",
             describe_game_type(game_type)
         ),
+        Instruction::GlobalTableCancelBets {
+            game_type,
+            round_id,
+        } => format!(
+            "Cancel global table bets ({}, round {round_id})",
+            describe_game_type(game_type)
+        ),
+        Instruction::GlobalTableReplaceBets {
+            game_type,
+            round_id,
+            bets,
+        } => format!(
+            "Replace with {} global table bets ({}, round {round_id})",
+            bets.len(),
+            describe_game_type(game_type)
+        ),
+        Instruction::GlobalTableCashOut {
+            game_type,
+            round_id,
+        } => format!(
+            "Cash out global table bets ({}, round {round_id})",
+            describe_game_type(game_type)
+        ),
         Instruction::CasinoEndTournament { tournament_id } => {
             format!("End tournament {tournament_id}")
         }
+        Instruction::CasinoCreateTournament {
+            tournament_id,
+            config,
+        } if config.is_sit_and_go() => format!(
+            "Create sit-and-go {tournament_id} ({} seats, {}s, buy-in {}, rake {} bps)",
+            config.max_seats, config.duration_secs, config.buy_in, config.rake_bps
+        ),
+        Instruction::CasinoCreateTournament {
+            tournament_id,
+            config,
+        } => format!(
+            "Create tournament {tournament_id} (buy-in {}, rake {} bps, guarantee {})",
+            config.buy_in, config.rake_bps, config.guarantee
+        ),
+        Instruction::CasinoCancelTournament { tournament_id } => {
+            format!("Cancel tournament {tournament_id}")
+        }
+        Instruction::CasinoTournamentTopUp {
+            tournament_id,
+            kind,
+        } => match kind {
+            TopUpKind::Rebuy => format!("Rebuy into tournament {tournament_id}"),
+            TopUpKind::AddOn => format!("Take add-on in tournament {tournament_id}"),
+        },
         Instruction::Stake { amount, duration } => {
             format!("Stake {amount} RNG for {duration} blocks")
         }
@@ -601,6 +711,42 @@ fn describe_instruction(instruction: &Instruction) -> String {
         } => format!(
             "Update oracle price {price_vusdt_numerator}/{price_rng_denominator} vUSDT/RNG"
         ),
+        Instruction::GrantAdminRole { public, role } => {
+            format!("Grant {role:?} admin role to {}", hex(public.as_ref()))
+        }
+        Instruction::RevokeAdminRole { public, role } => {
+            format!("Revoke {role:?} admin role from {}", hex(public.as_ref()))
+        }
+        Instruction::CreateMultisig {
+            signers,
+            threshold,
+            roles,
+            ..
+        } => format!(
+            "Create {threshold}-of-{} multisig (roles {roles:#04x})",
+            signers.len()
+        ),
+        Instruction::ProposeMultisig {
+            multisig_id,
+            instruction,
+        } => format!(
+            "Propose to multisig {multisig_id}: {}",
+            describe_instruction(instruction)
+        ),
+        Instruction::ApproveMultisig {
+            multisig_id,
+            proposal_id,
+        } => format!("Approve multisig {multisig_id} proposal {proposal_id}"),
+        Instruction::ExecuteMultisig {
+            multisig_id,
+            proposal_id,
+        } => format!("Execute multisig {multisig_id} proposal {proposal_id}"),
+        Instruction::DissolveMultisig { multisig_id } => {
+            format!("Dissolve multisig {multisig_id}")
+        }
+        Instruction::PruneCasinoSessions { session_ids } => {
+            format!("Prune {} casino sessions", session_ids.len())
+        }
     }
 }
 
@@ -639,6 +785,8 @@ pub(crate) fn apply_block_indexing(
                     position: idx as u32,
                     public_key: hex(tx.public.as_ref()),
                     nonce: tx.nonce,
+                    fee: tx.fee,
+                    expires_at_view: tx.expires_at_view,
                     description: describe_instruction(&tx.instruction),
                     instruction: format!("{:?}", tx.instruction),
                 };
@@ -914,6 +1062,77 @@ pub(crate) async fn search_explorer(
     .unwrap_or_else(|| StatusCode::NOT_FOUND.into_response())
 }
 
+#[derive(Deserialize)]
+pub(crate) struct FeeEstimateQuery {
+    blocks: Option<usize>,
+}
+
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    sorted[(sorted.len() - 1) * pct / 100]
+}
+
+/// Estimate the fee needed for inclusion from recently indexed blocks.
+///
+/// Fees only matter when blocks are full: the recommendation outbids the cheapest transaction
+/// that still made it into a recent full block, and is zero while blocks have spare room.
+pub(crate) async fn estimate_fees(
+    AxumState(simulator): AxumState<Arc<Simulator>>,
+    Query(params): Query<FeeEstimateQuery>,
+) -> impl IntoResponse {
+    let blocks = params
+        .blocks
+        .unwrap_or(FEE_ESTIMATE_DEFAULT_BLOCKS)
+        .clamp(1, FEE_ESTIMATE_MAX_BLOCKS);
+
+    let cache_key = format!("fees:blocks={blocks}");
+    let simulator_ref = Arc::clone(&simulator);
+    cached_json(simulator.as_ref(), &cache_key, move || {
+        let simulator = Arc::clone(&simulator_ref);
+        async move {
+            let explorer = simulator.explorer.read().await;
+
+            let mut sampled = 0;
+            let mut full_blocks = 0;
+            let mut fees = Vec::new();
+            let mut recommended_fee = 0u64;
+            for (height, block) in explorer.indexed_blocks.iter().rev().take(blocks) {
+                sampled += 1;
+                let block_fees: Vec<u64> = explorer
+                    .txs_by_height
+                    .get(height)
+                    .map(|digests| {
+                        digests
+                            .iter()
+                            .filter_map(|digest| explorer.txs_by_hash.get(digest))
+                            .map(|tx| tx.fee)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                if block.tx_count >= MAX_BLOCK_TRANSACTIONS {
+                    full_blocks += 1;
+                    let min_included = block_fees.iter().copied().min().unwrap_or(0);
+                    recommended_fee = recommended_fee.max(min_included.saturating_add(1));
+                }
+                fees.extend(block_fees);
+            }
+            fees.sort_unstable();
+
+            json!({
+                "blocks": sampled,
+                "full_blocks": full_blocks,
+                "transactions": fees.len(),
+                "median_fee": percentile(&fees, 50),
+                "p90_fee": percentile(&fees, 90),
+                "recommended_fee": recommended_fee,
+            })
+        }
+    })
+    .await
+}
+
 /// Get game history (completed games with logs) for an account
 pub(crate) async fn get_game_history(
     AxumState(simulator): AxumState<Arc<Simulator>>,
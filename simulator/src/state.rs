@@ -525,14 +525,18 @@ pub(crate) async fn index_events(
                     Event::GlobalTableRoundOpened { .. }
                     | Event::GlobalTableLocked { .. }
                     | Event::GlobalTableOutcome { .. }
-                    | Event::GlobalTableFinalized { .. } => {
+                    | Event::GlobalTableFinalized { .. }
+                    | Event::GlobalTableRolling { .. } => {
                         if needs_public_ops {
                             public_ops.push((loc, op.clone()));
                         }
                     }
                     Event::GlobalTableBetAccepted { player, .. }
                     | Event::GlobalTableBetRejected { player, .. }
-                    | Event::GlobalTablePlayerSettled { player, .. } => {
+                    | Event::GlobalTablePlayerSettled { player, .. }
+                    | Event::GlobalTableBetsCancelled { player, .. }
+                    | Event::GlobalTableBetsReplaced { player, .. }
+                    | Event::GlobalTableCashedOut { player, .. } => {
                         if has_account_subs
                             && (include_all_accounts
                                 || accounts_filter
@@ -545,12 +549,15 @@ pub(crate) async fn index_events(
                                 .push((loc, op.clone()));
                         }
                     }
-                    Event::TournamentStarted { .. } => {
+                    Event::TournamentStarted { .. } | Event::TournamentCreated { .. } => {
                         if needs_public_ops {
                             public_ops.push((loc, op.clone()));
                         }
                     }
-                    Event::PlayerJoined { player, .. } => {
+                    Event::PlayerJoined { player, .. }
+                    | Event::TournamentBuyIn { player, .. }
+                    | Event::TournamentTopUp { player, .. }
+                    | Event::CasinoSessionsPruned { pruner: player, .. } => {
                         if has_account_subs
                             && (include_all_accounts
                                 || accounts_filter
@@ -568,7 +575,13 @@ pub(crate) async fn index_events(
                             public_ops.push((loc, op.clone()));
                         }
                     }
-                    Event::TournamentEnded { rankings, .. } => {
+                    Event::TournamentEnded { rankings, .. }
+                    | Event::TournamentCancelled {
+                        refunds: rankings, ..
+                    }
+                    | Event::TournamentPrizesPaid {
+                        payouts: rankings, ..
+                    } => {
                         if has_account_subs {
                             for (player, _) in rankings {
                                 if include_all_accounts
@@ -636,6 +649,19 @@ pub(crate) async fn index_events(
                                 .push((loc, op.clone()));
                         }
                     }
+                    Event::TransactionFeePaid { player, .. } => {
+                        if has_account_subs
+                            && (include_all_accounts
+                                || accounts_filter
+                                    .map(|set| set.contains(player))
+                                    .unwrap_or(true))
+                        {
+                            account_ops
+                                .entry(player.clone())
+                                .or_default()
+                                .push((loc, op.clone()));
+                        }
+                    }
                     Event::AmmSwapped { player, .. } => {
                         if has_account_subs
                             && (include_all_accounts
@@ -679,6 +705,13 @@ pub(crate) async fn index_events(
                     | Event::AmmBootstrapFinalized { .. }
                     | Event::PolicyUpdated { .. }
                     | Event::OracleUpdated { .. }
+                    | Event::AdminRoleGranted { .. }
+                    | Event::AdminRoleRevoked { .. }
+                    | Event::MultisigCreated { .. }
+                    | Event::MultisigProposed { .. }
+                    | Event::MultisigApproved { .. }
+                    | Event::MultisigExecuted { .. }
+                    | Event::MultisigDissolved { .. }
                     | Event::TreasuryUpdated { .. }
                     | Event::TreasuryVestingUpdated { .. }
                     | Event::TreasuryAllocationReleased { .. }
@@ -241,6 +241,74 @@ fn log_admin_transactions(txs: &[Transaction]) {
                     "admin transaction submitted"
                 );
             }
+            Instruction::GrantAdminRole { public, role } => {
+                tracing::info!(
+                    action = "grant_admin_role",
+                    admin = %admin,
+                    tx_hash = %tx_hash,
+                    nonce = tx.nonce,
+                    public = %hex(&public.encode()),
+                    role = ?role,
+                    "admin transaction submitted"
+                );
+            }
+            Instruction::RevokeAdminRole { public, role } => {
+                tracing::info!(
+                    action = "revoke_admin_role",
+                    admin = %admin,
+                    tx_hash = %tx_hash,
+                    nonce = tx.nonce,
+                    public = %hex(&public.encode()),
+                    role = ?role,
+                    "admin transaction submitted"
+                );
+            }
+            Instruction::CreateMultisig {
+                signers,
+                threshold,
+                timelock_secs,
+                roles,
+            } => {
+                tracing::info!(
+                    action = "create_multisig",
+                    admin = %admin,
+                    tx_hash = %tx_hash,
+                    nonce = tx.nonce,
+                    signers = signers.len(),
+                    threshold = *threshold,
+                    timelock_secs = *timelock_secs,
+                    roles = *roles,
+                    "admin transaction submitted"
+                );
+            }
+            Instruction::ProposeMultisig {
+                multisig_id,
+                instruction,
+            } => {
+                tracing::info!(
+                    action = "propose_multisig",
+                    admin = %admin,
+                    tx_hash = %tx_hash,
+                    nonce = tx.nonce,
+                    multisig_id = *multisig_id,
+                    instruction_hash = %audit_hash(instruction.as_ref()),
+                    "admin transaction submitted"
+                );
+            }
+            Instruction::ExecuteMultisig {
+                multisig_id,
+                proposal_id,
+            } => {
+                tracing::info!(
+                    action = "execute_multisig",
+                    admin = %admin,
+                    tx_hash = %tx_hash,
+                    nonce = tx.nonce,
+                    multisig_id = *multisig_id,
+                    proposal_id = *proposal_id,
+                    "admin transaction submitted"
+                );
+            }
             _ => {}
         }
     }
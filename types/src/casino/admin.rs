@@ -0,0 +1,259 @@
+use bytes::{Buf, BufMut};
+use commonware_codec::{EncodeSize, Error, FixedSize, Read, ReadExt, ReadRangeExt, Write};
+use commonware_cryptography::ed25519::PublicKey;
+
+/// Upper bound on keys held by the admin registry.
+pub const MAX_ADMIN_REGISTRY_ENTRIES: usize = 64;
+
+/// Privileged capabilities an admin key can hold.
+///
+/// Each admin instruction checks exactly one role; `Registry` governs granting and revoking
+/// roles themselves.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AdminRole {
+    /// Policy updates and recovery pool management.
+    Policy = 0,
+    /// Treasury allocations, vesting and AMM bootstrap.
+    Treasury = 1,
+    /// Bridge deposits and withdrawal finalization.
+    Bridge = 2,
+    /// Tournament scheduling and player limits.
+    Tournament = 3,
+    /// Global table configuration and round control.
+    GlobalTable = 4,
+    /// Oracle price updates.
+    Oracle = 5,
+    /// Granting and revoking admin roles.
+    Registry = 6,
+}
+
+impl AdminRole {
+    pub const ALL: [AdminRole; 7] = [
+        AdminRole::Policy,
+        AdminRole::Treasury,
+        AdminRole::Bridge,
+        AdminRole::Tournament,
+        AdminRole::GlobalTable,
+        AdminRole::Oracle,
+        AdminRole::Registry,
+    ];
+
+    /// Bit for this role in `AdminEntry::roles`.
+    pub const fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+
+    /// Mask with every role set.
+    pub const fn all_mask() -> u8 {
+        (1 << AdminRole::ALL.len()) - 1
+    }
+}
+
+impl TryFrom<u8> for AdminRole {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AdminRole::Policy),
+            1 => Ok(AdminRole::Treasury),
+            2 => Ok(AdminRole::Bridge),
+            3 => Ok(AdminRole::Tournament),
+            4 => Ok(AdminRole::GlobalTable),
+            5 => Ok(AdminRole::Oracle),
+            6 => Ok(AdminRole::Registry),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Write for AdminRole {
+    fn write(&self, writer: &mut impl BufMut) {
+        (*self as u8).write(writer);
+    }
+}
+
+impl Read for AdminRole {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        let value = u8::read(reader)?;
+        AdminRole::try_from(value).map_err(|_| Error::InvalidEnum(value))
+    }
+}
+
+impl EncodeSize for AdminRole {
+    fn encode_size(&self) -> usize {
+        u8::SIZE
+    }
+}
+
+/// An admin key and the roles it holds (bitmask of `AdminRole::bit`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdminEntry {
+    pub public: PublicKey,
+    pub roles: u8,
+}
+
+impl AdminEntry {
+    pub fn has_role(&self, role: AdminRole) -> bool {
+        self.roles & role.bit() != 0
+    }
+}
+
+impl Write for AdminEntry {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.public.write(writer);
+        self.roles.write(writer);
+    }
+}
+
+impl Read for AdminEntry {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            public: PublicKey::read(reader)?,
+            roles: u8::read(reader)? & AdminRole::all_mask(),
+        })
+    }
+}
+
+impl EncodeSize for AdminEntry {
+    fn encode_size(&self) -> usize {
+        self.public.encode_size() + self.roles.encode_size()
+    }
+}
+
+/// Consensus-stored admin keys and their roles.
+///
+/// Entries are kept sorted by key, and keys without any role are dropped.
+///
+/// Roles held by a multisig account are "guarded": single keys keep their entries but can no
+/// longer exercise those roles, so the multisig is the only way to issue the instruction.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct AdminRegistry {
+    pub admins: Vec<AdminEntry>,
+    /// Roles that only multisig accounts may exercise (bitmask of `AdminRole::bit`).
+    pub guarded_roles: u8,
+    pub next_multisig_id: u64,
+}
+
+impl AdminRegistry {
+    /// Registry where every key holds every role (used for the genesis bootstrap).
+    pub fn with_full_access(keys: impl IntoIterator<Item = PublicKey>) -> Self {
+        let mut registry = Self::default();
+        for key in keys {
+            for role in AdminRole::ALL {
+                registry.grant(&key, role);
+            }
+        }
+        registry
+    }
+
+    /// Whether a single key may exercise `role`.
+    pub fn has_role(&self, public: &PublicKey, role: AdminRole) -> bool {
+        !self.is_guarded(role) && self.holds_role(public, role)
+    }
+
+    /// Whether `public` holds `role`, even if the role is guarded by a multisig.
+    pub fn holds_role(&self, public: &PublicKey, role: AdminRole) -> bool {
+        self.admins
+            .binary_search_by(|entry| entry.public.cmp(public))
+            .map(|idx| self.admins[idx].has_role(role))
+            .unwrap_or(false)
+    }
+
+    pub fn is_guarded(&self, role: AdminRole) -> bool {
+        self.guarded_roles & role.bit() != 0
+    }
+
+    /// Number of keys holding `role`.
+    pub fn role_count(&self, role: AdminRole) -> usize {
+        self.admins
+            .iter()
+            .filter(|entry| entry.has_role(role))
+            .count()
+    }
+
+    /// Grant `role` to `public`. Returns false if the key already held it or the registry is full.
+    pub fn grant(&mut self, public: &PublicKey, role: AdminRole) -> bool {
+        match self
+            .admins
+            .binary_search_by(|entry| entry.public.cmp(public))
+        {
+            Ok(idx) => {
+                let entry = &mut self.admins[idx];
+                if entry.has_role(role) {
+                    return false;
+                }
+                entry.roles |= role.bit();
+                true
+            }
+            Err(idx) => {
+                if self.admins.len() >= MAX_ADMIN_REGISTRY_ENTRIES {
+                    return false;
+                }
+                self.admins.insert(
+                    idx,
+                    AdminEntry {
+                        public: public.clone(),
+                        roles: role.bit(),
+                    },
+                );
+                true
+            }
+        }
+    }
+
+    /// Revoke `role` from `public`. Returns false if the key did not hold it.
+    pub fn revoke(&mut self, public: &PublicKey, role: AdminRole) -> bool {
+        let Ok(idx) = self
+            .admins
+            .binary_search_by(|entry| entry.public.cmp(public))
+        else {
+            return false;
+        };
+        let entry = &mut self.admins[idx];
+        if !entry.has_role(role) {
+            return false;
+        }
+        entry.roles &= !role.bit();
+        if entry.roles == 0 {
+            self.admins.remove(idx);
+        }
+        true
+    }
+}
+
+impl Write for AdminRegistry {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.admins.write(writer);
+        self.guarded_roles.write(writer);
+        self.next_multisig_id.write(writer);
+    }
+}
+
+impl Read for AdminRegistry {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        let mut admins = Vec::<AdminEntry>::read_range(reader, 0..=MAX_ADMIN_REGISTRY_ENTRIES)?;
+        admins.retain(|entry| entry.roles != 0);
+        admins.sort_by(|a, b| a.public.cmp(&b.public));
+        admins.dedup_by(|a, b| a.public == b.public);
+        Ok(Self {
+            admins,
+            guarded_roles: u8::read(reader)? & AdminRole::all_mask(),
+            next_multisig_id: u64::read(reader)?,
+        })
+    }
+}
+
+impl EncodeSize for AdminRegistry {
+    fn encode_size(&self) -> usize {
+        self.admins.encode_size()
+            + self.guarded_roles.encode_size()
+            + self.next_multisig_id.encode_size()
+    }
+}
@@ -13,7 +13,7 @@ pub const STARTING_SHIELDS: u32 = 3;
 /// Starting doubles per tournament
 pub const STARTING_DOUBLES: u32 = 3;
 
-/// Game session expiry in blocks
+/// Views without a move after which an active game session is resolved as abandoned
 pub const SESSION_EXPIRY: u64 = 100;
 
 /// Faucet deposit amount (dev mode only)
@@ -86,15 +86,46 @@ pub const ERROR_NOT_IN_TOURNAMENT: u8 = 14;
 /// Error for unauthorized admin instructions.
 pub const ERROR_UNAUTHORIZED: u8 = 15;
 
+/// Default views a completed casino session is kept in state before it is deleted (~1 hour).
+/// The outcome stays available from the `CasinoGameCompleted` event.
+pub const DEFAULT_CASINO_SESSION_RETENTION_VIEWS: u64 = 1_200;
+/// Completion views swept per block, bounding the catch-up work after idle periods.
+pub const MAX_SESSION_GC_VIEWS_PER_BLOCK: u64 = 64;
+/// Maximum session ids in one `PruneCasinoSessions` instruction.
+pub const MAX_PRUNE_CASINO_SESSIONS: usize = 256;
+
 /// Tournament duration in seconds (5 minutes)
 pub const TOURNAMENT_DURATION_SECS: u64 = 5 * 60;
 
+/// Highest house rake a paid tournament may take from each buy-in (basis points).
+pub const MAX_TOURNAMENT_RAKE_BPS: u16 = 2_000; // 20%
+
+/// Most entrants a stored tournament record may list.
+pub const MAX_TOURNAMENT_PLAYERS: usize = 1_000;
+
+/// Most finishing positions a tournament payout structure may pay.
+pub const MAX_TOURNAMENT_PAYOUT_PLACES: usize = 100;
+
+/// Most seats a sit-and-go tournament may have.
+pub const MAX_SIT_AND_GO_SEATS: u32 = 100;
+
+/// Most running sit-and-go tournaments waiting on their scheduled end.
+pub const MAX_SCHEDULED_TOURNAMENT_ENDS: usize = 10_000;
+
+/// Most sit-and-go tournaments ended on chain time in a single block.
+pub const MAX_TOURNAMENT_ENDS_PER_BLOCK: usize = 16;
+
+/// Most games a tournament may list in its allowed set or bet limits.
+pub const MAX_TOURNAMENT_GAME_RULES: usize = 32;
+
 /// Fixed-point scale used for staking reward accounting (`reward_per_voting_power`).
 pub const STAKING_REWARD_SCALE: u128 = 1_000_000_000_000_000_000;
 
 /// AMM defaults.
 pub const AMM_DEFAULT_FEE_BASIS_POINTS: u16 = 30; // 0.30%
 pub const AMM_DEFAULT_SELL_TAX_BASIS_POINTS: u16 = 500; // 5.00%
+/// Default share of each transaction fee that is burned (basis points); the rest goes to the house.
+pub const DEFAULT_TRANSACTION_FEE_BURN_BPS: u16 = 5_000; // 50%
 /// Default bootstrap price used when the AMM has no reserves (1 RNG = 1 vUSDT).
 pub const AMM_BOOTSTRAP_PRICE_VUSDT_NUMERATOR: u64 = 1;
 pub const AMM_BOOTSTRAP_PRICE_RNG_DENOMINATOR: u64 = 1;
@@ -4,9 +4,9 @@ use commonware_cryptography::ed25519::PublicKey;
 
 use super::{
     AMM_BOOTSTRAP_PRICE_RNG_DENOMINATOR, AMM_BOOTSTRAP_PRICE_VUSDT_NUMERATOR,
-    AMM_DEFAULT_SELL_TAX_BASIS_POINTS, FREEROLL_CREDIT_EXPIRY_SECS,
-    FREEROLL_CREDIT_IMMEDIATE_BPS, FREEROLL_CREDIT_VEST_SECS,
-    THREE_CARD_PROGRESSIVE_BASE_JACKPOT, UTH_PROGRESSIVE_BASE_JACKPOT,
+    AMM_DEFAULT_SELL_TAX_BASIS_POINTS, DEFAULT_CASINO_SESSION_RETENTION_VIEWS,
+    DEFAULT_TRANSACTION_FEE_BURN_BPS, FREEROLL_CREDIT_EXPIRY_SECS, FREEROLL_CREDIT_IMMEDIATE_BPS,
+    FREEROLL_CREDIT_VEST_SECS, THREE_CARD_PROGRESSIVE_BASE_JACKPOT, UTH_PROGRESSIVE_BASE_JACKPOT,
 };
 
 const MAX_ORACLE_SOURCE_BYTES: usize = 64;
@@ -504,6 +504,10 @@ pub struct PolicyState {
     pub oracle_enabled: bool,
     pub oracle_max_deviation_bps: u16,
     pub oracle_stale_secs: u64,
+    /// Share of each transaction fee that is burned; the rest is credited to the house.
+    pub transaction_fee_burn_bps: u16,
+    /// Views a completed casino session is kept in state before it is deleted.
+    pub casino_session_retention_views: u64,
 }
 
 impl Default for PolicyState {
@@ -539,6 +543,8 @@ impl Default for PolicyState {
             oracle_enabled: false,
             oracle_max_deviation_bps: 500,
             oracle_stale_secs: 900,
+            transaction_fee_burn_bps: DEFAULT_TRANSACTION_FEE_BURN_BPS,
+            casino_session_retention_views: DEFAULT_CASINO_SESSION_RETENTION_VIEWS,
         }
     }
 }
@@ -575,6 +581,8 @@ impl Write for PolicyState {
         self.oracle_enabled.write(writer);
         self.oracle_max_deviation_bps.write(writer);
         self.oracle_stale_secs.write(writer);
+        self.transaction_fee_burn_bps.write(writer);
+        self.casino_session_retention_views.write(writer);
     }
 }
 
@@ -649,6 +657,16 @@ impl Read for PolicyState {
             } else {
                 0
             },
+            transaction_fee_burn_bps: if reader.remaining() >= u16::SIZE {
+                u16::read(reader)?
+            } else {
+                DEFAULT_TRANSACTION_FEE_BURN_BPS
+            },
+            casino_session_retention_views: if reader.remaining() >= u64::SIZE {
+                u64::read(reader)?
+            } else {
+                DEFAULT_CASINO_SESSION_RETENTION_VIEWS
+            },
         })
     }
 }
@@ -685,6 +703,8 @@ impl EncodeSize for PolicyState {
             + self.oracle_enabled.encode_size()
             + self.oracle_max_deviation_bps.encode_size()
             + self.oracle_stale_secs.encode_size()
+            + self.transaction_fee_burn_bps.encode_size()
+            + self.casino_session_retention_views.encode_size()
     }
 }
 
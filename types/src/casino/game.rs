@@ -15,6 +15,29 @@ pub enum GameType {
     SicBo = 7,
     ThreeCard = 8,
     UltimateHoldem = 9,
+    PaiGow = 10,
+    LetItRide = 11,
+    Keno = 12,
+    BigSix = 13,
+    Crash = 14,
+}
+
+impl GameType {
+    /// Games that open with `bet = 0` because their stakes are charged later: table bets
+    /// are placed by `CasinoGameMove` deductions, and Keno charges its whole ticket when
+    /// it resolves.
+    pub fn allows_zero_bet(self) -> bool {
+        matches!(
+            self,
+            Self::Baccarat
+                | Self::BigSix
+                | Self::Craps
+                | Self::Crash
+                | Self::Keno
+                | Self::Roulette
+                | Self::SicBo
+        )
+    }
 }
 
 impl Write for GameType {
@@ -39,6 +62,11 @@ impl Read for GameType {
             7 => Ok(Self::SicBo),
             8 => Ok(Self::ThreeCard),
             9 => Ok(Self::UltimateHoldem),
+            10 => Ok(Self::PaiGow),
+            11 => Ok(Self::LetItRide),
+            12 => Ok(Self::Keno),
+            13 => Ok(Self::BigSix),
+            14 => Ok(Self::Crash),
             i => Err(Error::InvalidEnum(i)),
         }
     }
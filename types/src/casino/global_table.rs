@@ -3,10 +3,16 @@ use commonware_codec::{EncodeSize, Error, FixedSize, Read, ReadExt, ReadRangeExt
 
 use super::{GameSession, GameType};
 
-const MAX_GLOBAL_TABLE_TOTALS: usize = 64;
+/// Distinct (bet type, target) pairs tracked in a round's totals heatmap.
+pub const MAX_GLOBAL_TABLE_TOTALS: usize = 128;
+/// Players that can hold unsettled bets at one global table.
+pub const MAX_GLOBAL_TABLE_PLAYERS: usize = 256;
 const MAX_GLOBAL_TABLE_BETS: usize = 64;
 const MAX_RNG_COMMIT_LEN: usize = 32;
 const MAX_ROLL_SEED_LEN: usize = 32;
+const MAX_GLOBAL_TABLE_OUTCOME_LEN: usize = 8;
+/// Matches the `GameSession::state_blob` limit.
+const MAX_SESSION_BLOB_LEN: usize = 1024;
 
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -192,6 +198,11 @@ pub struct GlobalTableRound {
     /// Seed snapshot used to replay the roll deterministically (0 or 32 bytes).
     pub roll_seed: Vec<u8>,
     pub totals: Vec<GlobalTableTotal>,
+    /// Game-specific outcome bytes for the revealed round (empty until reveal).
+    ///
+    /// Craps: `[d1, d2]`. Roulette: `[result]`. Sic Bo: `[die1, die2, die3]`.
+    /// Baccarat: `[player_len, player_cards.., banker_len, banker_cards..]`.
+    pub outcome: Vec<u8>,
 }
 
 impl Write for GlobalTableRound {
@@ -209,6 +220,7 @@ impl Write for GlobalTableRound {
         self.rng_commit.write(writer);
         self.roll_seed.write(writer);
         self.totals.write(writer);
+        self.outcome.write(writer);
     }
 }
 
@@ -236,6 +248,7 @@ impl Read for GlobalTableRound {
         }
         let totals =
             Vec::<GlobalTableTotal>::read_range(reader, 0..=MAX_GLOBAL_TABLE_TOTALS)?;
+        let outcome = Vec::<u8>::read_range(reader, 0..=MAX_GLOBAL_TABLE_OUTCOME_LEN)?;
 
         Ok(Self {
             game_type,
@@ -251,6 +264,7 @@ impl Read for GlobalTableRound {
             rng_commit,
             roll_seed,
             totals,
+            outcome,
         })
     }
 }
@@ -270,6 +284,7 @@ impl EncodeSize for GlobalTableRound {
             + self.rng_commit.encode_size()
             + self.roll_seed.encode_size()
             + self.totals.encode_size()
+            + self.outcome.encode_size()
     }
 }
 
@@ -278,6 +293,10 @@ pub struct GlobalTablePlayerSession {
     pub game_type: GameType,
     pub session: GameSession,
     pub last_settled_round: u64,
+    /// Bets submitted for the open round (cleared on settle).
+    pub round_bets: Vec<GlobalTableBet>,
+    /// Session state before the open round's first bet; restored on cancel/replace.
+    pub round_start_blob: Vec<u8>,
 }
 
 impl Write for GlobalTablePlayerSession {
@@ -285,6 +304,8 @@ impl Write for GlobalTablePlayerSession {
         self.game_type.write(writer);
         self.session.write(writer);
         self.last_settled_round.write(writer);
+        self.round_bets.write(writer);
+        self.round_start_blob.write(writer);
     }
 }
 
@@ -292,10 +313,24 @@ impl Read for GlobalTablePlayerSession {
     type Cfg = ();
 
     fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        let game_type = GameType::read(reader)?;
+        let session = GameSession::read(reader)?;
+        let last_settled_round = u64::read(reader)?;
+        // Sessions written before cancel/replace support end here.
+        let (round_bets, round_start_blob) = if reader.has_remaining() {
+            (
+                Vec::<GlobalTableBet>::read_range(reader, global_table_bets_cfg())?,
+                Vec::<u8>::read_range(reader, 0..=MAX_SESSION_BLOB_LEN)?,
+            )
+        } else {
+            (Vec::new(), Vec::new())
+        };
         Ok(Self {
-            game_type: GameType::read(reader)?,
-            session: GameSession::read(reader)?,
-            last_settled_round: u64::read(reader)?,
+            game_type,
+            session,
+            last_settled_round,
+            round_bets,
+            round_start_blob,
         })
     }
 }
@@ -305,6 +340,8 @@ impl EncodeSize for GlobalTablePlayerSession {
         self.game_type.encode_size()
             + self.session.encode_size()
             + self.last_settled_round.encode_size()
+            + self.round_bets.encode_size()
+            + self.round_start_blob.encode_size()
     }
 }
 
@@ -3,21 +3,25 @@
 //! Defines game/session/player/tournament/economy state and constants used by the execution layer
 //! and clients.
 
+mod admin;
 mod codec;
 mod constants;
 mod economy;
 mod game;
 mod global_table;
 mod leaderboard;
+mod multisig;
 mod player;
 mod tournament;
 
+pub use admin::*;
 pub use codec::{read_string, string_encode_size, write_string};
 pub use constants::*;
 pub use economy::*;
 pub use game::*;
 pub use global_table::*;
 pub use leaderboard::*;
+pub use multisig::*;
 pub use player::*;
 pub use tournament::*;
 
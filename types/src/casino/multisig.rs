@@ -0,0 +1,160 @@
+use bytes::{Buf, BufMut};
+use commonware_codec::{EncodeSize, Error, Read, ReadExt, ReadRangeExt, Write};
+use commonware_cryptography::ed25519::PublicKey;
+
+use super::AdminRole;
+use crate::execution::Instruction;
+
+/// Upper bound on signers in a multisig account.
+pub const MAX_MULTISIG_SIGNERS: usize = 16;
+/// Upper bound on a multisig time-lock (30 days).
+pub const MAX_MULTISIG_TIMELOCK_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Threshold account that exercises admin roles through proposals.
+///
+/// Any signer can propose an inner instruction; it executes once `threshold` distinct signers
+/// approved it and `timelock_secs` elapsed since the threshold was reached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultisigAccount {
+    pub id: u64,
+    /// Sorted, unique signer keys.
+    pub signers: Vec<PublicKey>,
+    pub threshold: u8,
+    pub timelock_secs: u64,
+    /// Admin roles this account exercises (bitmask of `AdminRole::bit`).
+    pub roles: u8,
+    pub next_proposal_id: u64,
+}
+
+impl MultisigAccount {
+    pub fn is_signer(&self, public: &PublicKey) -> bool {
+        self.signers.binary_search(public).is_ok()
+    }
+
+    pub fn has_role(&self, role: AdminRole) -> bool {
+        self.roles & role.bit() != 0
+    }
+}
+
+impl Write for MultisigAccount {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.id.write(writer);
+        self.signers.write(writer);
+        self.threshold.write(writer);
+        self.timelock_secs.write(writer);
+        self.roles.write(writer);
+        self.next_proposal_id.write(writer);
+    }
+}
+
+impl Read for MultisigAccount {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        let id = u64::read(reader)?;
+        let mut signers = Vec::<PublicKey>::read_range(reader, 1..=MAX_MULTISIG_SIGNERS)?;
+        signers.sort_unstable();
+        signers.dedup();
+        let threshold = u8::read(reader)?;
+        if threshold == 0 || threshold as usize > signers.len() {
+            return Err(Error::Invalid("MultisigAccount", "invalid threshold"));
+        }
+        Ok(Self {
+            id,
+            signers,
+            threshold,
+            timelock_secs: u64::read(reader)?,
+            roles: u8::read(reader)? & AdminRole::all_mask(),
+            next_proposal_id: u64::read(reader)?,
+        })
+    }
+}
+
+impl EncodeSize for MultisigAccount {
+    fn encode_size(&self) -> usize {
+        self.id.encode_size()
+            + self.signers.encode_size()
+            + self.threshold.encode_size()
+            + self.timelock_secs.encode_size()
+            + self.roles.encode_size()
+            + self.next_proposal_id.encode_size()
+    }
+}
+
+/// A pending or executed multisig proposal wrapping one inner instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultisigProposal {
+    pub multisig_id: u64,
+    pub id: u64,
+    pub proposer: PublicKey,
+    pub instruction: Instruction,
+    /// Sorted, unique approving signers (the proposer approves implicitly).
+    pub approvals: Vec<PublicKey>,
+    /// Unix seconds after which the proposal may execute (0 until the threshold is reached).
+    pub executable_at: u64,
+    pub executed: bool,
+}
+
+impl MultisigProposal {
+    pub fn is_approved(&self, multisig: &MultisigAccount) -> bool {
+        self.approvals.len() >= multisig.threshold as usize
+    }
+
+    /// Record an approval from `signer`. Returns false if they already approved.
+    pub fn approve(&mut self, signer: &PublicKey) -> bool {
+        match self.approvals.binary_search(signer) {
+            Ok(_) => false,
+            Err(idx) => {
+                self.approvals.insert(idx, signer.clone());
+                true
+            }
+        }
+    }
+}
+
+impl Write for MultisigProposal {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.multisig_id.write(writer);
+        self.id.write(writer);
+        self.proposer.write(writer);
+        self.instruction.write(writer);
+        self.approvals.write(writer);
+        self.executable_at.write(writer);
+        self.executed.write(writer);
+    }
+}
+
+impl Read for MultisigProposal {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        let multisig_id = u64::read(reader)?;
+        let id = u64::read(reader)?;
+        let proposer = PublicKey::read(reader)?;
+        let instruction = Instruction::read(reader)?;
+        let mut approvals = Vec::<PublicKey>::read_range(reader, 0..=MAX_MULTISIG_SIGNERS)?;
+        approvals.sort_unstable();
+        approvals.dedup();
+        Ok(Self {
+            multisig_id,
+            id,
+            proposer,
+            instruction,
+            approvals,
+            executable_at: u64::read(reader)?,
+            executed: bool::read(reader)?,
+        })
+    }
+}
+
+impl EncodeSize for MultisigProposal {
+    fn encode_size(&self) -> usize {
+        self.multisig_id.encode_size()
+            + self.id.encode_size()
+            + self.proposer.encode_size()
+            + self.instruction.encode_size()
+            + self.approvals.encode_size()
+            + self.executable_at.encode_size()
+            + self.executed.encode_size()
+    }
+}
@@ -71,6 +71,8 @@ pub struct PlayerSessionState {
     pub last_session_ts: u64,
     pub bridge_daily_day: u64,
     pub bridge_daily_withdrawn: u64,
+    /// Most recently started session, deleted once complete when the next one starts.
+    pub last_session: Option<u64>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
@@ -143,6 +145,7 @@ impl Player {
                 last_session_ts: 0,
                 bridge_daily_day: 0,
                 bridge_daily_withdrawn: 0,
+                last_session: None,
             },
         }
     }
@@ -271,6 +274,7 @@ impl Write for Player {
         self.session.last_session_ts.write(writer);
         self.session.bridge_daily_day.write(writer);
         self.session.bridge_daily_withdrawn.write(writer);
+        self.session.last_session.write(writer);
     }
 }
 
@@ -368,6 +372,11 @@ impl Read for Player {
         } else {
             0
         };
+        let last_session = if reader.remaining() > 0 {
+            Option::<u64>::read(reader)?
+        } else {
+            None
+        };
 
         Ok(Self {
             nonce,
@@ -413,6 +422,7 @@ impl Read for Player {
                 last_session_ts,
                 bridge_daily_day,
                 bridge_daily_withdrawn,
+                last_session,
             },
         })
     }
@@ -454,6 +464,7 @@ impl EncodeSize for Player {
             + self.session.last_session_ts.encode_size()
             + self.session.bridge_daily_day.encode_size()
             + self.session.bridge_daily_withdrawn.encode_size()
+            + self.session.last_session.encode_size()
     }
 }
 
@@ -524,3 +535,66 @@ impl EncodeSize for GameSession {
             + self.tournament_id.encode_size()
     }
 }
+
+/// Progress of the completed-session and session-expiry sweeps.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SessionGcState {
+    /// Next completion view whose sessions have not been swept yet.
+    pub next_view: u64,
+    /// Next deadline view whose sessions have not been checked for expiry yet.
+    pub next_deadline_view: u64,
+}
+
+impl Write for SessionGcState {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.next_view.write(writer);
+        self.next_deadline_view.write(writer);
+    }
+}
+
+impl Read for SessionGcState {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            next_view: u64::read(reader)?,
+            next_deadline_view: u64::read(reader)?,
+        })
+    }
+}
+
+impl EncodeSize for SessionGcState {
+    fn encode_size(&self) -> usize {
+        self.next_view.encode_size() + self.next_deadline_view.encode_size()
+    }
+}
+
+/// An active session to check for inactivity at its deadline view.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionDeadline {
+    pub session_id: u64,
+    /// Move count when the deadline was set; any later move means the player is still active.
+    pub move_count: u32,
+}
+
+impl Write for SessionDeadline {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.session_id.write(writer);
+        self.move_count.write(writer);
+    }
+}
+
+impl Read for SessionDeadline {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            session_id: u64::read(reader)?,
+            move_count: u32::read(reader)?,
+        })
+    }
+}
+
+impl FixedSize for SessionDeadline {
+    const SIZE: usize = u64::SIZE + u32::SIZE;
+}
@@ -1,6 +1,6 @@
 use super::*;
 use commonware_codec::Encode;
-use commonware_codec::ReadExt;
+use commonware_codec::{EncodeSize, FixedSize, ReadExt, Write};
 use commonware_cryptography::{ed25519::PrivateKey, Signer};
 use commonware_math::algebra::Random;
 use rand::{rngs::StdRng, SeedableRng};
@@ -18,6 +18,11 @@ fn test_game_type_roundtrip() {
         GameType::SicBo,
         GameType::ThreeCard,
         GameType::UltimateHoldem,
+        GameType::PaiGow,
+        GameType::LetItRide,
+        GameType::Keno,
+        GameType::BigSix,
+        GameType::Crash,
     ] {
         let encoded = game_type.encode();
         let decoded = GameType::read(&mut &encoded[..]).unwrap();
@@ -34,6 +39,19 @@ fn test_player_roundtrip() {
     assert_eq!(player, decoded);
 }
 
+#[test]
+fn test_player_reads_encoding_without_last_session() {
+    let mut player = Player::new("TestPlayer".to_string());
+    player.session.last_session = Some(7);
+    let encoded = player.encode();
+    assert_eq!(Player::read(&mut &encoded[..]).unwrap(), player);
+
+    // Players written before `last_session` existed end right before it.
+    let mut legacy = &encoded[..encoded.len() - 9];
+    let decoded = Player::read(&mut legacy).unwrap();
+    assert_eq!(decoded.session.last_session, None);
+}
+
 #[test]
 fn test_player_validate_rejects_name_too_long() {
     let player = Player::new("x".repeat(MAX_NAME_LENGTH + 1));
@@ -188,3 +206,317 @@ fn test_tournament_decode_rejects_too_many_players() {
     let err = Tournament::read(&mut &encoded[..]).expect_err("should reject >1000 players");
     assert!(matches!(err, commonware_codec::Error::InvalidLength(_)));
 }
+
+#[test]
+fn test_payout_structure_validation() {
+    assert!(PayoutStructure::default().validate().is_ok());
+    assert!(PayoutStructure::winner_take_all().validate().is_ok());
+    assert!(PayoutStructure::top_three().validate().is_ok());
+    assert!(PayoutStructure::mtt_ladder().validate().is_ok());
+
+    let short = PayoutStructure {
+        shares_bps: vec![6_000, 3_000],
+    };
+    assert!(short.validate().is_err());
+    let zero_share = PayoutStructure {
+        shares_bps: vec![10_000, 0],
+    };
+    assert!(zero_share.validate().is_err());
+    let too_many = PayoutStructure {
+        shares_bps: vec![1; MAX_TOURNAMENT_PAYOUT_PLACES + 1],
+    };
+    assert!(too_many.validate().is_err());
+}
+
+#[test]
+fn test_payout_structure_split_handles_ties_and_short_fields() {
+    let top_three = PayoutStructure::top_three();
+    assert_eq!(
+        top_three.split(1_000, &[9, 8, 7, 6]),
+        vec![500, 300, 200, 0]
+    );
+    // Tied players share the prizes for the positions they occupy.
+    assert_eq!(
+        top_three.split(1_000, &[9, 8, 8, 6]),
+        vec![500, 250, 250, 0]
+    );
+    // A tie straddling the last paid place splits that place's prize.
+    assert_eq!(
+        top_three.split(1_000, &[9, 8, 7, 7, 7]),
+        vec![500, 300, 67, 67, 66]
+    );
+    // Odd units from a split go to the earliest tied players.
+    assert_eq!(top_three.split(1_001, &[5, 5, 5]), vec![334, 334, 333]);
+    // Fewer players than paid places: the pool is spread over the places filled.
+    assert_eq!(top_three.split(1_000, &[9, 8]), vec![625, 375]);
+    assert_eq!(
+        PayoutStructure::winner_take_all().split(1_000, &[4, 4]),
+        vec![500, 500]
+    );
+    assert!(PayoutStructure::default()
+        .split(1_000, &[1])
+        .iter()
+        .all(|p| *p == 0));
+}
+
+#[test]
+fn test_tournament_paid_config_roundtrip() {
+    let tournament = Tournament {
+        id: 9,
+        prize_pool: 1_800,
+        config: TournamentConfig {
+            currency: TournamentCurrency::Vusdt,
+            buy_in: 1_000,
+            rake_bps: 1_000,
+            guarantee: 5_000,
+            payout_structure: PayoutStructure::top_three(),
+            ..Default::default()
+        },
+        rake_collected: 200,
+        guarantee_overlay: 3_200,
+        ..Default::default()
+    };
+    assert_eq!(tournament.config.rake_per_entry(), 100);
+
+    let encoded = tournament.encode();
+    assert_eq!(encoded.len(), tournament.encode_size());
+    assert_eq!(Tournament::read(&mut &encoded[..]).unwrap(), tournament);
+
+    // A record that stops at the leaderboard is truncated, not a freeroll.
+    let trailing =
+        tournament.config.encode_size() + 2 * u64::SIZE + tournament.top_ups.encode_size();
+    assert!(Tournament::read(&mut &encoded[..encoded.len() - trailing]).is_err());
+}
+
+#[test]
+fn test_tournament_top_ups_track_per_player_counts() {
+    let pk1 = PrivateKey::from_seed(1).public_key();
+    let pk2 = PrivateKey::from_seed(2).public_key();
+
+    let mut tournament = Tournament {
+        config: TournamentConfig {
+            buy_in: 100,
+            rebuy: TopUpRule {
+                max_per_player: 2,
+                window_secs: 60,
+                cost: 100,
+                chips: 1_000,
+                ..Default::default()
+            },
+            add_on: TopUpRule {
+                max_per_player: 1,
+                window_secs: 60,
+                cost: 50,
+                chips: 500,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(tournament.top_ups_of(&pk1).count(TopUpKind::Rebuy), 0);
+
+    tournament.record_top_up(&pk2, TopUpKind::AddOn);
+    tournament.record_top_up(&pk1, TopUpKind::Rebuy);
+    let totals = tournament.record_top_up(&pk1, TopUpKind::Rebuy);
+    assert_eq!((totals.rebuys, totals.add_ons), (2, 0));
+    assert_eq!(tournament.top_ups_of(&pk2).count(TopUpKind::AddOn), 1);
+    assert_eq!(tournament.top_up_spend(&pk1), 200);
+    assert_eq!(tournament.top_up_spend(&pk2), 50);
+
+    let mut sorted = vec![pk1.clone(), pk2.clone()];
+    sorted.sort();
+    let order: Vec<_> = tournament
+        .top_ups
+        .iter()
+        .map(|t| t.player.clone())
+        .collect();
+    assert_eq!(order, sorted);
+
+    let encoded = tournament.encode();
+    assert_eq!(encoded.len(), tournament.encode_size());
+    assert_eq!(Tournament::read(&mut &encoded[..]).unwrap(), tournament);
+
+    // The top-up list is always present, so a record missing it does not decode.
+    let trailing = tournament.top_ups.encode_size();
+    assert!(Tournament::read(&mut &encoded[..encoded.len() - trailing]).is_err());
+}
+
+#[test]
+fn test_tournament_game_rules_validation() {
+    let blackjack_only = TournamentConfig {
+        allowed_games: vec![GameType::Blackjack],
+        bet_limits: vec![TournamentBetLimit {
+            game_type: GameType::Blackjack,
+            min_bet: 10,
+            max_bet: 0,
+        }],
+        ..Default::default()
+    };
+    assert!(blackjack_only.validate_game_rules().is_ok());
+    assert!(blackjack_only.allows_game(GameType::Blackjack));
+    assert!(!blackjack_only.allows_game(GameType::Craps));
+    let limit = blackjack_only.bet_limit(GameType::Blackjack).unwrap();
+    assert!(!limit.allows(5));
+    assert!(limit.allows(1_000_000));
+    assert!(TournamentConfig::default().allows_game(GameType::Craps));
+    assert_eq!(
+        TournamentConfig::default().starting_stack.chips,
+        STARTING_CHIPS
+    );
+
+    let repeated = TournamentConfig {
+        allowed_games: vec![GameType::Craps, GameType::Craps],
+        ..Default::default()
+    };
+    assert!(repeated.validate_game_rules().is_err());
+    let limit_outside_set = TournamentConfig {
+        bet_limits: vec![TournamentBetLimit {
+            game_type: GameType::Craps,
+            min_bet: 0,
+            max_bet: 100,
+        }],
+        ..blackjack_only.clone()
+    };
+    assert!(limit_outside_set.validate_game_rules().is_err());
+    let move_wagered = TournamentConfig {
+        bet_limits: vec![TournamentBetLimit {
+            game_type: GameType::Roulette,
+            min_bet: 10,
+            max_bet: 100,
+        }],
+        ..Default::default()
+    };
+    assert!(move_wagered.validate_game_rules().is_err());
+    let inverted = TournamentConfig {
+        bet_limits: vec![TournamentBetLimit {
+            game_type: GameType::Blackjack,
+            min_bet: 100,
+            max_bet: 10,
+        }],
+        ..blackjack_only.clone()
+    };
+    assert!(inverted.validate_game_rules().is_err());
+    let empty_stack = TournamentConfig {
+        starting_stack: TournamentStack {
+            chips: 0,
+            shields: 3,
+            doubles: 3,
+        },
+        ..Default::default()
+    };
+    assert!(empty_stack.validate_game_rules().is_err());
+
+    let encoded = blackjack_only.encode();
+    assert_eq!(encoded.len(), blackjack_only.encode_size());
+    assert_eq!(
+        TournamentConfig::read(&mut &encoded[..]).unwrap(),
+        blackjack_only
+    );
+}
+
+#[test]
+fn test_global_table_player_session_reads_legacy_encoding() {
+    let mut session = GlobalTablePlayerSession {
+        game_type: GameType::Roulette,
+        session: GameSession {
+            id: 3,
+            player: PrivateKey::from_seed(1).public_key(),
+            game_type: GameType::Roulette,
+            bet: 0,
+            state_blob: vec![1, 2, 3],
+            move_count: 2,
+            created_at: 7,
+            is_complete: false,
+            super_mode: SuperModeState::default(),
+            is_tournament: false,
+            tournament_id: None,
+        },
+        last_settled_round: 4,
+        round_bets: vec![GlobalTableBet {
+            bet_type: 1,
+            target: 0,
+            amount: 25,
+        }],
+        round_start_blob: vec![1],
+    };
+    let encoded = session.encode();
+    let decoded = GlobalTablePlayerSession::read(&mut &encoded[..]).unwrap();
+    assert_eq!(decoded, session);
+
+    // Sessions stored before `round_bets` existed decode with no open-round bets.
+    session.round_bets.clear();
+    session.round_start_blob.clear();
+    let mut legacy = Vec::new();
+    session.game_type.write(&mut legacy);
+    session.session.write(&mut legacy);
+    session.last_settled_round.write(&mut legacy);
+    let decoded = GlobalTablePlayerSession::read(&mut &legacy[..]).unwrap();
+    assert_eq!(decoded, session);
+}
+
+#[test]
+fn test_admin_registry_grant_revoke_roundtrip() {
+    let alice = PrivateKey::from_seed(1).public_key();
+    let bob = PrivateKey::from_seed(2).public_key();
+
+    let mut registry = AdminRegistry::with_full_access([alice.clone()]);
+    assert!(registry.grant(&bob, AdminRole::Bridge));
+    assert!(!registry.grant(&bob, AdminRole::Bridge));
+    assert!(registry.has_role(&bob, AdminRole::Bridge));
+    assert!(!registry.has_role(&bob, AdminRole::Treasury));
+    assert_eq!(registry.role_count(AdminRole::Registry), 1);
+
+    let encoded = registry.encode();
+    let decoded = AdminRegistry::read(&mut &encoded[..]).unwrap();
+    assert_eq!(decoded, registry);
+
+    // Keys left without any role are dropped.
+    assert!(registry.revoke(&bob, AdminRole::Bridge));
+    assert!(!registry.revoke(&bob, AdminRole::Bridge));
+    assert_eq!(registry.admins.len(), 1);
+}
+
+#[test]
+fn test_multisig_guards_registry_roles() {
+    let alice = PrivateKey::from_seed(1).public_key();
+    let bob = PrivateKey::from_seed(2).public_key();
+
+    let mut registry = AdminRegistry::with_full_access([alice.clone()]);
+    registry.guarded_roles = AdminRole::Treasury.bit();
+    registry.next_multisig_id = 1;
+    assert!(!registry.has_role(&alice, AdminRole::Treasury));
+    assert!(registry.holds_role(&alice, AdminRole::Treasury));
+    assert!(registry.has_role(&alice, AdminRole::Policy));
+    let encoded = registry.encode();
+    assert_eq!(AdminRegistry::read(&mut &encoded[..]).unwrap(), registry);
+
+    let multisig = MultisigAccount {
+        id: 0,
+        signers: vec![alice.clone(), bob.clone()],
+        threshold: 2,
+        timelock_secs: 60,
+        roles: AdminRole::Treasury.bit(),
+        next_proposal_id: 1,
+    };
+    let encoded = multisig.encode();
+    assert_eq!(MultisigAccount::read(&mut &encoded[..]).unwrap(), multisig);
+
+    let mut proposal = MultisigProposal {
+        multisig_id: 0,
+        id: 0,
+        proposer: alice.clone(),
+        instruction: crate::execution::Instruction::SetPolicy {
+            policy: PolicyState::default(),
+        },
+        approvals: vec![alice.clone()],
+        executable_at: 0,
+        executed: false,
+    };
+    assert!(!proposal.is_approved(&multisig));
+    assert!(!proposal.approve(&alice));
+    assert!(proposal.approve(&bob));
+    assert!(proposal.is_approved(&multisig));
+    let encoded = proposal.encode();
+    assert_eq!(MultisigProposal::read(&mut &encoded[..]).unwrap(), proposal);
+}
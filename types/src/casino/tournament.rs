@@ -2,7 +2,10 @@ use bytes::{Buf, BufMut};
 use commonware_codec::{EncodeSize, Error, FixedSize, Read, ReadExt, ReadRangeExt, Write};
 use commonware_cryptography::ed25519::PublicKey;
 
-use super::CasinoLeaderboard;
+use super::{
+    CasinoLeaderboard, GameType, MAX_TOURNAMENT_GAME_RULES, MAX_TOURNAMENT_PAYOUT_PLACES,
+    MAX_TOURNAMENT_PLAYERS, STARTING_CHIPS, STARTING_DOUBLES, STARTING_SHIELDS,
+};
 
 /// Tournament phases
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -12,6 +15,8 @@ pub enum TournamentPhase {
     Registration = 0, // 1 minute (~20 blocks at 3s/block)
     Active = 1, // 5 minutes (~100 blocks)
     Complete = 2,
+    /// Cancelled by an admin; buy-ins were refunded.
+    Cancelled = 3,
 }
 
 impl Write for TournamentPhase {
@@ -28,6 +33,7 @@ impl Read for TournamentPhase {
             0 => Ok(Self::Registration),
             1 => Ok(Self::Active),
             2 => Ok(Self::Complete),
+            3 => Ok(Self::Cancelled),
             i => Err(Error::InvalidEnum(i)),
         }
     }
@@ -37,6 +43,519 @@ impl FixedSize for TournamentPhase {
     const SIZE: usize = 1;
 }
 
+/// Currency a tournament's buy-ins are paid in (and its prizes paid out in).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum TournamentCurrency {
+    #[default]
+    Chips = 0,
+    Vusdt = 1,
+}
+
+impl Write for TournamentCurrency {
+    fn write(&self, writer: &mut impl BufMut) {
+        (*self as u8).write(writer);
+    }
+}
+
+impl Read for TournamentCurrency {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        match u8::read(reader)? {
+            0 => Ok(Self::Chips),
+            1 => Ok(Self::Vusdt),
+            i => Err(Error::InvalidEnum(i)),
+        }
+    }
+}
+
+impl FixedSize for TournamentCurrency {
+    const SIZE: usize = 1;
+}
+
+/// Entry rules for a tournament. A zero buy-in is a freeroll funded by emissions.
+///
+/// A non-zero `max_seats` makes it a sit-and-go: it starts on its own when the last seat
+/// fills and ends `duration_secs` later, with no admin transactions.
+///
+/// Paid events may also sell rebuys (to busted players) and add-ons (to anyone still
+/// seated) while the event runs.
+///
+/// Themed events restrict which games tournament chips can be played on, bound the
+/// opening bet per game, and may hand out a custom starting stack.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct TournamentConfig {
+    pub currency: TournamentCurrency,
+    /// Entry fee per player, rake included.
+    pub buy_in: u64,
+    /// Share of each buy-in kept by the house (basis points).
+    pub rake_bps: u16,
+    /// Minimum prize pool; the treasury covers any shortfall when the event starts.
+    pub guarantee: u64,
+    /// Seats of a sit-and-go (0 for scheduled tournaments).
+    pub max_seats: u32,
+    /// How long a sit-and-go runs once it starts.
+    pub duration_secs: u64,
+    pub rebuy: TopUpRule,
+    pub add_on: TopUpRule,
+    /// Games tournament chips can be played on (empty allows every game).
+    pub allowed_games: Vec<GameType>,
+    /// Opening-bet bounds for tournament sessions, per game. Games that open with no bet
+    /// (`GameType::allows_zero_bet`) cannot carry one.
+    pub bet_limits: Vec<TournamentBetLimit>,
+    /// Stack each entrant receives when the event starts.
+    pub starting_stack: TournamentStack,
+    /// Prize split fixed at creation. Sit-and-go events always pay out on it; an admin
+    /// start of a scheduled event may replace it.
+    pub payout_structure: PayoutStructure,
+}
+
+impl TournamentConfig {
+    pub fn is_freeroll(&self) -> bool {
+        self.buy_in == 0
+    }
+
+    pub fn is_sit_and_go(&self) -> bool {
+        self.max_seats > 0
+    }
+
+    /// House rake withheld from a single buy-in.
+    pub fn rake_per_entry(&self) -> u64 {
+        (self.buy_in as u128)
+            .saturating_mul(self.rake_bps as u128)
+            .checked_div(10_000)
+            .unwrap_or(0) as u64
+    }
+
+    pub fn allows_game(&self, game_type: GameType) -> bool {
+        self.allowed_games.is_empty() || self.allowed_games.contains(&game_type)
+    }
+
+    pub fn bet_limit(&self, game_type: GameType) -> Option<&TournamentBetLimit> {
+        self.bet_limits
+            .iter()
+            .find(|limit| limit.game_type == game_type)
+    }
+
+    /// Check the allowed games, bet limits and starting stack are coherent.
+    pub fn validate_game_rules(&self) -> Result<(), &'static str> {
+        if self.allowed_games.len() > MAX_TOURNAMENT_GAME_RULES
+            || self.bet_limits.len() > MAX_TOURNAMENT_GAME_RULES
+        {
+            return Err("Tournament lists too many games");
+        }
+        for (i, game_type) in self.allowed_games.iter().enumerate() {
+            if self.allowed_games[..i].contains(game_type) {
+                return Err("Allowed games must not repeat");
+            }
+        }
+        for (i, limit) in self.bet_limits.iter().enumerate() {
+            if self.bet_limits[..i]
+                .iter()
+                .any(|other| other.game_type == limit.game_type)
+            {
+                return Err("Bet limits must not repeat a game");
+            }
+            if !self.allows_game(limit.game_type) {
+                return Err("Bet limits must only cover allowed games");
+            }
+            // Their stakes are placed after the session opens, where no limit is checked.
+            if limit.game_type.allows_zero_bet() {
+                return Err("Bet limits only apply to games with an opening bet");
+            }
+            if limit.max_bet > 0 && limit.min_bet > limit.max_bet {
+                return Err("Minimum bet exceeds maximum bet");
+            }
+        }
+        if self.starting_stack.chips == 0 {
+            return Err("Starting stack must include chips");
+        }
+        Ok(())
+    }
+
+    pub fn top_up_rule(&self, kind: TopUpKind) -> &TopUpRule {
+        match kind {
+            TopUpKind::Rebuy => &self.rebuy,
+            TopUpKind::AddOn => &self.add_on,
+        }
+    }
+}
+
+impl Write for TournamentConfig {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.currency.write(writer);
+        self.buy_in.write(writer);
+        self.rake_bps.write(writer);
+        self.guarantee.write(writer);
+        self.max_seats.write(writer);
+        self.duration_secs.write(writer);
+        self.rebuy.write(writer);
+        self.add_on.write(writer);
+        self.allowed_games.write(writer);
+        self.bet_limits.write(writer);
+        self.starting_stack.write(writer);
+        self.payout_structure.write(writer);
+    }
+}
+
+impl Read for TournamentConfig {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            currency: TournamentCurrency::read(reader)?,
+            buy_in: u64::read(reader)?,
+            rake_bps: u16::read(reader)?,
+            guarantee: u64::read(reader)?,
+            max_seats: u32::read(reader)?,
+            duration_secs: u64::read(reader)?,
+            rebuy: TopUpRule::read(reader)?,
+            add_on: TopUpRule::read(reader)?,
+            allowed_games: Vec::<GameType>::read_range(reader, 0..=MAX_TOURNAMENT_GAME_RULES)?,
+            bet_limits: Vec::<TournamentBetLimit>::read_range(
+                reader,
+                0..=MAX_TOURNAMENT_GAME_RULES,
+            )?,
+            starting_stack: TournamentStack::read(reader)?,
+            payout_structure: PayoutStructure::read(reader)?,
+        })
+    }
+}
+
+impl EncodeSize for TournamentConfig {
+    fn encode_size(&self) -> usize {
+        self.currency.encode_size()
+            + self.buy_in.encode_size()
+            + self.rake_bps.encode_size()
+            + self.guarantee.encode_size()
+            + self.max_seats.encode_size()
+            + self.duration_secs.encode_size()
+            + self.rebuy.encode_size()
+            + self.add_on.encode_size()
+            + self.allowed_games.encode_size()
+            + self.bet_limits.encode_size()
+            + self.starting_stack.encode_size()
+            + self.payout_structure.encode_size()
+    }
+}
+
+/// Opening-bet bounds for one game in a tournament. A zero `max_bet` leaves it uncapped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TournamentBetLimit {
+    pub game_type: GameType,
+    pub min_bet: u64,
+    pub max_bet: u64,
+}
+
+impl TournamentBetLimit {
+    pub fn allows(&self, bet: u64) -> bool {
+        bet >= self.min_bet && (self.max_bet == 0 || bet <= self.max_bet)
+    }
+}
+
+impl Write for TournamentBetLimit {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.game_type.write(writer);
+        self.min_bet.write(writer);
+        self.max_bet.write(writer);
+    }
+}
+
+impl Read for TournamentBetLimit {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            game_type: GameType::read(reader)?,
+            min_bet: u64::read(reader)?,
+            max_bet: u64::read(reader)?,
+        })
+    }
+}
+
+impl FixedSize for TournamentBetLimit {
+    const SIZE: usize = GameType::SIZE + u64::SIZE + u64::SIZE;
+}
+
+/// Chips, shields and doubles each entrant starts a tournament with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TournamentStack {
+    pub chips: u64,
+    pub shields: u32,
+    pub doubles: u32,
+}
+
+impl Default for TournamentStack {
+    fn default() -> Self {
+        Self {
+            chips: STARTING_CHIPS,
+            shields: STARTING_SHIELDS,
+            doubles: STARTING_DOUBLES,
+        }
+    }
+}
+
+impl Write for TournamentStack {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.chips.write(writer);
+        self.shields.write(writer);
+        self.doubles.write(writer);
+    }
+}
+
+impl Read for TournamentStack {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            chips: u64::read(reader)?,
+            shields: u32::read(reader)?,
+            doubles: u32::read(reader)?,
+        })
+    }
+}
+
+impl FixedSize for TournamentStack {
+    const SIZE: usize = u64::SIZE + u32::SIZE + u32::SIZE;
+}
+
+/// Chips bought mid-tournament: a rebuy puts a busted player back in, an add-on tops up
+/// anyone still playing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TopUpKind {
+    Rebuy = 0,
+    AddOn = 1,
+}
+
+impl Write for TopUpKind {
+    fn write(&self, writer: &mut impl BufMut) {
+        (*self as u8).write(writer);
+    }
+}
+
+impl Read for TopUpKind {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        match u8::read(reader)? {
+            0 => Ok(Self::Rebuy),
+            1 => Ok(Self::AddOn),
+            i => Err(Error::InvalidEnum(i)),
+        }
+    }
+}
+
+impl FixedSize for TopUpKind {
+    const SIZE: usize = 1;
+}
+
+/// Terms of a rebuy or add-on. The whole cost goes to the prize pool.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct TopUpRule {
+    /// Purchases allowed per player (0 disables them).
+    pub max_per_player: u8,
+    /// Seconds after the start during which purchases are accepted.
+    pub window_secs: u64,
+    /// Price in the tournament currency.
+    pub cost: u64,
+    pub chips: u64,
+    pub shields: u32,
+    pub doubles: u32,
+}
+
+impl TopUpRule {
+    pub fn is_enabled(&self) -> bool {
+        self.max_per_player > 0
+    }
+}
+
+impl Write for TopUpRule {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.max_per_player.write(writer);
+        self.window_secs.write(writer);
+        self.cost.write(writer);
+        self.chips.write(writer);
+        self.shields.write(writer);
+        self.doubles.write(writer);
+    }
+}
+
+impl Read for TopUpRule {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            max_per_player: u8::read(reader)?,
+            window_secs: u64::read(reader)?,
+            cost: u64::read(reader)?,
+            chips: u64::read(reader)?,
+            shields: u32::read(reader)?,
+            doubles: u32::read(reader)?,
+        })
+    }
+}
+
+impl FixedSize for TopUpRule {
+    const SIZE: usize = u8::SIZE + u64::SIZE + u64::SIZE + u64::SIZE + u32::SIZE + u32::SIZE;
+}
+
+/// Rebuys and add-ons a player has bought in one tournament.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerTopUps {
+    pub player: PublicKey,
+    pub rebuys: u8,
+    pub add_ons: u8,
+}
+
+impl PlayerTopUps {
+    pub fn count(&self, kind: TopUpKind) -> u8 {
+        match kind {
+            TopUpKind::Rebuy => self.rebuys,
+            TopUpKind::AddOn => self.add_ons,
+        }
+    }
+}
+
+impl Write for PlayerTopUps {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.player.write(writer);
+        self.rebuys.write(writer);
+        self.add_ons.write(writer);
+    }
+}
+
+impl Read for PlayerTopUps {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            player: PublicKey::read(reader)?,
+            rebuys: u8::read(reader)?,
+            add_ons: u8::read(reader)?,
+        })
+    }
+}
+
+impl FixedSize for PlayerTopUps {
+    const SIZE: usize = PublicKey::SIZE + u8::SIZE + u8::SIZE;
+}
+
+/// Share of the prize pool paid to each finishing position (basis points, 1st place first).
+///
+/// An empty structure keeps the default split: the top 15% paid on a 1/rank curve.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct PayoutStructure {
+    pub shares_bps: Vec<u16>,
+}
+
+impl PayoutStructure {
+    pub fn winner_take_all() -> Self {
+        Self {
+            shares_bps: vec![10_000],
+        }
+    }
+
+    /// Top three paid 50/30/20.
+    pub fn top_three() -> Self {
+        Self {
+            shares_bps: vec![5_000, 3_000, 2_000],
+        }
+    }
+
+    /// Standard multi-table ladder paying nine places.
+    pub fn mtt_ladder() -> Self {
+        Self {
+            shares_bps: vec![3_000, 2_000, 1_400, 1_000, 800, 650, 550, 300, 300],
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.shares_bps.is_empty()
+    }
+
+    /// Check the structure pays positive shares that sum to exactly 100%.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.is_default() {
+            return Ok(());
+        }
+        if self.shares_bps.len() > MAX_TOURNAMENT_PAYOUT_PLACES {
+            return Err("Payout structure pays too many places");
+        }
+        if self.shares_bps.contains(&0) {
+            return Err("Payout shares must be greater than zero");
+        }
+        let total: u32 = self.shares_bps.iter().map(|share| *share as u32).sum();
+        if total != 10_000 {
+            return Err("Payout shares must sum to 100%");
+        }
+        Ok(())
+    }
+
+    /// Split `prize_pool` across final standings (`chips`, sorted descending).
+    ///
+    /// Returns one payout per standing. When the field is smaller than the number of paid
+    /// places the filled places share the whole pool pro rata, and rounding dust goes to
+    /// first place. Players tied on chips split the prizes for the positions they occupy
+    /// equally; any indivisible remainder goes to the earliest of them in `chips` order.
+    pub fn split(&self, prize_pool: u64, chips: &[u64]) -> Vec<u64> {
+        let mut payouts = vec![0u64; chips.len()];
+        let places = self.shares_bps.len().min(chips.len());
+        if places == 0 || prize_pool == 0 {
+            return payouts;
+        }
+
+        let shares = &self.shares_bps[..places];
+        let total_bps: u128 = shares.iter().map(|share| *share as u128).sum();
+        let mut position_prizes: Vec<u64> = shares
+            .iter()
+            .map(|share| (prize_pool as u128 * *share as u128 / total_bps) as u64)
+            .collect();
+        let distributed: u64 = position_prizes.iter().sum();
+        position_prizes[0] += prize_pool - distributed;
+
+        let mut start = 0;
+        while start < places {
+            let mut end = start + 1;
+            while end < chips.len() && chips[end] == chips[start] {
+                end += 1;
+            }
+            let group_prize: u64 = position_prizes[start..end.min(places)].iter().sum();
+            let group_size = (end - start) as u64;
+            let share = group_prize / group_size;
+            let remainder = (group_prize % group_size) as usize;
+            for (offset, payout) in payouts[start..end].iter_mut().enumerate() {
+                *payout = share + u64::from(offset < remainder);
+            }
+            start = end;
+        }
+        payouts
+    }
+}
+
+impl Write for PayoutStructure {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.shares_bps.write(writer);
+    }
+}
+
+impl Read for PayoutStructure {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            shares_bps: Vec::<u16>::read_range(reader, 0..=MAX_TOURNAMENT_PAYOUT_PLACES)?,
+        })
+    }
+}
+
+impl EncodeSize for PayoutStructure {
+    fn encode_size(&self) -> usize {
+        self.shares_bps.encode_size()
+    }
+}
+
 /// Tournament state
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct Tournament {
@@ -53,6 +572,13 @@ pub struct Tournament {
     pub starting_shields: u32, // 3
     pub starting_doubles: u32, // 3
     pub leaderboard: CasinoLeaderboard,
+    pub config: TournamentConfig,
+    /// Rake withheld from buy-ins, booked to the house when the event completes.
+    pub rake_collected: u64,
+    /// Treasury top-up that lifted the prize pool to the guarantee at start.
+    pub guarantee_overlay: u64,
+    /// Rebuys and add-ons bought so far, sorted by player (players without any are omitted).
+    pub top_ups: Vec<PlayerTopUps>,
 }
 
 impl Write for Tournament {
@@ -68,6 +594,10 @@ impl Write for Tournament {
         self.starting_shields.write(writer);
         self.starting_doubles.write(writer);
         self.leaderboard.write(writer);
+        self.config.write(writer);
+        self.rake_collected.write(writer);
+        self.guarantee_overlay.write(writer);
+        self.top_ups.write(writer);
     }
 }
 
@@ -81,7 +611,7 @@ impl Read for Tournament {
         let start_time_ms = u64::read(reader)?;
         let end_time_ms = u64::read(reader)?;
 
-        let mut players = Vec::<PublicKey>::read_range(reader, 0..=1000)?;
+        let mut players = Vec::<PublicKey>::read_range(reader, 0..=MAX_TOURNAMENT_PLAYERS)?;
         players.sort_unstable();
         players.dedup();
 
@@ -90,6 +620,10 @@ impl Read for Tournament {
         let starting_shields = u32::read(reader)?;
         let starting_doubles = u32::read(reader)?;
         let leaderboard = CasinoLeaderboard::read(reader)?;
+        let config = TournamentConfig::read(reader)?;
+        let rake_collected = u64::read(reader)?;
+        let guarantee_overlay = u64::read(reader)?;
+        let top_ups = Vec::<PlayerTopUps>::read_range(reader, 0..=MAX_TOURNAMENT_PLAYERS)?;
 
         Ok(Self {
             id,
@@ -103,6 +637,10 @@ impl Read for Tournament {
             starting_shields,
             starting_doubles,
             leaderboard,
+            config,
+            rake_collected,
+            guarantee_overlay,
+            top_ups,
         })
     }
 }
@@ -120,6 +658,10 @@ impl EncodeSize for Tournament {
             + self.starting_shields.encode_size()
             + self.starting_doubles.encode_size()
             + self.leaderboard.encode_size()
+            + self.config.encode_size()
+            + self.rake_collected.encode_size()
+            + self.guarantee_overlay.encode_size()
+            + self.top_ups.encode_size()
     }
 }
 
@@ -130,6 +672,60 @@ impl Tournament {
         self.players.binary_search(player).is_ok()
     }
 
+    /// Rebuys and add-ons `player` has bought so far.
+    pub fn top_ups_of(&self, player: &PublicKey) -> PlayerTopUps {
+        match self
+            .top_ups
+            .binary_search_by(|entry| entry.player.cmp(player))
+        {
+            Ok(pos) => self.top_ups[pos].clone(),
+            Err(_) => PlayerTopUps {
+                player: player.clone(),
+                rebuys: 0,
+                add_ons: 0,
+            },
+        }
+    }
+
+    /// Total `player` has paid for rebuys and add-ons.
+    pub fn top_up_spend(&self, player: &PublicKey) -> u64 {
+        let top_ups = self.top_ups_of(player);
+        let rebuys = self.config.rebuy.cost.saturating_mul(top_ups.rebuys as u64);
+        let add_ons = self
+            .config
+            .add_on
+            .cost
+            .saturating_mul(top_ups.add_ons as u64);
+        rebuys.saturating_add(add_ons)
+    }
+
+    /// Count one more `kind` purchase for `player` and return their updated totals.
+    pub fn record_top_up(&mut self, player: &PublicKey, kind: TopUpKind) -> PlayerTopUps {
+        let pos = match self
+            .top_ups
+            .binary_search_by(|entry| entry.player.cmp(player))
+        {
+            Ok(pos) => pos,
+            Err(pos) => {
+                self.top_ups.insert(
+                    pos,
+                    PlayerTopUps {
+                        player: player.clone(),
+                        rebuys: 0,
+                        add_ons: 0,
+                    },
+                );
+                pos
+            }
+        };
+        let entry = &mut self.top_ups[pos];
+        match kind {
+            TopUpKind::Rebuy => entry.rebuys = entry.rebuys.saturating_add(1),
+            TopUpKind::AddOn => entry.add_ons = entry.add_ons.saturating_add(1),
+        }
+        entry.clone()
+    }
+
     /// Add a player to the tournament.
     /// Returns true if the player was added, false if they were already present.
     pub fn add_player(&mut self, player: PublicKey) -> bool {
@@ -142,3 +738,32 @@ impl Tournament {
         }
     }
 }
+
+/// A running sit-and-go to end once chain time reaches `end_view`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TournamentDeadline {
+    pub end_view: u64,
+    pub tournament_id: u64,
+}
+
+impl Write for TournamentDeadline {
+    fn write(&self, writer: &mut impl BufMut) {
+        self.end_view.write(writer);
+        self.tournament_id.write(writer);
+    }
+}
+
+impl Read for TournamentDeadline {
+    type Cfg = ();
+
+    fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
+        Ok(Self {
+            end_view: u64::read(reader)?,
+            tournament_id: u64::read(reader)?,
+        })
+    }
+}
+
+impl FixedSize for TournamentDeadline {
+    const SIZE: usize = u64::SIZE + u64::SIZE;
+}
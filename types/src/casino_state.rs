@@ -18,6 +18,11 @@ pub enum GameType {
     VideoPoker,
     #[ts(rename = "HILO")]
     HiLo,
+    PaiGow,
+    LetItRide,
+    Keno,
+    BigSix,
+    Crash,
 }
 
 #[derive(TS, Debug, Clone, PartialEq)]
@@ -69,6 +74,20 @@ pub enum CrapsBetType {
     RideLine,
     Replay,
     HotRoller,
+    AnySeven,
+    AnyCraps,
+    Horn,
+    CAndE,
+    World,
+    #[ts(rename = "BIG_6")]
+    Big6,
+    #[ts(rename = "BIG_8")]
+    Big8,
+    Buy,
+    Lay,
+    BuyVigUpFront,
+    LayVigUpFront,
+    Put,
 }
 
 #[derive(TS, Debug, Clone, PartialEq)]
@@ -154,6 +173,11 @@ pub enum RouletteBetType {
     Street,
     Corner,
     SixLine,
+    Voisins,
+    Tiers,
+    Orphelins,
+    JeuZero,
+    Neighbours,
 }
 
 #[derive(TS, Debug, Clone, PartialEq)]
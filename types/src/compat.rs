@@ -1,7 +1,7 @@
 #![cfg(test)]
 use crate::api::{Query, UpdatesFilter};
 use crate::execution::{Instruction, Transaction};
-use commonware_codec::Encode;
+use commonware_codec::{DecodeExt, Encode};
 use commonware_cryptography::{ed25519::PrivateKey, Signer};
 
 #[test]
@@ -29,3 +29,54 @@ fn transaction_encoding_is_stable() {
     .expect("valid hex");
     assert_eq!(tx.encode().as_ref(), expected.as_slice());
 }
+
+#[test]
+fn fee_transaction_encoding_is_stable() {
+    let private = PrivateKey::from_seed(1);
+    let tx = Transaction::sign_with_fee(&private, 0, Instruction::CasinoDeposit { amount: 100 }, 7);
+    assert!(tx.verify());
+
+    let encoded = tx.encode();
+    // [nonce|flag] [extensions] [fee] [instruction]
+    assert_eq!(
+        &encoded[..26],
+        &[0x80, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7, 11, 0, 0, 0, 0, 0, 0, 0, 100]
+    );
+    let decoded = Transaction::decode(encoded.as_ref()).expect("decode fee transaction");
+    assert_eq!(decoded, tx);
+
+    // A zero fee must use the legacy encoding.
+    let mut zero_fee = encoded.to_vec();
+    zero_fee[9..17].copy_from_slice(&0u64.to_be_bytes());
+    assert!(Transaction::decode(zero_fee.as_slice()).is_err());
+}
+
+#[test]
+fn expiring_transaction_encoding_is_stable() {
+    let private = PrivateKey::from_seed(1);
+    let tx = Transaction::sign_with_extensions(
+        &private,
+        0,
+        Instruction::CasinoDeposit { amount: 100 },
+        0,
+        Some(42),
+    );
+    assert!(tx.verify());
+    assert!(!tx.is_expired(42));
+    assert!(tx.is_expired(43));
+
+    let encoded = tx.encode();
+    // [nonce|flag] [extensions] [expires_at_view] [instruction]
+    assert_eq!(
+        &encoded[..18],
+        &[0x80, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 42, 11]
+    );
+    let decoded = Transaction::decode(encoded.as_ref()).expect("decode expiring transaction");
+    assert_eq!(decoded, tx);
+
+    // The expiry is signed: changing it invalidates the signature.
+    let mut tampered = encoded.to_vec();
+    tampered[16] = 43;
+    let tampered = Transaction::decode(tampered.as_slice()).expect("decode tampered transaction");
+    assert!(!tampered.verify());
+}
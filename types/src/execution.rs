@@ -46,7 +46,7 @@ mod tags {
         pub const CASINO_JOIN_TOURNAMENT: u8 = 16;
         pub const CASINO_START_TOURNAMENT: u8 = 17;
 
-        // Global table (60-66)
+        // Global table (60-69)
         pub const CASINO_GLOBAL_TABLE_INIT: u8 = 60;
         pub const CASINO_GLOBAL_TABLE_OPEN_ROUND: u8 = 61;
         pub const CASINO_GLOBAL_TABLE_SUBMIT_BETS: u8 = 62;
@@ -54,6 +54,9 @@ mod tags {
         pub const CASINO_GLOBAL_TABLE_REVEAL: u8 = 64;
         pub const CASINO_GLOBAL_TABLE_SETTLE: u8 = 65;
         pub const CASINO_GLOBAL_TABLE_FINALIZE: u8 = 66;
+        pub const CASINO_GLOBAL_TABLE_CANCEL_BETS: u8 = 67;
+        pub const CASINO_GLOBAL_TABLE_REPLACE_BETS: u8 = 68;
+        pub const CASINO_GLOBAL_TABLE_CASH_OUT: u8 = 69;
 
         // Staking (18-21)
         pub const STAKE: u8 = 18;
@@ -93,6 +96,29 @@ mod tags {
         pub const BRIDGE_DEPOSIT: u8 = 44;
         pub const FINALIZE_BRIDGE_WITHDRAWAL: u8 = 45;
         pub const UPDATE_ORACLE: u8 = 46;
+
+        // Admin registry (47-48)
+        pub const GRANT_ADMIN_ROLE: u8 = 47;
+        pub const REVOKE_ADMIN_ROLE: u8 = 48;
+
+        // Multisig (49-52)
+        pub const CREATE_MULTISIG: u8 = 49;
+        pub const PROPOSE_MULTISIG: u8 = 50;
+        pub const APPROVE_MULTISIG: u8 = 51;
+        pub const EXECUTE_MULTISIG: u8 = 52;
+
+        // Session retention (53)
+        pub const PRUNE_CASINO_SESSIONS: u8 = 53;
+
+        // Paid tournaments (54-55)
+        pub const CASINO_CREATE_TOURNAMENT: u8 = 54;
+        pub const CASINO_CANCEL_TOURNAMENT: u8 = 55;
+
+        // Tournament rebuys and add-ons (56)
+        pub const CASINO_TOURNAMENT_TOP_UP: u8 = 56;
+
+        // Multisig dissolution (57)
+        pub const DISSOLVE_MULTISIG: u8 = 57;
     }
 
     pub mod key {
@@ -136,6 +162,24 @@ mod tags {
         pub const GLOBAL_TABLE_CONFIG: u8 = 29;
         pub const GLOBAL_TABLE_ROUND: u8 = 30;
         pub const GLOBAL_TABLE_PLAYER_SESSION: u8 = 31;
+
+        // Admin registry (32)
+        pub const ADMIN_REGISTRY: u8 = 32;
+
+        // Multisig (33-34)
+        pub const MULTISIG: u8 = 33;
+        pub const MULTISIG_PROPOSAL: u8 = 34;
+
+        // Session retention (35-37)
+        pub const COMPLETED_CASINO_SESSIONS: u8 = 35;
+        pub const CASINO_SESSION_GC: u8 = 36;
+        pub const CASINO_SESSION_DEADLINES: u8 = 37;
+
+        // Sit-and-go scheduling (38)
+        pub const TOURNAMENT_DEADLINES: u8 = 38;
+
+        // Global table settlement (39)
+        pub const GLOBAL_TABLE_PLAYERS: u8 = 39;
     }
 
     pub mod value {
@@ -180,6 +224,24 @@ mod tags {
         pub const GLOBAL_TABLE_CONFIG: u8 = 29;
         pub const GLOBAL_TABLE_ROUND: u8 = 30;
         pub const GLOBAL_TABLE_PLAYER_SESSION: u8 = 31;
+
+        // Admin registry (32)
+        pub const ADMIN_REGISTRY: u8 = 32;
+
+        // Multisig (33-34)
+        pub const MULTISIG: u8 = 33;
+        pub const MULTISIG_PROPOSAL: u8 = 34;
+
+        // Session retention (35-37)
+        pub const COMPLETED_CASINO_SESSIONS: u8 = 35;
+        pub const CASINO_SESSION_GC: u8 = 36;
+        pub const CASINO_SESSION_DEADLINES: u8 = 37;
+
+        // Sit-and-go scheduling (38)
+        pub const TOURNAMENT_DEADLINES: u8 = 38;
+
+        // Global table settlement (39)
+        pub const GLOBAL_TABLE_PLAYERS: u8 = 39;
     }
 
     pub mod event {
@@ -237,7 +299,7 @@ mod tags {
         // Oracle events (58)
         pub const ORACLE_UPDATED: u8 = 58;
 
-        // Global table events (60-66)
+        // Global table events (60-68)
         pub const GLOBAL_TABLE_ROUND_OPENED: u8 = 60;
         pub const GLOBAL_TABLE_BET_ACCEPTED: u8 = 61;
         pub const GLOBAL_TABLE_BET_REJECTED: u8 = 62;
@@ -245,6 +307,40 @@ mod tags {
         pub const GLOBAL_TABLE_OUTCOME: u8 = 64;
         pub const GLOBAL_TABLE_PLAYER_SETTLED: u8 = 65;
         pub const GLOBAL_TABLE_FINALIZED: u8 = 66;
+        pub const GLOBAL_TABLE_BETS_CANCELLED: u8 = 67;
+        pub const GLOBAL_TABLE_BETS_REPLACED: u8 = 68;
+
+        // Admin registry events (69-70)
+        pub const ADMIN_ROLE_GRANTED: u8 = 69;
+        pub const ADMIN_ROLE_REVOKED: u8 = 70;
+
+        // Multisig events (71-74)
+        pub const MULTISIG_CREATED: u8 = 71;
+        pub const MULTISIG_PROPOSED: u8 = 72;
+        pub const MULTISIG_APPROVED: u8 = 73;
+        pub const MULTISIG_EXECUTED: u8 = 74;
+
+        // Transaction fee events (75)
+        pub const TRANSACTION_FEE_PAID: u8 = 75;
+
+        // Global table rolling events (76-77)
+        pub const GLOBAL_TABLE_ROLLING: u8 = 76;
+        pub const GLOBAL_TABLE_CASHED_OUT: u8 = 77;
+
+        // Paid tournament events (78-81)
+        pub const TOURNAMENT_CREATED: u8 = 78;
+        pub const TOURNAMENT_BUY_IN: u8 = 79;
+        pub const TOURNAMENT_CANCELLED: u8 = 80;
+        pub const TOURNAMENT_PRIZES_PAID: u8 = 81;
+
+        // Tournament rebuy and add-on events (82)
+        pub const TOURNAMENT_TOP_UP: u8 = 82;
+
+        // Multisig dissolution events (83)
+        pub const MULTISIG_DISSOLVED: u8 = 83;
+
+        // Casino session pruning events (84)
+        pub const CASINO_SESSIONS_PRUNED: u8 = 84;
     }
 }
 
@@ -263,52 +359,165 @@ pub fn transaction_namespace(namespace: &[u8]) -> Vec<u8> {
     union(namespace, TRANSACTION_SUFFIX)
 }
 
+/// Set on the encoded nonce when a transaction carries an extension block.
+///
+/// Legacy transactions never set this bit, so their encoding (and signature) is unchanged.
+pub const TRANSACTION_EXTENSIONS_FLAG: u64 = 1 << 63;
+/// Extension bit: the transaction carries a non-zero fee.
+pub const TRANSACTION_EXT_FEE: u8 = 1 << 0;
+/// Extension bit: the transaction is only valid up to (and including) a view.
+pub const TRANSACTION_EXT_EXPIRY: u8 = 1 << 1;
+const TRANSACTION_EXT_KNOWN: u8 = TRANSACTION_EXT_FEE | TRANSACTION_EXT_EXPIRY;
+
+/// A signed instruction.
+///
+/// Binary: [nonce:u64 BE] [instruction] [public:32] [signature:64]
+///
+/// When `TRANSACTION_EXTENSIONS_FLAG` is set on the nonce, an extension block follows it:
+/// [nonce|flag:u64 BE] [extensions:u8] [fee:u64 BE if TRANSACTION_EXT_FEE]
+/// [expiresAtView:u64 BE if TRANSACTION_EXT_EXPIRY] [instruction] ...
+/// The extension block is part of the signed payload.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Transaction {
     pub nonce: u64,
     pub instruction: Instruction,
+    /// Fee paid by the signer for inclusion, debited from their chips (the RNG balance); zero for
+    /// legacy transactions. The policy decides how much is burned and how much goes to the house.
+    pub fee: u64,
+    /// Last view at which the transaction may be included; `None` never expires.
+    pub expires_at_view: Option<u64>,
 
     pub public: ed25519::PublicKey,
     pub signature: ed25519::Signature,
 }
 
 impl Transaction {
-    fn write_payload(nonce: &u64, instruction: &Instruction, payload: &mut Vec<u8>) {
+    fn extensions(fee: u64, expires_at_view: Option<u64>) -> u8 {
+        let mut extensions = 0;
+        if fee > 0 {
+            extensions |= TRANSACTION_EXT_FEE;
+        }
+        if expires_at_view.is_some() {
+            extensions |= TRANSACTION_EXT_EXPIRY;
+        }
+        extensions
+    }
+
+    fn write_header(nonce: u64, fee: u64, expires_at_view: Option<u64>, writer: &mut impl BufMut) {
+        let extensions = Self::extensions(fee, expires_at_view);
+        if extensions == 0 {
+            nonce.write(writer);
+            return;
+        }
+        (nonce | TRANSACTION_EXTENSIONS_FLAG).write(writer);
+        extensions.write(writer);
+        if extensions & TRANSACTION_EXT_FEE != 0 {
+            fee.write(writer);
+        }
+        if let Some(expires_at_view) = expires_at_view {
+            expires_at_view.write(writer);
+        }
+    }
+
+    fn header_size(fee: u64, expires_at_view: Option<u64>) -> usize {
+        let extensions = Self::extensions(fee, expires_at_view);
+        let mut size = u64::SIZE;
+        if extensions != 0 {
+            size += u8::SIZE;
+        }
+        if extensions & TRANSACTION_EXT_FEE != 0 {
+            size += u64::SIZE;
+        }
+        if extensions & TRANSACTION_EXT_EXPIRY != 0 {
+            size += u64::SIZE;
+        }
+        size
+    }
+
+    fn write_payload(
+        nonce: u64,
+        fee: u64,
+        expires_at_view: Option<u64>,
+        instruction: &Instruction,
+        payload: &mut Vec<u8>,
+    ) {
         payload.clear();
-        payload.reserve(nonce.encode_size() + instruction.encode_size());
-        nonce.write(payload);
+        payload.reserve(Self::header_size(fee, expires_at_view) + instruction.encode_size());
+        Self::write_header(nonce, fee, expires_at_view, payload);
         instruction.write(payload);
     }
 
     pub fn sign(private: &ed25519::PrivateKey, nonce: u64, instruction: Instruction) -> Self {
+        Self::sign_with_extensions(private, nonce, instruction, 0, None)
+    }
+
+    pub fn sign_with_fee(
+        private: &ed25519::PrivateKey,
+        nonce: u64,
+        instruction: Instruction,
+        fee: u64,
+    ) -> Self {
+        Self::sign_with_extensions(private, nonce, instruction, fee, None)
+    }
+
+    /// Sign with an optional fee and an optional last valid view.
+    pub fn sign_with_extensions(
+        private: &ed25519::PrivateKey,
+        nonce: u64,
+        instruction: Instruction,
+        fee: u64,
+        expires_at_view: Option<u64>,
+    ) -> Self {
         let mut scratch = Vec::new();
-        Self::sign_with_scratch(private, nonce, instruction, &mut scratch)
+        Self::sign_with_scratch(
+            private,
+            nonce,
+            instruction,
+            fee,
+            expires_at_view,
+            &mut scratch,
+        )
     }
 
     pub fn sign_with_scratch(
         private: &ed25519::PrivateKey,
         nonce: u64,
         instruction: Instruction,
+        fee: u64,
+        expires_at_view: Option<u64>,
         scratch: &mut Vec<u8>,
     ) -> Self {
-        Self::write_payload(&nonce, &instruction, scratch);
+        Self::write_payload(nonce, fee, expires_at_view, &instruction, scratch);
         let signature = private.sign(TRANSACTION_NAMESPACE, scratch.as_slice());
 
         Self {
             nonce,
             instruction,
+            fee,
+            expires_at_view,
             public: private.public_key(),
             signature,
         }
     }
 
+    /// Whether the transaction can no longer be included in a block at `view`.
+    pub fn is_expired(&self, view: u64) -> bool {
+        matches!(self.expires_at_view, Some(expires_at_view) if view > expires_at_view)
+    }
+
     pub fn verify(&self) -> bool {
         let mut scratch = Vec::new();
         self.verify_with_scratch(&mut scratch)
     }
 
     pub fn verify_with_scratch(&self, scratch: &mut Vec<u8>) -> bool {
-        Self::write_payload(&self.nonce, &self.instruction, scratch);
+        Self::write_payload(
+            self.nonce,
+            self.fee,
+            self.expires_at_view,
+            &self.instruction,
+            scratch,
+        );
         self.public
             .verify(TRANSACTION_NAMESPACE, scratch.as_slice(), &self.signature)
     }
@@ -319,7 +528,13 @@ impl Transaction {
     }
 
     pub fn verify_batch_with_scratch(&self, batch: &mut Batch, scratch: &mut Vec<u8>) {
-        Self::write_payload(&self.nonce, &self.instruction, scratch);
+        Self::write_payload(
+            self.nonce,
+            self.fee,
+            self.expires_at_view,
+            &self.instruction,
+            scratch,
+        );
         batch.add(
             TRANSACTION_NAMESPACE,
             scratch.as_slice(),
@@ -331,7 +546,7 @@ impl Transaction {
 
 impl Write for Transaction {
     fn write(&self, writer: &mut impl BufMut) {
-        self.nonce.write(writer);
+        Self::write_header(self.nonce, self.fee, self.expires_at_view, writer);
         self.instruction.write(writer);
         self.public.write(writer);
         self.signature.write(writer);
@@ -342,7 +557,26 @@ impl Read for Transaction {
     type Cfg = ();
 
     fn read_cfg(reader: &mut impl Buf, _: &Self::Cfg) -> Result<Self, Error> {
-        let nonce = u64::read(reader)?;
+        let mut nonce = u64::read(reader)?;
+        let mut fee = 0;
+        let mut expires_at_view = None;
+        if nonce & TRANSACTION_EXTENSIONS_FLAG != 0 {
+            nonce &= !TRANSACTION_EXTENSIONS_FLAG;
+            let extensions = u8::read(reader)?;
+            if extensions == 0 || extensions & !TRANSACTION_EXT_KNOWN != 0 {
+                return Err(Error::Invalid("Transaction", "unknown extensions"));
+            }
+            if extensions & TRANSACTION_EXT_FEE != 0 {
+                fee = u64::read(reader)?;
+                // Keep the encoding canonical: a zero fee is written without the extension.
+                if fee == 0 {
+                    return Err(Error::Invalid("Transaction", "zero fee extension"));
+                }
+            }
+            if extensions & TRANSACTION_EXT_EXPIRY != 0 {
+                expires_at_view = Some(u64::read(reader)?);
+            }
+        }
         let instruction = Instruction::read(reader)?;
         let public = ed25519::PublicKey::read(reader)?;
         let signature = ed25519::Signature::read(reader)?;
@@ -350,6 +584,8 @@ impl Read for Transaction {
         Ok(Self {
             nonce,
             instruction,
+            fee,
+            expires_at_view,
             public,
             signature,
         })
@@ -358,7 +594,7 @@ impl Read for Transaction {
 
 impl EncodeSize for Transaction {
     fn encode_size(&self) -> usize {
-        self.nonce.encode_size()
+        Self::header_size(self.fee, self.expires_at_view)
             + self.instruction.encode_size()
             + self.public.encode_size()
             + self.signature.encode_size()
@@ -369,8 +605,11 @@ impl Digestible for Transaction {
     type Digest = Digest;
 
     fn digest(&self) -> Digest {
+        let mut header = Vec::with_capacity(Self::header_size(self.fee, self.expires_at_view));
+        Self::write_header(self.nonce, self.fee, self.expires_at_view, &mut header);
+
         let mut hasher = Sha256::new();
-        hasher.update(self.nonce.to_be_bytes().as_ref());
+        hasher.update(header.as_ref());
         hasher.update(self.instruction.encode().as_ref());
         hasher.update(self.public.as_ref());
         // We don't include the signature as part of the digest (any valid
@@ -422,14 +661,17 @@ pub enum Instruction {
 
     /// Start a tournament (transitions from Registration to Active phase).
     /// Also resets all joined players' chips/shields/doubles to starting values.
+    /// An empty payout structure keeps the one set at creation.
     /// Binary: [17] [tournamentId:u64 BE] [startTimeMs:u64 BE] [endTimeMs:u64 BE]
+    ///         [payoutStructure:PayoutStructure]
     CasinoStartTournament {
         tournament_id: u64,
         start_time_ms: u64,
         end_time_ms: u64,
+        payout_structure: crate::casino::PayoutStructure,
     },
 
-    // Global table instructions (tags 60-66)
+    // Global table instructions (tags 60-68)
     /// Initialize or update global table config.
     /// Binary: [60] [config:GlobalTableConfig]
     GlobalTableInit {
@@ -478,6 +720,28 @@ pub enum Instruction {
         round_id: u64,
     },
 
+    /// Withdraw all of the player's bets for the current round (betting phase only).
+    /// Binary: [67] [gameType:u8] [roundId:u64 BE]
+    GlobalTableCancelBets {
+        game_type: crate::casino::GameType,
+        round_id: u64,
+    },
+
+    /// Replace the player's bets for the current round (betting phase only).
+    /// Binary: [68] [gameType:u8] [roundId:u64 BE] [bets:Vec<GlobalTableBet>]
+    GlobalTableReplaceBets {
+        game_type: crate::casino::GameType,
+        round_id: u64,
+        bets: Vec<crate::casino::GlobalTableBet>,
+    },
+
+    /// Cash out the player's bets at the live multiplier (rolling phase only).
+    /// Binary: [69] [gameType:u8] [roundId:u64 BE]
+    GlobalTableCashOut {
+        game_type: crate::casino::GameType,
+        round_id: u64,
+    },
+
     // Staking & House Instructions (tags 18-21)
     /// Stake chips for voting power and rewards.
     /// Binary: [18] [amount:u64 BE] [duration:u64 BE]
@@ -625,6 +889,86 @@ pub enum Instruction {
         updated_ts: u64,
         source: Vec<u8>,
     },
+
+    /// Admin: grant a role in the admin registry (requires the registry role).
+    /// Binary: [47] [public:PublicKey] [role:u8]
+    GrantAdminRole {
+        public: ed25519::PublicKey,
+        role: crate::casino::AdminRole,
+    },
+
+    /// Admin: revoke a role in the admin registry (requires the registry role).
+    /// Binary: [48] [public:PublicKey] [role:u8]
+    RevokeAdminRole {
+        public: ed25519::PublicKey,
+        role: crate::casino::AdminRole,
+    },
+
+    /// Admin: create a threshold multisig account (requires the registry role).
+    /// Roles held by a multisig can no longer be exercised by single admin keys.
+    /// Binary: [49] [signers:Vec<PublicKey>] [threshold:u8] [timelockSecs:u64 BE] [roles:u8]
+    CreateMultisig {
+        signers: Vec<ed25519::PublicKey>,
+        threshold: u8,
+        timelock_secs: u64,
+        roles: u8,
+    },
+
+    /// Multisig: propose an inner instruction (signers only; the proposer approves implicitly).
+    /// Binary: [50] [multisigId:u64 BE] [instruction:Instruction]
+    ProposeMultisig {
+        multisig_id: u64,
+        instruction: Box<Instruction>,
+    },
+
+    /// Multisig: approve a pending proposal (signers only).
+    /// Binary: [51] [multisigId:u64 BE] [proposalId:u64 BE]
+    ApproveMultisig { multisig_id: u64, proposal_id: u64 },
+
+    /// Multisig: execute an approved proposal once its time-lock elapsed (signers only).
+    /// Binary: [52] [multisigId:u64 BE] [proposalId:u64 BE]
+    ExecuteMultisig { multisig_id: u64, proposal_id: u64 },
+
+    /// Delete completed casino sessions that are past their retention window.
+    /// Used to clear sessions completed before retention existed; their outcomes stay in the
+    /// events log. Active or recent sessions are skipped.
+    /// Binary: [53] [sessionIds:Vec<u64>]
+    PruneCasinoSessions { session_ids: Vec<u64> },
+
+    /// Admin: Create a tournament in the registration phase with its entry rules.
+    /// Binary: [54] [tournamentId:u64 BE] [config:TournamentConfig]
+    CasinoCreateTournament {
+        tournament_id: u64,
+        config: crate::casino::TournamentConfig,
+    },
+
+    /// Admin: Cancel a tournament that has not completed and refund every buy-in.
+    /// Binary: [55] [tournamentId:u64 BE]
+    CasinoCancelTournament { tournament_id: u64 },
+
+    /// Casino: Buy a rebuy or add-on in a running tournament.
+    /// Binary: [56] [tournamentId:u64 BE] [kind:u8]
+    CasinoTournamentTopUp {
+        tournament_id: u64,
+        kind: crate::casino::TopUpKind,
+    },
+
+    /// Multisig: delete a multisig and release the roles it guarded (only as that multisig's
+    /// own approved proposal). Roles another multisig also holds stay guarded.
+    /// Binary: [57] [multisigId:u64 BE]
+    DissolveMultisig { multisig_id: u64 },
+}
+
+impl Instruction {
+    /// Whether this instruction drives a multisig proposal (and so cannot be wrapped by one).
+    pub fn is_multisig_proposal_step(&self) -> bool {
+        matches!(
+            self,
+            Self::ProposeMultisig { .. }
+                | Self::ApproveMultisig { .. }
+                | Self::ExecuteMultisig { .. }
+        )
+    }
 }
 
 impl Write for Instruction {
@@ -679,11 +1023,13 @@ impl Write for Instruction {
                 tournament_id,
                 start_time_ms,
                 end_time_ms,
+                payout_structure,
             } => {
                 tags::instruction::CASINO_START_TOURNAMENT.write(writer);
                 tournament_id.write(writer);
                 start_time_ms.write(writer);
                 end_time_ms.write(writer);
+                payout_structure.write(writer);
             }
 
             // Global table (60-66)
@@ -737,6 +1083,32 @@ impl Write for Instruction {
                 game_type.write(writer);
                 round_id.write(writer);
             }
+            Self::GlobalTableCancelBets {
+                game_type,
+                round_id,
+            } => {
+                tags::instruction::CASINO_GLOBAL_TABLE_CANCEL_BETS.write(writer);
+                game_type.write(writer);
+                round_id.write(writer);
+            }
+            Self::GlobalTableReplaceBets {
+                game_type,
+                round_id,
+                bets,
+            } => {
+                tags::instruction::CASINO_GLOBAL_TABLE_REPLACE_BETS.write(writer);
+                game_type.write(writer);
+                round_id.write(writer);
+                bets.write(writer);
+            }
+            Self::GlobalTableCashOut {
+                game_type,
+                round_id,
+            } => {
+                tags::instruction::CASINO_GLOBAL_TABLE_CASH_OUT.write(writer);
+                game_type.write(writer);
+                round_id.write(writer);
+            }
 
             // Staking (18-21)
             Self::Stake { amount, duration } => {
@@ -888,6 +1260,80 @@ impl Write for Instruction {
                 updated_ts.write(writer);
                 source.write(writer);
             }
+            Self::GrantAdminRole { public, role } => {
+                tags::instruction::GRANT_ADMIN_ROLE.write(writer);
+                public.write(writer);
+                role.write(writer);
+            }
+            Self::RevokeAdminRole { public, role } => {
+                tags::instruction::REVOKE_ADMIN_ROLE.write(writer);
+                public.write(writer);
+                role.write(writer);
+            }
+            Self::CreateMultisig {
+                signers,
+                threshold,
+                timelock_secs,
+                roles,
+            } => {
+                tags::instruction::CREATE_MULTISIG.write(writer);
+                signers.write(writer);
+                threshold.write(writer);
+                timelock_secs.write(writer);
+                roles.write(writer);
+            }
+            Self::ProposeMultisig {
+                multisig_id,
+                instruction,
+            } => {
+                tags::instruction::PROPOSE_MULTISIG.write(writer);
+                multisig_id.write(writer);
+                instruction.write(writer);
+            }
+            Self::ApproveMultisig {
+                multisig_id,
+                proposal_id,
+            } => {
+                tags::instruction::APPROVE_MULTISIG.write(writer);
+                multisig_id.write(writer);
+                proposal_id.write(writer);
+            }
+            Self::ExecuteMultisig {
+                multisig_id,
+                proposal_id,
+            } => {
+                tags::instruction::EXECUTE_MULTISIG.write(writer);
+                multisig_id.write(writer);
+                proposal_id.write(writer);
+            }
+            Self::PruneCasinoSessions { session_ids } => {
+                tags::instruction::PRUNE_CASINO_SESSIONS.write(writer);
+                session_ids.write(writer);
+            }
+            Self::CasinoCreateTournament {
+                tournament_id,
+                config,
+            } => {
+                tags::instruction::CASINO_CREATE_TOURNAMENT.write(writer);
+                tournament_id.write(writer);
+                config.write(writer);
+            }
+            Self::CasinoCancelTournament { tournament_id } => {
+                tags::instruction::CASINO_CANCEL_TOURNAMENT.write(writer);
+                tournament_id.write(writer);
+            }
+            Self::CasinoTournamentTopUp {
+                tournament_id,
+                kind,
+            } => {
+                tags::instruction::CASINO_TOURNAMENT_TOP_UP.write(writer);
+                tournament_id.write(writer);
+                kind.write(writer);
+            }
+            Self::DissolveMultisig { multisig_id } => {
+                tags::instruction::DISSOLVE_MULTISIG.write(writer);
+                multisig_id.write(writer);
+            }
         }
     }
 }
@@ -957,6 +1403,7 @@ impl Read for Instruction {
                 tournament_id: u64::read(reader)?,
                 start_time_ms: u64::read(reader)?,
                 end_time_ms: u64::read(reader)?,
+                payout_structure: crate::casino::PayoutStructure::read(reader)?,
             },
 
             tags::instruction::CASINO_GLOBAL_TABLE_INIT => Self::GlobalTableInit {
@@ -994,6 +1441,27 @@ impl Read for Instruction {
                 game_type: crate::casino::GameType::read(reader)?,
                 round_id: u64::read(reader)?,
             },
+            tags::instruction::CASINO_GLOBAL_TABLE_CANCEL_BETS => Self::GlobalTableCancelBets {
+                game_type: crate::casino::GameType::read(reader)?,
+                round_id: u64::read(reader)?,
+            },
+            tags::instruction::CASINO_GLOBAL_TABLE_REPLACE_BETS => {
+                let game_type = crate::casino::GameType::read(reader)?;
+                let round_id = u64::read(reader)?;
+                let bets = Vec::<crate::casino::GlobalTableBet>::read_range(
+                    reader,
+                    crate::casino::global_table_bets_cfg(),
+                )?;
+                Self::GlobalTableReplaceBets {
+                    game_type,
+                    round_id,
+                    bets,
+                }
+            }
+            tags::instruction::CASINO_GLOBAL_TABLE_CASH_OUT => Self::GlobalTableCashOut {
+                game_type: crate::casino::GameType::read(reader)?,
+                round_id: u64::read(reader)?,
+            },
 
             // Staking (18-21)
             tags::instruction::STAKE => Self::Stake {
@@ -1091,6 +1559,62 @@ impl Read for Instruction {
                 updated_ts: u64::read(reader)?,
                 source: Vec::<u8>::read_range(reader, 0..=64)?,
             },
+            tags::instruction::GRANT_ADMIN_ROLE => Self::GrantAdminRole {
+                public: PublicKey::read(reader)?,
+                role: crate::casino::AdminRole::read(reader)?,
+            },
+            tags::instruction::REVOKE_ADMIN_ROLE => Self::RevokeAdminRole {
+                public: PublicKey::read(reader)?,
+                role: crate::casino::AdminRole::read(reader)?,
+            },
+            tags::instruction::CREATE_MULTISIG => Self::CreateMultisig {
+                signers: Vec::<PublicKey>::read_range(
+                    reader,
+                    1..=crate::casino::MAX_MULTISIG_SIGNERS,
+                )?,
+                threshold: u8::read(reader)?,
+                timelock_secs: u64::read(reader)?,
+                roles: u8::read(reader)?,
+            },
+            tags::instruction::PROPOSE_MULTISIG => {
+                let multisig_id = u64::read(reader)?;
+                let instruction = Instruction::read(reader)?;
+                if instruction.is_multisig_proposal_step() {
+                    return Err(Error::Invalid("Instruction", "nested multisig proposal"));
+                }
+                Self::ProposeMultisig {
+                    multisig_id,
+                    instruction: Box::new(instruction),
+                }
+            }
+            tags::instruction::APPROVE_MULTISIG => Self::ApproveMultisig {
+                multisig_id: u64::read(reader)?,
+                proposal_id: u64::read(reader)?,
+            },
+            tags::instruction::EXECUTE_MULTISIG => Self::ExecuteMultisig {
+                multisig_id: u64::read(reader)?,
+                proposal_id: u64::read(reader)?,
+            },
+            tags::instruction::PRUNE_CASINO_SESSIONS => Self::PruneCasinoSessions {
+                session_ids: Vec::<u64>::read_range(
+                    reader,
+                    1..=crate::casino::MAX_PRUNE_CASINO_SESSIONS,
+                )?,
+            },
+            tags::instruction::CASINO_CREATE_TOURNAMENT => Self::CasinoCreateTournament {
+                tournament_id: u64::read(reader)?,
+                config: crate::casino::TournamentConfig::read(reader)?,
+            },
+            tags::instruction::CASINO_CANCEL_TOURNAMENT => Self::CasinoCancelTournament {
+                tournament_id: u64::read(reader)?,
+            },
+            tags::instruction::CASINO_TOURNAMENT_TOP_UP => Self::CasinoTournamentTopUp {
+                tournament_id: u64::read(reader)?,
+                kind: crate::casino::TopUpKind::read(reader)?,
+            },
+            tags::instruction::DISSOLVE_MULTISIG => Self::DissolveMultisig {
+                multisig_id: u64::read(reader)?,
+            },
 
             i => return Err(Error::InvalidEnum(i)),
         };
@@ -1113,13 +1637,20 @@ impl EncodeSize for Instruction {
                     player.encode_size() + daily_limit.encode_size()
                 }
                 Self::CasinoJoinTournament { .. } => 8,
-                Self::CasinoStartTournament { .. } => 8 + 8 + 8,
+                Self::CasinoStartTournament {
+                    payout_structure, ..
+                } => 8 + 8 + 8 + payout_structure.encode_size(),
                 Self::GlobalTableInit { config } => config.encode_size(),
                 Self::GlobalTableOpenRound { game_type } => game_type.encode_size(),
                 Self::GlobalTableSubmitBets {
                     game_type,
                     round_id,
                     bets,
+                }
+                | Self::GlobalTableReplaceBets {
+                    game_type,
+                    round_id,
+                    bets,
                 } => {
                     game_type.encode_size()
                         + round_id.encode_size()
@@ -1140,6 +1671,14 @@ impl EncodeSize for Instruction {
                 | Self::GlobalTableFinalize {
                     game_type,
                     round_id,
+                }
+                | Self::GlobalTableCancelBets {
+                    game_type,
+                    round_id,
+                }
+                | Self::GlobalTableCashOut {
+                    game_type,
+                    round_id,
                 } => game_type.encode_size() + round_id.encode_size(),
 
                 // Staking
@@ -1220,6 +1759,43 @@ impl EncodeSize for Instruction {
                         + updated_ts.encode_size()
                         + source.encode_size()
                 }
+                Self::GrantAdminRole { public, role } | Self::RevokeAdminRole { public, role } => {
+                    public.encode_size() + role.encode_size()
+                }
+                Self::CreateMultisig {
+                    signers,
+                    threshold,
+                    timelock_secs,
+                    roles,
+                } => {
+                    signers.encode_size()
+                        + threshold.encode_size()
+                        + timelock_secs.encode_size()
+                        + roles.encode_size()
+                }
+                Self::ProposeMultisig {
+                    multisig_id,
+                    instruction,
+                } => multisig_id.encode_size() + instruction.encode_size(),
+                Self::ApproveMultisig {
+                    multisig_id,
+                    proposal_id,
+                }
+                | Self::ExecuteMultisig {
+                    multisig_id,
+                    proposal_id,
+                } => multisig_id.encode_size() + proposal_id.encode_size(),
+                Self::PruneCasinoSessions { session_ids } => session_ids.encode_size(),
+                Self::CasinoCreateTournament {
+                    tournament_id,
+                    config,
+                } => tournament_id.encode_size() + config.encode_size(),
+                Self::CasinoCancelTournament { tournament_id } => tournament_id.encode_size(),
+                Self::CasinoTournamentTopUp {
+                    tournament_id,
+                    kind,
+                } => tournament_id.encode_size() + kind.encode_size(),
+                Self::DissolveMultisig { multisig_id } => multisig_id.encode_size(),
             }
     }
 }
@@ -1297,17 +1873,36 @@ impl Block {
     }
 }
 
+/// Chain parameters fixed at genesis.
+///
+/// The genesis block commits to them, so validators configured differently cannot agree on
+/// any block.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GenesisConfig {
+    /// Keys holding every admin role until the chain persists its admin registry.
+    pub admin_public_keys: Vec<PublicKey>,
+}
+
 /// The canonical genesis block used by the node.
-pub fn genesis_block() -> Block {
+pub fn genesis_block(config: &GenesisConfig) -> Block {
     // Use a deterministic, stable parent digest so the genesis commitment is constant.
-    // (Digest does not implement Default.)
-    let parent = Sha256::hash(b"NULLSPACE_GENESIS");
+    // (Digest does not implement Default.) An empty config keeps the original digest.
+    let parent = if config.admin_public_keys.is_empty() {
+        Sha256::hash(b"NULLSPACE_GENESIS")
+    } else {
+        let mut hasher = Sha256::new();
+        hasher.update(b"NULLSPACE_GENESIS");
+        for key in &config.admin_public_keys {
+            hasher.update(key.as_ref());
+        }
+        hasher.finalize()
+    };
     Block::new(parent, View::zero(), 0, Vec::new())
 }
 
 /// The digest/commitment of the canonical genesis block.
-pub fn genesis_digest() -> Digest {
-    genesis_block().digest()
+pub fn genesis_digest(config: &GenesisConfig) -> Digest {
+    genesis_block(config).digest()
 }
 
 impl Write for Block {
@@ -1555,6 +2150,28 @@ pub enum Key {
     GlobalTableConfig(crate::casino::GameType),
     GlobalTableRound(crate::casino::GameType),
     GlobalTablePlayerSession(crate::casino::GameType, PublicKey),
+
+    // Admin registry (Tag 32)
+    AdminRegistry,
+
+    // Multisig (Tags 33-34)
+    Multisig(u64),
+    MultisigProposal(u64, u64),
+
+    // Session retention (Tags 35-37)
+    /// Casino sessions completed at a view.
+    CompletedCasinoSessions(u64),
+    CasinoSessionGc,
+    /// Active casino sessions to check for inactivity at a view.
+    CasinoSessionDeadlines(u64),
+
+    // Sit-and-go scheduling (Tag 38)
+    /// Running sit-and-go tournaments, ordered by the view they end at.
+    TournamentDeadlines,
+
+    // Global table settlement (Tag 39)
+    /// Players holding bets at a global table that the next round open must settle.
+    GlobalTablePlayers(crate::casino::GameType),
 }
 
 impl Write for Key {
@@ -1627,6 +2244,30 @@ impl Write for Key {
                 game_type.write(writer);
                 pk.write(writer);
             }
+            Self::AdminRegistry => tags::key::ADMIN_REGISTRY.write(writer),
+            Self::Multisig(id) => {
+                tags::key::MULTISIG.write(writer);
+                id.write(writer);
+            }
+            Self::MultisigProposal(multisig_id, proposal_id) => {
+                tags::key::MULTISIG_PROPOSAL.write(writer);
+                multisig_id.write(writer);
+                proposal_id.write(writer);
+            }
+            Self::CompletedCasinoSessions(view) => {
+                tags::key::COMPLETED_CASINO_SESSIONS.write(writer);
+                view.write(writer);
+            }
+            Self::CasinoSessionGc => tags::key::CASINO_SESSION_GC.write(writer),
+            Self::CasinoSessionDeadlines(view) => {
+                tags::key::CASINO_SESSION_DEADLINES.write(writer);
+                view.write(writer);
+            }
+            Self::TournamentDeadlines => tags::key::TOURNAMENT_DEADLINES.write(writer),
+            Self::GlobalTablePlayers(game_type) => {
+                tags::key::GLOBAL_TABLE_PLAYERS.write(writer);
+                game_type.write(writer);
+            }
         }
     }
 }
@@ -1675,6 +2316,20 @@ impl Read for Key {
                 let player = PublicKey::read(reader)?;
                 Self::GlobalTablePlayerSession(game_type, player)
             }
+            tags::key::ADMIN_REGISTRY => Self::AdminRegistry,
+            tags::key::MULTISIG => Self::Multisig(u64::read(reader)?),
+            tags::key::MULTISIG_PROPOSAL => {
+                Self::MultisigProposal(u64::read(reader)?, u64::read(reader)?)
+            }
+            tags::key::COMPLETED_CASINO_SESSIONS => {
+                Self::CompletedCasinoSessions(u64::read(reader)?)
+            }
+            tags::key::CASINO_SESSION_GC => Self::CasinoSessionGc,
+            tags::key::CASINO_SESSION_DEADLINES => Self::CasinoSessionDeadlines(u64::read(reader)?),
+            tags::key::TOURNAMENT_DEADLINES => Self::TournamentDeadlines,
+            tags::key::GLOBAL_TABLE_PLAYERS => {
+                Self::GlobalTablePlayers(crate::casino::GameType::read(reader)?)
+            }
 
             i => return Err(Error::InvalidEnum(i)),
         };
@@ -1717,6 +2372,14 @@ impl EncodeSize for Key {
                 Self::GlobalTableConfig(_) => u8::SIZE,
                 Self::GlobalTableRound(_) => u8::SIZE,
                 Self::GlobalTablePlayerSession(_, _) => u8::SIZE + PublicKey::SIZE,
+                Self::AdminRegistry => 0,
+                Self::Multisig(_) => u64::SIZE,
+                Self::MultisigProposal(_, _) => u64::SIZE + u64::SIZE,
+                Self::CompletedCasinoSessions(_) => u64::SIZE,
+                Self::CasinoSessionGc => 0,
+                Self::CasinoSessionDeadlines(_) => u64::SIZE,
+                Self::TournamentDeadlines => 0,
+                Self::GlobalTablePlayers(_) => u8::SIZE,
         }
     }
 }
@@ -1773,6 +2436,24 @@ pub enum Value {
     GlobalTableConfig(crate::casino::GlobalTableConfig),
     GlobalTableRound(crate::casino::GlobalTableRound),
     GlobalTablePlayerSession(crate::casino::GlobalTablePlayerSession),
+
+    // Admin registry (Tag 32)
+    AdminRegistry(crate::casino::AdminRegistry),
+
+    // Multisig (Tags 33-34)
+    Multisig(crate::casino::MultisigAccount),
+    MultisigProposal(crate::casino::MultisigProposal),
+
+    // Session retention (Tags 35-37)
+    CompletedCasinoSessions(Vec<u64>),
+    CasinoSessionGc(crate::casino::SessionGcState),
+    CasinoSessionDeadlines(Vec<crate::casino::SessionDeadline>),
+
+    // Sit-and-go scheduling (Tag 38)
+    TournamentDeadlines(Vec<crate::casino::TournamentDeadline>),
+
+    // Global table settlement (Tag 39)
+    GlobalTablePlayers(Vec<PublicKey>),
 }
 
 impl Write for Value {
@@ -1884,6 +2565,38 @@ impl Write for Value {
                 tags::value::GLOBAL_TABLE_PLAYER_SESSION.write(writer);
                 session.write(writer);
             }
+            Self::AdminRegistry(registry) => {
+                tags::value::ADMIN_REGISTRY.write(writer);
+                registry.write(writer);
+            }
+            Self::Multisig(multisig) => {
+                tags::value::MULTISIG.write(writer);
+                multisig.write(writer);
+            }
+            Self::MultisigProposal(proposal) => {
+                tags::value::MULTISIG_PROPOSAL.write(writer);
+                proposal.write(writer);
+            }
+            Self::CompletedCasinoSessions(session_ids) => {
+                tags::value::COMPLETED_CASINO_SESSIONS.write(writer);
+                session_ids.write(writer);
+            }
+            Self::CasinoSessionGc(state) => {
+                tags::value::CASINO_SESSION_GC.write(writer);
+                state.write(writer);
+            }
+            Self::CasinoSessionDeadlines(deadlines) => {
+                tags::value::CASINO_SESSION_DEADLINES.write(writer);
+                deadlines.write(writer);
+            }
+            Self::TournamentDeadlines(deadlines) => {
+                tags::value::TOURNAMENT_DEADLINES.write(writer);
+                deadlines.write(writer);
+            }
+            Self::GlobalTablePlayers(players) => {
+                tags::value::GLOBAL_TABLE_PLAYERS.write(writer);
+                players.write(writer);
+            }
         }
     }
 }
@@ -1956,6 +2669,34 @@ impl Read for Value {
             tags::value::GLOBAL_TABLE_PLAYER_SESSION => Self::GlobalTablePlayerSession(
                 crate::casino::GlobalTablePlayerSession::read(reader)?,
             ),
+            tags::value::ADMIN_REGISTRY => {
+                Self::AdminRegistry(crate::casino::AdminRegistry::read(reader)?)
+            }
+            tags::value::MULTISIG => Self::Multisig(crate::casino::MultisigAccount::read(reader)?),
+            tags::value::MULTISIG_PROPOSAL => {
+                Self::MultisigProposal(crate::casino::MultisigProposal::read(reader)?)
+            }
+            tags::value::COMPLETED_CASINO_SESSIONS => Self::CompletedCasinoSessions(
+                Vec::<u64>::read_range(reader, 0..=MAX_BLOCK_TRANSACTIONS)?,
+            ),
+            tags::value::CASINO_SESSION_GC => {
+                Self::CasinoSessionGc(crate::casino::SessionGcState::read(reader)?)
+            }
+            tags::value::CASINO_SESSION_DEADLINES => {
+                Self::CasinoSessionDeadlines(Vec::<crate::casino::SessionDeadline>::read_range(
+                    reader,
+                    0..=MAX_BLOCK_TRANSACTIONS,
+                )?)
+            }
+            tags::value::TOURNAMENT_DEADLINES => {
+                Self::TournamentDeadlines(Vec::<crate::casino::TournamentDeadline>::read_range(
+                    reader,
+                    0..=crate::casino::MAX_SCHEDULED_TOURNAMENT_ENDS,
+                )?)
+            }
+            tags::value::GLOBAL_TABLE_PLAYERS => Self::GlobalTablePlayers(
+                Vec::<PublicKey>::read_range(reader, 0..=crate::casino::MAX_GLOBAL_TABLE_PLAYERS)?,
+            ),
 
             i => return Err(Error::InvalidEnum(i)),
         };
@@ -2001,6 +2742,14 @@ impl EncodeSize for Value {
                 Self::GlobalTableConfig(config) => config.encode_size(),
                 Self::GlobalTableRound(round) => round.encode_size(),
                 Self::GlobalTablePlayerSession(session) => session.encode_size(),
+                Self::AdminRegistry(registry) => registry.encode_size(),
+                Self::Multisig(multisig) => multisig.encode_size(),
+                Self::MultisigProposal(proposal) => proposal.encode_size(),
+                Self::CompletedCasinoSessions(session_ids) => session_ids.encode_size(),
+                Self::CasinoSessionGc(state) => state.encode_size(),
+                Self::CasinoSessionDeadlines(deadlines) => deadlines.encode_size(),
+                Self::TournamentDeadlines(deadlines) => deadlines.encode_size(),
+                Self::GlobalTablePlayers(players) => players.encode_size(),
             }
     }
 }
@@ -2064,7 +2813,7 @@ pub enum Event {
         active_super: bool,
     },
 
-    // Tournament events (tags 25-28)
+    // Tournament events (tags 25-28, 78-81)
     TournamentStarted {
         id: u64,
         start_block: u64,
@@ -2081,6 +2830,43 @@ pub enum Event {
         id: u64,
         rankings: Vec<(PublicKey, u64)>,
     },
+    TournamentCreated {
+        id: u64,
+        config: crate::casino::TournamentConfig,
+    },
+    TournamentBuyIn {
+        tournament_id: u64,
+        player: PublicKey,
+        amount: u64,
+        rake: u64,
+        prize_pool: u64,
+        player_balances: crate::casino::PlayerBalanceSnapshot,
+    },
+    TournamentCancelled {
+        id: u64,
+        refunds: Vec<(PublicKey, u64)>,
+    },
+    TournamentPrizesPaid {
+        id: u64,
+        currency: crate::casino::TournamentCurrency,
+        payouts: Vec<(PublicKey, u64)>,
+    },
+    TournamentTopUp {
+        tournament_id: u64,
+        player: PublicKey,
+        kind: crate::casino::TopUpKind,
+        cost: u64,
+        prize_pool: u64,
+        /// The player's totals in this tournament, including this purchase.
+        rebuys: u8,
+        add_ons: u8,
+        player_balances: crate::casino::PlayerBalanceSnapshot,
+    },
+    /// Completed sessions deleted by one `PruneCasinoSessions` instruction.
+    CasinoSessionsPruned {
+        pruner: PublicKey,
+        session_ids: Vec<u64>,
+    },
 
     // Vault & AMM events (tags 30-36)
     VaultCreated {
@@ -2233,7 +3019,7 @@ pub enum Event {
         pool_balance: u64,
     },
 
-    // Global table events (tags 60-66)
+    // Global table events (tags 60-68, 76-77)
     GlobalTableRoundOpened {
         round: crate::casino::GlobalTableRound,
     },
@@ -2268,6 +3054,81 @@ pub enum Event {
         game_type: crate::casino::GameType,
         round_id: u64,
     },
+    GlobalTableBetsCancelled {
+        player: PublicKey,
+        round_id: u64,
+        bets: Vec<crate::casino::GlobalTableBet>,
+        player_balances: crate::casino::PlayerBalanceSnapshot,
+    },
+    GlobalTableBetsReplaced {
+        player: PublicKey,
+        round_id: u64,
+        cancelled: Vec<crate::casino::GlobalTableBet>,
+        bets: Vec<crate::casino::GlobalTableBet>,
+        player_balances: crate::casino::PlayerBalanceSnapshot,
+    },
+    GlobalTableRolling {
+        game_type: crate::casino::GameType,
+        round_id: u64,
+        phase_ends_at_ms: u64,
+    },
+    GlobalTableCashedOut {
+        player: PublicKey,
+        round_id: u64,
+        /// Multiplier locked in, in hundredths (150 = 1.50x).
+        multiplier: u32,
+    },
+
+    // Admin registry events (tags 69-70)
+    AdminRoleGranted {
+        admin: PublicKey,
+        public: PublicKey,
+        role: crate::casino::AdminRole,
+    },
+    AdminRoleRevoked {
+        admin: PublicKey,
+        public: PublicKey,
+        role: crate::casino::AdminRole,
+    },
+
+    // Multisig events (tags 71-74, 83)
+    MultisigCreated {
+        admin: PublicKey,
+        multisig: crate::casino::MultisigAccount,
+    },
+    MultisigProposed {
+        proposer: PublicKey,
+        multisig_id: u64,
+        proposal_id: u64,
+    },
+    MultisigApproved {
+        signer: PublicKey,
+        multisig_id: u64,
+        proposal_id: u64,
+        approvals: u8,
+        /// Unix seconds after which the proposal may execute (0 until the threshold is reached).
+        executable_at: u64,
+    },
+    MultisigExecuted {
+        executor: PublicKey,
+        multisig_id: u64,
+        proposal_id: u64,
+    },
+    MultisigDissolved {
+        multisig_id: u64,
+        /// Roles still guarded by other multisigs afterwards.
+        guarded_roles: u8,
+    },
+
+    // Transaction fee events (tag 75)
+    /// Emitted before a transaction's own events when it paid a non-zero fee.
+    TransactionFeePaid {
+        player: PublicKey,
+        fee: u64,
+        /// Portion of `fee` that was burned; the remainder went to the house.
+        burned: u64,
+        player_balances: crate::casino::PlayerBalanceSnapshot,
+    },
 
     // Savings events (tags 48-50)
     SavingsDeposited {
@@ -2460,6 +3321,70 @@ impl Write for Event {
                 id.write(writer);
                 rankings.write(writer);
             }
+            Self::TournamentCreated { id, config } => {
+                tags::event::TOURNAMENT_CREATED.write(writer);
+                id.write(writer);
+                config.write(writer);
+            }
+            Self::TournamentBuyIn {
+                tournament_id,
+                player,
+                amount,
+                rake,
+                prize_pool,
+                player_balances,
+            } => {
+                tags::event::TOURNAMENT_BUY_IN.write(writer);
+                tournament_id.write(writer);
+                player.write(writer);
+                amount.write(writer);
+                rake.write(writer);
+                prize_pool.write(writer);
+                player_balances.write(writer);
+            }
+            Self::TournamentCancelled { id, refunds } => {
+                tags::event::TOURNAMENT_CANCELLED.write(writer);
+                id.write(writer);
+                refunds.write(writer);
+            }
+            Self::TournamentPrizesPaid {
+                id,
+                currency,
+                payouts,
+            } => {
+                tags::event::TOURNAMENT_PRIZES_PAID.write(writer);
+                id.write(writer);
+                currency.write(writer);
+                payouts.write(writer);
+            }
+            Self::TournamentTopUp {
+                tournament_id,
+                player,
+                kind,
+                cost,
+                prize_pool,
+                rebuys,
+                add_ons,
+                player_balances,
+            } => {
+                tags::event::TOURNAMENT_TOP_UP.write(writer);
+                tournament_id.write(writer);
+                player.write(writer);
+                kind.write(writer);
+                cost.write(writer);
+                prize_pool.write(writer);
+                rebuys.write(writer);
+                add_ons.write(writer);
+                player_balances.write(writer);
+            }
+            Self::CasinoSessionsPruned {
+                pruner,
+                session_ids,
+            } => {
+                tags::event::CASINO_SESSIONS_PRUNED.write(writer);
+                pruner.write(writer);
+                session_ids.write(writer);
+            }
 
             // Vault & AMM events (tags 30-36)
             Self::VaultCreated { player, vault } => {
@@ -2805,6 +3730,131 @@ impl Write for Event {
                 game_type.write(writer);
                 round_id.write(writer);
             }
+            Self::GlobalTableBetsCancelled {
+                player,
+                round_id,
+                bets,
+                player_balances,
+            } => {
+                tags::event::GLOBAL_TABLE_BETS_CANCELLED.write(writer);
+                player.write(writer);
+                round_id.write(writer);
+                bets.write(writer);
+                player_balances.write(writer);
+            }
+            Self::GlobalTableBetsReplaced {
+                player,
+                round_id,
+                cancelled,
+                bets,
+                player_balances,
+            } => {
+                tags::event::GLOBAL_TABLE_BETS_REPLACED.write(writer);
+                player.write(writer);
+                round_id.write(writer);
+                cancelled.write(writer);
+                bets.write(writer);
+                player_balances.write(writer);
+            }
+            Self::GlobalTableRolling {
+                game_type,
+                round_id,
+                phase_ends_at_ms,
+            } => {
+                tags::event::GLOBAL_TABLE_ROLLING.write(writer);
+                game_type.write(writer);
+                round_id.write(writer);
+                phase_ends_at_ms.write(writer);
+            }
+            Self::GlobalTableCashedOut {
+                player,
+                round_id,
+                multiplier,
+            } => {
+                tags::event::GLOBAL_TABLE_CASHED_OUT.write(writer);
+                player.write(writer);
+                round_id.write(writer);
+                multiplier.write(writer);
+            }
+            Self::AdminRoleGranted {
+                admin,
+                public,
+                role,
+            } => {
+                tags::event::ADMIN_ROLE_GRANTED.write(writer);
+                admin.write(writer);
+                public.write(writer);
+                role.write(writer);
+            }
+            Self::AdminRoleRevoked {
+                admin,
+                public,
+                role,
+            } => {
+                tags::event::ADMIN_ROLE_REVOKED.write(writer);
+                admin.write(writer);
+                public.write(writer);
+                role.write(writer);
+            }
+            Self::MultisigCreated { admin, multisig } => {
+                tags::event::MULTISIG_CREATED.write(writer);
+                admin.write(writer);
+                multisig.write(writer);
+            }
+            Self::MultisigProposed {
+                proposer,
+                multisig_id,
+                proposal_id,
+            } => {
+                tags::event::MULTISIG_PROPOSED.write(writer);
+                proposer.write(writer);
+                multisig_id.write(writer);
+                proposal_id.write(writer);
+            }
+            Self::MultisigApproved {
+                signer,
+                multisig_id,
+                proposal_id,
+                approvals,
+                executable_at,
+            } => {
+                tags::event::MULTISIG_APPROVED.write(writer);
+                signer.write(writer);
+                multisig_id.write(writer);
+                proposal_id.write(writer);
+                approvals.write(writer);
+                executable_at.write(writer);
+            }
+            Self::MultisigExecuted {
+                executor,
+                multisig_id,
+                proposal_id,
+            } => {
+                tags::event::MULTISIG_EXECUTED.write(writer);
+                executor.write(writer);
+                multisig_id.write(writer);
+                proposal_id.write(writer);
+            }
+            Self::MultisigDissolved {
+                multisig_id,
+                guarded_roles,
+            } => {
+                tags::event::MULTISIG_DISSOLVED.write(writer);
+                multisig_id.write(writer);
+                guarded_roles.write(writer);
+            }
+            Self::TransactionFeePaid {
+                player,
+                fee,
+                burned,
+                player_balances,
+            } => {
+                tags::event::TRANSACTION_FEE_PAID.write(writer);
+                player.write(writer);
+                fee.write(writer);
+                burned.write(writer);
+                player_balances.write(writer);
+            }
             Self::SavingsDeposited {
                 player,
                 amount,
@@ -3047,6 +4097,44 @@ impl Read for Event {
                 id: u64::read(reader)?,
                 rankings: Vec::<(PublicKey, u64)>::read_range(reader, 0..=1000)?,
             },
+            tags::event::TOURNAMENT_CREATED => Self::TournamentCreated {
+                id: u64::read(reader)?,
+                config: crate::casino::TournamentConfig::read(reader)?,
+            },
+            tags::event::TOURNAMENT_BUY_IN => Self::TournamentBuyIn {
+                tournament_id: u64::read(reader)?,
+                player: PublicKey::read(reader)?,
+                amount: u64::read(reader)?,
+                rake: u64::read(reader)?,
+                prize_pool: u64::read(reader)?,
+                player_balances: crate::casino::PlayerBalanceSnapshot::read(reader)?,
+            },
+            tags::event::TOURNAMENT_CANCELLED => Self::TournamentCancelled {
+                id: u64::read(reader)?,
+                refunds: Vec::<(PublicKey, u64)>::read_range(reader, 0..=1000)?,
+            },
+            tags::event::TOURNAMENT_PRIZES_PAID => Self::TournamentPrizesPaid {
+                id: u64::read(reader)?,
+                currency: crate::casino::TournamentCurrency::read(reader)?,
+                payouts: Vec::<(PublicKey, u64)>::read_range(reader, 0..=1000)?,
+            },
+            tags::event::TOURNAMENT_TOP_UP => Self::TournamentTopUp {
+                tournament_id: u64::read(reader)?,
+                player: PublicKey::read(reader)?,
+                kind: crate::casino::TopUpKind::read(reader)?,
+                cost: u64::read(reader)?,
+                prize_pool: u64::read(reader)?,
+                rebuys: u8::read(reader)?,
+                add_ons: u8::read(reader)?,
+                player_balances: crate::casino::PlayerBalanceSnapshot::read(reader)?,
+            },
+            tags::event::CASINO_SESSIONS_PRUNED => Self::CasinoSessionsPruned {
+                pruner: PublicKey::read(reader)?,
+                session_ids: Vec::<u64>::read_range(
+                    reader,
+                    0..=crate::casino::MAX_PRUNE_CASINO_SESSIONS,
+                )?,
+            },
 
             // Vault & AMM events (tags 30-36)
             tags::event::VAULT_CREATED => Self::VaultCreated {
@@ -3249,6 +4337,79 @@ impl Read for Event {
                 game_type: crate::casino::GameType::read(reader)?,
                 round_id: u64::read(reader)?,
             },
+            tags::event::GLOBAL_TABLE_BETS_CANCELLED => Self::GlobalTableBetsCancelled {
+                player: PublicKey::read(reader)?,
+                round_id: u64::read(reader)?,
+                bets: Vec::<crate::casino::GlobalTableBet>::read_range(
+                    reader,
+                    crate::casino::global_table_bets_cfg(),
+                )?,
+                player_balances: crate::casino::PlayerBalanceSnapshot::read(reader)?,
+            },
+            tags::event::GLOBAL_TABLE_BETS_REPLACED => Self::GlobalTableBetsReplaced {
+                player: PublicKey::read(reader)?,
+                round_id: u64::read(reader)?,
+                cancelled: Vec::<crate::casino::GlobalTableBet>::read_range(
+                    reader,
+                    crate::casino::global_table_bets_cfg(),
+                )?,
+                bets: Vec::<crate::casino::GlobalTableBet>::read_range(
+                    reader,
+                    crate::casino::global_table_bets_cfg(),
+                )?,
+                player_balances: crate::casino::PlayerBalanceSnapshot::read(reader)?,
+            },
+            tags::event::GLOBAL_TABLE_ROLLING => Self::GlobalTableRolling {
+                game_type: crate::casino::GameType::read(reader)?,
+                round_id: u64::read(reader)?,
+                phase_ends_at_ms: u64::read(reader)?,
+            },
+            tags::event::GLOBAL_TABLE_CASHED_OUT => Self::GlobalTableCashedOut {
+                player: PublicKey::read(reader)?,
+                round_id: u64::read(reader)?,
+                multiplier: u32::read(reader)?,
+            },
+            tags::event::ADMIN_ROLE_GRANTED => Self::AdminRoleGranted {
+                admin: PublicKey::read(reader)?,
+                public: PublicKey::read(reader)?,
+                role: crate::casino::AdminRole::read(reader)?,
+            },
+            tags::event::ADMIN_ROLE_REVOKED => Self::AdminRoleRevoked {
+                admin: PublicKey::read(reader)?,
+                public: PublicKey::read(reader)?,
+                role: crate::casino::AdminRole::read(reader)?,
+            },
+            tags::event::MULTISIG_CREATED => Self::MultisigCreated {
+                admin: PublicKey::read(reader)?,
+                multisig: crate::casino::MultisigAccount::read(reader)?,
+            },
+            tags::event::MULTISIG_PROPOSED => Self::MultisigProposed {
+                proposer: PublicKey::read(reader)?,
+                multisig_id: u64::read(reader)?,
+                proposal_id: u64::read(reader)?,
+            },
+            tags::event::MULTISIG_APPROVED => Self::MultisigApproved {
+                signer: PublicKey::read(reader)?,
+                multisig_id: u64::read(reader)?,
+                proposal_id: u64::read(reader)?,
+                approvals: u8::read(reader)?,
+                executable_at: u64::read(reader)?,
+            },
+            tags::event::MULTISIG_EXECUTED => Self::MultisigExecuted {
+                executor: PublicKey::read(reader)?,
+                multisig_id: u64::read(reader)?,
+                proposal_id: u64::read(reader)?,
+            },
+            tags::event::MULTISIG_DISSOLVED => Self::MultisigDissolved {
+                multisig_id: u64::read(reader)?,
+                guarded_roles: u8::read(reader)?,
+            },
+            tags::event::TRANSACTION_FEE_PAID => Self::TransactionFeePaid {
+                player: PublicKey::read(reader)?,
+                fee: u64::read(reader)?,
+                burned: u64::read(reader)?,
+                player_balances: crate::casino::PlayerBalanceSnapshot::read(reader)?,
+            },
             tags::event::SAVINGS_DEPOSITED => Self::SavingsDeposited {
                 player: PublicKey::read(reader)?,
                 amount: u64::read(reader)?,
@@ -3410,6 +4571,53 @@ impl EncodeSize for Event {
                     id.encode_size() + phase.encode_size()
                 }
                 Self::TournamentEnded { id, rankings } => id.encode_size() + rankings.encode_size(),
+                Self::TournamentCreated { id, config } => id.encode_size() + config.encode_size(),
+                Self::TournamentBuyIn {
+                    tournament_id,
+                    player,
+                    amount,
+                    rake,
+                    prize_pool,
+                    player_balances,
+                } => {
+                    tournament_id.encode_size()
+                        + player.encode_size()
+                        + amount.encode_size()
+                        + rake.encode_size()
+                        + prize_pool.encode_size()
+                        + player_balances.encode_size()
+                }
+                Self::TournamentCancelled { id, refunds } => {
+                    id.encode_size() + refunds.encode_size()
+                }
+                Self::TournamentPrizesPaid {
+                    id,
+                    currency,
+                    payouts,
+                } => id.encode_size() + currency.encode_size() + payouts.encode_size(),
+                Self::TournamentTopUp {
+                    tournament_id,
+                    player,
+                    kind,
+                    cost,
+                    prize_pool,
+                    rebuys,
+                    add_ons,
+                    player_balances,
+                } => {
+                    tournament_id.encode_size()
+                        + player.encode_size()
+                        + kind.encode_size()
+                        + cost.encode_size()
+                        + prize_pool.encode_size()
+                        + rebuys.encode_size()
+                        + add_ons.encode_size()
+                        + player_balances.encode_size()
+                }
+                Self::CasinoSessionsPruned {
+                    pruner,
+                    session_ids,
+                } => pruner.encode_size() + session_ids.encode_size(),
 
                 // Vault & AMM events (tags 30-36)
                 Self::VaultCreated { player, vault } => {
@@ -3710,6 +4918,95 @@ impl EncodeSize for Event {
                 Self::GlobalTableFinalized { game_type, round_id } => {
                     game_type.encode_size() + round_id.encode_size()
                 }
+                Self::GlobalTableBetsCancelled {
+                    player,
+                    round_id,
+                    bets,
+                    player_balances,
+                } => {
+                    player.encode_size()
+                        + round_id.encode_size()
+                        + bets.encode_size()
+                        + player_balances.encode_size()
+                }
+                Self::GlobalTableBetsReplaced {
+                    player,
+                    round_id,
+                    cancelled,
+                    bets,
+                    player_balances,
+                } => {
+                    player.encode_size()
+                        + round_id.encode_size()
+                        + cancelled.encode_size()
+                        + bets.encode_size()
+                        + player_balances.encode_size()
+                }
+                Self::GlobalTableRolling {
+                    game_type,
+                    round_id,
+                    phase_ends_at_ms,
+                } => {
+                    game_type.encode_size()
+                        + round_id.encode_size()
+                        + phase_ends_at_ms.encode_size()
+                }
+                Self::GlobalTableCashedOut {
+                    player,
+                    round_id,
+                    multiplier,
+                } => player.encode_size() + round_id.encode_size() + multiplier.encode_size(),
+                Self::AdminRoleGranted {
+                    admin,
+                    public,
+                    role,
+                }
+                | Self::AdminRoleRevoked {
+                    admin,
+                    public,
+                    role,
+                } => admin.encode_size() + public.encode_size() + role.encode_size(),
+                Self::MultisigCreated { admin, multisig } => {
+                    admin.encode_size() + multisig.encode_size()
+                }
+                Self::MultisigProposed {
+                    proposer: signer,
+                    multisig_id,
+                    proposal_id,
+                }
+                | Self::MultisigExecuted {
+                    executor: signer,
+                    multisig_id,
+                    proposal_id,
+                } => signer.encode_size() + multisig_id.encode_size() + proposal_id.encode_size(),
+                Self::MultisigDissolved {
+                    multisig_id,
+                    guarded_roles,
+                } => multisig_id.encode_size() + guarded_roles.encode_size(),
+                Self::MultisigApproved {
+                    signer,
+                    multisig_id,
+                    proposal_id,
+                    approvals,
+                    executable_at,
+                } => {
+                    signer.encode_size()
+                        + multisig_id.encode_size()
+                        + proposal_id.encode_size()
+                        + approvals.encode_size()
+                        + executable_at.encode_size()
+                }
+                Self::TransactionFeePaid {
+                    player,
+                    fee,
+                    burned,
+                    player_balances,
+                } => {
+                    player.encode_size()
+                        + fee.encode_size()
+                        + burned.encode_size()
+                        + player_balances.encode_size()
+                }
                 Self::SavingsDeposited {
                     player,
                     amount,
@@ -60,6 +60,11 @@ const ODDS_SUMMARY: Record<GameType, string> = {
   [GameType.THREE_CARD]: '1:1–40:1+',
   [GameType.ULTIMATE_HOLDEM]: '1:1–500:1',
   [GameType.CASINO_WAR]: '1:1–10:1',
+  [GameType.PAI_GOW]: '0.95:1–8000:1',
+  [GameType.LET_IT_RIDE]: '1:1–1000:1',
+  [GameType.KENO]: '1:1–10000:1',
+  [GameType.BIG_SIX]: '1:1–45:1',
+  [GameType.CRASH]: '1.01x–100x',
   [GameType.NONE]: '—',
 };
 
@@ -31,6 +31,15 @@ export const isValidCrapsTarget = (type: CrapsBet['type'], target?: number): boo
   if (type === 'HARDWAY') {
     return target === 4 || target === 6 || target === 8 || target === 10;
   }
+  if (
+    type === 'BUY'
+    || type === 'LAY'
+    || type === 'BUY_VIG_UP_FRONT'
+    || type === 'LAY_VIG_UP_FRONT'
+    || type === 'PUT'
+  ) {
+    return target === 4 || target === 5 || target === 6 || target === 8 || target === 9 || target === 10;
+  }
   return true;
 };
 
@@ -39,5 +48,8 @@ export const invalidTargetMessage = (type: CrapsBet['type']): string => {
   if (type === 'NO') return 'SELECT NO NUMBER (2-12, NOT 7)';
   if (type === 'NEXT') return 'SELECT NEXT NUMBER (2-12)';
   if (type === 'HARDWAY') return 'SELECT HARDWAY (4/6/8/10)';
+  if (type === 'BUY' || type === 'BUY_VIG_UP_FRONT') return 'SELECT BUY NUMBER (4/5/6/8/9/10)';
+  if (type === 'LAY' || type === 'LAY_VIG_UP_FRONT') return 'SELECT LAY NUMBER (4/5/6/8/9/10)';
+  if (type === 'PUT') return 'SELECT PUT NUMBER (4/5/6/8/9/10)';
   return 'INVALID TARGET';
 };
@@ -223,6 +223,26 @@ export const useDeal = ({
         }
       }
 
+      if (gameState.type === GameType.VIDEO_POKER && gameState.stage === 'BETTING') {
+        if (isPendingRef.current) {
+          logDebug('[useDeal] Video Poker deal blocked - transaction pending');
+          return;
+        }
+        isPendingRef.current = true;
+        try {
+          setGameState(prev => ({ ...prev, message: 'DEALING...' }));
+          // Pick the game and deal: Jacks or Better, full pay.
+          const result = await chainService.sendMove(sessionId, new Uint8Array([0xff, 0]));
+          if (result.txHash) setLastTxSig(result.txHash);
+          return;
+        } catch (error) {
+          console.error('[useDeal] Video Poker Deal failed:', error);
+          setGameState(prev => ({ ...prev, message: 'DEAL FAILED' }));
+          isPendingRef.current = false;
+          return;
+        }
+      }
+
       if (gameState.type === GameType.THREE_CARD) {
         if (isPendingRef.current) {
           logDebug('[useDeal] Three Card deal/reveal blocked - transaction pending');
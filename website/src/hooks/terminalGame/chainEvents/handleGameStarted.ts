@@ -104,6 +104,31 @@ export const createGameStartedHandler = ({
           })();
         }
       }
+
+      if (frontendGameType === GameType.VIDEO_POKER && chainService && currentSessionIdRef.current) {
+        const stage = event.initialState[0];
+        if (stage === 2) {
+          if (isPendingRef.current) {
+            logDebug('[chainEvents] Video Poker auto-deal blocked - pending');
+            return;
+          }
+
+          void (async () => {
+            isPendingRef.current = true;
+            try {
+              // Pick the game and deal: Jacks or Better, full pay.
+              const payload = new Uint8Array([0xff, 0]);
+              const result = await chainService.sendMove(currentSessionIdRef.current!, payload);
+              if (result.txHash) setLastTxSig(result.txHash);
+              setGameState(prev => ({ ...prev, message: 'DEALING...' }));
+            } catch (error) {
+              console.error('[chainEvents] Video Poker auto-deal failed:', error);
+              setGameState(prev => ({ ...prev, message: 'DEAL FAILED' }));
+              isPendingRef.current = false;
+            }
+          })();
+        }
+      }
     } else {
       const initialMessage =
         frontendGameType === GameType.CRAPS
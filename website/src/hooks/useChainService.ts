@@ -18,6 +18,11 @@ const CHAIN_TO_FRONTEND_GAME_TYPE: Record<ChainGameType, GameType> = {
   [ChainGameType.SicBo]: GameType.SIC_BO,
   [ChainGameType.ThreeCard]: GameType.THREE_CARD,
   [ChainGameType.UltimateHoldem]: GameType.ULTIMATE_HOLDEM,
+  [ChainGameType.PaiGow]: GameType.PAI_GOW,
+  [ChainGameType.LetItRide]: GameType.LET_IT_RIDE,
+  [ChainGameType.Keno]: GameType.KENO,
+  [ChainGameType.BigSix]: GameType.BIG_SIX,
+  [ChainGameType.Crash]: GameType.CRASH,
 };
 
 export const useChainService = () => {
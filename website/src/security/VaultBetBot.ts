@@ -233,7 +233,7 @@ export class VaultBetBot {
       case GameType.Craps:
         return [this.serializeCrapsBet(0, 0, this.config.betAmount), new Uint8Array([2])]; // Pass + roll
       case GameType.VideoPoker:
-        return [new Uint8Array([31])]; // Hold all
+        return [new Uint8Array([0xff, 0]), new Uint8Array([31])]; // Deal, hold all
       case GameType.HiLo:
         return [new Uint8Array([Math.floor(Math.random() * 2)])];
       case GameType.Roulette:
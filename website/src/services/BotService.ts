@@ -518,7 +518,9 @@ export class BotService {
       }
 
       case GameType.VideoPoker: {
-        // Random hold mask (0..31). Holding all (31) is allowed but uncommon.
+        // Deal Jacks or Better (full pay), then a random hold mask (0..31).
+        // Holding all (31) is allowed but uncommon.
+        moves.push(new Uint8Array([0xff, 0]));
         const mask = Math.random() < 0.15 ? 31 : this.randInt(0, 31);
         moves.push(new Uint8Array([mask]));
         return { startBet: mainBet, moves };
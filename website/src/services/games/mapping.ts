@@ -12,6 +12,11 @@ export const GAME_TYPE_MAP: Record<GameType, ChainGameType> = {
   [GameType.SIC_BO]: ChainGameType.SicBo,
   [GameType.THREE_CARD]: ChainGameType.ThreeCard,
   [GameType.ULTIMATE_HOLDEM]: ChainGameType.UltimateHoldem,
+  [GameType.PAI_GOW]: ChainGameType.PaiGow,
+  [GameType.LET_IT_RIDE]: ChainGameType.LetItRide,
+  [GameType.KENO]: ChainGameType.Keno,
+  [GameType.BIG_SIX]: ChainGameType.BigSix,
+  [GameType.CRASH]: ChainGameType.Crash,
   [GameType.NONE]: ChainGameType.Blackjack,
 };
 
@@ -26,11 +31,18 @@ export const CHAIN_TO_FRONTEND_GAME_TYPE: Record<ChainGameType, GameType> = {
   [ChainGameType.SicBo]: GameType.SIC_BO,
   [ChainGameType.ThreeCard]: GameType.THREE_CARD,
   [ChainGameType.UltimateHoldem]: GameType.ULTIMATE_HOLDEM,
+  [ChainGameType.PaiGow]: GameType.PAI_GOW,
+  [ChainGameType.LetItRide]: GameType.LET_IT_RIDE,
+  [ChainGameType.Keno]: GameType.KENO,
+  [ChainGameType.BigSix]: GameType.BIG_SIX,
+  [ChainGameType.Crash]: GameType.CRASH,
 };
 
 export const TABLE_GAMES: GameType[] = [
   GameType.BACCARAT,
+  GameType.BIG_SIX,
   GameType.CRAPS,
+  GameType.CRASH,
   GameType.ROULETTE,
   GameType.SIC_BO,
 ];
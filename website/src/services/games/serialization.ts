@@ -84,6 +84,11 @@ const rouletteBetToNumeric = (bet: RouletteBet): { betType: number; number: numb
     case 'STREET': betType = 11; number = bet.target ?? 0; break;
     case 'CORNER': betType = 12; number = bet.target ?? 0; break;
     case 'SIX_LINE': betType = 13; number = bet.target ?? 0; break;
+    case 'VOISINS': betType = 14; break;
+    case 'TIERS': betType = 15; break;
+    case 'ORPHELINS': betType = 16; break;
+    case 'JEU_ZERO': betType = 17; break;
+    case 'NEIGHBOURS': betType = 18; number = bet.target ?? 0; break;
     default: throw new Error(`Unknown bet type: ${bet.type}`);
   }
 
@@ -197,6 +202,18 @@ const crapsBetToNumeric = (bet: CrapsBet): { betType: number; target: number; am
     'RIDE_LINE': 20,
     'REPLAY': 21,
     'HOT_ROLLER': 22,
+    'ANY_SEVEN': 23,
+    'ANY_CRAPS': 24,
+    'HORN': 25,
+    'C_AND_E': 26,
+    'WORLD': 27,
+    'BIG_6': 28,
+    'BIG_8': 29,
+    'BUY': 30,
+    'LAY': 31,
+    'BUY_VIG_UP_FRONT': 32,
+    'LAY_VIG_UP_FRONT': 33,
+    'PUT': 34,
   };
 
   validateBetAmount(bet.amount, 'CrapsBet');
@@ -220,6 +237,14 @@ const crapsBetToNumeric = (bet: CrapsBet): { betType: number; target: number; am
     || bet.type === 'REPLAY'
     || bet.type === 'HOT_ROLLER') {
     target = 0;
+  } else if (bet.type === 'ANY_SEVEN'
+    || bet.type === 'ANY_CRAPS'
+    || bet.type === 'HORN'
+    || bet.type === 'C_AND_E'
+    || bet.type === 'WORLD'
+    || bet.type === 'BIG_6'
+    || bet.type === 'BIG_8') {
+    target = 0;
   }
 
   return { betType: betTypeValue, target, amount: bet.amount };
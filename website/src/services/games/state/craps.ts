@@ -39,6 +39,18 @@ const VALID_CRAPS_BETS = new Set<CrapsBet['type']>([
   'RIDE_LINE',
   'REPLAY',
   'HOT_ROLLER',
+  'ANY_SEVEN',
+  'ANY_CRAPS',
+  'HORN',
+  'C_AND_E',
+  'WORLD',
+  'BIG_6',
+  'BIG_8',
+  'BUY',
+  'LAY',
+  'BUY_VIG_UP_FRONT',
+  'LAY_VIG_UP_FRONT',
+  'PUT',
 ]);
 
 const isValidCrapsBet = (bet: CrapsBet): boolean => {
@@ -96,6 +108,18 @@ export const applyCrapsState = ({
     20: 'RIDE_LINE',
     21: 'REPLAY',
     22: 'HOT_ROLLER',
+    23: 'ANY_SEVEN',
+    24: 'ANY_CRAPS',
+    25: 'HORN',
+    26: 'C_AND_E',
+    27: 'WORLD',
+    28: 'BIG_6',
+    29: 'BIG_8',
+    30: 'BUY',
+    31: 'LAY',
+    32: 'BUY_VIG_UP_FRONT',
+    33: 'LAY_VIG_UP_FRONT',
+    34: 'PUT',
   };
 
   const parsedBets: CrapsBet[] = [];
@@ -111,6 +135,8 @@ export const applyCrapsState = ({
     const isAts = betTypeVal >= 15 && betTypeVal <= 17;
     const isFire = betTypeVal === 12;
     const isSideBetWithProgress = isFire || isAts || (betTypeVal >= 18 && betTypeVal <= 22);
+    // Buy/Lay with vig up front keep the paid commission in the odds slot.
+    const isVigUpFront = betTypeVal === 32 || betTypeVal === 33;
 
     let parsedTarget: number | undefined = target > 0 ? target : undefined;
     if (isHardway) {
@@ -125,7 +151,7 @@ export const applyCrapsState = ({
       target: parsedTarget,
       status: statusVal === 1 ? 'PENDING' : 'ON',
       amount,
-      oddsAmount: (!isHardway && !isSideBetWithProgress && oddsAmount > 0) ? oddsAmount : undefined,
+      oddsAmount: (!isHardway && !isSideBetWithProgress && !isVigUpFront && oddsAmount > 0) ? oddsAmount : undefined,
       progressMask: isSideBetWithProgress ? oddsAmount : undefined,
     });
   }
@@ -23,6 +23,25 @@ export const applyVideoPokerState = ({
     return;
   }
   const stage = parsed.stage;
+
+  // Betting stage: the deal move has not landed yet, so there are no cards.
+  if (stage === 2) {
+    setGameState((prev) => {
+      const newState: GameState = {
+        ...prev,
+        type: gameType,
+        playerCards: [],
+        videoPokerHand: null,
+        videoPokerMultiplier: null,
+        stage: 'BETTING',
+        message: 'DEALING...',
+      };
+      gameStateRef.current = newState;
+      return newState;
+    });
+    return;
+  }
+
   const cards: Card[] = parsed.cards.map((cardId) => decodeCard(cardId));
 
   if (gameStateRef.current) {
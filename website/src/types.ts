@@ -29,6 +29,11 @@ export enum GameType {
   ULTIMATE_HOLDEM = 'ULTIMATE_HOLDEM',
   VIDEO_POKER = 'VIDEO_POKER',
   HILO = 'HILO',
+  PAI_GOW = 'PAI_GOW',
+  LET_IT_RIDE = 'LET_IT_RIDE',
+  KENO = 'KENO',
+  BIG_SIX = 'BIG_SIX',
+  CRASH = 'CRASH',
 }
 
 export type TournamentPhase = GeneratedTournamentPhase;
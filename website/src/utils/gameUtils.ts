@@ -867,6 +867,14 @@ const VIDEO_POKER_HAND_NAMES: Record<number, string> = {
   7: 'FOUR OF A KIND',
   8: 'STRAIGHT FLUSH',
   9: 'ROYAL FLUSH',
+  10: 'FOUR ACES',
+  11: 'FOUR TWOS THROUGH FOURS',
+  12: 'FOUR ACES WITH KICKER',
+  13: 'FOUR TWOS THROUGH FOURS WITH KICKER',
+  14: 'FIVE OF A KIND',
+  15: 'WILD ROYAL FLUSH',
+  16: 'FOUR DEUCES',
+  17: 'KINGS OR BETTER',
 };
 
 /**
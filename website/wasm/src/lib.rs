@@ -126,7 +126,7 @@ define_instruction_kinds! {
     CasinoPlayerAction = 4 => Instruction::CasinoPlayerAction { .. } => "CasinoPlayerAction" => Instruction::CasinoPlayerAction { action: nullspace_types::casino::PlayerAction::ToggleShield },
     CasinoSetTournamentLimit = 5 => Instruction::CasinoSetTournamentLimit { .. } => "CasinoSetTournamentLimit" => Instruction::CasinoSetTournamentLimit { player: ed25519::PrivateKey::from_seed(1).public_key(), daily_limit: 1 },
     CasinoJoinTournament = 7 => Instruction::CasinoJoinTournament { .. } => "CasinoJoinTournament" => Instruction::CasinoJoinTournament { tournament_id: 1 },
-    CasinoStartTournament = 8 => Instruction::CasinoStartTournament { .. } => "CasinoStartTournament" => Instruction::CasinoStartTournament { tournament_id: 1, start_time_ms: 0, end_time_ms: 1 },
+    CasinoStartTournament = 8 => Instruction::CasinoStartTournament { .. } => "CasinoStartTournament" => Instruction::CasinoStartTournament { tournament_id: 1, start_time_ms: 0, end_time_ms: 1, payout_structure: nullspace_types::casino::PayoutStructure::default() },
     CasinoEndTournament = 9 => Instruction::CasinoEndTournament { .. } => "CasinoEndTournament" => Instruction::CasinoEndTournament { tournament_id: 1 },
 
     // Staking instructions
@@ -169,6 +169,34 @@ define_instruction_kinds! {
     GlobalTableReveal = 42 => Instruction::GlobalTableReveal { .. } => "GlobalTableReveal" => Instruction::GlobalTableReveal { game_type: nullspace_types::casino::GameType::Craps, round_id: 1 },
     GlobalTableSettle = 43 => Instruction::GlobalTableSettle { .. } => "GlobalTableSettle" => Instruction::GlobalTableSettle { game_type: nullspace_types::casino::GameType::Craps, round_id: 1 },
     GlobalTableFinalize = 44 => Instruction::GlobalTableFinalize { .. } => "GlobalTableFinalize" => Instruction::GlobalTableFinalize { game_type: nullspace_types::casino::GameType::Craps, round_id: 1 },
+    GlobalTableCancelBets = 45 => Instruction::GlobalTableCancelBets { .. } => "GlobalTableCancelBets" => Instruction::GlobalTableCancelBets { game_type: nullspace_types::casino::GameType::Craps, round_id: 1 },
+    GlobalTableReplaceBets = 46 => Instruction::GlobalTableReplaceBets { .. } => "GlobalTableReplaceBets" => Instruction::GlobalTableReplaceBets { game_type: nullspace_types::casino::GameType::Craps, round_id: 1, bets: vec![nullspace_types::casino::GlobalTableBet { bet_type: 0, target: 0, amount: 5 }] },
+
+    // Admin registry instructions
+    GrantAdminRole = 47 => Instruction::GrantAdminRole { .. } => "GrantAdminRole" => Instruction::GrantAdminRole { public: ed25519::PrivateKey::from_seed(5).public_key(), role: nullspace_types::casino::AdminRole::Oracle },
+    RevokeAdminRole = 48 => Instruction::RevokeAdminRole { .. } => "RevokeAdminRole" => Instruction::RevokeAdminRole { public: ed25519::PrivateKey::from_seed(5).public_key(), role: nullspace_types::casino::AdminRole::Oracle },
+
+    // Multisig instructions
+    CreateMultisig = 49 => Instruction::CreateMultisig { .. } => "CreateMultisig" => Instruction::CreateMultisig { signers: vec![ed25519::PrivateKey::from_seed(5).public_key(), ed25519::PrivateKey::from_seed(6).public_key()], threshold: 2, timelock_secs: 60, roles: nullspace_types::casino::AdminRole::Treasury.bit() },
+    ProposeMultisig = 50 => Instruction::ProposeMultisig { .. } => "ProposeMultisig" => Instruction::ProposeMultisig { multisig_id: 0, instruction: Box::new(Instruction::FinalizeAmmBootstrap) },
+    ApproveMultisig = 51 => Instruction::ApproveMultisig { .. } => "ApproveMultisig" => Instruction::ApproveMultisig { multisig_id: 0, proposal_id: 0 },
+    ExecuteMultisig = 52 => Instruction::ExecuteMultisig { .. } => "ExecuteMultisig" => Instruction::ExecuteMultisig { multisig_id: 0, proposal_id: 0 },
+
+    // Session retention instructions
+    PruneCasinoSessions = 53 => Instruction::PruneCasinoSessions { .. } => "PruneCasinoSessions" => Instruction::PruneCasinoSessions { session_ids: vec![1] },
+
+    // Global table crash instructions
+    GlobalTableCashOut = 54 => Instruction::GlobalTableCashOut { .. } => "GlobalTableCashOut" => Instruction::GlobalTableCashOut { game_type: nullspace_types::casino::GameType::Crash, round_id: 1 },
+
+    // Paid tournament instructions
+    CasinoCreateTournament = 55 => Instruction::CasinoCreateTournament { .. } => "CasinoCreateTournament" => Instruction::CasinoCreateTournament { tournament_id: 1, config: nullspace_types::casino::TournamentConfig { currency: nullspace_types::casino::TournamentCurrency::Chips, buy_in: 100, rake_bps: 1_000, guarantee: 0, ..Default::default() } },
+    CasinoCancelTournament = 56 => Instruction::CasinoCancelTournament { .. } => "CasinoCancelTournament" => Instruction::CasinoCancelTournament { tournament_id: 1 },
+
+    // Tournament rebuy and add-on instructions
+    CasinoTournamentTopUp = 57 => Instruction::CasinoTournamentTopUp { .. } => "CasinoTournamentTopUp" => Instruction::CasinoTournamentTopUp { tournament_id: 1, kind: nullspace_types::casino::TopUpKind::Rebuy },
+
+    // Multisig dissolution instructions
+    DissolveMultisig = 58 => Instruction::DissolveMultisig { .. } => "DissolveMultisig" => Instruction::DissolveMultisig { multisig_id: 0 },
 }
 
 /// Helper to convert serde_json::Value to a plain JavaScript object
@@ -281,6 +309,45 @@ impl Transaction {
             .to_string()
     }
 
+    /// Get the fee (in chips) this transaction pays for inclusion.
+    #[wasm_bindgen(getter)]
+    pub fn fee(&self) -> u64 {
+        self.inner.fee
+    }
+
+    /// Get the last view at which this transaction may be included, if it expires.
+    #[wasm_bindgen(getter)]
+    pub fn expires_at_view(&self) -> Option<u64> {
+        self.inner.expires_at_view
+    }
+
+    /// Re-sign the same nonce and instruction with a fee attached.
+    #[wasm_bindgen]
+    pub fn with_fee(&self, signer: &Signer, fee: u64) -> Transaction {
+        let tx = ExecutionTransaction::sign_with_extensions(
+            &signer.private_key,
+            self.inner.nonce,
+            self.inner.instruction.clone(),
+            fee,
+            self.inner.expires_at_view,
+        );
+        Transaction { inner: tx }
+    }
+
+    /// Re-sign the same nonce and instruction so it is only valid up to `expires_at_view`
+    /// (pass `undefined` to remove the expiry).
+    #[wasm_bindgen]
+    pub fn with_expiry(&self, signer: &Signer, expires_at_view: Option<u64>) -> Transaction {
+        let tx = ExecutionTransaction::sign_with_extensions(
+            &signer.private_key,
+            self.inner.nonce,
+            self.inner.instruction.clone(),
+            self.inner.fee,
+            expires_at_view,
+        );
+        Transaction { inner: tx }
+    }
+
     /// Sign a new casino start game transaction.
     #[wasm_bindgen]
     pub fn casino_start_game(
@@ -302,6 +369,11 @@ impl Transaction {
             7 => GameType::SicBo,
             8 => GameType::ThreeCard,
             9 => GameType::UltimateHoldem,
+            10 => GameType::PaiGow,
+            11 => GameType::LetItRide,
+            12 => GameType::Keno,
+            13 => GameType::BigSix,
+            14 => GameType::Crash,
             _ => {
                 return Err(JsValue::from_str(&format!(
                     "Invalid game type: {}",
@@ -432,6 +504,34 @@ impl Transaction {
             tournament_id,
             start_time_ms,
             end_time_ms,
+            payout_structure: nullspace_types::casino::PayoutStructure::default(),
+        };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
+    /// Sign a new casino start tournament transaction with a custom payout structure.
+    ///
+    /// `payout_shares_bps` lists each paid place's share of the pool (1st place first)
+    /// and must sum to 10000.
+    #[wasm_bindgen]
+    pub fn casino_start_tournament_with_payouts(
+        signer: &Signer,
+        nonce: u64,
+        tournament_id: u64,
+        start_time_ms: u64,
+        end_time_ms: u64,
+        payout_shares_bps: Vec<u16>,
+    ) -> Result<Transaction, JsValue> {
+        let payout_structure = nullspace_types::casino::PayoutStructure {
+            shares_bps: payout_shares_bps,
+        };
+        payout_structure.validate().map_err(JsValue::from_str)?;
+        let instruction = Instruction::CasinoStartTournament {
+            tournament_id,
+            start_time_ms,
+            end_time_ms,
+            payout_structure,
         };
         let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
         Ok(Transaction { inner: tx })
@@ -461,6 +561,120 @@ impl Transaction {
         Ok(Transaction { inner: tx })
     }
 
+    /// Sign a new casino create tournament transaction (admin only).
+    ///
+    /// `currency` is 0 for chips and 1 for vUSDT; a zero buy-in creates a freeroll.
+    /// A non-zero `max_seats` creates a sit-and-go that starts when full and runs for
+    /// `duration_secs`. `rebuy` and `add_on` are either empty (not offered) or
+    /// `[max_per_player, window_secs, cost, chips, shields, doubles]`.
+    ///
+    /// `allowed_games` lists game type ids (empty allows every game), `bet_limits` is a flat
+    /// list of `[game_type, min_bet, max_bet]` triples, and `starting_stack` is either empty
+    /// (house defaults) or `[chips, shields, doubles]`.
+    ///
+    /// `payout_shares_bps` lists each paid place's share of the pool (1st place first, summing
+    /// to 10000); empty keeps the default top-15% split.
+    #[wasm_bindgen]
+    #[allow(clippy::too_many_arguments)]
+    pub fn casino_create_tournament(
+        signer: &Signer,
+        nonce: u64,
+        tournament_id: u64,
+        currency: u8,
+        buy_in: u64,
+        rake_bps: u16,
+        guarantee: u64,
+        max_seats: u32,
+        duration_secs: u64,
+        rebuy: Vec<u64>,
+        add_on: Vec<u64>,
+        allowed_games: Vec<u8>,
+        bet_limits: Vec<u64>,
+        starting_stack: Vec<u64>,
+        payout_shares_bps: Vec<u16>,
+    ) -> Result<Transaction, JsValue> {
+        let currency = match currency {
+            0 => nullspace_types::casino::TournamentCurrency::Chips,
+            1 => nullspace_types::casino::TournamentCurrency::Vusdt,
+            _ => {
+                return Err(JsValue::from_str(&format!(
+                    "Invalid tournament currency: {}",
+                    currency
+                )))
+            }
+        };
+        let config = nullspace_types::casino::TournamentConfig {
+            currency,
+            buy_in,
+            rake_bps,
+            guarantee,
+            max_seats,
+            duration_secs,
+            rebuy: parse_top_up_rule("rebuy", &rebuy)?,
+            add_on: parse_top_up_rule("add-on", &add_on)?,
+            allowed_games: allowed_games
+                .into_iter()
+                .map(parse_game_type)
+                .collect::<Result<_, _>>()?,
+            bet_limits: parse_tournament_bet_limits(&bet_limits)?,
+            starting_stack: parse_tournament_stack(&starting_stack)?,
+            payout_structure: nullspace_types::casino::PayoutStructure {
+                shares_bps: payout_shares_bps,
+            },
+        };
+        config.validate_game_rules().map_err(JsValue::from_str)?;
+        config
+            .payout_structure
+            .validate()
+            .map_err(JsValue::from_str)?;
+        let instruction = Instruction::CasinoCreateTournament {
+            tournament_id,
+            config,
+        };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
+    /// Sign a new casino cancel tournament transaction (admin only).
+    #[wasm_bindgen]
+    pub fn casino_cancel_tournament(
+        signer: &Signer,
+        nonce: u64,
+        tournament_id: u64,
+    ) -> Result<Transaction, JsValue> {
+        let instruction = Instruction::CasinoCancelTournament { tournament_id };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
+    /// Sign a new casino tournament top-up transaction.
+    ///
+    /// `kind` is 0 for a rebuy and 1 for an add-on.
+    #[wasm_bindgen]
+    pub fn casino_tournament_top_up(
+        signer: &Signer,
+        nonce: u64,
+        tournament_id: u64,
+        kind: u8,
+    ) -> Result<Transaction, JsValue> {
+        let kind = match kind {
+            0 => nullspace_types::casino::TopUpKind::Rebuy,
+            1 => nullspace_types::casino::TopUpKind::AddOn,
+            _ => {
+                return Err(JsValue::from_str(&format!(
+                    "Invalid tournament top-up kind: {}",
+                    kind
+                )))
+            }
+        };
+        let instruction = Instruction::CasinoTournamentTopUp {
+            tournament_id,
+            kind,
+        };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
     /// Sign a new stake transaction.
     #[wasm_bindgen]
     pub fn stake(
@@ -701,12 +915,145 @@ impl Transaction {
         let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
         Ok(Transaction { inner: tx })
     }
+
+    /// Admin: grant an admin registry role (role index as in `AdminRole`).
+    #[wasm_bindgen]
+    pub fn grant_admin_role(
+        signer: &Signer,
+        nonce: u64,
+        public_key: &[u8],
+        role: u8,
+    ) -> Result<Transaction, JsValue> {
+        let mut buf = public_key;
+        let public = ed25519::PublicKey::read(&mut buf)
+            .map_err(|e| JsValue::from_str(&format!("Invalid public key: {e:?}")))?;
+        if !buf.is_empty() {
+            return Err(JsValue::from_str("Invalid public key length"));
+        }
+        let role = nullspace_types::casino::AdminRole::try_from(role)
+            .map_err(|_| JsValue::from_str("Invalid admin role"))?;
+        let instruction = Instruction::GrantAdminRole { public, role };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
+    /// Admin: revoke an admin registry role (role index as in `AdminRole`).
+    #[wasm_bindgen]
+    pub fn revoke_admin_role(
+        signer: &Signer,
+        nonce: u64,
+        public_key: &[u8],
+        role: u8,
+    ) -> Result<Transaction, JsValue> {
+        let mut buf = public_key;
+        let public = ed25519::PublicKey::read(&mut buf)
+            .map_err(|e| JsValue::from_str(&format!("Invalid public key: {e:?}")))?;
+        if !buf.is_empty() {
+            return Err(JsValue::from_str("Invalid public key length"));
+        }
+        let role = nullspace_types::casino::AdminRole::try_from(role)
+            .map_err(|_| JsValue::from_str("Invalid admin role"))?;
+        let instruction = Instruction::RevokeAdminRole { public, role };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
+    /// Admin: create a threshold multisig (`signers` is concatenated 32-byte public keys,
+    /// `roles` a bitmask of `AdminRole` indices).
+    #[wasm_bindgen]
+    pub fn create_multisig(
+        signer: &Signer,
+        nonce: u64,
+        signers: &[u8],
+        threshold: u8,
+        timelock_secs: u64,
+        roles: u8,
+    ) -> Result<Transaction, JsValue> {
+        if signers.is_empty() || !signers.len().is_multiple_of(32) {
+            return Err(JsValue::from_str("Invalid signer keys length"));
+        }
+        let signers = signers
+            .chunks(32)
+            .map(|mut chunk| {
+                ed25519::PublicKey::read(&mut chunk)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid public key: {e:?}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let instruction = Instruction::CreateMultisig {
+            signers,
+            threshold,
+            timelock_secs,
+            roles,
+        };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
+    /// Multisig: propose the instruction of `inner` (its nonce and signature are ignored).
+    #[wasm_bindgen]
+    pub fn propose_multisig(
+        signer: &Signer,
+        nonce: u64,
+        multisig_id: u64,
+        inner: &Transaction,
+    ) -> Result<Transaction, JsValue> {
+        if inner.inner.instruction.is_multisig_proposal_step() {
+            return Err(JsValue::from_str("Multisig proposals cannot be nested"));
+        }
+        let instruction = Instruction::ProposeMultisig {
+            multisig_id,
+            instruction: Box::new(inner.inner.instruction.clone()),
+        };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Ok(Transaction { inner: tx })
+    }
+
+    /// Multisig: approve a pending proposal.
+    #[wasm_bindgen]
+    pub fn approve_multisig(
+        signer: &Signer,
+        nonce: u64,
+        multisig_id: u64,
+        proposal_id: u64,
+    ) -> Transaction {
+        let instruction = Instruction::ApproveMultisig {
+            multisig_id,
+            proposal_id,
+        };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Transaction { inner: tx }
+    }
+
+    /// Multisig: execute an approved proposal after its time-lock.
+    #[wasm_bindgen]
+    pub fn execute_multisig(
+        signer: &Signer,
+        nonce: u64,
+        multisig_id: u64,
+        proposal_id: u64,
+    ) -> Transaction {
+        let instruction = Instruction::ExecuteMultisig {
+            multisig_id,
+            proposal_id,
+        };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Transaction { inner: tx }
+    }
+
+    /// Multisig: dissolve a multisig (wrap with `propose_multisig` on the same multisig).
+    #[wasm_bindgen]
+    pub fn dissolve_multisig(signer: &Signer, nonce: u64, multisig_id: u64) -> Transaction {
+        let instruction = Instruction::DissolveMultisig { multisig_id };
+        let tx = ExecutionTransaction::sign(&signer.private_key, nonce, instruction);
+        Transaction { inner: tx }
+    }
 }
 
 /// Compute the explorer transaction digest (signature excluded).
 ///
 /// Matches `types/src/execution.rs` `Transaction::digest()`:
-/// `sha256(nonce_be || instruction.encode() || public_key_bytes)`.
+/// `sha256(nonce_be || extensions || instruction.encode() || public_key_bytes)`, where the
+/// extension block is only present for transactions that carry one (e.g. a fee).
 #[wasm_bindgen]
 pub fn digest_transaction(tx_bytes: &[u8]) -> Result<String, JsValue> {
     let mut buf = tx_bytes;
@@ -873,6 +1220,27 @@ pub fn encode_oracle_state_key() -> Vec<u8> {
     key.encode().to_vec()
 }
 
+/// Encode the admin registry key.
+#[wasm_bindgen]
+pub fn encode_admin_registry_key() -> Vec<u8> {
+    let key = Key::AdminRegistry;
+    key.encode().to_vec()
+}
+
+/// Encode a multisig account key.
+#[wasm_bindgen]
+pub fn encode_multisig_key(multisig_id: u64) -> Vec<u8> {
+    let key = Key::Multisig(multisig_id);
+    key.encode().to_vec()
+}
+
+/// Encode a multisig proposal key.
+#[wasm_bindgen]
+pub fn encode_multisig_proposal_key(multisig_id: u64, proposal_id: u64) -> Vec<u8> {
+    let key = Key::MultisigProposal(multisig_id, proposal_id);
+    key.encode().to_vec()
+}
+
 /// Encode UpdatesFilter::All
 #[wasm_bindgen]
 pub fn encode_updates_filter_all() -> Vec<u8> {
@@ -928,6 +1296,145 @@ fn treasury_bucket_label(bucket: nullspace_types::casino::TreasuryBucket) -> &'s
     }
 }
 
+fn admin_role_names(roles: u8) -> Vec<String> {
+    nullspace_types::casino::AdminRole::ALL
+        .iter()
+        .filter(|role| roles & role.bit() != 0)
+        .map(|role| format!("{role:?}"))
+        .collect()
+}
+
+fn multisig_json(multisig: &nullspace_types::casino::MultisigAccount) -> serde_json::Value {
+    serde_json::json!({
+        "id": multisig.id,
+        "signers": multisig
+            .signers
+            .iter()
+            .map(|key| hex(&key.encode()))
+            .collect::<Vec<_>>(),
+        "threshold": multisig.threshold,
+        "timelock_secs": multisig.timelock_secs,
+        "roles": admin_role_names(multisig.roles),
+        "next_proposal_id": multisig.next_proposal_id
+    })
+}
+
+/// Parse `[max_per_player, window_secs, cost, chips, shields, doubles]`; an empty list
+/// leaves the purchase disabled.
+fn parse_top_up_rule(
+    label: &str,
+    values: &[u64],
+) -> Result<nullspace_types::casino::TopUpRule, JsValue> {
+    let [max_per_player, window_secs, cost, chips, shields, doubles] = *values else {
+        if values.is_empty() {
+            return Ok(Default::default());
+        }
+        return Err(JsValue::from_str(&format!(
+            "Invalid {label} rule: expected 6 values, got {}",
+            values.len()
+        )));
+    };
+    let invalid = |field: &str| JsValue::from_str(&format!("Invalid {label} {field}"));
+    Ok(nullspace_types::casino::TopUpRule {
+        max_per_player: u8::try_from(max_per_player).map_err(|_| invalid("max_per_player"))?,
+        window_secs,
+        cost,
+        chips,
+        shields: u32::try_from(shields).map_err(|_| invalid("shields"))?,
+        doubles: u32::try_from(doubles).map_err(|_| invalid("doubles"))?,
+    })
+}
+
+fn parse_game_type(value: u8) -> Result<nullspace_types::casino::GameType, JsValue> {
+    nullspace_types::casino::GameType::read(&mut &[value][..])
+        .map_err(|_| JsValue::from_str(&format!("Invalid game type: {value}")))
+}
+
+/// Parse a flat list of `[game_type, min_bet, max_bet]` triples.
+fn parse_tournament_bet_limits(
+    values: &[u64],
+) -> Result<Vec<nullspace_types::casino::TournamentBetLimit>, JsValue> {
+    if !values.len().is_multiple_of(3) {
+        return Err(JsValue::from_str(
+            "Bet limits must be [game_type, min_bet, max_bet] triples",
+        ));
+    }
+    values
+        .chunks_exact(3)
+        .map(|limit| {
+            let game_type = u8::try_from(limit[0])
+                .map_err(|_| JsValue::from_str(&format!("Invalid game type: {}", limit[0])))?;
+            Ok(nullspace_types::casino::TournamentBetLimit {
+                game_type: parse_game_type(game_type)?,
+                min_bet: limit[1],
+                max_bet: limit[2],
+            })
+        })
+        .collect()
+}
+
+/// Parse `[chips, shields, doubles]`; an empty list keeps the house defaults.
+fn parse_tournament_stack(
+    values: &[u64],
+) -> Result<nullspace_types::casino::TournamentStack, JsValue> {
+    let [chips, shields, doubles] = *values else {
+        if values.is_empty() {
+            return Ok(Default::default());
+        }
+        return Err(JsValue::from_str(&format!(
+            "Invalid starting stack: expected 3 values, got {}",
+            values.len()
+        )));
+    };
+    let invalid = |field: &str| JsValue::from_str(&format!("Invalid starting {field}"));
+    Ok(nullspace_types::casino::TournamentStack {
+        chips,
+        shields: u32::try_from(shields).map_err(|_| invalid("shields"))?,
+        doubles: u32::try_from(doubles).map_err(|_| invalid("doubles"))?,
+    })
+}
+
+/// Game restrictions and starting stack of a tournament definition.
+fn tournament_game_rules_json(
+    config: &nullspace_types::casino::TournamentConfig,
+) -> serde_json::Value {
+    let bet_limits: Vec<_> = config
+        .bet_limits
+        .iter()
+        .map(|limit| {
+            serde_json::json!({
+                "game_type": format!("{:?}", limit.game_type),
+                "min_bet": limit.min_bet,
+                "max_bet": limit.max_bet
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "allowed_games": config
+            .allowed_games
+            .iter()
+            .map(|game_type| format!("{game_type:?}"))
+            .collect::<Vec<_>>(),
+        "bet_limits": bet_limits,
+        "starting_stack": {
+            "chips": config.starting_stack.chips,
+            "shields": config.starting_stack.shields,
+            "doubles": config.starting_stack.doubles
+        }
+    })
+}
+
+fn top_up_rule_json(rule: &nullspace_types::casino::TopUpRule) -> serde_json::Value {
+    serde_json::json!({
+        "max_per_player": rule.max_per_player,
+        "window_secs": rule.window_secs,
+        "cost": rule.cost,
+        "chips": rule.chips,
+        "shields": rule.shields,
+        "doubles": rule.doubles
+    })
+}
+
 // Helper function to convert Value to JSON
 fn decode_value(value: Value) -> Result<JsValue, JsValue> {
     // Convert to JSON
@@ -976,6 +1483,7 @@ fn decode_value(value: Value) -> Result<JsValue, JsValue> {
                 "last_session_ts": player.session.last_session_ts,
                 "bridge_daily_day": player.session.bridge_daily_day,
                 "bridge_daily_withdrawn": player.session.bridge_daily_withdrawn,
+                "last_session": player.session.last_session,
                 "aura_meter": player.modifiers.aura_meter,
                 "tournaments_played_today": player.tournament.tournaments_played_today,
                 "last_tournament_ts": player.tournament.last_tournament_ts,
@@ -1053,6 +1561,18 @@ fn decode_value(value: Value) -> Result<JsValue, JsValue> {
                 })
                 .collect();
 
+            let top_ups: Vec<_> = tournament
+                .top_ups
+                .iter()
+                .map(|entry| {
+                    serde_json::json!({
+                        "player": hex(&entry.player.encode()),
+                        "rebuys": entry.rebuys,
+                        "add_ons": entry.add_ons
+                    })
+                })
+                .collect();
+
             serde_json::json!({
                 "type": "Tournament",
                 "id": tournament.id,
@@ -1067,7 +1587,22 @@ fn decode_value(value: Value) -> Result<JsValue, JsValue> {
                 "starting_doubles": tournament.starting_doubles,
                 "leaderboard": {
                     "entries": leaderboard_entries
-                }
+                },
+                "config": {
+                    "currency": format!("{:?}", tournament.config.currency),
+                    "buy_in": tournament.config.buy_in,
+                    "rake_bps": tournament.config.rake_bps,
+                    "guarantee": tournament.config.guarantee,
+                    "max_seats": tournament.config.max_seats,
+                    "duration_secs": tournament.config.duration_secs,
+                    "rebuy": top_up_rule_json(&tournament.config.rebuy),
+                    "add_on": top_up_rule_json(&tournament.config.add_on),
+                    "game_rules": tournament_game_rules_json(&tournament.config)
+                },
+                "rake_collected": tournament.rake_collected,
+                "guarantee_overlay": tournament.guarantee_overlay,
+                "payout_shares_bps": tournament.config.payout_structure.shares_bps,
+                "top_ups": top_ups
             })
         }
         // Staking & House values
@@ -1161,7 +1696,9 @@ fn decode_value(value: Value) -> Result<JsValue, JsValue> {
                 "bridge_delay_secs": policy.bridge_delay_secs,
                 "oracle_enabled": policy.oracle_enabled,
                 "oracle_max_deviation_bps": policy.oracle_max_deviation_bps,
-                "oracle_stale_secs": policy.oracle_stale_secs
+                "oracle_stale_secs": policy.oracle_stale_secs,
+                "transaction_fee_burn_bps": policy.transaction_fee_burn_bps,
+                "casino_session_retention_views": policy.casino_session_retention_views
             })
         }
         Value::Treasury(treasury) => {
@@ -1281,6 +1818,47 @@ fn decode_value(value: Value) -> Result<JsValue, JsValue> {
                 "source": hex(&state.source)
             })
         }
+        Value::AdminRegistry(registry) => {
+            let admins: Vec<_> = registry
+                .admins
+                .iter()
+                .map(|entry| {
+                    serde_json::json!({
+                        "public": hex(&entry.public.encode()),
+                        "roles": admin_role_names(entry.roles)
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "type": "AdminRegistry",
+                "admins": admins,
+                "guarded_roles": admin_role_names(registry.guarded_roles),
+                "next_multisig_id": registry.next_multisig_id
+            })
+        }
+        Value::Multisig(multisig) => {
+            serde_json::json!({
+                "type": "Multisig",
+                "multisig": multisig_json(&multisig)
+            })
+        }
+        Value::MultisigProposal(proposal) => {
+            serde_json::json!({
+                "type": "MultisigProposal",
+                "multisig_id": proposal.multisig_id,
+                "id": proposal.id,
+                "proposer": hex(&proposal.proposer.encode()),
+                "instruction": InstructionKind::from_instruction(&proposal.instruction).as_str(),
+                "instruction_bytes": hex(&proposal.instruction.encode()),
+                "approvals": proposal
+                    .approvals
+                    .iter()
+                    .map(|key| hex(&key.encode()))
+                    .collect::<Vec<_>>(),
+                "executable_at": proposal.executable_at,
+                "executed": proposal.executed
+            })
+        }
         Value::GlobalTableConfig(config) => {
             serde_json::json!({
                 "type": "GlobalTableConfig",
@@ -1343,6 +1921,56 @@ fn decode_value(value: Value) -> Result<JsValue, JsValue> {
                 "balance": bal
             })
         }
+        Value::CompletedCasinoSessions(session_ids) => {
+            serde_json::json!({
+                "type": "CompletedCasinoSessions",
+                "session_ids": session_ids
+            })
+        }
+        Value::CasinoSessionGc(state) => {
+            serde_json::json!({
+                "type": "CasinoSessionGc",
+                "next_view": state.next_view,
+                "next_deadline_view": state.next_deadline_view
+            })
+        }
+        Value::CasinoSessionDeadlines(deadlines) => {
+            let deadlines: Vec<_> = deadlines
+                .iter()
+                .map(|deadline| {
+                    serde_json::json!({
+                        "session_id": deadline.session_id,
+                        "move_count": deadline.move_count
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "type": "CasinoSessionDeadlines",
+                "deadlines": deadlines
+            })
+        }
+        Value::TournamentDeadlines(deadlines) => {
+            let deadlines: Vec<_> = deadlines
+                .iter()
+                .map(|deadline| {
+                    serde_json::json!({
+                        "end_view": deadline.end_view,
+                        "tournament_id": deadline.tournament_id
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "type": "TournamentDeadlines",
+                "deadlines": deadlines
+            })
+        }
+        Value::GlobalTablePlayers(players) => {
+            let players: Vec<_> = players.iter().map(|pk| hex(&pk.encode())).collect();
+            serde_json::json!({
+                "type": "GlobalTablePlayers",
+                "players": players
+            })
+        }
     };
 
     to_object(&json)
@@ -1457,7 +2085,8 @@ fn serialize_global_table_round(
             .totals
             .iter()
             .map(serialize_global_table_total)
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>(),
+        "outcome": round.outcome
     })
 }
 
@@ -1682,6 +2311,78 @@ fn decode_event(event: &Event) -> Result<serde_json::Value, JsValue> {
                 "round_id": round_id
             })
         }
+        Event::GlobalTableBetsCancelled {
+            player,
+            round_id,
+            bets,
+            player_balances,
+        } => {
+            serde_json::json!({
+                "type": "GlobalTableBetsCancelled",
+                "player": hex(&player.encode()),
+                "round_id": round_id,
+                "bets": bets.iter().map(serialize_global_table_bet).collect::<Vec<_>>(),
+                "player_balances": {
+                    "chips": player_balances.chips,
+                    "vusdt_balance": player_balances.vusdt_balance,
+                    "shields": player_balances.shields,
+                    "doubles": player_balances.doubles,
+                    "tournament_chips": player_balances.tournament_chips,
+                    "tournament_shields": player_balances.tournament_shields,
+                    "tournament_doubles": player_balances.tournament_doubles,
+                    "active_tournament": player_balances.active_tournament
+                }
+            })
+        }
+        Event::GlobalTableBetsReplaced {
+            player,
+            round_id,
+            cancelled,
+            bets,
+            player_balances,
+        } => {
+            serde_json::json!({
+                "type": "GlobalTableBetsReplaced",
+                "player": hex(&player.encode()),
+                "round_id": round_id,
+                "cancelled": cancelled.iter().map(serialize_global_table_bet).collect::<Vec<_>>(),
+                "bets": bets.iter().map(serialize_global_table_bet).collect::<Vec<_>>(),
+                "player_balances": {
+                    "chips": player_balances.chips,
+                    "vusdt_balance": player_balances.vusdt_balance,
+                    "shields": player_balances.shields,
+                    "doubles": player_balances.doubles,
+                    "tournament_chips": player_balances.tournament_chips,
+                    "tournament_shields": player_balances.tournament_shields,
+                    "tournament_doubles": player_balances.tournament_doubles,
+                    "active_tournament": player_balances.active_tournament
+                }
+            })
+        }
+        Event::GlobalTableRolling {
+            game_type,
+            round_id,
+            phase_ends_at_ms,
+        } => {
+            serde_json::json!({
+                "type": "GlobalTableRolling",
+                "game_type": format!("{:?}", game_type),
+                "round_id": round_id,
+                "phase_ends_at_ms": phase_ends_at_ms
+            })
+        }
+        Event::GlobalTableCashedOut {
+            player,
+            round_id,
+            multiplier,
+        } => {
+            serde_json::json!({
+                "type": "GlobalTableCashedOut",
+                "player": hex(&player.encode()),
+                "round_id": round_id,
+                "multiplier": multiplier
+            })
+        }
         Event::PlayerModifierToggled {
             player,
             action,
@@ -1721,6 +2422,7 @@ fn decode_event(event: &Event) -> Result<serde_json::Value, JsValue> {
                 nullspace_types::casino::TournamentPhase::Registration => "Registration",
                 nullspace_types::casino::TournamentPhase::Active => "Active",
                 nullspace_types::casino::TournamentPhase::Complete => "Complete",
+                nullspace_types::casino::TournamentPhase::Cancelled => "Cancelled",
             };
             serde_json::json!({
                 "type": "TournamentPhaseChanged",
@@ -1744,6 +2446,127 @@ fn decode_event(event: &Event) -> Result<serde_json::Value, JsValue> {
                 "rankings": rankings_json
             })
         }
+        Event::TournamentCreated { id, config } => {
+            serde_json::json!({
+                "type": "TournamentCreated",
+                "id": id,
+                "currency": format!("{:?}", config.currency),
+                "buy_in": config.buy_in,
+                "rake_bps": config.rake_bps,
+                "guarantee": config.guarantee,
+                "max_seats": config.max_seats,
+                "duration_secs": config.duration_secs,
+                "rebuy": top_up_rule_json(&config.rebuy),
+                "add_on": top_up_rule_json(&config.add_on),
+                "game_rules": tournament_game_rules_json(config),
+                "payout_shares_bps": config.payout_structure.shares_bps
+            })
+        }
+        Event::TournamentBuyIn {
+            tournament_id,
+            player,
+            amount,
+            rake,
+            prize_pool,
+            player_balances,
+        } => {
+            serde_json::json!({
+                "type": "TournamentBuyIn",
+                "tournament_id": tournament_id,
+                "player": hex(&player.encode()),
+                "amount": amount,
+                "rake": rake,
+                "prize_pool": prize_pool,
+                "player_balances": {
+                    "chips": player_balances.chips,
+                    "vusdt_balance": player_balances.vusdt_balance,
+                    "shields": player_balances.shields,
+                    "doubles": player_balances.doubles,
+                    "tournament_chips": player_balances.tournament_chips,
+                    "tournament_shields": player_balances.tournament_shields,
+                    "tournament_doubles": player_balances.tournament_doubles,
+                    "active_tournament": player_balances.active_tournament
+                }
+            })
+        }
+        Event::TournamentTopUp {
+            tournament_id,
+            player,
+            kind,
+            cost,
+            prize_pool,
+            rebuys,
+            add_ons,
+            player_balances,
+        } => {
+            serde_json::json!({
+                "type": "TournamentTopUp",
+                "tournament_id": tournament_id,
+                "player": hex(&player.encode()),
+                "kind": format!("{:?}", kind),
+                "cost": cost,
+                "prize_pool": prize_pool,
+                "rebuys": rebuys,
+                "add_ons": add_ons,
+                "player_balances": {
+                    "chips": player_balances.chips,
+                    "vusdt_balance": player_balances.vusdt_balance,
+                    "shields": player_balances.shields,
+                    "doubles": player_balances.doubles,
+                    "tournament_chips": player_balances.tournament_chips,
+                    "tournament_shields": player_balances.tournament_shields,
+                    "tournament_doubles": player_balances.tournament_doubles,
+                    "active_tournament": player_balances.active_tournament
+                }
+            })
+        }
+        Event::CasinoSessionsPruned {
+            pruner,
+            session_ids,
+        } => {
+            serde_json::json!({
+                "type": "CasinoSessionsPruned",
+                "pruner": hex(&pruner.encode()),
+                "session_ids": session_ids
+            })
+        }
+        Event::TournamentCancelled { id, refunds } => {
+            let refunds_json: Vec<_> = refunds
+                .iter()
+                .map(|(player, amount)| {
+                    serde_json::json!({
+                        "player": hex(&player.encode()),
+                        "amount": amount
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "type": "TournamentCancelled",
+                "id": id,
+                "refunds": refunds_json
+            })
+        }
+        Event::TournamentPrizesPaid {
+            id,
+            currency,
+            payouts,
+        } => {
+            let payouts_json: Vec<_> = payouts
+                .iter()
+                .map(|(player, amount)| {
+                    serde_json::json!({
+                        "player": hex(&player.encode()),
+                        "amount": amount
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "type": "TournamentPrizesPaid",
+                "id": id,
+                "currency": format!("{:?}", currency),
+                "payouts": payouts_json
+            })
+        }
 
         // Vault & AMM events
         Event::VaultCreated { player, vault } => {
@@ -2124,7 +2947,113 @@ fn decode_event(event: &Event) -> Result<serde_json::Value, JsValue> {
                     "bridge_delay_secs": policy.bridge_delay_secs,
                     "oracle_enabled": policy.oracle_enabled,
                     "oracle_max_deviation_bps": policy.oracle_max_deviation_bps,
-                    "oracle_stale_secs": policy.oracle_stale_secs
+                    "oracle_stale_secs": policy.oracle_stale_secs,
+                    "transaction_fee_burn_bps": policy.transaction_fee_burn_bps,
+                    "casino_session_retention_views": policy.casino_session_retention_views
+                }
+            })
+        }
+        Event::AdminRoleGranted {
+            admin,
+            public,
+            role,
+        } => {
+            serde_json::json!({
+                "type": "AdminRoleGranted",
+                "admin": hex(&admin.encode()),
+                "public": hex(&public.encode()),
+                "role": format!("{role:?}")
+            })
+        }
+        Event::AdminRoleRevoked {
+            admin,
+            public,
+            role,
+        } => {
+            serde_json::json!({
+                "type": "AdminRoleRevoked",
+                "admin": hex(&admin.encode()),
+                "public": hex(&public.encode()),
+                "role": format!("{role:?}")
+            })
+        }
+        Event::MultisigCreated { admin, multisig } => {
+            serde_json::json!({
+                "type": "MultisigCreated",
+                "admin": hex(&admin.encode()),
+                "multisig": multisig_json(multisig)
+            })
+        }
+        Event::MultisigProposed {
+            proposer,
+            multisig_id,
+            proposal_id,
+        } => {
+            serde_json::json!({
+                "type": "MultisigProposed",
+                "proposer": hex(&proposer.encode()),
+                "multisig_id": multisig_id,
+                "proposal_id": proposal_id
+            })
+        }
+        Event::MultisigApproved {
+            signer,
+            multisig_id,
+            proposal_id,
+            approvals,
+            executable_at,
+        } => {
+            serde_json::json!({
+                "type": "MultisigApproved",
+                "signer": hex(&signer.encode()),
+                "multisig_id": multisig_id,
+                "proposal_id": proposal_id,
+                "approvals": approvals,
+                "executable_at": executable_at
+            })
+        }
+        Event::MultisigExecuted {
+            executor,
+            multisig_id,
+            proposal_id,
+        } => {
+            serde_json::json!({
+                "type": "MultisigExecuted",
+                "executor": hex(&executor.encode()),
+                "multisig_id": multisig_id,
+                "proposal_id": proposal_id
+            })
+        }
+        Event::MultisigDissolved {
+            multisig_id,
+            guarded_roles,
+        } => {
+            serde_json::json!({
+                "type": "MultisigDissolved",
+                "multisig_id": multisig_id,
+                "guarded_roles": admin_role_names(*guarded_roles)
+            })
+        }
+        Event::TransactionFeePaid {
+            player,
+            fee,
+            burned,
+            player_balances,
+        } => {
+            serde_json::json!({
+                "type": "TransactionFeePaid",
+                "player": hex(&player.encode()),
+                "fee": fee,
+                "burned": burned,
+                "player_balances": {
+                    "chips": player_balances.chips,
+                    "vusdt_balance": player_balances.vusdt_balance,
+                    "shields": player_balances.shields,
+                    "doubles": player_balances.doubles,
+                    "tournament_chips": player_balances.tournament_chips,
+                    "tournament_shields": player_balances.tournament_shields,
+                    "tournament_doubles": player_balances.tournament_doubles,
+                    "active_tournament": player_balances.active_tournament
                 }
             })
         }